@@ -3,8 +3,9 @@
 //! This module provides different algorithms for dynamically adjusting
 //! concurrency limits based on observed latency and error rates.
 
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use tower_resilience_core::aimd::{AimdConfig, AimdController};
 
 /// Trait for adaptive concurrency control algorithms.
@@ -28,6 +29,47 @@ pub trait ConcurrencyAlgorithm: Send + Sync {
     fn max_limit(&self) -> usize;
 }
 
+/// Holds off limit adjustments until enough calls have been observed.
+#[derive(Debug, Default)]
+struct Warmup {
+    /// Calls to observe before adjusting
+    calls: usize,
+    /// Time since the first call before adjusting
+    duration: Duration,
+    /// Calls observed so far
+    seen: AtomicUsize,
+    /// When the first call was observed
+    started: OnceLock<Instant>,
+    /// Set once the warm-up period is over
+    warm: AtomicBool,
+}
+
+impl Warmup {
+    fn new(calls: usize, duration: Duration) -> Self {
+        Self {
+            calls,
+            duration,
+            ..Self::default()
+        }
+    }
+
+    /// Count a call, returning whether the warm-up period is over.
+    fn observe(&self) -> bool {
+        if self.warm.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        let started = *self.started.get_or_init(Instant::now);
+        let seen = self.seen.fetch_add(1, Ordering::Relaxed) + 1;
+        if seen > self.calls && started.elapsed() >= self.duration {
+            self.warm.store(true, Ordering::Relaxed);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 /// AIMD (Additive Increase Multiplicative Decrease) algorithm.
 ///
 /// This is the classic TCP congestion control algorithm:
@@ -35,10 +77,18 @@ pub trait ConcurrencyAlgorithm: Send + Sync {
 /// - On failure/timeout: decrease limit by a factor
 ///
 /// The algorithm creates a "sawtooth" pattern as it probes for capacity.
+///
+/// By default each call's latency is compared against the threshold. With a
+/// latency window configured, the limit is only adjusted once per window,
+/// based on a percentile of the latencies observed in it.
 pub struct Aimd {
     controller: AimdController,
     /// Latency threshold above which we consider the system congested.
     latency_threshold: Duration,
+    /// Samples for the windowed latency signal, if enabled.
+    window: Option<Mutex<LatencyWindow>>,
+    /// Startup period during which the limit is left alone
+    warmup: Warmup,
 }
 
 impl Aimd {
@@ -47,6 +97,8 @@ impl Aimd {
         Self {
             controller: AimdController::new(config),
             latency_threshold,
+            window: None,
+            warmup: Warmup::default(),
         }
     }
 
@@ -58,6 +110,22 @@ impl Aimd {
 
 impl ConcurrencyAlgorithm for Aimd {
     fn record_success(&self, latency: Duration) {
+        if !self.warmup.observe() {
+            return;
+        }
+
+        let latency = match &self.window {
+            Some(window) => {
+                let mut window = window.lock().unwrap_or_else(|e| e.into_inner());
+                match window.record(latency) {
+                    Some(percentile) => percentile,
+                    // Wait for the window to fill before adjusting
+                    None => return,
+                }
+            }
+            None => latency,
+        };
+
         if latency > self.latency_threshold {
             // High latency indicates congestion
             self.controller.record_failure();
@@ -67,7 +135,9 @@ impl ConcurrencyAlgorithm for Aimd {
     }
 
     fn record_failure(&self) {
-        self.controller.record_failure();
+        if self.warmup.observe() {
+            self.controller.record_failure();
+        }
     }
 
     fn record_dropped(&self) {
@@ -87,6 +157,58 @@ impl ConcurrencyAlgorithm for Aimd {
     }
 }
 
+/// How long a latency window stays open.
+#[derive(Debug, Clone, Copy)]
+enum WindowSpan {
+    /// Close after this many calls.
+    Calls(usize),
+    /// Close once this much time has passed since the first sample.
+    Time(Duration),
+}
+
+/// Latency samples collected for a windowed congestion signal.
+#[derive(Debug)]
+struct LatencyWindow {
+    span: WindowSpan,
+    percentile: f64,
+    samples: Vec<Duration>,
+    started: Instant,
+}
+
+impl LatencyWindow {
+    fn new(span: WindowSpan, percentile: f64) -> Self {
+        Self {
+            span,
+            percentile,
+            samples: Vec::new(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Record a sample, returning the window's percentile latency if this
+    /// sample closed the window.
+    fn record(&mut self, latency: Duration) -> Option<Duration> {
+        if self.samples.is_empty() {
+            self.started = Instant::now();
+        }
+        self.samples.push(latency);
+
+        let closed = match self.span {
+            WindowSpan::Calls(calls) => self.samples.len() >= calls,
+            WindowSpan::Time(duration) => self.started.elapsed() >= duration,
+        };
+        if !closed {
+            return None;
+        }
+
+        self.samples.sort_unstable();
+        let rank = (self.percentile * self.samples.len() as f64).ceil() as usize;
+        let value = self.samples[rank.clamp(1, self.samples.len()) - 1];
+        self.samples.clear();
+        Some(value)
+    }
+}
+
 /// Builder for AIMD algorithm.
 #[derive(Debug, Clone)]
 pub struct AimdBuilder {
@@ -96,6 +218,10 @@ pub struct AimdBuilder {
     increase_by: usize,
     decrease_factor: f64,
     latency_threshold: Duration,
+    window: Option<WindowSpan>,
+    latency_percentile: f64,
+    warmup_calls: usize,
+    warmup_duration: Duration,
 }
 
 impl Default for AimdBuilder {
@@ -107,6 +233,10 @@ impl Default for AimdBuilder {
             increase_by: 1,
             decrease_factor: 0.5,
             latency_threshold: Duration::from_millis(100),
+            window: None,
+            latency_percentile: 0.95,
+            warmup_calls: 0,
+            warmup_duration: Duration::ZERO,
         }
     }
 }
@@ -150,6 +280,65 @@ impl AimdBuilder {
         self
     }
 
+    /// Evaluate latency over windows of `calls` successful calls instead of
+    /// per call.
+    ///
+    /// At the end of each window, the configured percentile latency is
+    /// compared against the threshold and the limit is adjusted once. This
+    /// keeps a few slow outliers from shrinking the limit. Failures still
+    /// decrease the limit immediately.
+    ///
+    /// Default: disabled (per-call latency)
+    pub fn latency_window(mut self, calls: usize) -> Self {
+        self.window = Some(WindowSpan::Calls(calls.max(1)));
+        self
+    }
+
+    /// Evaluate latency over windows of `duration` instead of per call.
+    ///
+    /// Like [`latency_window`](Self::latency_window), but each window closes
+    /// on the first successful call after `duration` has elapsed.
+    ///
+    /// Default: disabled (per-call latency)
+    pub fn latency_window_duration(mut self, duration: Duration) -> Self {
+        self.window = Some(WindowSpan::Time(duration));
+        self
+    }
+
+    /// Set the latency percentile evaluated at the end of each window
+    /// (0.0 - 1.0).
+    ///
+    /// Only used when a latency window is configured.
+    ///
+    /// Default: 0.95
+    pub fn latency_percentile(mut self, percentile: f64) -> Self {
+        self.latency_percentile = percentile.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Leave the limit unchanged for the first `calls` calls.
+    ///
+    /// Startup traffic often sees cold caches and connection setup; without
+    /// a warm-up period that latency can drive the limit down to the minimum
+    /// before the service reaches steady state.
+    ///
+    /// Default: 0
+    pub fn warmup_calls(mut self, calls: usize) -> Self {
+        self.warmup_calls = calls;
+        self
+    }
+
+    /// Leave the limit unchanged until `duration` after the first call.
+    ///
+    /// Combined with [`warmup_calls`](Self::warmup_calls), both must be
+    /// satisfied before the limit starts adjusting.
+    ///
+    /// Default: zero
+    pub fn warmup_duration(mut self, duration: Duration) -> Self {
+        self.warmup_duration = duration;
+        self
+    }
+
     /// Build the AIMD algorithm.
     pub fn build(self) -> Aimd {
         let config = AimdConfig::new()
@@ -159,7 +348,12 @@ impl AimdBuilder {
             .with_increase_by(self.increase_by)
             .with_decrease_factor(self.decrease_factor);
 
-        Aimd::new(config, self.latency_threshold)
+        let mut aimd = Aimd::new(config, self.latency_threshold);
+        aimd.window = self
+            .window
+            .map(|span| Mutex::new(LatencyWindow::new(span, self.latency_percentile)));
+        aimd.warmup = Warmup::new(self.warmup_calls, self.warmup_duration);
+        aimd
     }
 }
 
@@ -191,6 +385,8 @@ pub struct Vegas {
     sample_count: AtomicUsize,
     /// Minimum samples before adjusting
     min_samples: usize,
+    /// Startup period during which the limit is left alone
+    warmup: Warmup,
 }
 
 impl Vegas {
@@ -213,6 +409,7 @@ impl Vegas {
             smoothed_rtt_nanos: AtomicU64::new(0),
             sample_count: AtomicUsize::new(0),
             min_samples: 10,
+            warmup: Warmup::default(),
         }
     }
 
@@ -292,11 +489,18 @@ impl Vegas {
 
 impl ConcurrencyAlgorithm for Vegas {
     fn record_success(&self, latency: Duration) {
+        // Keep building the RTT baseline while warming up
         self.update_rtt(latency);
-        self.adjust_limit();
+        if self.warmup.observe() {
+            self.adjust_limit();
+        }
     }
 
     fn record_failure(&self) {
+        if !self.warmup.observe() {
+            return;
+        }
+
         // On error, decrease limit immediately
         let current = self.limit.load(Ordering::Relaxed);
         let new_limit = (current / 2).max(self.min_limit);
@@ -328,6 +532,8 @@ pub struct VegasBuilder {
     max_limit: usize,
     alpha: usize,
     beta: usize,
+    warmup_calls: usize,
+    warmup_duration: Duration,
 }
 
 impl Default for VegasBuilder {
@@ -338,6 +544,8 @@ impl Default for VegasBuilder {
             max_limit: 100,
             alpha: 3,
             beta: 6,
+            warmup_calls: 0,
+            warmup_duration: Duration::ZERO,
         }
     }
 }
@@ -377,15 +585,320 @@ impl VegasBuilder {
         self
     }
 
+    /// Leave the limit unchanged for the first `calls` calls.
+    ///
+    /// Startup traffic often sees cold caches and connection setup; without
+    /// a warm-up period that latency can drive the limit down to the minimum
+    /// before the service reaches steady state.
+    ///
+    /// RTT samples still feed the baseline during warm-up.
+    ///
+    /// Default: 0
+    pub fn warmup_calls(mut self, calls: usize) -> Self {
+        self.warmup_calls = calls;
+        self
+    }
+
+    /// Leave the limit unchanged until `duration` after the first call.
+    ///
+    /// Combined with [`warmup_calls`](Self::warmup_calls), both must be
+    /// satisfied before the limit starts adjusting.
+    ///
+    /// Default: zero
+    pub fn warmup_duration(mut self, duration: Duration) -> Self {
+        self.warmup_duration = duration;
+        self
+    }
+
     /// Build the Vegas algorithm.
     pub fn build(self) -> Vegas {
-        Vegas::new(
+        let mut vegas = Vegas::new(
             self.initial_limit,
             self.min_limit,
             self.max_limit,
             self.alpha,
             self.beta,
-        )
+        );
+        vegas.warmup = Warmup::new(self.warmup_calls, self.warmup_duration);
+        vegas
+    }
+}
+
+/// Gradient2 algorithm for concurrency control.
+///
+/// Port of the Gradient2 limiter from Netflix concurrency-limits. It compares
+/// a long-term exponential average of the RTT against the latest sample:
+/// - When the short-term RTT rises above the long-term RTT (scaled by the
+///   tolerance), the gradient drops below 1.0 and the limit shrinks
+/// - Otherwise the limit grows by the queue size each sample
+///
+/// Because the baseline is an average rather than the minimum RTT, Gradient2
+/// copes better than Vegas with services whose latency is multi-modal (for
+/// example, a mix of cache hits and misses). When the long-term RTT drifts
+/// far above the short-term RTT it is decayed so the limiter recovers
+/// quickly after a latency spike.
+pub struct Gradient2 {
+    /// Minimum limit
+    min_limit: usize,
+    /// Maximum limit
+    max_limit: usize,
+    /// Smoothing factor applied to each limit update
+    smoothing: f64,
+    /// Ratio of short-term RTT to long-term RTT tolerated before shrinking
+    rtt_tolerance: f64,
+    /// Headroom added to the limit each sample
+    queue_size: usize,
+    /// Number of samples averaged into the long-term RTT
+    long_window: usize,
+    /// Mutable limiter state
+    state: Mutex<Gradient2State>,
+    /// Startup period during which the limit is left alone
+    warmup: Warmup,
+}
+
+/// Number of samples averaged with equal weight before the long-term RTT
+/// switches to an exponential average.
+const GRADIENT2_WARMUP: usize = 10;
+
+struct Gradient2State {
+    /// Current limit estimate, kept fractional for smoothing
+    estimated_limit: f64,
+    /// Long-term RTT in nanoseconds
+    long_rtt: f64,
+    /// Number of samples folded into the long-term RTT
+    samples: usize,
+}
+
+impl Gradient2 {
+    /// Create a new Gradient2 algorithm.
+    pub fn new(
+        initial_limit: usize,
+        min_limit: usize,
+        max_limit: usize,
+        smoothing: f64,
+        rtt_tolerance: f64,
+        queue_size: usize,
+        long_window: usize,
+    ) -> Self {
+        Self {
+            min_limit,
+            max_limit,
+            smoothing: smoothing.clamp(0.0, 1.0),
+            rtt_tolerance: rtt_tolerance.max(1.0),
+            queue_size,
+            long_window: long_window.max(1),
+            state: Mutex::new(Gradient2State {
+                estimated_limit: initial_limit.clamp(min_limit, max_limit) as f64,
+                long_rtt: 0.0,
+                samples: 0,
+            }),
+            warmup: Warmup::default(),
+        }
+    }
+
+    /// Create a builder for Gradient2.
+    pub fn builder() -> Gradient2Builder {
+        Gradient2Builder::default()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, Gradient2State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Fold a sample into the long-term RTT and return the updated value.
+    fn update_long_rtt(&self, state: &mut Gradient2State, rtt: f64) -> f64 {
+        state.samples += 1;
+        if state.samples <= GRADIENT2_WARMUP {
+            // Plain average until there are enough samples
+            state.long_rtt += (rtt - state.long_rtt) / state.samples as f64;
+        } else {
+            let factor = 2.0 / (self.long_window as f64 + 1.0);
+            state.long_rtt = state.long_rtt * (1.0 - factor) + rtt * factor;
+        }
+        state.long_rtt
+    }
+
+    /// Apply a gradient to the current estimate and store the result.
+    fn apply_gradient(&self, state: &mut Gradient2State, gradient: f64) {
+        let current = state.estimated_limit;
+        let target = current * gradient + self.queue_size as f64;
+        let smoothed = current * (1.0 - self.smoothing) + target * self.smoothing;
+        state.estimated_limit = smoothed.clamp(self.min_limit as f64, self.max_limit as f64);
+    }
+}
+
+impl ConcurrencyAlgorithm for Gradient2 {
+    fn record_success(&self, latency: Duration) {
+        let short_rtt = latency.as_nanos() as f64;
+        if short_rtt <= 0.0 {
+            return;
+        }
+
+        let mut state = self.state();
+        let mut long_rtt = self.update_long_rtt(&mut state, short_rtt);
+
+        // If the long-term RTT is far above the short-term RTT, decay it so
+        // the limiter recovers quickly once latency improves
+        if long_rtt / short_rtt > 2.0 {
+            long_rtt *= 0.95;
+            state.long_rtt = long_rtt;
+        }
+
+        // Keep building the RTT baseline while warming up
+        if !self.warmup.observe() {
+            return;
+        }
+
+        let gradient = (self.rtt_tolerance * long_rtt / short_rtt).clamp(0.5, 1.0);
+        self.apply_gradient(&mut state, gradient);
+    }
+
+    fn record_failure(&self) {
+        if !self.warmup.observe() {
+            return;
+        }
+
+        // Treat errors as the strongest congestion signal
+        let mut state = self.state();
+        self.apply_gradient(&mut state, 0.5);
+    }
+
+    fn record_dropped(&self) {
+        // Dropped requests don't affect the limit
+    }
+
+    fn limit(&self) -> usize {
+        self.state().estimated_limit as usize
+    }
+
+    fn min_limit(&self) -> usize {
+        self.min_limit
+    }
+
+    fn max_limit(&self) -> usize {
+        self.max_limit
+    }
+}
+
+/// Builder for Gradient2 algorithm.
+#[derive(Debug, Clone)]
+pub struct Gradient2Builder {
+    initial_limit: usize,
+    min_limit: usize,
+    max_limit: usize,
+    smoothing: f64,
+    rtt_tolerance: f64,
+    queue_size: usize,
+    long_window: usize,
+    warmup_calls: usize,
+    warmup_duration: Duration,
+}
+
+impl Default for Gradient2Builder {
+    fn default() -> Self {
+        Self {
+            initial_limit: 20,
+            min_limit: 1,
+            max_limit: 200,
+            smoothing: 0.2,
+            rtt_tolerance: 1.5,
+            queue_size: 4,
+            long_window: 600,
+            warmup_calls: 0,
+            warmup_duration: Duration::ZERO,
+        }
+    }
+}
+
+impl Gradient2Builder {
+    /// Set the initial concurrency limit.
+    pub fn initial_limit(mut self, limit: usize) -> Self {
+        self.initial_limit = limit;
+        self
+    }
+
+    /// Set the minimum concurrency limit.
+    pub fn min_limit(mut self, limit: usize) -> Self {
+        self.min_limit = limit;
+        self
+    }
+
+    /// Set the maximum concurrency limit.
+    pub fn max_limit(mut self, limit: usize) -> Self {
+        self.max_limit = limit;
+        self
+    }
+
+    /// Set the smoothing factor (0.0 - 1.0) applied to each limit update.
+    ///
+    /// Lower values react more slowly but oscillate less.
+    pub fn smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Set how far the short-term RTT may exceed the long-term RTT before
+    /// the limit shrinks.
+    ///
+    /// A tolerance of 1.5 allows the latest RTT to be 50% above the long-term
+    /// average. Values below 1.0 are treated as 1.0.
+    pub fn rtt_tolerance(mut self, tolerance: f64) -> Self {
+        self.rtt_tolerance = tolerance;
+        self
+    }
+
+    /// Set the headroom added to the limit on each sample.
+    ///
+    /// This lets the limit grow while latency is stable.
+    pub fn queue_size(mut self, size: usize) -> Self {
+        self.queue_size = size;
+        self
+    }
+
+    /// Set the number of samples averaged into the long-term RTT.
+    pub fn long_window(mut self, samples: usize) -> Self {
+        self.long_window = samples;
+        self
+    }
+
+    /// Leave the limit unchanged for the first `calls` calls.
+    ///
+    /// Startup traffic often sees cold caches and connection setup; without
+    /// a warm-up period that latency can drive the limit down to the minimum
+    /// before the service reaches steady state.
+    ///
+    /// RTT samples still feed the long-term average during warm-up.
+    ///
+    /// Default: 0
+    pub fn warmup_calls(mut self, calls: usize) -> Self {
+        self.warmup_calls = calls;
+        self
+    }
+
+    /// Leave the limit unchanged until `duration` after the first call.
+    ///
+    /// Combined with [`warmup_calls`](Self::warmup_calls), both must be
+    /// satisfied before the limit starts adjusting.
+    ///
+    /// Default: zero
+    pub fn warmup_duration(mut self, duration: Duration) -> Self {
+        self.warmup_duration = duration;
+        self
+    }
+
+    /// Build the Gradient2 algorithm.
+    pub fn build(self) -> Gradient2 {
+        let mut gradient2 = Gradient2::new(
+            self.initial_limit,
+            self.min_limit,
+            self.max_limit,
+            self.smoothing,
+            self.rtt_tolerance,
+            self.queue_size,
+            self.long_window,
+        );
+        gradient2.warmup = Warmup::new(self.warmup_calls, self.warmup_duration);
+        gradient2
     }
 }
 
@@ -395,6 +908,8 @@ pub enum Algorithm {
     Aimd(Aimd),
     /// Vegas algorithm
     Vegas(Vegas),
+    /// Gradient2 algorithm
+    Gradient2(Gradient2),
 }
 
 impl ConcurrencyAlgorithm for Algorithm {
@@ -402,6 +917,7 @@ impl ConcurrencyAlgorithm for Algorithm {
         match self {
             Algorithm::Aimd(a) => a.record_success(latency),
             Algorithm::Vegas(v) => v.record_success(latency),
+            Algorithm::Gradient2(g) => g.record_success(latency),
         }
     }
 
@@ -409,6 +925,7 @@ impl ConcurrencyAlgorithm for Algorithm {
         match self {
             Algorithm::Aimd(a) => a.record_failure(),
             Algorithm::Vegas(v) => v.record_failure(),
+            Algorithm::Gradient2(g) => g.record_failure(),
         }
     }
 
@@ -416,6 +933,7 @@ impl ConcurrencyAlgorithm for Algorithm {
         match self {
             Algorithm::Aimd(a) => a.record_dropped(),
             Algorithm::Vegas(v) => v.record_dropped(),
+            Algorithm::Gradient2(g) => g.record_dropped(),
         }
     }
 
@@ -423,6 +941,7 @@ impl ConcurrencyAlgorithm for Algorithm {
         match self {
             Algorithm::Aimd(a) => a.limit(),
             Algorithm::Vegas(v) => v.limit(),
+            Algorithm::Gradient2(g) => g.limit(),
         }
     }
 
@@ -430,6 +949,7 @@ impl ConcurrencyAlgorithm for Algorithm {
         match self {
             Algorithm::Aimd(a) => a.min_limit(),
             Algorithm::Vegas(v) => v.min_limit(),
+            Algorithm::Gradient2(g) => g.min_limit(),
         }
     }
 
@@ -437,6 +957,7 @@ impl ConcurrencyAlgorithm for Algorithm {
         match self {
             Algorithm::Aimd(a) => a.max_limit(),
             Algorithm::Vegas(v) => v.max_limit(),
+            Algorithm::Gradient2(g) => g.max_limit(),
         }
     }
 }
@@ -498,6 +1019,122 @@ mod tests {
         assert_eq!(aimd.limit(), 5);
     }
 
+    #[test]
+    fn test_aimd_window_waits_for_full_window() {
+        let aimd = Aimd::builder()
+            .initial_limit(10)
+            .latency_threshold(Duration::from_millis(100))
+            .latency_window(4)
+            .build();
+
+        for _ in 0..3 {
+            aimd.record_success(Duration::from_millis(10));
+        }
+        assert_eq!(aimd.limit(), 10);
+
+        aimd.record_success(Duration::from_millis(10));
+        assert_eq!(aimd.limit(), 11);
+    }
+
+    #[test]
+    fn test_aimd_window_ignores_outliers_below_percentile() {
+        let aimd = Aimd::builder()
+            .initial_limit(10)
+            .latency_threshold(Duration::from_millis(100))
+            .latency_window(20)
+            .latency_percentile(0.95)
+            .build();
+
+        // One slow call in twenty is at the p100, not the p95
+        aimd.record_success(Duration::from_millis(500));
+        for _ in 0..19 {
+            aimd.record_success(Duration::from_millis(10));
+        }
+        assert_eq!(aimd.limit(), 11);
+    }
+
+    #[test]
+    fn test_aimd_window_decreases_on_slow_percentile() {
+        let aimd = Aimd::builder()
+            .initial_limit(10)
+            .decrease_factor(0.5)
+            .latency_threshold(Duration::from_millis(100))
+            .latency_window(10)
+            .build();
+
+        for _ in 0..8 {
+            aimd.record_success(Duration::from_millis(10));
+        }
+        aimd.record_success(Duration::from_millis(200));
+        aimd.record_success(Duration::from_millis(200));
+        assert_eq!(aimd.limit(), 5);
+    }
+
+    #[test]
+    fn test_aimd_time_window() {
+        let aimd = Aimd::builder()
+            .initial_limit(10)
+            .latency_threshold(Duration::from_millis(100))
+            .latency_window_duration(Duration::from_millis(20))
+            .build();
+
+        aimd.record_success(Duration::from_millis(10));
+        assert_eq!(aimd.limit(), 10);
+
+        std::thread::sleep(Duration::from_millis(25));
+        aimd.record_success(Duration::from_millis(10));
+        assert_eq!(aimd.limit(), 11);
+    }
+
+    #[test]
+    fn test_aimd_warmup_calls() {
+        let aimd = Aimd::builder()
+            .initial_limit(10)
+            .decrease_factor(0.5)
+            .latency_threshold(Duration::from_millis(100))
+            .warmup_calls(3)
+            .build();
+
+        // Slow startup calls and errors leave the limit alone
+        aimd.record_success(Duration::from_millis(500));
+        aimd.record_failure();
+        aimd.record_success(Duration::from_millis(500));
+        assert_eq!(aimd.limit(), 10);
+
+        aimd.record_success(Duration::from_millis(500));
+        assert_eq!(aimd.limit(), 5);
+    }
+
+    #[test]
+    fn test_warmup_duration() {
+        let gradient2 = Gradient2::builder()
+            .initial_limit(20)
+            .warmup_duration(Duration::from_millis(20))
+            .build();
+
+        gradient2.record_failure();
+        assert_eq!(gradient2.limit(), 20);
+
+        std::thread::sleep(Duration::from_millis(25));
+        gradient2.record_failure();
+        assert!(gradient2.limit() < 20);
+    }
+
+    #[test]
+    fn test_vegas_warmup_keeps_limit_and_tracks_rtt() {
+        let vegas = Vegas::builder().initial_limit(10).warmup_calls(2).build();
+
+        vegas.record_success(Duration::from_millis(40));
+        vegas.record_failure();
+        assert_eq!(vegas.limit(), 10);
+
+        let min_rtt = vegas.min_rtt_nanos.load(Ordering::Relaxed);
+        assert_eq!(min_rtt, Duration::from_millis(40).as_nanos() as u64);
+
+        vegas.record_failure();
+        assert_eq!(vegas.limit(), 5);
+    }
+
     #[test]
     fn test_vegas_builder() {
         let vegas = Vegas::builder()
@@ -541,5 +1178,86 @@ mod tests {
 
         let vegas = Algorithm::Vegas(Vegas::builder().initial_limit(20).build());
         assert_eq!(vegas.limit(), 20);
+
+        let gradient2 = Algorithm::Gradient2(Gradient2::builder().initial_limit(30).build());
+        assert_eq!(gradient2.limit(), 30);
+    }
+
+    #[test]
+    fn test_gradient2_builder() {
+        let gradient2 = Gradient2::builder()
+            .initial_limit(20)
+            .min_limit(5)
+            .max_limit(200)
+            .smoothing(0.5)
+            .rtt_tolerance(2.0)
+            .queue_size(2)
+            .long_window(100)
+            .build();
+
+        assert_eq!(gradient2.limit(), 20);
+        assert_eq!(gradient2.min_limit(), 5);
+        assert_eq!(gradient2.max_limit(), 200);
+    }
+
+    #[test]
+    fn test_gradient2_grows_with_stable_latency() {
+        let gradient2 = Gradient2::builder().initial_limit(20).build();
+
+        for _ in 0..20 {
+            gradient2.record_success(Duration::from_millis(10));
+        }
+
+        assert!(gradient2.limit() > 20);
+    }
+
+    #[test]
+    fn test_gradient2_shrinks_on_latency_increase() {
+        let gradient2 = Gradient2::builder().initial_limit(50).queue_size(0).build();
+
+        // Establish a long-term baseline
+        for _ in 0..50 {
+            gradient2.record_success(Duration::from_millis(10));
+        }
+        let baseline = gradient2.limit();
+
+        // Latency jumps well beyond the tolerance
+        for _ in 0..10 {
+            gradient2.record_success(Duration::from_millis(100));
+        }
+
+        assert!(gradient2.limit() < baseline);
+    }
+
+    #[test]
+    fn test_gradient2_failure_decreases() {
+        let gradient2 = Gradient2::builder()
+            .initial_limit(100)
+            .smoothing(1.0)
+            .queue_size(0)
+            .build();
+
+        gradient2.record_failure();
+        assert_eq!(gradient2.limit(), 50);
+    }
+
+    #[test]
+    fn test_gradient2_respects_bounds() {
+        let gradient2 = Gradient2::builder()
+            .initial_limit(10)
+            .min_limit(8)
+            .max_limit(12)
+            .smoothing(1.0)
+            .build();
+
+        for _ in 0..20 {
+            gradient2.record_success(Duration::from_millis(10));
+        }
+        assert_eq!(gradient2.limit(), 12);
+
+        for _ in 0..5 {
+            gradient2.record_failure();
+        }
+        assert_eq!(gradient2.limit(), 8);
     }
 }
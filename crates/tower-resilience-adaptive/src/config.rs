@@ -0,0 +1,62 @@
+//! Configuration for the adaptive limiter.
+
+use crate::control::AdaptiveControl;
+use crate::events::AdaptiveEvent;
+use std::time::Duration;
+use tower_resilience_core::EventListeners;
+
+/// What happens to a request when the concurrency limit is reached.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd, LimitPolicy};
+/// use std::time::Duration;
+///
+/// let layer = AdaptiveLimiterLayer::new(Aimd::builder().build())
+///     .on_limit(LimitPolicy::Queue {
+///         max_wait: Duration::from_millis(50),
+///         max_queue: 100,
+///     });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitPolicy {
+    /// Apply backpressure: `poll_ready` returns `Pending` until a slot frees up.
+    #[default]
+    Backpressure,
+    /// Fail the request immediately with
+    /// [`AdaptiveError::LimitExceeded`](crate::AdaptiveError::LimitExceeded).
+    Reject,
+    /// Wait for a slot to free up.
+    ///
+    /// Requests are rejected with
+    /// [`AdaptiveError::LimitExceeded`](crate::AdaptiveError::LimitExceeded)
+    /// when `max_queue` requests are already waiting, or when no slot frees
+    /// up within `max_wait`.
+    Queue {
+        /// How long a request may wait for a slot.
+        max_wait: Duration,
+        /// How many requests may wait at once.
+        max_queue: usize,
+    },
+}
+
+/// Configuration shared by adaptive limiter services.
+#[derive(Clone)]
+pub(crate) struct AdaptiveConfig {
+    pub(crate) name: String,
+    pub(crate) limit_policy: LimitPolicy,
+    pub(crate) event_listeners: EventListeners<AdaptiveEvent>,
+    pub(crate) control: AdaptiveControl,
+}
+
+impl Default for AdaptiveConfig {
+    fn default() -> Self {
+        Self {
+            name: String::from("<unnamed>"),
+            limit_policy: LimitPolicy::default(),
+            event_listeners: EventListeners::new(),
+            control: AdaptiveControl::default(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Runtime control of the adaptive limiter.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Sentinel stored in `pinned` when no limit override is set.
+const NOT_PINNED: usize = 0;
+
+#[derive(Debug, Default)]
+struct ControlState {
+    /// The limit most recently applied by the limiter
+    current: AtomicUsize,
+    /// Operator-set limit, or [`NOT_PINNED`]
+    pinned: AtomicUsize,
+    /// Whether samples are withheld from the algorithm
+    frozen: AtomicBool,
+}
+
+/// A handle for reading and overriding the adaptive limit at runtime.
+///
+/// Obtained from [`crate::AdaptiveLimiterLayer::control()`]. The handle is
+/// cheap to clone and safe to share across threads (`Clone + Send + Sync`),
+/// so autoscalers can read the limit and operators can pin it during an
+/// incident without access to the algorithm itself.
+///
+/// While frozen, call results are not fed to the algorithm, so its limit
+/// stays where it is. Pinning a limit with [`set_limit`](Self::set_limit)
+/// also freezes the algorithm. After [`unfreeze`](Self::unfreeze), adaptation
+/// resumes from the algorithm's own last limit.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd};
+///
+/// let layer = AdaptiveLimiterLayer::new(Aimd::builder().initial_limit(10).build());
+/// let control = layer.control();
+///
+/// // Apply the layer to a service...
+///
+/// // During an incident, pin the limit and later hand control back:
+/// control.set_limit(5);
+/// assert_eq!(control.current_limit(), 5);
+/// control.unfreeze();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AdaptiveControl {
+    state: Arc<ControlState>,
+}
+
+impl AdaptiveControl {
+    pub(crate) fn new(initial_limit: usize) -> Self {
+        let control = Self::default();
+        control.update(initial_limit);
+        control
+    }
+
+    /// Pin the limit to `limit` and freeze adaptation.
+    ///
+    /// The limit stays pinned until [`unfreeze`](Self::unfreeze) is called.
+    /// Limits below 1 are treated as 1.
+    pub fn set_limit(&self, limit: usize) {
+        let limit = limit.max(1);
+        self.state.pinned.store(limit, Ordering::Release);
+        self.state.frozen.store(true, Ordering::Release);
+        self.update(limit);
+    }
+
+    /// Stop adjusting the limit, keeping it at its current value.
+    pub fn freeze(&self) {
+        self.state.frozen.store(true, Ordering::Release);
+    }
+
+    /// Remove any pinned limit and resume adaptation.
+    pub fn unfreeze(&self) {
+        self.state.pinned.store(NOT_PINNED, Ordering::Release);
+        self.state.frozen.store(false, Ordering::Release);
+    }
+
+    /// Returns whether adaptation is frozen.
+    pub fn is_frozen(&self) -> bool {
+        self.state.frozen.load(Ordering::Acquire)
+    }
+
+    /// Returns the concurrency limit currently in effect.
+    pub fn current_limit(&self) -> usize {
+        self.state.current.load(Ordering::Acquire)
+    }
+
+    /// Returns the pinned limit, if one is set.
+    pub(crate) fn pinned(&self) -> Option<usize> {
+        match self.state.pinned.load(Ordering::Acquire) {
+            NOT_PINNED => None,
+            limit => Some(limit),
+        }
+    }
+
+    /// Record the limit applied by the limiter.
+    pub(crate) fn update(&self, limit: usize) {
+        self.state.current.store(limit, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_state() {
+        let control = AdaptiveControl::new(10);
+        let clone = control.clone();
+
+        clone.set_limit(3);
+
+        assert_eq!(control.current_limit(), 3);
+        assert_eq!(control.pinned(), Some(3));
+        assert!(control.is_frozen());
+    }
+
+    #[test]
+    fn unfreeze_clears_pin() {
+        let control = AdaptiveControl::new(10);
+        control.set_limit(0);
+        assert_eq!(control.pinned(), Some(1));
+
+        control.unfreeze();
+        assert_eq!(control.pinned(), None);
+        assert!(!control.is_frozen());
+
+        control.freeze();
+        assert!(control.is_frozen());
+        assert_eq!(control.pinned(), None);
+    }
+}
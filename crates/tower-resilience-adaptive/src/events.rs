@@ -0,0 +1,156 @@
+//! Event types for the adaptive limiter.
+
+use std::time::{Duration, Instant};
+use tower_resilience_core::ResilienceEvent;
+
+/// Events emitted by the adaptive limiter.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum AdaptiveEvent {
+    /// The algorithm raised the concurrency limit.
+    LimitIncreased {
+        /// The name of the limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The previous limit.
+        from: usize,
+        /// The new limit.
+        to: usize,
+    },
+    /// The algorithm lowered the concurrency limit.
+    LimitDecreased {
+        /// The name of the limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The previous limit.
+        from: usize,
+        /// The new limit.
+        to: usize,
+    },
+    /// A request acquired a slot and was passed to the inner service.
+    CallPermitted {
+        /// The name of the limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A request was rejected because the limit was reached.
+    CallRejected {
+        /// The name of the limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A queued request gave up waiting for a slot.
+    QueueTimeout {
+        /// The name of the limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// How long the request waited.
+        waited: Duration,
+    },
+}
+
+impl ResilienceEvent for AdaptiveEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            AdaptiveEvent::LimitIncreased { .. } => "adaptive_limit_increased",
+            AdaptiveEvent::LimitDecreased { .. } => "adaptive_limit_decreased",
+            AdaptiveEvent::CallPermitted { .. } => "adaptive_call_permitted",
+            AdaptiveEvent::CallRejected { .. } => "adaptive_call_rejected",
+            AdaptiveEvent::QueueTimeout { .. } => "adaptive_queue_timeout",
+        }
+    }
+
+    fn timestamp(&self) -> Instant {
+        match self {
+            AdaptiveEvent::LimitIncreased { timestamp, .. }
+            | AdaptiveEvent::LimitDecreased { timestamp, .. }
+            | AdaptiveEvent::CallPermitted { timestamp, .. }
+            | AdaptiveEvent::CallRejected { timestamp, .. }
+            | AdaptiveEvent::QueueTimeout { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn pattern_name(&self) -> &str {
+        match self {
+            AdaptiveEvent::LimitIncreased { pattern_name, .. }
+            | AdaptiveEvent::LimitDecreased { pattern_name, .. }
+            | AdaptiveEvent::CallPermitted { pattern_name, .. }
+            | AdaptiveEvent::CallRejected { pattern_name, .. }
+            | AdaptiveEvent::QueueTimeout { pattern_name, .. } => pattern_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_types() {
+        let now = Instant::now();
+
+        let increased = AdaptiveEvent::LimitIncreased {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            from: 10,
+            to: 11,
+        };
+        assert_eq!(increased.event_type(), "adaptive_limit_increased");
+        assert_eq!(increased.pattern_name(), "test");
+
+        let decreased = AdaptiveEvent::LimitDecreased {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            from: 11,
+            to: 5,
+        };
+        assert_eq!(decreased.event_type(), "adaptive_limit_decreased");
+
+        let permitted = AdaptiveEvent::CallPermitted {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+        };
+        assert_eq!(permitted.event_type(), "adaptive_call_permitted");
+
+        let rejected = AdaptiveEvent::CallRejected {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+        };
+        assert_eq!(rejected.event_type(), "adaptive_call_rejected");
+
+        let timeout = AdaptiveEvent::QueueTimeout {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            waited: Duration::from_millis(10),
+        };
+        assert_eq!(timeout.event_type(), "adaptive_queue_timeout");
+        assert_eq!(timeout.pattern_name(), "test");
+        assert_eq!(timeout.timestamp(), now);
+    }
+}
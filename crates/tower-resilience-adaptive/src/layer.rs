@@ -1,8 +1,13 @@
 //! Layer implementation for adaptive concurrency limiting.
 
-use crate::{AdaptiveService, Algorithm, ConcurrencyAlgorithm};
+use crate::config::AdaptiveConfig;
+use crate::control::AdaptiveControl;
+use crate::{AdaptiveEvent, AdaptiveService, Algorithm, ConcurrencyAlgorithm, LimitPolicy};
 use std::sync::Arc;
+use std::time::Duration;
 use tower_layer::Layer;
+use tower_resilience_core::classifier::{DefaultClassifier, FnClassifier};
+use tower_resilience_core::FnListener;
 
 /// A Tower layer that applies adaptive concurrency limiting.
 ///
@@ -22,8 +27,10 @@ use tower_layer::Layer;
 ///         .build()
 /// );
 /// ```
-pub struct AdaptiveLimiterLayer<A> {
+pub struct AdaptiveLimiterLayer<A, C = DefaultClassifier> {
     algorithm: Arc<A>,
+    config: AdaptiveConfig,
+    classifier: Arc<C>,
 }
 
 impl<A> AdaptiveLimiterLayer<A>
@@ -32,8 +39,14 @@ where
 {
     /// Create a new adaptive limiter layer with the given algorithm.
     pub fn new(algorithm: A) -> Self {
+        let config = AdaptiveConfig {
+            control: AdaptiveControl::new(algorithm.limit()),
+            ..AdaptiveConfig::default()
+        };
         Self {
             algorithm: Arc::new(algorithm),
+            config,
+            classifier: Arc::new(DefaultClassifier),
         }
     }
 
@@ -43,22 +56,175 @@ where
     }
 }
 
-impl<A> Clone for AdaptiveLimiterLayer<A> {
+impl<A, C> AdaptiveLimiterLayer<A, C>
+where
+    A: ConcurrencyAlgorithm,
+{
+    /// Set the name of this limiter for observability.
+    ///
+    /// Default: `"<unnamed>"`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.config.name = name.into();
+        self
+    }
+
+    /// Set what happens to requests when the concurrency limit is reached.
+    ///
+    /// Default: [`LimitPolicy::Backpressure`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd, LimitPolicy};
+    ///
+    /// let layer = AdaptiveLimiterLayer::new(Aimd::builder().build())
+    ///     .on_limit(LimitPolicy::Reject);
+    /// ```
+    pub fn on_limit(mut self, policy: LimitPolicy) -> Self {
+        self.config.limit_policy = policy;
+        self
+    }
+
+    /// Register a callback for when the concurrency limit changes.
+    ///
+    /// The callback receives the previous and new limits.
+    pub fn on_limit_changed<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static,
+    {
+        self.config
+            .event_listeners
+            .add(FnListener::new(move |event| match event {
+                AdaptiveEvent::LimitIncreased { from, to, .. }
+                | AdaptiveEvent::LimitDecreased { from, to, .. } => f(*from, *to),
+                _ => {}
+            }));
+        self
+    }
+
+    /// Register a callback for when a request acquires a slot.
+    pub fn on_call_permitted<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.config
+            .event_listeners
+            .add(FnListener::new(move |event| {
+                if matches!(event, AdaptiveEvent::CallPermitted { .. }) {
+                    f();
+                }
+            }));
+        self
+    }
+
+    /// Register a callback for when a request is rejected at the limit.
+    ///
+    /// Not called under [`LimitPolicy::Backpressure`], which never rejects.
+    pub fn on_call_rejected<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.config
+            .event_listeners
+            .add(FnListener::new(move |event| {
+                if matches!(event, AdaptiveEvent::CallRejected { .. }) {
+                    f();
+                }
+            }));
+        self
+    }
+
+    /// Register a callback for when a queued request gives up waiting.
+    ///
+    /// The callback receives how long the request waited. Only called under
+    /// [`LimitPolicy::Queue`].
+    pub fn on_queue_timeout<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.config
+            .event_listeners
+            .add(FnListener::new(move |event| {
+                if let AdaptiveEvent::QueueTimeout { waited, .. } = event {
+                    f(*waited);
+                }
+            }));
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.config
+            .event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
+    /// Get a handle for reading and overriding the limit at runtime.
+    ///
+    /// The handle is shared by every service this layer creates.
+    pub fn control(&self) -> AdaptiveControl {
+        self.config.control.clone()
+    }
+
+    /// Set which results count as congestion.
+    ///
+    /// Only results the classifier returns `true` for shrink the limit.
+    /// Everything else, including errors that don't indicate overload (such
+    /// as a 404), is treated as an ordinary latency sample. Ok results can be
+    /// classified as congestion too, for services that report overload in
+    /// the response rather than as an error.
+    ///
+    /// Default: all errors are congestion
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd};
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let layer = AdaptiveLimiterLayer::new(Aimd::builder().build())
+    ///     .congestion_classifier(|result: &Result<String, Error>| {
+    ///         matches!(
+    ///             result,
+    ///             Err(e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::ConnectionReset)
+    ///         )
+    ///     });
+    /// ```
+    pub fn congestion_classifier<F>(self, f: F) -> AdaptiveLimiterLayer<A, FnClassifier<F>> {
+        AdaptiveLimiterLayer {
+            algorithm: self.algorithm,
+            config: self.config,
+            classifier: Arc::new(FnClassifier::new(f)),
+        }
+    }
+}
+
+impl<A, C> Clone for AdaptiveLimiterLayer<A, C> {
     fn clone(&self) -> Self {
         Self {
             algorithm: Arc::clone(&self.algorithm),
+            config: self.config.clone(),
+            classifier: Arc::clone(&self.classifier),
         }
     }
 }
 
-impl<S, A> Layer<S> for AdaptiveLimiterLayer<A>
+impl<S, A, C> Layer<S> for AdaptiveLimiterLayer<A, C>
 where
     A: ConcurrencyAlgorithm + 'static,
 {
-    type Service = AdaptiveService<S, A>;
+    type Service = AdaptiveService<S, A, C>;
 
     fn layer(&self, service: S) -> Self::Service {
-        AdaptiveService::new(service, Arc::clone(&self.algorithm))
+        AdaptiveService::with_config(
+            service,
+            Arc::clone(&self.algorithm),
+            Arc::new(self.config.clone()),
+            Arc::clone(&self.classifier),
+        )
     }
 }
 
@@ -81,6 +247,11 @@ impl AdaptiveLimiterLayerBuilder {
     pub fn vegas(self) -> crate::VegasBuilder {
         crate::Vegas::builder()
     }
+
+    /// Use the Gradient2 algorithm.
+    pub fn gradient2(self) -> crate::Gradient2Builder {
+        crate::Gradient2::builder()
+    }
 }
 
 /// Extension trait for building layers from algorithm builders.
@@ -108,6 +279,14 @@ impl IntoLayer for crate::Vegas {
     }
 }
 
+impl IntoLayer for crate::Gradient2 {
+    type Algorithm = crate::Gradient2;
+
+    fn into_layer(self) -> AdaptiveLimiterLayer<Self::Algorithm> {
+        AdaptiveLimiterLayer::new(self)
+    }
+}
+
 impl IntoLayer for Algorithm {
     type Algorithm = Algorithm;
 
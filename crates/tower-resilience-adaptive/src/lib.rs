@@ -1,7 +1,8 @@
 //! Adaptive concurrency limiter for Tower services.
 //!
 //! This crate provides a Tower layer that dynamically adjusts concurrency limits
-//! based on observed latency and error rates, using algorithms like AIMD or Vegas.
+//! based on observed latency and error rates, using algorithms like AIMD, Vegas or
+//! Gradient2.
 //!
 //! Unlike static concurrency limits which require manual tuning, adaptive limiters
 //! automatically find the optimal concurrency for your downstream services.
@@ -15,6 +16,19 @@
 //! - On failure or high latency: decrease limit by a factor (e.g., halve it)
 //!
 //! This creates a "sawtooth" pattern as it continuously probes for capacity.
+//! To reduce noise-driven oscillation, AIMD can instead compare a latency
+//! percentile over a window of calls against the threshold:
+//!
+//! ```rust
+//! use tower_resilience_adaptive::Aimd;
+//! use std::time::Duration;
+//!
+//! let aimd = Aimd::builder()
+//!     .latency_threshold(Duration::from_millis(100))
+//!     .latency_window(50)
+//!     .latency_percentile(0.95)
+//!     .build();
+//! ```
 //!
 //! ## Vegas
 //!
@@ -25,6 +39,17 @@
 //!
 //! Vegas is more stable than AIMD and avoids the sawtooth pattern.
 //!
+//! ## Gradient2
+//!
+//! The Netflix concurrency-limits algorithm based on the ratio of long-term to
+//! short-term RTT:
+//! - Tracks an exponential average of RTT as the baseline
+//! - Shrinks the limit when recent RTT exceeds the baseline by more than a tolerance
+//! - Grows the limit by a small queue allowance while latency is stable
+//!
+//! Because the baseline is an average rather than the minimum RTT, Gradient2 handles
+//! services with multi-modal latency better than Vegas.
+//!
 //! # Example
 //!
 //! ```rust
@@ -71,6 +96,119 @@
 //! );
 //! ```
 //!
+//! # Using Gradient2 Algorithm
+//!
+//! ```rust,no_run
+//! use tower_resilience_adaptive::{AdaptiveLimiterLayer, Gradient2};
+//!
+//! let layer = AdaptiveLimiterLayer::new(
+//!     Gradient2::builder()
+//!         .initial_limit(20)
+//!         .max_limit(200)
+//!         .rtt_tolerance(1.5)  // Shrink when RTT is 50% above the long-term average
+//!         .build()
+//! );
+//! ```
+//!
+//! # Warm-up
+//!
+//! Every algorithm can hold its initial limit until it has seen enough
+//! traffic, so slow startup calls against cold caches don't collapse the
+//! limit to the minimum. Vegas and Gradient2 still record RTTs during
+//! warm-up to establish their baseline:
+//!
+//! ```rust
+//! use tower_resilience_adaptive::Vegas;
+//! use std::time::Duration;
+//!
+//! let vegas = Vegas::builder()
+//!     .warmup_calls(100)
+//!     .warmup_duration(Duration::from_secs(10))
+//!     .build();
+//! ```
+//!
+//! # Behavior at the Limit
+//!
+//! By default, the limiter applies backpressure: `poll_ready` stays pending
+//! until a slot frees up. Use [`LimitPolicy`] to shed load instead, either
+//! immediately or after a bounded wait:
+//!
+//! ```rust
+//! use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd, LimitPolicy};
+//! use std::time::Duration;
+//!
+//! let layer = AdaptiveLimiterLayer::new(Aimd::builder().build())
+//!     .name("backend")
+//!     .on_limit(LimitPolicy::Queue {
+//!         max_wait: Duration::from_millis(50),
+//!         max_queue: 100,
+//!     })
+//!     .on_queue_timeout(|waited| println!("gave up after {:?}", waited));
+//! ```
+//!
+//! Rejected requests fail with [`AdaptiveError::LimitExceeded`].
+//!
+//! # Load-Aware Balancing
+//!
+//! [`AdaptiveService`] implements [`tower::load::Load`], reporting in-flight
+//! and queued requests as a fraction of its current limit. Wrap each replica
+//! in its own limiter and `tower::balance::p2c` will route requests away from
+//! replicas that are saturated relative to what their backend can take.
+//!
+//! # Runtime Control
+//!
+//! An [`AdaptiveControl`] handle reads the current limit and lets operators
+//! pin it during incidents:
+//!
+//! ```rust
+//! use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd};
+//!
+//! let layer = AdaptiveLimiterLayer::new(Aimd::builder().initial_limit(20).build());
+//! let control = layer.control();
+//!
+//! control.set_limit(5); // pin the limit and stop adapting
+//! control.unfreeze(); // resume adapting
+//! println!("limit is {}", control.current_limit());
+//! ```
+//!
+//! # Congestion Signals
+//!
+//! By default every error shrinks the limit. Errors that don't indicate
+//! overload, such as a 404, can be excluded with a classifier:
+//!
+//! ```rust
+//! use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd};
+//!
+//! # #[derive(Debug)]
+//! # enum ApiError { NotFound, Unavailable, Timeout }
+//! let layer = AdaptiveLimiterLayer::new(Aimd::builder().build())
+//!     .congestion_classifier(|result: &Result<String, ApiError>| {
+//!         matches!(result, Err(ApiError::Unavailable | ApiError::Timeout))
+//!     });
+//! ```
+//!
+//! # Events
+//!
+//! Callbacks can be registered to watch the limit move and correlate it with
+//! admitted and rejected traffic:
+//!
+//! ```rust
+//! use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd};
+//!
+//! let layer = AdaptiveLimiterLayer::new(Aimd::builder().build())
+//!     .name("backend")
+//!     .on_limit_changed(|from, to| println!("limit {} -> {}", from, to))
+//!     .on_call_permitted(|| println!("call permitted"))
+//!     .on_call_rejected(|| println!("call rejected"));
+//! ```
+//!
+//! # Metrics
+//!
+//! With the `metrics` feature enabled:
+//!
+//! - `resilience_adaptive_limit{name}` - Current concurrency limit
+//! - `resilience_adaptive_calls_total{name, result}` - Calls (permitted/rejected)
+//!
 //! # Combining with Other Patterns
 //!
 //! The adaptive limiter works well with other resilience patterns:
@@ -99,12 +237,22 @@
 //! - [Vector Adaptive Request Concurrency](https://vector.dev/blog/adaptive-request-concurrency/)
 
 mod algorithm;
+mod config;
+mod control;
+mod events;
 mod layer;
 mod service;
 
-pub use algorithm::{Aimd, AimdBuilder, Algorithm, ConcurrencyAlgorithm, Vegas, VegasBuilder};
+pub use algorithm::{
+    Aimd, AimdBuilder, Algorithm, ConcurrencyAlgorithm, Gradient2, Gradient2Builder, Vegas,
+    VegasBuilder,
+};
+pub use config::LimitPolicy;
+pub use control::AdaptiveControl;
+pub use events::AdaptiveEvent;
 pub use layer::{AdaptiveLimiterLayer, AdaptiveLimiterLayerBuilder, IntoLayer};
 pub use service::{AdaptiveError, AdaptiveFuture, AdaptiveService};
+pub use tower_resilience_core::classifier::{DefaultClassifier, FailureClassifier, FnClassifier};
 
 #[cfg(test)]
 mod tests {
@@ -1,26 +1,42 @@
 //! Service implementation for adaptive concurrency limiting.
 
+use crate::config::{AdaptiveConfig, LimitPolicy};
+use crate::control::AdaptiveControl;
+use crate::events::AdaptiveEvent;
 use crate::ConcurrencyAlgorithm;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Instant;
-use tokio::sync::Semaphore;
+use std::time::{Duration, Instant};
+use tokio::sync::{Notify, Semaphore};
+use tower::load::Load;
+use tower_resilience_core::classifier::{DefaultClassifier, FailureClassifier};
 use tower_service::Service;
 
+#[cfg(feature = "metrics")]
+use metrics::{counter, describe_counter, describe_gauge, gauge};
+use tower_resilience_core::PatternError;
+
 /// A service that applies adaptive concurrency limiting.
 ///
 /// This service dynamically adjusts the number of concurrent requests based
 /// on observed latency and error rates.
-pub struct AdaptiveService<S, A> {
+pub struct AdaptiveService<S, A, C = DefaultClassifier> {
     inner: S,
     algorithm: Arc<A>,
+    config: Arc<AdaptiveConfig>,
+    /// Decides which results are congestion signals
+    classifier: Arc<C>,
     /// Current limit (tracked separately for dynamic adjustment)
     current_limit: Arc<AtomicUsize>,
     /// In-flight requests counter
     in_flight: Arc<AtomicUsize>,
+    /// Requests waiting for a slot under [`LimitPolicy::Queue`]
+    queued: Arc<AtomicUsize>,
+    /// Wakes queued requests when a slot frees up
+    slot_freed: Arc<Notify>,
     /// Semaphore for limiting concurrency
     semaphore: Arc<Semaphore>,
 }
@@ -31,19 +47,66 @@ where
 {
     /// Create a new adaptive service.
     pub fn new(service: S, algorithm: Arc<A>) -> Self {
-        let initial_limit = algorithm.limit();
+        Self::with_config(
+            service,
+            algorithm,
+            Arc::new(AdaptiveConfig::default()),
+            Arc::new(DefaultClassifier),
+        )
+    }
+}
+
+impl<S, A, C> AdaptiveService<S, A, C>
+where
+    A: ConcurrencyAlgorithm,
+{
+    pub(crate) fn with_config(
+        service: S,
+        algorithm: Arc<A>,
+        config: Arc<AdaptiveConfig>,
+        classifier: Arc<C>,
+    ) -> Self {
+        let initial_limit = effective_limit(&*algorithm, &config);
+        config.control.update(initial_limit);
+
+        #[cfg(feature = "metrics")]
+        {
+            describe_gauge!(
+                "resilience_adaptive_limit",
+                "Current concurrency limit of the adaptive limiter"
+            );
+            describe_counter!(
+                "resilience_adaptive_calls_total",
+                "Total number of calls admitted or rejected by the adaptive limiter"
+            );
+            gauge!("resilience_adaptive_limit", "name" => config.name.clone())
+                .set(initial_limit as f64);
+        }
+
         Self {
             inner: service,
             algorithm,
+            config,
+            classifier,
             current_limit: Arc::new(AtomicUsize::new(initial_limit)),
             in_flight: Arc::new(AtomicUsize::new(0)),
+            queued: Arc::new(AtomicUsize::new(0)),
+            slot_freed: Arc::new(Notify::new()),
             semaphore: Arc::new(Semaphore::new(initial_limit)),
         }
     }
 
     /// Get the current concurrency limit.
+    ///
+    /// This is the pinned limit while one is set through
+    /// [`AdaptiveControl::set_limit`], and the algorithm's limit otherwise.
     pub fn limit(&self) -> usize {
-        self.algorithm.limit()
+        effective_limit(&*self.algorithm, &self.config)
+    }
+
+    /// Get a handle for reading and overriding the limit at runtime.
+    pub fn control(&self) -> AdaptiveControl {
+        self.config.control.clone()
     }
 
     /// Get the number of in-flight requests.
@@ -51,13 +114,18 @@ where
         self.in_flight.load(Ordering::Relaxed)
     }
 
+    /// Get the number of requests waiting for a slot.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
     /// Get a reference to the algorithm.
     pub fn algorithm(&self) -> &A {
         &self.algorithm
     }
 }
 
-impl<S, A> Clone for AdaptiveService<S, A>
+impl<S, A, C> Clone for AdaptiveService<S, A, C>
 where
     S: Clone,
 {
@@ -65,34 +133,210 @@ where
         Self {
             inner: self.inner.clone(),
             algorithm: Arc::clone(&self.algorithm),
+            config: Arc::clone(&self.config),
+            classifier: Arc::clone(&self.classifier),
             current_limit: Arc::clone(&self.current_limit),
             in_flight: Arc::clone(&self.in_flight),
+            queued: Arc::clone(&self.queued),
+            slot_freed: Arc::clone(&self.slot_freed),
             semaphore: Arc::clone(&self.semaphore),
         }
     }
 }
 
-impl<S, A, Req> Service<Req> for AdaptiveService<S, A>
+/// Reports in-flight and queued requests as a fraction of the current limit.
+///
+/// `0.0` is idle and `1.0` is saturated; the value exceeds `1.0` while
+/// requests are queued or after the limit shrinks below the number of calls
+/// already in flight. Since the load is relative to each replica's own
+/// learned limit, `tower::balance::p2c` steers traffic away from replicas
+/// whose backends are congested, even when replicas have different limits.
+impl<S, A, C> Load for AdaptiveService<S, A, C>
+where
+    A: ConcurrencyAlgorithm,
+{
+    type Metric = f64;
+
+    fn load(&self) -> Self::Metric {
+        let limit = self.limit();
+        let demand = self.in_flight() + self.queued();
+        if limit == 0 {
+            return if demand == 0 { 0.0 } else { f64::INFINITY };
+        }
+        demand as f64 / limit as f64
+    }
+}
+
+/// Claim an in-flight slot if the count is below `limit`.
+fn try_acquire(in_flight: &AtomicUsize, limit: usize) -> bool {
+    let mut current = in_flight.load(Ordering::Relaxed);
+    while current < limit {
+        match in_flight.compare_exchange_weak(
+            current,
+            current + 1,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => return true,
+            Err(actual) => current = actual,
+        }
+    }
+    false
+}
+
+/// The limit in effect: the pinned limit if set, otherwise the algorithm's.
+fn effective_limit<A: ConcurrencyAlgorithm>(algorithm: &A, config: &AdaptiveConfig) -> usize {
+    config.control.pinned().unwrap_or_else(|| algorithm.limit())
+}
+
+/// Apply the current limit, reporting any change.
+fn sync_limit<A: ConcurrencyAlgorithm>(
+    algorithm: &A,
+    current_limit: &AtomicUsize,
+    semaphore: &Semaphore,
+    config: &AdaptiveConfig,
+) {
+    let limit = effective_limit(algorithm, config);
+    let previous = current_limit.swap(limit, Ordering::Relaxed);
+    if limit == previous {
+        return;
+    }
+    config.control.update(limit);
+
+    if limit > previous {
+        semaphore.add_permits(limit - previous);
+    }
+
+    #[cfg(feature = "metrics")]
+    gauge!("resilience_adaptive_limit", "name" => config.name.clone()).set(limit as f64);
+
+    let pattern_name = config.name.clone();
+    let timestamp = Instant::now();
+    let event = if limit > previous {
+        AdaptiveEvent::LimitIncreased {
+            pattern_name,
+            timestamp,
+            from: previous,
+            to: limit,
+        }
+    } else {
+        AdaptiveEvent::LimitDecreased {
+            pattern_name,
+            timestamp,
+            from: previous,
+            to: limit,
+        }
+    };
+    config.event_listeners.emit(&event);
+}
+
+/// Report that a request was admitted or rejected.
+fn record_admission(config: &AdaptiveConfig, permitted: bool) {
+    #[cfg(feature = "metrics")]
+    counter!(
+        "resilience_adaptive_calls_total",
+        "name" => config.name.clone(),
+        "result" => if permitted { "permitted" } else { "rejected" }
+    )
+    .increment(1);
+
+    let pattern_name = config.name.clone();
+    let timestamp = Instant::now();
+    let event = if permitted {
+        AdaptiveEvent::CallPermitted {
+            pattern_name,
+            timestamp,
+        }
+    } else {
+        AdaptiveEvent::CallRejected {
+            pattern_name,
+            timestamp,
+        }
+    };
+    config.event_listeners.emit(&event);
+}
+
+/// An in-flight slot, released on drop.
+struct Slot {
+    in_flight: Arc<AtomicUsize>,
+    slot_freed: Arc<Notify>,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::AcqRel);
+        self.slot_freed.notify_waiters();
+    }
+}
+
+/// A place in the wait queue, released on drop.
+struct QueuePosition(Arc<AtomicUsize>);
+
+impl Drop for QueuePosition {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// How a request was admitted in `call`.
+enum Admission {
+    /// The request holds a slot.
+    Held(Slot),
+    /// The request must wait for a slot.
+    Queued {
+        max_wait: Duration,
+        position: QueuePosition,
+    },
+}
+
+/// Wait up to `max_wait` for an in-flight slot to become available.
+async fn wait_for_slot<A: ConcurrencyAlgorithm>(
+    algorithm: &A,
+    config: &AdaptiveConfig,
+    in_flight: &AtomicUsize,
+    slot_freed: &Notify,
+    max_wait: Duration,
+) -> bool {
+    let deadline = tokio::time::Instant::now() + max_wait;
+    loop {
+        // Register for wakeups before checking to avoid missing a release
+        let mut notified = std::pin::pin!(slot_freed.notified());
+        notified.as_mut().enable();
+
+        if try_acquire(in_flight, effective_limit(algorithm, config)) {
+            return true;
+        }
+        if tokio::time::timeout_at(deadline, notified).await.is_err() {
+            return try_acquire(in_flight, effective_limit(algorithm, config));
+        }
+    }
+}
+
+impl<S, A, C, Req> Service<Req> for AdaptiveService<S, A, C>
 where
-    S: Service<Req>,
+    S: Service<Req> + Clone + Send + 'static,
     S::Future: Send + 'static,
     S::Response: Send + 'static,
     S::Error: Send + 'static,
     A: ConcurrencyAlgorithm + 'static,
+    C: FailureClassifier<S::Response, S::Error> + 'static,
+    Req: Send + 'static,
 {
     type Response = S::Response;
     type Error = AdaptiveError<S::Error>;
     type Future = AdaptiveFuture<S::Response, S::Error>;
 
     fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        // Check if we have capacity
-        let algorithm_limit = self.algorithm.limit();
-        let in_flight = self.in_flight.load(Ordering::Relaxed);
-
-        if in_flight >= algorithm_limit {
-            // At capacity - wake and try again later
-            cx.waker().wake_by_ref();
-            return Poll::Pending;
+        if self.config.limit_policy == LimitPolicy::Backpressure {
+            // Check if we have capacity
+            let algorithm_limit = self.limit();
+            let in_flight = self.in_flight.load(Ordering::Relaxed);
+
+            if in_flight >= algorithm_limit {
+                // At capacity - wake and try again later
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
         }
 
         // Poll the inner service
@@ -100,50 +344,144 @@ where
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
-        let start = Instant::now();
-        self.in_flight.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "tracing")]
+        {
+            let span = tower_resilience_core::span::pattern_span("adaptive", &self.config.name);
+            let future = span.in_scope(|| self.call_untraced(req));
+            AdaptiveFuture {
+                inner: Box::pin(tower_resilience_core::span::instrument(
+                    span,
+                    future,
+                    tower_resilience_core::span::Outcome::from_result,
+                )),
+            }
+        }
 
-        let future = self.inner.call(req);
+        #[cfg(not(feature = "tracing"))]
+        self.call_untraced(req)
+    }
+}
 
-        // Adjust semaphore based on algorithm
-        let algorithm_limit = self.algorithm.limit();
-        let current = self.current_limit.load(Ordering::Relaxed);
-        if algorithm_limit > current {
-            let diff = algorithm_limit - current;
-            self.semaphore.add_permits(diff);
-            self.current_limit.store(algorithm_limit, Ordering::Relaxed);
-        } else if algorithm_limit < current {
-            self.current_limit.store(algorithm_limit, Ordering::Relaxed);
+impl<S, A, C> AdaptiveService<S, A, C> {
+    fn call_untraced<Req>(&mut self, req: Req) -> AdaptiveFuture<S::Response, S::Error>
+    where
+        S: Service<Req> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        S::Response: Send + 'static,
+        S::Error: Send + 'static,
+        A: ConcurrencyAlgorithm + 'static,
+        C: FailureClassifier<S::Response, S::Error> + 'static,
+        Req: Send + 'static,
+    {
+        // Claim a slot now unless the request has to queue for one
+        let slot = || Slot {
+            in_flight: Arc::clone(&self.in_flight),
+            slot_freed: Arc::clone(&self.slot_freed),
+        };
+        let admission = match self.config.limit_policy {
+            LimitPolicy::Backpressure => {
+                self.in_flight.fetch_add(1, Ordering::AcqRel);
+                Admission::Held(slot())
+            }
+            LimitPolicy::Reject => {
+                if !try_acquire(&self.in_flight, self.limit()) {
+                    record_admission(&self.config, false);
+                    return AdaptiveFuture::rejected();
+                }
+                Admission::Held(slot())
+            }
+            LimitPolicy::Queue {
+                max_wait,
+                max_queue,
+            } => {
+                if try_acquire(&self.in_flight, self.limit()) {
+                    Admission::Held(slot())
+                } else if self.queued.fetch_add(1, Ordering::AcqRel) >= max_queue {
+                    self.queued.fetch_sub(1, Ordering::AcqRel);
+                    record_admission(&self.config, false);
+                    return AdaptiveFuture::rejected();
+                } else {
+                    Admission::Queued {
+                        max_wait,
+                        position: QueuePosition(Arc::clone(&self.queued)),
+                    }
+                }
+            }
+        };
+
+        // The inner service is only called once a slot is held; take the
+        // readied instance along and leave a fresh clone in its place
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        if matches!(admission, Admission::Held(_)) {
+            record_admission(&self.config, true);
         }
 
+        // Adjust semaphore based on algorithm
+        sync_limit(
+            &*self.algorithm,
+            &self.current_limit,
+            &self.semaphore,
+            &self.config,
+        );
+
         let algorithm = Arc::clone(&self.algorithm);
+        let config = Arc::clone(&self.config);
+        let classifier = Arc::clone(&self.classifier);
         let in_flight = Arc::clone(&self.in_flight);
+        let slot_freed = Arc::clone(&self.slot_freed);
         let semaphore = Arc::clone(&self.semaphore);
         let current_limit = Arc::clone(&self.current_limit);
 
         AdaptiveFuture {
             inner: Box::pin(async move {
-                let result = future.await;
+                let slot = match admission {
+                    Admission::Held(slot) => slot,
+                    Admission::Queued { max_wait, position } => {
+                        let queued_at = Instant::now();
+                        let acquired =
+                            wait_for_slot(&*algorithm, &config, &in_flight, &slot_freed, max_wait)
+                                .await;
+                        drop(position);
+
+                        if !acquired {
+                            config.event_listeners.emit(&AdaptiveEvent::QueueTimeout {
+                                pattern_name: config.name.clone(),
+                                timestamp: Instant::now(),
+                                waited: queued_at.elapsed(),
+                            });
+                            record_admission(&config, false);
+                            return Err(AdaptiveError::LimitExceeded);
+                        }
+                        record_admission(&config, true);
+                        Slot {
+                            in_flight: Arc::clone(&in_flight),
+                            slot_freed: Arc::clone(&slot_freed),
+                        }
+                    }
+                };
+
+                let start = Instant::now();
+                let result = inner.call(req).await;
                 let latency = start.elapsed();
 
-                // Decrement in-flight counter
-                in_flight.fetch_sub(1, Ordering::Relaxed);
-
-                match &result {
-                    Ok(_) => algorithm.record_success(latency),
-                    Err(_) => algorithm.record_failure(),
+                // Release the slot
+                drop(slot);
+
+                // Only congestion shrinks the limit; other results are
+                // treated as ordinary latency samples. Nothing is recorded
+                // while an operator has frozen adaptation.
+                if !config.control.is_frozen() {
+                    if classifier.classify(&result) {
+                        algorithm.record_failure();
+                    } else {
+                        algorithm.record_success(latency);
+                    }
                 }
 
                 // Adjust semaphore based on new algorithm limit
-                let alg_limit = algorithm.limit();
-                let curr = current_limit.load(Ordering::Relaxed);
-                if alg_limit > curr {
-                    let diff = alg_limit - curr;
-                    semaphore.add_permits(diff);
-                    current_limit.store(alg_limit, Ordering::Relaxed);
-                } else if alg_limit < curr {
-                    current_limit.store(alg_limit, Ordering::Relaxed);
-                }
+                sync_limit(&*algorithm, &current_limit, &semaphore, &config);
 
                 result.map_err(AdaptiveError::Service)
             }),
@@ -156,15 +494,16 @@ where
 pub enum AdaptiveError<E> {
     /// The service returned an error.
     Service(E),
-    /// The concurrency limit was reached.
-    LimitReached,
+    /// The concurrency limit was reached and the request was rejected,
+    /// either immediately or after waiting in the queue.
+    LimitExceeded,
 }
 
 impl<E: std::fmt::Display> std::fmt::Display for AdaptiveError<E> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Service(e) => write!(f, "service error: {}", e),
-            Self::LimitReached => write!(f, "concurrency limit reached"),
+            Self::LimitExceeded => write!(f, "concurrency limit exceeded"),
         }
     }
 }
@@ -173,7 +512,29 @@ impl<E: std::error::Error + 'static> std::error::Error for AdaptiveError<E> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Service(e) => Some(e),
-            Self::LimitReached => None,
+            Self::LimitExceeded => None,
+        }
+    }
+}
+
+impl<E> PatternError for AdaptiveError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        matches!(self, Self::LimitExceeded)
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            Self::Service(e) => Some(e),
+            Self::LimitExceeded => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            Self::Service(e) => Ok(e),
+            other => Err(other),
         }
     }
 }
@@ -183,6 +544,18 @@ pub struct AdaptiveFuture<T, E> {
     inner: Pin<Box<dyn Future<Output = Result<T, AdaptiveError<E>>> + Send>>,
 }
 
+impl<T, E> AdaptiveFuture<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn rejected() -> Self {
+        Self {
+            inner: Box::pin(std::future::ready(Err(AdaptiveError::LimitExceeded))),
+        }
+    }
+}
+
 impl<T, E> Future for AdaptiveFuture<T, E> {
     type Output = Result<T, AdaptiveError<E>>;
 
@@ -242,8 +615,8 @@ mod tests {
 
     #[test]
     fn test_error_display() {
-        let err: AdaptiveError<&str> = AdaptiveError::LimitReached;
-        assert_eq!(err.to_string(), "concurrency limit reached");
+        let err: AdaptiveError<&str> = AdaptiveError::LimitExceeded;
+        assert_eq!(err.to_string(), "concurrency limit exceeded");
 
         let err: AdaptiveError<&str> = AdaptiveError::Service("test error");
         assert!(err.to_string().contains("test error"));
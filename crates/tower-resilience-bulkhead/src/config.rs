@@ -1,29 +1,82 @@
 //! Configuration for the bulkhead pattern.
 
 use crate::events::BulkheadEvent;
+use crate::priority::{Priority, PriorityFn, Reservations};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Notify;
 use tower_resilience_core::events::{EventListeners, FnListener};
 
 /// Configuration for the bulkhead pattern.
 #[derive(Clone)]
 pub struct BulkheadConfig {
-    /// Maximum number of concurrent calls allowed.
-    pub(crate) max_concurrent_calls: usize,
+    /// Maximum number of concurrent calls allowed, adjustable through a
+    /// [`BulkheadHandle`](crate::BulkheadHandle).
+    pub(crate) limit: Arc<AtomicUsize>,
     /// Maximum time to wait for a permit.
     pub(crate) max_wait_duration: Option<Duration>,
     /// Whether backpressure mode is enabled.
     pub(crate) backpressure: bool,
+    /// How long a call may hold a permit before it is reported.
+    pub(crate) max_permit_hold: Option<Duration>,
+    /// Whether a permit held past `max_permit_hold` is released early.
+    pub(crate) release_held_permits: bool,
+    /// How long a call may wait for a permit before it is reported.
+    pub(crate) starvation_threshold: Option<Duration>,
+    /// Capacity reserved per priority class.
+    pub(crate) reservations: Reservations,
+    /// Assigns a priority to each request.
+    pub(crate) priority_fn: Option<PriorityFn>,
+    /// Signalled whenever permits are returned.
+    pub(crate) released: Arc<Notify>,
     /// Name of this bulkhead instance.
     pub(crate) name: String,
     /// Event listeners.
     pub(crate) event_listeners: EventListeners<BulkheadEvent>,
 }
 
+impl BulkheadConfig {
+    /// Returns the current maximum number of concurrent calls.
+    pub(crate) fn max_concurrent_calls(&self) -> usize {
+        self.limit.load(Ordering::Acquire)
+    }
+
+    /// Returns whether permits are granted in the order calls asked for them.
+    ///
+    /// Priority reservations let a higher priority call overtake lower
+    /// priority calls that are already waiting, so acquisition is only FIFO
+    /// when no capacity is reserved or reservations do not apply.
+    pub(crate) fn is_fair(&self) -> bool {
+        self.backpressure || self.reservations.is_empty()
+    }
+
+    /// Returns how many permits `request` must leave free for higher
+    /// priority classes.
+    pub(crate) fn reserved_for<Request: 'static>(&self, request: &Request) -> usize {
+        if self.reservations.is_empty() {
+            return 0;
+        }
+        let priority = self
+            .priority_fn
+            .as_ref()
+            .and_then(|priority_fn| priority_fn(request))
+            .unwrap_or_default();
+        self.reservations
+            .reserved_above(priority, self.max_concurrent_calls())
+    }
+}
+
 /// Builder for bulkhead configuration.
 pub struct BulkheadConfigBuilder {
     max_concurrent_calls: usize,
     max_wait_duration: Option<Duration>,
     backpressure: bool,
+    max_permit_hold: Option<Duration>,
+    release_held_permits: bool,
+    starvation_threshold: Option<Duration>,
+    reservations: Reservations,
+    priority_fn: Option<PriorityFn>,
     name: String,
     event_listeners: EventListeners<BulkheadEvent>,
 }
@@ -35,6 +88,11 @@ impl BulkheadConfigBuilder {
             max_concurrent_calls: 25,
             max_wait_duration: None,
             backpressure: false,
+            max_permit_hold: None,
+            release_held_permits: false,
+            starvation_threshold: None,
+            reservations: Reservations::default(),
+            priority_fn: None,
             name: "bulkhead".to_string(),
             event_listeners: EventListeners::new(),
         }
@@ -126,6 +184,129 @@ impl BulkheadConfigBuilder {
         self
     }
 
+    /// Reports calls that hold a permit for longer than `duration`.
+    ///
+    /// When a call is still running after `duration`, a
+    /// [`BulkheadEvent::PermitHeldTooLong`] event is emitted. This catches
+    /// leaks where a few slow calls quietly starve the bulkhead. The call
+    /// itself is not cancelled; pair with a time limiter for that.
+    ///
+    /// If not called, permit hold times are not checked (the default).
+    ///
+    /// # Example
+    /// ```rust
+    /// use tower_resilience_bulkhead::BulkheadLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = BulkheadLayer::builder()
+    ///     .max_concurrent_calls(10)
+    ///     .max_permit_hold(Duration::from_secs(30))
+    ///     .on_permit_held_too_long(|held| {
+    ///         eprintln!("call has held a permit for {:?}", held);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn max_permit_hold(mut self, duration: Duration) -> Self {
+        self.max_permit_hold = Some(duration);
+        self
+    }
+
+    /// Releases the permit of a call that exceeds
+    /// [`max_permit_hold`](Self::max_permit_hold).
+    ///
+    /// The call keeps running, but its slot is handed back so that waiting
+    /// calls can proceed. This trades the concurrency bound for liveness:
+    /// while overrunning calls are still in flight, more calls than
+    /// `max_concurrent_calls` may be running.
+    ///
+    /// Has no effect unless `max_permit_hold` is set.
+    ///
+    /// Default: `false`
+    pub fn release_held_permits(mut self) -> Self {
+        self.release_held_permits = true;
+        self
+    }
+
+    /// Reports calls that wait longer than `threshold` for a permit.
+    ///
+    /// When a call is still queued after `threshold`, a
+    /// [`BulkheadEvent::WaiterStarved`] event is emitted and the call keeps
+    /// waiting. Set the threshold below
+    /// [`max_wait_duration`](Self::max_wait_duration) to see long-tail
+    /// queuing before it turns into timeouts, or use it on its own when
+    /// calls wait indefinitely. Applies in [`backpressure`](Self::backpressure)
+    /// mode as well.
+    ///
+    /// If not called, wait times are not checked (the default).
+    ///
+    /// # Example
+    /// ```rust
+    /// use tower_resilience_bulkhead::BulkheadLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = BulkheadLayer::builder()
+    ///     .max_concurrent_calls(10)
+    ///     .max_wait_duration(Duration::from_secs(5))
+    ///     .starvation_threshold(Duration::from_secs(1))
+    ///     .on_waiter_starved(|waited| {
+    ///         eprintln!("call has waited {:?} for a permit", waited);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn starvation_threshold(mut self, threshold: Duration) -> Self {
+        self.starvation_threshold = Some(threshold);
+        self
+    }
+
+    /// Reserves a fraction (0.0 to 1.0) of the permits for requests of
+    /// `priority` or higher.
+    ///
+    /// Lower-priority requests wait (or are rejected, subject to
+    /// [`max_wait_duration`](Self::max_wait_duration)) rather than take a
+    /// reserved permit, so background traffic can never starve interactive
+    /// traffic. Reservations for several classes add up: reserving 20% for
+    /// [`Priority::Critical`] and 10% for [`Priority::High`] leaves 70% to
+    /// normal traffic. The number of reserved permits is rounded up and
+    /// follows the current limit.
+    ///
+    /// Requests are classified with [`priority_fn`](Self::priority_fn);
+    /// without one every request is [`Priority::Normal`]. Reservations are
+    /// not applied in [`backpressure`](Self::backpressure) mode, where
+    /// permits are acquired before the request is known.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tower_resilience_bulkhead::{BulkheadLayer, Priority};
+    ///
+    /// # struct Request { interactive: bool }
+    /// let layer = BulkheadLayer::builder()
+    ///     .max_concurrent_calls(50)
+    ///     .reserve(Priority::Critical, 0.2)
+    ///     .priority_fn(|req: &Request| {
+    ///         if req.interactive { Priority::Critical } else { Priority::Low }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn reserve(mut self, priority: Priority, fraction: f64) -> Self {
+        self.reservations.set(priority, fraction);
+        self
+    }
+
+    /// Sets the function assigning a [`Priority`] to each request.
+    ///
+    /// Used with [`reserve`](Self::reserve). Requests of a type other than
+    /// `Req` are treated as [`Priority::Normal`].
+    pub fn priority_fn<Req, F>(mut self, f: F) -> Self
+    where
+        Req: 'static,
+        F: Fn(&Req) -> Priority + Send + Sync + 'static,
+    {
+        self.priority_fn = Some(Arc::new(move |request| {
+            request.downcast_ref::<Req>().map(&f)
+        }));
+        self
+    }
+
     /// Sets the name of this bulkhead instance.
     ///
     /// Default: "bulkhead"
@@ -294,6 +475,54 @@ impl BulkheadConfigBuilder {
         self
     }
 
+    /// Registers a callback when a call holds its permit too long.
+    ///
+    /// This callback is invoked once per call, when the call is still running
+    /// after the duration set with [`max_permit_hold`](Self::max_permit_hold).
+    ///
+    /// # Callback Signature
+    /// `Fn(Duration)` - Called with how long the permit had been held.
+    pub fn on_permit_held_too_long<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let BulkheadEvent::PermitHeldTooLong { held, .. } = event {
+                f(*held);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback when a call waits too long for a permit.
+    ///
+    /// This callback is invoked once per call, when the call is still waiting
+    /// after the duration set with
+    /// [`starvation_threshold`](Self::starvation_threshold).
+    ///
+    /// # Callback Signature
+    /// `Fn(Duration)` - Called with how long the call had been waiting.
+    pub fn on_waiter_starved<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let BulkheadEvent::WaiterStarved { waited, .. } = event {
+                f(*waited);
+            }
+        }));
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the configuration and returns a BulkheadLayer.
     pub fn build(self) -> crate::layer::BulkheadLayer {
         let config = self.into_config();
@@ -321,7 +550,7 @@ impl BulkheadConfigBuilder {
         let config = self.into_config();
         let config = std::sync::Arc::new(config);
         let semaphore =
-            std::sync::Arc::new(tokio::sync::Semaphore::new(config.max_concurrent_calls));
+            std::sync::Arc::new(tokio::sync::Semaphore::new(config.max_concurrent_calls()));
 
         let handle = crate::handle::BulkheadHandle {
             semaphore: std::sync::Arc::clone(&semaphore),
@@ -338,9 +567,15 @@ impl BulkheadConfigBuilder {
 
     fn into_config(self) -> BulkheadConfig {
         BulkheadConfig {
-            max_concurrent_calls: self.max_concurrent_calls,
+            limit: Arc::new(AtomicUsize::new(self.max_concurrent_calls)),
             max_wait_duration: self.max_wait_duration,
             backpressure: self.backpressure,
+            max_permit_hold: self.max_permit_hold,
+            release_held_permits: self.release_held_permits,
+            starvation_threshold: self.starvation_threshold,
+            reservations: self.reservations,
+            priority_fn: self.priority_fn,
+            released: Arc::new(Notify::new()),
             name: self.name,
             event_listeners: self.event_listeners,
         }
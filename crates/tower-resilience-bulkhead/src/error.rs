@@ -1,6 +1,6 @@
 //! Error types for bulkhead pattern.
 
-use tower_resilience_core::ResilienceError;
+use tower_resilience_core::{PatternError, ResilienceError};
 
 /// Errors that can occur when using a bulkhead.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -96,6 +96,32 @@ impl<E: std::error::Error + 'static> std::error::Error for BulkheadServiceError<
     }
 }
 
+impl<E> PatternError for BulkheadServiceError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        self.is_bulkhead()
+    }
+
+    fn is_timeout(&self) -> bool {
+        matches!(self, BulkheadServiceError::Bulkhead(BulkheadError::Timeout))
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            BulkheadServiceError::Bulkhead(_) => None,
+            BulkheadServiceError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> std::result::Result<E, Self> {
+        match self {
+            BulkheadServiceError::Inner(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 impl<E> From<BulkheadError> for BulkheadServiceError<E> {
     fn from(err: BulkheadError) -> Self {
         BulkheadServiceError::Bulkhead(err)
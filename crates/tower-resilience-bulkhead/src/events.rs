@@ -1,16 +1,22 @@
 //! Event types for bulkhead pattern.
 
 use std::time::{Duration, Instant};
-use tower_resilience_core::events::ResilienceEvent;
+use tower_resilience_core::events::{ResilienceEvent, Severity};
 
 /// Events emitted by the bulkhead pattern.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum BulkheadEvent {
     /// A call was permitted through the bulkhead.
     CallPermitted {
         /// Name of the bulkhead instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// Current number of concurrent calls.
         concurrent_calls: usize,
@@ -20,6 +26,10 @@ pub enum BulkheadEvent {
         /// Name of the bulkhead instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// Maximum concurrent calls allowed.
         max_concurrent_calls: usize,
@@ -29,6 +39,10 @@ pub enum BulkheadEvent {
         /// Name of the bulkhead instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// Duration of the call.
         duration: Duration,
@@ -38,10 +52,49 @@ pub enum BulkheadEvent {
         /// Name of the bulkhead instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// Duration of the call.
         duration: Duration,
     },
+    /// A call held its permit longer than the configured
+    /// [`max_permit_hold`](crate::BulkheadConfigBuilder::max_permit_hold).
+    PermitHeldTooLong {
+        /// Name of the bulkhead instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// How long the permit had been held.
+        held: Duration,
+        /// Whether the permit was released back to the bulkhead while the
+        /// call keeps running.
+        released: bool,
+    },
+    /// A call has waited for a permit longer than the configured
+    /// [`starvation_threshold`](crate::BulkheadConfigBuilder::starvation_threshold).
+    ///
+    /// The call keeps waiting; it may still be permitted or time out.
+    WaiterStarved {
+        /// Name of the bulkhead instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// How long the call had been waiting.
+        waited: Duration,
+        /// Maximum concurrent calls allowed.
+        max_concurrent_calls: usize,
+    },
 }
 
 impl ResilienceEvent for BulkheadEvent {
@@ -51,6 +104,8 @@ impl ResilienceEvent for BulkheadEvent {
             BulkheadEvent::CallRejected { .. } => "call_rejected",
             BulkheadEvent::CallFinished { .. } => "call_finished",
             BulkheadEvent::CallFailed { .. } => "call_failed",
+            BulkheadEvent::PermitHeldTooLong { .. } => "permit_held_too_long",
+            BulkheadEvent::WaiterStarved { .. } => "waiter_starved",
         }
     }
 
@@ -59,7 +114,9 @@ impl ResilienceEvent for BulkheadEvent {
             BulkheadEvent::CallPermitted { timestamp, .. }
             | BulkheadEvent::CallRejected { timestamp, .. }
             | BulkheadEvent::CallFinished { timestamp, .. }
-            | BulkheadEvent::CallFailed { timestamp, .. } => *timestamp,
+            | BulkheadEvent::CallFailed { timestamp, .. }
+            | BulkheadEvent::PermitHeldTooLong { timestamp, .. }
+            | BulkheadEvent::WaiterStarved { timestamp, .. } => *timestamp,
         }
     }
 
@@ -68,7 +125,21 @@ impl ResilienceEvent for BulkheadEvent {
             BulkheadEvent::CallPermitted { pattern_name, .. }
             | BulkheadEvent::CallRejected { pattern_name, .. }
             | BulkheadEvent::CallFinished { pattern_name, .. }
-            | BulkheadEvent::CallFailed { pattern_name, .. } => pattern_name,
+            | BulkheadEvent::CallFailed { pattern_name, .. }
+            | BulkheadEvent::PermitHeldTooLong { pattern_name, .. }
+            | BulkheadEvent::WaiterStarved { pattern_name, .. } => pattern_name,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            BulkheadEvent::CallRejected { .. }
+            | BulkheadEvent::CallFailed { .. }
+            | BulkheadEvent::PermitHeldTooLong { .. }
+            | BulkheadEvent::WaiterStarved { .. } => Severity::Warn,
+            BulkheadEvent::CallPermitted { .. } | BulkheadEvent::CallFinished { .. } => {
+                Severity::Info
+            }
         }
     }
 }
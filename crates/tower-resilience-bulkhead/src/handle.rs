@@ -1,6 +1,9 @@
 use crate::config::BulkheadConfig;
+use crate::settings::BulkheadSettings;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tokio::sync::Semaphore;
+use tower_resilience_core::Reloadable;
 
 /// A read-only handle for observing bulkhead state.
 ///
@@ -25,6 +28,10 @@ use tokio::sync::Semaphore;
 /// // Later, query state from the handle:
 /// assert_eq!(handle.active_calls(), 0);
 /// assert_eq!(handle.max_concurrent(), 10);
+///
+/// // Resize the running bulkhead:
+/// handle.set_max_concurrent(20);
+/// assert_eq!(handle.max_concurrent(), 20);
 /// ```
 #[derive(Clone)]
 pub struct BulkheadHandle {
@@ -36,35 +43,88 @@ impl BulkheadHandle {
     /// Returns the number of currently active (in-flight) calls.
     pub fn active_calls(&self) -> usize {
         self.config
-            .max_concurrent_calls
+            .max_concurrent_calls()
             .saturating_sub(self.semaphore.available_permits())
     }
 
     /// Returns the configured maximum concurrent calls.
     pub fn max_concurrent(&self) -> usize {
-        self.config.max_concurrent_calls
+        self.config.max_concurrent_calls()
+    }
+
+    /// Changes the maximum number of concurrent calls.
+    ///
+    /// Growing the limit releases new permits immediately. When shrinking,
+    /// in-flight calls are allowed to finish and their permits are retired
+    /// as they complete, so the new limit may take a moment to be reached.
+    pub fn set_max_concurrent(&self, max: usize) {
+        let previous = self.config.limit.swap(max, Ordering::AcqRel);
+        if max > previous {
+            self.semaphore.add_permits(max - previous);
+            self.config.released.notify_waiters();
+            return;
+        }
+
+        let excess = previous - max;
+        let outstanding = excess - self.semaphore.forget_permits(excess);
+        if outstanding == 0 {
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let semaphore = Arc::clone(&self.semaphore);
+        runtime.spawn(async move {
+            let count = u32::try_from(outstanding).unwrap_or(u32::MAX);
+            if let Ok(permits) = semaphore.acquire_many_owned(count).await {
+                permits.forget();
+            }
+        });
     }
 
     /// Returns the utilization ratio (0.0 to 1.0).
     ///
     /// A value of 1.0 means all permits are consumed.
     pub fn utilization(&self) -> f64 {
-        let max = self.config.max_concurrent_calls;
+        let max = self.config.max_concurrent_calls();
         if max == 0 {
             return 0.0;
         }
         self.active_calls() as f64 / max as f64
     }
 
+    /// Returns whether permits are granted in the order calls asked for them.
+    ///
+    /// Waiting calls queue in FIFO order, so a call cannot be overtaken by
+    /// calls that arrive after it. The exception is
+    /// [`reserve`](crate::BulkheadConfigBuilder::reserve): while capacity is
+    /// reserved, higher priority calls may be permitted ahead of lower
+    /// priority calls that are already waiting, and this returns `false`.
+    pub fn is_fair(&self) -> bool {
+        self.config.is_fair()
+    }
+
     /// Returns the number of available permits.
     pub fn available_permits(&self) -> usize {
         self.semaphore.available_permits()
     }
 }
 
+impl Reloadable for BulkheadHandle {
+    type Settings = BulkheadSettings;
+
+    /// Applies `max_concurrent_calls` through
+    /// [`set_max_concurrent`](BulkheadHandle::set_max_concurrent); other
+    /// fields are ignored.
+    fn reload(&self, settings: &BulkheadSettings) {
+        if let Some(max) = settings.max_concurrent_calls {
+            self.set_max_concurrent(max);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::BulkheadLayer;
     use std::future::Future;
     use std::pin::Pin;
@@ -106,6 +166,24 @@ mod tests {
         assert_eq!(handle.utilization(), 0.0);
     }
 
+    #[test]
+    fn test_handle_reports_fairness() {
+        let (_layer, handle) = BulkheadLayer::builder().build_with_handle();
+        assert!(handle.is_fair());
+
+        let (_layer, handle) = BulkheadLayer::builder()
+            .reserve(crate::Priority::Critical, 0.2)
+            .build_with_handle();
+        assert!(!handle.is_fair());
+
+        // Reservations are not applied in backpressure mode
+        let (_layer, handle) = BulkheadLayer::builder()
+            .reserve(crate::Priority::Critical, 0.2)
+            .backpressure()
+            .build_with_handle();
+        assert!(handle.is_fair());
+    }
+
     #[tokio::test]
     async fn test_handle_observes_active_calls() {
         let (layer, handle) = BulkheadLayer::builder()
@@ -148,6 +226,31 @@ mod tests {
         assert_eq!(handle.active_calls(), 0);
     }
 
+    #[tokio::test]
+    async fn test_handle_resize() {
+        let (layer, handle) = BulkheadLayer::builder()
+            .max_concurrent_calls(2)
+            .build_with_handle();
+
+        handle.set_max_concurrent(4);
+        assert_eq!(handle.max_concurrent(), 4);
+        assert_eq!(handle.available_permits(), 4);
+
+        // Shrink while a call holds a permit
+        let mut svc = layer.layer(SlowService);
+        let task = tokio::spawn(async move { svc.call("a".to_string()).await });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+        handle.set_max_concurrent(1);
+        assert_eq!(handle.max_concurrent(), 1);
+        assert_eq!(handle.available_permits(), 0);
+
+        let _ = task.await;
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        assert_eq!(handle.available_permits(), 1);
+        assert_eq!(handle.active_calls(), 0);
+    }
+
     #[tokio::test]
     async fn test_handle_clone() {
         let (_layer, handle) = BulkheadLayer::builder()
@@ -49,31 +49,39 @@ impl BulkheadLayer {
         {
             METRICS_INIT.call_once(|| {
                 describe_counter!(
-                    "bulkhead_calls_permitted_total",
+                    "resilience_bulkhead_calls_permitted_total",
                     "Total number of calls permitted through the bulkhead"
                 );
                 describe_counter!(
-                    "bulkhead_calls_rejected_total",
+                    "resilience_bulkhead_calls_rejected_total",
                     "Total number of calls rejected by the bulkhead"
                 );
                 describe_counter!(
-                    "bulkhead_calls_finished_total",
+                    "resilience_bulkhead_calls_finished_total",
                     "Total number of calls that finished successfully"
                 );
                 describe_counter!(
-                    "bulkhead_calls_failed_total",
+                    "resilience_bulkhead_calls_failed_total",
                     "Total number of calls that failed"
                 );
+                describe_counter!(
+                    "resilience_bulkhead_permit_held_too_long_total",
+                    "Total number of calls that held a permit past max_permit_hold"
+                );
+                describe_counter!(
+                    "resilience_bulkhead_waiters_starved_total",
+                    "Total number of calls that waited for a permit past starvation_threshold"
+                );
                 describe_gauge!(
-                    "bulkhead_concurrent_calls",
+                    "resilience_bulkhead_concurrent_calls",
                     "Current number of concurrent calls"
                 );
                 describe_histogram!(
-                    "bulkhead_wait_duration_seconds",
+                    "resilience_bulkhead_wait_duration_seconds",
                     "Time spent waiting to acquire a permit"
                 );
                 describe_histogram!(
-                    "bulkhead_call_duration_seconds",
+                    "resilience_bulkhead_call_duration_seconds",
                     "Duration of calls through the bulkhead"
                 );
             });
@@ -107,6 +107,53 @@
 //! # }
 //! ```
 //!
+//! # Load-Aware Balancing
+//!
+//! [`Bulkhead`] implements [`tower::load::Load`], reporting the fraction of its
+//! capacity in use (`0.0` to `1.0`). Wrap each replica in its own bulkhead and
+//! `tower::balance::p2c` will steer requests toward the least busy one.
+//!
+//! # Priority Reservations
+//!
+//! Part of the capacity can be reserved for high-priority requests, so that
+//! background traffic cannot starve them:
+//!
+//! ```rust
+//! use tower_resilience_bulkhead::{BulkheadLayer, Priority};
+//!
+//! # struct Request { health_check: bool }
+//! let layer = BulkheadLayer::builder()
+//!     .max_concurrent_calls(10)
+//!     .reserve(Priority::Critical, 0.2)
+//!     .priority_fn(|req: &Request| {
+//!         if req.health_check { Priority::Critical } else { Priority::Normal }
+//!     })
+//!     .build();
+//! ```
+//!
+//! Waiting calls are otherwise permitted in FIFO order;
+//! [`BulkheadHandle::is_fair`] reports whether that still holds.
+//!
+//! # Starvation Detection
+//!
+//! A call that has waited longer than
+//! [`starvation_threshold`](BulkheadConfigBuilder::starvation_threshold) for a
+//! permit is reported with a [`BulkheadEvent::WaiterStarved`] event, even if it
+//! has not timed out yet:
+//!
+//! ```rust
+//! use tower_resilience_bulkhead::BulkheadLayer;
+//! use std::time::Duration;
+//!
+//! let layer = BulkheadLayer::builder()
+//!     .max_concurrent_calls(10)
+//!     .starvation_threshold(Duration::from_millis(500))
+//!     .on_waiter_starved(|waited| {
+//!         eprintln!("call queued for {:?}", waited);
+//!     })
+//!     .build();
+//! ```
+//!
 //! # Fallback When Bulkhead is Full
 //!
 //! Handle bulkhead capacity errors with graceful degradation:
@@ -262,15 +309,20 @@ pub mod events;
 mod handle;
 /// Tower `Layer` implementation for the bulkhead.
 pub mod layer;
+mod priority;
 /// Tower `Service` implementation for the bulkhead.
 pub mod service;
+/// Bulkhead settings that can be loaded from configuration files.
+pub mod settings;
 
 pub use config::{BulkheadConfig, BulkheadConfigBuilder};
 pub use error::{BulkheadError, BulkheadServiceError, Result};
 pub use events::BulkheadEvent;
 pub use handle::BulkheadHandle;
 pub use layer::BulkheadLayer;
+pub use priority::Priority;
 pub use service::Bulkhead;
+pub use settings::BulkheadSettings;
 
 #[cfg(test)]
 mod tests {
@@ -348,6 +400,22 @@ mod tests {
             duration: Duration::from_millis(50),
         };
         assert_eq!(event.event_type(), "call_failed");
+
+        let event = BulkheadEvent::PermitHeldTooLong {
+            pattern_name: "test".to_string(),
+            timestamp: Instant::now(),
+            held: Duration::from_secs(30),
+            released: false,
+        };
+        assert_eq!(event.event_type(), "permit_held_too_long");
+
+        let event = BulkheadEvent::WaiterStarved {
+            pattern_name: "test".to_string(),
+            timestamp: Instant::now(),
+            waited: Duration::from_secs(1),
+            max_concurrent_calls: 10,
+        };
+        assert_eq!(event.event_type(), "waiter_starved");
     }
 
     #[test]
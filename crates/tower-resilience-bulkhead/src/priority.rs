@@ -0,0 +1,146 @@
+//! Priority classes and capacity reservations.
+
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::{AcquireError, Notify, OwnedSemaphorePermit, Semaphore};
+
+/// Priority class of a request.
+///
+/// Capacity reserved with
+/// [`BulkheadConfigBuilder::reserve`](crate::BulkheadConfigBuilder::reserve)
+/// can only be used by requests of that class or higher, so lower-priority
+/// traffic cannot starve it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Background work such as batch jobs or prefetching.
+    Low,
+    /// Regular traffic. Requests without a priority use this class.
+    #[default]
+    Normal,
+    /// Latency-sensitive traffic.
+    High,
+    /// Traffic that must be served even when the bulkhead is busy.
+    Critical,
+}
+
+impl Priority {
+    const ALL: [Priority; 4] = [
+        Priority::Low,
+        Priority::Normal,
+        Priority::High,
+        Priority::Critical,
+    ];
+}
+
+/// Type-erased function assigning a [`Priority`] to a request.
+pub(crate) type PriorityFn = Arc<dyn Fn(&dyn Any) -> Option<Priority> + Send + Sync>;
+
+/// Fractions of capacity reserved per priority class.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Reservations {
+    fractions: [f64; 4],
+}
+
+impl Reservations {
+    /// Reserves `fraction` of capacity for `priority` and above.
+    pub(crate) fn set(&mut self, priority: Priority, fraction: f64) {
+        self.fractions[priority as usize] = fraction.clamp(0.0, 1.0);
+    }
+
+    /// Whether any capacity is reserved.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.fractions.iter().all(|fraction| *fraction == 0.0)
+    }
+
+    /// Permits out of `limit` that `priority` must leave free for higher classes.
+    pub(crate) fn reserved_above(&self, priority: Priority, limit: usize) -> usize {
+        let fraction: f64 = Priority::ALL
+            .iter()
+            .filter(|class| **class > priority)
+            .map(|class| self.fractions[*class as usize])
+            .sum();
+        // Tolerate rounding error so 0.1 + 0.2 of 10 permits reserves 3, not 4
+        let reserved = (limit as f64 * fraction - 1e-9).ceil().max(0.0);
+        (reserved as usize).min(limit)
+    }
+}
+
+/// Acquires a permit while leaving `reserved` permits free.
+///
+/// Waits for `released` whenever taking a permit would dip into the
+/// reservation, re-checking after every release.
+pub(crate) async fn acquire_unreserved(
+    semaphore: Arc<Semaphore>,
+    reserved: usize,
+    released: &Notify,
+) -> Result<OwnedSemaphorePermit, AcquireError> {
+    loop {
+        // Register before checking so a release in between is not missed
+        let notified = released.notified();
+        if semaphore.available_permits() > reserved {
+            match Arc::clone(&semaphore).try_acquire_owned() {
+                Ok(permit) if semaphore.available_permits() >= reserved => return Ok(permit),
+                Ok(permit) => {
+                    // Waiters that checked while this permit was held may
+                    // have gone to sleep on it
+                    drop(permit);
+                    released.notify_waiters();
+                }
+                Err(tokio::sync::TryAcquireError::Closed) => {
+                    return Arc::clone(&semaphore).acquire_owned().await;
+                }
+                Err(tokio::sync::TryAcquireError::NoPermits) => {}
+            }
+        }
+        notified.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserved_above_sums_higher_classes() {
+        let mut reservations = Reservations::default();
+        reservations.set(Priority::Critical, 0.2);
+        reservations.set(Priority::High, 0.1);
+
+        assert_eq!(reservations.reserved_above(Priority::Low, 10), 3);
+        assert_eq!(reservations.reserved_above(Priority::Normal, 10), 3);
+        assert_eq!(reservations.reserved_above(Priority::High, 10), 2);
+        assert_eq!(reservations.reserved_above(Priority::Critical, 10), 0);
+    }
+
+    #[test]
+    fn test_reserved_above_rounds_up_and_caps() {
+        let mut reservations = Reservations::default();
+        reservations.set(Priority::Critical, 0.2);
+        assert_eq!(reservations.reserved_above(Priority::Normal, 3), 1);
+
+        reservations.set(Priority::High, 5.0);
+        assert_eq!(reservations.reserved_above(Priority::Normal, 3), 3);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_unreserved_waits_for_release() {
+        let semaphore = Arc::new(Semaphore::new(2));
+        let released = Arc::new(Notify::new());
+        let held = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+
+        // One permit left, and it is reserved
+        let waiter = {
+            let semaphore = Arc::clone(&semaphore);
+            let released = Arc::clone(&released);
+            tokio::spawn(async move { acquire_unreserved(semaphore, 1, &released).await })
+        };
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        drop(held);
+        released.notify_waiters();
+        let permit = waiter.await.unwrap().unwrap();
+        assert_eq!(semaphore.available_permits(), 1);
+        drop(permit);
+    }
+}
@@ -3,14 +3,16 @@
 use crate::config::BulkheadConfig;
 use crate::error::{BulkheadError, BulkheadServiceError};
 use crate::events::BulkheadEvent;
+use crate::priority::acquire_unreserved;
 use futures::future::BoxFuture;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Instant;
-use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
 use tokio::task::JoinHandle;
+use tower::load::Load;
 use tower::Service;
 
 #[cfg(feature = "metrics")]
@@ -38,6 +40,99 @@ impl<T> Drop for AbortOnDrop<T> {
     }
 }
 
+/// A permit that wakes calls waiting outside reserved capacity on release.
+struct HeldPermit<'a> {
+    permit: Option<OwnedSemaphorePermit>,
+    released: &'a Notify,
+}
+
+impl Drop for HeldPermit<'_> {
+    fn drop(&mut self) {
+        // Return the permit before waking waiters, so they find it free
+        drop(self.permit.take());
+        self.released.notify_waiters();
+    }
+}
+
+/// Drives `future` while holding `permit`, reporting calls that hold the
+/// permit past `max_permit_hold`.
+async fn hold_permit<F: Future>(
+    future: F,
+    permit: OwnedSemaphorePermit,
+    config: &BulkheadConfig,
+    start_time: Instant,
+) -> F::Output {
+    let permit = HeldPermit {
+        permit: Some(permit),
+        released: &config.released,
+    };
+
+    let Some(max_hold) = config.max_permit_hold else {
+        let output = future.await;
+        drop(permit);
+        return output;
+    };
+
+    let mut permit = Some(permit);
+    let future = std::pin::pin!(future);
+    let timer = std::pin::pin!(tokio::time::sleep(max_hold));
+    let future = match futures::future::select(future, timer).await {
+        futures::future::Either::Left((output, _)) => return output,
+        futures::future::Either::Right(((), future)) => future,
+    };
+
+    if config.release_held_permits {
+        permit = None;
+    }
+    let held = start_time.elapsed();
+
+    let event = BulkheadEvent::PermitHeldTooLong {
+        pattern_name: config.name.clone(),
+        timestamp: Instant::now(),
+        held,
+        released: permit.is_none(),
+    };
+    config.event_listeners.emit(&event);
+
+    #[cfg(feature = "metrics")]
+    counter!("resilience_bulkhead_permit_held_too_long_total", "name" => config.name.clone())
+        .increment(1);
+
+    let output = future.await;
+    drop(permit);
+    output
+}
+
+/// Waits for `acquire`, reporting calls that are still waiting after
+/// `starvation_threshold`.
+async fn watch_starvation<F: Future>(acquire: F, config: &BulkheadConfig) -> F::Output {
+    let Some(threshold) = config.starvation_threshold else {
+        return acquire.await;
+    };
+
+    let waiting_since = Instant::now();
+    let acquire = std::pin::pin!(acquire);
+    let timer = std::pin::pin!(tokio::time::sleep(threshold));
+    let acquire = match futures::future::select(acquire, timer).await {
+        futures::future::Either::Left((output, _)) => return output,
+        futures::future::Either::Right(((), acquire)) => acquire,
+    };
+
+    let event = BulkheadEvent::WaiterStarved {
+        pattern_name: config.name.clone(),
+        timestamp: Instant::now(),
+        waited: waiting_since.elapsed(),
+        max_concurrent_calls: config.max_concurrent_calls(),
+    };
+    config.event_listeners.emit(&event);
+
+    #[cfg(feature = "metrics")]
+    counter!("resilience_bulkhead_waiters_starved_total", "name" => config.name.clone())
+        .increment(1);
+
+    acquire.await
+}
+
 /// Bulkhead service that limits concurrent calls.
 pub struct Bulkhead<S> {
     inner: S,
@@ -67,7 +162,7 @@ impl<S: Clone> Clone for Bulkhead<S> {
 impl<S> Bulkhead<S> {
     /// Creates a new bulkhead service.
     pub(crate) fn new(inner: S, config: BulkheadConfig) -> Self {
-        let semaphore = Arc::new(Semaphore::new(config.max_concurrent_calls));
+        let semaphore = Arc::new(Semaphore::new(config.max_concurrent_calls()));
         Self {
             inner,
             semaphore,
@@ -93,6 +188,26 @@ impl<S> Bulkhead<S> {
     }
 }
 
+/// Reports the fraction of the bulkhead's capacity in use, from `0.0` (idle)
+/// to `1.0` (full).
+///
+/// This lets bulkheaded replicas be balanced directly with
+/// `tower::balance::p2c`, which sends each request to the less loaded of two
+/// randomly chosen replicas. Because the load is relative to each replica's
+/// own limit, replicas with different capacities compare fairly.
+impl<S> Load for Bulkhead<S> {
+    type Metric = f64;
+
+    fn load(&self) -> Self::Metric {
+        let capacity = self.config.max_concurrent_calls();
+        if capacity == 0 {
+            return 1.0;
+        }
+        let in_flight = capacity.saturating_sub(self.semaphore.available_permits());
+        in_flight as f64 / capacity as f64
+    }
+}
+
 impl<S, Request> Service<Request> for Bulkhead<S>
 where
     S: Service<Request> + Clone + Send + 'static,
@@ -132,7 +247,10 @@ where
                 Err(tokio::sync::TryAcquireError::NoPermits) => {
                     // Spawn a task to wait in the semaphore's FIFO queue.
                     let sem = Arc::clone(&self.semaphore);
-                    let handle = tokio::spawn(async move { sem.acquire_owned().await });
+                    let config = Arc::clone(&self.config);
+                    let handle = tokio::spawn(async move {
+                        watch_starvation(sem.acquire_owned(), &config).await
+                    });
                     self.acquire_task = Some(AbortOnDrop { handle });
                 }
                 Err(tokio::sync::TryAcquireError::Closed) => {
@@ -170,6 +288,34 @@ where
     }
 
     fn call(&mut self, request: Request) -> Self::Future {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tower_resilience_core::span::pattern_span("bulkhead", &self.config.name);
+            let future = span.in_scope(|| self.call_untraced(request));
+            Box::pin(tower_resilience_core::span::instrument(
+                span,
+                future,
+                tower_resilience_core::span::Outcome::from_result,
+            ))
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.call_untraced(request)
+    }
+}
+
+impl<S> Bulkhead<S> {
+    fn call_untraced<Request>(
+        &mut self,
+        request: Request,
+    ) -> BoxFuture<'static, Result<S::Response, BulkheadServiceError<S::Error>>>
+    where
+        S: Service<Request> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        S::Response: Send + 'static,
+        S::Error: Send + 'static,
+        Request: Send + 'static,
+    {
         if let Some(permit) = self.permit.take() {
             // Backpressure mode: permit already acquired in poll_ready
             let semaphore_for_check = Arc::clone(&self.semaphore);
@@ -179,8 +325,9 @@ where
             let start_time = Instant::now();
 
             // Emit call permitted event
-            let concurrent_calls =
-                config.max_concurrent_calls - semaphore_for_check.available_permits();
+            let concurrent_calls = config
+                .max_concurrent_calls()
+                .saturating_sub(semaphore_for_check.available_permits());
             let event = BulkheadEvent::CallPermitted {
                 pattern_name: config.name.clone(),
                 timestamp: Instant::now(),
@@ -190,17 +337,16 @@ where
 
             #[cfg(feature = "metrics")]
             {
-                counter!("bulkhead_calls_permitted_total", "bulkhead" => config.name.clone())
+                counter!("resilience_bulkhead_calls_permitted_total", "name" => config.name.clone())
                     .increment(1);
-                gauge!("bulkhead_concurrent_calls", "bulkhead" => config.name.clone())
+                gauge!("resilience_bulkhead_concurrent_calls", "name" => config.name.clone())
                     .set(concurrent_calls as f64);
-                histogram!("bulkhead_wait_duration_seconds", "bulkhead" => config.name.clone())
+                histogram!("resilience_bulkhead_wait_duration_seconds", "name" => config.name.clone())
                     .record(0.0);
             }
 
             return Box::pin(async move {
-                let result = inner.call(request).await;
-                drop(permit);
+                let result = hold_permit(inner.call(request), permit, &config, start_time).await;
                 let duration = start_time.elapsed();
 
                 match &result {
@@ -214,9 +360,9 @@ where
 
                         #[cfg(feature = "metrics")]
                         {
-                            counter!("bulkhead_calls_finished_total", "bulkhead" => config.name.clone())
+                            counter!("resilience_bulkhead_calls_finished_total", "name" => config.name.clone())
                                 .increment(1);
-                            histogram!("bulkhead_call_duration_seconds", "bulkhead" => config.name.clone())
+                            histogram!("resilience_bulkhead_call_duration_seconds", "name" => config.name.clone())
                                 .record(duration.as_secs_f64());
                         }
                     }
@@ -230,9 +376,9 @@ where
 
                         #[cfg(feature = "metrics")]
                         {
-                            counter!("bulkhead_calls_failed_total", "bulkhead" => config.name.clone())
+                            counter!("resilience_bulkhead_calls_failed_total", "name" => config.name.clone())
                                 .increment(1);
-                            histogram!("bulkhead_call_duration_seconds", "bulkhead" => config.name.clone())
+                            histogram!("resilience_bulkhead_call_duration_seconds", "name" => config.name.clone())
                                 .record(duration.as_secs_f64());
                         }
                     }
@@ -240,9 +386,10 @@ where
 
                 #[cfg(feature = "metrics")]
                 {
-                    let new_concurrent =
-                        config.max_concurrent_calls - semaphore_for_check.available_permits();
-                    gauge!("bulkhead_concurrent_calls", "bulkhead" => config.name.clone())
+                    let new_concurrent = config
+                        .max_concurrent_calls()
+                        .saturating_sub(semaphore_for_check.available_permits());
+                    gauge!("resilience_bulkhead_concurrent_calls", "name" => config.name.clone())
                         .set(new_concurrent as f64);
                 }
 
@@ -261,27 +408,39 @@ where
         #[cfg(feature = "metrics")]
         let acquire_start = Instant::now();
 
+        let reserved = config.reserved_for(&request);
+
         Box::pin(async move {
-            // Try to acquire a permit
+            // Try to acquire a permit, leaving reserved capacity to higher priorities
+            let acquire = watch_starvation(
+                async {
+                    if reserved == 0 {
+                        semaphore.acquire_owned().await
+                    } else {
+                        acquire_unreserved(semaphore, reserved, &config.released).await
+                    }
+                },
+                &config,
+            );
             let permit = match config.max_wait_duration {
                 Some(duration) => {
-                    match tokio::time::timeout(duration, semaphore.acquire_owned()).await {
+                    match tokio::time::timeout(duration, acquire).await {
                         Ok(Ok(permit)) => permit,
                         Ok(Err(_)) => {
                             // Semaphore was closed, shouldn't happen in normal operation
                             let event = BulkheadEvent::CallRejected {
                                 pattern_name: config.name.clone(),
                                 timestamp: Instant::now(),
-                                max_concurrent_calls: config.max_concurrent_calls,
+                                max_concurrent_calls: config.max_concurrent_calls(),
                             };
                             config.event_listeners.emit(&event);
 
                             #[cfg(feature = "metrics")]
-                            counter!("bulkhead_calls_rejected_total", "bulkhead" => config.name.clone())
+                            counter!("resilience_bulkhead_calls_rejected_total", "name" => config.name.clone())
                                 .increment(1);
 
                             return Err(BulkheadError::BulkheadFull {
-                                max_concurrent_calls: config.max_concurrent_calls,
+                                max_concurrent_calls: config.max_concurrent_calls(),
                             }
                             .into());
                         }
@@ -290,12 +449,12 @@ where
                             let event = BulkheadEvent::CallRejected {
                                 pattern_name: config.name.clone(),
                                 timestamp: Instant::now(),
-                                max_concurrent_calls: config.max_concurrent_calls,
+                                max_concurrent_calls: config.max_concurrent_calls(),
                             };
                             config.event_listeners.emit(&event);
 
                             #[cfg(feature = "metrics")]
-                            counter!("bulkhead_calls_rejected_total", "bulkhead" => config.name.clone())
+                            counter!("resilience_bulkhead_calls_rejected_total", "name" => config.name.clone())
                                 .increment(1);
 
                             return Err(BulkheadError::Timeout.into());
@@ -304,23 +463,23 @@ where
                 }
                 None => {
                     // Wait indefinitely
-                    match semaphore.acquire_owned().await {
+                    match acquire.await {
                         Ok(permit) => permit,
                         Err(_) => {
                             // Semaphore was closed
                             let event = BulkheadEvent::CallRejected {
                                 pattern_name: config.name.clone(),
                                 timestamp: Instant::now(),
-                                max_concurrent_calls: config.max_concurrent_calls,
+                                max_concurrent_calls: config.max_concurrent_calls(),
                             };
                             config.event_listeners.emit(&event);
 
                             #[cfg(feature = "metrics")]
-                            counter!("bulkhead_calls_rejected_total", "bulkhead" => config.name.clone())
+                            counter!("resilience_bulkhead_calls_rejected_total", "name" => config.name.clone())
                                 .increment(1);
 
                             return Err(BulkheadError::BulkheadFull {
-                                max_concurrent_calls: config.max_concurrent_calls,
+                                max_concurrent_calls: config.max_concurrent_calls(),
                             }
                             .into());
                         }
@@ -329,8 +488,9 @@ where
             };
 
             // Emit call permitted event
-            let concurrent_calls =
-                config.max_concurrent_calls - semaphore_for_check.available_permits();
+            let concurrent_calls = config
+                .max_concurrent_calls()
+                .saturating_sub(semaphore_for_check.available_permits());
             let event = BulkheadEvent::CallPermitted {
                 pattern_name: config.name.clone(),
                 timestamp: Instant::now(),
@@ -341,19 +501,16 @@ where
             #[cfg(feature = "metrics")]
             {
                 let wait_duration = acquire_start.elapsed();
-                counter!("bulkhead_calls_permitted_total", "bulkhead" => config.name.clone())
+                counter!("resilience_bulkhead_calls_permitted_total", "name" => config.name.clone())
                     .increment(1);
-                gauge!("bulkhead_concurrent_calls", "bulkhead" => config.name.clone())
+                gauge!("resilience_bulkhead_concurrent_calls", "name" => config.name.clone())
                     .set(concurrent_calls as f64);
-                histogram!("bulkhead_wait_duration_seconds", "bulkhead" => config.name.clone())
+                histogram!("resilience_bulkhead_wait_duration_seconds", "name" => config.name.clone())
                     .record(wait_duration.as_secs_f64());
             }
 
-            // Call the inner service
-            let result = inner.call(request).await;
-
-            // Drop the permit to release the slot
-            drop(permit);
+            // Call the inner service, releasing the slot when it completes
+            let result = hold_permit(inner.call(request), permit, &config, start_time).await;
 
             let duration = start_time.elapsed();
 
@@ -369,9 +526,9 @@ where
 
                     #[cfg(feature = "metrics")]
                     {
-                        counter!("bulkhead_calls_finished_total", "bulkhead" => config.name.clone())
+                        counter!("resilience_bulkhead_calls_finished_total", "name" => config.name.clone())
                             .increment(1);
-                        histogram!("bulkhead_call_duration_seconds", "bulkhead" => config.name.clone())
+                        histogram!("resilience_bulkhead_call_duration_seconds", "name" => config.name.clone())
                             .record(duration.as_secs_f64());
                     }
                 }
@@ -385,9 +542,9 @@ where
 
                     #[cfg(feature = "metrics")]
                     {
-                        counter!("bulkhead_calls_failed_total", "bulkhead" => config.name.clone())
+                        counter!("resilience_bulkhead_calls_failed_total", "name" => config.name.clone())
                             .increment(1);
-                        histogram!("bulkhead_call_duration_seconds", "bulkhead" => config.name.clone())
+                        histogram!("resilience_bulkhead_call_duration_seconds", "name" => config.name.clone())
                             .record(duration.as_secs_f64());
                     }
                 }
@@ -395,9 +552,10 @@ where
 
             #[cfg(feature = "metrics")]
             {
-                let new_concurrent =
-                    config.max_concurrent_calls - semaphore_for_check.available_permits();
-                gauge!("bulkhead_concurrent_calls", "bulkhead" => config.name.clone())
+                let new_concurrent = config
+                    .max_concurrent_calls()
+                    .saturating_sub(semaphore_for_check.available_permits());
+                gauge!("resilience_bulkhead_concurrent_calls", "name" => config.name.clone())
                     .set(new_concurrent as f64);
             }
 
@@ -0,0 +1,89 @@
+//! Bulkhead settings that can be loaded from configuration files.
+
+use crate::config::BulkheadConfigBuilder;
+use crate::layer::BulkheadLayer;
+use std::time::Duration;
+
+/// Plain-data bulkhead settings, deserializable with the `serde` feature.
+///
+/// Every field is optional; unset fields keep the builder defaults. Durations
+/// accept milliseconds or strings such as `"5s"` (see
+/// [`parse_duration`](tower_resilience_core::settings::parse_duration)).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tower_resilience_bulkhead::{BulkheadLayer, BulkheadSettings};
+///
+/// let settings = BulkheadSettings {
+///     max_concurrent_calls: Some(10),
+///     max_wait_duration: Some(Duration::from_millis(500)),
+///     ..Default::default()
+/// };
+///
+/// let layer = BulkheadLayer::from_config(settings);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct BulkheadSettings {
+    /// Instance name used in events and metrics.
+    pub name: Option<String>,
+    /// Maximum number of concurrent calls.
+    pub max_concurrent_calls: Option<usize>,
+    /// Maximum time to wait for a permit; zero rejects immediately when full.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub max_wait_duration: Option<Duration>,
+    /// Make `poll_ready` wait for a permit instead of rejecting calls.
+    pub backpressure: Option<bool>,
+}
+
+impl BulkheadConfigBuilder {
+    /// Applies every field that is set in `settings`, leaving the rest unchanged.
+    pub fn settings(mut self, settings: BulkheadSettings) -> Self {
+        if let Some(name) = settings.name {
+            self = self.name(name);
+        }
+        if let Some(max) = settings.max_concurrent_calls {
+            self = self.max_concurrent_calls(max);
+        }
+        if let Some(duration) = settings.max_wait_duration {
+            self = self.max_wait_duration(duration);
+        }
+        if settings.backpressure == Some(true) {
+            self = self.backpressure();
+        }
+        self
+    }
+}
+
+impl BulkheadLayer {
+    /// Creates a bulkhead layer from [`BulkheadSettings`].
+    pub fn from_config(settings: BulkheadSettings) -> Self {
+        Self::builder().settings(settings).build()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_settings() {
+        let settings: BulkheadSettings = serde_json::from_str(
+            r#"{"name": "db", "max_concurrent_calls": 8, "max_wait_duration": "2s"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.name.as_deref(), Some("db"));
+        assert_eq!(settings.max_concurrent_calls, Some(8));
+        assert_eq!(settings.max_wait_duration, Some(Duration::from_secs(2)));
+        assert_eq!(settings.backpressure, None);
+
+        let _layer = BulkheadLayer::from_config(settings);
+    }
+}
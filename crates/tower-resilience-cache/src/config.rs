@@ -2,10 +2,12 @@
 
 use crate::events::CacheEvent;
 use crate::eviction::EvictionPolicy;
+use crate::vary::VaryKey;
+use std::fmt;
 use std::hash::Hash;
 use std::sync::Arc;
 use std::time::Duration;
-use tower_resilience_core::{EventListeners, FnListener};
+use tower_resilience_core::{Clock, EventListeners, FnListener, SharedClock, SystemClock};
 
 /// Function that extracts a cache key from a request.
 pub type KeyExtractor<Req, K> = Arc<dyn Fn(&Req) -> K + Send + Sync>;
@@ -14,20 +16,24 @@ pub type KeyExtractor<Req, K> = Arc<dyn Fn(&Req) -> K + Send + Sync>;
 pub struct CacheConfig<Req, K> {
     pub(crate) max_size: usize,
     pub(crate) ttl: Option<Duration>,
+    pub(crate) refresh_ahead: Option<f64>,
     pub(crate) eviction_policy: EvictionPolicy,
     pub(crate) key_extractor: KeyExtractor<Req, K>,
     pub(crate) event_listeners: EventListeners<CacheEvent>,
     pub(crate) name: String,
+    pub(crate) clock: SharedClock,
 }
 
 /// Builder for configuring and constructing a cache.
 pub struct CacheConfigBuilder<Req, K> {
     max_size: usize,
     ttl: Option<Duration>,
+    refresh_ahead: Option<f64>,
     eviction_policy: EvictionPolicy,
     key_extractor: Option<KeyExtractor<Req, K>>,
     event_listeners: EventListeners<CacheEvent>,
     name: String,
+    clock: SharedClock,
 }
 
 impl<Req, K> CacheConfigBuilder<Req, K>
@@ -39,10 +45,12 @@ where
         Self {
             max_size: 100,
             ttl: None,
+            refresh_ahead: None,
             eviction_policy: EvictionPolicy::default(),
             key_extractor: None,
             event_listeners: EventListeners::new(),
             name: String::from("<unnamed>"),
+            clock: Arc::new(SystemClock),
         }
     }
 
@@ -63,6 +71,37 @@ where
         self
     }
 
+    /// Refreshes hot entries in the background before they expire.
+    ///
+    /// When a hit lands within the last `fraction` of an entry's TTL, the
+    /// cached value is returned and the request is also sent to the inner
+    /// service in the background. The fresh response replaces the entry, so
+    /// frequently used keys never expire and callers do not all stall on the
+    /// same miss. Only one refresh per entry is in flight at a time; if it
+    /// fails, the next hit tries again.
+    ///
+    /// `fraction` is clamped to `0.0..=1.0`. Requires [`ttl`](Self::ttl) and
+    /// a Tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_cache::CacheLayer;
+    /// use std::time::Duration;
+    ///
+    /// // Refresh entries hit during the last 12 seconds of their minute
+    /// let cache = CacheLayer::<String, String>::builder()
+    ///     .ttl(Duration::from_secs(60))
+    ///     .refresh_ahead(0.2)
+    ///     .key_extractor(|req| req.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn refresh_ahead(mut self, fraction: f64) -> Self {
+        self.refresh_ahead = Some(fraction);
+        self
+    }
+
     /// Sets the eviction policy for the cache.
     ///
     /// Determines which entry to evict when the cache reaches capacity.
@@ -251,6 +290,41 @@ where
         self
     }
 
+    /// Registers a callback when a background refresh starts.
+    ///
+    /// # Callback Signature
+    /// `Fn()` - Called with no parameters when a hit triggers a refresh
+    /// (see [`refresh_ahead`](Self::refresh_ahead)).
+    pub fn on_refresh<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, CacheEvent::Refresh { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Sets the clock used to measure entry age for TTL expiration.
+    ///
+    /// Defaults to the system clock. Pass a [`MockClock`](tower_resilience_core::MockClock)
+    /// to expire entries from tests without sleeping.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the cache layer.
     ///
     /// # Errors
@@ -265,16 +339,55 @@ where
         let config = CacheConfig {
             max_size: self.max_size,
             ttl: self.ttl,
+            refresh_ahead: self.refresh_ahead,
             eviction_policy: self.eviction_policy,
             key_extractor,
             event_listeners: self.event_listeners,
             name: self.name,
+            clock: self.clock,
         };
 
         Ok(crate::CacheLayer::new(config))
     }
 }
 
+impl<Req: 'static> CacheConfigBuilder<Req, VaryKey> {
+    /// Adds a part of the request to the cache key.
+    ///
+    /// Each call composes another extractor into a [`VaryKey`], so requests
+    /// share a cache entry only when every selected part is equal. Parts keep
+    /// their own types and are compared individually, so there is no need to
+    /// concatenate them into a string and risk collisions. Parts are appended
+    /// to the key from [`key_extractor`](Self::key_extractor), if one is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_cache::{CacheLayer, VaryKey};
+    ///
+    /// struct Request { path: String, language: Option<String> }
+    ///
+    /// let cache = CacheLayer::<Request, VaryKey>::builder()
+    ///     .vary_by(|req| req.path.clone())
+    ///     .vary_by(|req| req.language.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn vary_by<F, P>(mut self, f: F) -> Self
+    where
+        F: Fn(&Req) -> P + Send + Sync + 'static,
+        P: Hash + Eq + fmt::Debug + Send + Sync + 'static,
+    {
+        let base = self.key_extractor.take();
+        self.key_extractor = Some(Arc::new(move |req: &Req| {
+            let mut key = base.as_ref().map(|base| base(req)).unwrap_or_default();
+            key.push(f(req));
+            key
+        }));
+        self
+    }
+}
+
 impl<Req, K> Default for CacheConfigBuilder<Req, K>
 where
     K: Hash + Eq + Clone + Send + 'static,
@@ -1,6 +1,7 @@
 //! Error types for cache.
 
 use std::fmt;
+use tower_resilience_core::PatternError;
 
 /// Errors that can occur in the cache.
 #[derive(Debug)]
@@ -25,6 +26,24 @@ impl<E: std::error::Error + 'static> std::error::Error for CacheError<E> {
     }
 }
 
+impl<E> PatternError for CacheError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        false
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            CacheError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        Ok(self.into_inner())
+    }
+}
+
 impl<E> CacheError<E> {
     /// Converts this error into the inner error.
     pub fn into_inner(self) -> E {
@@ -5,12 +5,18 @@ use tower_resilience_core::ResilienceEvent;
 
 /// Events emitted by the cache.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum CacheEvent {
     /// A cache hit occurred.
     Hit {
         /// The name of the cache instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
     /// A cache miss occurred.
@@ -18,6 +24,10 @@ pub enum CacheEvent {
         /// The name of the cache instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
     /// An entry was evicted from the cache.
@@ -25,6 +35,21 @@ pub enum CacheEvent {
         /// The name of the cache instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A hit on an entry close to expiry started a background refresh.
+    Refresh {
+        /// The name of the cache instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 }
@@ -35,6 +60,7 @@ impl ResilienceEvent for CacheEvent {
             CacheEvent::Hit { .. } => "cache_hit",
             CacheEvent::Miss { .. } => "cache_miss",
             CacheEvent::Eviction { .. } => "cache_eviction",
+            CacheEvent::Refresh { .. } => "cache_refresh",
         }
     }
 
@@ -42,7 +68,8 @@ impl ResilienceEvent for CacheEvent {
         match self {
             CacheEvent::Hit { timestamp, .. }
             | CacheEvent::Miss { timestamp, .. }
-            | CacheEvent::Eviction { timestamp, .. } => *timestamp,
+            | CacheEvent::Eviction { timestamp, .. }
+            | CacheEvent::Refresh { timestamp, .. } => *timestamp,
         }
     }
 
@@ -50,7 +77,8 @@ impl ResilienceEvent for CacheEvent {
         match self {
             CacheEvent::Hit { pattern_name, .. }
             | CacheEvent::Miss { pattern_name, .. }
-            | CacheEvent::Eviction { pattern_name, .. } => pattern_name,
+            | CacheEvent::Eviction { pattern_name, .. }
+            | CacheEvent::Refresh { pattern_name, .. } => pattern_name,
         }
     }
 }
@@ -81,5 +109,11 @@ mod tests {
             timestamp: now,
         };
         assert_eq!(eviction.event_type(), "cache_eviction");
+
+        let refresh = CacheEvent::Refresh {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+        };
+        assert_eq!(refresh.event_type(), "cache_refresh");
     }
 }
@@ -49,6 +49,12 @@ pub(crate) trait EvictionStore<K, V>: Send {
     /// Clears all entries.
     fn clear(&mut self);
 
+    /// Returns all entries, next to be evicted first.
+    ///
+    /// Re-inserting the entries in this order into an empty store
+    /// reproduces the eviction order as closely as the policy allows.
+    fn entries(&self) -> Vec<(&K, &V)>;
+
     /// Returns true if the cache is empty.
     #[allow(dead_code)]
     fn is_empty(&self) -> bool {
@@ -90,6 +96,10 @@ impl<K: Hash + Eq + Send, V: Send> EvictionStore<K, V> for LruStore<K, V> {
     fn clear(&mut self) {
         self.cache.clear();
     }
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.cache.iter().rev().collect()
+    }
 }
 
 /// LFU (Least Frequently Used) cache storage.
@@ -165,6 +175,12 @@ impl<K: Hash + Eq + Clone + Send, V: Send> EvictionStore<K, V> for LfuStore<K, V
         self.data.clear();
         self.frequencies.clear();
     }
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        let mut entries: Vec<_> = self.data.iter().collect();
+        entries.sort_by_key(|(k, _)| self.frequencies.get(*k).copied().unwrap_or(0));
+        entries
+    }
 }
 
 /// FIFO (First In, First Out) cache storage.
@@ -226,6 +242,13 @@ impl<K: Hash + Eq + Clone + Send, V: Send> EvictionStore<K, V> for FifoStore<K,
         self.data.clear();
         self.order.clear();
     }
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.order
+            .iter()
+            .filter_map(|k| self.data.get_key_value(k))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -295,6 +318,28 @@ mod tests {
         assert_eq!(store.get(&"c"), Some(&3));
     }
 
+    #[test]
+    fn test_entries_in_eviction_order() {
+        let mut lru = LruStore::new(3);
+        lru.insert("a", 1);
+        lru.insert("b", 2);
+        lru.insert("c", 3);
+        lru.get(&"a");
+        assert_eq!(lru.entries(), vec![(&"b", &2), (&"c", &3), (&"a", &1)]);
+
+        let mut lfu = LfuStore::new(3);
+        lfu.insert("a", 1);
+        lfu.insert("b", 2);
+        lfu.get(&"a");
+        assert_eq!(lfu.entries(), vec![(&"b", &2), (&"a", &1)]);
+
+        let mut fifo = FifoStore::new(3);
+        fifo.insert("a", 1);
+        fifo.insert("b", 2);
+        fifo.get(&"a");
+        assert_eq!(fifo.entries(), vec![(&"a", &1), (&"b", &2)]);
+    }
+
     #[test]
     fn test_eviction_policy_default() {
         assert_eq!(EvictionPolicy::default(), EvictionPolicy::Lru);
@@ -8,8 +8,11 @@
 //!
 //! - **Multiple Eviction Policies**: LRU, LFU, and FIFO eviction strategies
 //! - **TTL Support**: Optional time-to-live for cache entries
-//! - **Event System**: Observability through cache events (Hit, Miss, Eviction)
+//! - **Refresh Ahead**: Refresh hot entries in the background before they expire
+//! - **Event System**: Observability through cache events (Hit, Miss, Eviction, Refresh)
 //! - **Flexible Key Extraction**: User-defined key extraction from requests
+//! - **Vary-By Keys**: Compose keys from several request parts without collisions
+//! - **Warm Start**: Export and import cache contents across restarts
 //!
 //! # Examples
 //!
@@ -46,6 +49,7 @@ mod eviction;
 mod layer;
 mod shared_layer;
 mod store;
+mod vary;
 
 pub use config::{CacheConfig, CacheConfigBuilder, KeyExtractor};
 pub use error::{CacheBuildError, CacheError};
@@ -53,6 +57,7 @@ pub use events::CacheEvent;
 pub use eviction::EvictionPolicy;
 pub use layer::CacheLayer;
 pub use shared_layer::{SharedCacheConfigBuilder, SharedCacheLayer};
+pub use vary::VaryKey;
 
 use futures::future::BoxFuture;
 use std::hash::Hash;
@@ -92,18 +97,32 @@ where
         #[cfg(feature = "metrics")]
         {
             describe_counter!(
-                "cache_requests_total",
+                "resilience_cache_requests_total",
                 "Total number of cache requests (hits and misses)"
             );
-            describe_counter!("cache_evictions_total", "Total number of cache evictions");
-            describe_gauge!("cache_size", "Current number of entries in the cache");
+            describe_counter!(
+                "resilience_cache_evictions_total",
+                "Total number of cache evictions"
+            );
+            describe_counter!(
+                "resilience_cache_refreshes_total",
+                "Total number of background refreshes of entries near expiry"
+            );
+            describe_gauge!(
+                "resilience_cache_size",
+                "Current number of entries in the cache"
+            );
         }
 
-        let store = Arc::new(Mutex::new(CacheStore::new(
-            config.max_size,
-            config.ttl,
-            config.eviction_policy,
-        )));
+        let store = Arc::new(Mutex::new(
+            CacheStore::new(
+                config.max_size,
+                config.ttl,
+                config.eviction_policy,
+                Arc::clone(&config.clock),
+            )
+            .with_refresh_ahead(config.refresh_ahead),
+        ));
         Self {
             inner,
             config,
@@ -123,11 +142,21 @@ where
         #[cfg(feature = "metrics")]
         {
             describe_counter!(
-                "cache_requests_total",
+                "resilience_cache_requests_total",
                 "Total number of cache requests (hits and misses)"
             );
-            describe_counter!("cache_evictions_total", "Total number of cache evictions");
-            describe_gauge!("cache_size", "Current number of entries in the cache");
+            describe_counter!(
+                "resilience_cache_evictions_total",
+                "Total number of cache evictions"
+            );
+            describe_counter!(
+                "resilience_cache_refreshes_total",
+                "Total number of background refreshes of entries near expiry"
+            );
+            describe_gauge!(
+                "resilience_cache_size",
+                "Current number of entries in the cache"
+            );
         }
 
         Self {
@@ -136,6 +165,29 @@ where
             store,
         }
     }
+
+    /// Returns a snapshot of the unexpired cache entries.
+    ///
+    /// Entries are ordered next to be evicted first, so passing the snapshot
+    /// to [`import`](Self::import) on a fresh cache preserves the eviction
+    /// order. Persist it on shutdown and reload it at startup to avoid serving
+    /// a deploy's worth of traffic from a cold cache.
+    pub fn export(&self) -> Vec<(K, Resp)> {
+        CacheStore::lock(&self.store).export()
+    }
+
+    /// Loads entries into the cache, typically from an earlier
+    /// [`export`](Self::export).
+    ///
+    /// Imported entries start a fresh TTL, and entries beyond
+    /// [`max_size`](CacheConfigBuilder::max_size) evict earlier ones as usual.
+    pub fn import(&self, entries: impl IntoIterator<Item = (K, Resp)>) {
+        let mut store = CacheStore::lock(&self.store);
+        store.import(entries);
+
+        #[cfg(feature = "metrics")]
+        gauge!("resilience_cache_size", "name" => self.config.name.clone()).set(store.len() as f64);
+    }
 }
 
 impl<S, Req, K, Resp> Clone for Cache<S, Req, K, Resp>
@@ -155,6 +207,7 @@ impl<S, Req, K> Service<Req> for Cache<S, Req, K, S::Response>
 where
     S: Service<Req>,
     S::Response: Clone + Send + 'static,
+    S::Error: 'static,
     K: Hash + Eq + Clone + Send + 'static,
     Req: Send + 'static,
     S::Future: Send + 'static,
@@ -168,20 +221,55 @@ where
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tower_resilience_core::span::pattern_span("cache", &self.config.name);
+            let future = span.in_scope(|| self.call_untraced(req));
+            Box::pin(tower_resilience_core::span::instrument(
+                span,
+                future,
+                tower_resilience_core::span::Outcome::from_result,
+            ))
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.call_untraced(req)
+    }
+}
+
+impl<S, Req, K> Cache<S, Req, K, S::Response>
+where
+    S: Service<Req>,
+    S::Response: Clone + Send + 'static,
+    S::Error: 'static,
+    K: Hash + Eq + Clone + Send + 'static,
+    Req: Send + 'static,
+    S::Future: Send + 'static,
+{
+    fn call_untraced(
+        &mut self,
+        req: Req,
+    ) -> BoxFuture<'static, Result<S::Response, CacheError<S::Error>>> {
         let key = (self.config.key_extractor)(&req);
         let cache_name = self.config.name.clone();
 
         // Check cache first
-        let cached = {
-            let mut store = self.store.lock().unwrap_or_else(|e| e.into_inner());
-            store.get(&key)
+        let (cached, refresh) = {
+            let mut store = CacheStore::lock(&self.store);
+            let cached = store.get(&key);
+            let refresh = cached.is_some() && store.claim_refresh(&key);
+            (cached, refresh)
         };
 
         if let Some(response) = cached {
+            if refresh {
+                self.refresh_in_background(req, key);
+            }
+
             // Cache hit
             #[cfg(feature = "metrics")]
             {
-                counter!("cache_requests_total", "cache" => cache_name.clone(), "result" => "hit")
+                counter!("resilience_cache_requests_total", "name" => cache_name.clone(), "result" => "hit")
                     .increment(1);
             }
 
@@ -199,7 +287,7 @@ where
         // Cache miss
         #[cfg(feature = "metrics")]
         {
-            counter!("cache_requests_total", "cache" => cache_name.clone(), "result" => "miss")
+            counter!("resilience_cache_requests_total", "name" => cache_name.clone(), "result" => "miss")
                 .increment(1);
         }
 
@@ -219,17 +307,20 @@ where
         Box::pin(async move {
             let response = future.await.map_err(CacheError::Inner)?;
 
-            // Store successful response in cache
+            // Store successful response in cache, cloning before taking the
+            // lock so a panicking `Clone` cannot poison it
+            let cached = response.clone();
             let was_evicted = {
-                let mut store = store.lock().unwrap_or_else(|e| e.into_inner());
+                let mut store = CacheStore::lock(&store);
                 let was_full = store.len() >= config.max_size;
-                store.insert(key, response.clone());
+                store.insert(key, cached);
 
                 // Update cache size gauge
                 #[cfg(feature = "metrics")]
                 {
                     let new_size = store.len();
-                    gauge!("cache_size", "cache" => config.name.clone()).set(new_size as f64);
+                    gauge!("resilience_cache_size", "name" => config.name.clone())
+                        .set(new_size as f64);
                 }
 
                 was_full
@@ -238,7 +329,8 @@ where
             if was_evicted {
                 #[cfg(feature = "metrics")]
                 {
-                    counter!("cache_evictions_total", "cache" => config.name.clone()).increment(1);
+                    counter!("resilience_cache_evictions_total", "name" => config.name.clone())
+                        .increment(1);
                 }
 
                 #[cfg(feature = "tracing")]
@@ -254,6 +346,37 @@ where
             Ok(response)
         })
     }
+
+    /// Sends `req` to the inner service on a background task and replaces the
+    /// entry for `key` with the response.
+    fn refresh_in_background(&mut self, req: Req, key: K) {
+        let future = self.inner.call(req);
+        let store = Arc::clone(&self.store);
+        let config = Arc::clone(&self.config);
+
+        #[cfg(feature = "metrics")]
+        counter!("resilience_cache_refreshes_total", "name" => config.name.clone()).increment(1);
+
+        #[cfg(feature = "tracing")]
+        debug!(cache = %config.name, "Refreshing entry ahead of expiry");
+
+        let event = CacheEvent::Refresh {
+            pattern_name: config.name.clone(),
+            timestamp: Instant::now(),
+        };
+        config.event_listeners.emit(&event);
+
+        tokio::spawn(async move {
+            let result = future.await;
+            let mut store = CacheStore::lock(&store);
+            match result {
+                Ok(response) => {
+                    store.insert(key, response);
+                }
+                Err(_) => store.release_refresh(&key),
+            }
+        });
+    }
 }
 
 #[cfg(test)]
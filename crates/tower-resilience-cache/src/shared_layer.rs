@@ -3,12 +3,14 @@
 use crate::events::CacheEvent;
 use crate::eviction::EvictionPolicy;
 use crate::store::CacheStore;
+use crate::vary::VaryKey;
 use crate::{Cache, CacheConfig, KeyExtractor};
+use std::fmt;
 use std::hash::Hash;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tower::Layer;
-use tower_resilience_core::{EventListeners, FnListener};
+use tower_resilience_core::{Clock, EventListeners, FnListener, SharedClock, SystemClock};
 
 /// A Tower [`Layer`] that applies response caching with a shared store.
 ///
@@ -82,11 +84,15 @@ where
 {
     /// Creates a new `SharedCacheLayer` with the given configuration.
     pub fn new(config: CacheConfig<Req, K>) -> Self {
-        let store = Arc::new(Mutex::new(CacheStore::new(
-            config.max_size,
-            config.ttl,
-            config.eviction_policy,
-        )));
+        let store = Arc::new(Mutex::new(
+            CacheStore::new(
+                config.max_size,
+                config.ttl,
+                config.eviction_policy,
+                Arc::clone(&config.clock),
+            )
+            .with_refresh_ahead(config.refresh_ahead),
+        ));
         Self {
             config: Arc::new(config),
             store,
@@ -97,11 +103,15 @@ where
     ///
     /// This is used by [`CacheLayer::shared()`](crate::CacheLayer::shared).
     pub(crate) fn from_config(config: Arc<CacheConfig<Req, K>>) -> Self {
-        let store = Arc::new(Mutex::new(CacheStore::new(
-            config.max_size,
-            config.ttl,
-            config.eviction_policy,
-        )));
+        let store = Arc::new(Mutex::new(
+            CacheStore::new(
+                config.max_size,
+                config.ttl,
+                config.eviction_policy,
+                Arc::clone(&config.clock),
+            )
+            .with_refresh_ahead(config.refresh_ahead),
+        ));
         Self { config, store }
     }
 
@@ -123,6 +133,41 @@ where
     pub fn builder() -> SharedCacheConfigBuilder<Req, K, Resp> {
         SharedCacheConfigBuilder::new()
     }
+
+    /// Returns a snapshot of the unexpired entries in the shared store.
+    ///
+    /// See [`Cache::export`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tower_resilience_cache::SharedCacheLayer;
+    ///
+    /// let layer: SharedCacheLayer<String, String, String> = SharedCacheLayer::builder()
+    ///     .key_extractor(|req: &String| req.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// layer.import(vec![("greeting".to_string(), "hello".to_string())]);
+    ///
+    /// // Later, e.g. on shutdown
+    /// let snapshot = layer.export();
+    /// assert_eq!(snapshot.len(), 1);
+    /// ```
+    pub fn export(&self) -> Vec<(K, Resp)> {
+        CacheStore::lock(&self.store).export()
+    }
+
+    /// Loads entries into the shared store.
+    ///
+    /// See [`Cache::import`].
+    pub fn import(&self, entries: impl IntoIterator<Item = (K, Resp)>) {
+        let mut store = CacheStore::lock(&self.store);
+        store.import(entries);
+
+        #[cfg(feature = "metrics")]
+        metrics::gauge!("resilience_cache_size", "name" => self.config.name.clone())
+            .set(store.len() as f64);
+    }
 }
 
 impl<S, Req, K, Resp> Layer<S> for SharedCacheLayer<Req, K, Resp>
@@ -142,10 +187,12 @@ where
 pub struct SharedCacheConfigBuilder<Req, K, Resp> {
     max_size: usize,
     ttl: Option<Duration>,
+    refresh_ahead: Option<f64>,
     eviction_policy: EvictionPolicy,
     key_extractor: Option<KeyExtractor<Req, K>>,
     event_listeners: EventListeners<CacheEvent>,
     name: String,
+    clock: SharedClock,
     _resp: std::marker::PhantomData<Resp>,
 }
 
@@ -159,10 +206,12 @@ where
         Self {
             max_size: 100,
             ttl: None,
+            refresh_ahead: None,
             eviction_policy: EvictionPolicy::default(),
             key_extractor: None,
             event_listeners: EventListeners::new(),
             name: String::from("<unnamed>"),
+            clock: Arc::new(SystemClock),
             _resp: std::marker::PhantomData,
         }
     }
@@ -184,6 +233,14 @@ where
         self
     }
 
+    /// Refreshes hot entries in the background before they expire.
+    ///
+    /// See [`CacheConfigBuilder::refresh_ahead`](crate::CacheConfigBuilder::refresh_ahead).
+    pub fn refresh_ahead(mut self, fraction: f64) -> Self {
+        self.refresh_ahead = Some(fraction);
+        self
+    }
+
     /// Sets the eviction policy for the cache.
     ///
     /// Determines which entry to evict when the cache reaches capacity.
@@ -258,6 +315,37 @@ where
         self
     }
 
+    /// Registers a callback when a background refresh starts.
+    pub fn on_refresh<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, CacheEvent::Refresh { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Sets the clock used to measure entry age for TTL expiration.
+    ///
+    /// Defaults to the system clock. Pass a [`MockClock`](tower_resilience_core::MockClock)
+    /// to expire entries from tests without sleeping.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the shared cache layer.
     ///
     /// # Errors
@@ -272,16 +360,58 @@ where
         let config = CacheConfig {
             max_size: self.max_size,
             ttl: self.ttl,
+            refresh_ahead: self.refresh_ahead,
             eviction_policy: self.eviction_policy,
             key_extractor,
             event_listeners: self.event_listeners,
             name: self.name,
+            clock: self.clock,
         };
 
         Ok(SharedCacheLayer::new(config))
     }
 }
 
+impl<Req: 'static, Resp> SharedCacheConfigBuilder<Req, VaryKey, Resp>
+where
+    Resp: Clone + Send + 'static,
+{
+    /// Adds a part of the request to the cache key.
+    ///
+    /// Each call composes another extractor into a [`VaryKey`], so requests
+    /// share a cache entry only when every selected part is equal. Parts keep
+    /// their own types and are compared individually, so there is no need to
+    /// concatenate them into a string and risk collisions. Parts are appended
+    /// to the key from [`key_extractor`](Self::key_extractor), if one is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_cache::{SharedCacheLayer, VaryKey};
+    ///
+    /// struct Request { path: String, language: Option<String> }
+    ///
+    /// let cache = SharedCacheLayer::<Request, VaryKey, String>::builder()
+    ///     .vary_by(|req| req.path.clone())
+    ///     .vary_by(|req| req.language.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn vary_by<F, P>(mut self, f: F) -> Self
+    where
+        F: Fn(&Req) -> P + Send + Sync + 'static,
+        P: Hash + Eq + fmt::Debug + Send + Sync + 'static,
+    {
+        let base = self.key_extractor.take();
+        self.key_extractor = Some(Arc::new(move |req: &Req| {
+            let mut key = base.as_ref().map(|base| base(req)).unwrap_or_default();
+            key.push(f(req));
+            key
+        }));
+        self
+    }
+}
+
 impl<Req, K, Resp> Default for SharedCacheConfigBuilder<Req, K, Resp>
 where
     K: Hash + Eq + Clone + Send + 'static,
@@ -1,8 +1,11 @@
 //! Cache storage implementation.
 
 use crate::eviction::{EvictionPolicy, EvictionStore, FifoStore, LfuStore, LruStore};
+use std::collections::HashSet;
 use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard};
 use std::time::{Duration, Instant};
+use tower_resilience_core::SharedClock;
 
 /// Entry in the cache with TTL tracking.
 #[derive(Clone, Debug)]
@@ -12,16 +15,13 @@ struct CacheEntry<V> {
 }
 
 impl<V> CacheEntry<V> {
-    fn new(value: V) -> Self {
-        Self {
-            value,
-            inserted_at: Instant::now(),
-        }
+    fn new(value: V, inserted_at: Instant) -> Self {
+        Self { value, inserted_at }
     }
 
-    fn is_expired(&self, ttl: Option<Duration>) -> bool {
+    fn is_expired(&self, ttl: Option<Duration>, now: Instant) -> bool {
         if let Some(ttl) = ttl {
-            self.inserted_at.elapsed() > ttl
+            now.saturating_duration_since(self.inserted_at) > ttl
         } else {
             false
         }
@@ -32,25 +32,72 @@ impl<V> CacheEntry<V> {
 pub(crate) struct CacheStore<K, V> {
     store: Box<dyn EvictionStore<K, CacheEntry<V>>>,
     ttl: Option<Duration>,
+    clock: SharedClock,
+    /// Age after which a hit triggers a background refresh.
+    refresh_after: Option<Duration>,
+    /// Keys with a background refresh in flight.
+    refreshing: HashSet<K>,
 }
 
 impl<K: Hash + Eq + Clone + Send + 'static, V: Clone + Send + 'static> CacheStore<K, V> {
     /// Creates a new cache store with the given capacity, TTL, and eviction policy.
-    pub(crate) fn new(capacity: usize, ttl: Option<Duration>, policy: EvictionPolicy) -> Self {
+    ///
+    /// Entry ages are measured on `clock`.
+    pub(crate) fn new(
+        capacity: usize,
+        ttl: Option<Duration>,
+        policy: EvictionPolicy,
+        clock: SharedClock,
+    ) -> Self {
         let store: Box<dyn EvictionStore<K, CacheEntry<V>>> = match policy {
             EvictionPolicy::Lru => Box::new(LruStore::new(capacity)),
             EvictionPolicy::Lfu => Box::new(LfuStore::new(capacity)),
             EvictionPolicy::Fifo => Box::new(FifoStore::new(capacity)),
         };
 
-        Self { store, ttl }
+        Self {
+            store,
+            ttl,
+            clock,
+            refresh_after: None,
+            refreshing: HashSet::new(),
+        }
+    }
+
+    /// Locks `store`, emptying it if the lock was poisoned.
+    ///
+    /// A panic while the lock was held, in a `Hash`, `Eq` or `Clone` impl for
+    /// example, may have left the entries half updated. Dropping the contents
+    /// of a cache is always safe, so the store starts over empty rather than
+    /// failing or serving from inconsistent state.
+    pub(crate) fn lock(store: &Mutex<Self>) -> MutexGuard<'_, Self> {
+        match store.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                let mut guard = poisoned.into_inner();
+                guard.clear();
+                store.clear_poison();
+                guard
+            }
+        }
+    }
+
+    /// Refreshes entries hit within the last `fraction` of their TTL.
+    ///
+    /// Has no effect without a TTL.
+    pub(crate) fn with_refresh_ahead(mut self, fraction: Option<f64>) -> Self {
+        self.refresh_after = match (self.ttl, fraction) {
+            (Some(ttl), Some(fraction)) => Some(ttl.mul_f64(1.0 - fraction.clamp(0.0, 1.0))),
+            _ => None,
+        };
+        self
     }
 
     /// Gets a value from the cache if it exists and is not expired.
     pub(crate) fn get(&mut self, key: &K) -> Option<V> {
         let entry = self.store.get(key)?;
 
-        if entry.is_expired(self.ttl) {
+        if entry.is_expired(self.ttl, self.clock.now()) {
             // Entry expired, remove it
             self.store.remove(key);
             None
@@ -59,33 +106,82 @@ impl<K: Hash + Eq + Clone + Send + 'static, V: Clone + Send + 'static> CacheStor
         }
     }
 
+    /// Claims the background refresh of `key` if its entry is due for one.
+    ///
+    /// Returns `true` at most once per entry until it is replaced with
+    /// [`insert`](Self::insert) or released with
+    /// [`release_refresh`](Self::release_refresh).
+    pub(crate) fn claim_refresh(&mut self, key: &K) -> bool {
+        let Some(refresh_after) = self.refresh_after else {
+            return false;
+        };
+        if self.refreshing.contains(key) {
+            return false;
+        }
+        let now = self.clock.now();
+        let due = self
+            .store
+            .get(key)
+            .is_some_and(|entry| now.saturating_duration_since(entry.inserted_at) >= refresh_after);
+        if due {
+            self.refreshing.insert(key.clone());
+        }
+        due
+    }
+
+    /// Gives up a refresh claimed with [`claim_refresh`](Self::claim_refresh).
+    pub(crate) fn release_refresh(&mut self, key: &K) {
+        self.refreshing.remove(key);
+    }
+
     /// Inserts a value into the cache.
     /// Returns the evicted entry if the cache was full.
     pub(crate) fn insert(&mut self, key: K, value: V) -> Option<V> {
-        let entry = CacheEntry::new(value);
+        self.refreshing.remove(&key);
+        let entry = CacheEntry::new(value, self.clock.now());
         self.store.insert(key, entry).map(|(_, e)| e.value)
     }
 
+    /// Returns the unexpired entries, next to be evicted first.
+    pub(crate) fn export(&self) -> Vec<(K, V)> {
+        let now = self.clock.now();
+        self.store
+            .entries()
+            .into_iter()
+            .filter(|(_, entry)| !entry.is_expired(self.ttl, now))
+            .map(|(key, entry)| (key.clone(), entry.value.clone()))
+            .collect()
+    }
+
+    /// Inserts `entries` in order, each with a fresh TTL.
+    pub(crate) fn import(&mut self, entries: impl IntoIterator<Item = (K, V)>) {
+        for (key, value) in entries {
+            self.insert(key, value);
+        }
+    }
+
     /// Returns the current number of entries in the cache.
     pub(crate) fn len(&self) -> usize {
         self.store.len()
     }
 
     /// Clears all entries from the cache.
-    #[allow(dead_code)]
     pub(crate) fn clear(&mut self) {
         self.store.clear();
+        self.refreshing.clear();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
     use std::thread::sleep;
+    use tower_resilience_core::{MockClock, SystemClock};
 
     #[test]
     fn test_cache_store_basic() {
-        let mut store = CacheStore::new(2, None, EvictionPolicy::Lru);
+        let mut store = CacheStore::new(2, None, EvictionPolicy::Lru, Arc::new(SystemClock));
 
         // Insert and retrieve
         store.insert("key1", "value1");
@@ -98,7 +194,7 @@ mod tests {
 
     #[test]
     fn test_cache_store_lru_eviction() {
-        let mut store = CacheStore::new(2, None, EvictionPolicy::Lru);
+        let mut store = CacheStore::new(2, None, EvictionPolicy::Lru, Arc::new(SystemClock));
 
         store.insert("key1", "value1");
         store.insert("key2", "value2");
@@ -114,7 +210,12 @@ mod tests {
 
     #[test]
     fn test_cache_store_ttl_expiration() {
-        let mut store = CacheStore::new(10, Some(Duration::from_millis(50)), EvictionPolicy::Lru);
+        let mut store = CacheStore::new(
+            10,
+            Some(Duration::from_millis(50)),
+            EvictionPolicy::Lru,
+            Arc::new(SystemClock),
+        );
 
         store.insert("key1", "value1");
         assert_eq!(store.get(&"key1"), Some("value1"));
@@ -126,9 +227,87 @@ mod tests {
         assert_eq!(store.get(&"key1"), None);
     }
 
+    #[test]
+    fn test_cache_store_ttl_with_mock_clock() {
+        let clock = MockClock::new();
+        let mut store = CacheStore::new(
+            10,
+            Some(Duration::from_secs(60)),
+            EvictionPolicy::Lru,
+            Arc::new(clock.clone()),
+        );
+
+        store.insert("key1", "value1");
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(store.get(&"key1"), Some("value1"));
+
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(store.get(&"key1"), None);
+    }
+
+    #[test]
+    fn test_cache_store_claim_refresh() {
+        let clock = MockClock::new();
+        let mut store = CacheStore::new(
+            10,
+            Some(Duration::from_secs(100)),
+            EvictionPolicy::Lru,
+            Arc::new(clock.clone()),
+        )
+        .with_refresh_ahead(Some(0.2));
+
+        store.insert("key1", "value1");
+        clock.advance(Duration::from_secs(79));
+        assert!(!store.claim_refresh(&"key1"));
+
+        // Within the last 20% of the TTL, only the first claim succeeds
+        clock.advance(Duration::from_secs(1));
+        assert!(store.claim_refresh(&"key1"));
+        assert!(!store.claim_refresh(&"key1"));
+
+        // A failed refresh can be retried
+        store.release_refresh(&"key1");
+        assert!(store.claim_refresh(&"key1"));
+
+        // Replacing the entry starts a new TTL
+        store.insert("key1", "value2");
+        assert!(!store.claim_refresh(&"key1"));
+    }
+
+    #[test]
+    fn test_cache_store_export_import() {
+        let clock = MockClock::new();
+        let mut store = CacheStore::new(
+            10,
+            Some(Duration::from_secs(60)),
+            EvictionPolicy::Lru,
+            Arc::new(clock.clone()),
+        );
+
+        store.insert("key1", "value1");
+        clock.advance(Duration::from_secs(30));
+        store.insert("key2", "value2");
+        clock.advance(Duration::from_secs(31));
+
+        // Expired entries are left out
+        let snapshot = store.export();
+        assert_eq!(snapshot, vec![("key2", "value2")]);
+
+        let mut restored = CacheStore::new(
+            10,
+            Some(Duration::from_secs(60)),
+            EvictionPolicy::Lru,
+            Arc::new(clock.clone()),
+        );
+        restored.import(snapshot);
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(restored.get(&"key2"), Some("value2"));
+    }
+
     #[test]
     fn test_cache_store_clear() {
-        let mut store = CacheStore::new(10, None, EvictionPolicy::Lru);
+        let mut store = CacheStore::new(10, None, EvictionPolicy::Lru, Arc::new(SystemClock));
 
         store.insert("key1", "value1");
         store.insert("key2", "value2");
@@ -138,4 +317,28 @@ mod tests {
         assert_eq!(store.len(), 0);
         assert_eq!(store.get(&"key1"), None);
     }
+
+    #[test]
+    fn test_lock_rebuilds_poisoned_store() {
+        let store = Mutex::new(CacheStore::new(
+            10,
+            None,
+            EvictionPolicy::Lru,
+            Arc::new(SystemClock),
+        ));
+        CacheStore::lock(&store).insert("key1", "value1");
+
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = store.lock().unwrap();
+            panic!("poison the lock");
+        });
+        assert!(store.is_poisoned());
+
+        let mut guard = CacheStore::lock(&store);
+        assert_eq!(guard.len(), 0);
+        guard.insert("key2", "value2");
+        drop(guard);
+        assert!(!store.is_poisoned());
+        assert_eq!(CacheStore::lock(&store).get(&"key2"), Some("value2"));
+    }
 }
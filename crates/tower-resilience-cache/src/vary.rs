@@ -0,0 +1,134 @@
+//! Composite cache keys built from several parts of a request.
+
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// A cache key composed of the request parts selected with
+/// [`CacheConfigBuilder::vary_by`](crate::CacheConfigBuilder::vary_by).
+///
+/// Each part keeps its own type and value, so keys compare part by part and
+/// never collide the way concatenated strings can (`"ab" + "c"` versus
+/// `"a" + "bc"`).
+#[derive(Clone, Default)]
+pub struct VaryKey {
+    parts: Vec<Arc<dyn KeyPart>>,
+}
+
+impl VaryKey {
+    /// Creates an empty key.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a part to the key.
+    pub fn push<P>(&mut self, part: P)
+    where
+        P: Hash + Eq + fmt::Debug + Send + Sync + 'static,
+    {
+        self.parts.push(Arc::new(part));
+    }
+
+    /// Number of parts in the key.
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Whether the key has no parts.
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+}
+
+impl PartialEq for VaryKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.parts.len() == other.parts.len()
+            && self
+                .parts
+                .iter()
+                .zip(&other.parts)
+                .all(|(a, b)| a.eq_part(b.as_ref()))
+    }
+}
+
+impl Eq for VaryKey {}
+
+impl Hash for VaryKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parts.len().hash(state);
+        for part in &self.parts {
+            part.hash_part(state);
+        }
+    }
+}
+
+impl fmt::Debug for VaryKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VaryKey")
+            .field(&self.parts.iter().map(|part| &**part).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Object-safe view of a key part.
+trait KeyPart: fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn eq_part(&self, other: &dyn KeyPart) -> bool;
+    fn hash_part(&self, state: &mut dyn Hasher);
+}
+
+impl<P> KeyPart for P
+where
+    P: Hash + Eq + fmt::Debug + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_part(&self, other: &dyn KeyPart) -> bool {
+        other.as_any().downcast_ref::<P>() == Some(self)
+    }
+
+    fn hash_part(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn key(parts: &[&'static str]) -> VaryKey {
+        let mut key = VaryKey::new();
+        for part in parts {
+            key.push(*part);
+        }
+        key
+    }
+
+    #[test]
+    fn test_keys_compare_part_by_part() {
+        assert_eq!(key(&["a", "b"]), key(&["a", "b"]));
+        assert_ne!(key(&["ab", "c"]), key(&["a", "bc"]));
+        assert_ne!(key(&["a"]), key(&["a", "b"]));
+    }
+
+    #[test]
+    fn test_parts_of_different_types_differ() {
+        let mut a = VaryKey::new();
+        a.push(1u32);
+        let mut b = VaryKey::new();
+        b.push(1u64);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_equal_keys_hash_equal() {
+        let set: HashSet<_> = [key(&["a", "b"]), key(&["a", "b"]), key(&["b", "a"])]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+}
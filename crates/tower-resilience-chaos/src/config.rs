@@ -1,10 +1,14 @@
 //! Configuration for chaos engineering layer.
 
+use crate::control::ChaosControl;
 use crate::events::ChaosEvent;
+#[cfg(feature = "pressure")]
+use crate::pressure::ResourcePressure;
+use crate::schedule::ChaosSchedule;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tower_resilience_core::{EventListeners, FnListener};
 
 /// Trait for error injection behavior.
@@ -17,6 +21,15 @@ pub trait ErrorInjector<Req, Err>: Send + Sync {
 
     /// Get the error rate for this injector.
     fn error_rate(&self) -> f64;
+
+    /// Generate an error regardless of the configured rate.
+    ///
+    /// Used when the rate is controlled elsewhere, such as by a
+    /// [`ChaosSchedule`] phase. `roll` is uniform in `0.0..1.0` and may be
+    /// used to choose among error variants.
+    fn generate_error(&self, req: &Req, _roll: f64) -> Option<Err> {
+        self.inject_error(req, f64::NEG_INFINITY)
+    }
 }
 
 /// No error injection - only latency chaos.
@@ -79,6 +92,192 @@ where
     fn error_rate(&self) -> f64 {
         self.rate
     }
+
+    fn generate_error(&self, req: &Req, _roll: f64) -> Option<Err> {
+        Some((self.f)(req))
+    }
+}
+
+type ErrorConstructor<Err> = Arc<dyn Fn() -> Err + Send + Sync>;
+
+/// Weighted catalog of error constructors.
+///
+/// When an error is injected, one variant is chosen in proportion to its
+/// weight, so the injected failure mix can resemble a production
+/// distribution instead of a single error value. Weights are relative and
+/// need not sum to 1.0; the overall injection rate is set separately with
+/// `error_rate()`.
+pub struct ErrorCatalog<Err> {
+    variants: Vec<(f64, ErrorConstructor<Err>)>,
+    rate: f64,
+}
+
+impl<Err> Clone for ErrorCatalog<Err> {
+    fn clone(&self) -> Self {
+        Self {
+            variants: self.variants.clone(),
+            rate: self.rate,
+        }
+    }
+}
+
+impl<Err> ErrorCatalog<Err> {
+    /// Create an empty catalog with the given injection rate.
+    pub fn new(rate: f64) -> Self {
+        Self {
+            variants: Vec::new(),
+            rate: rate.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Add an error constructor with the given relative weight.
+    ///
+    /// Variants with a non-positive weight are never chosen.
+    pub fn variant<F>(mut self, weight: f64, f: F) -> Self
+    where
+        F: Fn() -> Err + Send + Sync + 'static,
+    {
+        self.variants.push((weight.max(0.0), Arc::new(f)));
+        self
+    }
+
+    /// Returns the number of registered variants.
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// Returns true if no variants are registered.
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    /// Construct the variant selected by `roll` (uniform in `0.0..1.0`).
+    fn select(&self, roll: f64) -> Option<Err> {
+        let total: f64 = self.variants.iter().map(|(weight, _)| weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = roll.clamp(0.0, 1.0) * total;
+        let mut chosen = None;
+        for (weight, f) in &self.variants {
+            if *weight <= 0.0 {
+                continue;
+            }
+            chosen = Some(f);
+            if target < *weight {
+                break;
+            }
+            target -= weight;
+        }
+        chosen.map(|f| f())
+    }
+}
+
+impl<Req, Err> ErrorInjector<Req, Err> for ErrorCatalog<Err>
+where
+    Err: 'static,
+{
+    fn inject_error(&self, _req: &Req, roll: f64) -> Option<Err> {
+        if roll < self.rate {
+            // Reuse the roll, rescaled to 0.0..1.0, to pick the variant
+            self.select(roll / self.rate)
+        } else {
+            None
+        }
+    }
+
+    fn error_rate(&self) -> f64 {
+        self.rate
+    }
+
+    fn generate_error(&self, _req: &Req, roll: f64) -> Option<Err> {
+        self.select(roll)
+    }
+}
+
+/// Trait for response corruption behavior.
+///
+/// Corruptors transform successful responses into subtly wrong or truncated
+/// ones, so tests can verify that downstream validation and fallback logic
+/// catch bad data rather than only failed calls.
+pub trait ResponseCorruptor<Res>: Send + Sync {
+    /// Transform a successful response.
+    fn corrupt(&self, res: Res) -> Res;
+
+    /// Whether this corruptor modifies responses at all.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+}
+
+/// No response corruption.
+///
+/// This is the default response corruptor. Since it never modifies responses,
+/// it implements `ResponseCorruptor<Res>` for ALL types, enabling type
+/// inference at the point of use.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoResponseCorruption;
+
+impl<Res> ResponseCorruptor<Res> for NoResponseCorruption {
+    fn corrupt(&self, res: Res) -> Res {
+        res
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Custom response corruption function.
+///
+/// This corruptor calls a function to transform successful responses.
+pub struct CustomCorruptFn<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for CustomCorruptFn<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> CustomCorruptFn<F> {
+    /// Create a new custom response corruptor.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Res, F> ResponseCorruptor<Res> for CustomCorruptFn<F>
+where
+    F: Fn(Res) -> Res + Send + Sync + 'static,
+{
+    fn corrupt(&self, res: Res) -> Res {
+        (self.f)(res)
+    }
+}
+
+/// A predicate that must pass for chaos to be injected.
+type Guard = Arc<dyn Fn() -> bool + Send + Sync>;
+
+/// Returns true if the environment variable is set to an enabling value.
+///
+/// Unset variables and the values `""`, `"0"`, `"false"` and `"no"`
+/// (case-insensitive) are treated as disabled.
+fn env_enabled(var: &str) -> bool {
+    match std::env::var(var) {
+        Ok(value) => {
+            let value = value.trim();
+            !(value.is_empty()
+                || value == "0"
+                || value.eq_ignore_ascii_case("false")
+                || value.eq_ignore_ascii_case("no"))
+        }
+        Err(_) => false,
+    }
 }
 
 /// Configuration for the chaos engineering layer.
@@ -86,7 +285,10 @@ where
 /// The type parameter `E` is the error injector type:
 /// - `ChaosConfig<NoErrorInjection>` - latency-only chaos (works with any types)
 /// - `ChaosConfig<CustomErrorFn<F>>` - custom error injection
-pub struct ChaosConfig<E> {
+///
+/// The type parameter `C` is the response corruptor type, which defaults to
+/// `NoResponseCorruption`.
+pub struct ChaosConfig<E, C = NoResponseCorruption> {
     /// Name of this chaos layer instance for observability
     pub(crate) name: String,
     /// Error injector
@@ -97,13 +299,30 @@ pub struct ChaosConfig<E> {
     pub(crate) min_latency: Duration,
     /// Maximum latency to inject
     pub(crate) max_latency: Duration,
+    /// Response corruptor
+    pub(crate) response_corruptor: C,
+    /// Probability of corrupting a successful response (0.0 - 1.0)
+    pub(crate) corruption_rate: f64,
+    /// Optional schedule of time-bound chaos phases
+    pub(crate) schedule: Option<ChaosSchedule>,
+    /// Probability of blackholing a request (0.0 - 1.0)
+    pub(crate) blackhole_rate: f64,
+    /// Maximum time a blackholed request is held before being forwarded
+    pub(crate) blackhole_cap: Option<Duration>,
+    /// CPU and memory pressure applied to a fraction of requests
+    #[cfg(feature = "pressure")]
+    pub(crate) pressure: Option<ResourcePressure>,
+    /// Guards that must all pass for chaos to be injected
+    pub(crate) guards: Vec<Guard>,
     /// Optional seed for deterministic chaos
     pub(crate) seed: Option<u64>,
     /// Event listeners
     pub(crate) event_listeners: EventListeners<ChaosEvent>,
+    /// Runtime control handle
+    pub(crate) control: ChaosControl,
 }
 
-impl<E: Clone> Clone for ChaosConfig<E> {
+impl<E: Clone, C: Clone> Clone for ChaosConfig<E, C> {
     fn clone(&self) -> Self {
         Self {
             name: self.name.clone(),
@@ -111,13 +330,133 @@ impl<E: Clone> Clone for ChaosConfig<E> {
             latency_rate: self.latency_rate,
             min_latency: self.min_latency,
             max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor.clone(),
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule.clone(),
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards.clone(),
             seed: self.seed,
             event_listeners: self.event_listeners.clone(),
+            control: self.control.clone(),
         }
     }
 }
 
-impl<E> ChaosConfig<E> {
+/// Rates in effect for a single request.
+pub(crate) struct ChaosRates {
+    /// Error rate replacing the injector's own rate, if any
+    pub(crate) error_rate: Option<f64>,
+    pub(crate) latency_rate: f64,
+    pub(crate) min_latency: Duration,
+    pub(crate) max_latency: Duration,
+    pub(crate) corruption_rate: f64,
+    pub(crate) blackhole_rate: f64,
+    #[cfg(feature = "pressure")]
+    pub(crate) pressure: Option<ResourcePressure>,
+}
+
+impl ChaosRates {
+    fn none() -> Self {
+        Self {
+            error_rate: Some(0.0),
+            latency_rate: 0.0,
+            min_latency: Duration::ZERO,
+            max_latency: Duration::ZERO,
+            corruption_rate: 0.0,
+            blackhole_rate: 0.0,
+            #[cfg(feature = "pressure")]
+            pressure: None,
+        }
+    }
+}
+
+impl<E, C> ChaosConfig<E, C> {
+    /// Resolve the rates for a request.
+    ///
+    /// A failing guard disables all chaos. Otherwise, runtime control takes
+    /// precedence over the active schedule phase, which takes precedence over
+    /// the static rates.
+    pub(crate) fn current_rates(&self) -> ChaosRates {
+        if !self.guards_pass() {
+            return ChaosRates::none();
+        }
+
+        let mut rates = match &self.schedule {
+            Some(schedule) => self.scheduled_rates(schedule),
+            None => ChaosRates {
+                error_rate: None,
+                latency_rate: self.latency_rate,
+                min_latency: self.min_latency,
+                max_latency: self.max_latency,
+                corruption_rate: self.corruption_rate,
+                blackhole_rate: self.blackhole_rate,
+                #[cfg(feature = "pressure")]
+                pressure: self.pressure,
+            },
+        };
+
+        if self.control.is_paused() {
+            return ChaosRates::none();
+        }
+        if let Some(rate) = self.control.error_rate() {
+            rates.error_rate = Some(rate);
+        }
+        if let Some(rate) = self.control.latency_rate() {
+            rates.latency_rate = rate;
+        }
+        rates
+    }
+
+    /// Returns true if every guard passes.
+    pub(crate) fn guards_pass(&self) -> bool {
+        self.guards.iter().all(|guard| guard())
+    }
+
+    /// Advance the schedule, emitting an event if the phase changed.
+    fn scheduled_rates(&self, schedule: &ChaosSchedule) -> ChaosRates {
+        let advance = schedule.advance(Instant::now());
+        if let Some(from) = advance.changed {
+            let event = ChaosEvent::PhaseChanged {
+                pattern_name: self.name.clone(),
+                timestamp: Instant::now(),
+                from: from.map(str::to_string),
+                to: advance.phase.map(|p| p.name.clone()),
+            };
+            self.event_listeners.emit(&event);
+
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                chaos_layer = %self.name,
+                from = ?from,
+                to = ?advance.phase.map(|p| p.name.as_str()),
+                "chaos: phase changed"
+            );
+        }
+
+        match advance.phase {
+            Some(phase) => ChaosRates {
+                error_rate: Some(phase.error_rate),
+                latency_rate: phase.latency_rate,
+                min_latency: phase.min_latency,
+                max_latency: phase.max_latency,
+                corruption_rate: self.corruption_rate,
+                blackhole_rate: self.blackhole_rate,
+                #[cfg(feature = "pressure")]
+                pressure: self.pressure,
+            },
+            None => ChaosRates {
+                corruption_rate: self.corruption_rate,
+                blackhole_rate: self.blackhole_rate,
+                #[cfg(feature = "pressure")]
+                pressure: self.pressure,
+                ..ChaosRates::none()
+            },
+        }
+    }
+
     /// Get the RNG for this configuration.
     pub(crate) fn create_rng(&self) -> StdRng {
         match self.seed {
@@ -158,12 +497,20 @@ impl<E> ChaosConfig<E> {
 ///     .error_fn(|_req: &String| std::io::Error::other("chaos!"))
 ///     .build();
 /// ```
-pub struct ChaosConfigBuilder<E = NoErrorInjection> {
+pub struct ChaosConfigBuilder<E = NoErrorInjection, C = NoResponseCorruption> {
     name: String,
     error_injector: E,
     latency_rate: f64,
     min_latency: Duration,
     max_latency: Duration,
+    response_corruptor: C,
+    corruption_rate: f64,
+    schedule: Option<ChaosSchedule>,
+    blackhole_rate: f64,
+    blackhole_cap: Option<Duration>,
+    #[cfg(feature = "pressure")]
+    pressure: Option<ResourcePressure>,
+    guards: Vec<Guard>,
     seed: Option<u64>,
     event_listeners: EventListeners<ChaosEvent>,
 }
@@ -185,13 +532,21 @@ impl ChaosConfigBuilder<NoErrorInjection> {
             latency_rate: 0.0,
             min_latency: Duration::from_millis(10),
             max_latency: Duration::from_millis(100),
+            response_corruptor: NoResponseCorruption,
+            corruption_rate: 0.0,
+            schedule: None,
+            blackhole_rate: 0.0,
+            blackhole_cap: None,
+            #[cfg(feature = "pressure")]
+            pressure: None,
+            guards: Vec::new(),
             seed: None,
             event_listeners: EventListeners::new(),
         }
     }
 }
 
-impl<E> ChaosConfigBuilder<E> {
+impl<E, C> ChaosConfigBuilder<E, C> {
     /// Set the name of this chaos layer instance.
     ///
     /// # Example
@@ -223,7 +578,7 @@ impl<E> ChaosConfigBuilder<E> {
     ///     .error_fn(|_req: &String| std::io::Error::other("chaos!"))
     ///     .build();
     /// ```
-    pub fn error_fn<Req, Err, F>(self, f: F) -> ChaosConfigBuilder<CustomErrorFn<F>>
+    pub fn error_fn<Req, Err, F>(self, f: F) -> ChaosConfigBuilder<CustomErrorFn<F>, C>
     where
         F: Fn(&Req) -> Err + Send + Sync + 'static,
     {
@@ -233,6 +588,14 @@ impl<E> ChaosConfigBuilder<E> {
             latency_rate: self.latency_rate,
             min_latency: self.min_latency,
             max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor,
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
             seed: self.seed,
             event_listeners: self.event_listeners,
         }
@@ -288,6 +651,204 @@ impl<E> ChaosConfigBuilder<E> {
         self
     }
 
+    /// Set the response corruption rate (0.0 - 1.0).
+    ///
+    /// Only successful responses are corrupted, and only when combined with
+    /// `corrupt_response_fn()`.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .corruption_rate(0.05)  // 5% of responses corrupted
+    ///     .corrupt_response_fn(|resp: String| resp[..resp.len() / 2].to_string())
+    ///     .build();
+    /// ```
+    pub fn corruption_rate(mut self, rate: f64) -> Self {
+        self.corruption_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the response corruption function.
+    ///
+    /// At the configured `corruption_rate()`, successful responses from the
+    /// inner service are passed through this function before being returned,
+    /// letting chaos tests return subtly wrong or truncated data. Types are
+    /// inferred from the closure.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// // Drop the last element of every response
+    /// let layer = ChaosLayer::builder()
+    ///     .corruption_rate(1.0)
+    ///     .corrupt_response_fn(|mut resp: Vec<u8>| {
+    ///         resp.pop();
+    ///         resp
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn corrupt_response_fn<Res, F>(self, f: F) -> ChaosConfigBuilder<E, CustomCorruptFn<F>>
+    where
+        F: Fn(Res) -> Res + Send + Sync + 'static,
+    {
+        ChaosConfigBuilder {
+            name: self.name,
+            error_injector: self.error_injector,
+            latency_rate: self.latency_rate,
+            min_latency: self.min_latency,
+            max_latency: self.max_latency,
+            response_corruptor: CustomCorruptFn::new(f),
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
+            seed: self.seed,
+            event_listeners: self.event_listeners,
+        }
+    }
+
+    /// Set the blackhole rate (0.0 - 1.0).
+    ///
+    /// Blackholed requests never complete, simulating a network partition
+    /// where the peer silently drops traffic. Unlike error injection, this
+    /// exercises timeout and hedging behavior. Use `blackhole_cap()` to bound
+    /// how long a blackholed request hangs.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .blackhole_rate(0.05)  // 5% of requests hang
+    ///     .build();
+    /// ```
+    pub fn blackhole_rate(mut self, rate: f64) -> Self {
+        self.blackhole_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the maximum time a blackholed request hangs.
+    ///
+    /// Once the cap elapses, the request is forwarded to the inner service.
+    /// By default, blackholed requests stay pending forever.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .blackhole_rate(0.05)
+    ///     .blackhole_cap(Duration::from_secs(30))
+    ///     .build();
+    /// ```
+    pub fn blackhole_cap(mut self, cap: Duration) -> Self {
+        self.blackhole_cap = Some(cap);
+        self
+    }
+
+    /// Burn CPU and hold memory for a fraction of requests.
+    ///
+    /// Simulates a noisy neighbor exhausting the caller's resources, which
+    /// exercises adaptive limiters and bulkheads rather than error handling.
+    /// Pressure is applied in addition to injected latency; requests that
+    /// get an injected error are not affected.
+    ///
+    /// Requires the `pressure` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::{ChaosLayer, ResourcePressure};
+    /// use std::time::Duration;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .resource_pressure(ResourcePressure::new(0.05).cpu(Duration::from_millis(50)))
+    ///     .build();
+    /// ```
+    #[cfg(feature = "pressure")]
+    pub fn resource_pressure(mut self, pressure: ResourcePressure) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    /// Run a scripted scenario of time-bound chaos phases.
+    ///
+    /// While a schedule is set, the active phase's error and latency rates
+    /// replace the static rates configured on this builder.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::{ChaosLayer, ChaosPhase, ChaosSchedule};
+    /// use std::time::Duration;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .schedule(
+    ///         ChaosSchedule::new()
+    ///             .phase(ChaosPhase::new("baseline", Duration::from_secs(60)))
+    ///             .phase(
+    ///                 ChaosPhase::new("slow", Duration::from_secs(60))
+    ///                     .latency_rate(1.0)
+    ///                     .latency(Duration::from_millis(500)),
+    ///             ),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn schedule(mut self, schedule: ChaosSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Only inject chaos when the given environment variable is enabled.
+    ///
+    /// Unless the variable is set to a value other than `""`, `"0"`,
+    /// `"false"` or `"no"`, the layer passes every request through
+    /// unchanged. This enforces the "never in production" rule
+    /// programmatically. The variable is checked on every request.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// // No chaos unless ENABLE_CHAOS=1 (or similar) is set
+    /// let layer = ChaosLayer::builder()
+    ///     .latency_rate(0.2)
+    ///     .require_env("ENABLE_CHAOS")
+    ///     .build();
+    /// ```
+    pub fn require_env(self, var: impl Into<String>) -> Self {
+        let var = var.into();
+        self.guard(move || env_enabled(&var))
+    }
+
+    /// Only inject chaos while the given predicate returns true.
+    ///
+    /// When the guard fails, the layer passes every request through
+    /// unchanged. Multiple guards must all pass. Guards are checked on
+    /// every request, so they should be cheap.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .latency_rate(0.2)
+    ///     .guard(|| cfg!(debug_assertions))
+    ///     .build();
+    /// ```
+    pub fn guard<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.guards.push(Arc::new(f));
+        self
+    }
+
     /// Set a seed for deterministic chaos injection.
     ///
     /// Useful for reproducible tests.
@@ -357,6 +918,121 @@ impl<E> ChaosConfigBuilder<E> {
         self
     }
 
+    /// Add a listener for response corruption events.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .corruption_rate(0.1)
+    ///     .corrupt_response_fn(|_resp: String| String::new())
+    ///     .on_response_corrupted(|| {
+    ///         println!("Chaos: response corrupted!");
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_response_corrupted<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, ChaosEvent::ResponseCorrupted { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Add a listener for blackhole events.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .blackhole_rate(0.05)
+    ///     .on_blackholed(|| {
+    ///         println!("Chaos: request blackholed!");
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_blackholed<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, ChaosEvent::Blackholed { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Add a listener for resource pressure injection events.
+    ///
+    /// The listener receives the CPU time burned and the bytes held.
+    ///
+    /// Requires the `pressure` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::{ChaosLayer, ResourcePressure};
+    /// use std::time::Duration;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .resource_pressure(ResourcePressure::new(0.05).memory(1024 * 1024))
+    ///     .on_pressure_injected(|cpu: Duration, memory: usize| {
+    ///         println!("Chaos: burned {:?}, held {} bytes", cpu, memory);
+    ///     })
+    ///     .build();
+    /// ```
+    #[cfg(feature = "pressure")]
+    pub fn on_pressure_injected<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration, usize) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ChaosEvent::PressureInjected { cpu, memory, .. } = event {
+                f(*cpu, *memory);
+            }
+        }));
+        self
+    }
+
+    /// Add a listener for schedule phase changes.
+    ///
+    /// The listener receives the previous and new phase names. The previous
+    /// phase is `None` when the schedule starts, and the new phase is `None`
+    /// when the schedule completes.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::{ChaosLayer, ChaosPhase, ChaosSchedule};
+    /// use std::time::Duration;
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .schedule(
+    ///         ChaosSchedule::new()
+    ///             .phase(ChaosPhase::new("baseline", Duration::from_secs(60))),
+    ///     )
+    ///     .on_phase_change(|from: Option<&str>, to: Option<&str>| {
+    ///         println!("Chaos: phase {:?} -> {:?}", from, to);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_phase_change<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Option<&str>, Option<&str>) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ChaosEvent::PhaseChanged { from, to, .. } = event {
+                f(from.as_deref(), to.as_deref());
+            }
+        }));
+        self
+    }
+
     /// Add a listener for pass-through events (no chaos injected).
     ///
     /// # Example
@@ -382,23 +1058,62 @@ impl<E> ChaosConfigBuilder<E> {
         self
     }
 
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Build the chaos configuration and return a ChaosLayer.
-    pub fn build(self) -> crate::layer::ChaosLayer<E> {
+    pub fn build(self) -> crate::layer::ChaosLayer<E, C> {
+        self.build_with_control().0
+    }
+
+    /// Build the chaos layer and return a handle for adjusting it at runtime.
+    ///
+    /// The [`ChaosControl`] handle can change error and latency rates or
+    /// pause chaos entirely without rebuilding the service stack.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    ///
+    /// let (layer, control) = ChaosLayer::builder()
+    ///     .latency_rate(0.2)
+    ///     .build_with_control();
+    ///
+    /// // Disable chaos for a critical section of a test
+    /// control.pause();
+    /// ```
+    pub fn build_with_control(self) -> (crate::layer::ChaosLayer<E, C>, ChaosControl) {
+        let control = ChaosControl::new();
         let config = ChaosConfig {
             name: self.name,
             error_injector: self.error_injector,
             latency_rate: self.latency_rate,
             min_latency: self.min_latency,
             max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor,
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
             seed: self.seed,
             event_listeners: self.event_listeners,
+            control: control.clone(),
         };
-        crate::layer::ChaosLayer::new(config)
+        (crate::layer::ChaosLayer::new(config), control)
     }
 }
 
 // Special impl for CustomErrorFn to set the error rate
-impl<F> ChaosConfigBuilder<CustomErrorFn<F>> {
+impl<F, C> ChaosConfigBuilder<CustomErrorFn<F>, C> {
     /// Set the error injection rate (0.0 - 1.0).
     ///
     /// This should be called before `error_fn()` or the rate will need to be updated.
@@ -418,8 +1133,95 @@ impl<F> ChaosConfigBuilder<CustomErrorFn<F>> {
     }
 }
 
+// Special impl for ErrorCatalog to add variants and set the error rate
+impl<Err, C> ChaosConfigBuilder<ErrorCatalog<Err>, C> {
+    /// Add another weighted error variant.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .error_rate(0.1)
+    ///     .error_variant(0.7, || Error::from(ErrorKind::TimedOut))
+    ///     .error_variant(0.3, || Error::from(ErrorKind::ConnectionReset))
+    ///     .build();
+    /// ```
+    pub fn error_variant<F>(mut self, weight: f64, f: F) -> Self
+    where
+        F: Fn() -> Err + Send + Sync + 'static,
+    {
+        self.error_injector = self.error_injector.variant(weight, f);
+        self
+    }
+
+    /// Set the error injection rate (0.0 - 1.0).
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .error_variant(0.7, || Error::from(ErrorKind::TimedOut))
+    ///     .error_variant(0.3, || Error::from(ErrorKind::ConnectionReset))
+    ///     .error_rate(0.1)
+    ///     .build();
+    /// ```
+    pub fn error_rate(mut self, rate: f64) -> Self {
+        self.error_injector.rate = rate.clamp(0.0, 1.0);
+        self
+    }
+}
+
 // Also allow error_rate on any builder (for the common case of calling it before error_fn)
-impl ChaosConfigBuilder<NoErrorInjection> {
+impl<C> ChaosConfigBuilder<NoErrorInjection, C> {
+    /// Add a weighted error variant, starting an error catalog.
+    ///
+    /// Each injected error is built by one of the registered variants,
+    /// chosen in proportion to its weight. The overall injection rate is set
+    /// with `error_rate()`. Types are inferred from the closure.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// // 10% of requests fail: 70% of those time out, 30% are reset
+    /// let layer = ChaosLayer::builder()
+    ///     .error_rate(0.1)
+    ///     .error_variant(0.7, || Error::from(ErrorKind::TimedOut))
+    ///     .error_variant(0.3, || Error::from(ErrorKind::ConnectionReset))
+    ///     .build();
+    /// ```
+    pub fn error_variant<Err, F>(
+        self,
+        weight: f64,
+        f: F,
+    ) -> ChaosConfigBuilder<ErrorCatalog<Err>, C>
+    where
+        F: Fn() -> Err + Send + Sync + 'static,
+    {
+        ChaosConfigBuilder {
+            name: self.name,
+            error_injector: ErrorCatalog::new(0.0).variant(weight, f),
+            latency_rate: self.latency_rate,
+            min_latency: self.min_latency,
+            max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor,
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
+            seed: self.seed,
+            event_listeners: self.event_listeners,
+        }
+    }
+
     /// Set the error injection rate (0.0 - 1.0).
     ///
     /// Note: This only takes effect when combined with `error_fn()`.
@@ -434,13 +1236,21 @@ impl ChaosConfigBuilder<NoErrorInjection> {
     ///     .error_fn(|_req: &String| std::io::Error::other("chaos!"))
     ///     .build();
     /// ```
-    pub fn error_rate(self, _rate: f64) -> ChaosConfigBuilderWithRate {
+    pub fn error_rate(self, _rate: f64) -> ChaosConfigBuilderWithRate<C> {
         ChaosConfigBuilderWithRate {
             name: self.name,
             error_rate: _rate.clamp(0.0, 1.0),
             latency_rate: self.latency_rate,
             min_latency: self.min_latency,
             max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor,
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
             seed: self.seed,
             event_listeners: self.event_listeners,
         }
@@ -448,17 +1258,25 @@ impl ChaosConfigBuilder<NoErrorInjection> {
 }
 
 /// Builder that has an error rate set but no error function yet.
-pub struct ChaosConfigBuilderWithRate {
+pub struct ChaosConfigBuilderWithRate<C = NoResponseCorruption> {
     name: String,
     error_rate: f64,
     latency_rate: f64,
     min_latency: Duration,
     max_latency: Duration,
+    response_corruptor: C,
+    corruption_rate: f64,
+    schedule: Option<ChaosSchedule>,
+    blackhole_rate: f64,
+    blackhole_cap: Option<Duration>,
+    #[cfg(feature = "pressure")]
+    pressure: Option<ResourcePressure>,
+    guards: Vec<Guard>,
     seed: Option<u64>,
     event_listeners: EventListeners<ChaosEvent>,
 }
 
-impl ChaosConfigBuilderWithRate {
+impl<C> ChaosConfigBuilderWithRate<C> {
     /// Set the error injection function.
     ///
     /// # Example
@@ -470,7 +1288,7 @@ impl ChaosConfigBuilderWithRate {
     ///     .error_fn(|_req: &String| std::io::Error::other("chaos!"))
     ///     .build();
     /// ```
-    pub fn error_fn<Req, Err, F>(self, f: F) -> ChaosConfigBuilder<CustomErrorFn<F>>
+    pub fn error_fn<Req, Err, F>(self, f: F) -> ChaosConfigBuilder<CustomErrorFn<F>, C>
     where
         F: Fn(&Req) -> Err + Send + Sync + 'static,
     {
@@ -480,6 +1298,54 @@ impl ChaosConfigBuilderWithRate {
             latency_rate: self.latency_rate,
             min_latency: self.min_latency,
             max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor,
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
+            seed: self.seed,
+            event_listeners: self.event_listeners,
+        }
+    }
+
+    /// Add a weighted error variant, starting an error catalog.
+    ///
+    /// # Example
+    /// ```
+    /// use tower_resilience_chaos::ChaosLayer;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let layer = ChaosLayer::builder()
+    ///     .error_rate(0.1)
+    ///     .error_variant(0.7, || Error::from(ErrorKind::TimedOut))
+    ///     .error_variant(0.3, || Error::from(ErrorKind::ConnectionReset))
+    ///     .build();
+    /// ```
+    pub fn error_variant<Err, F>(
+        self,
+        weight: f64,
+        f: F,
+    ) -> ChaosConfigBuilder<ErrorCatalog<Err>, C>
+    where
+        F: Fn() -> Err + Send + Sync + 'static,
+    {
+        ChaosConfigBuilder {
+            name: self.name,
+            error_injector: ErrorCatalog::new(self.error_rate).variant(weight, f),
+            latency_rate: self.latency_rate,
+            min_latency: self.min_latency,
+            max_latency: self.max_latency,
+            response_corruptor: self.response_corruptor,
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
             seed: self.seed,
             event_listeners: self.event_listeners,
         }
@@ -509,6 +1375,76 @@ impl ChaosConfigBuilderWithRate {
         self
     }
 
+    /// Set the response corruption rate (0.0 - 1.0).
+    pub fn corruption_rate(mut self, rate: f64) -> Self {
+        self.corruption_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the response corruption function.
+    pub fn corrupt_response_fn<Res, F>(self, f: F) -> ChaosConfigBuilderWithRate<CustomCorruptFn<F>>
+    where
+        F: Fn(Res) -> Res + Send + Sync + 'static,
+    {
+        ChaosConfigBuilderWithRate {
+            name: self.name,
+            error_rate: self.error_rate,
+            latency_rate: self.latency_rate,
+            min_latency: self.min_latency,
+            max_latency: self.max_latency,
+            response_corruptor: CustomCorruptFn::new(f),
+            corruption_rate: self.corruption_rate,
+            schedule: self.schedule,
+            blackhole_rate: self.blackhole_rate,
+            blackhole_cap: self.blackhole_cap,
+            #[cfg(feature = "pressure")]
+            pressure: self.pressure,
+            guards: self.guards,
+            seed: self.seed,
+            event_listeners: self.event_listeners,
+        }
+    }
+
+    /// Set the blackhole rate (0.0 - 1.0).
+    pub fn blackhole_rate(mut self, rate: f64) -> Self {
+        self.blackhole_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the maximum time a blackholed request hangs.
+    pub fn blackhole_cap(mut self, cap: Duration) -> Self {
+        self.blackhole_cap = Some(cap);
+        self
+    }
+
+    /// Burn CPU and hold memory for a fraction of requests.
+    #[cfg(feature = "pressure")]
+    pub fn resource_pressure(mut self, pressure: ResourcePressure) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    /// Run a scripted scenario of time-bound chaos phases.
+    pub fn schedule(mut self, schedule: ChaosSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Only inject chaos when the given environment variable is enabled.
+    pub fn require_env(self, var: impl Into<String>) -> Self {
+        let var = var.into();
+        self.guard(move || env_enabled(&var))
+    }
+
+    /// Only inject chaos while the given predicate returns true.
+    pub fn guard<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.guards.push(Arc::new(f));
+        self
+    }
+
     /// Set a seed for deterministic chaos injection.
     pub fn seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
@@ -541,6 +1477,59 @@ impl ChaosConfigBuilderWithRate {
         self
     }
 
+    /// Add a listener for response corruption events.
+    pub fn on_response_corrupted<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, ChaosEvent::ResponseCorrupted { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Add a listener for blackhole events.
+    pub fn on_blackholed<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, ChaosEvent::Blackholed { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Add a listener for resource pressure injection events.
+    #[cfg(feature = "pressure")]
+    pub fn on_pressure_injected<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration, usize) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ChaosEvent::PressureInjected { cpu, memory, .. } = event {
+                f(*cpu, *memory);
+            }
+        }));
+        self
+    }
+
+    /// Add a listener for schedule phase changes.
+    pub fn on_phase_change<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Option<&str>, Option<&str>) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ChaosEvent::PhaseChanged { from, to, .. } = event {
+                f(from.as_deref(), to.as_deref());
+            }
+        }));
+        self
+    }
+
     /// Add a listener for pass-through events.
     pub fn on_passed_through<F>(mut self, f: F) -> Self
     where
@@ -554,3 +1543,15 @@ impl ChaosConfigBuilderWithRate {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_enabled_values() {
+        assert!(!env_enabled("TOWER_RESILIENCE_CHAOS_TEST_NEVER_SET"));
+        // PATH is set to a non-empty, non-false value on every supported platform
+        assert!(env_enabled("PATH"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Runtime control of chaos injection.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+struct Overrides {
+    error_rate: Option<f64>,
+    latency_rate: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct ControlState {
+    paused: AtomicBool,
+    overrides: Mutex<Overrides>,
+}
+
+/// A handle for adjusting chaos injection at runtime.
+///
+/// Obtained from [`crate::ChaosConfigBuilder::build_with_control()`]. The
+/// handle is cheap to clone and safe to share across threads
+/// (`Clone + Send + Sync`), so integration tests and admin endpoints can
+/// flip chaos on and off without rebuilding the service stack.
+///
+/// Rates set through the handle take precedence over both the builder's
+/// static rates and any active [`ChaosSchedule`](crate::ChaosSchedule)
+/// phase. Pausing disables all chaos, including response corruption.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_chaos::ChaosLayer;
+///
+/// let (layer, control) = ChaosLayer::builder()
+///     .error_fn(|_req: &String| std::io::Error::other("chaos!"))
+///     .build_with_control();
+///
+/// // Apply the layer to a service...
+///
+/// // Later, turn errors on for a test and back off again:
+/// control.set_error_rate(0.5);
+/// control.pause();
+/// control.resume();
+/// control.clear_overrides();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChaosControl {
+    state: Arc<ControlState>,
+}
+
+impl ChaosControl {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the error injection rate (0.0 - 1.0).
+    ///
+    /// Errors are generated by the layer's `error_fn()`; without one, this
+    /// has no effect.
+    pub fn set_error_rate(&self, rate: f64) {
+        self.overrides().error_rate = Some(rate.clamp(0.0, 1.0));
+    }
+
+    /// Override the latency injection rate (0.0 - 1.0).
+    pub fn set_latency_rate(&self, rate: f64) {
+        self.overrides().latency_rate = Some(rate.clamp(0.0, 1.0));
+    }
+
+    /// Returns the overridden error rate, if one is set.
+    pub fn error_rate(&self) -> Option<f64> {
+        self.overrides().error_rate
+    }
+
+    /// Returns the overridden latency rate, if one is set.
+    pub fn latency_rate(&self) -> Option<f64> {
+        self.overrides().latency_rate
+    }
+
+    /// Remove rate overrides, reverting to the configured rates.
+    pub fn clear_overrides(&self) {
+        *self.overrides() = Overrides::default();
+    }
+
+    /// Stop injecting chaos until [`resume`](Self::resume) is called.
+    pub fn pause(&self) {
+        self.state.paused.store(true, Ordering::Release);
+    }
+
+    /// Resume injecting chaos after [`pause`](Self::pause).
+    pub fn resume(&self) {
+        self.state.paused.store(false, Ordering::Release);
+    }
+
+    /// Returns whether chaos injection is paused.
+    pub fn is_paused(&self) -> bool {
+        self.state.paused.load(Ordering::Acquire)
+    }
+
+    fn overrides(&self) -> std::sync::MutexGuard<'_, Overrides> {
+        self.state
+            .overrides
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_state() {
+        let control = ChaosControl::new();
+        let clone = control.clone();
+
+        clone.set_error_rate(0.3);
+        clone.pause();
+
+        assert_eq!(control.error_rate(), Some(0.3));
+        assert!(control.is_paused());
+    }
+
+    #[test]
+    fn rates_are_clamped_and_clearable() {
+        let control = ChaosControl::new();
+        control.set_latency_rate(2.0);
+        assert_eq!(control.latency_rate(), Some(1.0));
+
+        control.clear_overrides();
+        assert_eq!(control.latency_rate(), None);
+        assert_eq!(control.error_rate(), None);
+    }
+}
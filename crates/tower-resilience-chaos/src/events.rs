@@ -5,12 +5,18 @@ use tower_resilience_core::ResilienceEvent;
 
 /// Events emitted by the chaos layer.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum ChaosEvent {
     /// An error was injected into the response.
     ErrorInjected {
         /// Name of the chaos layer instance
         pattern_name: String,
         /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
     /// Latency was injected (request delayed).
@@ -18,15 +24,77 @@ pub enum ChaosEvent {
         /// Name of the chaos layer instance
         pattern_name: String,
         /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// Amount of delay injected
         delay: Duration,
     },
+    /// A request was blackholed (held without completing).
+    Blackholed {
+        /// Name of the chaos layer instance
+        pattern_name: String,
+        /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Maximum time the request is held, or `None` if held forever
+        cap: Option<Duration>,
+    },
+    /// CPU and memory pressure was applied before forwarding the request.
+    PressureInjected {
+        /// Name of the chaos layer instance
+        pattern_name: String,
+        /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// CPU time burned
+        cpu: Duration,
+        /// Bytes held while the request is in flight
+        memory: usize,
+    },
+    /// A successful response was corrupted before being returned.
+    ResponseCorrupted {
+        /// Name of the chaos layer instance
+        pattern_name: String,
+        /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A chaos schedule moved to a new phase.
+    PhaseChanged {
+        /// Name of the chaos layer instance
+        pattern_name: String,
+        /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Previous phase, or `None` if the schedule just started
+        from: Option<String>,
+        /// New phase, or `None` if the schedule completed
+        to: Option<String>,
+    },
     /// Request passed through without chaos injection.
     PassedThrough {
         /// Name of the chaos layer instance
         pattern_name: String,
         /// When the event occurred
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 }
@@ -36,6 +104,10 @@ impl ResilienceEvent for ChaosEvent {
         match self {
             ChaosEvent::ErrorInjected { .. } => "chaos.error_injected",
             ChaosEvent::LatencyInjected { .. } => "chaos.latency_injected",
+            ChaosEvent::Blackholed { .. } => "chaos.blackholed",
+            ChaosEvent::PressureInjected { .. } => "chaos.pressure_injected",
+            ChaosEvent::ResponseCorrupted { .. } => "chaos.response_corrupted",
+            ChaosEvent::PhaseChanged { .. } => "chaos.phase_changed",
             ChaosEvent::PassedThrough { .. } => "chaos.passed_through",
         }
     }
@@ -44,6 +116,10 @@ impl ResilienceEvent for ChaosEvent {
         match self {
             ChaosEvent::ErrorInjected { timestamp, .. }
             | ChaosEvent::LatencyInjected { timestamp, .. }
+            | ChaosEvent::Blackholed { timestamp, .. }
+            | ChaosEvent::PressureInjected { timestamp, .. }
+            | ChaosEvent::ResponseCorrupted { timestamp, .. }
+            | ChaosEvent::PhaseChanged { timestamp, .. }
             | ChaosEvent::PassedThrough { timestamp, .. } => *timestamp,
         }
     }
@@ -52,6 +128,10 @@ impl ResilienceEvent for ChaosEvent {
         match self {
             ChaosEvent::ErrorInjected { pattern_name, .. }
             | ChaosEvent::LatencyInjected { pattern_name, .. }
+            | ChaosEvent::Blackholed { pattern_name, .. }
+            | ChaosEvent::PressureInjected { pattern_name, .. }
+            | ChaosEvent::ResponseCorrupted { pattern_name, .. }
+            | ChaosEvent::PhaseChanged { pattern_name, .. }
             | ChaosEvent::PassedThrough { pattern_name, .. } => pattern_name,
         }
     }
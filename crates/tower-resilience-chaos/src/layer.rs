@@ -1,6 +1,6 @@
 //! Tower layer for chaos engineering.
 
-use crate::config::{ChaosConfig, ChaosConfigBuilder, NoErrorInjection};
+use crate::config::{ChaosConfig, ChaosConfigBuilder, NoErrorInjection, NoResponseCorruption};
 use crate::service::Chaos;
 use tower_layer::Layer;
 
@@ -9,6 +9,10 @@ use tower_layer::Layer;
 /// The type parameter `E` is the error injector type:
 /// - `ChaosLayer<NoErrorInjection>` - latency-only chaos (works with any types)
 /// - `ChaosLayer<CustomErrorFn<F>>` - custom error injection
+/// - `ChaosLayer<ErrorCatalog<Err>>` - weighted error variants
+///
+/// The type parameter `C` is the response corruptor type, which becomes
+/// `CustomCorruptFn<F>` after calling `.corrupt_response_fn()`.
 ///
 /// # Latency-Only Chaos (no type parameters needed)
 ///
@@ -49,13 +53,13 @@ use tower_layer::Layer;
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct ChaosLayer<E = NoErrorInjection> {
-    config: ChaosConfig<E>,
+pub struct ChaosLayer<E = NoErrorInjection, C = NoResponseCorruption> {
+    config: ChaosConfig<E, C>,
 }
 
-impl<E> ChaosLayer<E> {
+impl<E, C> ChaosLayer<E, C> {
     /// Create a new chaos layer from configuration.
-    pub fn new(config: ChaosConfig<E>) -> Self {
+    pub fn new(config: ChaosConfig<E, C>) -> Self {
         Self { config }
     }
 }
@@ -95,8 +99,8 @@ impl ChaosLayer<NoErrorInjection> {
 }
 
 // Implement Layer<S> for NoErrorInjection - works with any service
-impl<S> Layer<S> for ChaosLayer<NoErrorInjection> {
-    type Service = Chaos<S, NoErrorInjection>;
+impl<S, C: Clone> Layer<S> for ChaosLayer<NoErrorInjection, C> {
+    type Service = Chaos<S, NoErrorInjection, C>;
 
     fn layer(&self, inner: S) -> Self::Service {
         Chaos::new(inner, self.config.clone())
@@ -104,11 +108,23 @@ impl<S> Layer<S> for ChaosLayer<NoErrorInjection> {
 }
 
 // Implement Layer<S> for CustomErrorFn - the closure determines compatible services
-impl<S, F> Layer<S> for ChaosLayer<crate::config::CustomErrorFn<F>>
+impl<S, F, C: Clone> Layer<S> for ChaosLayer<crate::config::CustomErrorFn<F>, C>
 where
     F: 'static,
 {
-    type Service = Chaos<S, crate::config::CustomErrorFn<F>>;
+    type Service = Chaos<S, crate::config::CustomErrorFn<F>, C>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Chaos::new(inner, self.config.clone())
+    }
+}
+
+// Implement Layer<S> for ErrorCatalog - the error type determines compatible services
+impl<S, Err, C: Clone> Layer<S> for ChaosLayer<crate::config::ErrorCatalog<Err>, C>
+where
+    Err: 'static,
+{
+    type Service = Chaos<S, crate::config::ErrorCatalog<Err>, C>;
 
     fn layer(&self, inner: S) -> Self::Service {
         Chaos::new(inner, self.config.clone())
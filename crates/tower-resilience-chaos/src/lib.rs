@@ -8,7 +8,13 @@
 //! # Features
 //!
 //! - **Error Injection**: Inject errors at a configurable rate
+//! - **Error Catalogs**: Mix weighted error variants to match production failures
 //! - **Latency Injection**: Add random delays to requests
+//! - **Blackholing**: Hold requests without completing them, like a network partition
+//! - **Response Corruption**: Return subtly wrong or truncated responses
+//! - **Resource Pressure**: Burn CPU and hold memory (requires the `pressure` feature)
+//! - **Scheduled Scenarios**: Script time-bound phases of errors and latency
+//! - **Runtime Control**: Adjust rates or pause chaos through a shared handle
 //! - **Deterministic Testing**: Use seeds for reproducible chaos
 //! - **Event System**: Monitor chaos injection via event listeners
 //! - **Composable**: Works with all other tower-resilience patterns
@@ -16,8 +22,19 @@
 //! # Safety
 //!
 //! **WARNING**: This layer is intended for testing and development only. Never use it in
-//! production environments. Consider using feature flags or environment checks to ensure
-//! chaos layers are only enabled in non-production environments.
+//! production environments. Use [`require_env`](ChaosConfigBuilder::require_env) or
+//! [`guard`](ChaosConfigBuilder::guard) to make the layer a pass-through unless chaos
+//! is explicitly enabled:
+//!
+//! ```rust
+//! use tower_resilience_chaos::ChaosLayer;
+//!
+//! // Injects nothing unless ENABLE_CHAOS is set (e.g. ENABLE_CHAOS=1)
+//! let chaos = ChaosLayer::builder()
+//!     .latency_rate(0.2)
+//!     .require_env("ENABLE_CHAOS")
+//!     .build();
+//! ```
 //!
 //! # Basic Example
 //!
@@ -73,6 +90,26 @@
 //! # }
 //! ```
 //!
+//! ## Weighted Error Variants
+//!
+//! Register several error constructors with weights so the injected failure
+//! mix resembles production:
+//!
+//! ```rust
+//! use tower_resilience_chaos::ChaosLayer;
+//! use std::io::{Error, ErrorKind};
+//!
+//! # async fn example() {
+//! // 20% of requests fail: 70% of failures time out, 30% are connection resets
+//! let chaos = ChaosLayer::builder()
+//!     .name("api-chaos")
+//!     .error_rate(0.2)
+//!     .error_variant(0.7, || Error::from(ErrorKind::TimedOut))
+//!     .error_variant(0.3, || Error::from(ErrorKind::ConnectionReset))
+//!     .build();
+//! # }
+//! ```
+//!
 //! # Testing Circuit Breakers
 //!
 //! ```rust,ignore
@@ -167,6 +204,120 @@
 //! # }
 //! ```
 //!
+//! # Blackholing
+//!
+//! Simulate a partition where the peer silently drops traffic. Blackholed
+//! requests stay pending, which exercises timeouts and hedging rather than
+//! error handling:
+//!
+//! ```rust
+//! use tower_resilience_chaos::ChaosLayer;
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let chaos = ChaosLayer::builder()
+//!     .name("partition-chaos")
+//!     .blackhole_rate(0.1)  // 10% of requests hang
+//!     .blackhole_cap(Duration::from_secs(30))  // then reach the service
+//!     .build();
+//! # }
+//! ```
+//!
+//! # Response Corruption
+//!
+//! Errors and latency exercise retries and timeouts, but not the logic that
+//! validates response contents. Corrupt a fraction of successful responses
+//! to check that downstream validation and fallbacks catch bad data:
+//!
+//! ```rust
+//! use tower::ServiceBuilder;
+//! use tower_resilience_chaos::ChaosLayer;
+//!
+//! # async fn example() {
+//! let chaos = ChaosLayer::builder()
+//!     .name("truncation-chaos")
+//!     .corruption_rate(0.1)  // 10% of successful responses truncated
+//!     .corrupt_response_fn(|resp: String| resp.chars().take(4).collect())
+//!     .build();
+//!
+//! let service = ServiceBuilder::new()
+//!     .layer(chaos)
+//!     .service_fn(|req: String| async move {
+//!         Ok::<String, std::io::Error>(format!("Response to: {}", req))
+//!     });
+//! # }
+//! ```
+//!
+//! # Resource Pressure
+//!
+//! With the `pressure` feature enabled, a fraction of requests can burn CPU
+//! and hold memory before being forwarded, simulating a noisy neighbor for
+//! adaptive limiters and bulkheads. Amounts are capped at [`MAX_CPU_BURN`]
+//! and [`MAX_MEMORY`]:
+//!
+//! ```rust,ignore
+//! use tower_resilience_chaos::{ChaosLayer, ResourcePressure};
+//! use std::time::Duration;
+//!
+//! let chaos = ChaosLayer::builder()
+//!     .resource_pressure(
+//!         ResourcePressure::new(0.1)
+//!             .cpu(Duration::from_millis(20))
+//!             .memory(16 * 1024 * 1024),
+//!     )
+//!     .build();
+//! ```
+//!
+//! # Scheduled Scenarios
+//!
+//! Run a scripted failure scenario instead of constant rates:
+//!
+//! ```rust
+//! use tower_resilience_chaos::{ChaosLayer, ChaosPhase, ChaosSchedule};
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let schedule = ChaosSchedule::new()
+//!     .phase(ChaosPhase::new("baseline", Duration::from_secs(60)))
+//!     .phase(ChaosPhase::new("errors", Duration::from_secs(60)).error_rate(0.5))
+//!     .phase(
+//!         ChaosPhase::new("slow", Duration::from_secs(60))
+//!             .latency_rate(1.0)
+//!             .latency(Duration::from_millis(500)),
+//!     );
+//!
+//! let chaos = ChaosLayer::builder()
+//!     .name("scenario-chaos")
+//!     .error_fn(|_req: &String| std::io::Error::other("chaos"))
+//!     .schedule(schedule)
+//!     .on_phase_change(|from: Option<&str>, to: Option<&str>| {
+//!         println!("chaos phase {:?} -> {:?}", from, to);
+//!     })
+//!     .build();
+//! # }
+//! ```
+//!
+//! # Runtime Control
+//!
+//! Flip chaos on and off without rebuilding the service stack:
+//!
+//! ```rust
+//! use tower_resilience_chaos::ChaosLayer;
+//!
+//! # async fn example() {
+//! let (chaos, control) = ChaosLayer::builder()
+//!     .error_fn(|_req: &String| std::io::Error::other("chaos"))
+//!     .build_with_control();
+//!
+//! // Start failing half of all requests
+//! control.set_error_rate(0.5);
+//!
+//! // Temporarily disable all chaos
+//! control.pause();
+//! control.resume();
+//! # }
+//! ```
+//!
 //! # Latency Injection Only
 //!
 //! Test timeout handling without errors (no type parameters needed!):
@@ -186,22 +337,45 @@
 //! // Use with TimeLimiter to test timeout behavior
 //! # }
 //! ```
+//!
+//! # Metrics
+//!
+//! With the `metrics` feature enabled, the following metrics are recorded,
+//! labeled by the chaos layer name (`chaos`), so game-day dashboards show
+//! exactly how much fault was injected:
+//!
+//! - `resilience_chaos_errors_injected_total` - injected errors
+//! - `resilience_chaos_latency_injected_seconds` - injected latency
+//! - `resilience_chaos_passthrough_total` - requests passed through without faults
+//! - `resilience_chaos_responses_corrupted_total` - corrupted responses
+//! - `resilience_chaos_blackholed_total` - blackholed requests
+//! - `resilience_chaos_pressure_injected_total` - requests with CPU/memory pressure applied
 
 /// Configuration types for chaos injection.
 pub mod config;
+/// Runtime control handle for chaos injection.
+pub mod control;
 /// Event types emitted by chaos injection.
 pub mod events;
 /// Tower `Layer` implementation for chaos injection.
 pub mod layer;
+#[cfg(feature = "pressure")]
+mod pressure;
+/// Scheduled, scenario-based chaos profiles.
+pub mod schedule;
 /// Tower `Service` implementation for chaos injection.
 pub mod service;
 
 pub use config::{
-    ChaosConfig, ChaosConfigBuilder, ChaosConfigBuilderWithRate, CustomErrorFn, ErrorInjector,
-    NoErrorInjection,
+    ChaosConfig, ChaosConfigBuilder, ChaosConfigBuilderWithRate, CustomCorruptFn, CustomErrorFn,
+    ErrorCatalog, ErrorInjector, NoErrorInjection, NoResponseCorruption, ResponseCorruptor,
 };
+pub use control::ChaosControl;
 pub use events::ChaosEvent;
 pub use layer::ChaosLayer;
+#[cfg(feature = "pressure")]
+pub use pressure::{ResourcePressure, MAX_CPU_BURN, MAX_MEMORY};
+pub use schedule::{ChaosPhase, ChaosSchedule};
 pub use service::Chaos;
 
 #[cfg(test)]
@@ -330,6 +504,377 @@ mod tests {
         assert_eq!(pass_count.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn test_response_corruption() {
+        let corrupted = Arc::new(AtomicUsize::new(0));
+        let c = corrupted.clone();
+
+        let chaos = ChaosLayer::builder()
+            .corruption_rate(1.0) // Always corrupt
+            .corrupt_response_fn(|resp: String| resp[..4].to_string())
+            .on_response_corrupted(move || {
+                c.fetch_add(1, Ordering::SeqCst);
+            })
+            .seed(42)
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, ()>(format!("echo: {}", req))
+        }));
+
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(response, "echo");
+        assert_eq!(corrupted.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_response_corruption_skips_errors() {
+        let corrupted = Arc::new(AtomicUsize::new(0));
+        let c = corrupted.clone();
+
+        let chaos = ChaosLayer::builder()
+            .corruption_rate(1.0)
+            .corrupt_response_fn(|_resp: String| String::new())
+            .on_response_corrupted(move || {
+                c.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|_req: String| async move {
+            Err::<String, &'static str>("inner failure")
+        }));
+
+        let result = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await;
+
+        assert_eq!(result.unwrap_err(), "inner failure");
+        assert_eq!(corrupted.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_response_corruption_with_error_injection() {
+        // Corruption composes with error injection in either builder order
+        let chaos = ChaosLayer::builder()
+            .error_rate(0.0)
+            .corruption_rate(1.0)
+            .corrupt_response_fn(|resp: u32| resp + 1)
+            .error_fn(|_req: &u32| "chaos error")
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: u32| async move {
+            Ok::<u32, &'static str>(req)
+        }));
+
+        let response = service.ready().await.unwrap().call(41).await.unwrap();
+        assert_eq!(response, 42);
+    }
+
+    #[tokio::test]
+    async fn test_schedule_phases() {
+        let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let c = changes.clone();
+
+        let chaos = ChaosLayer::builder()
+            .error_fn(|_req: &String| "chaos error")
+            .schedule(
+                ChaosSchedule::new()
+                    .phase(ChaosPhase::new("baseline", Duration::from_millis(100)))
+                    .phase(ChaosPhase::new("errors", Duration::from_millis(100)).error_rate(1.0)),
+            )
+            .on_phase_change(move |from, to| {
+                c.lock()
+                    .unwrap()
+                    .push((from.map(str::to_string), to.map(str::to_string)));
+            })
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, &'static str>(req)
+        }));
+
+        // Baseline phase injects nothing
+        let result = service.ready().await.unwrap().call("a".to_string()).await;
+        assert!(result.is_ok());
+
+        // Error phase fails every request
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        let result = service.ready().await.unwrap().call("b".to_string()).await;
+        assert_eq!(result.unwrap_err(), "chaos error");
+
+        // Completed schedule injects nothing
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let result = service.ready().await.unwrap().call("c".to_string()).await;
+        assert!(result.is_ok());
+
+        let changes = changes.lock().unwrap();
+        assert_eq!(
+            *changes,
+            vec![
+                (None, Some("baseline".to_string())),
+                (Some("baseline".to_string()), Some("errors".to_string())),
+                (Some("errors".to_string()), None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_schedule_latency_phase() {
+        let chaos = ChaosLayer::builder()
+            .schedule(
+                ChaosSchedule::new().phase(
+                    ChaosPhase::new("slow", Duration::from_secs(10))
+                        .latency_rate(1.0)
+                        .latency(Duration::from_millis(50)),
+                ),
+            )
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, ()>(req)
+        }));
+
+        let start = std::time::Instant::now();
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+
+        assert!(
+            start.elapsed().as_millis() >= 40,
+            "Expected at least 40ms, got {}ms",
+            start.elapsed().as_millis()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_control_adjusts_rates_at_runtime() {
+        let (chaos, control) = ChaosLayer::builder()
+            .error_fn(|_req: &String| "chaos error")
+            .build_with_control();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, &'static str>(req)
+        }));
+
+        // No error rate configured
+        let result = service.ready().await.unwrap().call("a".to_string()).await;
+        assert!(result.is_ok());
+
+        control.set_error_rate(1.0);
+        let result = service.ready().await.unwrap().call("b".to_string()).await;
+        assert_eq!(result.unwrap_err(), "chaos error");
+
+        control.pause();
+        let result = service.ready().await.unwrap().call("c".to_string()).await;
+        assert!(result.is_ok());
+
+        control.resume();
+        let result = service.ready().await.unwrap().call("d".to_string()).await;
+        assert!(result.is_err());
+
+        control.clear_overrides();
+        let result = service.ready().await.unwrap().call("e".to_string()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_control_pause_disables_corruption() {
+        let (chaos, control) = ChaosLayer::builder()
+            .corruption_rate(1.0)
+            .corrupt_response_fn(|_resp: String| String::new())
+            .build_with_control();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, ()>(req)
+        }));
+
+        control.pause();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+        assert_eq!(response, "test");
+    }
+
+    #[tokio::test]
+    async fn test_blackhole_never_completes() {
+        let blackholed = Arc::new(AtomicUsize::new(0));
+        let b = blackholed.clone();
+
+        let chaos = ChaosLayer::builder()
+            .blackhole_rate(1.0)
+            .on_blackholed(move || {
+                b.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, ()>(req)
+        }));
+
+        let call = service.ready().await.unwrap().call("test".to_string());
+        let result = tokio::time::timeout(Duration::from_millis(50), call).await;
+
+        assert!(result.is_err(), "blackholed request should not complete");
+        assert_eq!(blackholed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_blackhole_cap_forwards_request() {
+        let chaos = ChaosLayer::builder()
+            .blackhole_rate(1.0)
+            .blackhole_cap(Duration::from_millis(50))
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, ()>(req)
+        }));
+
+        let start = std::time::Instant::now();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(response, "test");
+        assert!(
+            start.elapsed().as_millis() >= 40,
+            "Expected at least 40ms, got {}ms",
+            start.elapsed().as_millis()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_catalog_mixes_variants() {
+        let chaos = ChaosLayer::builder()
+            .error_rate(1.0)
+            .error_variant(0.7, || "timeout")
+            .error_variant(0.3, || "reset")
+            .seed(7)
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: u32| async move {
+            Ok::<u32, &'static str>(req)
+        }));
+
+        let mut timeouts = 0;
+        let mut resets = 0;
+        for i in 0..1000 {
+            match service.ready().await.unwrap().call(i).await {
+                Err("timeout") => timeouts += 1,
+                Err("reset") => resets += 1,
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        assert!((600..800).contains(&timeouts), "timeouts: {}", timeouts);
+        assert!((200..400).contains(&resets), "resets: {}", resets);
+    }
+
+    #[tokio::test]
+    async fn test_error_catalog_respects_rate() {
+        let chaos = ChaosLayer::builder()
+            .error_variant(1.0, || "timeout")
+            .error_rate(0.0)
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: u32| async move {
+            Ok::<u32, &'static str>(req)
+        }));
+
+        let result = service.ready().await.unwrap().call(1).await;
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn test_error_catalog_selection() {
+        let catalog = ErrorCatalog::new(1.0)
+            .variant(1.0, || "a")
+            .variant(0.0, || "never")
+            .variant(3.0, || "b");
+
+        assert_eq!(catalog.len(), 3);
+        assert_eq!(
+            ErrorInjector::<(), _>::generate_error(&catalog, &(), 0.0),
+            Some("a")
+        );
+        assert_eq!(
+            ErrorInjector::<(), _>::generate_error(&catalog, &(), 0.24),
+            Some("a")
+        );
+        assert_eq!(
+            ErrorInjector::<(), _>::generate_error(&catalog, &(), 0.25),
+            Some("b")
+        );
+        assert_eq!(
+            ErrorInjector::<(), _>::generate_error(&catalog, &(), 0.99),
+            Some("b")
+        );
+        assert_eq!(
+            ErrorInjector::<(), &str>::generate_error(&ErrorCatalog::new(1.0), &(), 0.5),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guard_disables_chaos() {
+        let enabled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let e = enabled.clone();
+
+        let chaos = ChaosLayer::builder()
+            .error_rate(1.0)
+            .error_fn(|_req: &String| "chaos error")
+            .guard(move || e.load(Ordering::SeqCst))
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, &'static str>(req)
+        }));
+
+        let result = service.ready().await.unwrap().call("a".to_string()).await;
+        assert!(result.is_ok());
+
+        enabled.store(true, Ordering::SeqCst);
+        let result = service.ready().await.unwrap().call("b".to_string()).await;
+        assert_eq!(result.unwrap_err(), "chaos error");
+    }
+
+    #[tokio::test]
+    async fn test_require_env_unset_passes_through() {
+        let chaos = ChaosLayer::builder()
+            .error_rate(1.0)
+            .error_fn(|_req: &String| "chaos error")
+            .require_env("TOWER_RESILIENCE_CHAOS_TEST_NEVER_SET")
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, &'static str>(req)
+        }));
+
+        let result = service.ready().await.unwrap().call("a".to_string()).await;
+        assert_eq!(result, Ok("a".to_string()));
+    }
+
     #[tokio::test]
     async fn test_deterministic_behavior() {
         // Create two services with the same seed
@@ -359,4 +904,41 @@ mod tests {
             assert_eq!(result1.is_ok(), result2.is_ok());
         }
     }
+
+    #[cfg(feature = "pressure")]
+    #[tokio::test]
+    async fn test_resource_pressure_injected() {
+        let injected = Arc::new(AtomicUsize::new(0));
+        let passed = Arc::new(AtomicUsize::new(0));
+        let i = Arc::clone(&injected);
+        let p = Arc::clone(&passed);
+
+        let chaos = ChaosLayer::builder()
+            .resource_pressure(
+                ResourcePressure::new(1.0)
+                    .cpu(Duration::from_millis(2))
+                    .memory(4096),
+            )
+            .on_pressure_injected(move |cpu, memory| {
+                assert_eq!(cpu, Duration::from_millis(2));
+                assert_eq!(memory, 4096);
+                i.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_passed_through(move || {
+                p.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+
+        let mut service = chaos.layer(tower::service_fn(|req: String| async move {
+            Ok::<String, ()>(req)
+        }));
+
+        for _ in 0..3 {
+            let result = service.ready().await.unwrap().call("a".to_string()).await;
+            assert_eq!(result, Ok("a".to_string()));
+        }
+
+        assert_eq!(injected.load(Ordering::SeqCst), 3);
+        assert_eq!(passed.load(Ordering::SeqCst), 0);
+    }
 }
@@ -0,0 +1,108 @@
+//! Resource pressure injection.
+//!
+//! Errors and latency only exercise failure-handling paths. Burning CPU and
+//! holding memory on the caller's side simulates a noisy neighbor, which is
+//! what adaptive concurrency limiters and bulkheads are meant to absorb.
+
+use std::time::{Duration, Instant};
+
+/// Upper bound on CPU time burned per request.
+pub const MAX_CPU_BURN: Duration = Duration::from_secs(1);
+
+/// Upper bound on memory held per request (256 MiB).
+pub const MAX_MEMORY: usize = 256 * 1024 * 1024;
+
+/// CPU and memory pressure applied to a fraction of requests.
+///
+/// Selected requests spin the executor thread for the configured CPU time
+/// and hold an allocation of the configured size until the inner service
+/// responds. Both amounts are capped at [`MAX_CPU_BURN`] and [`MAX_MEMORY`]
+/// so a misconfigured layer cannot take the process down.
+///
+/// # Example
+/// ```
+/// use tower_resilience_chaos::{ChaosLayer, ResourcePressure};
+/// use std::time::Duration;
+///
+/// let layer = ChaosLayer::builder()
+///     .resource_pressure(
+///         ResourcePressure::new(0.1)
+///             .cpu(Duration::from_millis(20))
+///             .memory(16 * 1024 * 1024),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourcePressure {
+    pub(crate) rate: f64,
+    pub(crate) cpu: Duration,
+    pub(crate) memory: usize,
+}
+
+impl ResourcePressure {
+    /// Apply pressure to the given fraction of requests (0.0 - 1.0).
+    ///
+    /// No CPU or memory pressure is configured by default.
+    pub fn new(rate: f64) -> Self {
+        Self {
+            rate: rate.clamp(0.0, 1.0),
+            cpu: Duration::ZERO,
+            memory: 0,
+        }
+    }
+
+    /// Busy-loop for this long before forwarding a selected request.
+    ///
+    /// Capped at [`MAX_CPU_BURN`].
+    pub fn cpu(mut self, duration: Duration) -> Self {
+        self.cpu = duration.min(MAX_CPU_BURN);
+        self
+    }
+
+    /// Allocate and hold this many bytes while a selected request is in flight.
+    ///
+    /// Capped at [`MAX_MEMORY`].
+    pub fn memory(mut self, bytes: usize) -> Self {
+        self.memory = bytes.min(MAX_MEMORY);
+        self
+    }
+
+    /// Burn CPU and allocate memory, returning the allocation to hold.
+    pub(crate) fn apply(&self) -> Vec<u8> {
+        let start = Instant::now();
+        let mut spins: u64 = 0;
+        while start.elapsed() < self.cpu {
+            spins = std::hint::black_box(spins.wrapping_add(1));
+        }
+
+        // Fill the buffer so the pages are actually committed
+        vec![1u8; self.memory]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_bounded() {
+        let pressure = ResourcePressure::new(2.0)
+            .cpu(Duration::from_secs(60))
+            .memory(usize::MAX);
+        assert_eq!(pressure.rate, 1.0);
+        assert_eq!(pressure.cpu, MAX_CPU_BURN);
+        assert_eq!(pressure.memory, MAX_MEMORY);
+    }
+
+    #[test]
+    fn apply_burns_cpu_and_allocates() {
+        let pressure = ResourcePressure::new(1.0)
+            .cpu(Duration::from_millis(5))
+            .memory(1024);
+
+        let start = Instant::now();
+        let held = pressure.apply();
+        assert!(start.elapsed() >= Duration::from_millis(5));
+        assert_eq!(held.len(), 1024);
+    }
+}
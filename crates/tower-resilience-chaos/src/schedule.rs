@@ -0,0 +1,286 @@
+//! Scheduled, scenario-based chaos profiles.
+//!
+//! A [`ChaosSchedule`] is a sequence of time-bound [`ChaosPhase`]s, letting a
+//! resilience test run a scripted failure scenario (baseline, then an error
+//! burst, then a latency spike) instead of constant rates.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A single time-bound phase of a [`ChaosSchedule`].
+///
+/// A new phase injects no chaos until rates are configured, which makes it
+/// suitable as a baseline.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_chaos::ChaosPhase;
+/// use std::time::Duration;
+///
+/// let burst = ChaosPhase::new("error-burst", Duration::from_secs(60)).error_rate(0.5);
+/// let spike = ChaosPhase::new("latency-spike", Duration::from_secs(60))
+///     .latency_rate(1.0)
+///     .latency(Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChaosPhase {
+    pub(crate) name: String,
+    pub(crate) duration: Duration,
+    pub(crate) error_rate: f64,
+    pub(crate) latency_rate: f64,
+    pub(crate) min_latency: Duration,
+    pub(crate) max_latency: Duration,
+}
+
+impl ChaosPhase {
+    /// Create a phase with the given name and duration that injects no chaos.
+    pub fn new(name: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            name: name.into(),
+            duration,
+            error_rate: 0.0,
+            latency_rate: 0.0,
+            min_latency: Duration::from_millis(10),
+            max_latency: Duration::from_millis(100),
+        }
+    }
+
+    /// Set the error injection rate (0.0 - 1.0) during this phase.
+    ///
+    /// Errors are generated by the layer's `error_fn()`.
+    pub fn error_rate(mut self, rate: f64) -> Self {
+        self.error_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the latency injection rate (0.0 - 1.0) during this phase.
+    pub fn latency_rate(mut self, rate: f64) -> Self {
+        self.latency_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set a fixed latency to inject during this phase.
+    pub fn latency(mut self, duration: Duration) -> Self {
+        self.min_latency = duration;
+        self.max_latency = duration;
+        self
+    }
+
+    /// Set the minimum latency to inject during this phase.
+    pub fn min_latency(mut self, duration: Duration) -> Self {
+        self.min_latency = duration;
+        self
+    }
+
+    /// Set the maximum latency to inject during this phase.
+    pub fn max_latency(mut self, duration: Duration) -> Self {
+        self.max_latency = duration;
+        self
+    }
+
+    /// Returns the name of this phase.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the duration of this phase.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+#[derive(Debug, Default)]
+struct ScheduleState {
+    started_at: Option<Instant>,
+    current: Option<usize>,
+}
+
+/// A scripted sequence of chaos phases.
+///
+/// When a schedule is configured, the active phase's rates replace the
+/// layer's static error and latency rates. The schedule clock starts with
+/// the first request. Once the last phase has elapsed, no error or latency
+/// chaos is injected unless the schedule repeats.
+///
+/// Phase changes are observed lazily: the
+/// [`PhaseChanged`](crate::ChaosEvent::PhaseChanged) event fires on the first
+/// request that lands in a new phase.
+///
+/// Clones share the same clock.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_chaos::{ChaosLayer, ChaosPhase, ChaosSchedule};
+/// use std::time::Duration;
+///
+/// let schedule = ChaosSchedule::new()
+///     .phase(ChaosPhase::new("baseline", Duration::from_secs(60)))
+///     .phase(ChaosPhase::new("errors", Duration::from_secs(60)).error_rate(0.5))
+///     .phase(
+///         ChaosPhase::new("slow", Duration::from_secs(60))
+///             .latency_rate(1.0)
+///             .latency(Duration::from_millis(500)),
+///     );
+///
+/// let layer = ChaosLayer::builder()
+///     .error_fn(|_req: &String| std::io::Error::other("chaos!"))
+///     .schedule(schedule)
+///     .on_phase_change(|from: Option<&str>, to: Option<&str>| {
+///         println!("chaos phase {:?} -> {:?}", from, to);
+///     })
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChaosSchedule {
+    phases: Vec<ChaosPhase>,
+    repeat: bool,
+    state: Arc<Mutex<ScheduleState>>,
+}
+
+impl ChaosSchedule {
+    /// Create an empty schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a phase to the schedule.
+    pub fn phase(mut self, phase: ChaosPhase) -> Self {
+        self.phases.push(phase);
+        self
+    }
+
+    /// Restart from the first phase after the last phase has elapsed.
+    ///
+    /// Default: false
+    pub fn repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns the phases of this schedule.
+    pub fn phases(&self) -> &[ChaosPhase] {
+        &self.phases
+    }
+
+    /// Returns the combined duration of all phases.
+    pub fn total_duration(&self) -> Duration {
+        self.phases.iter().map(|p| p.duration).sum()
+    }
+
+    /// Restart the schedule clock at the next request.
+    pub fn reset(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        *state = ScheduleState::default();
+    }
+
+    /// Returns the phase index active at `elapsed`, if any.
+    fn index_at(&self, elapsed: Duration) -> Option<usize> {
+        let total = self.total_duration();
+        if total.is_zero() {
+            return None;
+        }
+
+        let mut offset = if self.repeat {
+            Duration::from_nanos((elapsed.as_nanos() % total.as_nanos()) as u64)
+        } else if elapsed >= total {
+            return None;
+        } else {
+            elapsed
+        };
+
+        for (index, phase) in self.phases.iter().enumerate() {
+            if offset < phase.duration {
+                return Some(index);
+            }
+            offset -= phase.duration;
+        }
+        None
+    }
+
+    /// Advance the schedule to `now`.
+    ///
+    /// Returns the active phase and, if it differs from the phase seen by the
+    /// previous request, the previously active phase.
+    pub(crate) fn advance(&self, now: Instant) -> Advance<'_> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let started_at = *state.started_at.get_or_insert(now);
+        let index = self.index_at(now.saturating_duration_since(started_at));
+
+        let changed = if index != state.current {
+            let previous = state.current.map(|i| self.phases[i].name.as_str());
+            state.current = index;
+            Some(previous)
+        } else {
+            None
+        };
+
+        Advance {
+            phase: index.map(|i| &self.phases[i]),
+            changed,
+        }
+    }
+}
+
+/// Result of advancing a [`ChaosSchedule`].
+pub(crate) struct Advance<'a> {
+    /// The active phase, or `None` once the schedule has completed.
+    pub(crate) phase: Option<&'a ChaosPhase>,
+    /// Set when the phase changed, holding the previous phase name.
+    pub(crate) changed: Option<Option<&'a str>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> ChaosSchedule {
+        ChaosSchedule::new()
+            .phase(ChaosPhase::new("baseline", Duration::from_secs(10)))
+            .phase(ChaosPhase::new("errors", Duration::from_secs(20)).error_rate(0.5))
+    }
+
+    #[test]
+    fn index_follows_phase_boundaries() {
+        let schedule = schedule();
+        assert_eq!(schedule.index_at(Duration::ZERO), Some(0));
+        assert_eq!(schedule.index_at(Duration::from_secs(9)), Some(0));
+        assert_eq!(schedule.index_at(Duration::from_secs(10)), Some(1));
+        assert_eq!(schedule.index_at(Duration::from_secs(29)), Some(1));
+        assert_eq!(schedule.index_at(Duration::from_secs(30)), None);
+    }
+
+    #[test]
+    fn repeating_schedule_wraps() {
+        let schedule = schedule().repeat(true);
+        assert_eq!(schedule.index_at(Duration::from_secs(30)), Some(0));
+        assert_eq!(schedule.index_at(Duration::from_secs(45)), Some(1));
+    }
+
+    #[test]
+    fn empty_schedule_has_no_phase() {
+        assert_eq!(ChaosSchedule::new().index_at(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn advance_reports_changes_once() {
+        let schedule = schedule();
+        let start = Instant::now();
+
+        let first = schedule.advance(start);
+        assert_eq!(first.phase.map(|p| p.name()), Some("baseline"));
+        assert_eq!(first.changed, Some(None));
+
+        let same = schedule.advance(start + Duration::from_secs(5));
+        assert!(same.changed.is_none());
+
+        let next = schedule.advance(start + Duration::from_secs(15));
+        assert_eq!(next.phase.map(|p| p.name()), Some("errors"));
+        assert_eq!(next.changed, Some(Some("baseline")));
+
+        let done = schedule.advance(start + Duration::from_secs(40));
+        assert!(done.phase.is_none());
+        assert_eq!(done.changed, Some(Some("errors")));
+    }
+}
@@ -1,8 +1,10 @@
 //! Chaos service implementation.
 
-use crate::config::{ChaosConfig, ErrorInjector};
+use crate::config::{ChaosConfig, ErrorInjector, NoResponseCorruption, ResponseCorruptor};
 use crate::events::ChaosEvent;
 use futures::future::BoxFuture;
+#[cfg(feature = "metrics")]
+use metrics::{counter, describe_counter, describe_histogram, histogram};
 use rand::rngs::StdRng;
 use rand::Rng;
 use std::sync::{Arc, Mutex};
@@ -10,21 +12,53 @@ use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 use tower_service::Service;
 
-/// A Tower service that injects chaos (errors and latency) into requests.
+/// A Tower service that injects chaos (errors, latency and corrupted
+/// responses) into requests.
 ///
 /// The type parameter `E` is the error injector type:
 /// - `Chaos<S, NoErrorInjection>` - latency-only chaos
 /// - `Chaos<S, CustomErrorFn<F>>` - custom error injection
+///
+/// The type parameter `C` is the response corruptor type.
 #[derive(Clone)]
-pub struct Chaos<S, E> {
+pub struct Chaos<S, E, C = NoResponseCorruption> {
     inner: S,
-    config: Arc<ChaosConfig<E>>,
+    config: Arc<ChaosConfig<E, C>>,
     rng: Arc<Mutex<StdRng>>,
 }
 
-impl<S, E> Chaos<S, E> {
+impl<S, E, C> Chaos<S, E, C> {
     /// Create a new chaos service.
-    pub(crate) fn new(inner: S, config: ChaosConfig<E>) -> Self {
+    pub(crate) fn new(inner: S, config: ChaosConfig<E, C>) -> Self {
+        #[cfg(feature = "metrics")]
+        {
+            describe_counter!(
+                "resilience_chaos_errors_injected_total",
+                "Total number of errors injected by the chaos layer"
+            );
+            describe_histogram!(
+                "resilience_chaos_latency_injected_seconds",
+                "Latency injected by the chaos layer"
+            );
+            describe_counter!(
+                "resilience_chaos_passthrough_total",
+                "Total number of requests passed through without injected faults"
+            );
+            describe_counter!(
+                "resilience_chaos_responses_corrupted_total",
+                "Total number of successful responses corrupted by the chaos layer"
+            );
+            describe_counter!(
+                "resilience_chaos_blackholed_total",
+                "Total number of requests blackholed by the chaos layer"
+            );
+            #[cfg(feature = "pressure")]
+            describe_counter!(
+                "resilience_chaos_pressure_injected_total",
+                "Total number of requests that had CPU or memory pressure applied"
+            );
+        }
+
         let rng = config.create_rng();
         Self {
             inner,
@@ -34,7 +68,7 @@ impl<S, E> Chaos<S, E> {
     }
 }
 
-impl<S, E, Req, Res, Err> Service<Req> for Chaos<S, E>
+impl<S, E, C, Req, Res, Err> Service<Req> for Chaos<S, E, C>
 where
     S: Service<Req, Response = Res, Error = Err> + Clone + Send + 'static,
     S::Future: Send + 'static,
@@ -42,6 +76,7 @@ where
     Res: Send + 'static,
     Err: Send + 'static,
     E: ErrorInjector<Req, Err> + Clone + 'static,
+    C: ResponseCorruptor<Res> + Clone + 'static,
 {
     type Response = Res;
     type Error = Err;
@@ -57,28 +92,45 @@ where
         let config = Arc::clone(&self.config);
         let rng = Arc::clone(&self.rng);
 
-        Box::pin(async move {
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("chaos", &config.name);
+
+        let future = async move {
+            let rates = config.current_rates();
+            let error_rate = rates
+                .error_rate
+                .unwrap_or_else(|| config.error_injector.error_rate());
+
+            let mut should_blackhole = false;
             let mut should_inject_latency = false;
             let mut latency_duration = Duration::ZERO;
             let mut error_roll: f64 = 1.0; // Default to no error injection
+            #[cfg(feature = "pressure")]
+            let mut pressure = None;
 
             // Determine what chaos to inject
             {
                 let mut rng = rng.lock().unwrap_or_else(|e| e.into_inner());
 
                 // Check if we should inject an error
-                if config.error_injector.error_rate() > 0.0 {
+                if error_rate > 0.0 {
                     error_roll = rng.random();
                 }
 
-                // Check if we should inject latency (only if not injecting error)
-                if config.latency_rate > 0.0 && error_roll >= config.error_injector.error_rate() {
+                // Check if we should blackhole (only if not injecting error)
+                if rates.blackhole_rate > 0.0 && error_roll >= error_rate {
+                    let blackhole_roll: f64 = rng.random();
+                    should_blackhole = blackhole_roll < rates.blackhole_rate;
+                }
+
+                // Check if we should inject latency (only if not injecting error or blackholing)
+                if rates.latency_rate > 0.0 && error_roll >= error_rate && !should_blackhole {
                     let latency_roll: f64 = rng.random();
-                    should_inject_latency = latency_roll < config.latency_rate;
+                    should_inject_latency = latency_roll < rates.latency_rate;
 
                     if should_inject_latency {
-                        let min_ms = config.min_latency.as_millis() as u64;
-                        let max_ms = config.max_latency.as_millis() as u64;
+                        let min_ms = rates.min_latency.as_millis() as u64;
+                        let max_ms = rates.max_latency.as_millis() as u64;
                         let delay_ms = if max_ms > min_ms {
                             rng.random_range(min_ms..=max_ms)
                         } else {
@@ -87,10 +139,28 @@ where
                         latency_duration = Duration::from_millis(delay_ms);
                     }
                 }
+
+                // Check if we should apply resource pressure (same conditions as latency)
+                #[cfg(feature = "pressure")]
+                if let Some(p) = rates.pressure {
+                    if p.rate > 0.0 && error_roll >= error_rate && !should_blackhole {
+                        let pressure_roll: f64 = rng.random();
+                        if pressure_roll < p.rate {
+                            pressure = Some(p);
+                        }
+                    }
+                }
             }
 
             // Check if error injection should happen
-            if let Some(err) = config.error_injector.inject_error(&req, error_roll) {
+            let injected = match rates.error_rate {
+                Some(rate) if error_roll < rate => config
+                    .error_injector
+                    .generate_error(&req, error_roll / rate),
+                Some(_) => None,
+                None => config.error_injector.inject_error(&req, error_roll),
+            };
+            if let Some(err) = injected {
                 let event = ChaosEvent::ErrorInjected {
                     pattern_name: config.name.clone(),
                     timestamp: Instant::now(),
@@ -104,12 +174,38 @@ where
                 );
 
                 #[cfg(feature = "metrics")]
-                metrics::counter!("chaos.errors_injected", "layer" => config.name.clone())
+                counter!("resilience_chaos_errors_injected_total", "name" => config.name.clone())
                     .increment(1);
 
                 return Err(err);
             }
 
+            // Hold the request without completing it if determined
+            if should_blackhole {
+                let event = ChaosEvent::Blackholed {
+                    pattern_name: config.name.clone(),
+                    timestamp: Instant::now(),
+                    cap: config.blackhole_cap,
+                };
+                config.event_listeners.emit(&event);
+
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    chaos_layer = %config.name,
+                    cap_ms = config.blackhole_cap.map(|cap| cap.as_millis() as u64),
+                    "chaos: request blackholed"
+                );
+
+                #[cfg(feature = "metrics")]
+                counter!("resilience_chaos_blackholed_total", "name" => config.name.clone())
+                    .increment(1);
+
+                match config.blackhole_cap {
+                    Some(cap) => tokio::time::sleep(cap).await,
+                    None => std::future::pending::<()>().await,
+                }
+            }
+
             // Inject latency if determined
             if should_inject_latency {
                 let event = ChaosEvent::LatencyInjected {
@@ -127,18 +223,42 @@ where
                 );
 
                 #[cfg(feature = "metrics")]
-                {
-                    metrics::counter!("chaos.latency_injections", "layer" => config.name.clone())
-                        .increment(1);
-                    metrics::histogram!("chaos.injected_latency_ms", "layer" => config.name.clone())
-                        .record(latency_duration.as_millis() as f64);
-                }
+                histogram!("resilience_chaos_latency_injected_seconds", "name" => config.name.clone())
+                    .record(latency_duration.as_secs_f64());
 
                 tokio::time::sleep(latency_duration).await;
             }
 
+            // Burn CPU and hold memory until the inner service responds
+            #[cfg(feature = "pressure")]
+            let _held = pressure.map(|pressure| {
+                let event = ChaosEvent::PressureInjected {
+                    pattern_name: config.name.clone(),
+                    timestamp: Instant::now(),
+                    cpu: pressure.cpu,
+                    memory: pressure.memory,
+                };
+                config.event_listeners.emit(&event);
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    chaos_layer = %config.name,
+                    cpu_ms = pressure.cpu.as_millis(),
+                    memory_bytes = pressure.memory,
+                    "chaos: resource pressure injected"
+                );
+
+                #[cfg(feature = "metrics")]
+                counter!("resilience_chaos_pressure_injected_total", "name" => config.name.clone())
+                    .increment(1);
+
+                pressure.apply()
+            });
+            #[cfg(not(feature = "pressure"))]
+            let pressure: Option<()> = None;
+
             // Pass through (no chaos or after latency)
-            if !should_inject_latency {
+            if !should_inject_latency && !should_blackhole && pressure.is_none() {
                 let event = ChaosEvent::PassedThrough {
                     pattern_name: config.name.clone(),
                     timestamp: Instant::now(),
@@ -146,11 +266,50 @@ where
                 config.event_listeners.emit(&event);
 
                 #[cfg(feature = "metrics")]
-                metrics::counter!("chaos.passed_through", "layer" => config.name.clone())
+                counter!("resilience_chaos_passthrough_total", "name" => config.name.clone())
                     .increment(1);
             }
 
-            inner.call(req).await
-        })
+            let res = inner.call(req).await?;
+
+            // Corrupt the successful response if determined
+            if rates.corruption_rate > 0.0 && config.response_corruptor.is_enabled() {
+                let corruption_roll: f64 = {
+                    let mut rng = rng.lock().unwrap_or_else(|e| e.into_inner());
+                    rng.random()
+                };
+
+                if corruption_roll < rates.corruption_rate {
+                    let event = ChaosEvent::ResponseCorrupted {
+                        pattern_name: config.name.clone(),
+                        timestamp: Instant::now(),
+                    };
+                    config.event_listeners.emit(&event);
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        chaos_layer = %config.name,
+                        "chaos: response corrupted"
+                    );
+
+                    #[cfg(feature = "metrics")]
+                    counter!("resilience_chaos_responses_corrupted_total", "name" => config.name.clone())
+                        .increment(1);
+
+                    return Ok(config.response_corruptor.corrupt(res));
+                }
+            }
+
+            Ok(res)
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_plain_result,
+        );
+
+        Box::pin(future)
     }
 }
@@ -2,9 +2,10 @@ use crate::config::{CircuitBreakerConfig, FailureModel, SlidingWindowType};
 use crate::events::CircuitBreakerEvent;
 #[cfg(feature = "metrics")]
 use metrics::{counter, gauge, histogram};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::atomic::{AtomicU8, Ordering};
-use std::time::{Duration, Instant};
+use std::time::Duration;
+use tower_resilience_core::time::Instant;
 
 /// Represents the state of the circuit breaker.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,6 +44,18 @@ pub struct CircuitMetrics {
     pub slow_call_rate: f64,
     /// Time since the last state transition.
     pub time_since_state_change: std::time::Duration,
+    /// Failed calls in the sliding window, by the category assigned with
+    /// [`error_category`](crate::CircuitBreakerConfigBuilder::error_category).
+    ///
+    /// Empty unless categories are configured. Failures without a category
+    /// are only counted in `failure_count`.
+    pub failures_by_category: BTreeMap<&'static str, usize>,
+    /// [`failures_by_category`](Self::failures_by_category) as it was when
+    /// the circuit last opened, showing which errors tripped it.
+    ///
+    /// Kept until the circuit opens again, since the window itself is
+    /// cleared on every state transition.
+    pub failures_by_category_at_open: BTreeMap<&'static str, usize>,
 }
 
 impl CircuitState {
@@ -62,17 +75,20 @@ struct CallRecord {
     timestamp: Instant,
     is_failure: bool,
     is_slow: bool,
+    category: Option<&'static str>,
 }
 
 pub(crate) struct Circuit {
     state: CircuitState,
     state_atomic: std::sync::Arc<AtomicU8>,
-    last_state_change: std::time::Instant,
+    last_state_change: Instant,
     // Count-based window tracking
     failure_count: usize,
     success_count: usize,
     total_count: usize,
     slow_call_count: usize,
+    failure_categories: BTreeMap<&'static str, usize>,
+    categories_at_open: BTreeMap<&'static str, usize>,
     // Time-based window tracking
     call_records: VecDeque<CallRecord>,
     // FailureModel::ConsecutiveFailures tracking. Resets to 0 on every
@@ -82,9 +98,10 @@ pub(crate) struct Circuit {
 
 impl Default for Circuit {
     fn default() -> Self {
-        Self::new_with_atomic(std::sync::Arc::new(AtomicU8::new(
-            CircuitState::Closed as u8,
-        )))
+        Self::new_with_atomic(
+            std::sync::Arc::new(AtomicU8::new(CircuitState::Closed as u8)),
+            Instant::now(),
+        )
     }
 }
 
@@ -94,20 +111,29 @@ impl Circuit {
         Self::default()
     }
 
-    pub(crate) fn new_with_atomic(state_atomic: std::sync::Arc<AtomicU8>) -> Self {
+    pub(crate) fn new_with_atomic(state_atomic: std::sync::Arc<AtomicU8>, now: Instant) -> Self {
         Self {
             state: CircuitState::Closed,
             state_atomic,
-            last_state_change: std::time::Instant::now(),
+            last_state_change: now,
             failure_count: 0,
             success_count: 0,
             total_count: 0,
             slow_call_count: 0,
+            failure_categories: BTreeMap::new(),
+            categories_at_open: BTreeMap::new(),
             call_records: VecDeque::new(),
             consecutive_failures: 0,
         }
     }
 
+    /// Starts the circuit in `state` without emitting a transition.
+    pub(crate) fn with_state(mut self, state: CircuitState) -> Self {
+        self.state = state;
+        self.state_atomic.store(state as u8, Ordering::Release);
+        self
+    }
+
     pub fn state(&self) -> CircuitState {
         self.state
     }
@@ -125,7 +151,7 @@ impl Circuit {
                     self.success_count,
                     self.slow_call_count,
                 ),
-                SlidingWindowType::TimeBased => self.time_based_stats(),
+                SlidingWindowType::TimeBased | SlidingWindowType::Hybrid => self.time_based_stats(),
             };
 
         let failure_rate = if total_calls > 0 {
@@ -148,13 +174,17 @@ impl Circuit {
             slow_call_count,
             failure_rate,
             slow_call_rate,
-            time_since_state_change: self.last_state_change.elapsed(),
+            time_since_state_change: config
+                .clock
+                .now()
+                .saturating_duration_since(self.last_state_change),
+            failures_by_category: self.window_categories(config),
+            failures_by_category_at_open: self.categories_at_open.clone(),
         }
     }
 
     /// Clean up old records from the time-based window.
-    fn cleanup_old_records(&mut self, window_duration: Duration) {
-        let now = Instant::now();
+    fn cleanup_old_records(&mut self, window_duration: Duration, now: Instant) {
         while let Some(record) = self.call_records.front() {
             if now.duration_since(record.timestamp) > window_duration {
                 self.call_records.pop_front();
@@ -164,6 +194,34 @@ impl Circuit {
         }
     }
 
+    /// Drop the oldest records so at most `size` remain (hybrid window).
+    fn trim_to_size(&mut self, size: usize) {
+        while self.call_records.len() > size {
+            self.call_records.pop_front();
+        }
+    }
+
+    /// Failure counts per category in the current window.
+    fn window_categories<C>(
+        &self,
+        config: &CircuitBreakerConfig<C>,
+    ) -> BTreeMap<&'static str, usize> {
+        match config.sliding_window_type {
+            SlidingWindowType::CountBased => self.failure_categories.clone(),
+            SlidingWindowType::TimeBased | SlidingWindowType::Hybrid => {
+                let mut categories = BTreeMap::new();
+                for category in self
+                    .call_records
+                    .iter()
+                    .filter_map(|record| record.category)
+                {
+                    *categories.entry(category).or_insert(0) += 1;
+                }
+                categories
+            }
+        }
+    }
+
     /// Calculate statistics from time-based window.
     fn time_based_stats(&self) -> (usize, usize, usize, usize) {
         let mut total = 0;
@@ -209,14 +267,18 @@ impl Circuit {
                     self.slow_call_count += 1;
                 }
             }
-            SlidingWindowType::TimeBased => {
+            SlidingWindowType::TimeBased | SlidingWindowType::Hybrid => {
                 if let Some(window_duration) = config.sliding_window_duration {
-                    self.cleanup_old_records(window_duration);
+                    self.cleanup_old_records(window_duration, config.clock.now());
                     self.call_records.push_back(CallRecord {
-                        timestamp: Instant::now(),
+                        timestamp: config.clock.now(),
                         is_failure: false,
                         is_slow,
+                        category: None,
                     });
+                    if config.sliding_window_type == SlidingWindowType::Hybrid {
+                        self.trim_to_size(config.sliding_window_size);
+                    }
                 }
             }
         }
@@ -233,7 +295,7 @@ impl Circuit {
                 });
 
             #[cfg(feature = "metrics")]
-            counter!("circuitbreaker_slow_calls_total", "circuitbreaker" => config.name.clone())
+            counter!("resilience_circuitbreaker_slow_calls_total", "name" => config.name.clone())
                 .increment(1);
         }
 
@@ -245,11 +307,20 @@ impl Circuit {
                 timestamp: Instant::now(),
                 state: self.state,
             });
+        if self.state == CircuitState::HalfOpen {
+            config
+                .event_listeners
+                .emit(&CircuitBreakerEvent::ProbeSucceeded {
+                    pattern_name: config.name.clone(),
+                    timestamp: Instant::now(),
+                    duration,
+                });
+        }
 
         #[cfg(feature = "metrics")]
         {
-            counter!("circuitbreaker_calls_total", "circuitbreaker" => config.name.clone(), "outcome" => "success").increment(1);
-            histogram!("circuitbreaker_call_duration_seconds", "circuitbreaker" => config.name.clone())
+            counter!("resilience_circuitbreaker_calls_total", "name" => config.name.clone(), "outcome" => "success").increment(1);
+            histogram!("resilience_circuitbreaker_call_duration_seconds", "name" => config.name.clone())
                 .record(duration.as_secs_f64());
         }
 
@@ -257,7 +328,9 @@ impl Circuit {
             CircuitState::HalfOpen => {
                 let success_count = match config.sliding_window_type {
                     SlidingWindowType::CountBased => self.success_count,
-                    SlidingWindowType::TimeBased => self.time_based_stats().2,
+                    SlidingWindowType::TimeBased | SlidingWindowType::Hybrid => {
+                        self.time_based_stats().2
+                    }
                 };
                 if success_count >= config.permitted_calls_in_half_open {
                     self.transition_to(CircuitState::Closed, config);
@@ -269,10 +342,25 @@ impl Circuit {
         }
     }
 
+    /// Records a call whose outcome is ignored: no window statistics change.
+    pub fn record_ignored<C>(&mut self, config: &CircuitBreakerConfig<C>) {
+        config
+            .event_listeners
+            .emit(&CircuitBreakerEvent::CallIgnored {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+                state: self.state,
+            });
+
+        #[cfg(feature = "metrics")]
+        counter!("resilience_circuitbreaker_calls_total", "name" => config.name.clone(), "outcome" => "ignored").increment(1);
+    }
+
     pub fn record_failure<C>(
         &mut self,
         config: &CircuitBreakerConfig<C>,
         duration: std::time::Duration,
+        category: Option<&'static str>,
     ) {
         let is_slow = config
             .slow_call_duration_threshold
@@ -292,15 +380,22 @@ impl Circuit {
                 if is_slow {
                     self.slow_call_count += 1;
                 }
+                if let Some(category) = category {
+                    *self.failure_categories.entry(category).or_insert(0) += 1;
+                }
             }
-            SlidingWindowType::TimeBased => {
+            SlidingWindowType::TimeBased | SlidingWindowType::Hybrid => {
                 if let Some(window_duration) = config.sliding_window_duration {
-                    self.cleanup_old_records(window_duration);
+                    self.cleanup_old_records(window_duration, config.clock.now());
                     self.call_records.push_back(CallRecord {
-                        timestamp: Instant::now(),
+                        timestamp: config.clock.now(),
                         is_failure: true,
                         is_slow,
+                        category,
                     });
+                    if config.sliding_window_type == SlidingWindowType::Hybrid {
+                        self.trim_to_size(config.sliding_window_size);
+                    }
                 }
             }
         }
@@ -317,7 +412,7 @@ impl Circuit {
                 });
 
             #[cfg(feature = "metrics")]
-            counter!("circuitbreaker_slow_calls_total", "circuitbreaker" => config.name.clone())
+            counter!("resilience_circuitbreaker_slow_calls_total", "name" => config.name.clone())
                 .increment(1);
         }
 
@@ -329,11 +424,23 @@ impl Circuit {
                 timestamp: Instant::now(),
                 state: self.state,
             });
+        if self.state == CircuitState::HalfOpen {
+            config
+                .event_listeners
+                .emit(&CircuitBreakerEvent::ProbeFailed {
+                    pattern_name: config.name.clone(),
+                    timestamp: Instant::now(),
+                    duration,
+                });
+        }
 
         #[cfg(feature = "metrics")]
         {
-            counter!("circuitbreaker_calls_total", "circuitbreaker" => config.name.clone(), "outcome" => "failure").increment(1);
-            histogram!("circuitbreaker_call_duration_seconds", "circuitbreaker" => config.name.clone())
+            counter!("resilience_circuitbreaker_calls_total", "name" => config.name.clone(), "outcome" => "failure").increment(1);
+            if let Some(category) = category {
+                counter!("resilience_circuitbreaker_failures_total", "name" => config.name.clone(), "category" => category).increment(1);
+            }
+            histogram!("resilience_circuitbreaker_call_duration_seconds", "name" => config.name.clone())
                 .record(duration.as_secs_f64());
         }
 
@@ -360,7 +467,12 @@ impl Circuit {
                 true
             }
             CircuitState::Open => {
-                if self.last_state_change.elapsed() >= config.wait_duration_in_open {
+                if config
+                    .clock
+                    .now()
+                    .saturating_duration_since(self.last_state_change)
+                    >= config.thresholds.wait_duration_in_open()
+                {
                     self.transition_to(CircuitState::HalfOpen, config);
                     config
                         .event_listeners
@@ -369,6 +481,7 @@ impl Circuit {
                             timestamp: Instant::now(),
                             state: self.state,
                         });
+                    Self::emit_probe_permitted(config);
                     true
                 } else {
                     config
@@ -391,6 +504,7 @@ impl Circuit {
                             timestamp: Instant::now(),
                             state: self.state,
                         });
+                    Self::emit_probe_permitted(config);
                 } else {
                     config
                         .event_listeners
@@ -414,12 +528,15 @@ impl Circuit {
         match self.state {
             CircuitState::Closed => Ok(()),
             CircuitState::Open => {
-                let elapsed = self.last_state_change.elapsed();
-                if elapsed >= config.wait_duration_in_open {
+                let elapsed = config
+                    .clock
+                    .now()
+                    .saturating_duration_since(self.last_state_change);
+                if elapsed >= config.thresholds.wait_duration_in_open() {
                     // Wait has elapsed; try_acquire will transition to HalfOpen
                     Ok(())
                 } else {
-                    Err(config.wait_duration_in_open - elapsed)
+                    Err(config.thresholds.wait_duration_in_open() - elapsed)
                 }
             }
             CircuitState::HalfOpen => {
@@ -427,7 +544,7 @@ impl Circuit {
                     Ok(())
                 } else {
                     // Half-open slots full; wait for resolution
-                    Err(config.wait_duration_in_open)
+                    Err(config.thresholds.wait_duration_in_open())
                 }
             }
         }
@@ -445,6 +562,15 @@ impl Circuit {
         self.transition_to(CircuitState::Closed, config);
     }
 
+    fn emit_probe_permitted<C>(config: &CircuitBreakerConfig<C>) {
+        config
+            .event_listeners
+            .emit(&CircuitBreakerEvent::ProbePermitted {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+            });
+    }
+
     fn transition_to<C>(&mut self, state: CircuitState, config: &CircuitBreakerConfig<C>) {
         if self.state == state {
             return;
@@ -468,8 +594,8 @@ impl Circuit {
         #[cfg(feature = "metrics")]
         {
             counter!(
-                "circuitbreaker_transitions_total",
-                "circuitbreaker" => config.name.clone(),
+                "resilience_circuitbreaker_transitions_total",
+                "name" => config.name.clone(),
                 "from" => match from_state {
                     CircuitState::Closed => "Closed",
                     CircuitState::Open => "Open",
@@ -483,7 +609,7 @@ impl Circuit {
             )
             .increment(1);
 
-            gauge!("circuitbreaker_state", "circuitbreaker" => config.name.clone(), "state" => match state {
+            gauge!("resilience_circuitbreaker_state", "name" => config.name.clone(), "state" => match state {
                 CircuitState::Closed => "Closed",
                 CircuitState::Open => "Open",
                 CircuitState::HalfOpen => "HalfOpen",
@@ -491,13 +617,18 @@ impl Circuit {
             .set(1.0);
         }
 
+        if state == CircuitState::Open {
+            self.categories_at_open = self.window_categories(config);
+        }
+
         self.state = state;
         self.state_atomic.store(state as u8, Ordering::Release);
-        self.last_state_change = std::time::Instant::now();
+        self.last_state_change = config.clock.now();
         self.success_count = 0;
         self.failure_count = 0;
         self.total_count = 0;
         self.slow_call_count = 0;
+        self.failure_categories.clear();
         self.call_records.clear();
         self.consecutive_failures = 0;
     }
@@ -511,39 +642,48 @@ impl Circuit {
                     self.success_count,
                     self.slow_call_count,
                 ),
-                SlidingWindowType::TimeBased => {
+                SlidingWindowType::TimeBased | SlidingWindowType::Hybrid => {
                     if let Some(window_duration) = config.sliding_window_duration {
-                        self.cleanup_old_records(window_duration);
+                        self.cleanup_old_records(window_duration, config.clock.now());
                     }
                     self.time_based_stats()
                 }
             };
 
+        // Count-based windows wait until full; hybrid windows wait until the
+        // circuit has been observing for the whole window duration.
+        let window_ready = match config.sliding_window_type {
+            SlidingWindowType::CountBased => total_count >= config.sliding_window_size,
+            SlidingWindowType::TimeBased => true,
+            SlidingWindowType::Hybrid => config.sliding_window_duration.is_none_or(|window| {
+                config
+                    .clock
+                    .now()
+                    .saturating_duration_since(self.last_state_change)
+                    >= window
+            }),
+        };
+
         // Slow-call detection runs in both failure models -- a circuit that
         // is healthy by error rate but degraded by latency should still open.
         // Sliding-window gating still applies to the slow-call evaluation.
         let slow_should_open = config.slow_call_duration_threshold.is_some()
             && total_count >= config.minimum_number_of_calls
-            && !(config.sliding_window_type == SlidingWindowType::CountBased
-                && total_count < config.sliding_window_size)
+            && window_ready
             && {
                 let slow_call_rate = slow_call_count as f64 / total_count as f64;
-                slow_call_rate >= config.slow_call_rate_threshold
+                slow_call_rate >= config.thresholds.slow_call_rate()
             };
 
         let failure_should_open = match config.failure_model {
             FailureModel::SlidingWindow => {
-                // Don't evaluate until minimum calls threshold is met
-                if total_count < config.minimum_number_of_calls {
-                    false
-                } else if config.sliding_window_type == SlidingWindowType::CountBased
-                    && total_count < config.sliding_window_size
-                {
-                    // For count-based window, also wait until window is full
+                // Don't evaluate until minimum calls threshold is met and
+                // the window is ready
+                if total_count < config.minimum_number_of_calls || !window_ready {
                     false
                 } else {
                     let failure_rate = failure_count as f64 / total_count as f64;
-                    failure_rate >= config.failure_rate_threshold
+                    failure_rate >= config.thresholds.failure_rate()
                 }
             }
             FailureModel::ConsecutiveFailures { k } => self.consecutive_failures >= k,
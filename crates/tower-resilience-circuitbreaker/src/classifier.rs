@@ -1,8 +1,115 @@
 //! Failure classification for circuit breaker decisions.
 //!
 //! This module re-exports the [`FailureClassifier`] trait and implementations
-//! from [`tower_resilience_core::classifier`] for convenience.
+//! from [`tower_resilience_core::classifier`] for convenience, along with
+//! [`IgnoringClassifier`] for outcomes that should not be recorded at all and
+//! [`CategorizingClassifier`] for breaking failures down by error class.
 //!
 //! See the core module documentation for full details and examples.
 
+use std::sync::Arc;
+
 pub use tower_resilience_core::classifier::{DefaultClassifier, FailureClassifier, FnClassifier};
+
+/// A failure classifier that also ignores some outcomes entirely.
+///
+/// Created by
+/// [`CircuitBreakerConfigBuilder::ignore_classifier`](crate::CircuitBreakerConfigBuilder::ignore_classifier).
+/// Results matched by the ignore predicate are not recorded, so they affect
+/// neither the failure rate nor the call count. All other results are
+/// classified by the wrapped classifier.
+#[derive(Clone)]
+pub struct IgnoringClassifier<C, F> {
+    inner: C,
+    ignore: Arc<F>,
+}
+
+impl<C, F> IgnoringClassifier<C, F> {
+    /// Wraps `inner`, ignoring results for which `ignore` returns `true`.
+    pub fn new(inner: C, ignore: F) -> Self {
+        Self {
+            inner,
+            ignore: Arc::new(ignore),
+        }
+    }
+}
+
+impl<C, F, Res, Err> FailureClassifier<Res, Err> for IgnoringClassifier<C, F>
+where
+    C: FailureClassifier<Res, Err>,
+    F: Fn(&Result<Res, Err>) -> bool + Send + Sync,
+{
+    fn classify(&self, result: &Result<Res, Err>) -> bool {
+        self.inner.classify(result)
+    }
+
+    fn is_ignored(&self, result: &Result<Res, Err>) -> bool {
+        (self.ignore)(result) || self.inner.is_ignored(result)
+    }
+
+    fn category(&self, result: &Result<Res, Err>) -> Option<&'static str> {
+        self.inner.category(result)
+    }
+}
+
+impl<C: std::fmt::Debug, F> std::fmt::Debug for IgnoringClassifier<C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IgnoringClassifier")
+            .field("inner", &self.inner)
+            .field("ignore", &"<closure>")
+            .finish()
+    }
+}
+
+/// A failure classifier that also names the category of each error.
+///
+/// Created by
+/// [`CircuitBreakerConfigBuilder::error_category`](crate::CircuitBreakerConfigBuilder::error_category).
+/// Failures are counted per category in
+/// [`CircuitMetrics::failures_by_category`](crate::CircuitMetrics::failures_by_category).
+/// Classification and ignoring are delegated to the wrapped classifier.
+#[derive(Clone)]
+pub struct CategorizingClassifier<C, F> {
+    inner: C,
+    category: Arc<F>,
+}
+
+impl<C, F> CategorizingClassifier<C, F> {
+    /// Wraps `inner`, naming the category of each error with `category`.
+    pub fn new(inner: C, category: F) -> Self {
+        Self {
+            inner,
+            category: Arc::new(category),
+        }
+    }
+}
+
+impl<C, F, Res, Err> FailureClassifier<Res, Err> for CategorizingClassifier<C, F>
+where
+    C: FailureClassifier<Res, Err>,
+    F: Fn(&Err) -> &'static str + Send + Sync,
+{
+    fn classify(&self, result: &Result<Res, Err>) -> bool {
+        self.inner.classify(result)
+    }
+
+    fn is_ignored(&self, result: &Result<Res, Err>) -> bool {
+        self.inner.is_ignored(result)
+    }
+
+    fn category(&self, result: &Result<Res, Err>) -> Option<&'static str> {
+        match result {
+            Ok(_) => self.inner.category(result),
+            Err(err) => Some((self.category)(err)),
+        }
+    }
+}
+
+impl<C: std::fmt::Debug, F> std::fmt::Debug for CategorizingClassifier<C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CategorizingClassifier")
+            .field("inner", &self.inner)
+            .field("category", &"<closure>")
+            .finish()
+    }
+}
@@ -1,23 +1,35 @@
-use std::sync::atomic::AtomicU8;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::sync::Mutex;
-use tower_resilience_core::EventListeners;
+use tower_resilience_core::{Clock, EventListeners, SharedClock, SystemClock};
 
 use crate::circuit::{Circuit, CircuitState};
-use crate::classifier::{DefaultClassifier, FnClassifier};
+use crate::classifier::{
+    CategorizingClassifier, DefaultClassifier, FnClassifier, IgnoringClassifier,
+};
 use crate::events::CircuitBreakerEvent;
 use crate::handle::CircuitBreakerHandle;
 use crate::layer::SharedCircuit;
 
 /// Type of sliding window used for tracking calls.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SlidingWindowType {
     /// Count-based window tracks the last N calls.
     CountBased,
     /// Time-based window tracks calls within a time duration.
     TimeBased,
+    /// Hybrid window tracks at most the last N calls within a time duration.
+    ///
+    /// Calls expire once they are older than `sliding_window_duration` or
+    /// fall outside the last `sliding_window_size` calls. Thresholds are only
+    /// evaluated once `minimum_number_of_calls` have been recorded *and* the
+    /// circuit has been observing for at least `sliding_window_duration`, so a
+    /// short burst of failures right after a state change cannot trip it.
+    Hybrid,
 }
 
 /// Trip-condition model used by the circuit breaker.
@@ -87,20 +99,97 @@ pub enum FailureModel {
 /// - `CircuitBreakerConfig<DefaultClassifier>` - uses the default classifier (errors = failures)
 /// - `CircuitBreakerConfig<FnClassifier<F>>` - uses a custom classifier function
 pub struct CircuitBreakerConfig<C> {
-    pub(crate) failure_rate_threshold: f64,
+    pub(crate) thresholds: Arc<Thresholds>,
     pub(crate) sliding_window_type: SlidingWindowType,
     pub(crate) sliding_window_size: usize,
     pub(crate) sliding_window_duration: Option<Duration>,
-    pub(crate) wait_duration_in_open: Duration,
     pub(crate) permitted_calls_in_half_open: usize,
     pub(crate) minimum_number_of_calls: usize,
     pub(crate) failure_classifier: C,
     pub(crate) slow_call_duration_threshold: Option<Duration>,
-    pub(crate) slow_call_rate_threshold: f64,
     pub(crate) failure_model: FailureModel,
     pub(crate) event_listeners: EventListeners<CircuitBreakerEvent>,
     pub(crate) name: String,
     pub(crate) backpressure: bool,
+    pub(crate) shadow_mode: bool,
+    pub(crate) clock: SharedClock,
+}
+
+impl<C: Clone> CircuitBreakerConfig<C> {
+    /// Returns a copy of this configuration under a different name.
+    ///
+    /// Thresholds stay shared, so reloading one copy changes them all.
+    pub(crate) fn renamed(&self, name: String) -> Self {
+        Self {
+            thresholds: Arc::clone(&self.thresholds),
+            sliding_window_type: self.sliding_window_type,
+            sliding_window_size: self.sliding_window_size,
+            sliding_window_duration: self.sliding_window_duration,
+            permitted_calls_in_half_open: self.permitted_calls_in_half_open,
+            minimum_number_of_calls: self.minimum_number_of_calls,
+            failure_classifier: self.failure_classifier.clone(),
+            slow_call_duration_threshold: self.slow_call_duration_threshold,
+            failure_model: self.failure_model,
+            event_listeners: self.event_listeners.clone(),
+            name,
+            backpressure: self.backpressure,
+            shadow_mode: self.shadow_mode,
+            clock: Arc::clone(&self.clock),
+        }
+    }
+}
+
+/// Thresholds that can be changed on a running circuit breaker through
+/// [`CircuitBreakerHandle::reload`](crate::CircuitBreakerHandle).
+#[derive(Debug)]
+pub(crate) struct Thresholds {
+    failure_rate: AtomicU64,
+    slow_call_rate: AtomicU64,
+    wait_duration_in_open_nanos: AtomicU64,
+}
+
+impl Thresholds {
+    pub(crate) fn new(
+        failure_rate: f64,
+        slow_call_rate: f64,
+        wait_duration_in_open: Duration,
+    ) -> Self {
+        let thresholds = Self {
+            failure_rate: AtomicU64::new(0),
+            slow_call_rate: AtomicU64::new(0),
+            wait_duration_in_open_nanos: AtomicU64::new(0),
+        };
+        thresholds.set_failure_rate(failure_rate);
+        thresholds.set_slow_call_rate(slow_call_rate);
+        thresholds.set_wait_duration_in_open(wait_duration_in_open);
+        thresholds
+    }
+
+    pub(crate) fn failure_rate(&self) -> f64 {
+        f64::from_bits(self.failure_rate.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_failure_rate(&self, rate: f64) {
+        self.failure_rate.store(rate.to_bits(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn slow_call_rate(&self) -> f64 {
+        f64::from_bits(self.slow_call_rate.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_slow_call_rate(&self, rate: f64) {
+        self.slow_call_rate.store(rate.to_bits(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn wait_duration_in_open(&self) -> Duration {
+        Duration::from_nanos(self.wait_duration_in_open_nanos.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_wait_duration_in_open(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.wait_duration_in_open_nanos
+            .store(nanos, Ordering::Relaxed);
+    }
 }
 
 /// Builder for configuring and constructing a circuit breaker.
@@ -152,6 +241,8 @@ pub struct CircuitBreakerConfigBuilder<C = DefaultClassifier> {
     event_listeners: EventListeners<CircuitBreakerEvent>,
     name: String,
     backpressure: bool,
+    shadow_mode: bool,
+    clock: SharedClock,
 }
 
 impl Default for CircuitBreakerConfigBuilder<DefaultClassifier> {
@@ -181,6 +272,8 @@ impl CircuitBreakerConfigBuilder<DefaultClassifier> {
             event_listeners: EventListeners::new(),
             name: String::from("<unnamed>"),
             backpressure: false,
+            shadow_mode: false,
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -204,7 +297,7 @@ impl<C> CircuitBreakerConfigBuilder<C> {
 
     /// Sets the size of the sliding window for failure rate calculation (count-based).
     ///
-    /// For count-based windows, this is the number of calls to track.
+    /// For count-based and hybrid windows, this is the number of calls to track.
     /// For time-based windows, this is used as the minimum calls threshold if not set explicitly.
     ///
     /// Default: 100
@@ -213,12 +306,12 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         self
     }
 
-    /// Sets the duration of the sliding window (time-based only).
+    /// Sets the duration of the sliding window (time-based and hybrid only).
     ///
-    /// Only used when `sliding_window_type` is `TimeBased`.
+    /// Only used when `sliding_window_type` is `TimeBased` or `Hybrid`.
     /// Calls older than this duration are excluded from failure rate calculation.
     ///
-    /// Default: None (must be set for time-based windows)
+    /// Default: None (must be set for time-based and hybrid windows)
     pub fn sliding_window_duration(mut self, duration: Duration) -> Self {
         self.sliding_window_duration = Some(duration);
         self
@@ -348,6 +441,38 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         self
     }
 
+    /// Runs the circuit breaker in shadow mode.
+    ///
+    /// The breaker records every call and moves between states exactly as it
+    /// would when enforcing, emitting the same events and metrics, but calls
+    /// it would reject still reach the inner service. Use this to validate
+    /// new thresholds against live traffic before turning them on.
+    ///
+    /// Outcomes of calls that would have been rejected are not recorded,
+    /// since an enforcing breaker would never have seen them, so recovery
+    /// still goes through half-open probes after `wait_duration_in_open`.
+    /// [`backpressure`](Self::backpressure) is ignored and fallbacks are not
+    /// called.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    ///
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .failure_rate_threshold(0.2)
+    ///     .shadow_mode(true)
+    ///     .on_state_transition(|from, to| {
+    ///         println!("would have moved from {:?} to {:?}", from, to);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn shadow_mode(mut self, shadow_mode: bool) -> Self {
+        self.shadow_mode = shadow_mode;
+        self
+    }
+
     /// Sets a custom failure classifier function.
     ///
     /// The classifier determines which results should be counted as failures
@@ -420,6 +545,8 @@ impl<C> CircuitBreakerConfigBuilder<C> {
             event_listeners: self.event_listeners,
             name: self.name,
             backpressure: self.backpressure,
+            shadow_mode: self.shadow_mode,
+            clock: self.clock,
         }
     }
 
@@ -491,6 +618,111 @@ impl<C> CircuitBreakerConfigBuilder<C> {
             event_listeners: self.event_listeners,
             name: self.name,
             backpressure: self.backpressure,
+            shadow_mode: self.shadow_mode,
+            clock: self.clock,
+        }
+    }
+
+    /// Ignores some outcomes entirely, recording them as neither success nor
+    /// failure.
+    ///
+    /// Results for which `ignore` returns `true` do not count towards the
+    /// failure rate, the slow-call rate or the minimum number of calls. Use it
+    /// for errors that say nothing about the health of the downstream service,
+    /// such as business validation errors. All other results are classified
+    /// as before.
+    ///
+    /// Ignored calls emit [`CircuitBreakerEvent::CallIgnored`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .ignore_classifier(|result: &Result<String, Error>| {
+    ///         matches!(result, Err(e) if e.kind() == ErrorKind::InvalidInput)
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn ignore_classifier<F, Res, Err>(
+        self,
+        ignore: F,
+    ) -> CircuitBreakerConfigBuilder<IgnoringClassifier<C, F>>
+    where
+        F: Fn(&Result<Res, Err>) -> bool + Send + Sync + 'static,
+    {
+        CircuitBreakerConfigBuilder {
+            failure_rate_threshold: self.failure_rate_threshold,
+            sliding_window_type: self.sliding_window_type,
+            sliding_window_size: self.sliding_window_size,
+            sliding_window_duration: self.sliding_window_duration,
+            wait_duration_in_open: self.wait_duration_in_open,
+            permitted_calls_in_half_open: self.permitted_calls_in_half_open,
+            failure_classifier: IgnoringClassifier::new(self.failure_classifier, ignore),
+            minimum_number_of_calls: self.minimum_number_of_calls,
+            slow_call_duration_threshold: self.slow_call_duration_threshold,
+            slow_call_rate_threshold: self.slow_call_rate_threshold,
+            failure_model: self.failure_model,
+            event_listeners: self.event_listeners,
+            name: self.name,
+            backpressure: self.backpressure,
+            shadow_mode: self.shadow_mode,
+            clock: self.clock,
+        }
+    }
+
+    /// Counts failures per error category.
+    ///
+    /// Every error classified as a failure is assigned the category returned
+    /// by `category`. Counts over the current window are reported in
+    /// [`CircuitMetrics::failures_by_category`](crate::CircuitMetrics::failures_by_category),
+    /// so when the circuit opens you can see which class of error drove it.
+    /// With the `metrics` feature, failures are also counted in
+    /// `resilience_circuitbreaker_failures_total{name, category}`.
+    ///
+    /// Categories do not change which results are failures. Successful
+    /// responses classified as failures have no category.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .error_category(|err: &Error| match err.kind() {
+    ///         ErrorKind::TimedOut => "timeout",
+    ///         ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset => "connection",
+    ///         _ => "other",
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn error_category<F, Err>(
+        self,
+        category: F,
+    ) -> CircuitBreakerConfigBuilder<CategorizingClassifier<C, F>>
+    where
+        F: Fn(&Err) -> &'static str + Send + Sync + 'static,
+    {
+        CircuitBreakerConfigBuilder {
+            failure_rate_threshold: self.failure_rate_threshold,
+            sliding_window_type: self.sliding_window_type,
+            sliding_window_size: self.sliding_window_size,
+            sliding_window_duration: self.sliding_window_duration,
+            wait_duration_in_open: self.wait_duration_in_open,
+            permitted_calls_in_half_open: self.permitted_calls_in_half_open,
+            failure_classifier: CategorizingClassifier::new(self.failure_classifier, category),
+            minimum_number_of_calls: self.minimum_number_of_calls,
+            slow_call_duration_threshold: self.slow_call_duration_threshold,
+            slow_call_rate_threshold: self.slow_call_rate_threshold,
+            failure_model: self.failure_model,
+            event_listeners: self.event_listeners,
+            name: self.name,
+            backpressure: self.backpressure,
+            shadow_mode: self.shadow_mode,
+            clock: self.clock,
         }
     }
 
@@ -552,6 +784,34 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         )
     }
 
+    /// Adds a listener that receives every circuit breaker event.
+    ///
+    /// Combined with [`FnListener::min_severity`](tower_resilience_core::FnListener::min_severity),
+    /// this drops the high-volume per-call events cheaply while state
+    /// transitions and rejections still come through.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_circuitbreaker::{CircuitBreakerEvent, CircuitBreakerLayer};
+    /// use tower_resilience_core::{FnListener, ResilienceEvent, Severity};
+    ///
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .on_event(
+    ///         FnListener::new(|event: &CircuitBreakerEvent| {
+    ///             println!("[{}] {}", event.severity(), event.event_type());
+    ///         })
+    ///         .min_severity(Severity::Warn),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn on_event<L>(mut self, listener: L) -> Self
+    where
+        L: tower_resilience_core::EventListener<CircuitBreakerEvent> + 'static,
+    {
+        self.event_listeners.add(listener);
+        self
+    }
+
     /// Registers a callback when the circuit breaker transitions between states.
     ///
     /// The circuit breaker has three states: Closed (normal operation), Open (failing),
@@ -679,6 +939,38 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         self
     }
 
+    /// Registers a callback when a call's outcome is ignored.
+    ///
+    /// This callback is invoked when a completed call matches the predicate
+    /// given to [`ignore_classifier`](Self::ignore_classifier) and is therefore
+    /// not recorded.
+    ///
+    /// # Callback Signature
+    /// `Fn()` - Called with no parameters when an outcome is ignored.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    ///
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .on_call_ignored(|| println!("Outcome ignored"))
+    ///     .build();
+    /// ```
+    pub fn on_call_ignored<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners
+            .add(tower_resilience_core::FnListener::new(
+                move |event: &CircuitBreakerEvent| {
+                    if matches!(event, CircuitBreakerEvent::CallIgnored { .. }) {
+                        f();
+                    }
+                },
+            ));
+        self
+    }
+
     /// Registers a callback when a successful call is recorded.
     ///
     /// This callback is invoked when a call completes successfully (as determined by the
@@ -802,6 +1094,76 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         self
     }
 
+    /// Registers a callback when a half-open probe call completes.
+    ///
+    /// Only calls made while the circuit is half-open are reported, so recovery
+    /// probing can be tracked separately from normal traffic. The same calls
+    /// are also reported to [`on_success`](Self::on_success) and
+    /// [`on_failure`](Self::on_failure).
+    ///
+    /// # Callback Signature
+    /// `Fn(bool, Duration)` - Called with whether the probe succeeded and how long it took.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    ///
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .on_probe_result(|succeeded, duration| {
+    ///         println!("Recovery probe succeeded={} in {:?}", succeeded, duration);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_probe_result<F>(mut self, f: F) -> Self
+    where
+        F: Fn(bool, Duration) + Send + Sync + 'static,
+    {
+        use tower_resilience_core::FnListener;
+        self.event_listeners.add(FnListener::new(
+            move |event: &CircuitBreakerEvent| match event {
+                CircuitBreakerEvent::ProbeSucceeded { duration, .. } => f(true, *duration),
+                CircuitBreakerEvent::ProbeFailed { duration, .. } => f(false, *duration),
+                _ => {}
+            },
+        ));
+        self
+    }
+
+    /// Sets the clock used to measure call durations and open-state waits.
+    ///
+    /// Defaults to the system clock. Pass a [`MockClock`](tower_resilience_core::MockClock)
+    /// to drive open and half-open transitions from tests without sleeping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    /// use tower_resilience_core::MockClock;
+    ///
+    /// let clock = MockClock::new();
+    /// let layer = CircuitBreakerLayer::builder()
+    ///     .wait_duration_in_open(Duration::from_secs(30))
+    ///     .clock(clock.clone())
+    ///     .build();
+    ///
+    /// // Later, in the test: skip the open wait instantly
+    /// clock.advance(Duration::from_secs(30));
+    /// ```
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the configuration and returns a CircuitBreakerLayer.
     pub fn build(self) -> crate::layer::CircuitBreakerLayer<C> {
         let config = self.into_config();
@@ -832,12 +1194,45 @@ impl<C> CircuitBreakerConfigBuilder<C> {
     ) -> (
         crate::layer::CircuitBreakerLayer<C>,
         CircuitBreakerHandle<C>,
+    ) {
+        self.build_with_state(CircuitState::Closed)
+    }
+
+    /// Builds a layer and handle whose shared circuit starts in `state`.
+    ///
+    /// Intended for tests: open and half-open behavior can be exercised
+    /// directly instead of by generating enough failures to trip the breaker.
+    /// An open circuit starts its wait at the configured clock's current time,
+    /// so combined with [`clock`](Self::clock) and a
+    /// [`MockClock`](tower_resilience_core::MockClock) the transition to
+    /// half-open is fully deterministic. No transition event is emitted for
+    /// the initial state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitState};
+    ///
+    /// let (layer, handle) = CircuitBreakerLayer::builder()
+    ///     .permitted_calls_in_half_open(1)
+    ///     .build_with_state(CircuitState::HalfOpen);
+    ///
+    /// assert_eq!(handle.state(), CircuitState::HalfOpen);
+    /// ```
+    #[doc(hidden)]
+    pub fn build_with_state(
+        self,
+        state: CircuitState,
+    ) -> (
+        crate::layer::CircuitBreakerLayer<C>,
+        CircuitBreakerHandle<C>,
     ) {
         let config = Arc::new(self.into_config());
         let state_atomic = Arc::new(AtomicU8::new(CircuitState::Closed as u8));
-        let circuit = Arc::new(Mutex::new(Circuit::new_with_atomic(Arc::clone(
-            &state_atomic,
-        ))));
+        let circuit = Arc::new(Mutex::new(
+            Circuit::new_with_atomic(Arc::clone(&state_atomic), config.clock.now())
+                .with_state(state),
+        ));
 
         let shared = SharedCircuit {
             circuit: Arc::clone(&circuit),
@@ -855,6 +1250,32 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         (layer, handle)
     }
 
+    /// Builds a group of circuit breakers that share one circuit.
+    ///
+    /// Layers created from the group with
+    /// [`CircuitBreakerGroup::layer`](crate::CircuitBreakerGroup::layer) record
+    /// into and are gated by the same circuit, so failures seen through any of
+    /// them can open it for all of them. Each layer keeps its own name for
+    /// events and metrics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+    ///
+    /// let group = CircuitBreakerLayer::builder()
+    ///     .failure_rate_threshold(0.5)
+    ///     .name("inventory")
+    ///     .build_group();
+    ///
+    /// let get_layer = group.layer("inventory-get");
+    /// let post_layer = group.layer("inventory-post");
+    /// ```
+    pub fn build_group(self) -> crate::group::CircuitBreakerGroup<C> {
+        let (_, handle) = self.build_with_handle();
+        crate::group::CircuitBreakerGroup::new(handle)
+    }
+
     fn into_config(self) -> CircuitBreakerConfig<C> {
         // Validate time-based window configuration
         if self.sliding_window_type == SlidingWindowType::TimeBased
@@ -862,6 +1283,11 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         {
             panic!("sliding_window_duration must be set when using TimeBased sliding window");
         }
+        if self.sliding_window_type == SlidingWindowType::Hybrid
+            && self.sliding_window_duration.is_none()
+        {
+            panic!("sliding_window_duration must be set when using Hybrid sliding window");
+        }
 
         // Validate failure model configuration
         if let FailureModel::ConsecutiveFailures { k } = self.failure_model {
@@ -869,22 +1295,26 @@ impl<C> CircuitBreakerConfigBuilder<C> {
         }
 
         CircuitBreakerConfig {
-            failure_rate_threshold: self.failure_rate_threshold,
+            thresholds: Arc::new(Thresholds::new(
+                self.failure_rate_threshold,
+                self.slow_call_rate_threshold,
+                self.wait_duration_in_open,
+            )),
             sliding_window_type: self.sliding_window_type,
             sliding_window_size: self.sliding_window_size,
             sliding_window_duration: self.sliding_window_duration,
-            wait_duration_in_open: self.wait_duration_in_open,
             permitted_calls_in_half_open: self.permitted_calls_in_half_open,
             failure_classifier: self.failure_classifier,
             minimum_number_of_calls: self
                 .minimum_number_of_calls
                 .unwrap_or(self.sliding_window_size),
             slow_call_duration_threshold: self.slow_call_duration_threshold,
-            slow_call_rate_threshold: self.slow_call_rate_threshold,
             failure_model: self.failure_model,
             event_listeners: self.event_listeners,
             name: self.name,
             backpressure: self.backpressure,
+            shadow_mode: self.shadow_mode,
+            clock: self.clock,
         }
     }
 }
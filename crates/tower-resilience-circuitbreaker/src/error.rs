@@ -1,18 +1,37 @@
-use thiserror::Error;
-use tower_resilience_core::ResilienceError;
+use std::fmt;
+use tower_resilience_core::{CircuitOpenError, PatternError, ResilienceError};
 
 /// Errors returned by the `CircuitBreaker` service.
-#[derive(Debug, Error)]
+///
+/// An open-circuit rejection reports [`CircuitOpenError`] as its source, so
+/// it can be recognized below other layers' errors.
+#[derive(Debug)]
 pub enum CircuitBreakerError<E> {
     /// The circuit is open; calls are not permitted.
-    #[error("circuit is open; call not permitted")]
     OpenCircuit,
 
     /// An error returned by the inner service.
-    #[error("inner service error: {0}")]
     Inner(E),
 }
 
+impl<E: fmt::Display> fmt::Display for CircuitBreakerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitBreakerError::OpenCircuit => write!(f, "circuit is open; call not permitted"),
+            CircuitBreakerError::Inner(e) => write!(f, "inner service error: {}", e),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for CircuitBreakerError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CircuitBreakerError::OpenCircuit => Some(&CircuitOpenError),
+            CircuitBreakerError::Inner(_) => None,
+        }
+    }
+}
+
 impl<E> CircuitBreakerError<E> {
     /// Returns true if the error indicates the circuit is open.
     pub fn is_circuit_open(&self) -> bool {
@@ -34,6 +53,32 @@ impl<E> From<E> for CircuitBreakerError<E> {
     }
 }
 
+impl<E> PatternError for CircuitBreakerError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        self.is_circuit_open()
+    }
+
+    fn is_circuit_open(&self) -> bool {
+        self.is_circuit_open()
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            CircuitBreakerError::Inner(e) => Some(e),
+            CircuitBreakerError::OpenCircuit => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            CircuitBreakerError::Inner(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 // Conversion to ResilienceError for zero-boilerplate error handling
 impl<E> From<CircuitBreakerError<E>> for ResilienceError<E> {
     fn from(err: CircuitBreakerError<E>) -> Self {
@@ -1,24 +1,38 @@
 use crate::CircuitState;
-use std::time::Instant;
-use tower_resilience_core::ResilienceEvent;
+use tower_resilience_core::time::Instant;
+use tower_resilience_core::{ResilienceEvent, Severity};
 
 /// Events emitted by the circuit breaker pattern.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum CircuitBreakerEvent {
     /// A call was permitted through the circuit breaker.
     CallPermitted {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         state: CircuitState,
     },
     /// A call was rejected because the circuit is open.
     CallRejected {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
     /// The circuit breaker transitioned between states.
     StateTransition {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         from_state: CircuitState,
         to_state: CircuitState,
@@ -26,22 +40,80 @@ pub enum CircuitBreakerEvent {
     /// A successful call was recorded.
     SuccessRecorded {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         state: CircuitState,
     },
     /// A failed call was recorded.
     FailureRecorded {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        state: CircuitState,
+    },
+    /// A call's outcome was ignored and not recorded.
+    CallIgnored {
+        pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         state: CircuitState,
     },
     /// A slow call was detected.
     SlowCallDetected {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         duration: std::time::Duration,
         state: CircuitState,
     },
+    /// A probe call was permitted while the circuit is half-open.
+    ///
+    /// Emitted alongside [`CallPermitted`](Self::CallPermitted) so recovery
+    /// probing can be told apart from normal traffic.
+    ProbePermitted {
+        pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A probe call succeeded while the circuit is half-open.
+    ///
+    /// Emitted alongside [`SuccessRecorded`](Self::SuccessRecorded).
+    ProbeSucceeded {
+        pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        duration: std::time::Duration,
+    },
+    /// A probe call failed while the circuit is half-open.
+    ///
+    /// Emitted alongside [`FailureRecorded`](Self::FailureRecorded).
+    ProbeFailed {
+        pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        duration: std::time::Duration,
+    },
 }
 
 impl ResilienceEvent for CircuitBreakerEvent {
@@ -52,7 +124,11 @@ impl ResilienceEvent for CircuitBreakerEvent {
             CircuitBreakerEvent::StateTransition { .. } => "state_transition",
             CircuitBreakerEvent::SuccessRecorded { .. } => "success_recorded",
             CircuitBreakerEvent::FailureRecorded { .. } => "failure_recorded",
+            CircuitBreakerEvent::CallIgnored { .. } => "call_ignored",
             CircuitBreakerEvent::SlowCallDetected { .. } => "slow_call_detected",
+            CircuitBreakerEvent::ProbePermitted { .. } => "probe_permitted",
+            CircuitBreakerEvent::ProbeSucceeded { .. } => "probe_succeeded",
+            CircuitBreakerEvent::ProbeFailed { .. } => "probe_failed",
         }
     }
 
@@ -63,7 +139,11 @@ impl ResilienceEvent for CircuitBreakerEvent {
             | CircuitBreakerEvent::StateTransition { timestamp, .. }
             | CircuitBreakerEvent::SuccessRecorded { timestamp, .. }
             | CircuitBreakerEvent::FailureRecorded { timestamp, .. }
-            | CircuitBreakerEvent::SlowCallDetected { timestamp, .. } => *timestamp,
+            | CircuitBreakerEvent::CallIgnored { timestamp, .. }
+            | CircuitBreakerEvent::SlowCallDetected { timestamp, .. }
+            | CircuitBreakerEvent::ProbePermitted { timestamp, .. }
+            | CircuitBreakerEvent::ProbeSucceeded { timestamp, .. }
+            | CircuitBreakerEvent::ProbeFailed { timestamp, .. } => *timestamp,
         }
     }
 
@@ -74,7 +154,32 @@ impl ResilienceEvent for CircuitBreakerEvent {
             | CircuitBreakerEvent::StateTransition { pattern_name, .. }
             | CircuitBreakerEvent::SuccessRecorded { pattern_name, .. }
             | CircuitBreakerEvent::FailureRecorded { pattern_name, .. }
-            | CircuitBreakerEvent::SlowCallDetected { pattern_name, .. } => pattern_name,
+            | CircuitBreakerEvent::CallIgnored { pattern_name, .. }
+            | CircuitBreakerEvent::SlowCallDetected { pattern_name, .. }
+            | CircuitBreakerEvent::ProbePermitted { pattern_name, .. }
+            | CircuitBreakerEvent::ProbeSucceeded { pattern_name, .. }
+            | CircuitBreakerEvent::ProbeFailed { pattern_name, .. } => pattern_name,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            CircuitBreakerEvent::StateTransition { to_state, .. } => {
+                if *to_state == CircuitState::Open {
+                    Severity::Error
+                } else {
+                    Severity::Warn
+                }
+            }
+            CircuitBreakerEvent::CallRejected { .. }
+            | CircuitBreakerEvent::FailureRecorded { .. }
+            | CircuitBreakerEvent::SlowCallDetected { .. }
+            | CircuitBreakerEvent::ProbeFailed { .. } => Severity::Warn,
+            CircuitBreakerEvent::CallPermitted { .. }
+            | CircuitBreakerEvent::SuccessRecorded { .. }
+            | CircuitBreakerEvent::CallIgnored { .. }
+            | CircuitBreakerEvent::ProbePermitted { .. }
+            | CircuitBreakerEvent::ProbeSucceeded { .. } => Severity::Info,
         }
     }
 }
@@ -123,6 +228,14 @@ mod tests {
             state: CircuitState::Closed,
         };
         assert_eq!(failure.event_type(), "failure_recorded");
+
+        let probe = CircuitBreakerEvent::ProbeFailed {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            duration: std::time::Duration::from_millis(5),
+        };
+        assert_eq!(probe.event_type(), "probe_failed");
+        assert_eq!(probe.pattern_name(), "test");
     }
 
     #[test]
@@ -135,4 +248,22 @@ mod tests {
         };
         assert_eq!(event.timestamp(), now);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_event() {
+        let event = CircuitBreakerEvent::StateTransition {
+            pattern_name: "payments".to_string(),
+            timestamp: Instant::now(),
+            from_state: CircuitState::Closed,
+            to_state: CircuitState::Open,
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "state_transition");
+        assert_eq!(json["pattern_name"], "payments");
+        assert_eq!(json["from_state"], "Closed");
+        assert_eq!(json["to_state"], "Open");
+        assert!(json["timestamp"].as_u64().unwrap() > 0);
+    }
 }
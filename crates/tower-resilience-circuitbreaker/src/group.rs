@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::classifier::DefaultClassifier;
+use crate::handle::CircuitBreakerHandle;
+use crate::layer::{CircuitBreakerLayer, SharedCircuit};
+
+/// A set of circuit breaker layers that share one circuit.
+///
+/// Useful when several routes reach the same upstream: a GET and a POST layer
+/// in the same group both feed and honor a single circuit, so an outage seen on
+/// one route also protects the other. Every layer created from the group keeps
+/// its own name in events and metrics.
+///
+/// Obtained from
+/// [`CircuitBreakerConfigBuilder::build_group()`](crate::CircuitBreakerConfigBuilder::build_group).
+///
+/// # Example
+///
+/// ```rust
+/// use tower::{Layer, Service, ServiceExt};
+/// use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitState};
+///
+/// # async fn example() {
+/// let group = CircuitBreakerLayer::builder()
+///     .sliding_window_size(10)
+///     .name("inventory")
+///     .build_group();
+///
+/// let mut get = group
+///     .layer("inventory-get")
+///     .layer(tower::service_fn(|_req: ()| async { Ok::<_, ()>(()) }));
+/// let mut post = group
+///     .layer("inventory-post")
+///     .layer(tower::service_fn(|_req: ()| async { Ok::<_, ()>(()) }));
+///
+/// // Opening the shared circuit affects every route
+/// group.handle().force_open().await;
+/// assert!(get.ready().await.unwrap().call(()).await.is_err());
+/// assert!(post.ready().await.unwrap().call(()).await.is_err());
+/// assert_eq!(group.handle().state(), CircuitState::Open);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CircuitBreakerGroup<C = DefaultClassifier> {
+    handle: CircuitBreakerHandle<C>,
+}
+
+impl<C> CircuitBreakerGroup<C> {
+    pub(crate) fn new(handle: CircuitBreakerHandle<C>) -> Self {
+        Self { handle }
+    }
+
+    /// Returns a handle observing and controlling the shared circuit.
+    pub fn handle(&self) -> CircuitBreakerHandle<C> {
+        CircuitBreakerHandle {
+            circuit: Arc::clone(&self.handle.circuit),
+            state_atomic: Arc::clone(&self.handle.state_atomic),
+            config: Arc::clone(&self.handle.config),
+        }
+    }
+
+    /// Returns the group's name, as set on the builder.
+    pub fn name(&self) -> &str {
+        &self.handle.config.name
+    }
+}
+
+impl<C: Clone> CircuitBreakerGroup<C> {
+    /// Creates a layer that shares the group's circuit, reporting events and
+    /// metrics under `name`.
+    pub fn layer(&self, name: impl Into<String>) -> CircuitBreakerLayer<C> {
+        let config = self.handle.config.renamed(name.into());
+        let shared = SharedCircuit {
+            circuit: Arc::clone(&self.handle.circuit),
+            state_atomic: Arc::clone(&self.handle.state_atomic),
+        };
+        CircuitBreakerLayer::new_with_shared(config, shared)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn members_keep_names_and_share_thresholds() {
+        let group = CircuitBreakerLayer::builder()
+            .name("upstream")
+            .build_group();
+        assert_eq!(group.name(), "upstream");
+
+        let service = tower::service_fn(|_req: ()| async { Ok::<_, ()>(()) });
+        let get = group.layer("upstream-get").layer_fn(service);
+        let post = group.layer("upstream-post").layer_fn(service);
+
+        assert_eq!(get.config.name, "upstream-get");
+        assert_eq!(post.config.name, "upstream-post");
+        assert!(Arc::ptr_eq(&get.circuit, &post.circuit));
+        assert!(Arc::ptr_eq(
+            &get.config.thresholds,
+            &group.handle().config.thresholds
+        ));
+    }
+}
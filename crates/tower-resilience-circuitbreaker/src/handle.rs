@@ -3,10 +3,13 @@ use std::sync::Arc;
 
 use tokio::sync::Mutex;
 
+use tower_resilience_core::Reloadable;
+
 use crate::circuit::{Circuit, CircuitMetrics, CircuitState};
 use crate::config::CircuitBreakerConfig;
+use crate::settings::CircuitBreakerSettings;
 
-/// A read-only handle for observing circuit breaker state.
+/// A handle for observing and controlling circuit breaker state.
 ///
 /// Obtained from [`crate::CircuitBreakerConfigBuilder::build_with_handle()`]. The handle
 /// is cheap to clone and safe to share across threads (`Clone + Send + Sync`).
@@ -32,6 +35,10 @@ use crate::config::CircuitBreakerConfig;
 /// let health = handle.health_status();
 /// assert_eq!(health, "healthy");
 /// ```
+///
+/// The handle also implements [`Reloadable`], so thresholds can be changed
+/// on the running breaker, for example from a
+/// [`ConfigWatcher`](tower_resilience_core::ConfigWatcher).
 #[derive(Clone)]
 pub struct CircuitBreakerHandle<C = crate::classifier::DefaultClassifier> {
     pub(crate) circuit: Arc<Mutex<Circuit>>,
@@ -84,6 +91,47 @@ impl<C> CircuitBreakerHandle<C> {
         let circuit = self.circuit.lock().await;
         circuit.metrics(&self.config)
     }
+
+    /// Forces the circuit into the open state.
+    pub async fn force_open(&self) {
+        let mut circuit = self.circuit.lock().await;
+        circuit.force_open(&self.config);
+    }
+
+    /// Forces the circuit into the closed state.
+    pub async fn force_closed(&self) {
+        let mut circuit = self.circuit.lock().await;
+        circuit.force_closed(&self.config);
+    }
+
+    /// Resets the circuit to the closed state and clears counts.
+    pub async fn reset(&self) {
+        let mut circuit = self.circuit.lock().await;
+        circuit.reset(&self.config);
+    }
+}
+
+impl<C: Send + Sync> Reloadable for CircuitBreakerHandle<C> {
+    type Settings = CircuitBreakerSettings;
+
+    /// Applies `failure_rate_threshold`, `slow_call_rate_threshold` and
+    /// `wait_duration_in_open`.
+    ///
+    /// The new thresholds take effect on the next evaluation; the current
+    /// state and recorded calls are kept. Other fields describe the shape of
+    /// the breaker and are ignored.
+    fn reload(&self, settings: &CircuitBreakerSettings) {
+        let thresholds = &self.config.thresholds;
+        if let Some(rate) = settings.failure_rate_threshold {
+            thresholds.set_failure_rate(rate);
+        }
+        if let Some(rate) = settings.slow_call_rate_threshold {
+            thresholds.set_slow_call_rate(rate);
+        }
+        if let Some(duration) = settings.wait_duration_in_open {
+            thresholds.set_wait_duration_in_open(duration);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -210,6 +258,44 @@ mod tests {
         assert!(handle.is_open());
     }
 
+    #[tokio::test]
+    async fn test_handle_reload_thresholds() {
+        let (layer, handle) = CircuitBreakerLayer::builder()
+            .failure_rate_threshold(0.9)
+            .sliding_window_size(4)
+            .minimum_number_of_calls(4)
+            .build_with_handle();
+
+        handle.reload(&CircuitBreakerSettings {
+            failure_rate_threshold: Some(0.5),
+            ..Default::default()
+        });
+
+        // Two failures out of four only trip the lowered threshold
+        let mut ok = layer.layer(OkService);
+        let mut err = layer.layer(ErrService);
+        let _ = ok.call("a".to_string()).await;
+        let _ = err.call("b".to_string()).await;
+        let _ = ok.call("c".to_string()).await;
+        let _ = err.call("d".to_string()).await;
+
+        assert_eq!(handle.state(), CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn test_handle_force_open_and_reset() {
+        let (layer, handle) = CircuitBreakerLayer::builder().build_with_handle();
+        let mut svc = layer.layer(OkService);
+
+        handle.force_open().await;
+        assert!(handle.is_open());
+        assert!(svc.call("a".to_string()).await.is_err());
+
+        handle.reset().await;
+        assert_eq!(handle.state(), CircuitState::Closed);
+        assert!(svc.call("b".to_string()).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_handle_clone_is_independent() {
         let (_layer, handle) = CircuitBreakerLayer::builder().build_with_handle();
@@ -75,20 +75,24 @@ mod tests {
 
     fn dummy_config() -> CircuitBreakerConfig<DefaultClassifier> {
         CircuitBreakerConfig {
-            failure_rate_threshold: 0.5,
+            thresholds: Arc::new(crate::config::Thresholds::new(
+                0.5,
+                1.0,
+                Duration::from_secs(60),
+            )),
             sliding_window_type: crate::config::SlidingWindowType::CountBased,
             sliding_window_size: 10,
             sliding_window_duration: None,
-            wait_duration_in_open: Duration::from_secs(60),
             permitted_calls_in_half_open: 1,
             failure_classifier: DefaultClassifier,
             minimum_number_of_calls: 10,
             slow_call_duration_threshold: None,
-            slow_call_rate_threshold: 1.0,
             failure_model: crate::config::FailureModel::SlidingWindow,
             event_listeners: EventListeners::new(),
             name: "test".into(),
             backpressure: false,
+            shadow_mode: false,
+            clock: std::sync::Arc::new(tower_resilience_core::SystemClock),
         }
     }
 
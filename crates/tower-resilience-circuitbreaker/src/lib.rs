@@ -166,6 +166,25 @@
 //! # }
 //! ```
 //!
+//! ## Hybrid Sliding Window
+//!
+//! For bursty traffic, a hybrid window keeps at most the last N calls within
+//! a time duration, and only evaluates thresholds once it has both enough
+//! calls and a full window of observation:
+//!
+//! ```rust
+//! use tower_resilience_circuitbreaker::{CircuitBreakerLayer, SlidingWindowType};
+//! use std::time::Duration;
+//!
+//! let layer = CircuitBreakerLayer::builder()
+//!     .failure_rate_threshold(0.5)
+//!     .sliding_window_type(SlidingWindowType::Hybrid)
+//!     .sliding_window_size(100)                         // At most the last 100 calls
+//!     .sliding_window_duration(Duration::from_secs(60)) // from the last 60 seconds
+//!     .minimum_number_of_calls(20)
+//!     .build();
+//! ```
+//!
 //! ## Slow Call Detection
 //!
 //! Open circuit based on slow calls:
@@ -213,6 +232,9 @@
 //!     .on_slow_call(|duration| {
 //!         println!("Slow call detected: {:?}", duration);
 //!     })
+//!     .on_probe_result(|succeeded, _duration| {
+//!         println!("Half-open probe succeeded: {}", succeeded);
+//!     })
 //!     .build();
 //!
 //! let service = ServiceBuilder::new()
@@ -301,10 +323,14 @@
 //! - Count-based and time-based sliding windows
 //! - Configurable failure rate threshold
 //! - Slow call detection and rate threshold
+//! - Ignore-list for outcomes that should not be recorded
+//! - Failure counts per error category
+//! - Shadow mode that records and transitions without rejecting calls
 //! - Half-open state for gradual recovery
 //! - Event system for observability
 //! - Optional fallback handling
 //! - Manual state control (force_open, force_closed, reset)
+//! - Grouped breakers sharing one circuit across routes
 //! - Sync state inspection with `state_sync()`, `is_open()`, and `metrics()`
 //! - Metrics integration via `metrics` feature
 //! - Tracing support via `tracing` feature
@@ -323,7 +349,6 @@
 
 use crate::circuit::Circuit;
 use futures::future::BoxFuture;
-use futures::Future;
 #[cfg(feature = "metrics")]
 use metrics::{counter, describe_counter, describe_gauge, describe_histogram};
 use std::sync::Arc;
@@ -336,14 +361,18 @@ use tower::Service;
 use tracing::debug;
 
 pub use circuit::{CircuitMetrics, CircuitState};
-pub use classifier::{DefaultClassifier, FailureClassifier, FnClassifier};
+pub use classifier::{
+    CategorizingClassifier, DefaultClassifier, FailureClassifier, FnClassifier, IgnoringClassifier,
+};
 pub use config::{
     CircuitBreakerConfig, CircuitBreakerConfigBuilder, FailureModel, SlidingWindowType,
 };
 pub use error::CircuitBreakerError;
 pub use events::CircuitBreakerEvent;
+pub use group::CircuitBreakerGroup;
 pub use handle::CircuitBreakerHandle;
 pub use layer::CircuitBreakerLayer;
+pub use settings::CircuitBreakerSettings;
 
 mod circuit;
 /// Custom failure classifiers for circuit breaker evaluation.
@@ -351,10 +380,12 @@ pub mod classifier;
 mod config;
 mod error;
 mod events;
+mod group;
 mod handle;
 #[cfg(feature = "health-integration")]
 mod health_integration;
 mod layer;
+mod settings;
 
 pub(crate) type FallbackFn<Req, Res, Err> =
     dyn Fn(Req) -> BoxFuture<'static, Result<Res, Err>> + Send + Sync;
@@ -387,23 +418,27 @@ pub fn circuit_breaker_builder() -> CircuitBreakerConfigBuilder<DefaultClassifie
     {
         METRICS_INIT.call_once(|| {
             describe_counter!(
-                "circuitbreaker_calls_total",
+                "resilience_circuitbreaker_calls_total",
                 "Total number of calls through the circuit breaker"
             );
             describe_counter!(
-                "circuitbreaker_transitions_total",
+                "resilience_circuitbreaker_transitions_total",
                 "Total number of circuit breaker state transitions"
             );
             describe_counter!(
-                "circuitbreaker_slow_calls_total",
+                "resilience_circuitbreaker_slow_calls_total",
                 "Total number of slow calls detected"
             );
+            describe_counter!(
+                "resilience_circuitbreaker_failures_total",
+                "Total number of failed calls by error category"
+            );
             describe_gauge!(
-                "circuitbreaker_state",
+                "resilience_circuitbreaker_state",
                 "Current state of the circuit breaker"
             );
             describe_histogram!(
-                "circuitbreaker_call_duration_seconds",
+                "resilience_circuitbreaker_call_duration_seconds",
                 "Duration of calls through the circuit breaker"
             );
         });
@@ -425,7 +460,7 @@ pub struct CircuitBreaker<S, C> {
     state_atomic: Arc<std::sync::atomic::AtomicU8>,
     pub(crate) config: Arc<CircuitBreakerConfig<C>>,
     /// Sleep future for backpressure mode wake-ups.
-    sleep: Option<std::pin::Pin<Box<tokio::time::Sleep>>>,
+    sleep: Option<tower_resilience_core::clock::Sleep>,
 }
 
 impl<S, C> CircuitBreaker<S, C> {
@@ -434,9 +469,10 @@ impl<S, C> CircuitBreaker<S, C> {
         let state_atomic = Arc::new(std::sync::atomic::AtomicU8::new(CircuitState::Closed as u8));
         Self {
             inner,
-            circuit: Arc::new(Mutex::new(Circuit::new_with_atomic(Arc::clone(
-                &state_atomic,
-            )))),
+            circuit: Arc::new(Mutex::new(Circuit::new_with_atomic(
+                Arc::clone(&state_atomic),
+                config.clock.now(),
+            ))),
             state_atomic,
             config,
             sleep: None,
@@ -618,7 +654,7 @@ where
             Poll::Ready(Ok(())) => {}
         }
 
-        if !self.config.backpressure {
+        if !self.config.backpressure || self.config.shadow_mode {
             return Poll::Ready(Ok(()));
         }
 
@@ -640,8 +676,8 @@ where
             Ok(guard) => guard,
             Err(_) => {
                 // Mutex contended; set a short sleep and return Pending
-                let sleep = tokio::time::sleep(std::time::Duration::from_millis(1));
-                let mut pinned = Box::pin(sleep);
+                let mut pinned: tower_resilience_core::clock::Sleep =
+                    Box::pin(tokio::time::sleep(std::time::Duration::from_millis(1)));
                 let _ = pinned.as_mut().poll(cx);
                 self.sleep = Some(pinned);
                 return Poll::Pending;
@@ -651,8 +687,7 @@ where
         match circuit.check_permitted(&self.config) {
             Ok(()) => Poll::Ready(Ok(())),
             Err(wait) => {
-                let sleep = tokio::time::sleep(wait);
-                let mut pinned = Box::pin(sleep);
+                let mut pinned = self.config.clock.sleep(wait);
                 let _ = pinned.as_mut().poll(cx);
                 self.sleep = Some(pinned);
                 Poll::Pending
@@ -666,7 +701,10 @@ where
         let clone = self.inner.clone();
         let mut inner = std::mem::replace(&mut self.inner, clone);
 
-        Box::pin(async move {
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("circuit_breaker", &config.name);
+
+        let future = async move {
             #[cfg(feature = "tracing")]
             {
                 let cb_name = &config.name;
@@ -697,25 +735,41 @@ where
             if !permitted {
                 #[cfg(feature = "metrics")]
                 {
-                    counter!("circuitbreaker_calls_total", "circuitbreaker" => config.name.clone(), "outcome" => "rejected").increment(1);
+                    counter!("resilience_circuitbreaker_calls_total", "name" => config.name.clone(), "outcome" => "rejected").increment(1);
+                }
+
+                if config.shadow_mode {
+                    return inner.call(req).await.map_err(CircuitBreakerError::Inner);
                 }
 
                 return Err(CircuitBreakerError::OpenCircuit);
             }
 
-            let start = std::time::Instant::now();
+            let start = config.clock.now();
             let result = inner.call(req).await;
-            let duration = start.elapsed();
+            let duration = config.clock.now().saturating_duration_since(start);
 
             let mut circuit = circuit.lock().await;
-            if config.failure_classifier.classify(&result) {
-                circuit.record_failure(&config, duration);
+            if config.failure_classifier.is_ignored(&result) {
+                circuit.record_ignored(&config);
+            } else if config.failure_classifier.classify(&result) {
+                let category = config.failure_classifier.category(&result);
+                circuit.record_failure(&config, duration, category);
             } else {
                 circuit.record_success(&config, duration);
             }
 
             result.map_err(CircuitBreakerError::Inner)
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_result,
+        );
+
+        Box::pin(future)
     }
 }
 
@@ -731,7 +785,7 @@ pub struct CircuitBreakerWithFallback<S, C, Req, Res, Err> {
     fallback: SharedFallback<Req, Res, Err>,
     _phantom: std::marker::PhantomData<(Req, Res, Err)>,
     /// Sleep future for backpressure mode wake-ups.
-    sleep: Option<std::pin::Pin<Box<tokio::time::Sleep>>>,
+    sleep: Option<tower_resilience_core::clock::Sleep>,
 }
 
 impl<S, C, Req, Res, Err> CircuitBreakerWithFallback<S, C, Req, Res, Err> {
@@ -832,7 +886,7 @@ where
             Poll::Ready(Ok(())) => {}
         }
 
-        if !self.config.backpressure {
+        if !self.config.backpressure || self.config.shadow_mode {
             return Poll::Ready(Ok(()));
         }
 
@@ -849,8 +903,8 @@ where
         let circuit = match self.circuit.try_lock() {
             Ok(guard) => guard,
             Err(_) => {
-                let sleep = tokio::time::sleep(std::time::Duration::from_millis(1));
-                let mut pinned = Box::pin(sleep);
+                let mut pinned: tower_resilience_core::clock::Sleep =
+                    Box::pin(tokio::time::sleep(std::time::Duration::from_millis(1)));
                 let _ = pinned.as_mut().poll(cx);
                 self.sleep = Some(pinned);
                 return Poll::Pending;
@@ -860,8 +914,7 @@ where
         match circuit.check_permitted(&self.config) {
             Ok(()) => Poll::Ready(Ok(())),
             Err(wait) => {
-                let sleep = tokio::time::sleep(wait);
-                let mut pinned = Box::pin(sleep);
+                let mut pinned = self.config.clock.sleep(wait);
                 let _ = pinned.as_mut().poll(cx);
                 self.sleep = Some(pinned);
                 Poll::Pending
@@ -876,7 +929,10 @@ where
         let mut inner = std::mem::replace(&mut self.inner, clone);
         let fallback = Arc::clone(&self.fallback);
 
-        Box::pin(async move {
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("circuit_breaker", &config.name);
+
+        let future = async move {
             #[cfg(feature = "tracing")]
             {
                 let cb_name = &config.name;
@@ -907,7 +963,11 @@ where
             if !permitted {
                 #[cfg(feature = "metrics")]
                 {
-                    counter!("circuitbreaker_calls_total", "circuitbreaker" => config.name.clone(), "outcome" => "rejected").increment(1);
+                    counter!("resilience_circuitbreaker_calls_total", "name" => config.name.clone(), "outcome" => "rejected").increment(1);
+                }
+
+                if config.shadow_mode {
+                    return inner.call(req).await.map_err(CircuitBreakerError::Inner);
                 }
 
                 #[cfg(feature = "tracing")]
@@ -919,19 +979,31 @@ where
                 return fallback(req).await.map_err(CircuitBreakerError::Inner);
             }
 
-            let start = std::time::Instant::now();
+            let start = config.clock.now();
             let result = inner.call(req).await;
-            let duration = start.elapsed();
+            let duration = config.clock.now().saturating_duration_since(start);
 
             let mut circuit = circuit.lock().await;
-            if config.failure_classifier.classify(&result) {
-                circuit.record_failure(&config, duration);
+            if config.failure_classifier.is_ignored(&result) {
+                circuit.record_ignored(&config);
+            } else if config.failure_classifier.classify(&result) {
+                let category = config.failure_classifier.category(&result);
+                circuit.record_failure(&config, duration, category);
             } else {
                 circuit.record_success(&config, duration);
             }
 
             result.map_err(CircuitBreakerError::Inner)
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_result,
+        );
+
+        Box::pin(future)
     }
 }
 
@@ -944,20 +1016,24 @@ mod tests {
     fn dummy_config() -> CircuitBreakerConfig<DefaultClassifier> {
         use tower_resilience_core::EventListeners;
         CircuitBreakerConfig {
-            failure_rate_threshold: 0.5,
+            thresholds: Arc::new(crate::config::Thresholds::new(
+                0.5,
+                1.0,
+                Duration::from_secs(1),
+            )),
             sliding_window_type: crate::config::SlidingWindowType::CountBased,
             sliding_window_size: 10,
             sliding_window_duration: None,
-            wait_duration_in_open: Duration::from_secs(1),
             permitted_calls_in_half_open: 1,
             failure_classifier: DefaultClassifier,
             minimum_number_of_calls: 10,
             slow_call_duration_threshold: None,
-            slow_call_rate_threshold: 1.0,
             failure_model: crate::config::FailureModel::SlidingWindow,
             event_listeners: EventListeners::new(),
             name: "test".into(),
             backpressure: false,
+            shadow_mode: false,
+            clock: Arc::new(tower_resilience_core::SystemClock),
         }
     }
 
@@ -967,7 +1043,7 @@ mod tests {
         let config = dummy_config();
 
         for _ in 0..6 {
-            circuit.record_failure(&config, Duration::from_millis(10));
+            circuit.record_failure(&config, Duration::from_millis(10), None);
         }
         for _ in 0..4 {
             circuit.record_success(&config, Duration::from_millis(10));
@@ -982,7 +1058,7 @@ mod tests {
         let config = dummy_config();
 
         for _ in 0..2 {
-            circuit.record_failure(&config, Duration::from_millis(10));
+            circuit.record_failure(&config, Duration::from_millis(10), None);
         }
         for _ in 0..8 {
             circuit.record_success(&config, Duration::from_millis(10));
@@ -1032,16 +1108,18 @@ mod tests {
         let f_clone = Arc::clone(&failures);
 
         let config: CircuitBreakerConfig<DefaultClassifier> = CircuitBreakerConfig {
-            failure_rate_threshold: 0.5,
+            thresholds: Arc::new(crate::config::Thresholds::new(
+                0.5,
+                1.0,
+                Duration::from_secs(1),
+            )),
             sliding_window_type: crate::config::SlidingWindowType::CountBased,
             sliding_window_size: 10,
             sliding_window_duration: None,
-            wait_duration_in_open: Duration::from_secs(1),
             permitted_calls_in_half_open: 1,
             failure_classifier: DefaultClassifier,
             minimum_number_of_calls: 10,
             slow_call_duration_threshold: None,
-            slow_call_rate_threshold: 1.0,
             failure_model: crate::config::FailureModel::SlidingWindow,
             event_listeners: {
                 let mut listeners = EventListeners::new();
@@ -1062,20 +1140,22 @@ mod tests {
                         CircuitBreakerEvent::FailureRecorded { .. } => {
                             f_clone.fetch_add(1, Ordering::SeqCst);
                         }
-                        CircuitBreakerEvent::SlowCallDetected { .. } => {}
+                        _ => {}
                     },
                 ));
                 listeners
             },
             name: "test".into(),
             backpressure: false,
+            shadow_mode: false,
+            clock: Arc::new(tower_resilience_core::SystemClock),
         };
 
         let mut circuit = Circuit::new();
 
         // Record failures to trigger state transition
         for _ in 0..6 {
-            circuit.record_failure(&config, Duration::from_millis(10));
+            circuit.record_failure(&config, Duration::from_millis(10), None);
         }
         for _ in 0..4 {
             circuit.record_success(&config, Duration::from_millis(10));
@@ -1102,16 +1182,18 @@ mod tests {
         let slow_clone = Arc::clone(&slow_calls);
 
         let config: CircuitBreakerConfig<DefaultClassifier> = CircuitBreakerConfig {
-            failure_rate_threshold: 0.5,
+            thresholds: Arc::new(crate::config::Thresholds::new(
+                0.5,
+                0.5,
+                Duration::from_secs(1),
+            )),
             sliding_window_type: crate::config::SlidingWindowType::CountBased,
             sliding_window_size: 10,
             sliding_window_duration: None,
-            wait_duration_in_open: Duration::from_secs(1),
             permitted_calls_in_half_open: 1,
             failure_classifier: DefaultClassifier,
             minimum_number_of_calls: 10,
             slow_call_duration_threshold: Some(Duration::from_millis(100)),
-            slow_call_rate_threshold: 0.5,
             failure_model: crate::config::FailureModel::SlidingWindow,
             event_listeners: {
                 let mut listeners = EventListeners::new();
@@ -1124,6 +1206,8 @@ mod tests {
             },
             name: "test".into(),
             backpressure: false,
+            shadow_mode: false,
+            clock: Arc::new(tower_resilience_core::SystemClock),
         };
 
         let mut circuit = Circuit::new();
@@ -1144,6 +1228,69 @@ mod tests {
         assert_eq!(circuit.state(), CircuitState::Open);
     }
 
+    #[test]
+    fn test_probe_events_only_in_half_open() {
+        use std::sync::Mutex;
+        use tower_resilience_core::{EventListeners, ResilienceEvent};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_clone = Arc::clone(&events);
+
+        let config: CircuitBreakerConfig<DefaultClassifier> = CircuitBreakerConfig {
+            thresholds: Arc::new(crate::config::Thresholds::new(0.5, 1.0, Duration::ZERO)),
+            sliding_window_type: crate::config::SlidingWindowType::CountBased,
+            sliding_window_size: 10,
+            sliding_window_duration: None,
+            permitted_calls_in_half_open: 2,
+            failure_classifier: DefaultClassifier,
+            minimum_number_of_calls: 10,
+            slow_call_duration_threshold: None,
+            failure_model: crate::config::FailureModel::SlidingWindow,
+            event_listeners: {
+                let mut listeners = EventListeners::new();
+                listeners.add(tower_resilience_core::FnListener::new(
+                    move |event: &CircuitBreakerEvent| {
+                        if event.event_type().starts_with("probe_") {
+                            events_clone.lock().unwrap().push(event.event_type());
+                        }
+                    },
+                ));
+                listeners
+            },
+            name: "test".into(),
+            backpressure: false,
+            shadow_mode: false,
+            clock: Arc::new(tower_resilience_core::SystemClock),
+        };
+
+        let mut circuit = Circuit::new();
+
+        // Normal traffic while closed emits no probe events
+        assert!(circuit.try_acquire(&config));
+        circuit.record_success(&config, Duration::from_millis(10));
+        circuit.record_failure(&config, Duration::from_millis(10), None);
+        assert!(events.lock().unwrap().is_empty());
+
+        // Opening and then probing: one success, one failure
+        circuit.force_open(&config);
+        assert!(circuit.try_acquire(&config));
+        assert_eq!(circuit.state(), CircuitState::HalfOpen);
+        circuit.record_success(&config, Duration::from_millis(10));
+        assert!(circuit.try_acquire(&config));
+        circuit.record_failure(&config, Duration::from_millis(10), None);
+        assert_eq!(circuit.state(), CircuitState::Open);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "probe_permitted",
+                "probe_succeeded",
+                "probe_permitted",
+                "probe_failed"
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_circuit_breaker_sync_state() {
         let config = Arc::new(dummy_config());
@@ -1206,7 +1353,7 @@ mod tests {
             let mut circuit = breaker.circuit.lock().await;
             circuit.record_success(&breaker.config, Duration::from_millis(10));
             circuit.record_success(&breaker.config, Duration::from_millis(10));
-            circuit.record_failure(&breaker.config, Duration::from_millis(10));
+            circuit.record_failure(&breaker.config, Duration::from_millis(10), None);
         }
 
         // Get updated metrics
@@ -1217,6 +1364,31 @@ mod tests {
         assert!((metrics.failure_rate - 0.333).abs() < 0.01);
     }
 
+    #[test]
+    fn test_failures_by_category() {
+        let mut circuit = Circuit::new();
+        let config = dummy_config();
+
+        circuit.record_failure(&config, Duration::from_millis(10), Some("timeout"));
+        circuit.record_failure(&config, Duration::from_millis(10), Some("timeout"));
+        circuit.record_failure(&config, Duration::from_millis(10), Some("connection"));
+        circuit.record_failure(&config, Duration::from_millis(10), None);
+
+        let metrics = circuit.metrics(&config);
+        assert_eq!(metrics.failure_count, 4);
+        assert_eq!(metrics.failures_by_category.get("timeout"), Some(&2));
+        assert_eq!(metrics.failures_by_category.get("connection"), Some(&1));
+        assert_eq!(metrics.failures_by_category.len(), 2);
+
+        circuit.force_open(&config);
+        let metrics = circuit.metrics(&config);
+        assert!(metrics.failures_by_category.is_empty());
+        assert_eq!(
+            metrics.failures_by_category_at_open.get("timeout"),
+            Some(&2)
+        );
+    }
+
     #[test]
     fn test_preset_standard() {
         let _layer = CircuitBreakerLayer::standard().build();
@@ -1299,8 +1471,8 @@ mod tests {
         // Record 2 failures to open the circuit
         {
             let mut circuit = service.circuit.lock().await;
-            circuit.record_failure(&service.config, Duration::from_millis(10));
-            circuit.record_failure(&service.config, Duration::from_millis(10));
+            circuit.record_failure(&service.config, Duration::from_millis(10), None);
+            circuit.record_failure(&service.config, Duration::from_millis(10), None);
         }
 
         assert_eq!(service.state().await, CircuitState::Open);
@@ -0,0 +1,176 @@
+//! Circuit breaker settings that can be loaded from configuration files.
+
+use crate::config::{CircuitBreakerConfigBuilder, SlidingWindowType};
+use crate::layer::CircuitBreakerLayer;
+use crate::DefaultClassifier;
+use std::time::Duration;
+
+/// Plain-data circuit breaker settings, deserializable with the `serde` feature.
+///
+/// Every field is optional; unset fields keep the builder defaults. Durations
+/// accept milliseconds or strings such as `"30s"` (see
+/// [`parse_duration`](tower_resilience_core::settings::parse_duration)).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitBreakerSettings};
+///
+/// let settings = CircuitBreakerSettings {
+///     name: Some("payments".to_string()),
+///     failure_rate_threshold: Some(0.25),
+///     wait_duration_in_open: Some(Duration::from_secs(10)),
+///     ..Default::default()
+/// };
+///
+/// let layer = CircuitBreakerLayer::from_config(settings);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct CircuitBreakerSettings {
+    /// Instance name used in events and metrics.
+    pub name: Option<String>,
+    /// Failure rate (0.0 to 1.0) at which the circuit opens.
+    pub failure_rate_threshold: Option<f64>,
+    /// Whether the window counts calls or spans a duration.
+    pub sliding_window_type: Option<SlidingWindowType>,
+    /// Number of calls in a count-based window.
+    pub sliding_window_size: Option<usize>,
+    /// Duration of a time-based window.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub sliding_window_duration: Option<Duration>,
+    /// How long the circuit stays open before allowing a probe.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub wait_duration_in_open: Option<Duration>,
+    /// Number of probe calls permitted while half-open.
+    pub permitted_calls_in_half_open: Option<usize>,
+    /// Minimum number of calls before the failure rate is evaluated.
+    pub minimum_number_of_calls: Option<usize>,
+    /// Calls at least this long are counted as slow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub slow_call_duration_threshold: Option<Duration>,
+    /// Slow call rate (0.0 to 1.0) at which the circuit opens.
+    pub slow_call_rate_threshold: Option<f64>,
+    /// Trip after this many consecutive failures instead of on failure rate.
+    pub consecutive_failures: Option<usize>,
+    /// Make `poll_ready` wait while the circuit is open instead of rejecting calls.
+    pub backpressure: Option<bool>,
+    /// Record calls and change state without ever rejecting calls.
+    pub shadow_mode: Option<bool>,
+}
+
+impl<C> CircuitBreakerConfigBuilder<C> {
+    /// Applies every field that is set in `settings`, leaving the rest unchanged.
+    ///
+    /// Use this instead of [`CircuitBreakerLayer::from_config`] to combine file
+    /// based settings with a custom classifier or event listeners.
+    pub fn settings(mut self, settings: CircuitBreakerSettings) -> Self {
+        if let Some(name) = settings.name {
+            self = self.name(name);
+        }
+        if let Some(rate) = settings.failure_rate_threshold {
+            self = self.failure_rate_threshold(rate);
+        }
+        if let Some(window_type) = settings.sliding_window_type {
+            self = self.sliding_window_type(window_type);
+        }
+        if let Some(size) = settings.sliding_window_size {
+            self = self.sliding_window_size(size);
+        }
+        if let Some(duration) = settings.sliding_window_duration {
+            self = self.sliding_window_duration(duration);
+        }
+        if let Some(duration) = settings.wait_duration_in_open {
+            self = self.wait_duration_in_open(duration);
+        }
+        if let Some(n) = settings.permitted_calls_in_half_open {
+            self = self.permitted_calls_in_half_open(n);
+        }
+        if let Some(n) = settings.minimum_number_of_calls {
+            self = self.minimum_number_of_calls(n);
+        }
+        if let Some(duration) = settings.slow_call_duration_threshold {
+            self = self.slow_call_duration_threshold(duration);
+        }
+        if let Some(rate) = settings.slow_call_rate_threshold {
+            self = self.slow_call_rate_threshold(rate);
+        }
+        if let Some(k) = settings.consecutive_failures {
+            self = self.consecutive_failures(k);
+        }
+        if settings.backpressure == Some(true) {
+            self = self.backpressure();
+        }
+        if let Some(shadow_mode) = settings.shadow_mode {
+            self = self.shadow_mode(shadow_mode);
+        }
+        self
+    }
+}
+
+impl CircuitBreakerLayer<DefaultClassifier> {
+    /// Creates a circuit breaker layer from [`CircuitBreakerSettings`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`CircuitBreakerConfigBuilder::build`],
+    /// e.g. a time-based window without `sliding_window_duration`.
+    pub fn from_config(settings: CircuitBreakerSettings) -> Self {
+        Self::builder().settings(settings).build()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_settings() {
+        let settings: CircuitBreakerSettings = serde_json::from_str(
+            r#"{
+                "name": "payments",
+                "failure_rate_threshold": 0.25,
+                "sliding_window_type": "time_based",
+                "sliding_window_duration": "1m",
+                "wait_duration_in_open": 5000,
+                "slow_call_duration_threshold": "250ms"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.name.as_deref(), Some("payments"));
+        assert_eq!(
+            settings.sliding_window_type,
+            Some(SlidingWindowType::TimeBased)
+        );
+        assert_eq!(
+            settings.sliding_window_duration,
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(settings.wait_duration_in_open, Some(Duration::from_secs(5)));
+        assert_eq!(
+            settings.slow_call_duration_threshold,
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(settings.minimum_number_of_calls, None);
+
+        let _layer = CircuitBreakerLayer::from_config(settings);
+    }
+
+    #[test]
+    fn test_rejects_unknown_fields() {
+        let result = serde_json::from_str::<CircuitBreakerSettings>(r#"{"failure_rate": 0.5}"#);
+        assert!(result.is_err());
+    }
+}
@@ -1,16 +1,49 @@
 //! Configuration for the coalesce layer.
 
+use crate::events::CoalesceEvent;
+use crate::key::KeyNormalizer;
 use std::marker::PhantomData;
+use std::time::Duration;
+use tower_resilience_core::{EventListeners, FnListener};
+
+/// What waiters do when the leader request is cancelled.
+///
+/// A leader is cancelled when its future is dropped before completing, for
+/// example because its caller timed out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeaderFailurePolicy {
+    /// Fail all waiters with
+    /// [`CoalesceError::LeaderCancelled`](crate::CoalesceError::LeaderCancelled).
+    #[default]
+    Propagate,
+    /// Promote one waiter to re-execute the request while the others keep
+    /// waiting.
+    Retry,
+}
 
 /// Configuration for the coalesce layer.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct CoalesceConfig<K, F> {
     /// Function to extract a key from a request.
     pub(crate) key_extractor: F,
-    /// Optional name for metrics/tracing.
-    /// Only used when `metrics` or `tracing` features are enabled.
-    #[cfg_attr(not(any(feature = "metrics", feature = "tracing")), allow(dead_code))]
+    /// Optional function applied to every extracted key.
+    pub(crate) normalizer: Option<KeyNormalizer<K>>,
+    /// Optional name for events, metrics and tracing.
     pub(crate) name: Option<String>,
+    /// How long a completed result is shared with new callers.
+    pub(crate) share_window: Option<Duration>,
+    /// Maximum number of callers waiting on a single in-flight key.
+    pub(crate) max_waiters_per_key: Option<usize>,
+    /// Maximum number of distinct keys in flight at once.
+    pub(crate) max_inflight_keys: Option<usize>,
+    /// Whether a leader's error is propagated to all waiters.
+    pub(crate) share_errors: bool,
+    /// What waiters do when the leader is cancelled.
+    pub(crate) leader_failure_policy: LeaderFailurePolicy,
+    /// Whether the leader's request runs on its own task.
+    pub(crate) detach_leader: bool,
+    /// Event listeners.
+    pub(crate) event_listeners: EventListeners<CoalesceEvent>,
     /// Marker for the key type.
     pub(crate) _key: PhantomData<K>,
 }
@@ -20,7 +53,15 @@ impl<K, F> CoalesceConfig<K, F> {
     pub fn new(key_extractor: F) -> Self {
         Self {
             key_extractor,
+            normalizer: None,
             name: None,
+            share_window: None,
+            max_waiters_per_key: None,
+            max_inflight_keys: None,
+            share_errors: true,
+            leader_failure_policy: LeaderFailurePolicy::Propagate,
+            detach_leader: false,
+            event_listeners: EventListeners::new(),
             _key: PhantomData,
         }
     }
@@ -31,11 +72,34 @@ impl<K, F> CoalesceConfig<K, F> {
     }
 }
 
+impl<K, F> std::fmt::Debug for CoalesceConfig<K, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoalesceConfig")
+            .field("name", &self.name)
+            .field("normalizer", &self.normalizer.is_some())
+            .field("share_window", &self.share_window)
+            .field("max_waiters_per_key", &self.max_waiters_per_key)
+            .field("max_inflight_keys", &self.max_inflight_keys)
+            .field("share_errors", &self.share_errors)
+            .field("leader_failure_policy", &self.leader_failure_policy)
+            .field("detach_leader", &self.detach_leader)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Builder for coalesce configuration.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct CoalesceConfigBuilder<K, F> {
     key_extractor: F,
+    normalizer: Option<KeyNormalizer<K>>,
     name: Option<String>,
+    share_window: Option<Duration>,
+    max_waiters_per_key: Option<usize>,
+    max_inflight_keys: Option<usize>,
+    share_errors: bool,
+    leader_failure_policy: LeaderFailurePolicy,
+    detach_leader: bool,
+    event_listeners: EventListeners<CoalesceEvent>,
     _key: PhantomData<K>,
 }
 
@@ -44,7 +108,15 @@ impl<K, F> CoalesceConfigBuilder<K, F> {
     pub fn new(key_extractor: F) -> Self {
         Self {
             key_extractor,
+            normalizer: None,
             name: None,
+            share_window: None,
+            max_waiters_per_key: None,
+            max_inflight_keys: None,
+            share_errors: true,
+            leader_failure_policy: LeaderFailurePolicy::Propagate,
+            detach_leader: false,
+            event_listeners: EventListeners::new(),
             _key: PhantomData,
         }
     }
@@ -65,16 +137,314 @@ impl<K, F> CoalesceConfigBuilder<K, F> {
         self
     }
 
+    /// Normalize every extracted key before coalescing.
+    ///
+    /// Use this to make semantically identical requests coalesce even when
+    /// they differ byte-wise, for example by lowercasing a path or stripping
+    /// volatile query parameters, without putting that logic into every key
+    /// extractor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .normalize_key(|key: String| key.to_lowercase())
+    ///     .build();
+    /// ```
+    pub fn normalize_key<N>(mut self, normalizer: N) -> Self
+    where
+        N: Fn(K) -> K + Send + Sync + 'static,
+    {
+        self.normalizer = Some(std::sync::Arc::new(normalizer));
+        self
+    }
+
+    /// Share completed results with callers arriving shortly afterwards.
+    ///
+    /// Without a share window, a request that arrives just after the leader
+    /// completes starts a new execution. With one, successful results are
+    /// reused for the given duration, acting as a micro-cache between pure
+    /// in-flight coalescing and a full cache layer. Errors are never shared
+    /// beyond the in-flight request, so the next caller retries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .share_window(Duration::from_millis(100))
+    ///     .build();
+    /// ```
+    pub fn share_window(mut self, window: Duration) -> Self {
+        self.share_window = Some(window);
+        self
+    }
+
+    /// Limit how many callers may wait on a single in-flight key.
+    ///
+    /// Once the limit is reached, further requests for that key are rejected
+    /// with [`CoalesceError::TooManyWaiters`](crate::CoalesceError::TooManyWaiters)
+    /// instead of piling up behind a slow leader. The leader itself does not
+    /// count towards the limit.
+    ///
+    /// Default: unbounded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .max_waiters_per_key(100)
+    ///     .build();
+    /// ```
+    pub fn max_waiters_per_key(mut self, max: usize) -> Self {
+        self.max_waiters_per_key = Some(max);
+        self
+    }
+
+    /// Limit how many distinct keys may be in flight at once.
+    ///
+    /// Once the limit is reached, requests for a new key are rejected with
+    /// [`CoalesceError::TooManyWaiters`](crate::CoalesceError::TooManyWaiters).
+    /// Requests joining a key that is already in flight are unaffected.
+    ///
+    /// Default: unbounded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .max_inflight_keys(1_000)
+    ///     .build();
+    /// ```
+    pub fn max_inflight_keys(mut self, max: usize) -> Self {
+        self.max_inflight_keys = Some(max);
+        self
+    }
+
+    /// Whether a leader's error is propagated to all waiters.
+    ///
+    /// By default every waiter receives a clone of the leader's error, so a
+    /// single transient failure fails every coalesced caller. When disabled,
+    /// only the leader's caller sees the error; one waiter is promoted to
+    /// re-execute the request with its own copy of the request while the
+    /// others keep waiting. If the retry fails too, the next waiter is
+    /// promoted, so each waiter executes the request at most once.
+    ///
+    /// Default: true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .share_errors(false)
+    ///     .build();
+    /// ```
+    pub fn share_errors(mut self, share: bool) -> Self {
+        self.share_errors = share;
+        self
+    }
+
+    /// Set what waiters do when the leader request is cancelled.
+    ///
+    /// With [`LeaderFailurePolicy::Retry`], one waiter is promoted to
+    /// re-execute the request, as with [`share_errors(false)`](Self::share_errors).
+    ///
+    /// Default: [`LeaderFailurePolicy::Propagate`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::{CoalesceConfig, LeaderFailurePolicy};
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .leader_failure_policy(LeaderFailurePolicy::Retry)
+    ///     .build();
+    /// ```
+    pub fn leader_failure_policy(mut self, policy: LeaderFailurePolicy) -> Self {
+        self.leader_failure_policy = policy;
+        self
+    }
+
+    /// Run the leader's request on its own task.
+    ///
+    /// By default the request executes inside the leader caller's future, so
+    /// if that caller drops it (for example on a timeout), the request is
+    /// cancelled and waiters are handled according to the
+    /// [`leader_failure_policy`](Self::leader_failure_policy). When detached,
+    /// the request is spawned onto the Tokio runtime and runs to completion
+    /// for the waiters even if the initiating caller goes away, matching Go's
+    /// `singleflight` semantics.
+    ///
+    /// Requires a Tokio runtime.
+    ///
+    /// Default: false
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .detach_leader(true)
+    ///     .build();
+    /// ```
+    pub fn detach_leader(mut self, detach: bool) -> Self {
+        self.detach_leader = detach;
+        self
+    }
+
+    /// Register a callback for when a request starts executing as leader.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .on_leader_started(|| println!("executing request"))
+    ///     .build();
+    /// ```
+    pub fn on_leader_started<G>(mut self, f: G) -> Self
+    where
+        G: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, CoalesceEvent::LeaderStarted { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Register a callback for when a request is deduplicated.
+    ///
+    /// Called for requests that join an in-flight leader and for requests
+    /// served from the share window.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .on_follower_joined(|| println!("request deduplicated"))
+    ///     .build();
+    /// ```
+    pub fn on_follower_joined<G>(mut self, f: G) -> Self
+    where
+        G: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, CoalesceEvent::FollowerJoined { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Register a callback for when a leader's result is shared with followers.
+    ///
+    /// The callback receives the number of followers that received the
+    /// result. Leaders without followers do not trigger it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .on_result_shared(|followers| println!("shared with {} followers", followers))
+    ///     .build();
+    /// ```
+    pub fn on_result_shared<G>(mut self, f: G) -> Self
+    where
+        G: Fn(usize) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let CoalesceEvent::ResultShared { followers, .. } = event {
+                f(*followers);
+            }
+        }));
+        self
+    }
+
+    /// Register a callback for when a leader's request fails.
+    ///
+    /// The callback receives the number of followers waiting on the leader.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceConfig;
+    ///
+    /// let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .on_leader_failed(|followers| println!("leader failed, {} waiting", followers))
+    ///     .build();
+    /// ```
+    pub fn on_leader_failed<G>(mut self, f: G) -> Self
+    where
+        G: Fn(usize) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let CoalesceEvent::LeaderFailed { followers, .. } = event {
+                f(*followers);
+            }
+        }));
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> CoalesceConfig<K, F> {
         CoalesceConfig {
             key_extractor: self.key_extractor,
+            normalizer: self.normalizer,
             name: self.name,
+            share_window: self.share_window,
+            max_waiters_per_key: self.max_waiters_per_key,
+            max_inflight_keys: self.max_inflight_keys,
+            share_errors: self.share_errors,
+            leader_failure_policy: self.leader_failure_policy,
+            detach_leader: self.detach_leader,
+            event_listeners: self.event_listeners,
             _key: PhantomData,
         }
     }
 }
 
+impl<K, F> std::fmt::Debug for CoalesceConfigBuilder<K, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoalesceConfigBuilder")
+            .field("name", &self.name)
+            .field("normalizer", &self.normalizer.is_some())
+            .field("share_window", &self.share_window)
+            .field("max_waiters_per_key", &self.max_waiters_per_key)
+            .field("max_inflight_keys", &self.max_inflight_keys)
+            .field("share_errors", &self.share_errors)
+            .field("leader_failure_policy", &self.leader_failure_policy)
+            .field("detach_leader", &self.detach_leader)
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,11 +456,70 @@ mod tests {
             .build();
 
         assert_eq!(config.name, Some("test".to_string()));
+        assert!(config.share_window.is_none());
+    }
+
+    #[test]
+    fn test_config_share_window() {
+        let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+            .share_window(Duration::from_millis(100))
+            .build();
+
+        assert_eq!(config.share_window, Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_config_limits() {
+        let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+            .max_waiters_per_key(10)
+            .max_inflight_keys(100)
+            .build();
+
+        assert_eq!(config.max_waiters_per_key, Some(10));
+        assert_eq!(config.max_inflight_keys, Some(100));
+    }
+
+    #[test]
+    fn test_config_normalize_key() {
+        let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+            .normalize_key(|key: String| key.to_lowercase())
+            .build();
+
+        let normalizer = config.normalizer.expect("normalizer set");
+        assert_eq!(normalizer("Users/42".to_string()), "users/42");
+    }
+
+    #[test]
+    fn test_config_leader_handling() {
+        let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+            .share_errors(false)
+            .leader_failure_policy(LeaderFailurePolicy::Retry)
+            .detach_leader(true)
+            .build();
+
+        assert!(!config.share_errors);
+        assert_eq!(config.leader_failure_policy, LeaderFailurePolicy::Retry);
+        assert!(config.detach_leader);
+    }
+
+    #[test]
+    fn test_event_listeners() {
+        let config: CoalesceConfig<String, _> = CoalesceConfig::builder(|req: &String| req.clone())
+            .on_leader_started(|| {})
+            .on_follower_joined(|| {})
+            .on_result_shared(|_| {})
+            .on_leader_failed(|_| {})
+            .build();
+
+        assert_eq!(config.event_listeners.len(), 4);
     }
 
     #[test]
     fn test_config_new() {
         let config: CoalesceConfig<String, _> = CoalesceConfig::new(|req: &String| req.clone());
         assert!(config.name.is_none());
+        assert!(config.share_errors);
+        assert_eq!(config.leader_failure_policy, LeaderFailurePolicy::Propagate);
+        assert!(!config.detach_leader);
     }
 }
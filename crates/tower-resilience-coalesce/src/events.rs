@@ -0,0 +1,129 @@
+//! Event types for coalesce.
+
+use std::time::Instant;
+use tower_resilience_core::ResilienceEvent;
+
+/// Events emitted by the coalesce layer.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum CoalesceEvent {
+    /// A request started executing as the leader for its key.
+    ///
+    /// Also emitted when a waiter is promoted to re-execute a failed request.
+    LeaderStarted {
+        /// The name of the coalesce instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A request was deduplicated, either by joining an in-flight leader or
+    /// by reusing a result from the share window.
+    FollowerJoined {
+        /// The name of the coalesce instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A leader's successful result was delivered to its waiting followers.
+    ResultShared {
+        /// The name of the coalesce instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Number of followers that received the result.
+        followers: usize,
+    },
+    /// A leader's request returned an error.
+    LeaderFailed {
+        /// The name of the coalesce instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Number of followers waiting on the leader.
+        followers: usize,
+    },
+}
+
+impl ResilienceEvent for CoalesceEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            CoalesceEvent::LeaderStarted { .. } => "coalesce_leader_started",
+            CoalesceEvent::FollowerJoined { .. } => "coalesce_follower_joined",
+            CoalesceEvent::ResultShared { .. } => "coalesce_result_shared",
+            CoalesceEvent::LeaderFailed { .. } => "coalesce_leader_failed",
+        }
+    }
+
+    fn timestamp(&self) -> Instant {
+        match self {
+            CoalesceEvent::LeaderStarted { timestamp, .. }
+            | CoalesceEvent::FollowerJoined { timestamp, .. }
+            | CoalesceEvent::ResultShared { timestamp, .. }
+            | CoalesceEvent::LeaderFailed { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn pattern_name(&self) -> &str {
+        match self {
+            CoalesceEvent::LeaderStarted { pattern_name, .. }
+            | CoalesceEvent::FollowerJoined { pattern_name, .. }
+            | CoalesceEvent::ResultShared { pattern_name, .. }
+            | CoalesceEvent::LeaderFailed { pattern_name, .. } => pattern_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_types() {
+        let now = Instant::now();
+
+        let started = CoalesceEvent::LeaderStarted {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+        };
+        assert_eq!(started.event_type(), "coalesce_leader_started");
+        assert_eq!(started.pattern_name(), "test");
+
+        let joined = CoalesceEvent::FollowerJoined {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+        };
+        assert_eq!(joined.event_type(), "coalesce_follower_joined");
+
+        let shared = CoalesceEvent::ResultShared {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            followers: 3,
+        };
+        assert_eq!(shared.event_type(), "coalesce_result_shared");
+
+        let failed = CoalesceEvent::LeaderFailed {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            followers: 3,
+        };
+        assert_eq!(failed.event_type(), "coalesce_leader_failed");
+        assert_eq!(failed.timestamp(), now);
+    }
+}
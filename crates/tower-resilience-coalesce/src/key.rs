@@ -0,0 +1,98 @@
+//! Key extraction for request coalescing.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Function applied to every extracted key before coalescing.
+pub(crate) type KeyNormalizer<K> = Arc<dyn Fn(K) -> K + Send + Sync>;
+
+/// Derives the coalescing key for a request.
+///
+/// Implemented for any `Fn(&Req) -> K` closure, which always produces a key,
+/// and for [`TryKeyExtractor`], which may fail. Requests without a key bypass
+/// coalescing and go straight to the inner service.
+pub trait KeyExtractor<Req, K> {
+    /// Extract the key for `req`, or `None` if the request cannot be coalesced.
+    fn extract(&self, req: &Req) -> Option<K>;
+}
+
+impl<Req, K, F> KeyExtractor<Req, K> for F
+where
+    F: Fn(&Req) -> K,
+{
+    fn extract(&self, req: &Req) -> Option<K> {
+        Some(self(req))
+    }
+}
+
+/// A fallible key extractor.
+///
+/// Wraps a function returning `Result<K, E>`, for keys that depend on
+/// parsing the request (a header, a body field, a normalized URL). Requests
+/// whose key cannot be extracted are not coalesced; they are forwarded to the
+/// inner service as-is and the error is discarded.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_coalesce::CoalesceLayer;
+///
+/// // Only numeric IDs are coalesced; anything else bypasses the layer
+/// let layer = CoalesceLayer::try_builder(|req: &String| req.parse::<u64>())
+///     .name("user-lookup")
+///     .build();
+/// ```
+pub struct TryKeyExtractor<F, E> {
+    f: F,
+    _error: PhantomData<fn() -> E>,
+}
+
+impl<F, E> TryKeyExtractor<F, E> {
+    /// Wrap a fallible key extraction function.
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<F: Clone, E> Clone for TryKeyExtractor<F, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.f.clone())
+    }
+}
+
+impl<F, E> fmt::Debug for TryKeyExtractor<F, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryKeyExtractor").finish_non_exhaustive()
+    }
+}
+
+impl<Req, K, F, E> KeyExtractor<Req, K> for TryKeyExtractor<F, E>
+where
+    F: Fn(&Req) -> Result<K, E>,
+{
+    fn extract(&self, req: &Req) -> Option<K> {
+        (self.f)(req).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closure_always_extracts() {
+        let extractor = |req: &String| req.len();
+        assert_eq!(extractor.extract(&"abc".to_string()), Some(3));
+    }
+
+    #[test]
+    fn test_try_extractor_drops_errors() {
+        let extractor = TryKeyExtractor::new(|req: &String| req.parse::<u64>());
+        assert_eq!(extractor.extract(&"42".to_string()), Some(42));
+        assert_eq!(extractor.extract(&"abc".to_string()), None);
+    }
+}
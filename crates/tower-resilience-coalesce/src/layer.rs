@@ -1,9 +1,14 @@
 //! Layer implementation for request coalescing.
 
-use crate::{CoalesceConfig, CoalesceService};
+use crate::service::CoalescePredicate;
+use crate::{
+    CoalesceConfig, CoalesceConfigBuilder, CoalesceService, KeyExtractor, LeaderFailurePolicy,
+    TryKeyExtractor,
+};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 use tower_layer::Layer;
 
 /// A Tower layer that coalesces concurrent identical requests.
@@ -33,6 +38,7 @@ use tower_layer::Layer;
 /// ```
 pub struct CoalesceLayer<K, Req, F> {
     config: Arc<CoalesceConfig<K, F>>,
+    predicate: Option<CoalescePredicate<Req>>,
     _req: PhantomData<Req>,
 }
 
@@ -62,43 +68,98 @@ where
     pub fn new(key_extractor: F) -> Self {
         Self {
             config: Arc::new(CoalesceConfig::new(key_extractor)),
+            predicate: None,
             _req: PhantomData,
         }
     }
 
-    /// Create a new coalesce layer with a configuration.
+    /// Create a builder for more configuration options.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use tower_resilience_coalesce::{CoalesceLayer, CoalesceConfig};
+    /// use tower_resilience_coalesce::CoalesceLayer;
     ///
-    /// let config = CoalesceConfig::builder(|req: &String| req.clone())
-    ///     .name("my-coalesce")
+    /// let layer = CoalesceLayer::builder(|req: &String| req.clone())
+    ///     .name("user-lookup")
     ///     .build();
+    /// ```
+    pub fn builder(key_extractor: F) -> CoalesceLayerBuilder<K, Req, F> {
+        CoalesceLayerBuilder::new(key_extractor)
+    }
+}
+
+impl<K, Req, G, E> CoalesceLayer<K, Req, TryKeyExtractor<G, E>>
+where
+    K: Hash + Eq + Clone + Send + Sync + 'static,
+    G: Fn(&Req) -> Result<K, E> + Clone + Send + Sync + 'static,
+    E: 'static,
+{
+    /// Create a new coalesce layer with a fallible key extractor.
     ///
-    /// let layer = CoalesceLayer::<String, String, _>::with_config(config);
+    /// Requests for which the extractor returns an error are not coalesced;
+    /// they are forwarded to the inner service unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceLayer;
+    ///
+    /// let layer = CoalesceLayer::try_new(|req: &String| req.parse::<u64>());
     /// ```
-    pub fn with_config(config: CoalesceConfig<K, F>) -> Self {
-        Self {
-            config: Arc::new(config),
-            _req: PhantomData,
-        }
+    pub fn try_new(key_extractor: G) -> Self {
+        Self::with_config(CoalesceConfig::new(TryKeyExtractor::new(key_extractor)))
     }
 
-    /// Create a builder for more configuration options.
+    /// Create a builder with a fallible key extractor.
+    ///
+    /// See [`try_new`](Self::try_new).
     ///
     /// # Example
     ///
     /// ```rust
     /// use tower_resilience_coalesce::CoalesceLayer;
     ///
-    /// let layer = CoalesceLayer::builder(|req: &String| req.clone())
-    ///     .name("user-lookup")
+    /// # struct Request { headers: Vec<(String, String)> }
+    /// let layer = CoalesceLayer::try_builder(|req: &Request| {
+    ///     req.headers
+    ///         .iter()
+    ///         .find(|(name, _)| name == "x-resource-id")
+    ///         .map(|(_, value)| value.clone())
+    ///         .ok_or("missing resource id")
+    /// })
+    /// .name("resource-lookup")
+    /// .build();
+    /// ```
+    pub fn try_builder(key_extractor: G) -> CoalesceLayerBuilder<K, Req, TryKeyExtractor<G, E>> {
+        CoalesceLayerBuilder::new(TryKeyExtractor::new(key_extractor))
+    }
+}
+
+impl<K, Req, F> CoalesceLayer<K, Req, F>
+where
+    K: Hash + Eq + Clone + Send + Sync + 'static,
+    F: KeyExtractor<Req, K> + Clone + Send + Sync + 'static,
+{
+    /// Create a new coalesce layer with a configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::{CoalesceLayer, CoalesceConfig};
+    ///
+    /// let config = CoalesceConfig::builder(|req: &String| req.clone())
+    ///     .name("my-coalesce")
     ///     .build();
+    ///
+    /// let layer = CoalesceLayer::<String, String, _>::with_config(config);
     /// ```
-    pub fn builder(key_extractor: F) -> CoalesceLayerBuilder<K, Req, F> {
-        CoalesceLayerBuilder::new(key_extractor)
+    pub fn with_config(config: CoalesceConfig<K, F>) -> Self {
+        Self {
+            config: Arc::new(config),
+            predicate: None,
+            _req: PhantomData,
+        }
     }
 }
 
@@ -106,6 +167,7 @@ impl<K, Req, F> Clone for CoalesceLayer<K, Req, F> {
     fn clone(&self) -> Self {
         Self {
             config: Arc::clone(&self.config),
+            predicate: self.predicate.clone(),
             _req: PhantomData,
         }
     }
@@ -117,51 +179,203 @@ where
     S::Response: Clone,
     S::Error: Clone,
     K: Hash + Eq + Clone + Send + Sync + 'static,
-    F: Fn(&Req) -> K + Clone + Send + Sync + 'static,
+    F: KeyExtractor<Req, K> + Clone + Send + Sync + 'static,
 {
     type Service = CoalesceService<S, K, Req, F>;
 
     fn layer(&self, service: S) -> Self::Service {
         CoalesceService::new(service, Arc::clone(&self.config))
+            .with_predicate(self.predicate.clone())
     }
 }
 
 /// Builder for CoalesceLayer.
 pub struct CoalesceLayerBuilder<K, Req, F> {
-    key_extractor: F,
-    name: Option<String>,
-    _key: PhantomData<K>,
+    config: CoalesceConfigBuilder<K, F>,
+    predicate: Option<CoalescePredicate<Req>>,
     _req: PhantomData<Req>,
 }
 
 impl<K, Req, F> CoalesceLayerBuilder<K, Req, F>
 where
     K: Hash + Eq + Clone + Send + Sync + 'static,
-    F: Fn(&Req) -> K + Clone + Send + Sync + 'static,
+    F: KeyExtractor<Req, K> + Clone + Send + Sync + 'static,
 {
     /// Create a new builder with the given key extractor.
     pub fn new(key_extractor: F) -> Self {
         Self {
-            key_extractor,
-            name: None,
-            _key: PhantomData,
+            config: CoalesceConfig::builder(key_extractor),
+            predicate: None,
             _req: PhantomData,
         }
     }
 
     /// Set a name for this coalesce instance.
     pub fn name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(name.into());
+        self.config = self.config.name(name);
+        self
+    }
+
+    /// Normalize every extracted key before coalescing.
+    ///
+    /// See [`CoalesceConfigBuilder::normalize_key`](crate::CoalesceConfigBuilder::normalize_key).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceLayer;
+    ///
+    /// // "/Users/42?trace=abc" and "/users/42" share one execution
+    /// let layer = CoalesceLayer::builder(|req: &String| req.clone())
+    ///     .normalize_key(|key: String| {
+    ///         let path = key.split('?').next().unwrap_or_default();
+    ///         path.to_lowercase()
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn normalize_key<N>(mut self, normalizer: N) -> Self
+    where
+        N: Fn(K) -> K + Send + Sync + 'static,
+    {
+        self.config = self.config.normalize_key(normalizer);
+        self
+    }
+
+    /// Share completed results with callers arriving within `window`.
+    ///
+    /// See [`CoalesceConfigBuilder::share_window`](crate::CoalesceConfigBuilder::share_window).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = CoalesceLayer::builder(|req: &String| req.clone())
+    ///     .share_window(Duration::from_millis(100))
+    ///     .build();
+    /// ```
+    pub fn share_window(mut self, window: Duration) -> Self {
+        self.config = self.config.share_window(window);
+        self
+    }
+
+    /// Limit how many callers may wait on a single in-flight key.
+    ///
+    /// See [`CoalesceConfigBuilder::max_waiters_per_key`](crate::CoalesceConfigBuilder::max_waiters_per_key).
+    pub fn max_waiters_per_key(mut self, max: usize) -> Self {
+        self.config = self.config.max_waiters_per_key(max);
+        self
+    }
+
+    /// Limit how many distinct keys may be in flight at once.
+    ///
+    /// See [`CoalesceConfigBuilder::max_inflight_keys`](crate::CoalesceConfigBuilder::max_inflight_keys).
+    pub fn max_inflight_keys(mut self, max: usize) -> Self {
+        self.config = self.config.max_inflight_keys(max);
+        self
+    }
+
+    /// Whether a leader's error is propagated to all waiters.
+    ///
+    /// See [`CoalesceConfigBuilder::share_errors`](crate::CoalesceConfigBuilder::share_errors).
+    pub fn share_errors(mut self, share: bool) -> Self {
+        self.config = self.config.share_errors(share);
+        self
+    }
+
+    /// Set what waiters do when the leader request is cancelled.
+    ///
+    /// See [`CoalesceConfigBuilder::leader_failure_policy`](crate::CoalesceConfigBuilder::leader_failure_policy).
+    pub fn leader_failure_policy(mut self, policy: LeaderFailurePolicy) -> Self {
+        self.config = self.config.leader_failure_policy(policy);
+        self
+    }
+
+    /// Only coalesce requests for which `predicate` returns true.
+    ///
+    /// Other requests bypass coalescing entirely and go straight to the
+    /// inner service, without calling the key extractor. Use this for
+    /// non-idempotent or unique requests instead of generating unique keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_coalesce::CoalesceLayer;
+    ///
+    /// # #[derive(Clone)]
+    /// # struct Request { method: &'static str, path: String }
+    /// let layer = CoalesceLayer::builder(|req: &Request| req.path.clone())
+    ///     .coalesce_if(|req: &Request| req.method == "GET")
+    ///     .build();
+    /// ```
+    pub fn coalesce_if<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&Req) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Run the leader's request on its own task.
+    ///
+    /// See [`CoalesceConfigBuilder::detach_leader`](crate::CoalesceConfigBuilder::detach_leader).
+    pub fn detach_leader(mut self, detach: bool) -> Self {
+        self.config = self.config.detach_leader(detach);
+        self
+    }
+
+    /// Register a callback for when a request starts executing as leader.
+    ///
+    /// See [`CoalesceConfigBuilder::on_leader_started`](crate::CoalesceConfigBuilder::on_leader_started).
+    pub fn on_leader_started<G>(mut self, f: G) -> Self
+    where
+        G: Fn() + Send + Sync + 'static,
+    {
+        self.config = self.config.on_leader_started(f);
+        self
+    }
+
+    /// Register a callback for when a request is deduplicated.
+    ///
+    /// See [`CoalesceConfigBuilder::on_follower_joined`](crate::CoalesceConfigBuilder::on_follower_joined).
+    pub fn on_follower_joined<G>(mut self, f: G) -> Self
+    where
+        G: Fn() + Send + Sync + 'static,
+    {
+        self.config = self.config.on_follower_joined(f);
+        self
+    }
+
+    /// Register a callback for when a leader's result is shared with followers.
+    ///
+    /// See [`CoalesceConfigBuilder::on_result_shared`](crate::CoalesceConfigBuilder::on_result_shared).
+    pub fn on_result_shared<G>(mut self, f: G) -> Self
+    where
+        G: Fn(usize) + Send + Sync + 'static,
+    {
+        self.config = self.config.on_result_shared(f);
+        self
+    }
+
+    /// Register a callback for when a leader's request fails.
+    ///
+    /// See [`CoalesceConfigBuilder::on_leader_failed`](crate::CoalesceConfigBuilder::on_leader_failed).
+    pub fn on_leader_failed<G>(mut self, f: G) -> Self
+    where
+        G: Fn(usize) + Send + Sync + 'static,
+    {
+        self.config = self.config.on_leader_failed(f);
         self
     }
 
     /// Build the layer.
     pub fn build(self) -> CoalesceLayer<K, Req, F> {
-        let mut config_builder = CoalesceConfig::builder(self.key_extractor);
-        if let Some(name) = self.name {
-            config_builder = config_builder.name(name);
+        CoalesceLayer {
+            config: Arc::new(self.config.build()),
+            predicate: self.predicate,
+            _req: PhantomData,
         }
-        CoalesceLayer::with_config(config_builder.build())
     }
 }
 
@@ -182,4 +396,13 @@ mod tests {
             .build();
         let _ = layer.clone();
     }
+
+    #[test]
+    fn test_layer_try_builder() {
+        let layer = CoalesceLayer::try_builder(|req: &String| req.parse::<u64>())
+            .normalize_key(|id| id % 1_000)
+            .name("test")
+            .build();
+        let _ = layer.clone();
+    }
 }
@@ -9,7 +9,9 @@
 //! 1. The first request with a given key begins execution
 //! 2. Subsequent requests with the same key wait for the first to complete
 //! 3. All waiting requests receive a clone of the result
-//! 4. Errors are also propagated to all waiters
+//! 4. Errors are also propagated to all waiters, unless error sharing is
+//!    disabled with `share_errors(false)`, in which case one waiter is
+//!    promoted to retry the request
 //!
 //! # Example
 //!
@@ -32,6 +34,77 @@
 //! # }
 //! ```
 //!
+//! # Share Window
+//!
+//! Optionally, successful results can be reused by callers that arrive
+//! shortly after the leader completes, bridging the gap between in-flight
+//! coalescing and a full cache:
+//!
+//! ```rust
+//! use tower_resilience_coalesce::CoalesceLayer;
+//! use std::time::Duration;
+//!
+//! let layer = CoalesceLayer::builder(|req: &String| req.clone())
+//!     .share_window(Duration::from_millis(100))
+//!     .build();
+//! ```
+//!
+//! # Selective Coalescing
+//!
+//! Requests that must not be deduplicated, such as writes, can bypass the
+//! layer with a predicate:
+//!
+//! ```rust
+//! use tower_resilience_coalesce::CoalesceLayer;
+//!
+//! # #[derive(Clone)]
+//! # struct Request { method: &'static str, path: String }
+//! let layer = CoalesceLayer::builder(|req: &Request| req.path.clone())
+//!     .coalesce_if(|req: &Request| req.method == "GET")
+//!     .build();
+//! ```
+//!
+//! # Key Extraction
+//!
+//! Keys that come from parsing the request can be extracted fallibly with
+//! [`CoalesceLayer::try_builder`]; requests whose key cannot be extracted
+//! bypass the layer. A normalizer makes semantically identical requests
+//! coalesce even when they differ byte-wise:
+//!
+//! ```rust
+//! use tower_resilience_coalesce::CoalesceLayer;
+//!
+//! let layer = CoalesceLayer::try_builder(|req: &String| {
+//!     req.strip_prefix("GET ").map(str::to_string).ok_or("not a read")
+//! })
+//! .normalize_key(|path: String| path.split('?').next().unwrap_or_default().to_lowercase())
+//! .build();
+//! ```
+//!
+//! # Events
+//!
+//! Callbacks can be registered for leader and follower activity:
+//!
+//! ```rust
+//! use tower_resilience_coalesce::CoalesceLayer;
+//!
+//! let layer = CoalesceLayer::builder(|req: &String| req.clone())
+//!     .name("user-lookup")
+//!     .on_leader_started(|| println!("executing request"))
+//!     .on_follower_joined(|| println!("request deduplicated"))
+//!     .on_result_shared(|followers| println!("result shared with {}", followers))
+//!     .on_leader_failed(|followers| println!("leader failed, {} waiting", followers))
+//!     .build();
+//! ```
+//!
+//! # Metrics
+//!
+//! With the `metrics` feature enabled:
+//!
+//! - `resilience_coalesce_requests_total{name, role}` - Requests by role (leader/waiter/shared/rejected/bypassed)
+//! - `resilience_coalesce_followers_total{name}` - Deduplicated requests that did not execute
+//! - `resilience_coalesce_dedup_ratio{name}` - Fraction of requests deduplicated (0.0 - 1.0)
+//!
 //! # Use Cases
 //!
 //! - **Cache refresh protection**: When a cached value expires, multiple requests
@@ -61,10 +134,14 @@
 //! - **Request collapsing**
 
 mod config;
+mod events;
+mod key;
 mod layer;
 mod service;
 
-pub use config::{CoalesceConfig, CoalesceConfigBuilder};
+pub use config::{CoalesceConfig, CoalesceConfigBuilder, LeaderFailurePolicy};
+pub use events::CoalesceEvent;
+pub use key::{KeyExtractor, TryKeyExtractor};
 pub use layer::CoalesceLayer;
 pub use service::{CoalesceError, CoalesceFuture, CoalesceService};
 
@@ -1,6 +1,6 @@
 //! Service implementation for request coalescing.
 
-use crate::CoalesceConfig;
+use crate::{CoalesceConfig, CoalesceEvent, KeyExtractor, LeaderFailurePolicy};
 use hashbrown::HashMap;
 use parking_lot::Mutex;
 use std::future::Future;
@@ -9,12 +9,20 @@ use std::marker::PhantomData;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tower_resilience_core::EventListeners;
 use tower_service::Service;
 
 #[cfg(feature = "metrics")]
-use metrics::{counter, describe_counter};
+use metrics::{counter, describe_counter, describe_gauge, gauge};
+#[cfg(feature = "metrics")]
+use std::sync::atomic::{AtomicU64, Ordering};
 
+#[cfg(feature = "tracing")]
+use tower_resilience_core::span;
+use tower_resilience_core::PatternError;
 #[cfg(feature = "tracing")]
 use tracing::debug;
 
@@ -27,6 +35,9 @@ pub enum CoalesceError<E> {
     LeaderCancelled,
     /// Failed to receive the result from the leader.
     RecvError,
+    /// The request was rejected because the per-key waiter limit or the
+    /// in-flight key limit was reached.
+    TooManyWaiters,
 }
 
 impl<E: std::fmt::Display> std::fmt::Display for CoalesceError<E> {
@@ -35,6 +46,7 @@ impl<E: std::fmt::Display> std::fmt::Display for CoalesceError<E> {
             CoalesceError::Service(e) => write!(f, "service error: {}", e),
             CoalesceError::LeaderCancelled => write!(f, "leader request was cancelled"),
             CoalesceError::RecvError => write!(f, "failed to receive result from leader"),
+            CoalesceError::TooManyWaiters => write!(f, "too many coalesced requests in flight"),
         }
     }
 }
@@ -48,12 +60,91 @@ impl<E: std::error::Error + 'static> std::error::Error for CoalesceError<E> {
     }
 }
 
+impl<E> PatternError for CoalesceError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        matches!(self, CoalesceError::TooManyWaiters)
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            CoalesceError::Service(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            CoalesceError::Service(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 impl<E: Clone> Clone for CoalesceError<E> {
     fn clone(&self) -> Self {
         match self {
             CoalesceError::Service(e) => CoalesceError::Service(e.clone()),
             CoalesceError::LeaderCancelled => CoalesceError::LeaderCancelled,
             CoalesceError::RecvError => CoalesceError::RecvError,
+            CoalesceError::TooManyWaiters => CoalesceError::TooManyWaiters,
+        }
+    }
+}
+
+/// Message broadcast by a leader to its waiters.
+#[derive(Clone)]
+enum Outcome<Res, E> {
+    /// The leader's result.
+    Done(Result<Res, E>),
+    /// The leader failed without sharing its result; a waiter should retry.
+    Retry,
+}
+
+/// Outcome of joining the in-flight map for a key.
+enum Join<Res, E> {
+    /// No request is in flight; the caller must execute the request.
+    Lead,
+    /// Another request is in flight; wait for its result.
+    Wait(broadcast::Receiver<Outcome<Res, E>>),
+    /// A recent result is still within the share window.
+    Shared(Res),
+    /// A waiter or in-flight key limit was reached.
+    Rejected,
+}
+
+/// Settings for the in-flight map, taken from [`CoalesceConfig`].
+#[derive(Clone)]
+struct Settings {
+    name: String,
+    event_listeners: EventListeners<CoalesceEvent>,
+    share_window: Option<Duration>,
+    max_waiters_per_key: Option<usize>,
+    max_inflight_keys: Option<usize>,
+    share_errors: bool,
+    leader_failure_policy: LeaderFailurePolicy,
+    detach_leader: bool,
+}
+
+impl Settings {
+    /// Whether waiters may be promoted to re-execute the request.
+    fn can_retry(&self) -> bool {
+        !self.share_errors || self.leader_failure_policy == LeaderFailurePolicy::Retry
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            name: String::from("<unnamed>"),
+            event_listeners: EventListeners::new(),
+            share_window: None,
+            max_waiters_per_key: None,
+            max_inflight_keys: None,
+            share_errors: true,
+            leader_failure_policy: LeaderFailurePolicy::Propagate,
+            detach_leader: false,
         }
     }
 }
@@ -61,7 +152,15 @@ impl<E: Clone> Clone for CoalesceError<E> {
 /// Shared state for tracking in-flight requests.
 struct InFlight<K, Res, E> {
     /// Map from key to broadcast sender for that key's result.
-    requests: Mutex<HashMap<K, broadcast::Sender<Result<Res, E>>>>,
+    requests: Mutex<HashMap<K, broadcast::Sender<Outcome<Res, E>>>>,
+    /// Recently completed successful results and when they expire.
+    recent: Mutex<HashMap<K, (Instant, Res)>>,
+    settings: Settings,
+    /// Request counts for the dedup ratio gauge.
+    #[cfg(feature = "metrics")]
+    total: AtomicU64,
+    #[cfg(feature = "metrics")]
+    followers: AtomicU64,
 }
 
 impl<K, Res, E> InFlight<K, Res, E>
@@ -70,44 +169,150 @@ where
     Res: Clone,
     E: Clone,
 {
-    fn new() -> Self {
+    fn new(settings: Settings) -> Self {
         Self {
             requests: Mutex::new(HashMap::new()),
+            recent: Mutex::new(HashMap::new()),
+            settings,
+            #[cfg(feature = "metrics")]
+            total: AtomicU64::new(0),
+            #[cfg(feature = "metrics")]
+            followers: AtomicU64::new(0),
         }
     }
 
-    /// Try to become the leader for a key. Returns None if we're the leader,
-    /// or Some(receiver) if another request is already in flight.
-    fn try_join(&self, key: K) -> Option<broadcast::Receiver<Result<Res, E>>> {
+    /// Emit a leader or follower event and update the dedup metrics.
+    fn joined(&self, follower: bool) {
+        let pattern_name = self.settings.name.clone();
+        let timestamp = Instant::now();
+        let event = if follower {
+            CoalesceEvent::FollowerJoined {
+                pattern_name,
+                timestamp,
+            }
+        } else {
+            CoalesceEvent::LeaderStarted {
+                pattern_name,
+                timestamp,
+            }
+        };
+        self.settings.event_listeners.emit(&event);
+
+        #[cfg(feature = "metrics")]
+        {
+            let name = &self.settings.name;
+            let total = self.total.fetch_add(1, Ordering::Relaxed) + 1;
+            let followers = if follower {
+                counter!("resilience_coalesce_followers_total", "name" => name.clone())
+                    .increment(1);
+                self.followers.fetch_add(1, Ordering::Relaxed) + 1
+            } else {
+                self.followers.load(Ordering::Relaxed)
+            };
+            gauge!("resilience_coalesce_dedup_ratio", "name" => name.clone())
+                .set(followers as f64 / total as f64);
+        }
+    }
+
+    /// Try to become the leader for a key, join an in-flight request, or
+    /// reuse a result that is still within the share window.
+    fn try_join(&self, key: K) -> Join<Res, E> {
+        if self.settings.share_window.is_some() {
+            let mut recent = self.recent.lock();
+            if let Some((expires_at, res)) = recent.get(&key) {
+                if Instant::now() < *expires_at {
+                    return Join::Shared(res.clone());
+                }
+                recent.remove(&key);
+            }
+        }
+
         let mut requests = self.requests.lock();
         if let Some(sender) = requests.get(&key) {
+            if let Some(max) = self.settings.max_waiters_per_key {
+                if sender.receiver_count() >= max {
+                    return Join::Rejected;
+                }
+            }
             // Another request is in flight, subscribe to its result
-            Some(sender.subscribe())
+            Join::Wait(sender.subscribe())
+        } else if self
+            .settings
+            .max_inflight_keys
+            .is_some_and(|max| requests.len() >= max)
+        {
+            Join::Rejected
         } else {
             // We're the leader, create a new broadcast channel
             // Use a capacity of 1 since we only send one result
             let (tx, _rx) = broadcast::channel(1);
             requests.insert(key, tx);
-            None
+            Join::Lead
         }
     }
 
     /// Complete a request and notify all waiters.
     fn complete(&self, key: &K, result: Result<Res, E>) {
-        let mut requests = self.requests.lock();
-        if let Some(sender) = requests.remove(key) {
+        // Record the result before releasing the key so callers arriving in
+        // between find it rather than starting a new request
+        if let (Some(window), Ok(res)) = (self.settings.share_window, &result) {
+            let now = Instant::now();
+            let mut recent = self.recent.lock();
+            recent.retain(|_, (expires_at, _)| now < *expires_at);
+            recent.insert(key.clone(), (now + window, res.clone()));
+        }
+
+        let succeeded = result.is_ok();
+        let outcome = match result {
+            Err(_) if !self.settings.share_errors => Outcome::Retry,
+            result => Outcome::Done(result),
+        };
+
+        let sender = self.requests.lock().remove(key);
+        let followers = sender.as_ref().map_or(0, |s| s.receiver_count());
+        if let Some(sender) = sender {
             // Send result to all waiters (ignore errors if no receivers)
-            let _ = sender.send(result);
+            let _ = sender.send(outcome);
+        }
+
+        let pattern_name = self.settings.name.clone();
+        let timestamp = Instant::now();
+        if !succeeded {
+            self.settings
+                .event_listeners
+                .emit(&CoalesceEvent::LeaderFailed {
+                    pattern_name,
+                    timestamp,
+                    followers,
+                });
+        } else if followers > 0 {
+            self.settings
+                .event_listeners
+                .emit(&CoalesceEvent::ResultShared {
+                    pattern_name,
+                    timestamp,
+                    followers,
+                });
         }
     }
 
     /// Remove a key without sending a result (for cancellation).
+    ///
+    /// With [`LeaderFailurePolicy::Retry`], waiters are told to retry
+    /// instead of failing.
     fn cancel(&self, key: &K) {
         let mut requests = self.requests.lock();
-        requests.remove(key);
+        if let Some(sender) = requests.remove(key) {
+            if self.settings.leader_failure_policy == LeaderFailurePolicy::Retry {
+                let _ = sender.send(Outcome::Retry);
+            }
+        }
     }
 }
 
+/// Predicate deciding whether a request takes part in coalescing.
+pub(crate) type CoalescePredicate<Req> = Arc<dyn Fn(&Req) -> bool + Send + Sync>;
+
 /// A service that coalesces concurrent identical requests.
 ///
 /// When multiple requests arrive concurrently with the same key, only the
@@ -119,6 +324,7 @@ where
     inner: S,
     config: Arc<CoalesceConfig<K, F>>,
     in_flight: Arc<InFlight<K, S::Response, S::Error>>,
+    predicate: Option<CoalescePredicate<Req>>,
     _req: PhantomData<Req>,
 }
 
@@ -128,25 +334,53 @@ where
     S::Response: Clone,
     S::Error: Clone,
     K: Hash + Eq + Clone + Send + Sync + 'static,
-    F: Fn(&Req) -> K,
+    F: KeyExtractor<Req, K>,
 {
     /// Create a new coalescing service.
     pub fn new(inner: S, config: Arc<CoalesceConfig<K, F>>) -> Self {
         #[cfg(feature = "metrics")]
         {
             describe_counter!(
-                "coalesce_requests_total",
+                "resilience_coalesce_requests_total",
                 "Total number of requests processed by the coalesce layer"
             );
+            describe_counter!(
+                "resilience_coalesce_followers_total",
+                "Total number of requests deduplicated by the coalesce layer"
+            );
+            describe_gauge!(
+                "resilience_coalesce_dedup_ratio",
+                "Fraction of requests deduplicated by the coalesce layer"
+            );
         }
 
+        let settings = Settings {
+            name: config
+                .name
+                .clone()
+                .unwrap_or_else(|| String::from("<unnamed>")),
+            event_listeners: config.event_listeners.clone(),
+            share_window: config.share_window,
+            max_waiters_per_key: config.max_waiters_per_key,
+            max_inflight_keys: config.max_inflight_keys,
+            share_errors: config.share_errors,
+            leader_failure_policy: config.leader_failure_policy,
+            detach_leader: config.detach_leader,
+        };
         Self {
             inner,
             config,
-            in_flight: Arc::new(InFlight::new()),
+            in_flight: Arc::new(InFlight::new(settings)),
+            predicate: None,
             _req: PhantomData,
         }
     }
+
+    /// Only coalesce requests matching `predicate`; others bypass the layer.
+    pub(crate) fn with_predicate(mut self, predicate: Option<CoalescePredicate<Req>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
 }
 
 impl<S, K, Req, F> Clone for CoalesceService<S, K, Req, F>
@@ -158,6 +392,7 @@ where
             inner: self.inner.clone(),
             config: Arc::clone(&self.config),
             in_flight: Arc::clone(&self.in_flight),
+            predicate: self.predicate.clone(),
             _req: PhantomData,
         }
     }
@@ -168,10 +403,10 @@ where
     S: Service<Req> + Clone + Send + 'static,
     S::Response: Clone + Send + 'static,
     S::Error: Clone + Send + 'static,
-    S::Future: Send,
+    S::Future: Send + 'static,
     K: Hash + Eq + Clone + Send + Sync + 'static,
     Req: Send + 'static,
-    F: Fn(&Req) -> K + Clone + Send + Sync + 'static,
+    F: KeyExtractor<Req, K> + Clone + Send + Sync + 'static,
 {
     type Response = S::Response;
     type Error = CoalesceError<S::Error>;
@@ -182,40 +417,129 @@ where
     }
 
     fn call(&mut self, request: Req) -> Self::Future {
-        let key = (self.config.key_extractor)(&request);
-
         #[cfg(any(feature = "metrics", feature = "tracing"))]
         let name = self.config.name.as_deref().unwrap_or("<unnamed>");
 
-        // Check if there's already an in-flight request for this key
-        if let Some(receiver) = self.in_flight.try_join(key.clone()) {
-            // Wait for the leader's result
+        // Waiters and leaders keep polling after this call returns, outside the
+        // span, so the outcome is only recorded when it is known right away
+        #[cfg(feature = "tracing")]
+        let span = span::pattern_span("coalesce", name);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
+
+        // Requests excluded by the predicate or without a key go straight through
+        let key = match &self.predicate {
+            Some(predicate) if !predicate(&request) => None,
+            _ => self.config.key_extractor.extract(&request),
+        };
+        let Some(key) = key else {
             #[cfg(feature = "metrics")]
             {
-                counter!("coalesce_requests_total", "coalesce" => name.to_string(), "role" => "waiter").increment(1);
+                counter!("resilience_coalesce_requests_total", "name" => name.to_string(), "role" => "bypassed").increment(1);
             }
 
             #[cfg(feature = "tracing")]
-            debug!(coalesce = %name, "Request coalesced as waiter");
+            debug!(coalesce = %name, "Request bypassed coalescing");
+
+            return CoalesceFuture::Bypassed {
+                future: Box::pin(self.inner.call(request)),
+            };
+        };
+        let key = match &self.config.normalizer {
+            Some(normalize) => normalize(key),
+            None => key,
+        };
+
+        // Check if there's already an in-flight request or a shared result for this key
+        match self.in_flight.try_join(key.clone()) {
+            Join::Shared(response) => {
+                #[cfg(feature = "metrics")]
+                {
+                    counter!("resilience_coalesce_requests_total", "name" => name.to_string(), "role" => "shared").increment(1);
+                }
 
-            CoalesceFuture::Waiting { receiver }
-        } else {
-            // We're the leader, execute the request
-            #[cfg(feature = "metrics")]
-            {
-                counter!("coalesce_requests_total", "coalesce" => name.to_string(), "role" => "leader").increment(1);
+                #[cfg(feature = "tracing")]
+                debug!(coalesce = %name, "Request served from share window");
+
+                self.in_flight.joined(true);
+
+                #[cfg(feature = "tracing")]
+                span::record_outcome(&span, span::Outcome::Success);
+
+                CoalesceFuture::Shared {
+                    response: Some(response),
+                }
             }
+            Join::Rejected => {
+                #[cfg(feature = "metrics")]
+                {
+                    counter!("resilience_coalesce_requests_total", "name" => name.to_string(), "role" => "rejected").increment(1);
+                }
 
-            #[cfg(feature = "tracing")]
-            debug!(coalesce = %name, "Request executing as leader");
+                #[cfg(feature = "tracing")]
+                debug!(coalesce = %name, "Request rejected, coalesce limit reached");
+
+                #[cfg(feature = "tracing")]
+                span::record_outcome(&span, span::Outcome::Rejected);
+
+                CoalesceFuture::Rejected
+            }
+            Join::Wait(receiver) => {
+                // Wait for the leader's result
+                #[cfg(feature = "metrics")]
+                {
+                    counter!("resilience_coalesce_requests_total", "name" => name.to_string(), "role" => "waiter").increment(1);
+                }
+
+                #[cfg(feature = "tracing")]
+                debug!(coalesce = %name, "Request coalesced as waiter");
+
+                self.in_flight.joined(true);
+
+                // Keep the request if this waiter may be promoted to retry it
+                let retry = self
+                    .in_flight
+                    .settings
+                    .can_retry()
+                    .then(|| (self.inner.clone(), request));
+
+                CoalesceFuture::Waiting {
+                    receiver,
+                    key,
+                    retry,
+                    in_flight: Arc::clone(&self.in_flight),
+                }
+            }
+            Join::Lead => {
+                // We're the leader, execute the request
+                #[cfg(feature = "metrics")]
+                {
+                    counter!("resilience_coalesce_requests_total", "name" => name.to_string(), "role" => "leader").increment(1);
+                }
 
-            let future = self.inner.call(request);
-            let in_flight = Arc::clone(&self.in_flight);
+                #[cfg(feature = "tracing")]
+                debug!(coalesce = %name, "Request executing as leader");
 
-            CoalesceFuture::Leading {
-                future: Box::pin(future),
-                key: Some(key),
-                in_flight,
+                self.in_flight.joined(false);
+
+                let future = self.inner.call(request);
+                let in_flight = Arc::clone(&self.in_flight);
+
+                let leading = CoalesceFuture::Leading {
+                    future: Box::pin(future),
+                    key: Some(key),
+                    in_flight,
+                };
+
+                if self.in_flight.settings.detach_leader {
+                    // Run the request on its own task so it completes for the
+                    // waiters even if this caller goes away
+                    CoalesceFuture::Detached {
+                        handle: tokio::spawn(leading),
+                    }
+                } else {
+                    leading
+                }
             }
         }
     }
@@ -238,8 +562,38 @@ where
         in_flight: Arc<InFlight<K, S::Response, S::Error>>,
     },
     /// We're waiting for another request's result.
+    #[doc(hidden)]
     Waiting {
-        receiver: broadcast::Receiver<Result<S::Response, S::Error>>,
+        #[allow(private_interfaces)]
+        receiver: broadcast::Receiver<Outcome<S::Response, S::Error>>,
+        key: K,
+        retry: Option<(S, Req)>,
+        #[allow(private_interfaces)]
+        in_flight: Arc<InFlight<K, S::Response, S::Error>>,
+    },
+    /// We were promoted from waiter to leader and are waiting for the
+    /// service to become ready.
+    #[doc(hidden)]
+    Promoted {
+        service: S,
+        request: Option<Req>,
+        key: Option<K>,
+        #[allow(private_interfaces)]
+        in_flight: Arc<InFlight<K, S::Response, S::Error>>,
+    },
+    /// We're reusing a result completed within the share window.
+    #[doc(hidden)]
+    Shared { response: Option<S::Response> },
+    /// We were rejected because a coalesce limit was reached.
+    #[doc(hidden)]
+    Rejected,
+    /// The request is excluded from coalescing and runs directly.
+    #[doc(hidden)]
+    Bypassed { future: Pin<Box<S::Future>> },
+    /// We're the leader, waiting on the request running on its own task.
+    #[doc(hidden)]
+    Detached {
+        handle: JoinHandle<Result<S::Response, CoalesceError<S::Error>>>,
     },
 }
 
@@ -253,48 +607,132 @@ where
     type Output = Result<S::Response, CoalesceError<S::Error>>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // SAFETY: We only access the inner fields, not moving anything
+        // SAFETY: No field is structurally pinned (the leader's future is
+        // boxed), so we may move fields out and replace the state in place
         let this = unsafe { self.get_unchecked_mut() };
 
-        match this {
-            CoalesceFuture::Leading {
-                future,
-                key,
-                in_flight,
-            } => {
-                match future.as_mut().poll(cx) {
-                    Poll::Ready(result) => {
-                        // Notify all waiters
-                        if let Some(k) = key.take() {
-                            let result_clone = match &result {
-                                Ok(res) => Ok(res.clone()),
-                                Err(e) => Err(e.clone()),
+        loop {
+            match this {
+                CoalesceFuture::Leading {
+                    future,
+                    key,
+                    in_flight,
+                } => {
+                    return match future.as_mut().poll(cx) {
+                        Poll::Ready(result) => {
+                            // Notify all waiters
+                            if let Some(k) = key.take() {
+                                let result_clone = match &result {
+                                    Ok(res) => Ok(res.clone()),
+                                    Err(e) => Err(e.clone()),
+                                };
+                                in_flight.complete(&k, result_clone);
+                            }
+                            Poll::Ready(result.map_err(CoalesceError::Service))
+                        }
+                        Poll::Pending => Poll::Pending,
+                    };
+                }
+                CoalesceFuture::Waiting {
+                    receiver,
+                    key,
+                    retry,
+                    in_flight,
+                } => {
+                    // Try to receive the result
+                    match receiver.try_recv() {
+                        Ok(Outcome::Done(result)) => {
+                            return Poll::Ready(result.map_err(CoalesceError::Service));
+                        }
+                        Ok(Outcome::Retry) => {
+                            let Some((service, request)) = retry.take() else {
+                                return Poll::Ready(Err(CoalesceError::LeaderCancelled));
                             };
-                            in_flight.complete(&k, result_clone);
+                            // Race the other waiters to become the new leader
+                            match in_flight.try_join(key.clone()) {
+                                Join::Lead => {
+                                    let next = CoalesceFuture::Promoted {
+                                        service,
+                                        request: Some(request),
+                                        key: Some(key.clone()),
+                                        in_flight: Arc::clone(in_flight),
+                                    };
+                                    *this = next;
+                                }
+                                Join::Wait(next) => {
+                                    *receiver = next;
+                                    *retry = Some((service, request));
+                                }
+                                Join::Shared(response) => return Poll::Ready(Ok(response)),
+                                Join::Rejected => {
+                                    return Poll::Ready(Err(CoalesceError::TooManyWaiters));
+                                }
+                            }
+                        }
+                        Err(broadcast::error::TryRecvError::Empty) => {
+                            // Not ready yet, register for wakeup
+                            // We need to poll the receiver properly
+                            cx.waker().wake_by_ref();
+                            return Poll::Pending;
+                        }
+                        Err(broadcast::error::TryRecvError::Closed) => {
+                            // Leader was cancelled or dropped without sending
+                            return Poll::Ready(Err(CoalesceError::LeaderCancelled));
+                        }
+                        Err(broadcast::error::TryRecvError::Lagged(_)) => {
+                            // Missed the message (shouldn't happen with capacity 1)
+                            return Poll::Ready(Err(CoalesceError::RecvError));
                         }
-                        Poll::Ready(result.map_err(CoalesceError::Service))
                     }
-                    Poll::Pending => Poll::Pending,
                 }
-            }
-            CoalesceFuture::Waiting { receiver } => {
-                // Try to receive the result
-                match receiver.try_recv() {
-                    Ok(result) => Poll::Ready(result.map_err(CoalesceError::Service)),
-                    Err(broadcast::error::TryRecvError::Empty) => {
-                        // Not ready yet, register for wakeup
-                        // We need to poll the receiver properly
-                        cx.waker().wake_by_ref();
-                        Poll::Pending
-                    }
-                    Err(broadcast::error::TryRecvError::Closed) => {
-                        // Leader was cancelled or dropped without sending
-                        Poll::Ready(Err(CoalesceError::LeaderCancelled))
+                CoalesceFuture::Promoted {
+                    service,
+                    request,
+                    key,
+                    in_flight,
+                } => match service.poll_ready(cx) {
+                    Poll::Ready(Ok(())) => {
+                        let Some(request) = request.take() else {
+                            return Poll::Ready(Err(CoalesceError::RecvError));
+                        };
+                        in_flight
+                            .settings
+                            .event_listeners
+                            .emit(&CoalesceEvent::LeaderStarted {
+                                pattern_name: in_flight.settings.name.clone(),
+                                timestamp: Instant::now(),
+                            });
+                        let next = CoalesceFuture::Leading {
+                            future: Box::pin(service.call(request)),
+                            key: key.take(),
+                            in_flight: Arc::clone(in_flight),
+                        };
+                        *this = next;
                     }
-                    Err(broadcast::error::TryRecvError::Lagged(_)) => {
-                        // Missed the message (shouldn't happen with capacity 1)
-                        Poll::Ready(Err(CoalesceError::RecvError))
+                    Poll::Ready(Err(e)) => {
+                        if let Some(k) = key.take() {
+                            in_flight.complete(&k, Err(e.clone()));
+                        }
+                        return Poll::Ready(Err(CoalesceError::Service(e)));
                     }
+                    Poll::Pending => return Poll::Pending,
+                },
+                CoalesceFuture::Shared { response } => {
+                    return Poll::Ready(response.take().ok_or(CoalesceError::RecvError));
+                }
+                CoalesceFuture::Rejected => {
+                    return Poll::Ready(Err(CoalesceError::TooManyWaiters));
+                }
+                CoalesceFuture::Bypassed { future } => {
+                    return future.as_mut().poll(cx).map_err(CoalesceError::Service);
+                }
+                CoalesceFuture::Detached { handle } => {
+                    return match Pin::new(handle).poll(cx) {
+                        Poll::Ready(Ok(result)) => Poll::Ready(result),
+                        // The task panicked or the runtime shut down
+                        Poll::Ready(Err(_)) => Poll::Ready(Err(CoalesceError::LeaderCancelled)),
+                        Poll::Pending => Poll::Pending,
+                    };
                 }
             }
         }
@@ -311,7 +749,10 @@ where
     fn drop(&mut self) {
         // If we're the leader and being dropped without completing,
         // remove ourselves from the in-flight map so waiters get an error
-        if let CoalesceFuture::Leading { key, in_flight, .. } = self {
+        // (or retry, depending on the leader failure policy)
+        if let CoalesceFuture::Leading { key, in_flight, .. }
+        | CoalesceFuture::Promoted { key, in_flight, .. } = self
+        {
             if let Some(k) = key.take() {
                 in_flight.cancel(&k);
             }
@@ -331,28 +772,130 @@ mod tests {
         let err: CoalesceError<std::io::Error> = CoalesceError::RecvError;
         assert_eq!(err.to_string(), "failed to receive result from leader");
 
-        let io_err = std::io::Error::new(std::io::ErrorKind::Other, "test");
+        let err: CoalesceError<std::io::Error> = CoalesceError::TooManyWaiters;
+        assert_eq!(err.to_string(), "too many coalesced requests in flight");
+
+        let io_err = std::io::Error::other("test");
         let err = CoalesceError::Service(io_err);
         assert!(err.to_string().contains("service error"));
     }
 
     #[test]
     fn test_in_flight_basic() {
-        let in_flight: InFlight<String, String, String> = InFlight::new();
+        let in_flight: InFlight<String, String, String> = InFlight::new(Settings::default());
 
         // First request becomes leader
-        assert!(in_flight.try_join("key1".to_string()).is_none());
+        assert!(matches!(in_flight.try_join("key1".to_string()), Join::Lead));
 
         // Second request joins
-        assert!(in_flight.try_join("key1".to_string()).is_some());
+        assert!(matches!(
+            in_flight.try_join("key1".to_string()),
+            Join::Wait(_)
+        ));
 
         // Different key becomes leader
-        assert!(in_flight.try_join("key2".to_string()).is_none());
+        assert!(matches!(in_flight.try_join("key2".to_string()), Join::Lead));
 
         // Complete key1
         in_flight.complete(&"key1".to_string(), Ok("result".to_string()));
 
         // New request for key1 becomes leader again
-        assert!(in_flight.try_join("key1".to_string()).is_none());
+        assert!(matches!(in_flight.try_join("key1".to_string()), Join::Lead));
+    }
+
+    #[test]
+    fn test_in_flight_share_window() {
+        let in_flight: InFlight<String, String, String> = InFlight::new(Settings {
+            share_window: Some(Duration::from_secs(60)),
+            ..Default::default()
+        });
+
+        assert!(matches!(in_flight.try_join("key".to_string()), Join::Lead));
+        in_flight.complete(&"key".to_string(), Ok("result".to_string()));
+
+        // Completed result is reused within the window
+        match in_flight.try_join("key".to_string()) {
+            Join::Shared(res) => assert_eq!(res, "result"),
+            _ => panic!("expected shared result"),
+        }
+
+        // Errors are not shared
+        assert!(matches!(in_flight.try_join("err".to_string()), Join::Lead));
+        in_flight.complete(&"err".to_string(), Err("boom".to_string()));
+        assert!(matches!(in_flight.try_join("err".to_string()), Join::Lead));
+    }
+
+    #[test]
+    fn test_in_flight_limits() {
+        let in_flight: InFlight<String, String, String> = InFlight::new(Settings {
+            max_waiters_per_key: Some(1),
+            max_inflight_keys: Some(2),
+            ..Default::default()
+        });
+
+        assert!(matches!(in_flight.try_join("a".to_string()), Join::Lead));
+
+        // One waiter is allowed, the next is rejected
+        let _waiter = match in_flight.try_join("a".to_string()) {
+            Join::Wait(rx) => rx,
+            _ => panic!("expected waiter"),
+        };
+        assert!(matches!(
+            in_flight.try_join("a".to_string()),
+            Join::Rejected
+        ));
+
+        // Second key fits, a third does not
+        assert!(matches!(in_flight.try_join("b".to_string()), Join::Lead));
+        assert!(matches!(
+            in_flight.try_join("c".to_string()),
+            Join::Rejected
+        ));
+
+        // Completing a key frees a slot
+        in_flight.complete(&"b".to_string(), Ok("done".to_string()));
+        assert!(matches!(in_flight.try_join("c".to_string()), Join::Lead));
+    }
+
+    #[test]
+    fn test_in_flight_error_not_shared() {
+        let in_flight: InFlight<String, String, String> = InFlight::new(Settings {
+            share_errors: false,
+            ..Default::default()
+        });
+
+        assert!(matches!(in_flight.try_join("key".to_string()), Join::Lead));
+        let mut waiter = match in_flight.try_join("key".to_string()) {
+            Join::Wait(rx) => rx,
+            _ => panic!("expected waiter"),
+        };
+
+        in_flight.complete(&"key".to_string(), Err("boom".to_string()));
+        assert!(matches!(waiter.try_recv(), Ok(Outcome::Retry)));
+    }
+
+    #[test]
+    fn test_in_flight_cancel_policy() {
+        for (policy, expect_retry) in [
+            (LeaderFailurePolicy::Propagate, false),
+            (LeaderFailurePolicy::Retry, true),
+        ] {
+            let in_flight: InFlight<String, String, String> = InFlight::new(Settings {
+                leader_failure_policy: policy,
+                ..Default::default()
+            });
+
+            assert!(matches!(in_flight.try_join("key".to_string()), Join::Lead));
+            let mut waiter = match in_flight.try_join("key".to_string()) {
+                Join::Wait(rx) => rx,
+                _ => panic!("expected waiter"),
+            };
+
+            in_flight.cancel(&"key".to_string());
+            assert_eq!(
+                matches!(waiter.try_recv(), Ok(Outcome::Retry)),
+                expect_retry
+            );
+        }
     }
 }
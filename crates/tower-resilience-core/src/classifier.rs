@@ -23,6 +23,24 @@ pub trait FailureClassifier<Res, Err>: Send + Sync {
     ///
     /// Returns `true` if the result represents a failure.
     fn classify(&self, result: &Result<Res, Err>) -> bool;
+
+    /// Determines if the given result should not be recorded at all.
+    ///
+    /// Ignored results count as neither a success nor a failure, for example
+    /// business validation errors that say nothing about the health of the
+    /// downstream service. Defaults to ignoring nothing.
+    fn is_ignored(&self, _result: &Result<Res, Err>) -> bool {
+        false
+    }
+
+    /// Names the class of error behind a failed result, such as `"timeout"`
+    /// or `"connection"`.
+    ///
+    /// Patterns that break failures down by category only call this for
+    /// results already classified as failures. Defaults to no category.
+    fn category(&self, _result: &Result<Res, Err>) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Default failure classifier that treats all errors as failures.
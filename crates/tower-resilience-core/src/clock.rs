@@ -0,0 +1,144 @@
+//! Clock abstraction for deterministic testing.
+//!
+//! Patterns that make decisions based on elapsed time (how long a circuit has
+//! been open, how old a cache entry is, how long to back off) read time and
+//! sleep through a [`Clock`] instead of calling [`Instant::now`] and
+//! [`tokio::time::sleep`] directly. Production code uses [`SystemClock`];
+//! tests can substitute a [`MockClock`] and move time forward explicitly.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use tower_resilience_core::clock::{Clock, MockClock};
+//!
+//! let clock = MockClock::new();
+//! let start = clock.now();
+//!
+//! clock.advance(Duration::from_secs(30));
+//! assert_eq!(clock.now() - start, Duration::from_secs(30));
+//! ```
+
+use crate::time::Instant;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A boxed future returned by [`Clock::sleep`].
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
+/// A clock shared between a layer's configuration and its services.
+pub type SharedClock = Arc<dyn Clock>;
+
+/// A source of time for resilience patterns.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+
+    /// Returns a future that completes once `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// The real clock, backed by [`Instant::now`] and [`tokio::time::sleep`].
+///
+/// This is the default clock for every pattern.
+///
+/// Because sleeping goes through Tokio, `tokio::time::pause` still applies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A manually controlled clock for tests.
+///
+/// Time only moves when [`advance`](MockClock::advance) is called, or when
+/// something sleeps on the clock: [`sleep`](Clock::sleep) advances the clock by
+/// the requested duration and completes immediately, so backoff delays elapse
+/// without real waiting. Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    start: Instant,
+    offset: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// Creates a mock clock starting at the current instant.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            offset: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.offset.lock().unwrap() += duration;
+    }
+
+    /// Returns how far the clock has moved since it was created.
+    pub fn elapsed(&self) -> Duration {
+        *self.offset.lock().unwrap()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        self.advance(duration);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_advances() {
+        let clock = MockClock::new();
+        let start = clock.now();
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(clock.now() - start, Duration::from_secs(5));
+        assert_eq!(clock.elapsed(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_time() {
+        let clock = MockClock::new();
+        let shared: SharedClock = Arc::new(clock.clone());
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(shared.now(), clock.now());
+    }
+
+    #[tokio::test]
+    async fn test_mock_sleep_advances_without_waiting() {
+        let clock = MockClock::new();
+        let real_start = Instant::now();
+
+        clock.sleep(Duration::from_secs(3600)).await;
+
+        assert_eq!(clock.elapsed(), Duration::from_secs(3600));
+        assert!(real_start.elapsed() < Duration::from_secs(1));
+    }
+}
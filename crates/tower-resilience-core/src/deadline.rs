@@ -0,0 +1,208 @@
+//! Deadline propagation across composed layers.
+//!
+//! A time limiter bounds how long a call may take, but layers nested inside
+//! it (retries, hedges, inner time limiters) do not know about that bound and
+//! may schedule work that can never finish in time. A [`Deadline`] is set for
+//! the duration of a call and can be read by any nested layer, so inner
+//! operations shrink their budgets instead of exceeding the outer deadline.
+//!
+//! The current deadline is stored in a task-local. Layers that spawn tasks
+//! must re-enter the scope in the spawned task for the deadline to be
+//! visible there.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tower_resilience_core::Deadline;
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let deadline = Deadline::after(Duration::from_secs(2));
+//!
+//! deadline
+//!     .scope(async {
+//!         // Nested layers see the deadline and cap their own budgets
+//!         let budget = Deadline::remaining_or(Duration::from_secs(5));
+//!         assert!(budget <= Duration::from_secs(2));
+//!     })
+//!     .await;
+//! # }
+//! ```
+
+use crate::time::Instant;
+use std::future::Future;
+use std::time::Duration;
+
+tokio::task_local! {
+    static CURRENT: Deadline;
+}
+
+/// A point in time by which a call must complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline {
+    at: Instant,
+}
+
+impl Deadline {
+    /// Create a deadline at the given instant.
+    pub fn at(at: Instant) -> Self {
+        Self { at }
+    }
+
+    /// Create a deadline the given duration from now.
+    pub fn after(timeout: Duration) -> Self {
+        Self::at(Instant::now() + timeout)
+    }
+
+    /// Returns the instant of this deadline.
+    pub fn instant(&self) -> Instant {
+        self.at
+    }
+
+    /// Returns the time left until the deadline, or zero if it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.at.saturating_duration_since(Instant::now())
+    }
+
+    /// Returns `true` if the deadline has passed.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.at
+    }
+
+    /// Returns the earlier of this deadline and `other`.
+    pub fn min(self, other: Deadline) -> Deadline {
+        std::cmp::min(self, other)
+    }
+
+    /// Formats the remaining time as a gRPC `grpc-timeout` header value.
+    ///
+    /// The value uses the finest unit that fits in the 8 digits allowed by
+    /// the gRPC wire format, e.g. `"250m"` for 250 milliseconds.
+    pub fn grpc_timeout(&self) -> String {
+        const MAX: u128 = 99_999_999;
+
+        let remaining = self.remaining();
+        let nanos = remaining.as_nanos();
+        if nanos <= MAX {
+            return format!("{nanos}n");
+        }
+        let micros = remaining.as_micros();
+        if micros <= MAX {
+            return format!("{micros}u");
+        }
+        let millis = remaining.as_millis();
+        if millis <= MAX {
+            return format!("{millis}m");
+        }
+        let secs = remaining.as_secs() as u128;
+        if secs <= MAX {
+            return format!("{secs}S");
+        }
+        let minutes = secs / 60;
+        if minutes <= MAX {
+            return format!("{minutes}M");
+        }
+        format!("{}H", (minutes / 60).min(MAX))
+    }
+
+    /// Returns the deadline of the enclosing scope, if any.
+    pub fn current() -> Option<Deadline> {
+        CURRENT.try_with(|deadline| *deadline).ok()
+    }
+
+    /// Returns the time left until the current deadline, or `budget` if it is
+    /// shorter or no deadline is set.
+    pub fn remaining_or(budget: Duration) -> Duration {
+        match Self::current() {
+            Some(deadline) => budget.min(deadline.remaining()),
+            None => budget,
+        }
+    }
+
+    /// Runs `future` with this deadline as the current deadline.
+    ///
+    /// Deadlines only tighten: if an enclosing scope already has an earlier
+    /// deadline, that deadline remains in effect.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        let deadline = self.tightened();
+        CURRENT.scope(deadline, future).await
+    }
+
+    /// Runs `f` with this deadline as the current deadline.
+    ///
+    /// Like [`scope`](Self::scope), deadlines only tighten.
+    pub fn sync_scope<R>(self, f: impl FnOnce() -> R) -> R {
+        let deadline = self.tightened();
+        CURRENT.sync_scope(deadline, f)
+    }
+
+    fn tightened(self) -> Deadline {
+        match Self::current() {
+            Some(outer) => self.min(outer),
+            None => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_saturates_at_zero() {
+        let deadline = Deadline::at(Instant::now() - Duration::from_secs(1));
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+        assert!(deadline.is_expired());
+    }
+
+    #[test]
+    fn no_current_deadline_outside_scope() {
+        assert_eq!(Deadline::current(), None);
+        assert_eq!(
+            Deadline::remaining_or(Duration::from_secs(3)),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[tokio::test]
+    async fn scope_sets_current_deadline() {
+        let deadline = Deadline::after(Duration::from_secs(1));
+        deadline
+            .scope(async move {
+                assert_eq!(Deadline::current(), Some(deadline));
+                assert!(Deadline::remaining_or(Duration::from_secs(10)) <= Duration::from_secs(1));
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn nested_scope_only_tightens() {
+        let outer = Deadline::after(Duration::from_secs(1));
+        let later = Deadline::after(Duration::from_secs(10));
+        let earlier = Deadline::after(Duration::from_millis(100));
+
+        outer
+            .scope(async move {
+                later
+                    .scope(async move { assert_eq!(Deadline::current(), Some(outer)) })
+                    .await;
+                earlier
+                    .scope(async move { assert_eq!(Deadline::current(), Some(earlier)) })
+                    .await;
+            })
+            .await;
+    }
+
+    #[test]
+    fn grpc_timeout_uses_finest_unit() {
+        let now = Instant::now();
+        let value = Deadline::at(now + Duration::from_millis(250)).grpc_timeout();
+        assert!(value.ends_with('u'), "{value}");
+
+        let value = Deadline::at(now + Duration::from_secs(3600)).grpc_timeout();
+        assert!(value.ends_with('m'), "{value}");
+
+        let value = Deadline::at(now + Duration::from_secs(200_000)).grpc_timeout();
+        assert!(value.ends_with('S'), "{value}");
+    }
+}
@@ -136,6 +136,36 @@
 //! // New code (zero boilerplate)
 //! type ServiceError = ResilienceError<MyAppError>;
 //! ```
+//!
+//! # Classifying Nested Errors
+//!
+//! Without a unified error type, a composed stack produces nested errors such as
+//! `CircuitBreakerError<TimeLimiterError<E>>`. Every pattern's error implements
+//! [`PatternError`], so each level can be asked the same questions without
+//! matching on its variants:
+//!
+//! ```rust
+//! use tower_resilience_core::PatternError;
+//!
+//! fn should_back_off<P: PatternError>(error: &P) -> bool {
+//!     error.is_rejection() || error.retry_after().is_some()
+//! }
+//! ```
+//!
+//! For stacks built around [`BoxError`], [`flatten`] converts a pattern error
+//! into a single box: the wrapped service's error is passed through unchanged
+//! and the pattern's own failures become a [`PatternFailure`], which
+//! [`find_pattern_failure`] recovers later:
+//!
+//! ```rust
+//! use tower_resilience_core::{find_pattern_failure, flatten, BoxError, PatternError, ResilienceError};
+//!
+//! let error: ResilienceError<BoxError> = ResilienceError::CircuitOpen { name: None };
+//! let boxed: BoxError = flatten(error);
+//!
+//! let failure = find_pattern_failure(&*boxed).unwrap();
+//! assert!(failure.is_rejection());
+//! ```
 
 use std::fmt;
 use std::time::Duration;
@@ -258,7 +288,33 @@ where
     }
 }
 
-impl<E> std::error::Error for ResilienceError<E> where E: std::error::Error {}
+impl<E> std::error::Error for ResilienceError<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ResilienceError::CircuitOpen { .. } => Some(&CircuitOpenError),
+            _ => None,
+        }
+    }
+}
+
+/// Source of errors caused by an open circuit.
+///
+/// Circuit breaker rejections report this as their
+/// [`source`](std::error::Error::source), so that
+/// [`is_circuit_open_error`] can find them below other layers' errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CircuitOpenError;
+
+impl fmt::Display for CircuitOpenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("circuit is open")
+    }
+}
+
+impl std::error::Error for CircuitOpenError {}
 
 // Note: From implementations for each resilience layer error are provided
 // by the individual crates (bulkhead, circuitbreaker, etc.) to avoid
@@ -292,6 +348,210 @@ where
     }
 }
 
+/// A type-erased error, as used by `BoxError`-based Tower stacks.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Classification shared by every resilience pattern's error type.
+///
+/// Each pattern error either carries an error from the service it wraps
+/// (available through [`source_inner`](PatternError::source_inner)) or
+/// describes a failure produced by the pattern itself.
+pub trait PatternError {
+    /// The error type of the wrapped service.
+    type Inner;
+
+    /// Returns `true` if the pattern refused the request without calling the
+    /// wrapped service (open circuit, full bulkhead, rate limit, etc.).
+    fn is_rejection(&self) -> bool;
+
+    /// Returns `true` if the pattern gave up waiting on a deadline.
+    fn is_timeout(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if a circuit breaker refused the request because its
+    /// circuit is open.
+    fn is_circuit_open(&self) -> bool {
+        false
+    }
+
+    /// How long the caller should wait before trying again, if known.
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Returns the wrapped service's error, if this error carries one.
+    fn source_inner(&self) -> Option<&Self::Inner>;
+
+    /// Consumes the error, returning the wrapped service's error or `self` if
+    /// the failure came from the pattern itself.
+    fn into_source_inner(self) -> Result<Self::Inner, Self>
+    where
+        Self: Sized;
+}
+
+/// A pattern's own failure with its classification preserved.
+///
+/// Produced by [`flatten`] so that the failure can be boxed without knowing
+/// the wrapped service's error type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternFailure {
+    message: String,
+    rejection: bool,
+    timeout: bool,
+    circuit_open: bool,
+    retry_after: Option<Duration>,
+}
+
+impl PatternFailure {
+    /// Captures the message and classification of a pattern error.
+    pub fn from_pattern<P>(error: &P) -> Self
+    where
+        P: PatternError + fmt::Display,
+    {
+        Self {
+            message: error.to_string(),
+            rejection: error.is_rejection(),
+            timeout: error.is_timeout(),
+            circuit_open: error.is_circuit_open(),
+            retry_after: error.retry_after(),
+        }
+    }
+}
+
+impl fmt::Display for PatternFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for PatternFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.circuit_open
+            .then_some(&CircuitOpenError as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl PatternError for PatternFailure {
+    type Inner = std::convert::Infallible;
+
+    fn is_rejection(&self) -> bool {
+        self.rejection
+    }
+
+    fn is_timeout(&self) -> bool {
+        self.timeout
+    }
+
+    fn is_circuit_open(&self) -> bool {
+        self.circuit_open
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    fn source_inner(&self) -> Option<&Self::Inner> {
+        None
+    }
+
+    fn into_source_inner(self) -> Result<Self::Inner, Self> {
+        Err(self)
+    }
+}
+
+/// Converts a pattern error into a single [`BoxError`].
+///
+/// The wrapped service's error is boxed as-is, so an inner layer that has
+/// already been flattened is not wrapped again. Failures produced by the
+/// pattern itself become a [`PatternFailure`].
+///
+/// Use it with `map_err` to flatten each layer of a `BoxError` stack.
+pub fn flatten<P>(error: P) -> BoxError
+where
+    P: PatternError + fmt::Display,
+    P::Inner: Into<BoxError>,
+{
+    match error.into_source_inner() {
+        Ok(inner) => inner.into(),
+        Err(error) => Box::new(PatternFailure::from_pattern(&error)),
+    }
+}
+
+/// Searches an error and its sources for a [`PatternFailure`].
+pub fn find_pattern_failure<'a>(
+    error: &'a (dyn std::error::Error + 'static),
+) -> Option<&'a PatternFailure> {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if let Some(failure) = error.downcast_ref::<PatternFailure>() {
+            return Some(failure);
+        }
+        current = error.source();
+    }
+    None
+}
+
+/// Returns `true` if an error or any of its sources was caused by an open
+/// circuit.
+///
+/// Unlike [`PatternError::is_circuit_open`], this also finds a circuit
+/// breaker rejection wrapped in another layer's error, such as a time
+/// limiter or bulkhead error.
+pub fn is_circuit_open_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if error.is::<CircuitOpenError>() {
+            return true;
+        }
+        current = error.source();
+    }
+    false
+}
+
+impl<E> PatternError for ResilienceError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            ResilienceError::CircuitOpen { .. }
+                | ResilienceError::BulkheadFull { .. }
+                | ResilienceError::RateLimited { .. }
+                | ResilienceError::InstanceEjected { .. }
+        )
+    }
+
+    fn is_timeout(&self) -> bool {
+        self.is_timeout()
+    }
+
+    fn is_circuit_open(&self) -> bool {
+        self.is_circuit_open()
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ResilienceError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            ResilienceError::Application(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            ResilienceError::Application(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 impl<E> ResilienceError<E> {
     /// Returns `true` if this is a timeout error.
     pub fn is_timeout(&self) -> bool {
@@ -401,4 +661,63 @@ mod tests {
         let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(err);
         assert!(boxed.to_string().contains("test error"));
     }
+
+    #[test]
+    fn test_pattern_error_classification() {
+        let err: ResilienceError<TestError> = ResilienceError::RateLimited {
+            retry_after: Some(Duration::from_secs(1)),
+        };
+        assert!(err.is_rejection());
+        assert!(!PatternError::is_timeout(&err));
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(1)));
+        assert!(err.source_inner().is_none());
+
+        let err: ResilienceError<TestError> = ResilienceError::Timeout { layer: "test" };
+        assert!(!err.is_rejection());
+        assert!(PatternError::is_timeout(&err));
+
+        let err: ResilienceError<TestError> = ResilienceError::CircuitOpen { name: None };
+        assert!(err.is_rejection());
+        assert!(PatternError::is_circuit_open(&err));
+        assert!(find_pattern_failure(&*flatten(err))
+            .unwrap()
+            .is_circuit_open());
+
+        let err: ResilienceError<TestError> = ResilienceError::Application(TestError);
+        assert!(!err.is_rejection());
+        assert!(!PatternError::is_circuit_open(&err));
+        assert!(err.source_inner().is_some());
+    }
+
+    #[test]
+    fn test_flatten_passes_inner_error_through() {
+        let err: ResilienceError<TestError> = ResilienceError::Application(TestError);
+        let boxed = flatten(err);
+        assert!(boxed.downcast_ref::<TestError>().is_some());
+        assert!(find_pattern_failure(&*boxed).is_none());
+    }
+
+    #[test]
+    fn test_flatten_preserves_classification() {
+        let err: ResilienceError<TestError> = ResilienceError::RateLimited {
+            retry_after: Some(Duration::from_millis(50)),
+        };
+        let boxed = flatten(err);
+
+        let failure = find_pattern_failure(&*boxed).unwrap();
+        assert!(failure.is_rejection());
+        assert!(!failure.is_timeout());
+        assert_eq!(failure.retry_after(), Some(Duration::from_millis(50)));
+        assert_eq!(boxed.to_string(), "Rate limited, retry after 50ms");
+    }
+
+    #[test]
+    fn test_circuit_open_found_in_source_chain() {
+        let open: ResilienceError<TestError> = ResilienceError::CircuitOpen { name: None };
+        assert!(is_circuit_open_error(&open));
+        assert!(is_circuit_open_error(&*flatten(open)));
+
+        let app: ResilienceError<TestError> = ResilienceError::Application(TestError);
+        assert!(!is_circuit_open_error(&app));
+    }
 }
@@ -0,0 +1,366 @@
+//! Bounded background dispatch for event listeners.
+//!
+//! By default [`EventListeners::emit`](crate::EventListeners::emit) runs every
+//! listener inline, so a slow listener adds its latency to the request that
+//! produced the event. An [`EventBus`] moves that work off the request path:
+//! events are queued in a bounded buffer and delivered by a background task.
+//!
+//! When the buffer is full the oldest queued event is dropped to make room,
+//! so request latency never depends on listener throughput. Dropped events are
+//! counted by [`EventBus::dropped_events`] and, with the `metrics` feature, by
+//! the `resilience_event_bus_dropped_total` counter.
+//!
+//! The background task is spawned on the current Tokio runtime the first time
+//! an event is published, and again if that runtime shuts down. Outside a
+//! runtime, events are delivered inline.
+
+use crate::events::{dispatch, BoxedEventListener, ResilienceEvent};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use tokio::sync::Notify;
+
+/// Listeners an event is delivered to.
+type Listeners<E> = Arc<Vec<BoxedEventListener<E>>>;
+
+type PublishFn<E> = fn(&Arc<Shared<E>>, &E, &Listeners<E>);
+
+/// A bounded queue that delivers events to listeners from a background task.
+///
+/// Clones share the same queue and background task. Each event is delivered
+/// to the listeners of the collection that emitted it, so one bus can serve
+/// several [`EventListeners`](crate::EventListeners).
+///
+/// # Examples
+///
+/// ```rust
+/// use tower_resilience_core::{EventBus, EventListeners, FnListener, ResilienceEvent};
+/// # use std::time::Instant;
+/// # #[derive(Debug, Clone)]
+/// # struct MyEvent;
+/// # impl ResilienceEvent for MyEvent {
+/// #     fn event_type(&self) -> &'static str { "my_event" }
+/// #     fn timestamp(&self) -> Instant { Instant::now() }
+/// #     fn pattern_name(&self) -> &str { "example" }
+/// # }
+///
+/// let mut listeners = EventListeners::new();
+/// listeners.add(FnListener::new(|event: &MyEvent| println!("{:?}", event)));
+/// listeners.set_event_bus(EventBus::new(1024));
+/// ```
+pub struct EventBus<E> {
+    shared: Arc<Shared<E>>,
+    publish: PublishFn<E>,
+}
+
+struct Shared<E> {
+    queue: Mutex<VecDeque<(E, Listeners<E>)>>,
+    capacity: usize,
+    dropped: AtomicU64,
+    notify: Arc<Notify>,
+    /// Whether a worker task is alive to drain the queue.
+    running: Arc<AtomicBool>,
+}
+
+impl<E: ResilienceEvent + Clone + 'static> EventBus<E> {
+    /// Creates a bus that buffers up to `capacity` undelivered events.
+    ///
+    /// A capacity of zero is treated as one.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                queue: Mutex::new(VecDeque::new()),
+                capacity: capacity.max(1),
+                dropped: AtomicU64::new(0),
+                notify: Arc::new(Notify::new()),
+                running: Arc::new(AtomicBool::new(false)),
+            }),
+            publish: publish::<E>,
+        }
+    }
+}
+
+impl<E> EventBus<E> {
+    /// Returns the maximum number of undelivered events.
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    /// Returns the number of events dropped because the buffer was full.
+    pub fn dropped_events(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of events waiting to be delivered.
+    pub fn pending(&self) -> usize {
+        self.shared.queue.lock().unwrap().len()
+    }
+
+    pub(crate) fn publish(&self, event: &E, listeners: &Listeners<E>) {
+        (self.publish)(&self.shared, event, listeners)
+    }
+}
+
+impl<E> Clone for EventBus<E> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+            publish: self.publish,
+        }
+    }
+}
+
+impl<E> fmt::Debug for EventBus<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus")
+            .field("capacity", &self.shared.capacity)
+            .field("dropped_events", &self.dropped_events())
+            .finish()
+    }
+}
+
+impl<E> Drop for Shared<E> {
+    fn drop(&mut self) {
+        // Wake the worker so it notices the bus is gone and exits
+        self.notify.notify_one();
+    }
+}
+
+fn publish<E: ResilienceEvent + Clone + 'static>(
+    shared: &Arc<Shared<E>>,
+    event: &E,
+    listeners: &Listeners<E>,
+) {
+    if !shared.running.load(Ordering::Acquire) {
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            // No worker and no runtime to start one on: deliver whatever a
+            // dead worker left behind, then this event
+            loop {
+                let next = shared.queue.lock().unwrap().pop_front();
+                match next {
+                    Some((event, listeners)) => dispatch(&listeners, &event),
+                    None => break,
+                }
+            }
+            dispatch(listeners, event);
+            return;
+        };
+        if shared
+            .running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            let weak = Arc::downgrade(shared);
+            let notify = Arc::clone(&shared.notify);
+            let running = WorkerGuard(Arc::clone(&shared.running));
+            handle.spawn(run_worker(weak, notify, running));
+        }
+    }
+
+    {
+        let mut queue = shared.queue.lock().unwrap();
+        if queue.len() >= shared.capacity {
+            if let Some((_oldest, _)) = queue.pop_front() {
+                shared.dropped.fetch_add(1, Ordering::Relaxed);
+
+                #[cfg(feature = "metrics")]
+                record_dropped_metric(&_oldest);
+            }
+        }
+        queue.push_back((event.clone(), Arc::clone(listeners)));
+    }
+    shared.notify.notify_one();
+}
+
+/// Marks the worker as gone when its task ends or is dropped, including
+/// when the hosting runtime shuts down, so the next publish starts another.
+struct WorkerGuard(Arc<AtomicBool>);
+
+impl Drop for WorkerGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+async fn run_worker<E: ResilienceEvent>(
+    shared: Weak<Shared<E>>,
+    notify: Arc<Notify>,
+    _running: WorkerGuard,
+) {
+    loop {
+        // Drain first: a replacement worker inherits its predecessor's queue
+        {
+            let Some(shared) = shared.upgrade() else {
+                return;
+            };
+            loop {
+                let next = shared.queue.lock().unwrap().pop_front();
+                match next {
+                    Some((event, listeners)) => dispatch(&listeners, &event),
+                    None => break,
+                }
+            }
+        }
+
+        notify.notified().await;
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn record_dropped_metric<E: ResilienceEvent>(event: &E) {
+    metrics::counter!(
+        "resilience_event_bus_dropped_total",
+        "pattern" => event.pattern_name().to_string(),
+        "event_type" => event.event_type()
+    )
+    .increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EventListeners, FnListener};
+    use std::sync::atomic::AtomicUsize;
+    use std::time::{Duration, Instant};
+
+    #[derive(Debug, Clone)]
+    struct TestEvent {
+        id: usize,
+    }
+
+    impl ResilienceEvent for TestEvent {
+        fn event_type(&self) -> &'static str {
+            "test"
+        }
+
+        fn timestamp(&self) -> Instant {
+            Instant::now()
+        }
+
+        fn pattern_name(&self) -> &str {
+            "bus"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delivers_in_background() {
+        let seen = Arc::new(AtomicUsize::new(0));
+        let seen_clone = Arc::clone(&seen);
+
+        let mut listeners = EventListeners::new();
+        listeners.add(FnListener::new(move |_: &TestEvent| {
+            seen_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+        listeners.set_event_bus(EventBus::new(16));
+
+        for id in 0..5 {
+            listeners.emit(&TestEvent { id });
+        }
+
+        for _ in 0..100 {
+            if seen.load(Ordering::SeqCst) == 5 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        assert_eq!(seen.load(Ordering::SeqCst), 5);
+        assert_eq!(listeners.event_bus().unwrap().dropped_events(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drops_oldest_when_full() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let mut listeners = EventListeners::new();
+        listeners.add(FnListener::new(move |event: &TestEvent| {
+            seen_clone.lock().unwrap().push(event.id);
+        }));
+        let bus = EventBus::new(2);
+        listeners.set_event_bus(bus.clone());
+
+        // The current-thread runtime cannot run the worker until we yield
+        for id in 0..5 {
+            listeners.emit(&TestEvent { id });
+        }
+        assert_eq!(bus.pending(), 2);
+        assert_eq!(bus.dropped_events(), 3);
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(*seen.lock().unwrap(), vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_shared_bus_delivers_to_emitting_listeners() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let bus = EventBus::new(16);
+
+        let mut all = Vec::new();
+        for name in ["first", "second"] {
+            let seen = Arc::clone(&seen);
+            let mut listeners = EventListeners::new();
+            listeners.add(FnListener::new(move |event: &TestEvent| {
+                seen.lock().unwrap().push((name, event.id));
+            }));
+            listeners.set_event_bus(bus.clone());
+            all.push(listeners);
+        }
+
+        all[0].emit(&TestEvent { id: 0 });
+        all[1].emit(&TestEvent { id: 1 });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(*seen.lock().unwrap(), vec![("first", 0), ("second", 1)]);
+    }
+
+    #[test]
+    fn test_survives_runtime_shutdown() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        let mut listeners = EventListeners::new();
+        listeners.add(FnListener::new(move |event: &TestEvent| {
+            seen_clone.lock().unwrap().push(event.id);
+        }));
+        listeners.set_event_bus(EventBus::new(16));
+
+        let runtime = || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+        };
+
+        // The worker is spawned but the runtime shuts down before it runs
+        let rt = runtime();
+        rt.block_on(async { listeners.emit(&TestEvent { id: 0 }) });
+        drop(rt);
+
+        // Without a runtime, the stranded event is delivered inline first
+        listeners.emit(&TestEvent { id: 1 });
+        assert_eq!(*seen.lock().unwrap(), vec![0, 1]);
+
+        // A new runtime gets a new worker
+        runtime().block_on(async {
+            listeners.emit(&TestEvent { id: 2 });
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        });
+        assert_eq!(*seen.lock().unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_inline_outside_runtime() {
+        let seen = Arc::new(AtomicUsize::new(0));
+        let seen_clone = Arc::clone(&seen);
+
+        let mut listeners = EventListeners::new();
+        listeners.add(FnListener::new(move |_: &TestEvent| {
+            seen_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+        listeners.set_event_bus(EventBus::new(16));
+
+        listeners.emit(&TestEvent { id: 0 });
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,12 +2,62 @@
 //!
 //! Provides a unified event system that all resilience patterns can use
 //! for observability and monitoring.
+//!
+//! Listeners are normally registered per layer through its builder. For
+//! integrations that want every event from every layer, [`subscribe`]
+//! registers a process-wide callback instead:
+//!
+//! ```rust
+//! use tower_resilience_core::events::{subscribe, ResilienceEvent};
+//!
+//! let subscription = subscribe(|event: &dyn ResilienceEvent| {
+//!     println!("{} {}", event.pattern_name(), event.event_type());
+//! });
+//!
+//! // Later, to stop receiving events:
+//! subscription.unsubscribe();
+//! ```
+//!
+//! A listener that panics never takes down the request that emitted the
+//! event: the panic is caught, the remaining listeners still run, and global
+//! subscribers receive a [`ListenerPanicked`] diagnostic.
 
-#[cfg(feature = "tracing")]
+use crate::event_bus::EventBus;
+use crate::time::Instant;
 use std::any::Any;
 use std::fmt;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// How significant an event is.
+///
+/// Routine per-call events such as permitted calls are [`Info`](Self::Info),
+/// degraded outcomes such as rejections are [`Warn`](Self::Warn), and
+/// failures surfaced to the caller or circuits opening are
+/// [`Error`](Self::Error). Listeners can use it to drop high-volume events
+/// cheaply with [`FnListener::min_severity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Severity {
+    /// Routine activity.
+    #[default]
+    Info,
+    /// Degraded behavior that the pattern handled.
+    Warn,
+    /// A failure or a change that needs attention.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warn => "warn",
+            Severity::Error => "error",
+        })
+    }
+}
 
 /// Trait for events emitted by resilience patterns.
 pub trait ResilienceEvent: Send + Sync + fmt::Debug {
@@ -19,12 +69,86 @@ pub trait ResilienceEvent: Send + Sync + fmt::Debug {
 
     /// Returns the name of the pattern instance that emitted this event.
     fn pattern_name(&self) -> &str;
+
+    /// Returns how significant this event is.
+    ///
+    /// Defaults to [`Severity::Info`].
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
 }
 
 /// Trait for listening to resilience events.
 pub trait EventListener<E: ResilienceEvent>: Send + Sync {
     /// Called when an event occurs.
     fn on_event(&self, event: &E);
+
+    /// Returns whether a panic in [`on_event`](Self::on_event) is caught.
+    ///
+    /// Caught panics are reported and the remaining listeners still run.
+    /// Listeners returning `false` let the panic unwind into the code that
+    /// emitted the event, typically the request future. Defaults to `true`.
+    fn catch_panics(&self) -> bool {
+        true
+    }
+}
+
+/// Diagnostic sent to global subscribers when an event listener panics.
+///
+/// The panic itself was caught, so the request that emitted the event carried
+/// on. Receive it with [`subscribe`] to surface broken listeners:
+///
+/// ```rust
+/// use tower_resilience_core::events::{subscribe, ListenerPanicked, ResilienceEvent};
+///
+/// let subscription = subscribe(|event: &dyn ResilienceEvent| {
+///     if event.event_type() == ListenerPanicked::EVENT_TYPE {
+///         eprintln!("{:?}", event);
+///     }
+/// });
+/// # subscription.unsubscribe();
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ListenerPanicked {
+    /// Name of the pattern instance whose listener panicked.
+    pub pattern_name: String,
+    /// When the panic was caught.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::timestamp::serialize")
+    )]
+    pub timestamp: Instant,
+    /// Type of the event the listener was handling.
+    pub event_type: &'static str,
+    /// Position of the listener among those registered on the layer, or
+    /// among global subscribers.
+    pub listener_index: usize,
+    /// The panic message, if it was a string.
+    pub message: String,
+}
+
+impl ListenerPanicked {
+    /// The [`event_type`](ResilienceEvent::event_type) of this diagnostic.
+    pub const EVENT_TYPE: &'static str = "listener_panicked";
+}
+
+impl ResilienceEvent for ListenerPanicked {
+    fn event_type(&self) -> &'static str {
+        Self::EVENT_TYPE
+    }
+
+    fn timestamp(&self) -> Instant {
+        self.timestamp
+    }
+
+    fn pattern_name(&self) -> &str {
+        &self.pattern_name
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
 }
 
 /// Type alias for boxed event listeners.
@@ -33,14 +157,16 @@ pub type BoxedEventListener<E> = Arc<dyn EventListener<E>>;
 /// A collection of event listeners.
 #[derive(Clone)]
 pub struct EventListeners<E: ResilienceEvent> {
-    listeners: Vec<BoxedEventListener<E>>,
+    listeners: Arc<Vec<BoxedEventListener<E>>>,
+    bus: Option<EventBus<E>>,
 }
 
 impl<E: ResilienceEvent> EventListeners<E> {
     /// Creates a new empty event listener collection.
     pub fn new() -> Self {
         Self {
-            listeners: Vec::new(),
+            listeners: Arc::new(Vec::new()),
+            bus: None,
         }
     }
 
@@ -49,36 +175,43 @@ impl<E: ResilienceEvent> EventListeners<E> {
     where
         L: EventListener<E> + 'static,
     {
-        self.listeners.push(Arc::new(listener));
+        Arc::make_mut(&mut self.listeners).push(Arc::new(listener));
     }
 
     /// Emits an event to all registered listeners.
     ///
     /// If a listener panics, the panic is caught and the remaining listeners
     /// will still be called. This ensures one misbehaving listener doesn't
-    /// prevent others from receiving events. When the optional `tracing`
+    /// prevent others from receiving events. Global subscribers are sent a
+    /// [`ListenerPanicked`] diagnostic. When the optional `tracing`
     /// feature is enabled, panicking listeners are logged as warnings; with the
     /// `metrics` feature enabled a counter is incremented for observability.
+    /// Listeners whose [`EventListener::catch_panics`] returns `false` are
+    /// called without this protection.
+    ///
+    /// With an [`EventBus`] set, the event is queued and listeners run on a
+    /// background task instead.
     pub fn emit(&self, event: &E) {
-        for (index, listener) in self.listeners.iter().enumerate() {
-            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                listener.on_event(event);
-            }));
-
-            if let Err(_panic_payload) = result {
-                #[cfg(feature = "tracing")]
-                log_listener_panic(index, event, _panic_payload.as_ref());
-
-                #[cfg(feature = "metrics")]
-                record_listener_panic_metric(event);
+        if self.listeners.is_empty() && SUBSCRIBER_COUNT.load(Ordering::Acquire) == 0 {
+            return;
+        }
+        match &self.bus {
+            Some(bus) => bus.publish(event, &self.listeners),
+            None => dispatch(&self.listeners, event),
+        }
+    }
 
-                #[cfg(not(feature = "tracing"))]
-                let _ = index;
+    /// Delivers events through `bus` instead of calling listeners inline.
+    ///
+    /// Each event is queued with the listeners registered when it was
+    /// emitted, so one bus can be shared by several listener collections.
+    pub fn set_event_bus(&mut self, bus: EventBus<E>) {
+        self.bus = Some(bus);
+    }
 
-                #[cfg(not(any(feature = "tracing", feature = "metrics")))]
-                let _ = _panic_payload;
-            }
-        }
+    /// Returns the event bus, if events are dispatched asynchronously.
+    pub fn event_bus(&self) -> Option<&EventBus<E>> {
+        self.bus.as_ref()
     }
 
     /// Returns true if there are no listeners.
@@ -98,12 +231,128 @@ impl<E: ResilienceEvent> Default for EventListeners<E> {
     }
 }
 
+/// Type alias for process-wide subscriber callbacks.
+pub type GlobalSubscriber = Arc<dyn Fn(&dyn ResilienceEvent) + Send + Sync>;
+
+static SUBSCRIBERS: RwLock<Vec<(u64, GlobalSubscriber)>> = RwLock::new(Vec::new());
+static SUBSCRIBER_COUNT: AtomicUsize = AtomicUsize::new(0);
+static NEXT_SUBSCRIBER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Registers a callback that receives events from every pattern instance in
+/// the process.
+///
+/// Subscribers are called after the emitting layer's own listeners, on the
+/// same thread or background task, and see the event as a trait object so a
+/// single callback can handle every pattern. Use
+/// [`ResilienceEvent::pattern_name`] and [`ResilienceEvent::event_type`] to
+/// tell events apart.
+///
+/// The subscription stays active until [`Subscription::unsubscribe`] is
+/// called; dropping the handle does not remove it.
+pub fn subscribe<F>(f: F) -> Subscription
+where
+    F: Fn(&dyn ResilienceEvent) + Send + Sync + 'static,
+{
+    let id = NEXT_SUBSCRIBER_ID.fetch_add(1, Ordering::Relaxed);
+    let mut subscribers = SUBSCRIBERS.write().unwrap_or_else(|e| e.into_inner());
+    subscribers.push((id, Arc::new(f)));
+    SUBSCRIBER_COUNT.store(subscribers.len(), Ordering::Release);
+    Subscription { id }
+}
+
+/// Handle to a process-wide subscriber registered with [`subscribe`].
+#[derive(Debug)]
+pub struct Subscription {
+    id: u64,
+}
+
+impl Subscription {
+    /// Removes the subscriber so it receives no further events.
+    pub fn unsubscribe(self) {
+        let mut subscribers = SUBSCRIBERS.write().unwrap_or_else(|e| e.into_inner());
+        subscribers.retain(|(id, _)| *id != self.id);
+        SUBSCRIBER_COUNT.store(subscribers.len(), Ordering::Release);
+    }
+}
+
+/// Calls each listener in turn, then every global subscriber, isolating panics.
+pub(crate) fn dispatch<E: ResilienceEvent>(listeners: &[BoxedEventListener<E>], event: &E) {
+    for (index, listener) in listeners.iter().enumerate() {
+        if listener.catch_panics() {
+            isolate_panic(index, event, || listener.on_event(event));
+        } else {
+            listener.on_event(event);
+        }
+    }
+
+    if SUBSCRIBER_COUNT.load(Ordering::Acquire) > 0 {
+        notify_subscribers(event);
+    }
+}
+
+fn notify_subscribers<E: ResilienceEvent>(event: &E) {
+    // Snapshot so subscribers can subscribe or unsubscribe from the callback
+    let subscribers: Vec<GlobalSubscriber> = SUBSCRIBERS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(_, f)| Arc::clone(f))
+        .collect();
+
+    for (index, subscriber) in subscribers.iter().enumerate() {
+        isolate_panic(index, event, || subscriber(event));
+    }
+}
+
+/// Runs a listener, catching and reporting any panic so the rest still run.
+fn isolate_panic<E: ResilienceEvent>(index: usize, event: &E, f: impl FnOnce()) {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+
+    if let Err(panic_payload) = result {
+        let message = panic_message(panic_payload.as_ref());
+
+        #[cfg(feature = "tracing")]
+        log_listener_panic(index, event, &message);
+
+        #[cfg(feature = "metrics")]
+        record_listener_panic_metric(event);
+
+        // A subscriber panicking on the diagnostic itself is not reported
+        // again, which would never end
+        if event.event_type() != ListenerPanicked::EVENT_TYPE
+            && SUBSCRIBER_COUNT.load(Ordering::Acquire) > 0
+        {
+            notify_subscribers(&ListenerPanicked {
+                pattern_name: event.pattern_name().to_string(),
+                timestamp: Instant::now(),
+                event_type: event.event_type(),
+                listener_index: index,
+                message,
+            });
+        }
+    }
+}
+
+/// Extracts the message from a panic payload.
+fn panic_message(panic_payload: &(dyn Any + Send)) -> String {
+    panic_payload
+        .downcast_ref::<&'static str>()
+        .map(|s| (*s).to_string())
+        .or_else(|| panic_payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "non-string panic payload".to_string())
+}
+
+/// Predicate deciding which events an [`FnListener`] receives.
+pub type EventFilter<E> = Box<dyn Fn(&E) -> bool + Send + Sync>;
+
 /// A simple function-based event listener.
 pub struct FnListener<E, F>
 where
     F: Fn(&E) + Send + Sync,
 {
     f: F,
+    filter: Option<EventFilter<E>>,
+    catch_panics: bool,
     _phantom: std::marker::PhantomData<E>,
 }
 
@@ -115,9 +364,54 @@ where
     pub fn new(f: F) -> Self {
         Self {
             f,
+            filter: None,
+            catch_panics: true,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Only calls the listener for events matching `filter`.
+    ///
+    /// Calling this again replaces the previous filter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_core::events::{FnListener, ResilienceEvent};
+    ///
+    /// # fn listener<E: ResilienceEvent>() -> impl tower_resilience_core::EventListener<E> {
+    /// FnListener::new(|event: &E| println!("{:?}", event))
+    ///     .with_filter(|event: &E| event.event_type() != "call_permitted")
+    /// # }
+    /// ```
+    pub fn with_filter<P>(mut self, filter: P) -> Self
+    where
+        P: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Only calls the listener for events of at least `severity`.
+    ///
+    /// Shorthand for a [`with_filter`](Self::with_filter) on
+    /// [`ResilienceEvent::severity`].
+    pub fn min_severity(self, severity: Severity) -> Self
+    where
+        E: ResilienceEvent,
+    {
+        self.with_filter(move |event: &E| event.severity() >= severity)
+    }
+
+    /// Sets whether a panic in the listener is caught.
+    ///
+    /// Caught panics are reported and the request carries on (the default).
+    /// Pass `false` to let the panic unwind into the code emitting the event,
+    /// for example to fail tests on a broken listener.
+    pub fn catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+        self
+    }
 }
 
 impl<E, F> EventListener<E> for FnListener<E, F>
@@ -126,22 +420,18 @@ where
     F: Fn(&E) + Send + Sync,
 {
     fn on_event(&self, event: &E) {
-        (self.f)(event)
+        if self.filter.as_ref().is_none_or(|filter| filter(event)) {
+            (self.f)(event)
+        }
+    }
+
+    fn catch_panics(&self) -> bool {
+        self.catch_panics
     }
 }
 
 #[cfg(feature = "tracing")]
-fn log_listener_panic<E: ResilienceEvent>(
-    index: usize,
-    event: &E,
-    panic_payload: &(dyn Any + Send),
-) {
-    let panic_message = panic_payload
-        .downcast_ref::<&'static str>()
-        .map(|s| (*s).to_string())
-        .or_else(|| panic_payload.downcast_ref::<String>().cloned())
-        .unwrap_or_else(|| "non-string panic payload".to_string());
-
+fn log_listener_panic<E: ResilienceEvent>(index: usize, event: &E, panic_message: &str) {
     tracing::warn!(
         listener_index = index,
         pattern = event.pattern_name(),
@@ -237,6 +527,69 @@ mod tests {
         assert_eq!(counter2.load(Ordering::SeqCst), 2);
     }
 
+    #[test]
+    fn test_filtered_listener() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let c1 = Arc::clone(&counter);
+        let c2 = Arc::clone(&counter);
+
+        let mut listeners = EventListeners::new();
+        listeners.add(
+            FnListener::new(move |_: &TestEvent| {
+                c1.fetch_add(1, Ordering::SeqCst);
+            })
+            .with_filter(|event: &TestEvent| event.name == "kept"),
+        );
+        // Test events are Info, so this listener never fires
+        listeners.add(
+            FnListener::new(move |_: &TestEvent| {
+                c2.fetch_add(10, Ordering::SeqCst);
+            })
+            .min_severity(Severity::Warn),
+        );
+
+        for name in ["kept", "dropped"] {
+            listeners.emit(&TestEvent {
+                name: name.to_string(),
+                timestamp: Instant::now(),
+            });
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_severity_ordering() {
+        assert!(Severity::Info < Severity::Warn);
+        assert!(Severity::Warn < Severity::Error);
+        assert_eq!(Severity::default(), Severity::Info);
+    }
+
+    #[test]
+    fn test_global_subscriber() {
+        let seen = Arc::new(AtomicUsize::new(0));
+        let seen_clone = Arc::clone(&seen);
+
+        // Other tests emit concurrently, so only count this test's events
+        let subscription = subscribe(move |event: &dyn ResilienceEvent| {
+            if event.pattern_name() == "global-subscriber-test" {
+                seen_clone.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        // No local listeners: the event still reaches the subscriber
+        let listeners = EventListeners::<TestEvent>::new();
+        let event = TestEvent {
+            name: "global-subscriber-test".to_string(),
+            timestamp: Instant::now(),
+        };
+        listeners.emit(&event);
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+
+        subscription.unsubscribe();
+        listeners.emit(&event);
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+    }
+
     #[cfg(feature = "metrics")]
     #[test]
     fn listener_panics_increment_metrics_and_keep_processing() {
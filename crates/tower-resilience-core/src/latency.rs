@@ -0,0 +1,123 @@
+//! Sliding window of recent latencies for quantile estimation.
+//!
+//! Patterns that adapt to observed latency, such as quantile-driven hedge
+//! delays and adaptive timeouts, keep the most recent samples in a
+//! [`LatencyWindow`] and read a quantile from it on each call.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A bounded window of the most recent latency samples.
+///
+/// Once the window is full, each new sample evicts the oldest one.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_core::LatencyWindow;
+/// use std::time::Duration;
+///
+/// let window = LatencyWindow::new(100);
+/// for ms in 1..=100 {
+///     window.record(Duration::from_millis(ms));
+/// }
+/// assert_eq!(window.quantile(0.95, 1), Some(Duration::from_millis(95)));
+/// ```
+#[derive(Debug)]
+pub struct LatencyWindow {
+    capacity: usize,
+    samples: Mutex<VecDeque<Duration>>,
+}
+
+impl LatencyWindow {
+    /// Create a window holding up to `capacity` samples.
+    ///
+    /// A capacity of zero is treated as one.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            samples: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Returns the maximum number of samples kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Record an observed latency.
+    pub fn record(&self, latency: Duration) {
+        let mut samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+        while samples.len() >= self.capacity {
+            samples.pop_front();
+        }
+        samples.push_back(latency);
+    }
+
+    /// Returns the number of samples currently in the window.
+    pub fn len(&self) -> usize {
+        self.samples.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns `true` if no samples have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the latency at `quantile` (clamped to `0.0..=1.0`), or `None`
+    /// if fewer than `min_samples` (at least one) samples are in the window.
+    pub fn quantile(&self, quantile: f64, min_samples: usize) -> Option<Duration> {
+        let mut values: Vec<Duration> = {
+            let samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+            if samples.len() < min_samples.max(1) {
+                return None;
+            }
+            samples.iter().copied().collect()
+        };
+
+        let rank = (quantile.clamp(0.0, 1.0) * values.len() as f64).ceil() as usize;
+        let index = rank.saturating_sub(1).min(values.len() - 1);
+        let (_, value, _) = values.select_nth_unstable(index);
+        Some(*value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_quantile_until_min_samples() {
+        let window = LatencyWindow::new(10);
+        assert_eq!(window.quantile(0.5, 1), None);
+
+        window.record(Duration::from_millis(10));
+        window.record(Duration::from_millis(20));
+        assert_eq!(window.quantile(0.5, 3), None);
+        assert_eq!(window.quantile(0.5, 2), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn computes_quantile() {
+        let window = LatencyWindow::new(1000);
+        for ms in 1..=100 {
+            window.record(Duration::from_millis(ms));
+        }
+        assert_eq!(window.quantile(0.95, 1), Some(Duration::from_millis(95)));
+        assert_eq!(window.quantile(0.5, 1), Some(Duration::from_millis(50)));
+        assert_eq!(window.quantile(1.0, 1), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn evicts_oldest_samples() {
+        let window = LatencyWindow::new(2);
+        window.record(Duration::from_millis(500));
+        window.record(Duration::from_millis(10));
+        window.record(Duration::from_millis(20));
+
+        assert_eq!(window.len(), 2);
+        assert_eq!(window.quantile(1.0, 1), Some(Duration::from_millis(20)));
+    }
+}
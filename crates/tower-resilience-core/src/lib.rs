@@ -1,22 +1,48 @@
 //! Core infrastructure for tower-resilience.
 //!
 //! This crate provides shared functionality used across all tower-resilience modules:
-//! - Event system for observability
+//! - Event system for observability, with optional background dispatch
+//! - Wall-clock timestamps and optional serde support for exporting events
+//! - Optional OpenTelemetry span events and metrics
 //! - Metrics infrastructure
-//! - Common configuration patterns
+//! - Standard tracing spans shared by every layer
+//! - Common configuration patterns, including settings loaded from files
+//! - Runtime reload of settings into running layers
 //! - Registry for managing instances
 //! - Common error types for resilience patterns
 //! - AIMD controller for congestion control
+//! - Deadline propagation across composed layers
+//! - Sliding latency windows for quantile-driven delays and timeouts
+//! - Clock abstraction for deterministic testing
+//! - Time types that also work on `wasm32-unknown-unknown` (`wasm` feature)
 //! - Health integration traits for proactive resilience
 
 /// AIMD (Additive Increase / Multiplicative Decrease) controller.
 pub mod aimd;
 /// Failure classification traits and default implementations.
 pub mod classifier;
+/// Clock abstraction for deterministic testing.
+pub mod clock;
+/// Deadline propagation across composed layers.
+pub mod deadline;
 /// Common error types for resilience patterns.
 pub mod error;
+/// Bounded background dispatch for event listeners.
+pub mod event_bus;
 /// Event system for resilience pattern observability.
 pub mod events;
+/// Sliding window of recent latencies for quantile estimation.
+pub mod latency;
+/// Applying updated settings to running layers.
+pub mod reload;
+/// Shared helpers for loading pattern settings from configuration files.
+pub mod settings;
+/// Standard tracing spans for resilience layers.
+pub mod span;
+/// Time types used by every pattern, swappable for WebAssembly.
+pub mod time;
+/// Conversion of event timestamps to wall-clock time.
+pub mod timestamp;
 
 /// Unified error layer for composing resilience middleware.
 #[cfg(feature = "layer")]
@@ -26,17 +52,31 @@ pub mod error_layer;
 #[cfg(feature = "health-integration")]
 pub mod health_integration;
 
+/// OpenTelemetry integration for resilience events.
+#[cfg(feature = "otel")]
+pub mod otel;
+
 /// Test helpers for layer-crate contract regression tests.
 #[cfg(feature = "testing")]
 pub mod testing;
 
 pub use aimd::{AimdConfig, AimdController};
 pub use classifier::{DefaultClassifier, FailureClassifier, FnClassifier};
-pub use error::{IntoResilienceError, ResilienceError};
+pub use clock::{Clock, MockClock, SharedClock, SystemClock};
+pub use deadline::Deadline;
+pub use error::{
+    find_pattern_failure, flatten, is_circuit_open_error, BoxError, CircuitOpenError,
+    IntoResilienceError, PatternError, PatternFailure, ResilienceError,
+};
 
 #[cfg(feature = "layer")]
 pub use error_layer::{ResilienceErrorLayer, ResilienceErrorService, UnifiedErrors};
-pub use events::{EventListener, EventListeners, FnListener, ResilienceEvent};
+pub use event_bus::EventBus;
+pub use events::{
+    EventListener, EventListeners, FnListener, ListenerPanicked, ResilienceEvent, Severity,
+};
+pub use latency::LatencyWindow;
+pub use reload::{ConfigWatcher, Reloadable};
 
 #[cfg(feature = "health-integration")]
 pub use health_integration::{HealthTriggerable, SharedHealthTrigger, TriggerHealth};
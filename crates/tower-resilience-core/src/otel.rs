@@ -0,0 +1,164 @@
+//! OpenTelemetry integration for resilience events.
+//!
+//! [`install`] registers a [global subscriber](crate::events::subscribe) that
+//! forwards every event from every layer to OpenTelemetry:
+//!
+//! - **Span events**: each event is added to the active span (from the current
+//!   OpenTelemetry [`Context`](opentelemetry::Context)) as
+//!   `resilience.<event_type>`, timestamped with the event's own timestamp.
+//! - **Metrics**: a `resilience.events` counter is incremented for each event.
+//!
+//! Both carry the [`PATTERN_ATTRIBUTE`] and [`EVENT_TYPE_ATTRIBUTE`]
+//! attributes, so dashboards can break events down by instance and kind.
+//!
+//! Span events are only recorded when an event is dispatched inline on the
+//! request's task. Layers configured with `async_events` deliver events from a
+//! background task with no active span, so only the counter is updated.
+//!
+//! # Examples
+//!
+//! ```rust
+//! // Uses the globally configured meter provider
+//! let subscription = tower_resilience_core::otel::install();
+//!
+//! // Stop forwarding events
+//! subscription.unsubscribe();
+//! ```
+
+use crate::events::{subscribe, ResilienceEvent, Subscription};
+use crate::timestamp::to_system_time;
+use opentelemetry::metrics::{Counter, Meter};
+use opentelemetry::trace::get_active_span;
+use opentelemetry::KeyValue;
+
+/// Attribute holding the name of the layer instance that emitted the event.
+pub const PATTERN_ATTRIBUTE: &str = "resilience.pattern";
+
+/// Attribute holding the event type, as returned by [`ResilienceEvent::event_type`].
+pub const EVENT_TYPE_ATTRIBUTE: &str = "resilience.event_type";
+
+/// Name of the meter used by [`install`].
+pub const METER_NAME: &str = "tower-resilience";
+
+/// Forwards events from every layer to OpenTelemetry using the global meter provider.
+///
+/// Equivalent to `install_with_meter(opentelemetry::global::meter(METER_NAME))`.
+pub fn install() -> Subscription {
+    install_with_meter(opentelemetry::global::meter(METER_NAME))
+}
+
+/// Forwards events from every layer to OpenTelemetry, recording metrics on `meter`.
+pub fn install_with_meter(meter: Meter) -> Subscription {
+    let events = meter
+        .u64_counter("resilience.events")
+        .with_description("Resilience pattern events, by pattern and event type")
+        .build();
+
+    subscribe(move |event: &dyn ResilienceEvent| record(&events, event))
+}
+
+fn record(events: &Counter<u64>, event: &dyn ResilienceEvent) {
+    let attributes = [
+        KeyValue::new(PATTERN_ATTRIBUTE, event.pattern_name().to_string()),
+        KeyValue::new(EVENT_TYPE_ATTRIBUTE, event.event_type()),
+    ];
+    events.add(1, &attributes);
+
+    get_active_span(|span| {
+        if span.is_recording() {
+            span.add_event_with_timestamp(
+                format!("resilience.{}", event.event_type()),
+                to_system_time(event.timestamp()),
+                attributes.to_vec(),
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EventListeners, FnListener};
+    use opentelemetry::metrics::MeterProvider as _;
+    use opentelemetry::trace::{Tracer, TracerProvider as _};
+    use opentelemetry::Value;
+    use opentelemetry_sdk::metrics::data::{AggregatedMetrics, MetricData};
+    use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader, SdkMeterProvider};
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+    use std::time::Instant;
+
+    #[derive(Debug, Clone)]
+    struct TestEvent;
+
+    impl ResilienceEvent for TestEvent {
+        fn event_type(&self) -> &'static str {
+            "test_event"
+        }
+
+        fn timestamp(&self) -> Instant {
+            Instant::now()
+        }
+
+        fn pattern_name(&self) -> &str {
+            "otel-test"
+        }
+    }
+
+    fn is_test_pattern(attributes: &[KeyValue]) -> bool {
+        attributes
+            .iter()
+            .any(|kv| kv.key.as_str() == PATTERN_ATTRIBUTE && kv.value == Value::from("otel-test"))
+    }
+
+    #[test]
+    fn test_records_span_events_and_counter() {
+        let spans = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(spans.clone())
+            .build();
+        let metrics = InMemoryMetricExporter::default();
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(metrics.clone()).build())
+            .build();
+
+        let subscription = install_with_meter(meter_provider.meter("test"));
+
+        let mut listeners = EventListeners::new();
+        listeners.add(FnListener::new(|_: &TestEvent| {}));
+        tracer_provider.tracer("test").in_span("request", |_| {
+            listeners.emit(&TestEvent);
+            listeners.emit(&TestEvent);
+        });
+        subscription.unsubscribe();
+
+        // Other tests may emit events concurrently, so only count ours
+        let finished = spans.get_finished_spans().unwrap();
+        let span_events: Vec<_> = finished[0]
+            .events
+            .iter()
+            .filter(|e| is_test_pattern(&e.attributes))
+            .collect();
+        assert_eq!(span_events.len(), 2);
+        assert_eq!(span_events[0].name, "resilience.test_event");
+
+        meter_provider.force_flush().unwrap();
+        let mut count = 0;
+        for resource_metrics in metrics.get_finished_metrics().unwrap() {
+            for scope in resource_metrics.scope_metrics() {
+                for metric in scope.metrics() {
+                    assert_eq!(metric.name(), "resilience.events");
+                    if let AggregatedMetrics::U64(MetricData::Sum(sum)) = metric.data() {
+                        count += sum
+                            .data_points()
+                            .filter(|dp| {
+                                is_test_pattern(&dp.attributes().cloned().collect::<Vec<_>>())
+                            })
+                            .map(|dp| dp.value())
+                            .sum::<u64>();
+                    }
+                }
+            }
+        }
+        assert_eq!(count, 2);
+    }
+}
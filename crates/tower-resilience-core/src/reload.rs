@@ -0,0 +1,520 @@
+//! Applying updated settings to running layers.
+//!
+//! Layers are normally configured once, when the service stack is built. A
+//! [`ConfigWatcher`] keeps the tunable parts of that configuration live: it
+//! holds the application's settings type `S`, and every time a new value is
+//! supplied (from an admin API via [`ConfigWatcher::apply`], or from a file via
+//! [`ConfigWatcher::watch_file`]) it pushes the relevant section to each
+//! registered control handle.
+//!
+//! Handles opt in by implementing [`Reloadable`]. Only values that can change
+//! safely at runtime are applied (thresholds, limits and timeouts); structural
+//! settings such as window types still require rebuilding the layer.
+//!
+//! ```rust
+//! use tower_resilience_core::reload::{ConfigWatcher, Reloadable};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//!
+//! #[derive(Clone, Default)]
+//! struct LimitHandle(Arc<AtomicUsize>);
+//!
+//! impl Reloadable for LimitHandle {
+//!     type Settings = usize;
+//!
+//!     fn reload(&self, limit: &usize) {
+//!         self.0.store(*limit, Ordering::Release);
+//!     }
+//! }
+//!
+//! struct AppSettings {
+//!     limit: Option<usize>,
+//! }
+//!
+//! let handle = LimitHandle::default();
+//! let watcher = ConfigWatcher::new("app")
+//!     .on_reloaded(|applied| println!("reloaded {} handles", applied));
+//! watcher.register(handle.clone(), |settings: &AppSettings| settings.limit);
+//!
+//! watcher.apply(AppSettings { limit: Some(64) });
+//! assert_eq!(handle.0.load(Ordering::Acquire), 64);
+//! ```
+
+use crate::events::{EventListeners, FnListener, ResilienceEvent, Severity};
+use crate::time::Instant;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime};
+
+/// A control handle whose settings can be changed on a running layer.
+pub trait Reloadable: Send + Sync {
+    /// The settings section this handle understands.
+    type Settings;
+
+    /// Applies `settings` to the running layer.
+    ///
+    /// Fields that are unset or cannot change at runtime are ignored.
+    fn reload(&self, settings: &Self::Settings);
+}
+
+/// Events emitted by a [`ConfigWatcher`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum ReloadEvent {
+    /// New settings were applied to the registered handles.
+    ConfigReloaded {
+        /// Name of the watcher.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Number of handles that received a settings section.
+        applied: usize,
+    },
+    /// Updated settings could not be read or parsed; the previous settings
+    /// remain in effect.
+    ReloadFailed {
+        /// Name of the watcher.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Description of the failure.
+        error: String,
+    },
+}
+
+impl ResilienceEvent for ReloadEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            ReloadEvent::ConfigReloaded { .. } => "config_reloaded",
+            ReloadEvent::ReloadFailed { .. } => "reload_failed",
+        }
+    }
+
+    fn timestamp(&self) -> Instant {
+        match self {
+            ReloadEvent::ConfigReloaded { timestamp, .. }
+            | ReloadEvent::ReloadFailed { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn pattern_name(&self) -> &str {
+        match self {
+            ReloadEvent::ConfigReloaded { pattern_name, .. }
+            | ReloadEvent::ReloadFailed { pattern_name, .. } => pattern_name,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            ReloadEvent::ConfigReloaded { .. } => Severity::Info,
+            ReloadEvent::ReloadFailed { .. } => Severity::Error,
+        }
+    }
+}
+
+/// Error returned when updated settings cannot be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReloadError {
+    message: String,
+}
+
+impl fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to reload settings: {}", self.message)
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
+type Target<S> = Box<dyn Fn(&S) -> bool + Send + Sync>;
+
+struct Inner<S> {
+    name: String,
+    targets: Mutex<Vec<Target<S>>>,
+    current: Mutex<Option<Arc<S>>>,
+    event_listeners: EventListeners<ReloadEvent>,
+}
+
+/// Distributes updated settings to registered control handles.
+///
+/// Clones share the same handles and current settings, so a watcher can be
+/// handed to both an admin endpoint and a file-watching task.
+pub struct ConfigWatcher<S> {
+    inner: Arc<Inner<S>>,
+}
+
+impl<S> ConfigWatcher<S> {
+    /// Creates a watcher with no registered handles.
+    ///
+    /// The name is reported as the pattern name of [`ReloadEvent`]s.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                name: name.into(),
+                targets: Mutex::new(Vec::new()),
+                current: Mutex::new(None),
+                event_listeners: EventListeners::new(),
+            }),
+        }
+    }
+
+    /// Registers a callback invoked after settings are applied, with the
+    /// number of handles that received a section.
+    ///
+    /// Listeners must be added before the watcher is cloned.
+    pub fn on_reloaded<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.listeners()
+            .add(FnListener::new(move |event: &ReloadEvent| {
+                if let ReloadEvent::ConfigReloaded { applied, .. } = event {
+                    f(*applied);
+                }
+            }));
+        self
+    }
+
+    /// Registers a callback invoked when updated settings cannot be loaded.
+    ///
+    /// Listeners must be added before the watcher is cloned.
+    pub fn on_reload_failed<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.listeners()
+            .add(FnListener::new(move |event: &ReloadEvent| {
+                if let ReloadEvent::ReloadFailed { error, .. } = event {
+                    f(error);
+                }
+            }));
+        self
+    }
+
+    /// Registers a handle, with a function selecting its section of `S`.
+    ///
+    /// When the selector returns `None` the handle is left untouched. If
+    /// settings have already been applied, the handle receives them
+    /// immediately.
+    pub fn register<H, F>(&self, handle: H, select: F)
+    where
+        H: Reloadable + 'static,
+        F: Fn(&S) -> Option<H::Settings> + Send + Sync + 'static,
+    {
+        self.register_fn(move |settings: &S| match select(settings) {
+            Some(section) => {
+                handle.reload(&section);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Registers a function called with every new value of `S`.
+    ///
+    /// The function returns whether it applied anything, which is counted in
+    /// [`ReloadEvent::ConfigReloaded`]. Use this for state that has no
+    /// [`Reloadable`] handle.
+    pub fn register_fn<F>(&self, apply: F)
+    where
+        F: Fn(&S) -> bool + Send + Sync + 'static,
+    {
+        // Holding the targets lock means a concurrent `apply` either set
+        // `current` before we read it or reaches this target after the push
+        let mut targets = lock(&self.inner.targets);
+        if let Some(current) = self.current() {
+            apply(&current);
+        }
+        targets.push(Box::new(apply));
+    }
+
+    /// Applies new settings to every registered handle and emits
+    /// [`ReloadEvent::ConfigReloaded`].
+    pub fn apply(&self, settings: S) {
+        let settings = Arc::new(settings);
+        let targets = lock(&self.inner.targets);
+        *lock(&self.inner.current) = Some(Arc::clone(&settings));
+
+        let applied = targets.iter().filter(|apply| apply(&settings)).count();
+
+        self.inner
+            .event_listeners
+            .emit(&ReloadEvent::ConfigReloaded {
+                pattern_name: self.inner.name.clone(),
+                timestamp: Instant::now(),
+                applied,
+            });
+    }
+
+    /// Parses `text` and applies the result.
+    ///
+    /// On a parse error the current settings are kept and
+    /// [`ReloadEvent::ReloadFailed`] is emitted.
+    pub fn apply_str<F, E>(&self, text: &str, parse: F) -> Result<(), ReloadError>
+    where
+        F: FnOnce(&str) -> Result<S, E>,
+        E: fmt::Display,
+    {
+        match parse(text) {
+            Ok(settings) => {
+                self.apply(settings);
+                Ok(())
+            }
+            Err(e) => Err(self.fail(e.to_string())),
+        }
+    }
+
+    /// Returns the most recently applied settings.
+    pub fn current(&self) -> Option<Arc<S>> {
+        lock(&self.inner.current).clone()
+    }
+
+    /// Returns the name of this watcher.
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn fail(&self, message: String) -> ReloadError {
+        self.inner.event_listeners.emit(&ReloadEvent::ReloadFailed {
+            pattern_name: self.inner.name.clone(),
+            timestamp: Instant::now(),
+            error: message.clone(),
+        });
+        ReloadError { message }
+    }
+
+    fn listeners(&mut self) -> &mut EventListeners<ReloadEvent> {
+        &mut Arc::get_mut(&mut self.inner)
+            .expect("listeners must be added before the watcher is cloned")
+            .event_listeners
+    }
+}
+
+impl<S: Send + Sync + 'static> ConfigWatcher<S> {
+    /// Polls `path` every `interval` and applies its contents whenever the
+    /// file's modification time changes.
+    ///
+    /// The file is read once immediately. Read and parse errors emit
+    /// [`ReloadEvent::ReloadFailed`] and keep the current settings. The task
+    /// runs until the returned handle is aborted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    pub fn watch_file<F, E>(
+        &self,
+        path: impl Into<PathBuf>,
+        interval: Duration,
+        parse: F,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn(&str) -> Result<S, E> + Send + 'static,
+        E: fmt::Display,
+    {
+        let watcher = self.clone();
+        let path = path.into();
+
+        tokio::spawn(async move {
+            let mut last_modified: Option<SystemTime> = None;
+            loop {
+                match std::fs::metadata(&path).and_then(|m| m.modified()) {
+                    Ok(modified) if last_modified != Some(modified) => {
+                        last_modified = Some(modified);
+                        match std::fs::read_to_string(&path) {
+                            Ok(text) => {
+                                let _ = watcher.apply_str(&text, &parse);
+                            }
+                            Err(e) => {
+                                watcher.fail(format!("{}: {}", path.display(), e));
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) if last_modified.is_some() => {
+                        last_modified = None;
+                        watcher.fail(format!("{}: {}", path.display(), e));
+                    }
+                    Err(_) => {}
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+}
+
+impl<S> Clone for ConfigWatcher<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<S> fmt::Debug for ConfigWatcher<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigWatcher")
+            .field("name", &self.inner.name)
+            .field("handles", &lock(&self.inner.targets).len())
+            .finish()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct Limit(Arc<AtomicUsize>);
+
+    impl Reloadable for Limit {
+        type Settings = usize;
+
+        fn reload(&self, limit: &usize) {
+            self.0.store(*limit, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Debug)]
+    struct Settings {
+        a: Option<usize>,
+        b: Option<usize>,
+    }
+
+    fn parse(text: &str) -> Result<Settings, std::num::ParseIntError> {
+        let (a, b) = text.trim().split_once(',').unwrap_or((text, ""));
+        Ok(Settings {
+            a: Some(a.trim().parse()?),
+            b: b.trim().parse().ok(),
+        })
+    }
+
+    #[test]
+    fn applies_selected_sections() {
+        let reloads = Arc::new(Mutex::new(Vec::new()));
+        let reloads_clone = Arc::clone(&reloads);
+
+        let (a, b) = (Limit::default(), Limit::default());
+        let watcher = ConfigWatcher::new("test")
+            .on_reloaded(move |applied| reloads_clone.lock().unwrap().push(applied));
+        watcher.register(a.clone(), |s: &Settings| s.a);
+        watcher.register(b.clone(), |s: &Settings| s.b);
+
+        watcher.apply(Settings {
+            a: Some(4),
+            b: None,
+        });
+        assert_eq!(a.0.load(Ordering::SeqCst), 4);
+        assert_eq!(b.0.load(Ordering::SeqCst), 0);
+
+        watcher.apply(Settings {
+            a: Some(8),
+            b: Some(2),
+        });
+        assert_eq!(a.0.load(Ordering::SeqCst), 8);
+        assert_eq!(b.0.load(Ordering::SeqCst), 2);
+        assert_eq!(*reloads.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn late_registration_receives_current_settings() {
+        let watcher = ConfigWatcher::new("test");
+        watcher.apply(Settings {
+            a: Some(3),
+            b: None,
+        });
+
+        let limit = Limit::default();
+        watcher.register(limit.clone(), |s: &Settings| s.a);
+        assert_eq!(limit.0.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn apply_during_registration_reaches_new_target() {
+        let watcher = ConfigWatcher::new("test");
+        watcher.apply(Settings {
+            a: Some(1),
+            b: None,
+        });
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let (registering, started) = std::sync::mpsc::channel();
+        let register = {
+            let watcher = watcher.clone();
+            let seen = Arc::clone(&seen);
+            std::thread::spawn(move || {
+                watcher.register_fn(move |s: &Settings| {
+                    seen.lock().unwrap().push(s.a);
+                    // Hold the registration open while new settings arrive
+                    let _ = registering.send(());
+                    std::thread::sleep(Duration::from_millis(50));
+                    true
+                });
+            })
+        };
+
+        started.recv().unwrap();
+        watcher.apply(Settings {
+            a: Some(2),
+            b: None,
+        });
+        register.join().unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn parse_error_keeps_current_settings() {
+        let failures = Arc::new(AtomicUsize::new(0));
+        let failures_clone = Arc::clone(&failures);
+
+        let limit = Limit::default();
+        let watcher = ConfigWatcher::new("test").on_reload_failed(move |_| {
+            failures_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        watcher.register(limit.clone(), |s: &Settings| s.a);
+
+        watcher.apply_str("5", parse).unwrap();
+        assert!(watcher.apply_str("five", parse).is_err());
+
+        assert_eq!(limit.0.load(Ordering::SeqCst), 5);
+        assert_eq!(watcher.current().unwrap().a, Some(5));
+        assert_eq!(failures.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn watch_file_applies_changes() {
+        let path = std::env::temp_dir().join(format!("reload-{}.txt", std::process::id()));
+        std::fs::write(&path, "1").unwrap();
+
+        let limit = Limit::default();
+        let watcher = ConfigWatcher::new("file");
+        watcher.register(limit.clone(), |s: &Settings| s.a);
+
+        let task = watcher.watch_file(&path, Duration::from_millis(5), parse);
+        for _ in 0..100 {
+            if limit.0.load(Ordering::SeqCst) == 1 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(limit.0.load(Ordering::SeqCst), 1);
+
+        task.abort();
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,176 @@
+//! Shared helpers for loading pattern settings from configuration files.
+//!
+//! Each pattern crate exposes a plain-data settings struct (for example
+//! `CircuitBreakerSettings`) that can be deserialized with the `serde`
+//! feature and turned into a layer with `from_config`. Durations in those
+//! structs accept either an integer number of milliseconds or a string with a
+//! unit suffix, parsed by [`parse_duration`]:
+//!
+//! ```yaml
+//! wait_duration_in_open: 30s
+//! slow_call_duration_threshold: 250ms
+//! max_wait_duration: 100      # milliseconds
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+/// Error returned by [`parse_duration`] for malformed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDurationError {
+    input: String,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid duration {:?}: expected a number followed by ns, us, ms, s, m or h",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parses a human-readable duration such as `"250ms"`, `"30s"` or `"1.5m"`.
+///
+/// Supported units are `ns`, `us`, `ms`, `s`, `m` and `h`. A bare number is
+/// interpreted as milliseconds.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tower_resilience_core::settings::parse_duration;
+///
+/// assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+/// assert_eq!(parse_duration("1.5m").unwrap(), Duration::from_secs(90));
+/// assert_eq!(parse_duration("250").unwrap(), Duration::from_millis(250));
+/// assert!(parse_duration("soon").is_err());
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, ParseDurationError> {
+    let error = || ParseDurationError {
+        input: input.to_string(),
+    };
+
+    let trimmed = input.trim();
+    let split = trimmed
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+
+    let value: f64 = number.trim().parse().map_err(|_| error())?;
+    if !value.is_finite() || value < 0.0 {
+        return Err(error());
+    }
+
+    let seconds_per_unit = match unit {
+        "ns" => 1e-9,
+        "us" => 1e-6,
+        "" | "ms" => 1e-3,
+        "s" => 1.0,
+        "m" => 60.0,
+        "h" => 3600.0,
+        _ => return Err(error()),
+    };
+
+    Duration::try_from_secs_f64(value * seconds_per_unit).map_err(|_| error())
+}
+
+/// Deserializes a duration from milliseconds or a [`parse_duration`] string.
+///
+/// Intended for required `Duration` fields of settings structs:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Backoff {
+///     #[serde(deserialize_with = "tower_resilience_core::settings::duration")]
+///     interval: Duration,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    RawDuration::deserialize(deserializer)?.into_duration::<D::Error>()
+}
+
+/// Deserializes an optional duration from milliseconds or a [`parse_duration`] string.
+///
+/// Intended for `Option<Duration>` fields of settings structs:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Settings {
+///     #[serde(default, deserialize_with = "tower_resilience_core::settings::optional_duration")]
+///     timeout: Option<Duration>,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn optional_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    Option::<RawDuration>::deserialize(deserializer)?
+        .map(RawDuration::into_duration::<D::Error>)
+        .transpose()
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum RawDuration {
+    Millis(u64),
+    Text(String),
+}
+
+#[cfg(feature = "serde")]
+impl RawDuration {
+    fn into_duration<E: serde::de::Error>(self) -> Result<Duration, E> {
+        match self {
+            RawDuration::Millis(millis) => Ok(Duration::from_millis(millis)),
+            RawDuration::Text(text) => parse_duration(&text).map_err(E::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_units() {
+        assert_eq!(parse_duration("500ns").unwrap(), Duration::from_nanos(500));
+        assert_eq!(parse_duration("20us").unwrap(), Duration::from_micros(20));
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("2s").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3600));
+        assert_eq!(
+            parse_duration(" 1.5 s ").unwrap(),
+            Duration::from_millis(1500)
+        );
+    }
+
+    #[test]
+    fn test_bare_number_is_millis() {
+        assert_eq!(parse_duration("100").unwrap(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        for input in ["", "s", "-1s", "10 parsecs", "NaNs", "1e400s"] {
+            assert!(parse_duration(input).is_err(), "{input:?} should not parse");
+        }
+    }
+}
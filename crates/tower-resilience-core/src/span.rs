@@ -0,0 +1,240 @@
+//! Standard tracing spans for resilience layers.
+//!
+//! With the `tracing` feature enabled on a pattern crate, its service wraps
+//! every request in a span named [`SPAN_NAME`] carrying the same three fields:
+//!
+//! - `resilience.pattern`: which pattern handled the request, for example
+//!   `circuit_breaker` or `retry`
+//! - `resilience.name`: the instance name given to the layer's builder
+//! - `resilience.outcome`: how the request left the layer (see [`Outcome`])
+//!
+//! Layers nest, so a trace of a composed stack shows one span per layer and
+//! the outcome field points at the pattern that rejected, timed out or failed
+//! the request.
+//!
+//! [`Outcome`] is always available so that layers can classify results
+//! without the `tracing` feature; the span helpers require it.
+
+use crate::error::PatternError;
+use std::fmt;
+
+/// Name of the span created around each request.
+pub const SPAN_NAME: &str = "resilience";
+
+/// Field holding the pattern that created the span.
+pub const PATTERN_FIELD: &str = "resilience.pattern";
+
+/// Field holding the instance name of the layer.
+pub const NAME_FIELD: &str = "resilience.name";
+
+/// Field recorded with the request's [`Outcome`] when it completes.
+pub const OUTCOME_FIELD: &str = "resilience.outcome";
+
+/// How a request left a resilience layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// The request completed successfully.
+    Success,
+    /// The wrapped service returned an error.
+    Error,
+    /// The pattern refused the request without calling the wrapped service.
+    Rejected,
+    /// The pattern gave up waiting on a deadline.
+    Timeout,
+}
+
+impl Outcome {
+    /// Returns the value recorded in the `resilience.outcome` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Success => "success",
+            Outcome::Error => "error",
+            Outcome::Rejected => "rejected",
+            Outcome::Timeout => "timeout",
+        }
+    }
+
+    /// Classifies the result of a layer whose error implements
+    /// [`PatternError`].
+    pub fn from_result<T, E: PatternError>(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Outcome::Success,
+            Err(e) if e.is_rejection() => Outcome::Rejected,
+            Err(e) if e.is_timeout() => Outcome::Timeout,
+            Err(_) => Outcome::Error,
+        }
+    }
+
+    /// Classifies a result as success or error, for layers that return the
+    /// wrapped service's error unchanged.
+    pub fn from_plain_result<T, E>(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Outcome::Success,
+            Err(_) => Outcome::Error,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Creates the standard span for one request through a layer.
+///
+/// The outcome field starts empty and is filled in by [`record_outcome`].
+#[cfg(feature = "tracing")]
+pub fn pattern_span(pattern: &'static str, name: &str) -> tracing::Span {
+    tracing::info_span!(
+        "resilience",
+        resilience.pattern = pattern,
+        resilience.name = name,
+        resilience.outcome = tracing::field::Empty,
+    )
+}
+
+/// Records `outcome` on a span created by [`pattern_span`].
+#[cfg(feature = "tracing")]
+pub fn record_outcome(span: &tracing::Span, outcome: Outcome) {
+    span.record(OUTCOME_FIELD, outcome.as_str());
+}
+
+/// Runs `future` inside `span` and records the outcome chosen by `classify`,
+/// usually [`Outcome::from_result`].
+#[cfg(feature = "tracing")]
+pub fn instrument<F>(
+    span: tracing::Span,
+    future: F,
+    classify: fn(&F::Output) -> Outcome,
+) -> impl std::future::Future<Output = F::Output>
+where
+    F: std::future::Future,
+{
+    use tracing::Instrument;
+
+    let record = span.clone();
+    async move {
+        let output = future.await;
+        record_outcome(&record, classify(&output));
+        output
+    }
+    .instrument(span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    enum TestError {
+        Rejected,
+        Timeout,
+        Inner,
+    }
+
+    impl PatternError for TestError {
+        type Inner = ();
+
+        fn is_rejection(&self) -> bool {
+            matches!(self, TestError::Rejected)
+        }
+
+        fn is_timeout(&self) -> bool {
+            matches!(self, TestError::Timeout)
+        }
+
+        fn source_inner(&self) -> Option<&()> {
+            None
+        }
+
+        fn into_source_inner(self) -> Result<(), Self> {
+            Err(self)
+        }
+    }
+
+    #[test]
+    fn test_outcome_from_result() {
+        assert_eq!(
+            Outcome::from_result::<_, TestError>(&Ok(())),
+            Outcome::Success
+        );
+        assert_eq!(
+            Outcome::from_result::<(), _>(&Err(TestError::Rejected)),
+            Outcome::Rejected
+        );
+        assert_eq!(
+            Outcome::from_result::<(), _>(&Err(TestError::Timeout)),
+            Outcome::Timeout
+        );
+        assert_eq!(
+            Outcome::from_result::<(), _>(&Err(TestError::Inner)),
+            Outcome::Error
+        );
+        assert_eq!(Outcome::Rejected.to_string(), "rejected");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_span_records_fields() {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::Subscriber;
+        use tracing_subscriber::layer::{Context, SubscriberExt};
+        use tracing_subscriber::Layer;
+
+        #[derive(Clone, Default)]
+        struct Fields(Arc<Mutex<Vec<(String, String)>>>);
+
+        impl Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((field.name().to_string(), value.to_string()));
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+        }
+
+        impl<S: Subscriber> Layer<S> for Fields {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+                attrs.record(&mut self.clone());
+            }
+
+            fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+                values.record(&mut self.clone());
+            }
+        }
+
+        let fields = Fields::default();
+        let subscriber = tracing_subscriber::registry().with(fields.clone());
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let span = pattern_span("bulkhead", "db");
+        let result = instrument(
+            span,
+            async { Err::<(), _>(TestError::Rejected) },
+            Outcome::from_result,
+        )
+        .await;
+        assert!(result.is_err());
+
+        let recorded = fields.0.lock().unwrap().clone();
+        let expected = [
+            (PATTERN_FIELD, "bulkhead"),
+            (NAME_FIELD, "db"),
+            (OUTCOME_FIELD, "rejected"),
+        ];
+        for (field, value) in expected {
+            assert!(
+                recorded.contains(&(field.to_string(), value.to_string())),
+                "missing {}={} in {:?}",
+                field,
+                value,
+                recorded
+            );
+        }
+    }
+}
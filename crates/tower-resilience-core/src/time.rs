@@ -0,0 +1,20 @@
+//! Time types used by every pattern.
+//!
+//! On `wasm32-unknown-unknown` the standard library cannot read the clock:
+//! `std::time::Instant::now` panics. With the `wasm` feature these types come
+//! from [`web-time`](https://docs.rs/web-time), which reads the browser or
+//! edge runtime's clock there and re-exports the standard library types on
+//! every other target, so enabling the feature changes nothing natively.
+//!
+//! Patterns that support WebAssembly take time from this module rather than
+//! `std::time`. Their waiting still goes through Tokio unless a [`Clock`]
+//! is injected, so runtimes without a Tokio timer should configure one and
+//! prefer fail-fast settings that never sleep.
+//!
+//! [`Clock`]: crate::Clock
+
+#[cfg(not(feature = "wasm"))]
+pub use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "wasm")]
+pub use web_time::{Instant, SystemTime, UNIX_EPOCH};
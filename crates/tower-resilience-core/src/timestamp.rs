@@ -0,0 +1,80 @@
+//! Conversion of event timestamps to wall-clock time.
+//!
+//! Events record a monotonic [`Instant`], which is only meaningful within the
+//! current process. Anything leaving the process (JSON logs, message queues,
+//! dashboards) needs wall-clock time instead, so [`to_system_time`] and
+//! [`to_unix_millis`] map an instant onto the system clock. With the `serde`
+//! feature, [`serialize`] does the same for `#[serde(serialize_with = "...")]`
+//! fields.
+
+use crate::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Converts an [`Instant`] to the corresponding [`SystemTime`].
+///
+/// The conversion is relative to the current time on both clocks, so it is
+/// affected by system clock adjustments made since the instant was recorded.
+pub fn to_system_time(instant: Instant) -> SystemTime {
+    let now = Instant::now();
+    let system_now = SystemTime::now();
+    let system = if instant <= now {
+        system_now.checked_sub(now - instant)
+    } else {
+        system_now.checked_add(instant - now)
+    };
+    system.unwrap_or(UNIX_EPOCH)
+}
+
+/// Converts an [`Instant`] to milliseconds since the Unix epoch.
+///
+/// Instants that map before the epoch yield `0`.
+pub fn to_unix_millis(instant: Instant) -> u64 {
+    to_system_time(instant)
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Serializes an [`Instant`] as milliseconds since the Unix epoch.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Instant;
+///
+/// #[derive(serde::Serialize)]
+/// struct Record {
+///     #[serde(serialize_with = "tower_resilience_core::timestamp::serialize")]
+///     timestamp: Instant,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn serialize<S: serde::Serializer>(
+    instant: &Instant,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(to_unix_millis(*instant))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_now_matches_system_clock() {
+        let expected = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let millis = to_unix_millis(Instant::now());
+        assert!(millis.abs_diff(expected) < 1_000);
+    }
+
+    #[test]
+    fn test_past_instant_is_earlier() {
+        let now = Instant::now();
+        let earlier = now - Duration::from_secs(5);
+        let diff = to_unix_millis(now) - to_unix_millis(earlier);
+        assert!((4_900..=5_100).contains(&diff));
+    }
+}
@@ -0,0 +1,32 @@
+//! Configuration for the executor layer.
+
+use crate::context::ContextPropagator;
+use crate::drain::TaskRegistry;
+use crate::events::ExecutorEvent;
+use crate::service::SpawnLimits;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_resilience_core::EventListeners;
+
+/// Configuration shared by executor services.
+pub(crate) struct ExecutorConfig {
+    pub(crate) name: String,
+    pub(crate) limits: Option<Arc<SpawnLimits>>,
+    pub(crate) task_timeout: Option<Duration>,
+    pub(crate) event_listeners: EventListeners<ExecutorEvent>,
+    pub(crate) propagators: Vec<ContextPropagator>,
+    pub(crate) registry: Arc<TaskRegistry>,
+}
+
+impl Default for ExecutorConfig {
+    fn default() -> Self {
+        Self {
+            name: String::from("<unnamed>"),
+            limits: None,
+            task_timeout: None,
+            event_listeners: EventListeners::new(),
+            propagators: Vec::new(),
+            registry: Arc::new(TaskRegistry::default()),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Propagation of request-scoped context onto spawned tasks.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A spawned request task, as seen by a context propagator.
+pub type TaskFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Wraps a spawned task with context captured on the calling task.
+pub(crate) type TaskWrapper = Box<dyn FnOnce(TaskFuture) -> TaskFuture + Send>;
+
+/// Captures context on the calling task when a request is submitted.
+pub(crate) type ContextPropagator = Arc<dyn Fn() -> TaskWrapper + Send + Sync>;
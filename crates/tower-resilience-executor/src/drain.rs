@@ -0,0 +1,93 @@
+//! Tracking of spawned tasks for graceful shutdown.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::task::AbortHandle;
+
+/// The spawned tasks of every service created from one layer.
+#[derive(Debug, Default)]
+pub(crate) struct TaskRegistry {
+    /// Set once shutdown has begun
+    draining: AtomicBool,
+    /// Source of task ids
+    next_id: AtomicU64,
+    /// Abort handles for tasks that are still running
+    tasks: Mutex<HashMap<u64, AbortHandle>>,
+    /// Wakes the drain when a task finishes
+    task_finished: Notify,
+}
+
+impl TaskRegistry {
+    pub(crate) fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
+    /// Reserve an id for a task that is about to be spawned.
+    ///
+    /// The returned guard must be moved into the task, so the task is
+    /// deregistered however it ends.
+    pub(crate) fn guard(self: &Arc<Self>) -> TaskGuard {
+        TaskGuard {
+            registry: Arc::clone(self),
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Lock the task table. Hold the lock across the spawn so a task that
+    /// finishes immediately cannot deregister before it is registered.
+    pub(crate) fn tasks(&self) -> MutexGuard<'_, HashMap<u64, AbortHandle>> {
+        self.tasks.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn in_flight(&self) -> usize {
+        self.tasks().len()
+    }
+
+    /// Stop accepting tasks and wait up to `grace` for running tasks to
+    /// finish, then abort the rest. Returns the number of aborted tasks.
+    pub(crate) async fn drain(&self, grace: Duration) -> usize {
+        self.draining.store(true, Ordering::Release);
+
+        let deadline = tokio::time::Instant::now() + grace;
+        loop {
+            // Register for wakeups before checking to avoid missing a finish
+            let mut notified = std::pin::pin!(self.task_finished.notified());
+            notified.as_mut().enable();
+
+            if self.in_flight() == 0 {
+                return 0;
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                break;
+            }
+        }
+
+        let stragglers: Vec<_> = self.tasks().drain().map(|(_, handle)| handle).collect();
+        for handle in &stragglers {
+            handle.abort();
+        }
+        stragglers.len()
+    }
+}
+
+/// Deregisters a spawned task when dropped.
+pub(crate) struct TaskGuard {
+    registry: Arc<TaskRegistry>,
+    id: u64,
+}
+
+impl TaskGuard {
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        self.registry.tasks().remove(&self.id);
+        self.registry.task_finished.notify_waiters();
+    }
+}
@@ -0,0 +1,135 @@
+//! Event types for the executor layer.
+
+use std::time::{Duration, Instant};
+use tower_resilience_core::ResilienceEvent;
+
+/// Events emitted by the executor layer.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum ExecutorEvent {
+    /// The inner service panicked while processing a request.
+    TaskPanicked {
+        /// The name of the executor instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The panic message, if it was a string.
+        message: String,
+    },
+    /// A spawned task exceeded the task timeout and was aborted.
+    TaskTimedOut {
+        /// The name of the executor instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The configured task timeout.
+        timeout: Duration,
+    },
+    /// Shutdown began; new requests are rejected.
+    DrainStarted {
+        /// The name of the executor instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Number of tasks still running.
+        in_flight: usize,
+    },
+    /// Shutdown finished.
+    DrainCompleted {
+        /// The name of the executor instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// How long the drain took.
+        elapsed: Duration,
+        /// Number of tasks aborted after the grace period.
+        aborted: usize,
+    },
+}
+
+impl ResilienceEvent for ExecutorEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            ExecutorEvent::TaskPanicked { .. } => "executor_task_panicked",
+            ExecutorEvent::TaskTimedOut { .. } => "executor_task_timed_out",
+            ExecutorEvent::DrainStarted { .. } => "executor_drain_started",
+            ExecutorEvent::DrainCompleted { .. } => "executor_drain_completed",
+        }
+    }
+
+    fn timestamp(&self) -> Instant {
+        match self {
+            ExecutorEvent::TaskPanicked { timestamp, .. }
+            | ExecutorEvent::TaskTimedOut { timestamp, .. }
+            | ExecutorEvent::DrainStarted { timestamp, .. }
+            | ExecutorEvent::DrainCompleted { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn pattern_name(&self) -> &str {
+        match self {
+            ExecutorEvent::TaskPanicked { pattern_name, .. }
+            | ExecutorEvent::TaskTimedOut { pattern_name, .. }
+            | ExecutorEvent::DrainStarted { pattern_name, .. }
+            | ExecutorEvent::DrainCompleted { pattern_name, .. } => pattern_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_types() {
+        let now = Instant::now();
+
+        let panicked = ExecutorEvent::TaskPanicked {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            message: "boom".to_string(),
+        };
+        assert_eq!(panicked.event_type(), "executor_task_panicked");
+        assert_eq!(panicked.pattern_name(), "test");
+
+        let timed_out = ExecutorEvent::TaskTimedOut {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            timeout: Duration::from_secs(1),
+        };
+        assert_eq!(timed_out.event_type(), "executor_task_timed_out");
+        assert_eq!(timed_out.timestamp(), now);
+
+        let started = ExecutorEvent::DrainStarted {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            in_flight: 2,
+        };
+        assert_eq!(started.event_type(), "executor_drain_started");
+
+        let completed = ExecutorEvent::DrainCompleted {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            elapsed: Duration::from_millis(5),
+            aborted: 1,
+        };
+        assert_eq!(completed.event_type(), "executor_drain_completed");
+    }
+}
@@ -41,10 +41,13 @@ impl Executor for tokio::runtime::Handle {
     }
 }
 
-/// An executor that uses `spawn_blocking` for blocking operations.
+/// An executor that runs each request on tokio's blocking thread pool.
 ///
-/// This is useful for services that perform blocking I/O or CPU-intensive
-/// work that would block the async runtime.
+/// Each future is driven to completion with `block_on` inside
+/// `spawn_blocking`, so inner services that block the thread (synchronous
+/// I/O, blocking client libraries, FFI calls) never stall the async worker
+/// threads. The future can still use tokio timers and I/O, since it runs
+/// within the runtime's context.
 ///
 /// # Example
 ///
@@ -81,10 +84,77 @@ impl Executor for BlockingExecutor {
         F: Future + Send + 'static,
         F::Output: Send + 'static,
     {
-        // We need to spawn the future on the runtime, then use spawn_blocking
-        // for the actual work. However, spawn_blocking is for sync code.
-        // For async code that may block, we spawn normally but on the dedicated handle.
-        self.handle.spawn(future)
+        let handle = self.handle.clone();
+        self.handle.spawn_blocking(move || handle.block_on(future))
+    }
+}
+
+/// An executor that runs each request on a rayon thread pool.
+///
+/// Intended for CPU-bound request processing that should not compete with
+/// the async runtime's worker threads. Each future is driven to completion
+/// on a pool thread; tokio timers and I/O remain available through the
+/// runtime handle. Panics on the pool are propagated to the returned
+/// [`JoinHandle`].
+///
+/// Requires the `rayon` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tower_resilience_executor::RayonExecutor;
+/// use std::sync::Arc;
+///
+/// let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+/// let executor = RayonExecutor::current(Arc::new(pool));
+/// ```
+#[cfg(feature = "rayon")]
+#[derive(Clone)]
+pub struct RayonExecutor {
+    pool: std::sync::Arc<rayon::ThreadPool>,
+    handle: tokio::runtime::Handle,
+}
+
+#[cfg(feature = "rayon")]
+impl RayonExecutor {
+    /// Creates a new rayon executor using the given pool and runtime handle.
+    pub fn new(pool: std::sync::Arc<rayon::ThreadPool>, handle: tokio::runtime::Handle) -> Self {
+        Self { pool, handle }
+    }
+
+    /// Creates a new rayon executor using the current runtime handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from outside a tokio runtime.
+    pub fn current(pool: std::sync::Arc<rayon::ThreadPool>) -> Self {
+        Self::new(pool, tokio::runtime::Handle::current())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Executor for RayonExecutor {
+    fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let handle = self.handle.clone();
+        self.pool.spawn(move || {
+            let result =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handle.block_on(future)));
+            let _ = tx.send(result);
+        });
+
+        // Bridge the result back so callers get a regular JoinHandle
+        self.handle.spawn(async move {
+            match rx.await {
+                Ok(Ok(output)) => output,
+                Ok(Err(panic)) => std::panic::resume_unwind(panic),
+                Err(_) => panic!("rayon pool dropped the task"),
+            }
+        })
     }
 }
 
@@ -150,4 +220,42 @@ mod tests {
         let join = executor.spawn(async { 42 });
         assert_eq!(join.await.unwrap(), 42);
     }
+
+    #[tokio::test]
+    async fn test_blocking_executor_runs_off_worker_threads() {
+        let executor = BlockingExecutor::current();
+        let join = executor.spawn(async {
+            // Blocking here would stall a worker thread
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            std::thread::current().id()
+        });
+        assert_ne!(join.await.unwrap(), std::thread::current().id());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[tokio::test]
+    async fn test_rayon_executor() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+        let executor = RayonExecutor::current(std::sync::Arc::new(pool));
+
+        let join = executor.spawn(async { rayon::current_thread_index().is_some() });
+        assert!(join.await.unwrap());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[tokio::test]
+    async fn test_rayon_executor_propagates_panics() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let executor = RayonExecutor::current(std::sync::Arc::new(pool));
+
+        let join = executor.spawn(async { panic!("boom") });
+        assert!(join.await.unwrap_err().is_panic());
+    }
 }
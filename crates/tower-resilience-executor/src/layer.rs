@@ -1,7 +1,13 @@
 //! Layer implementation for the executor middleware.
 
-use crate::{Executor, ExecutorService};
+use crate::config::ExecutorConfig;
+use crate::context::{ContextPropagator, TaskFuture};
+use crate::service::SpawnLimits;
+use crate::{BlockingExecutor, Executor, ExecutorEvent, ExecutorService};
+use std::sync::Arc;
+use std::time::Duration;
 use tower_layer::Layer;
+use tower_resilience_core::{EventListeners, FnListener};
 
 /// A Tower layer that delegates request processing to an executor.
 ///
@@ -21,6 +27,7 @@ use tower_layer::Layer;
 #[derive(Clone)]
 pub struct ExecutorLayer<E> {
     executor: E,
+    config: Arc<ExecutorConfig>,
 }
 
 impl<E> ExecutorLayer<E>
@@ -29,7 +36,10 @@ where
 {
     /// Creates a new executor layer with the given executor.
     pub fn new(executor: E) -> Self {
-        Self { executor }
+        Self {
+            executor,
+            config: Arc::new(ExecutorConfig::default()),
+        }
     }
 
     /// Creates a builder for configuring the executor layer.
@@ -56,19 +66,33 @@ where
     type Service = ExecutorService<S, E>;
 
     fn layer(&self, service: S) -> Self::Service {
-        ExecutorService::new(service, self.executor.clone())
+        ExecutorService::with_config(service, self.executor.clone(), Arc::clone(&self.config))
     }
 }
 
 /// Builder for configuring an [`ExecutorLayer`].
 pub struct ExecutorLayerBuilder<E> {
     executor: Option<E>,
+    name: String,
+    max_in_flight: Option<usize>,
+    max_queue: usize,
+    task_timeout: Option<Duration>,
+    event_listeners: EventListeners<ExecutorEvent>,
+    propagators: Vec<ContextPropagator>,
 }
 
 impl<E> ExecutorLayerBuilder<E> {
     /// Creates a new builder.
     fn new() -> Self {
-        Self { executor: None }
+        Self {
+            executor: None,
+            name: String::from("<unnamed>"),
+            max_in_flight: None,
+            max_queue: 0,
+            task_timeout: None,
+            event_listeners: EventListeners::new(),
+            propagators: Vec::new(),
+        }
     }
 }
 
@@ -82,14 +106,184 @@ where
         self
     }
 
+    /// Sets the name of this executor for observability.
+    ///
+    /// Default: `"<unnamed>"`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the maximum number of requests processed at once.
+    ///
+    /// Requests beyond this limit wait in a queue bounded by
+    /// [`max_queue`](Self::max_queue), and are rejected with
+    /// [`ExecutorError::Saturated`](crate::ExecutorError::Saturated) once the
+    /// queue is full. The limit is shared by every service this layer
+    /// creates.
+    ///
+    /// Default: unbounded
+    pub fn max_in_flight(mut self, max: usize) -> Self {
+        self.max_in_flight = Some(max);
+        self
+    }
+
+    /// Sets how many requests may wait for a slot when
+    /// [`max_in_flight`](Self::max_in_flight) is reached.
+    ///
+    /// Has no effect unless `max_in_flight` is set.
+    ///
+    /// Default: 0 (reject as soon as all slots are busy)
+    pub fn max_queue(mut self, max: usize) -> Self {
+        self.max_queue = max;
+        self
+    }
+
+    /// Sets how long a spawned task may run before it is aborted.
+    ///
+    /// Runaway tasks fail with
+    /// [`ExecutorError::Timeout`](crate::ExecutorError::Timeout). The time
+    /// spent waiting in the queue does not count towards the timeout.
+    ///
+    /// Default: none
+    pub fn task_timeout(mut self, timeout: Duration) -> Self {
+        self.task_timeout = Some(timeout);
+        self
+    }
+
+    /// Registers a callback for when the inner service panics.
+    ///
+    /// The callback receives the panic message.
+    pub fn on_task_panicked<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ExecutorEvent::TaskPanicked { message, .. } = event {
+                f(message);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback for when a task is aborted by the task timeout.
+    ///
+    /// The callback receives the configured timeout.
+    pub fn on_task_timeout<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ExecutorEvent::TaskTimedOut { timeout, .. } = event {
+                f(*timeout);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback for when shutdown begins.
+    ///
+    /// The callback receives the number of tasks still running.
+    pub fn on_drain_started<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ExecutorEvent::DrainStarted { in_flight, .. } = event {
+                f(*in_flight);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback for when shutdown finishes.
+    ///
+    /// The callback receives the number of tasks aborted after the grace
+    /// period.
+    pub fn on_drain_completed<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ExecutorEvent::DrainCompleted { aborted, .. } = event {
+                f(*aborted);
+            }
+        }));
+        self
+    }
+
+    /// Adds a propagator that carries request-scoped context, such as trace
+    /// IDs, deadlines, or tokio task-locals, onto the spawned task.
+    ///
+    /// `capture` runs on the calling task for every request and returns a
+    /// wrapper that is applied to the spawned task. Propagators are applied
+    /// in the order they are added. With the `tracing` feature, the caller's
+    /// current span is propagated automatically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_executor::{ExecutorLayer, TaskFuture};
+    ///
+    /// tokio::task_local! {
+    ///     static REQUEST_ID: u64;
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let layer = ExecutorLayer::builder()
+    ///     .current()
+    ///     .context_propagator(|| {
+    ///         let id = REQUEST_ID.try_with(|id| *id).ok();
+    ///         move |task: TaskFuture| -> TaskFuture {
+    ///             match id {
+    ///                 Some(id) => Box::pin(REQUEST_ID.scope(id, task)),
+    ///                 None => task,
+    ///             }
+    ///         }
+    ///     })
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn context_propagator<P, W>(mut self, capture: P) -> Self
+    where
+        P: Fn() -> W + Send + Sync + 'static,
+        W: FnOnce(TaskFuture) -> TaskFuture + Send + 'static,
+    {
+        self.propagators
+            .push(Arc::new(move || Box::new(capture()) as _));
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the executor layer.
     ///
     /// # Panics
     ///
     /// Panics if no executor was configured.
     pub fn build(self) -> ExecutorLayer<E> {
+        let max_queue = self.max_queue;
+        let config = ExecutorConfig {
+            name: self.name,
+            limits: self
+                .max_in_flight
+                .map(|max| Arc::new(SpawnLimits::new(max, max_queue))),
+            task_timeout: self.task_timeout,
+            event_listeners: self.event_listeners,
+            propagators: self.propagators,
+            ..ExecutorConfig::default()
+        };
         ExecutorLayer {
             executor: self.executor.expect("executor must be configured"),
+            config: Arc::new(config),
         }
     }
 }
@@ -112,6 +306,35 @@ impl ExecutorLayerBuilder<tokio::runtime::Handle> {
     }
 }
 
+impl ExecutorLayerBuilder<BlockingExecutor> {
+    /// Runs requests on the current runtime's blocking thread pool.
+    ///
+    /// Use this for inner services that block the thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from outside a tokio runtime.
+    pub fn spawn_blocking(mut self) -> Self {
+        self.executor = Some(BlockingExecutor::current());
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl ExecutorLayerBuilder<crate::RayonExecutor> {
+    /// Runs requests on the given rayon thread pool.
+    ///
+    /// Use this for CPU-bound request processing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from outside a tokio runtime.
+    pub fn rayon(mut self, pool: Arc<rayon::ThreadPool>) -> Self {
+        self.executor = Some(crate::RayonExecutor::current(pool));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,6 +353,12 @@ mod tests {
         let _layer2 = layer.clone();
     }
 
+    #[tokio::test]
+    async fn test_builder_spawn_blocking() {
+        let layer = ExecutorLayer::builder().spawn_blocking().build();
+        let _layer2 = layer.clone();
+    }
+
     #[tokio::test]
     async fn test_builder_with_handle() {
         let handle = tokio::runtime::Handle::current();
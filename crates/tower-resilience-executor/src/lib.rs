@@ -54,21 +54,99 @@
 //! let layer = ExecutorLayer::new(compute_runtime.handle().clone());
 //! ```
 //!
-//! # Combining with Bulkhead
+//! # Blocking and CPU-Bound Services
 //!
-//! For bounded parallel execution, combine with a bulkhead layer:
+//! [`BlockingExecutor`] runs each request on tokio's blocking thread pool,
+//! for inner services that block the thread:
+//!
+//! ```rust
+//! use tower_resilience_executor::ExecutorLayer;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let layer = ExecutorLayer::builder().spawn_blocking().build();
+//! # }
+//! ```
+//!
+//! With the `rayon` feature, `RayonExecutor` runs requests on a rayon thread
+//! pool for CPU-bound processing:
 //!
 //! ```rust,ignore
 //! use tower_resilience_executor::ExecutorLayer;
-//! use tower_resilience_bulkhead::BulkheadLayer;
-//! use tower::ServiceBuilder;
+//! use std::sync::Arc;
 //!
-//! let service = ServiceBuilder::new()
-//!     // Limit concurrent requests
-//!     .layer(BulkheadLayer::builder().max_concurrent_calls(16).build())
-//!     // Execute on dedicated runtime
-//!     .layer(ExecutorLayer::current())
-//!     .service(tower::service_fn(|_: ()| async { Ok::<_, ()>(()) }));
+//! let pool = rayon::ThreadPoolBuilder::new().num_threads(8).build().unwrap();
+//! let layer = ExecutorLayer::builder().rayon(Arc::new(pool)).build();
+//! ```
+//!
+//! # Bounded Parallelism
+//!
+//! By default every request is spawned immediately. To bound parallelism,
+//! limit the number of requests in flight and how many may wait for a slot;
+//! requests beyond both fail with [`ExecutorError::Saturated`]:
+//!
+//! ```rust
+//! use tower_resilience_executor::ExecutorLayer;
+//! use tokio::runtime::Handle;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let layer = ExecutorLayer::builder()
+//!     .handle(Handle::current())
+//!     .max_in_flight(16)
+//!     .max_queue(64)
+//!     .build();
+//! # }
+//! ```
+//!
+//! # Panics and Runaway Tasks
+//!
+//! A panic in the inner service is caught and returned as
+//! [`ExecutorError::Panicked`] with the panic message. A task timeout aborts
+//! spawned tasks that run too long with [`ExecutorError::Timeout`]:
+//!
+//! ```rust
+//! use tower_resilience_executor::ExecutorLayer;
+//! use std::time::Duration;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let layer = ExecutorLayer::builder()
+//!     .current()
+//!     .name("compute")
+//!     .task_timeout(Duration::from_secs(30))
+//!     .on_task_panicked(|message| eprintln!("task panicked: {}", message))
+//!     .on_task_timeout(|timeout| eprintln!("task exceeded {:?}", timeout))
+//!     .build();
+//! # }
+//! ```
+//!
+//! # Context Propagation
+//!
+//! Spawned tasks don't inherit the caller's task-locals. With the `tracing`
+//! feature the caller's current span is propagated automatically; other
+//! request-scoped data can be carried over with
+//! [`ExecutorLayerBuilder::context_propagator`].
+//!
+//! # Graceful Shutdown
+//!
+//! [`ExecutorService::shutdown`] stops accepting requests, waits for spawned
+//! tasks up to a grace period, and aborts whatever is still running:
+//!
+//! ```rust
+//! use tower_resilience_executor::ExecutorLayer;
+//! use tower::Layer;
+//! use std::time::Duration;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # let backend = tower::service_fn(|_: ()| async { Ok::<_, ()>(()) });
+//! let service = ExecutorLayer::current().layer(backend);
+//!
+//! // On SIGTERM:
+//! let aborted = service.shutdown(Duration::from_secs(10)).await;
+//! # assert_eq!(aborted, 0);
+//! # }
 //! ```
 //!
 //! # Service Requirements
@@ -78,10 +156,18 @@
 //! already implement `Clone`, and for those that don't, consider wrapping
 //! them with `Buffer` first.
 
+mod config;
+mod context;
+mod drain;
+mod events;
 mod executor;
 mod layer;
 mod service;
 
+pub use context::TaskFuture;
+pub use events::ExecutorEvent;
+#[cfg(feature = "rayon")]
+pub use executor::RayonExecutor;
 pub use executor::{BlockingExecutor, CurrentRuntime, Executor};
 pub use layer::{ExecutorLayer, ExecutorLayerBuilder};
 pub use service::{ExecutorError, ExecutorFuture, ExecutorService};
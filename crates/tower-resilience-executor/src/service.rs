@@ -1,11 +1,22 @@
 //! Service implementation for the executor middleware.
 
+use crate::config::ExecutorConfig;
+use crate::context::TaskFuture;
+use crate::events::ExecutorEvent;
 use crate::Executor;
 use pin_project_lite::pin_project;
+use std::any::Any;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::task::{Context, Poll};
-use tokio::sync::oneshot;
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, Semaphore};
+#[cfg(feature = "tracing")]
+use tower_resilience_core::span::Outcome;
+use tower_resilience_core::PatternError;
 use tower_service::Service;
 
 /// A service that delegates request processing to an executor.
@@ -28,6 +39,39 @@ use tower_service::Service;
 pub struct ExecutorService<S, E> {
     inner: S,
     executor: E,
+    config: Arc<ExecutorConfig>,
+}
+
+/// Bounds on how many requests run and wait at once.
+///
+/// Shared by every service created from the same layer.
+#[derive(Debug)]
+pub(crate) struct SpawnLimits {
+    /// One permit per request allowed to run
+    permits: Arc<Semaphore>,
+    /// Requests spawned but waiting for a permit
+    queued: AtomicUsize,
+    /// Maximum number of waiting requests
+    max_queue: usize,
+}
+
+impl SpawnLimits {
+    pub(crate) fn new(max_in_flight: usize, max_queue: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_in_flight)),
+            queued: AtomicUsize::new(0),
+            max_queue,
+        }
+    }
+}
+
+/// A place in the wait queue, released on drop.
+struct QueuePosition(Arc<SpawnLimits>);
+
+impl Drop for QueuePosition {
+    fn drop(&mut self) {
+        self.0.queued.fetch_sub(1, Ordering::AcqRel);
+    }
 }
 
 impl<S, E> ExecutorService<S, E> {
@@ -36,6 +80,15 @@ impl<S, E> ExecutorService<S, E> {
         Self {
             inner: service,
             executor,
+            config: Arc::new(ExecutorConfig::default()),
+        }
+    }
+
+    pub(crate) fn with_config(service: S, executor: E, config: Arc<ExecutorConfig>) -> Self {
+        Self {
+            inner: service,
+            executor,
+            config,
         }
     }
 
@@ -53,6 +106,43 @@ impl<S, E> ExecutorService<S, E> {
     pub fn into_inner(self) -> S {
         self.inner
     }
+
+    /// Returns the number of spawned tasks that have not finished.
+    ///
+    /// Counts tasks from every service created by the same layer.
+    pub fn in_flight(&self) -> usize {
+        self.config.registry.in_flight()
+    }
+
+    /// Gracefully shuts down the executor.
+    ///
+    /// New requests are rejected with [`ExecutorError::ShuttingDown`]
+    /// immediately. Spawned tasks are given up to `grace` to finish, after
+    /// which the remaining tasks are aborted. Returns the number of aborted
+    /// tasks.
+    ///
+    /// Shutdown applies to every service created by the same layer. Tasks
+    /// on a `RayonExecutor` pool cannot be interrupted
+    /// once running; aborting them only stops waiting for their result.
+    pub async fn shutdown(&self, grace: Duration) -> usize {
+        let config = &self.config;
+        config.event_listeners.emit(&ExecutorEvent::DrainStarted {
+            pattern_name: config.name.clone(),
+            timestamp: Instant::now(),
+            in_flight: config.registry.in_flight(),
+        });
+
+        let started = Instant::now();
+        let aborted = config.registry.drain(grace).await;
+
+        config.event_listeners.emit(&ExecutorEvent::DrainCompleted {
+            pattern_name: config.name.clone(),
+            timestamp: Instant::now(),
+            elapsed: started.elapsed(),
+            aborted,
+        });
+        aborted
+    }
 }
 
 impl<S, E, Req> Service<Req> for ExecutorService<S, E>
@@ -74,22 +164,115 @@ where
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
+        let (tx, rx) = oneshot::channel();
+
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("executor", &self.config.name);
+
+        if self.config.registry.is_draining() {
+            #[cfg(feature = "tracing")]
+            tower_resilience_core::span::record_outcome(&span, Outcome::Rejected);
+            let _ = tx.send(Err(ExecutorError::ShuttingDown));
+            return ExecutorFuture { rx };
+        }
+
+        // Claim a slot now, or a place in the queue to wait for one
+        let (permit, position) = match &self.config.limits {
+            None => (None, None),
+            Some(limits) => match Arc::clone(&limits.permits).try_acquire_owned() {
+                Ok(permit) => (Some(permit), None),
+                Err(_) => {
+                    if limits.queued.fetch_add(1, Ordering::AcqRel) >= limits.max_queue {
+                        limits.queued.fetch_sub(1, Ordering::AcqRel);
+                        #[cfg(feature = "tracing")]
+                        tower_resilience_core::span::record_outcome(&span, Outcome::Rejected);
+                        let _ = tx.send(Err(ExecutorError::Saturated));
+                        return ExecutorFuture { rx };
+                    }
+                    (None, Some(QueuePosition(Arc::clone(limits))))
+                }
+            },
+        };
+
         // Take the readied service for the spawned task, leaving a fresh
         // clone behind for the next poll_ready cycle. See #286.
         let clone = self.inner.clone();
         let mut service = std::mem::replace(&mut self.inner, clone);
-        let (tx, rx) = oneshot::channel();
 
-        // Spawn the request processing on the executor
-        let _handle = self.executor.spawn(async move {
-            // Call the service
-            let result = service.call(req).await;
+        let config = Arc::clone(&self.config);
+        let guard = self.config.registry.guard();
+        let id = guard.id();
+        #[cfg(feature = "tracing")]
+        let task_span = span.clone();
+
+        // Process the request on the executor
+        let task = async move {
+            // Deregisters the task however it ends, including when aborted
+            let _guard = guard;
+
+            // Wait for a slot if the request was queued
+            let _permit = match position {
+                Some(position) => {
+                    let permit = Arc::clone(&position.0.permits).acquire_owned().await;
+                    drop(position);
+                    permit.ok()
+                }
+                None => permit,
+            };
+
+            // Call the service, capturing panics so they reach the caller
+            let call = CatchUnwind {
+                inner: async move { service.call(req).await },
+            };
+            let outcome = match config.task_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, call).await.ok(),
+                None => Some(call.await),
+            };
+
+            let result = match outcome {
+                Some(Ok(result)) => result.map_err(ExecutorError::Service),
+                Some(Err(panic)) => {
+                    let message = panic_message(&*panic);
+                    config.event_listeners.emit(&ExecutorEvent::TaskPanicked {
+                        pattern_name: config.name.clone(),
+                        timestamp: Instant::now(),
+                        message: message.clone(),
+                    });
+                    Err(ExecutorError::Panicked(message))
+                }
+                None => {
+                    config.event_listeners.emit(&ExecutorEvent::TaskTimedOut {
+                        pattern_name: config.name.clone(),
+                        timestamp: Instant::now(),
+                        timeout: config.task_timeout.unwrap_or_default(),
+                    });
+                    Err(ExecutorError::Timeout)
+                }
+            };
+
+            #[cfg(feature = "tracing")]
+            tower_resilience_core::span::record_outcome(&task_span, Outcome::from_result(&result));
 
             // Send the result back
             // The send may fail if the receiver is dropped (caller cancelled)
             // We ignore this error since there's nothing useful to do.
-            let _ = tx.send(result.map_err(ExecutorError::Service));
-        });
+            let _ = tx.send(result);
+        };
+
+        // Carry the caller's context over to the spawned task
+        let mut task: TaskFuture = Box::pin(task);
+        for propagator in &self.config.propagators {
+            task = propagator()(task);
+        }
+        // The pattern span is a child of the caller's span, so the spawned
+        // task stays inside the caller's trace
+        #[cfg(feature = "tracing")]
+        let task = tracing::Instrument::instrument(task, span);
+
+        let mut tasks = self.config.registry.tasks();
+        let handle = self.executor.spawn(task);
+        tasks.insert(id, handle.abort_handle());
+        drop(tasks);
 
         ExecutorFuture { rx }
     }
@@ -100,6 +283,14 @@ where
 pub enum ExecutorError<E> {
     /// The spawned task was cancelled or panicked.
     TaskCancelled,
+    /// The executor was at its in-flight limit and the queue was full.
+    Saturated,
+    /// The inner service panicked. Contains the panic message.
+    Panicked(String),
+    /// The spawned task exceeded the task timeout and was aborted.
+    Timeout,
+    /// The executor is shutting down and no longer accepts requests.
+    ShuttingDown,
     /// The inner service returned an error.
     Service(E),
 }
@@ -108,6 +299,10 @@ impl<E: std::fmt::Display> std::fmt::Display for ExecutorError<E> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::TaskCancelled => write!(f, "executor task was cancelled"),
+            Self::Saturated => write!(f, "executor is saturated"),
+            Self::Panicked(message) => write!(f, "executor task panicked: {}", message),
+            Self::Timeout => write!(f, "executor task timed out"),
+            Self::ShuttingDown => write!(f, "executor is shutting down"),
             Self::Service(e) => write!(f, "service error: {}", e),
         }
     }
@@ -122,6 +317,64 @@ impl<E: std::error::Error + 'static> std::error::Error for ExecutorError<E> {
     }
 }
 
+impl<E> PatternError for ExecutorError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        matches!(self, Self::Saturated | Self::ShuttingDown)
+    }
+
+    fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            Self::Service(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            Self::Service(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
+/// Extract a readable message from a panic payload.
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("<non-string panic>")
+    }
+}
+
+pin_project! {
+    /// Resolves to `Err` with the panic payload if the inner future panics.
+    struct CatchUnwind<F> {
+        #[pin]
+        inner: F,
+    }
+}
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.project().inner;
+        match std::panic::catch_unwind(AssertUnwindSafe(|| inner.poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(panic) => Poll::Ready(Err(panic)),
+        }
+    }
+}
+
 pin_project! {
     /// Future returned by [`ExecutorService`].
     pub struct ExecutorFuture<T, E> {
@@ -153,6 +406,19 @@ mod tests {
         assert_eq!(err.to_string(), "executor task was cancelled");
     }
 
+    #[test]
+    fn test_saturated_display() {
+        let err: ExecutorError<std::io::Error> = ExecutorError::Saturated;
+        assert_eq!(err.to_string(), "executor is saturated");
+    }
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(panic_message(&"boom"), "boom");
+        assert_eq!(panic_message(&String::from("boom")), "boom");
+        assert_eq!(panic_message(&42), "<non-string panic>");
+    }
+
     #[test]
     fn test_error_eq() {
         let err1: ExecutorError<&str> = ExecutorError::TaskCancelled;
@@ -1,7 +1,12 @@
 //! Configuration for the fallback service.
 
+use crate::rate::FallbackRate;
 use crate::{FallbackEvent, FallbackStrategy, HandlePredicate, HandleResponsePredicate};
-use tower_resilience_core::{EventListeners, FnListener};
+use std::time::Instant;
+use tower_resilience_core::{is_circuit_open_error, EventListeners, FnListener, PatternError};
+
+#[cfg(feature = "metrics")]
+use metrics::{counter, gauge};
 
 /// Configuration for the fallback service.
 pub struct FallbackConfig<Req, Res, E> {
@@ -10,6 +15,52 @@ pub struct FallbackConfig<Req, Res, E> {
     pub(crate) handle_predicate: Option<HandlePredicate<E>>,
     pub(crate) handle_response_predicate: Option<HandleResponsePredicate<Res>>,
     pub(crate) event_listeners: EventListeners<FallbackEvent>,
+    pub(crate) rate: FallbackRate,
+}
+
+impl<Req, Res, E> FallbackConfig<Req, Res, E> {
+    /// Emits `event` and, for call outcomes, updates the rolling fallback rate.
+    pub(crate) fn emit(&self, event: &FallbackEvent) {
+        self.event_listeners.emit(event);
+
+        let applied = match event {
+            FallbackEvent::Applied { .. } => true,
+            FallbackEvent::Success { .. }
+            | FallbackEvent::Failed { .. }
+            | FallbackEvent::Skipped { .. } => false,
+            FallbackEvent::FailedAttempt { .. } | FallbackEvent::RateExceeded { .. } => return,
+        };
+
+        #[cfg(feature = "metrics")]
+        if let FallbackEvent::Applied { strategy, .. } = event {
+            counter!(
+                "resilience_fallback_applied_total",
+                "name" => self.name.clone(),
+                "strategy" => *strategy
+            )
+            .increment(1);
+        }
+
+        let sample = self.rate.record(applied);
+
+        #[cfg(feature = "metrics")]
+        gauge!("resilience_fallback_active_ratio", "name" => self.name.clone()).set(sample.ratio);
+
+        if sample.exceeded {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                fallback = %self.name,
+                ratio = sample.ratio,
+                "Fallback rate exceeded threshold"
+            );
+
+            self.event_listeners.emit(&FallbackEvent::RateExceeded {
+                pattern_name: self.name.clone(),
+                timestamp: Instant::now(),
+                ratio: sample.ratio,
+            });
+        }
+    }
 }
 
 /// Builder for constructing a [`FallbackLayer`](crate::FallbackLayer).
@@ -19,6 +70,8 @@ pub struct FallbackConfigBuilder<Req, Res, E> {
     handle_predicate: Option<HandlePredicate<E>>,
     handle_response_predicate: Option<HandleResponsePredicate<Res>>,
     event_listeners: EventListeners<FallbackEvent>,
+    rate_window: usize,
+    rate_threshold: Option<f64>,
 }
 
 impl<Req, Res, E> Default for FallbackConfigBuilder<Req, Res, E> {
@@ -36,6 +89,8 @@ impl<Req, Res, E> FallbackConfigBuilder<Req, Res, E> {
             handle_predicate: None,
             handle_response_predicate: None,
             event_listeners: EventListeners::new(),
+            rate_window: 100,
+            rate_threshold: None,
         }
     }
 
@@ -132,6 +187,42 @@ impl<Req, Res, E> FallbackConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Only trigger fallback when a circuit breaker rejects the request with
+    /// an open circuit.
+    ///
+    /// Works with any error reporting
+    /// [`PatternError::is_circuit_open`], such as `CircuitBreakerError` or a
+    /// unified `ResilienceError`, and with an open-circuit rejection wrapped
+    /// in another layer's error (see
+    /// [`is_circuit_open_error`](tower_resilience_core::is_circuit_open_error)),
+    /// such as a time limiter between the fallback and the breaker. Other
+    /// errors are propagated as-is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_core::ResilienceError;
+    /// use tower_resilience_fallback::FallbackLayer;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// # impl std::fmt::Display for MyError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str("my error") }
+    /// # }
+    /// # impl std::error::Error for MyError {}
+    /// let layer: FallbackLayer<String, String, ResilienceError<MyError>> =
+    ///     FallbackLayer::builder()
+    ///         .service(|req: String| async move { Ok(format!("backup: {}", req)) })
+    ///         .handle_circuit_open()
+    ///         .build();
+    /// ```
+    pub fn handle_circuit_open(self) -> Self
+    where
+        E: PatternError + std::error::Error + 'static,
+    {
+        self.handle(|e: &E| e.is_circuit_open() || is_circuit_open_error(e))
+    }
+
     /// Trigger fallback for successful responses matching this predicate.
     ///
     /// When this predicate returns `true` for a response, the fallback strategy
@@ -168,6 +259,54 @@ impl<Req, Res, E> FallbackConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Sets the number of recent calls the fallback rate is computed over.
+    ///
+    /// The rate is the fraction of these calls that were served by the
+    /// fallback. It is reported by the `resilience_fallback_active_ratio`
+    /// gauge and checked against the threshold of
+    /// [`on_fallback_rate_exceeded`](Self::on_fallback_rate_exceeded).
+    ///
+    /// Default: 100
+    pub fn fallback_rate_window(mut self, calls: usize) -> Self {
+        self.rate_window = calls;
+        self
+    }
+
+    /// Registers a callback for when more than `threshold` (0.0 to 1.0) of
+    /// recent calls are served by the fallback.
+    ///
+    /// The rate is only checked once [`fallback_rate_window`](Self::fallback_rate_window)
+    /// calls have been made, and the callback fires once per crossing: the
+    /// rate has to drop back to the threshold before it fires again. The
+    /// callback receives the current rate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_fallback::FallbackLayer;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// let layer: FallbackLayer<String, String, MyError> = FallbackLayer::builder()
+    ///     .value("degraded".to_string())
+    ///     .on_fallback_rate_exceeded(0.5, |ratio| {
+    ///         eprintln!("{:.0}% of traffic is degraded", ratio * 100.0);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_fallback_rate_exceeded<F>(mut self, threshold: f64, f: F) -> Self
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.rate_threshold = Some(threshold);
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let FallbackEvent::RateExceeded { ratio, .. } = event {
+                f(*ratio);
+            }
+        }));
+        self
+    }
+
     /// Adds an event listener.
     pub fn on_event<F>(mut self, listener: F) -> Self
     where
@@ -177,6 +316,15 @@ impl<Req, Res, E> FallbackConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the fallback layer.
     ///
     /// # Panics
@@ -189,6 +337,7 @@ impl<Req, Res, E> FallbackConfigBuilder<Req, Res, E> {
             handle_predicate: self.handle_predicate,
             handle_response_predicate: self.handle_response_predicate,
             event_listeners: self.event_listeners,
+            rate: FallbackRate::new(self.rate_window, self.rate_threshold),
         };
         crate::FallbackLayer::new(config)
     }
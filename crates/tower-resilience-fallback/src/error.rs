@@ -1,6 +1,7 @@
 //! Error types for the fallback service.
 
 use std::fmt;
+use tower_resilience_core::PatternError;
 
 /// Error type for the fallback service.
 #[derive(Debug)]
@@ -75,3 +76,19 @@ impl<E: std::error::Error + 'static> std::error::Error for FallbackError<E> {
         }
     }
 }
+
+impl<E> PatternError for FallbackError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        false
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        Some(self.inner())
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        Ok(self.into_inner())
+    }
+}
@@ -5,12 +5,18 @@ use tower_resilience_core::ResilienceEvent;
 
 /// Events emitted by the fallback service.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum FallbackEvent {
     /// The inner service succeeded; no fallback was needed.
     Success {
         /// Name of the fallback instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
@@ -19,6 +25,10 @@ pub enum FallbackEvent {
         /// Name of the fallback instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
@@ -27,6 +37,10 @@ pub enum FallbackEvent {
         /// Name of the fallback instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// The strategy that was applied.
         strategy: &'static str,
@@ -37,6 +51,10 @@ pub enum FallbackEvent {
         /// Name of the fallback instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
@@ -45,8 +63,27 @@ pub enum FallbackEvent {
         /// Name of the fallback instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
+
+    /// The share of recent calls served by the fallback rose above the
+    /// configured threshold.
+    RateExceeded {
+        /// Name of the fallback instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// Fraction of calls in the window served by the fallback.
+        ratio: f64,
+    },
 }
 
 impl ResilienceEvent for FallbackEvent {
@@ -57,6 +94,7 @@ impl ResilienceEvent for FallbackEvent {
             Self::Applied { .. } => "applied",
             Self::Failed { .. } => "failed",
             Self::Skipped { .. } => "skipped",
+            Self::RateExceeded { .. } => "rate_exceeded",
         }
     }
 
@@ -66,7 +104,8 @@ impl ResilienceEvent for FallbackEvent {
             | Self::FailedAttempt { timestamp, .. }
             | Self::Applied { timestamp, .. }
             | Self::Failed { timestamp, .. }
-            | Self::Skipped { timestamp, .. } => *timestamp,
+            | Self::Skipped { timestamp, .. }
+            | Self::RateExceeded { timestamp, .. } => *timestamp,
         }
     }
 
@@ -76,7 +115,8 @@ impl ResilienceEvent for FallbackEvent {
             | Self::FailedAttempt { pattern_name, .. }
             | Self::Applied { pattern_name, .. }
             | Self::Failed { pattern_name, .. }
-            | Self::Skipped { pattern_name, .. } => pattern_name,
+            | Self::Skipped { pattern_name, .. }
+            | Self::RateExceeded { pattern_name, .. } => pattern_name,
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::config::{FallbackConfig, FallbackConfigBuilder};
 use crate::Fallback;
 use std::sync::Arc;
 use tower::layer::Layer;
+use tower_resilience_core::PatternError;
 
 /// A Tower layer that applies fallback behavior to a service.
 ///
@@ -155,6 +156,39 @@ where
     {
         FallbackConfigBuilder::new().exception(f).build()
     }
+
+    /// Creates a fallback layer that returns a static value only when a
+    /// circuit breaker below it rejects the request with an open circuit.
+    ///
+    /// Other errors are propagated as-is. To route open-circuit rejections to
+    /// a backup service instead, combine [`service`](FallbackConfigBuilder::service)
+    /// with [`handle_circuit_open`](FallbackConfigBuilder::handle_circuit_open).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_core::ResilienceError;
+    /// use tower_resilience_fallback::FallbackLayer;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// # impl std::fmt::Display for MyError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str("my error") }
+    /// # }
+    /// # impl std::error::Error for MyError {}
+    /// let layer = FallbackLayer::<String, String, ResilienceError<MyError>>::on_circuit_open(
+    ///     "temporarily unavailable".to_string(),
+    /// );
+    /// ```
+    pub fn on_circuit_open(value: Res) -> Self
+    where
+        E: PatternError + std::error::Error + 'static,
+    {
+        FallbackConfigBuilder::new()
+            .value(value)
+            .handle_circuit_open()
+            .build()
+    }
 }
 
 impl<Req, Res, E> Clone for FallbackLayer<Req, Res, E>
@@ -105,6 +105,24 @@
 //!     .build();
 //! ```
 //!
+//! Or only when a circuit breaker below the fallback has opened, even with
+//! other layers in between, passing other errors through:
+//!
+//! ```rust
+//! use tower_resilience_fallback::FallbackLayer;
+//! use tower_resilience_core::ResilienceError;
+//!
+//! # #[derive(Debug, Clone)]
+//! # struct MyError;
+//! # impl std::fmt::Display for MyError {
+//! #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str("my error") }
+//! # }
+//! # impl std::error::Error for MyError {}
+//! let layer = FallbackLayer::<String, String, ResilienceError<MyError>>::on_circuit_open(
+//!     "temporarily unavailable".to_string(),
+//! );
+//! ```
+//!
 //! # Composition with Other Layers
 //!
 //! Fallback works well with other resilience patterns:
@@ -136,11 +154,14 @@
 //! - `Applied`: Fallback was successfully applied
 //! - `Failed`: Fallback itself failed (service fallback only)
 //! - `Skipped`: Error didn't match predicate, propagated as-is
+//! - `RateExceeded`: Share of recent calls served by the fallback rose above
+//!   the [`on_fallback_rate_exceeded`](FallbackConfigBuilder::on_fallback_rate_exceeded) threshold
 
 mod config;
 mod error;
 mod events;
 mod layer;
+mod rate;
 
 pub use config::{FallbackConfig, FallbackConfigBuilder};
 pub use error::FallbackError;
@@ -154,7 +175,7 @@ use std::time::Instant;
 use tower::Service;
 
 #[cfg(feature = "metrics")]
-use metrics::{counter, describe_counter};
+use metrics::{counter, describe_counter, describe_gauge};
 
 #[cfg(feature = "metrics")]
 use std::sync::Once;
@@ -239,9 +260,17 @@ impl<S, Req, Res, E> Fallback<S, Req, Res, E> {
         #[cfg(feature = "metrics")]
         METRICS_INIT.call_once(|| {
             describe_counter!(
-                "fallback_calls_total",
+                "resilience_fallback_calls_total",
                 "Total number of fallback operations"
             );
+            describe_counter!(
+                "resilience_fallback_applied_total",
+                "Total number of responses served by the fallback"
+            );
+            describe_gauge!(
+                "resilience_fallback_active_ratio",
+                "Fraction of recent calls served by the fallback"
+            );
         });
 
         Self { inner, config }
@@ -283,7 +312,10 @@ where
         let config = Arc::clone(&self.config);
         let req_clone = req.clone();
 
-        Box::pin(async move {
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("fallback", &config.name);
+
+        let future = async move {
             #[cfg(feature = "tracing")]
             tracing::debug!(fallback = %config.name, "Calling inner service");
 
@@ -310,15 +342,15 @@ where
                             pattern_name: config.name.clone(),
                             timestamp: Instant::now(),
                         };
-                        config.event_listeners.emit(&event);
+                        config.emit(&event);
 
                         // Apply fallback strategy (only strategies that don't need an error)
                         match &config.strategy {
                             FallbackStrategy::Value(v) => {
                                 #[cfg(feature = "metrics")]
                                 counter!(
-                                    "fallback_calls_total",
-                                    "fallback" => config.name.clone(),
+                                    "resilience_fallback_calls_total",
+                                    "name" => config.name.clone(),
                                     "result" => "applied",
                                     "strategy" => "value"
                                 )
@@ -329,7 +361,7 @@ where
                                     timestamp: Instant::now(),
                                     strategy: "value",
                                 };
-                                config.event_listeners.emit(&event);
+                                config.emit(&event);
 
                                 return Ok(v.clone());
                             }
@@ -339,8 +371,8 @@ where
 
                                 #[cfg(feature = "metrics")]
                                 counter!(
-                                    "fallback_calls_total",
-                                    "fallback" => config.name.clone(),
+                                    "resilience_fallback_calls_total",
+                                    "name" => config.name.clone(),
                                     "result" => "applied",
                                     "strategy" => "value_fn"
                                 )
@@ -351,7 +383,7 @@ where
                                     timestamp: Instant::now(),
                                     strategy: "value_fn",
                                 };
-                                config.event_listeners.emit(&event);
+                                config.emit(&event);
 
                                 return Ok(fallback_response);
                             }
@@ -364,8 +396,8 @@ where
                                     Ok(backup_response) => {
                                         #[cfg(feature = "metrics")]
                                         counter!(
-                                            "fallback_calls_total",
-                                            "fallback" => config.name.clone(),
+                                            "resilience_fallback_calls_total",
+                                            "name" => config.name.clone(),
                                             "result" => "applied",
                                             "strategy" => "service"
                                         )
@@ -376,7 +408,7 @@ where
                                             timestamp: Instant::now(),
                                             strategy: "service",
                                         };
-                                        config.event_listeners.emit(&event);
+                                        config.emit(&event);
 
                                         return Ok(backup_response);
                                     }
@@ -389,8 +421,8 @@ where
 
                                         #[cfg(feature = "metrics")]
                                         counter!(
-                                            "fallback_calls_total",
-                                            "fallback" => config.name.clone(),
+                                            "resilience_fallback_calls_total",
+                                            "name" => config.name.clone(),
                                             "result" => "failed",
                                             "strategy" => "service"
                                         )
@@ -400,7 +432,7 @@ where
                                             pattern_name: config.name.clone(),
                                             timestamp: Instant::now(),
                                         };
-                                        config.event_listeners.emit(&event);
+                                        config.emit(&event);
 
                                         return Err(FallbackError::FallbackFailed(backup_error));
                                     }
@@ -420,8 +452,8 @@ where
 
                     #[cfg(feature = "metrics")]
                     counter!(
-                        "fallback_calls_total",
-                        "fallback" => config.name.clone(),
+                        "resilience_fallback_calls_total",
+                        "name" => config.name.clone(),
                         "result" => "success"
                     )
                     .increment(1);
@@ -430,7 +462,7 @@ where
                         pattern_name: config.name.clone(),
                         timestamp: Instant::now(),
                     };
-                    config.event_listeners.emit(&event);
+                    config.emit(&event);
 
                     Ok(response)
                 }
@@ -451,8 +483,8 @@ where
 
                         #[cfg(feature = "metrics")]
                         counter!(
-                            "fallback_calls_total",
-                            "fallback" => config.name.clone(),
+                            "resilience_fallback_calls_total",
+                            "name" => config.name.clone(),
                             "result" => "skipped"
                         )
                         .increment(1);
@@ -461,7 +493,7 @@ where
                             pattern_name: config.name.clone(),
                             timestamp: Instant::now(),
                         };
-                        config.event_listeners.emit(&event);
+                        config.emit(&event);
 
                         return Err(FallbackError::Inner(error));
                     }
@@ -474,15 +506,15 @@ where
                         pattern_name: config.name.clone(),
                         timestamp: Instant::now(),
                     };
-                    config.event_listeners.emit(&event);
+                    config.emit(&event);
 
                     // Apply fallback strategy
                     match &config.strategy {
                         FallbackStrategy::Value(v) => {
                             #[cfg(feature = "metrics")]
                             counter!(
-                                "fallback_calls_total",
-                                "fallback" => config.name.clone(),
+                                "resilience_fallback_calls_total",
+                                "name" => config.name.clone(),
                                 "result" => "applied",
                                 "strategy" => "value"
                             )
@@ -493,7 +525,7 @@ where
                                 timestamp: Instant::now(),
                                 strategy: "value",
                             };
-                            config.event_listeners.emit(&event);
+                            config.emit(&event);
 
                             Ok(v.clone())
                         }
@@ -503,8 +535,8 @@ where
 
                             #[cfg(feature = "metrics")]
                             counter!(
-                                "fallback_calls_total",
-                                "fallback" => config.name.clone(),
+                                "resilience_fallback_calls_total",
+                                "name" => config.name.clone(),
                                 "result" => "applied",
                                 "strategy" => "value_fn"
                             )
@@ -515,7 +547,7 @@ where
                                 timestamp: Instant::now(),
                                 strategy: "value_fn",
                             };
-                            config.event_listeners.emit(&event);
+                            config.emit(&event);
 
                             Ok(response)
                         }
@@ -525,8 +557,8 @@ where
 
                             #[cfg(feature = "metrics")]
                             counter!(
-                                "fallback_calls_total",
-                                "fallback" => config.name.clone(),
+                                "resilience_fallback_calls_total",
+                                "name" => config.name.clone(),
                                 "result" => "applied",
                                 "strategy" => "from_error"
                             )
@@ -537,7 +569,7 @@ where
                                 timestamp: Instant::now(),
                                 strategy: "from_error",
                             };
-                            config.event_listeners.emit(&event);
+                            config.emit(&event);
 
                             Ok(response)
                         }
@@ -547,8 +579,8 @@ where
 
                             #[cfg(feature = "metrics")]
                             counter!(
-                                "fallback_calls_total",
-                                "fallback" => config.name.clone(),
+                                "resilience_fallback_calls_total",
+                                "name" => config.name.clone(),
                                 "result" => "applied",
                                 "strategy" => "from_request_error"
                             )
@@ -559,7 +591,7 @@ where
                                 timestamp: Instant::now(),
                                 strategy: "from_request_error",
                             };
-                            config.event_listeners.emit(&event);
+                            config.emit(&event);
 
                             Ok(response)
                         }
@@ -572,8 +604,8 @@ where
                                 Ok(response) => {
                                     #[cfg(feature = "metrics")]
                                     counter!(
-                                        "fallback_calls_total",
-                                        "fallback" => config.name.clone(),
+                                        "resilience_fallback_calls_total",
+                                        "name" => config.name.clone(),
                                         "result" => "applied",
                                         "strategy" => "service"
                                     )
@@ -584,7 +616,7 @@ where
                                         timestamp: Instant::now(),
                                         strategy: "service",
                                     };
-                                    config.event_listeners.emit(&event);
+                                    config.emit(&event);
 
                                     Ok(response)
                                 }
@@ -597,8 +629,8 @@ where
 
                                     #[cfg(feature = "metrics")]
                                     counter!(
-                                        "fallback_calls_total",
-                                        "fallback" => config.name.clone(),
+                                        "resilience_fallback_calls_total",
+                                        "name" => config.name.clone(),
                                         "result" => "failed",
                                         "strategy" => "service"
                                     )
@@ -608,7 +640,7 @@ where
                                         pattern_name: config.name.clone(),
                                         timestamp: Instant::now(),
                                     };
-                                    config.event_listeners.emit(&event);
+                                    config.emit(&event);
 
                                     Err(FallbackError::FallbackFailed(backup_error))
                                 }
@@ -620,8 +652,8 @@ where
 
                             #[cfg(feature = "metrics")]
                             counter!(
-                                "fallback_calls_total",
-                                "fallback" => config.name.clone(),
+                                "resilience_fallback_calls_total",
+                                "name" => config.name.clone(),
                                 "result" => "transformed",
                                 "strategy" => "exception"
                             )
@@ -632,14 +664,23 @@ where
                                 timestamp: Instant::now(),
                                 strategy: "exception",
                             };
-                            config.event_listeners.emit(&event);
+                            config.emit(&event);
 
                             Err(FallbackError::Inner(transformed))
                         }
                     }
                 }
             }
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_result,
+        );
+
+        Box::pin(future)
     }
 }
 
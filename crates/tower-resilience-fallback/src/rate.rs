@@ -0,0 +1,102 @@
+//! Rolling fallback rate tracking.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Tracks the fraction of the last `size` calls served by the fallback.
+pub(crate) struct FallbackRate {
+    window: Mutex<Window>,
+    threshold: Option<f64>,
+}
+
+struct Window {
+    outcomes: VecDeque<bool>,
+    size: usize,
+    applied: usize,
+    exceeded: bool,
+}
+
+/// Result of recording a call outcome.
+pub(crate) struct RateSample {
+    /// Fraction of calls in the window served by the fallback.
+    pub(crate) ratio: f64,
+    /// Whether this call pushed the ratio over the threshold.
+    pub(crate) exceeded: bool,
+}
+
+impl FallbackRate {
+    /// Creates a tracker over the last `size` calls, reporting when the
+    /// ratio rises above `threshold`.
+    pub(crate) fn new(size: usize, threshold: Option<f64>) -> Self {
+        let size = size.max(1);
+        Self {
+            window: Mutex::new(Window {
+                outcomes: VecDeque::with_capacity(size),
+                size,
+                applied: 0,
+                exceeded: false,
+            }),
+            threshold,
+        }
+    }
+
+    /// Records whether a call was served by the fallback.
+    ///
+    /// The threshold is only checked once the window is full, and is reported
+    /// once per crossing: the ratio has to drop back to the threshold before
+    /// it is reported again.
+    pub(crate) fn record(&self, applied: bool) -> RateSample {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+
+        if window.outcomes.len() == window.size && window.outcomes.pop_front() == Some(true) {
+            window.applied -= 1;
+        }
+        window.outcomes.push_back(applied);
+        if applied {
+            window.applied += 1;
+        }
+
+        let ratio = window.applied as f64 / window.outcomes.len() as f64;
+        let mut exceeded = false;
+        if let Some(threshold) = self.threshold {
+            if ratio <= threshold {
+                window.exceeded = false;
+            } else if window.outcomes.len() == window.size && !window.exceeded {
+                window.exceeded = true;
+                exceeded = true;
+            }
+        }
+
+        RateSample { ratio, exceeded }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_covers_only_the_window() {
+        let rate = FallbackRate::new(4, None);
+        for applied in [true, true, false, false] {
+            rate.record(applied);
+        }
+        assert_eq!(rate.record(false).ratio, 0.25);
+        assert_eq!(rate.record(false).ratio, 0.0);
+    }
+
+    #[test]
+    fn threshold_reported_once_per_crossing() {
+        let rate = FallbackRate::new(2, Some(0.5));
+
+        // Not reported until the window is full
+        assert!(!rate.record(true).exceeded);
+        assert!(rate.record(true).exceeded);
+        assert!(!rate.record(true).exceeded);
+
+        // Re-armed once the ratio drops back to the threshold
+        assert!(!rate.record(false).exceeded);
+        assert!(!rate.record(true).exceeded);
+        assert!(rate.record(true).exceeded);
+    }
+}
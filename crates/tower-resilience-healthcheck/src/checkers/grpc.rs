@@ -0,0 +1,128 @@
+//! gRPC health checking protocol (`grpc.health.v1.Health/Check`).
+
+use crate::{HealthChecker, HealthStatus};
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::Endpoint;
+use tonic_prost::ProstCodec;
+
+const CHECK_PATH: &str = "/grpc.health.v1.Health/Check";
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct HealthCheckRequest {
+    #[prost(string, tag = "1")]
+    service: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct HealthCheckResponse {
+    #[prost(int32, tag = "1")]
+    status: i32,
+}
+
+/// Checks a resource with the standard [gRPC health checking protocol].
+///
+/// The resource is the server URI, e.g. `http://10.0.0.1:50051`. A
+/// `SERVING` response is healthy; `NOT_SERVING`, `SERVICE_UNKNOWN` and any
+/// RPC or connection error are unhealthy, and `UNKNOWN` leaves the current
+/// status unchanged.
+///
+/// [gRPC health checking protocol]: https://github.com/grpc/grpc/blob/master/doc/health-checking.md
+///
+/// # Examples
+///
+/// ```rust
+/// use tower_resilience_healthcheck::{GrpcChecker, HealthCheckWrapper};
+///
+/// let wrapper = HealthCheckWrapper::builder()
+///     .with_context("http://10.0.0.1:50051".to_string(), "greeter-1")
+///     .with_context("http://10.0.0.2:50051".to_string(), "greeter-2")
+///     .with_checker(GrpcChecker::new().service("helloworld.Greeter"))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GrpcChecker {
+    service: String,
+}
+
+impl GrpcChecker {
+    /// Create a checker for the server's overall health (the empty service name).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check a specific service instead of the whole server.
+    pub fn service(mut self, service: impl Into<String>) -> Self {
+        self.service = service.into();
+        self
+    }
+
+    async fn query(&self, uri: &str) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        let channel = Endpoint::from_shared(uri.to_string())?.connect().await?;
+        let mut client = tonic::client::Grpc::new(channel);
+        client.ready().await?;
+
+        let request = tonic::Request::new(HealthCheckRequest {
+            service: self.service.clone(),
+        });
+        let response: tonic::Response<HealthCheckResponse> = client
+            .unary(
+                request,
+                PathAndQuery::from_static(CHECK_PATH),
+                ProstCodec::default(),
+            )
+            .await?;
+        Ok(response.into_inner().status)
+    }
+}
+
+/// Maps a `HealthCheckResponse.ServingStatus` value to a [`HealthStatus`].
+fn serving_status(status: i32) -> HealthStatus {
+    match status {
+        // UNKNOWN
+        0 => HealthStatus::Unknown,
+        // SERVING
+        1 => HealthStatus::Healthy,
+        // NOT_SERVING, SERVICE_UNKNOWN
+        _ => HealthStatus::Unhealthy,
+    }
+}
+
+impl<T> HealthChecker<T> for GrpcChecker
+where
+    T: AsRef<str> + Sync,
+{
+    async fn check(&self, resource: &T) -> HealthStatus {
+        match self.query(resource.as_ref()).await {
+            Ok(status) => serving_status(status),
+            Err(_) => HealthStatus::Unhealthy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serving_status_mapping() {
+        assert_eq!(serving_status(0), HealthStatus::Unknown);
+        assert_eq!(serving_status(1), HealthStatus::Healthy);
+        assert_eq!(serving_status(2), HealthStatus::Unhealthy);
+        assert_eq!(serving_status(3), HealthStatus::Unhealthy);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_server_is_unhealthy() {
+        let checker = GrpcChecker::new();
+        assert_eq!(
+            checker.check(&"http://127.0.0.1:1").await,
+            HealthStatus::Unhealthy
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_uri_is_unhealthy() {
+        let checker = GrpcChecker::new().service("svc");
+        assert_eq!(checker.check(&"not a uri").await, HealthStatus::Unhealthy);
+    }
+}
@@ -0,0 +1,147 @@
+//! HTTP GET health checks.
+
+use crate::{HealthChecker, HealthStatus};
+
+/// Checks a resource by sending an HTTP GET and inspecting the status code.
+///
+/// The resource is a base URL; the configured [`path`](Self::path) is
+/// appended to it. By default any `2xx` response is healthy; with
+/// [`expected_status`](Self::expected_status) only that exact code is.
+/// Everything else, including connection errors, is unhealthy.
+///
+/// # Examples
+///
+/// ```rust
+/// use tower_resilience_healthcheck::{HealthCheckWrapper, HttpChecker};
+///
+/// let wrapper = HealthCheckWrapper::builder()
+///     .with_context("http://10.0.0.1:8080".to_string(), "api-1")
+///     .with_context("http://10.0.0.2:8080".to_string(), "api-2")
+///     .with_checker(HttpChecker::new().path("/healthz").expected_status(204))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HttpChecker {
+    client: reqwest::Client,
+    path: String,
+    expected_status: Option<u16>,
+}
+
+impl HttpChecker {
+    /// Create a checker that GETs the resource URL itself.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the path appended to each resource's base URL, e.g. `/health`.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Require an exact status code instead of any `2xx`.
+    pub fn expected_status(mut self, status: u16) -> Self {
+        self.expected_status = Some(status);
+        self
+    }
+
+    /// Use a preconfigured client (TLS settings, default headers, etc.).
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    fn url(&self, base: &str) -> String {
+        if self.path.is_empty() {
+            return base.to_string();
+        }
+        format!(
+            "{}/{}",
+            base.trim_end_matches('/'),
+            self.path.trim_start_matches('/')
+        )
+    }
+}
+
+impl<T> HealthChecker<T> for HttpChecker
+where
+    T: AsRef<str> + Sync,
+{
+    async fn check(&self, resource: &T) -> HealthStatus {
+        let status = match self.client.get(self.url(resource.as_ref())).send().await {
+            Ok(response) => response.status(),
+            Err(_) => return HealthStatus::Unhealthy,
+        };
+
+        let healthy = match self.expected_status {
+            Some(expected) => status.as_u16() == expected,
+            None => status.is_success(),
+        };
+
+        if healthy {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_url_joining() {
+        let checker = HttpChecker::new();
+        assert_eq!(checker.url("http://host"), "http://host");
+
+        let checker = HttpChecker::new().path("/health");
+        assert_eq!(checker.url("http://host/"), "http://host/health");
+        assert_eq!(checker.url("http://host"), "http://host/health");
+    }
+
+    #[tokio::test]
+    async fn test_status_codes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/broken"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let base = server.uri();
+        let check = |checker: HttpChecker| {
+            let base = base.clone();
+            async move { checker.check(&base).await }
+        };
+
+        assert_eq!(
+            check(HttpChecker::new().path("/health")).await,
+            HealthStatus::Healthy
+        );
+        assert_eq!(
+            check(HttpChecker::new().path("/health").expected_status(200)).await,
+            HealthStatus::Unhealthy
+        );
+        assert_eq!(
+            check(HttpChecker::new().path("/broken")).await,
+            HealthStatus::Unhealthy
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_is_unhealthy() {
+        let checker = HttpChecker::new();
+        assert_eq!(
+            checker.check(&"http://127.0.0.1:1").await,
+            HealthStatus::Unhealthy
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Built-in [`HealthChecker`](crate::HealthChecker) implementations for
+//! common endpoint types.
+//!
+//! Each checker treats the monitored resource as an address (anything that
+//! implements `AsRef<str>`, such as a `String` URL), so a pool of endpoints can
+//! be checked without writing a checker by hand.
+
+#[cfg(feature = "grpc")]
+mod grpc;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "tcp")]
+mod tcp;
+
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcChecker;
+#[cfg(feature = "http")]
+pub use http::HttpChecker;
+#[cfg(feature = "tcp")]
+pub use tcp::TcpChecker;
@@ -0,0 +1,69 @@
+//! TCP connect health checks.
+
+use crate::{HealthChecker, HealthStatus};
+use tokio::net::TcpStream;
+
+/// Checks that a TCP connection can be established to the resource's address.
+///
+/// The resource is a `host:port` address. The check succeeds as soon as the
+/// connection is accepted and the socket is closed right away; use the
+/// wrapper's timeout to bound how long a hanging connect may take.
+///
+/// # Examples
+///
+/// ```rust
+/// use tower_resilience_healthcheck::{HealthCheckWrapper, TcpChecker};
+///
+/// let wrapper = HealthCheckWrapper::builder()
+///     .with_context("10.0.0.1:6379".to_string(), "redis-primary")
+///     .with_context("10.0.0.2:6379".to_string(), "redis-replica")
+///     .with_checker(TcpChecker::new())
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpChecker;
+
+impl TcpChecker {
+    /// Create a new TCP connect checker.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T> HealthChecker<T> for TcpChecker
+where
+    T: AsRef<str> + Sync,
+{
+    async fn check(&self, resource: &T) -> HealthStatus {
+        match TcpStream::connect(resource.as_ref()).await {
+            Ok(_) => HealthStatus::Healthy,
+            Err(_) => HealthStatus::Unhealthy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_listening_port_is_healthy() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        assert_eq!(TcpChecker::new().check(&addr).await, HealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_closed_port_is_unhealthy() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        drop(listener);
+
+        assert_eq!(
+            TcpChecker::new().check(&addr).await,
+            HealthStatus::Unhealthy
+        );
+    }
+}
@@ -1,10 +1,9 @@
 //! Configuration for health checking behavior.
 
-use crate::SelectionStrategy;
-use std::time::Duration;
+use crate::{HealthEvent, HealthStatus, SelectionStrategy};
+use std::time::{Duration, Instant};
+use tower_resilience_core::{EventListeners, FnListener};
 
-#[cfg(feature = "tracing")]
-use crate::HealthStatus;
 #[cfg(feature = "tracing")]
 use std::sync::Arc;
 
@@ -22,6 +21,9 @@ type CheckFailedCallback = Arc<dyn Fn(&str, &dyn std::error::Error) + Send + Syn
 /// Configuration for health checking behavior.
 #[derive(Clone)]
 pub struct HealthCheckConfig {
+    /// Name of this health check instance, used in events
+    pub(crate) name: String,
+
     /// Interval between health checks
     pub(crate) interval: Duration,
 
@@ -40,6 +42,9 @@ pub struct HealthCheckConfig {
     /// Selection strategy for choosing healthy resources
     pub(crate) selection_strategy: SelectionStrategy,
 
+    /// Listeners for health events
+    pub(crate) event_listeners: EventListeners<HealthEvent>,
+
     /// Event callbacks (behind tracing feature)
     #[cfg(feature = "tracing")]
     pub(crate) on_health_change: Option<HealthChangeCallback>,
@@ -55,12 +60,14 @@ pub struct HealthCheckConfig {
 impl Default for HealthCheckConfig {
     fn default() -> Self {
         Self {
+            name: String::from("<unnamed>"),
             interval: Duration::from_secs(5),
             initial_delay: Duration::from_millis(500),
             timeout: Duration::from_secs(2),
             success_threshold: 1,
             failure_threshold: 2,
             selection_strategy: SelectionStrategy::default(),
+            event_listeners: EventListeners::new(),
             #[cfg(feature = "tracing")]
             on_health_change: None,
             #[cfg(feature = "tracing")]
@@ -77,6 +84,11 @@ impl HealthCheckConfig {
         HealthCheckConfigBuilder::default()
     }
 
+    /// Get the name of this health check instance.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     /// Get the health check interval.
     pub fn interval(&self) -> Duration {
         self.interval
@@ -101,16 +113,68 @@ impl HealthCheckConfig {
     pub fn failure_threshold(&self) -> u32 {
         self.failure_threshold
     }
+
+    pub(crate) fn record_status_change(
+        &self,
+        resource: &str,
+        from: HealthStatus,
+        to: HealthStatus,
+    ) {
+        self.event_listeners.emit(&HealthEvent::StatusChanged {
+            pattern_name: self.name.clone(),
+            timestamp: Instant::now(),
+            resource: resource.to_string(),
+            from,
+            to,
+        });
+    }
+
+    pub(crate) fn record_check(
+        &self,
+        resource: &str,
+        status: HealthStatus,
+        duration: Duration,
+        timed_out: bool,
+    ) {
+        let event = match status {
+            HealthStatus::Healthy | HealthStatus::Degraded => HealthEvent::CheckSucceeded {
+                pattern_name: self.name.clone(),
+                timestamp: Instant::now(),
+                resource: resource.to_string(),
+                status,
+                duration,
+            },
+            HealthStatus::Unhealthy => HealthEvent::CheckFailed {
+                pattern_name: self.name.clone(),
+                timestamp: Instant::now(),
+                resource: resource.to_string(),
+                duration,
+                timed_out,
+            },
+            HealthStatus::Unknown => return,
+        };
+        self.event_listeners.emit(&event);
+    }
+
+    pub(crate) fn record_all_unhealthy(&self, resources: usize) {
+        self.event_listeners.emit(&HealthEvent::AllUnhealthy {
+            pattern_name: self.name.clone(),
+            timestamp: Instant::now(),
+            resources,
+        });
+    }
 }
 
 /// Builder for `HealthCheckConfig`.
 pub struct HealthCheckConfigBuilder {
+    name: Option<String>,
     interval: Option<Duration>,
     initial_delay: Option<Duration>,
     timeout: Option<Duration>,
     success_threshold: Option<u32>,
     failure_threshold: Option<u32>,
     selection_strategy: Option<SelectionStrategy>,
+    event_listeners: EventListeners<HealthEvent>,
     #[cfg(feature = "tracing")]
     on_health_change: Option<HealthChangeCallback>,
     #[cfg(feature = "tracing")]
@@ -123,12 +187,14 @@ pub struct HealthCheckConfigBuilder {
 impl Default for HealthCheckConfigBuilder {
     fn default() -> Self {
         Self {
+            name: None,
             interval: None,
             initial_delay: None,
             timeout: None,
             success_threshold: None,
             failure_threshold: None,
             selection_strategy: None,
+            event_listeners: EventListeners::new(),
             #[cfg(feature = "tracing")]
             on_health_change: None,
             #[cfg(feature = "tracing")]
@@ -140,6 +206,14 @@ impl Default for HealthCheckConfigBuilder {
 }
 
 impl HealthCheckConfigBuilder {
+    /// Set the name of this health check instance, used in events.
+    ///
+    /// Default: `"<unnamed>"`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// Set the interval between health checks.
     ///
     /// Default: 5 seconds
@@ -188,6 +262,49 @@ impl HealthCheckConfigBuilder {
         self
     }
 
+    /// Adds a listener for every [`HealthEvent`].
+    pub fn on_event<F>(mut self, listener: F) -> Self
+    where
+        F: Fn(&HealthEvent) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(listener));
+        self
+    }
+
+    /// Register a callback for when a resource's status changes.
+    ///
+    /// The callback receives: (resource_name, old_status, new_status)
+    pub fn on_status_changed<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, HealthStatus, HealthStatus) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let HealthEvent::StatusChanged {
+                resource, from, to, ..
+            } = event
+            {
+                f(resource, *from, *to);
+            }
+        }));
+        self
+    }
+
+    /// Register a callback for when no resource in the pool is usable.
+    ///
+    /// Fires once each time the pool goes from having a usable resource to
+    /// having none.
+    pub fn on_all_unhealthy<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, HealthEvent::AllUnhealthy { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
     /// Callback when health status changes.
     ///
     /// The callback receives: (resource_name, old_status, new_status)
@@ -236,10 +353,20 @@ impl HealthCheckConfigBuilder {
         self
     }
 
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> HealthCheckConfig {
         let default = HealthCheckConfig::default();
         HealthCheckConfig {
+            name: self.name.unwrap_or(default.name),
             interval: self.interval.unwrap_or(default.interval),
             initial_delay: self.initial_delay.unwrap_or(default.initial_delay),
             timeout: self.timeout.unwrap_or(default.timeout),
@@ -248,6 +375,7 @@ impl HealthCheckConfigBuilder {
             selection_strategy: self
                 .selection_strategy
                 .unwrap_or(default.selection_strategy),
+            event_listeners: self.event_listeners,
             #[cfg(feature = "tracing")]
             on_health_change: self.on_health_change,
             #[cfg(feature = "tracing")]
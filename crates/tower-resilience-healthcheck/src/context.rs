@@ -3,7 +3,12 @@
 use crate::HealthStatus;
 use std::any::Any;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Weight given to the newest latency sample in the moving average.
+const LATENCY_EWMA_ALPHA: f64 = 0.3;
 
 /// A resource with health tracking and custom metrics.
 #[derive(Debug)]
@@ -17,10 +22,40 @@ pub struct HealthCheckedContext<T> {
     /// Health check state (protected by RwLock for concurrent access)
     state: Arc<RwLock<ContextState>>,
 
+    /// Relative share of traffic under weighted selection
+    weight: u32,
+
+    /// In-flight requests and latency, updated on every request
+    load: Arc<Load>,
+
     /// Extension storage for custom metrics
     extensions: Arc<RwLock<HashMap<String, Box<dyn Any + Send + Sync>>>>,
 }
 
+#[derive(Debug, Default)]
+struct Load {
+    outstanding: AtomicUsize,
+    /// Exponentially weighted moving average in nanoseconds; 0 means no samples yet
+    latency_ewma_nanos: AtomicU64,
+}
+
+impl Load {
+    fn record_latency(&self, latency: Duration) {
+        let sample = (latency.as_nanos() as u64).max(1);
+        let _ =
+            self.latency_ewma_nanos
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                    Some(if current == 0 {
+                        sample
+                    } else {
+                        let ewma = LATENCY_EWMA_ALPHA * sample as f64
+                            + (1.0 - LATENCY_EWMA_ALPHA) * current as f64;
+                        (ewma as u64).max(1)
+                    })
+                });
+    }
+}
+
 #[derive(Debug)]
 struct ContextState {
     status: HealthStatus,
@@ -41,10 +76,54 @@ impl<T> HealthCheckedContext<T> {
                 consecutive_failures: 0,
                 consecutive_successes: 0,
             })),
+            weight: 1,
+            load: Arc::new(Load::default()),
             extensions: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// Set the weight used by [`SelectionStrategy::WeightedRoundRobin`](crate::SelectionStrategy::WeightedRoundRobin).
+    ///
+    /// Default: 1
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Get the selection weight.
+    pub fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    /// Get the number of requests currently in flight to this resource.
+    pub fn outstanding(&self) -> usize {
+        self.load.outstanding.load(Ordering::Relaxed)
+    }
+
+    /// Get the moving average of observed probe and request latency.
+    ///
+    /// Returns `None` until a latency has been recorded.
+    pub fn latency(&self) -> Option<Duration> {
+        match self.load.latency_ewma_nanos.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(Duration::from_nanos(nanos)),
+        }
+    }
+
+    /// Fold a latency sample into the moving average.
+    pub(crate) fn record_latency(&self, latency: Duration) {
+        self.load.record_latency(latency);
+    }
+
+    /// Mark a request as started; the returned guard marks it finished when dropped.
+    pub(crate) fn begin_request(&self) -> InFlight {
+        self.load.outstanding.fetch_add(1, Ordering::Relaxed);
+        InFlight {
+            load: Arc::clone(&self.load),
+            started: Instant::now(),
+        }
+    }
+
     /// Get the current health status.
     pub fn status(&self) -> HealthStatus {
         self.state.read().unwrap().status
@@ -134,13 +213,36 @@ impl<T: Clone> Clone for HealthCheckedContext<T> {
             context: self.context.clone(),
             name: self.name.clone(),
             state: Arc::clone(&self.state),
+            weight: self.weight,
+            load: Arc::clone(&self.load),
             extensions: Arc::clone(&self.extensions),
         }
     }
 }
 
+/// Tracks one in-flight request against a resource.
+#[derive(Debug)]
+pub(crate) struct InFlight {
+    load: Arc<Load>,
+    started: Instant,
+}
+
+impl InFlight {
+    /// Record the time since the request started as a latency sample.
+    pub(crate) fn record_latency(&self) {
+        self.load.record_latency(self.started.elapsed());
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.load.outstanding.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 /// Detailed health information for a resource.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HealthDetail {
     /// Name of the resource
     pub name: String,
@@ -235,4 +337,28 @@ mod tests {
         ctx.set_status(HealthStatus::Degraded);
         assert_eq!(cloned.status(), HealthStatus::Degraded);
     }
+
+    #[test]
+    fn test_outstanding_tracking() {
+        let ctx = HealthCheckedContext::new("resource", "test");
+        let first = ctx.begin_request();
+        let second = ctx.clone().begin_request();
+        assert_eq!(ctx.outstanding(), 2);
+
+        drop(first);
+        drop(second);
+        assert_eq!(ctx.outstanding(), 0);
+    }
+
+    #[test]
+    fn test_latency_ewma() {
+        let ctx = HealthCheckedContext::new("resource", "test");
+        assert_eq!(ctx.latency(), None);
+
+        ctx.record_latency(Duration::from_millis(100));
+        assert_eq!(ctx.latency(), Some(Duration::from_millis(100)));
+
+        ctx.record_latency(Duration::from_millis(200));
+        assert_eq!(ctx.latency(), Some(Duration::from_millis(130)));
+    }
 }
@@ -0,0 +1,73 @@
+//! Error types for health-routed services.
+
+use std::fmt;
+use tower_resilience_core::PatternError;
+
+/// Errors returned by [`HealthRoutedService`](crate::HealthRoutedService).
+#[derive(Debug)]
+pub enum HealthCheckError<E> {
+    /// No resource in the pool is currently eligible to receive the request.
+    NoHealthyResource,
+    /// An error from the selected resource.
+    Inner(E),
+}
+
+impl<E> HealthCheckError<E> {
+    /// Returns `true` if the request was rejected because no resource was available.
+    pub fn is_no_healthy_resource(&self) -> bool {
+        matches!(self, HealthCheckError::NoHealthyResource)
+    }
+
+    /// Returns `true` if this is an error from the selected resource.
+    pub fn is_inner(&self) -> bool {
+        matches!(self, HealthCheckError::Inner(_))
+    }
+
+    /// Consumes self and returns the inner error, if present.
+    pub fn into_inner(self) -> Option<E> {
+        match self {
+            HealthCheckError::Inner(e) => Some(e),
+            HealthCheckError::NoHealthyResource => None,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for HealthCheckError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthCheckError::NoHealthyResource => write!(f, "no healthy resource available"),
+            HealthCheckError::Inner(e) => write!(f, "inner service error: {}", e),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for HealthCheckError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HealthCheckError::NoHealthyResource => None,
+            HealthCheckError::Inner(e) => Some(e),
+        }
+    }
+}
+
+impl<E> PatternError for HealthCheckError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        self.is_no_healthy_resource()
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            HealthCheckError::NoHealthyResource => None,
+            HealthCheckError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            HealthCheckError::Inner(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Event types for the health check pattern.
+
+use crate::HealthStatus;
+use std::time::{Duration, Instant};
+use tower_resilience_core::ResilienceEvent;
+
+/// Events emitted while monitoring a pool of resources.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum HealthEvent {
+    /// A resource's health status changed.
+    StatusChanged {
+        /// The name of the health check instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The name of the resource.
+        resource: String,
+        /// The previous status.
+        from: HealthStatus,
+        /// The new status.
+        to: HealthStatus,
+    },
+    /// A probe reported the resource as healthy or degraded.
+    CheckSucceeded {
+        /// The name of the health check instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The name of the resource.
+        resource: String,
+        /// The status reported by the checker.
+        status: HealthStatus,
+        /// How long the check took.
+        duration: Duration,
+    },
+    /// A probe reported the resource as unhealthy or timed out.
+    CheckFailed {
+        /// The name of the health check instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The name of the resource.
+        resource: String,
+        /// How long the check took.
+        duration: Duration,
+        /// Whether the check was abandoned after the configured timeout.
+        timed_out: bool,
+    },
+    /// No resource in the pool is usable any more.
+    AllUnhealthy {
+        /// The name of the health check instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The number of resources in the pool.
+        resources: usize,
+    },
+}
+
+impl ResilienceEvent for HealthEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            HealthEvent::StatusChanged { .. } => "health_status_changed",
+            HealthEvent::CheckSucceeded { .. } => "health_check_succeeded",
+            HealthEvent::CheckFailed { .. } => "health_check_failed",
+            HealthEvent::AllUnhealthy { .. } => "health_all_unhealthy",
+        }
+    }
+
+    fn timestamp(&self) -> Instant {
+        match self {
+            HealthEvent::StatusChanged { timestamp, .. }
+            | HealthEvent::CheckSucceeded { timestamp, .. }
+            | HealthEvent::CheckFailed { timestamp, .. }
+            | HealthEvent::AllUnhealthy { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn pattern_name(&self) -> &str {
+        match self {
+            HealthEvent::StatusChanged { pattern_name, .. }
+            | HealthEvent::CheckSucceeded { pattern_name, .. }
+            | HealthEvent::CheckFailed { pattern_name, .. }
+            | HealthEvent::AllUnhealthy { pattern_name, .. } => pattern_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_types() {
+        let now = Instant::now();
+
+        let changed = HealthEvent::StatusChanged {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            resource: "db".to_string(),
+            from: HealthStatus::Healthy,
+            to: HealthStatus::Unhealthy,
+        };
+        assert_eq!(changed.event_type(), "health_status_changed");
+        assert_eq!(changed.pattern_name(), "test");
+
+        let succeeded = HealthEvent::CheckSucceeded {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            resource: "db".to_string(),
+            status: HealthStatus::Healthy,
+            duration: Duration::from_millis(3),
+        };
+        assert_eq!(succeeded.event_type(), "health_check_succeeded");
+
+        let failed = HealthEvent::CheckFailed {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            resource: "db".to_string(),
+            duration: Duration::from_secs(2),
+            timed_out: true,
+        };
+        assert_eq!(failed.event_type(), "health_check_failed");
+
+        let all = HealthEvent::AllUnhealthy {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            resources: 2,
+        };
+        assert_eq!(all.event_type(), "health_all_unhealthy");
+        assert_eq!(all.pattern_name(), "test");
+        assert_eq!(all.timestamp(), now);
+    }
+}
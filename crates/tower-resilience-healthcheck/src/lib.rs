@@ -46,23 +46,147 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Built-in Checkers
+//!
+//! Checkers for standard endpoints are available behind feature flags. Each
+//! treats the resource as an address (`AsRef<str>`):
+//!
+//! | Feature | Checker | Resource | Healthy when |
+//! |---------|---------|----------|--------------|
+//! | `http` | `HttpChecker` | base URL | GET returns `2xx` (or the expected status) |
+//! | `tcp` | `TcpChecker` | `host:port` | a TCP connection is accepted |
+//! | `grpc` | `GrpcChecker` | server URI | `grpc.health.v1.Health/Check` reports `SERVING` |
+//!
+//! ```rust,ignore
+//! use tower_resilience_healthcheck::{HealthCheckWrapper, HttpChecker};
+//!
+//! let wrapper = HealthCheckWrapper::builder()
+//!     .with_context("http://10.0.0.1:8080".to_string(), "api-1")
+//!     .with_context("http://10.0.0.2:8080".to_string(), "api-2")
+//!     .with_checker(HttpChecker::new().path("/health"))
+//!     .build();
+//! ```
+//!
+//! # Routing Requests
+//!
+//! When the pooled resources are themselves Tower services, [`HealthCheckLayer`]
+//! turns the wrapper into a [`HealthRoutedService`] that dispatches every
+//! request to a healthy member chosen by the configured [`SelectionStrategy`].
+//! It sits at the bottom of a `ServiceBuilder` stack like any other service:
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use tower::ServiceBuilder;
+//! use tower::util::BoxCloneSyncService;
+//! use tower_resilience_healthcheck::{
+//!     HealthCheckLayer, HealthCheckWrapper, HealthChecker, HealthStatus,
+//! };
+//!
+//! type Backend = BoxCloneSyncService<String, String, std::io::Error>;
+//!
+//! struct PingChecker;
+//!
+//! impl HealthChecker<Backend> for PingChecker {
+//!     async fn check(&self, _backend: &Backend) -> HealthStatus {
+//!         HealthStatus::Healthy
+//!     }
+//! }
+//!
+//! # async fn example(primary: Backend, secondary: Backend) {
+//! let pool = Arc::new(
+//!     HealthCheckWrapper::builder()
+//!         .with_context(primary, "primary")
+//!         .with_context(secondary, "secondary")
+//!         .with_checker(PingChecker)
+//!         .build(),
+//! );
+//! pool.start().await;
+//!
+//! let service = ServiceBuilder::new()
+//!     .layer(HealthCheckLayer::new())
+//!     .service(Arc::clone(&pool));
+//! # }
+//! ```
+//!
+//! Beyond first-available and round-robin, routed pools can balance load with
+//! [`SelectionStrategy::WeightedRoundRobin`] (weights set with
+//! `with_weighted_context`), [`SelectionStrategy::LeastOutstanding`] (fewest
+//! requests in flight through the routed service), or
+//! [`SelectionStrategy::LatencyAware`] (lowest moving average of probe and
+//! request latency).
+//!
+//! # Readiness Endpoint
+//!
+//! [`HealthCheckWrapper::report`] aggregates every resource into a
+//! [`HealthReport`], which serializes with the `serde` feature. With the
+//! `axum` feature, `readiness_router` serves it at `/health/ready`, returning
+//! `200 OK` while at least one resource is usable and `503 Service
+//! Unavailable` otherwise, ready to be wired to a Kubernetes readiness probe.
+//!
+//! # Events
+//!
+//! [`HealthEvent`] reports pool activity through the core event system:
+//! `StatusChanged` when a resource changes status, `CheckSucceeded` and
+//! `CheckFailed` for each probe, and `AllUnhealthy` when the last usable
+//! resource goes down. Register callbacks on the config builder:
+//!
+//! ```rust
+//! use tower_resilience_healthcheck::HealthCheckConfig;
+//!
+//! let config = HealthCheckConfig::builder()
+//!     .name("replicas")
+//!     .on_status_changed(|resource, from, to| {
+//!         println!("{}: {:?} -> {:?}", resource, from, to)
+//!     })
+//!     .on_all_unhealthy(|| eprintln!("no usable replicas"))
+//!     .on_event(|event| println!("{:?}", event))
+//!     .build();
+//! ```
+//!
+//! # Passive Health Signals
+//!
+//! Probes only run every interval. Outcomes of real requests can be fed in
+//! between them with [`HealthCheckWrapper::report_success`] and
+//! [`HealthCheckWrapper::report_failure`]; failures count towards the same
+//! failure threshold, so a backend that starts failing traffic is ejected
+//! immediately. [`HealthCheckLayer::report_outcomes`] does this automatically
+//! for every routed request.
 
 mod checker;
+mod checkers;
 mod config;
 mod context;
+mod error;
+mod events;
+mod report;
 mod selector;
+mod service;
 #[cfg(feature = "triggers")]
 pub(crate) mod triggers;
 mod wrapper;
 
 pub use checker::HealthChecker;
+#[cfg(feature = "grpc")]
+pub use checkers::GrpcChecker;
+#[cfg(feature = "http")]
+pub use checkers::HttpChecker;
+#[cfg(feature = "tcp")]
+pub use checkers::TcpChecker;
 pub use config::{HealthCheckConfig, HealthCheckConfigBuilder};
 pub use context::{HealthCheckedContext, HealthDetail};
+pub use error::HealthCheckError;
+pub use events::HealthEvent;
+#[cfg(feature = "axum")]
+pub use report::readiness_router;
+pub use report::HealthReport;
 pub use selector::{SelectionStrategy, Selector};
+pub use service::{HealthCheckLayer, HealthRoutedFuture, HealthRoutedService};
 pub use wrapper::{HealthCheckWrapper, HealthCheckWrapperBuilder};
 
 /// Health status of a monitored resource.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HealthStatus {
     /// Resource is healthy and ready to use
     Healthy,
@@ -0,0 +1,193 @@
+//! Aggregated readiness reports.
+
+use crate::{HealthDetail, HealthStatus};
+
+/// A point-in-time summary of every resource in a [`HealthCheckWrapper`](crate::HealthCheckWrapper).
+///
+/// Returned by [`HealthCheckWrapper::report`](crate::HealthCheckWrapper::report).
+/// The pool is ready when at least one resource is usable (healthy or
+/// degraded), which matches what [`get_usable`](crate::HealthCheckWrapper::get_usable)
+/// can hand out.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HealthReport {
+    /// Whether the pool can currently serve requests.
+    pub ready: bool,
+
+    /// Overall status of the pool.
+    ///
+    /// `Healthy` if every resource is healthy, `Degraded` if some but not all
+    /// resources are usable, `Unhealthy` if none are, and `Unknown` if no
+    /// resource has been checked yet.
+    pub status: HealthStatus,
+
+    /// Per-resource details.
+    pub resources: Vec<HealthDetail>,
+}
+
+impl HealthReport {
+    pub(crate) fn new(resources: Vec<HealthDetail>) -> Self {
+        let all_unknown = resources.iter().all(|r| r.status == HealthStatus::Unknown);
+        let all_healthy = resources.iter().all(|r| r.status.is_healthy());
+        let any_usable = resources.iter().any(|r| r.status.is_usable());
+
+        let status = if resources.is_empty() || all_unknown {
+            HealthStatus::Unknown
+        } else if all_healthy {
+            HealthStatus::Healthy
+        } else if any_usable {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Unhealthy
+        };
+
+        Self {
+            ready: any_usable,
+            status,
+            resources,
+        }
+    }
+}
+
+/// Responds with the report as JSON: `200 OK` when ready, `503 Service
+/// Unavailable` otherwise, as expected by Kubernetes readiness probes.
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for HealthReport {
+    fn into_response(self) -> axum::response::Response {
+        let code = if self.ready {
+            axum::http::StatusCode::OK
+        } else {
+            axum::http::StatusCode::SERVICE_UNAVAILABLE
+        };
+        (code, axum::Json(self)).into_response()
+    }
+}
+
+/// Builds an axum [`Router`](axum::Router) serving the pool's readiness
+/// report at `/health/ready`.
+///
+/// Merge it into the application router, or mount it on a separate admin
+/// port.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use tower_resilience_healthcheck::{readiness_router, HealthCheckWrapper, HealthStatus};
+///
+/// let pool = Arc::new(
+///     HealthCheckWrapper::builder()
+///         .with_context("10.0.0.1:5432".to_string(), "db-primary")
+///         .with_checker(|_db: &String| async { HealthStatus::Healthy })
+///         .build(),
+/// );
+///
+/// let app: axum::Router = axum::Router::new().merge(readiness_router(pool));
+/// ```
+#[cfg(feature = "axum")]
+pub fn readiness_router<T, C, S>(
+    wrapper: std::sync::Arc<crate::HealthCheckWrapper<T, C>>,
+) -> axum::Router<S>
+where
+    T: Clone + Send + Sync + 'static,
+    C: crate::HealthChecker<T> + 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    axum::Router::new().route(
+        "/health/ready",
+        axum::routing::get(move || {
+            let wrapper = std::sync::Arc::clone(&wrapper);
+            async move { wrapper.report().await }
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(name: &str, status: HealthStatus) -> HealthDetail {
+        HealthDetail {
+            name: name.to_string(),
+            status,
+            last_check_millis: 0,
+            consecutive_failures: 0,
+            consecutive_successes: 0,
+        }
+    }
+
+    #[test]
+    fn test_overall_status() {
+        let report = HealthReport::new(vec![
+            detail("a", HealthStatus::Healthy),
+            detail("b", HealthStatus::Healthy),
+        ]);
+        assert!(report.ready);
+        assert_eq!(report.status, HealthStatus::Healthy);
+
+        let report = HealthReport::new(vec![
+            detail("a", HealthStatus::Healthy),
+            detail("b", HealthStatus::Unhealthy),
+        ]);
+        assert!(report.ready);
+        assert_eq!(report.status, HealthStatus::Degraded);
+
+        let report = HealthReport::new(vec![
+            detail("a", HealthStatus::Unhealthy),
+            detail("b", HealthStatus::Unknown),
+        ]);
+        assert!(!report.ready);
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+
+        let report = HealthReport::new(vec![detail("a", HealthStatus::Unknown)]);
+        assert!(!report.ready);
+        assert_eq!(report.status, HealthStatus::Unknown);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serializes_to_json() {
+        let report = HealthReport::new(vec![detail("db", HealthStatus::Degraded)]);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["ready"], true);
+        assert_eq!(json["status"], "Degraded");
+        assert_eq!(json["resources"][0]["name"], "db");
+        assert_eq!(json["resources"][0]["consecutive_failures"], 0);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_readiness_router_status_codes() {
+        use crate::HealthCheckWrapper;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use std::sync::Arc;
+        use std::time::Duration;
+        use tower::ServiceExt;
+
+        let pool = Arc::new(
+            HealthCheckWrapper::builder()
+                .with_context("db".to_string(), "db")
+                .with_checker(|_: &String| async { HealthStatus::Healthy })
+                .with_initial_delay(Duration::from_millis(1))
+                .build(),
+        );
+        let router: axum::Router = readiness_router(Arc::clone(&pool));
+        let request = || {
+            Request::builder()
+                .uri("/health/ready")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // Nothing checked yet
+        let response = router.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        pool.start().await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let response = router.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
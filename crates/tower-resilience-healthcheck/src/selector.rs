@@ -61,8 +61,24 @@ pub enum SelectionStrategy {
     /// Best for: Accepting degraded performance over failure.
     PreferHealthy,
 
+    /// Round-robin in proportion to each resource's weight.
+    /// Resources with weight 0 receive no traffic.
+    /// Best for: Backends with different capacities.
+    WeightedRoundRobin,
+
+    /// Pick the resource with the fewest requests in flight.
+    /// In-flight requests are counted by [`HealthRoutedService`](crate::HealthRoutedService).
+    /// Best for: Backends with uneven or unpredictable response times.
+    LeastOutstanding,
+
+    /// Pick the resource with the lowest moving-average latency.
+    /// The average is fed by probe durations and successful routed requests;
+    /// resources with no samples yet are tried first.
+    /// Best for: Backends spread across regions or zones.
+    LatencyAware,
+
     /// Use a custom selector implementation.
-    /// Best for: Complex selection logic (latency-based, weighted, etc.).
+    /// Best for: Complex selection logic (affinity, geography, etc.).
     Custom(CustomSelectorFn),
 }
 
@@ -120,6 +136,51 @@ impl SelectionStrategy {
                 }
             }
 
+            SelectionStrategy::WeightedRoundRobin => {
+                let total: u64 = contexts
+                    .iter()
+                    .zip(&statuses)
+                    .filter(|(_, s)| s.is_usable())
+                    .map(|(ctx, _)| ctx.weight() as u64)
+                    .sum();
+
+                if total == 0 {
+                    return None;
+                }
+
+                let mut slot = round_robin_counter.fetch_add(1, Ordering::Relaxed) as u64 % total;
+                contexts
+                    .iter()
+                    .zip(&statuses)
+                    .enumerate()
+                    .filter(|(_, (_, s))| s.is_usable())
+                    .find_map(|(i, (ctx, _))| {
+                        let weight = ctx.weight() as u64;
+                        if slot < weight {
+                            Some(i)
+                        } else {
+                            slot -= weight;
+                            None
+                        }
+                    })
+            }
+
+            SelectionStrategy::LeastOutstanding => contexts
+                .iter()
+                .zip(&statuses)
+                .enumerate()
+                .filter(|(_, (_, s))| s.is_usable())
+                .min_by_key(|(_, (ctx, _))| ctx.outstanding())
+                .map(|(i, _)| i),
+
+            SelectionStrategy::LatencyAware => contexts
+                .iter()
+                .zip(&statuses)
+                .enumerate()
+                .filter(|(_, (_, s))| s.is_usable())
+                .min_by_key(|(_, (ctx, _))| ctx.latency().unwrap_or_default())
+                .map(|(i, _)| i),
+
             SelectionStrategy::PreferHealthy => {
                 // Try to find healthy first
                 statuses
@@ -279,4 +340,71 @@ mod tests {
         let selected = strategy.select(&contexts, &counter);
         assert_eq!(selected, Some(1)); // Last healthy
     }
+
+    #[test]
+    fn test_weighted_round_robin() {
+        let contexts = vec![
+            create_context("heavy", HealthStatus::Healthy).with_weight(3),
+            create_context("unhealthy", HealthStatus::Unhealthy).with_weight(5),
+            create_context("light", HealthStatus::Healthy),
+        ];
+
+        let strategy = SelectionStrategy::WeightedRoundRobin;
+        let counter = AtomicUsize::new(0);
+
+        let selected: Vec<_> = (0..8)
+            .map(|_| strategy.select(&contexts, &counter).unwrap())
+            .collect();
+        assert_eq!(selected, vec![0, 0, 0, 2, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn test_weighted_round_robin_zero_weight() {
+        let contexts = vec![create_context("drained", HealthStatus::Healthy).with_weight(0)];
+
+        let strategy = SelectionStrategy::WeightedRoundRobin;
+        let counter = AtomicUsize::new(0);
+
+        assert_eq!(strategy.select(&contexts, &counter), None);
+    }
+
+    #[test]
+    fn test_least_outstanding() {
+        let contexts = vec![
+            create_context("busy", HealthStatus::Healthy),
+            create_context("idle", HealthStatus::Healthy),
+            create_context("unhealthy", HealthStatus::Unhealthy),
+        ];
+        let _busy = contexts[0].begin_request();
+
+        let strategy = SelectionStrategy::LeastOutstanding;
+        let counter = AtomicUsize::new(0);
+
+        assert_eq!(strategy.select(&contexts, &counter), Some(1));
+
+        let _idle = contexts[1].begin_request();
+        let _also_idle = contexts[1].begin_request();
+        assert_eq!(strategy.select(&contexts, &counter), Some(0));
+    }
+
+    #[test]
+    fn test_latency_aware() {
+        let contexts = vec![
+            create_context("slow", HealthStatus::Healthy),
+            create_context("fast", HealthStatus::Healthy),
+        ];
+        contexts[0].record_latency(std::time::Duration::from_millis(200));
+
+        let strategy = SelectionStrategy::LatencyAware;
+        let counter = AtomicUsize::new(0);
+
+        // Resources without samples are tried first
+        assert_eq!(strategy.select(&contexts, &counter), Some(1));
+
+        contexts[1].record_latency(std::time::Duration::from_millis(20));
+        assert_eq!(strategy.select(&contexts, &counter), Some(1));
+
+        contexts[1].record_latency(std::time::Duration::from_secs(2));
+        assert_eq!(strategy.select(&contexts, &counter), Some(0));
+    }
 }
@@ -0,0 +1,451 @@
+//! Tower integration that routes each request to a healthy resource.
+
+use crate::context::InFlight;
+use crate::wrapper::OutcomeSink;
+use crate::{
+    HealthCheckError, HealthCheckWrapper, HealthCheckedContext, HealthChecker, HealthStatus,
+};
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use tower_layer::Layer;
+use tower_resilience_core::span::Outcome;
+use tower_service::Service;
+
+/// A Tower [`Layer`] that turns a [`HealthCheckWrapper`] of services into a
+/// [`HealthRoutedService`].
+///
+/// Unlike the other patterns, the layer is applied to the *pool* rather than
+/// a single service: pass the wrapper (or an `Arc` of it, to keep a handle
+/// for [`start`](HealthCheckWrapper::start) and
+/// [`stop`](HealthCheckWrapper::stop)) as the service at the bottom of a
+/// `ServiceBuilder` stack, and everything above it sees one `Service`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use tower::ServiceBuilder;
+/// use tower::util::BoxCloneSyncService;
+/// use tower_resilience_healthcheck::{
+///     HealthCheckLayer, HealthCheckWrapper, HealthChecker, HealthStatus,
+/// };
+///
+/// type Backend = BoxCloneSyncService<String, String, std::io::Error>;
+///
+/// struct PingChecker;
+///
+/// impl HealthChecker<Backend> for PingChecker {
+///     async fn check(&self, _backend: &Backend) -> HealthStatus {
+///         HealthStatus::Healthy
+///     }
+/// }
+///
+/// fn backend(name: &'static str) -> Backend {
+///     BoxCloneSyncService::new(tower::service_fn(move |req: String| async move {
+///         Ok(format!("{}: {}", name, req))
+///     }))
+/// }
+///
+/// # async fn example() {
+/// let pool = Arc::new(
+///     HealthCheckWrapper::builder()
+///         .with_context(backend("primary"), "primary")
+///         .with_context(backend("secondary"), "secondary")
+///         .with_checker(PingChecker)
+///         .with_interval(Duration::from_secs(5))
+///         .build(),
+/// );
+/// pool.start().await;
+///
+/// let service = ServiceBuilder::new()
+///     .layer(HealthCheckLayer::new())
+///     .service(Arc::clone(&pool));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HealthCheckLayer {
+    healthy_only: bool,
+    report_outcomes: bool,
+}
+
+impl HealthCheckLayer {
+    /// Create a layer that routes to usable (healthy or degraded) resources.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only route to resources that are fully healthy, never degraded ones.
+    pub fn healthy_only(mut self) -> Self {
+        self.healthy_only = true;
+        self
+    }
+
+    /// Feed the outcome of every routed request back into the pool.
+    ///
+    /// Successes and errors from the selected resource are reported with
+    /// [`HealthCheckWrapper::report_success`] and
+    /// [`HealthCheckWrapper::report_failure`], so a backend that starts
+    /// failing real traffic is ejected without waiting for the next probe.
+    pub fn report_outcomes(mut self) -> Self {
+        self.report_outcomes = true;
+        self
+    }
+}
+
+impl<T, C> Layer<HealthCheckWrapper<T, C>> for HealthCheckLayer {
+    type Service = HealthRoutedService<T, C>;
+
+    fn layer(&self, wrapper: HealthCheckWrapper<T, C>) -> Self::Service {
+        self.layer(Arc::new(wrapper))
+    }
+}
+
+impl<T, C> Layer<Arc<HealthCheckWrapper<T, C>>> for HealthCheckLayer {
+    type Service = HealthRoutedService<T, C>;
+
+    fn layer(&self, wrapper: Arc<HealthCheckWrapper<T, C>>) -> Self::Service {
+        HealthRoutedService {
+            wrapper,
+            healthy_only: self.healthy_only,
+            report_outcomes: self.report_outcomes,
+        }
+    }
+}
+
+/// A service that dispatches each request to a healthy service from a
+/// [`HealthCheckWrapper`] pool.
+///
+/// The resource is chosen per request using the wrapper's
+/// [`SelectionStrategy`](crate::SelectionStrategy). When no resource is
+/// eligible the request fails with [`HealthCheckError::NoHealthyResource`];
+/// errors from the chosen service are returned as
+/// [`HealthCheckError::Inner`].
+///
+/// Readiness is checked on the selected service when the request is
+/// dispatched, so `poll_ready` on the routed service itself is always ready.
+pub struct HealthRoutedService<T, C> {
+    wrapper: Arc<HealthCheckWrapper<T, C>>,
+    healthy_only: bool,
+    report_outcomes: bool,
+}
+
+impl<T, C> HealthRoutedService<T, C> {
+    /// Create a service routing over the given pool, to usable resources.
+    pub fn new(wrapper: Arc<HealthCheckWrapper<T, C>>) -> Self {
+        HealthCheckLayer::new().layer(wrapper)
+    }
+
+    /// Returns the underlying health-checked pool.
+    pub fn wrapper(&self) -> &Arc<HealthCheckWrapper<T, C>> {
+        &self.wrapper
+    }
+}
+
+impl<T, C> Clone for HealthRoutedService<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            wrapper: Arc::clone(&self.wrapper),
+            healthy_only: self.healthy_only,
+            report_outcomes: self.report_outcomes,
+        }
+    }
+}
+
+impl<T, C, Request> Service<Request> for HealthRoutedService<T, C>
+where
+    T: Service<Request> + Clone + Send + Sync + 'static,
+    C: HealthChecker<T> + 'static,
+{
+    type Response = T::Response;
+    type Error = HealthCheckError<T::Error>;
+    type Future = HealthRoutedFuture<T, Request>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let selected = if self.healthy_only {
+            self.wrapper
+                .try_get_with_filter(|s| s == HealthStatus::Healthy)
+        } else {
+            self.wrapper.try_get_with_filter(|s| s.is_usable())
+        };
+
+        let Some(ctx) = selected else {
+            return HealthRoutedFuture {
+                state: State::Unavailable,
+                in_flight: None,
+                reporter: None,
+                span: request_span("<none>"),
+            };
+        };
+
+        let span = request_span(&ctx.name);
+
+        let in_flight = Some(ctx.begin_request());
+
+        let (service, reporter) = if self.report_outcomes {
+            let sink: Arc<dyn OutcomeSink<T>> = self.wrapper.clone();
+            (ctx.context.clone(), Some((ctx, sink)))
+        } else {
+            (ctx.context, None)
+        };
+
+        HealthRoutedFuture {
+            state: State::Ready {
+                service,
+                request: Some(request),
+            },
+            in_flight,
+            reporter,
+            span,
+        }
+    }
+}
+
+// The pool has no name of its own, so the span is named after the resource
+// the request was routed to.
+#[cfg(feature = "tracing")]
+type RequestSpan = tracing::Span;
+
+#[cfg(not(feature = "tracing"))]
+struct RequestSpan;
+
+#[cfg(feature = "tracing")]
+fn request_span(resource: &str) -> RequestSpan {
+    tower_resilience_core::span::pattern_span("health_check", resource)
+}
+
+#[cfg(not(feature = "tracing"))]
+fn request_span(_resource: &str) -> RequestSpan {
+    RequestSpan
+}
+
+pin_project! {
+    /// Future returned by [`HealthRoutedService`].
+    pub struct HealthRoutedFuture<S, Request>
+    where
+        S: Service<Request>,
+    {
+        #[pin]
+        state: State<S, Request, S::Future>,
+        in_flight: Option<InFlight>,
+        reporter: Option<(HealthCheckedContext<S>, Arc<dyn OutcomeSink<S>>)>,
+        span: RequestSpan,
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    enum State<S, Request, F> {
+        Unavailable,
+        Ready {
+            service: S,
+            request: Option<Request>,
+        },
+        Calling {
+            #[pin]
+            future: F,
+        },
+    }
+}
+
+impl<S, Request> Future for HealthRoutedFuture<S, Request>
+where
+    S: Service<Request>,
+{
+    type Output = Result<S::Response, HealthCheckError<S::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut state = this.state;
+        #[cfg(feature = "tracing")]
+        let _enter = this.span.enter();
+        let _finish = |_outcome: Outcome| {
+            #[cfg(feature = "tracing")]
+            tower_resilience_core::span::record_outcome(this.span, _outcome);
+        };
+        let mut report = |success: bool| {
+            if let Some((ctx, sink)) = this.reporter.take() {
+                sink.record_outcome(&ctx, success);
+            }
+        };
+        loop {
+            match state.as_mut().project() {
+                StateProj::Unavailable => {
+                    _finish(Outcome::Rejected);
+                    return Poll::Ready(Err(HealthCheckError::NoHealthyResource));
+                }
+                StateProj::Ready { service, request } => {
+                    match service.poll_ready(cx) {
+                        Poll::Ready(Ok(())) => {}
+                        Poll::Ready(Err(e)) => {
+                            report(false);
+                            _finish(Outcome::Error);
+                            return Poll::Ready(Err(HealthCheckError::Inner(e)));
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+                    let request = request.take().expect("polled after completion");
+                    let future = service.call(request);
+                    state.set(State::Calling { future });
+                }
+                StateProj::Calling { future } => {
+                    let result = ready!(future.poll(cx));
+                    if let Some(in_flight) = this.in_flight.take() {
+                        if result.is_ok() {
+                            in_flight.record_latency();
+                        }
+                    }
+                    report(result.is_ok());
+                    _finish(Outcome::from_plain_result(&result));
+                    return Poll::Ready(result.map_err(HealthCheckError::Inner));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    #[derive(Clone)]
+    struct Backend {
+        name: &'static str,
+        status: HealthStatus,
+    }
+
+    impl Service<u32> for Backend {
+        type Response = &'static str;
+        type Error = std::io::Error;
+        type Future = std::future::Ready<Result<&'static str, std::io::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: u32) -> Self::Future {
+            std::future::ready(Ok(self.name))
+        }
+    }
+
+    struct StatusChecker;
+
+    impl HealthChecker<Backend> for StatusChecker {
+        async fn check(&self, backend: &Backend) -> HealthStatus {
+            backend.status
+        }
+    }
+
+    async fn pool(backends: &[Backend]) -> Arc<HealthCheckWrapper<Backend, StatusChecker>> {
+        let mut builder = HealthCheckWrapper::builder()
+            .with_checker(StatusChecker)
+            .with_interval(Duration::from_millis(50))
+            .with_initial_delay(Duration::from_millis(1))
+            .with_failure_threshold(1);
+        for backend in backends {
+            builder = builder.with_context(backend.clone(), backend.name);
+        }
+        let wrapper = Arc::new(builder.build());
+        wrapper.start().await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        wrapper
+    }
+
+    fn backend(name: &'static str, status: HealthStatus) -> Backend {
+        Backend { name, status }
+    }
+
+    #[tokio::test]
+    async fn test_routes_to_healthy_resource() {
+        let pool = pool(&[
+            backend("down", HealthStatus::Unhealthy),
+            backend("up", HealthStatus::Healthy),
+        ])
+        .await;
+
+        let service = HealthRoutedService::new(pool);
+        assert_eq!(service.oneshot(1).await.unwrap(), "up");
+    }
+
+    #[tokio::test]
+    async fn test_no_healthy_resource() {
+        let pool = pool(&[backend("down", HealthStatus::Unhealthy)]).await;
+
+        let service = HealthRoutedService::new(pool);
+        let err = service.oneshot(1).await.unwrap_err();
+        assert!(err.is_no_healthy_resource());
+    }
+
+    #[tokio::test]
+    async fn test_healthy_only_skips_degraded() {
+        let pool = pool(&[backend("slow", HealthStatus::Degraded)]).await;
+
+        let usable = HealthCheckLayer::new().layer(Arc::clone(&pool));
+        assert_eq!(usable.oneshot(1).await.unwrap(), "slow");
+
+        let healthy = HealthCheckLayer::new().healthy_only().layer(pool);
+        assert!(healthy
+            .oneshot(1)
+            .await
+            .unwrap_err()
+            .is_no_healthy_resource());
+    }
+
+    /// A backend that fails every request while still passing its probe.
+    #[derive(Clone)]
+    struct Failing;
+
+    impl Service<u32> for Failing {
+        type Response = ();
+        type Error = std::io::Error;
+        type Future = std::future::Ready<Result<(), std::io::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: u32) -> Self::Future {
+            std::future::ready(Err(std::io::Error::other("boom")))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_outcomes_ejects_failing_resource() {
+        let pool = Arc::new(
+            HealthCheckWrapper::builder()
+                .with_context(Failing, "failing")
+                .with_checker(|_: &Failing| async { HealthStatus::Healthy })
+                .with_interval(Duration::from_secs(60))
+                .with_initial_delay(Duration::from_millis(1))
+                .with_failure_threshold(2)
+                .build(),
+        );
+        pool.start().await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let service = HealthCheckLayer::new()
+            .report_outcomes()
+            .layer(Arc::clone(&pool));
+
+        assert!(service.clone().oneshot(1).await.unwrap_err().is_inner());
+        assert!(service.clone().oneshot(2).await.unwrap_err().is_inner());
+        assert_eq!(
+            pool.get_status("failing").await,
+            Some(HealthStatus::Unhealthy)
+        );
+        assert!(service
+            .oneshot(3)
+            .await
+            .unwrap_err()
+            .is_no_healthy_resource());
+    }
+}
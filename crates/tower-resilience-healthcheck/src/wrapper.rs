@@ -1,9 +1,12 @@
 //! Health check wrapper for managing multiple resources.
 
-use crate::{HealthCheckConfig, HealthCheckedContext, HealthChecker, HealthDetail, HealthStatus};
-use std::sync::atomic::AtomicUsize;
+use crate::{
+    HealthCheckConfig, HealthCheckedContext, HealthChecker, HealthDetail, HealthReport,
+    HealthStatus,
+};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 
@@ -53,13 +56,16 @@ pub struct HealthCheckWrapper<T, C> {
     checker: Arc<C>,
 
     /// Configuration
-    config: HealthCheckConfig,
+    config: Arc<HealthCheckConfig>,
 
     /// Handle to the background health check task
     health_check_task: Arc<RwLock<Option<JoinHandle<()>>>>,
 
     /// Round-robin counter for RoundRobin strategy
     round_robin_counter: Arc<AtomicUsize>,
+
+    /// Whether any resource was usable when the pool was last evaluated
+    pool_usable: Arc<AtomicBool>,
 }
 
 impl<T, C> HealthCheckWrapper<T, C>
@@ -86,7 +92,8 @@ where
 
         let contexts = Arc::clone(&self.contexts);
         let checker = Arc::clone(&self.checker);
-        let config = self.config.clone();
+        let config = Arc::clone(&self.config);
+        let pool_usable = Arc::clone(&self.pool_usable);
 
         let task = tokio::spawn(async move {
             // Initial delay
@@ -104,34 +111,26 @@ where
                 for ctx in contexts_read.iter() {
                     let ctx_clone = ctx.clone();
                     let checker_clone = Arc::clone(&checker);
-                    let timeout = config.timeout;
-                    let failure_threshold = config.failure_threshold;
-                    let success_threshold = config.success_threshold;
-
-                    #[cfg(feature = "tracing")]
-                    let on_health_change = config.on_health_change.clone();
-                    #[cfg(feature = "tracing")]
-                    let on_check_failed = config.on_check_failed.clone();
-                    #[cfg(feature = "tracing")]
-                    let ctx_name = ctx.name.clone();
-
-                    #[cfg(feature = "triggers")]
-                    let triggers = config.triggers.clone();
+                    let config = Arc::clone(&config);
 
                     let handle = tokio::spawn(async move {
                         // Perform health check with timeout
-                        let check_result =
-                            tokio::time::timeout(timeout, checker_clone.check(&ctx_clone.context))
-                                .await;
-
+                        let started = Instant::now();
+                        let check_result = tokio::time::timeout(
+                            config.timeout,
+                            checker_clone.check(&ctx_clone.context),
+                        )
+                        .await;
+
+                        let timed_out = check_result.is_err();
                         let status = match check_result {
                             Ok(status) => status,
                             #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
                             Err(timeout_err) => {
                                 // Timeout = unhealthy, invoke callback if registered
                                 #[cfg(feature = "tracing")]
-                                if let Some(ref callback) = on_check_failed {
-                                    callback(&ctx_name, &timeout_err);
+                                if let Some(ref callback) = config.on_check_failed {
+                                    callback(&ctx_clone.name, &timeout_err);
                                 }
                                 HealthStatus::Unhealthy
                             }
@@ -143,55 +142,29 @@ where
                             .unwrap()
                             .as_millis() as u64;
                         ctx_clone.set_last_check(now);
-
-                        // Get old status for event callback and triggers
-                        #[cfg(any(feature = "tracing", feature = "triggers"))]
-                        let old_status = ctx_clone.status();
+                        let elapsed = started.elapsed();
+                        if status.is_usable() {
+                            ctx_clone.record_latency(elapsed);
+                        }
+                        config.record_check(&ctx_clone.name, status, elapsed, timed_out);
 
                         // Update consecutive counters and status based on check result
-                        match status {
+                        transition(&ctx_clone, &config, |ctx| match status {
                             HealthStatus::Healthy => {
-                                ctx_clone.record_success();
-                                if ctx_clone.consecutive_successes() >= success_threshold as u64 {
-                                    ctx_clone.set_status(HealthStatus::Healthy);
+                                ctx.record_success();
+                                if ctx.consecutive_successes() >= config.success_threshold as u64 {
+                                    ctx.set_status(HealthStatus::Healthy);
                                 }
                             }
                             HealthStatus::Degraded => {
-                                ctx_clone.record_success();
-                                ctx_clone.set_status(HealthStatus::Degraded);
-                            }
-                            HealthStatus::Unhealthy => {
-                                ctx_clone.record_failure();
-                                if ctx_clone.consecutive_failures() >= failure_threshold as u64 {
-                                    ctx_clone.set_status(HealthStatus::Unhealthy);
-                                }
+                                ctx.record_success();
+                                ctx.set_status(HealthStatus::Degraded);
                             }
+                            HealthStatus::Unhealthy => record_failure(ctx, &config),
                             HealthStatus::Unknown => {
                                 // Don't change status on unknown
                             }
-                        }
-
-                        // Get new status for callbacks
-                        #[cfg(any(feature = "tracing", feature = "triggers"))]
-                        let new_status = ctx_clone.status();
-
-                        // Emit health change event if status changed
-                        #[cfg(feature = "tracing")]
-                        {
-                            if old_status != new_status {
-                                if let Some(ref callback) = on_health_change {
-                                    callback(&ctx_clone.name, old_status, new_status);
-                                }
-                            }
-                        }
-
-                        // Notify triggers if status changed
-                        #[cfg(feature = "triggers")]
-                        {
-                            if old_status != new_status {
-                                crate::triggers::notify_triggers(&triggers, old_status, new_status);
-                            }
-                        }
+                        });
                     });
 
                     handles.push(handle);
@@ -203,6 +176,8 @@ where
                 for handle in handles {
                     let _ = handle.await;
                 }
+
+                check_pool(&contexts.read().await, &pool_usable, &config);
             }
         });
 
@@ -217,6 +192,34 @@ where
         }
     }
 
+    /// Report a successful request to the named resource.
+    ///
+    /// Passive signals from real traffic complement the periodic probes: a
+    /// resource marked unhealthy recovers once it reaches the success
+    /// threshold, while a degraded resource stays degraded until a probe says
+    /// otherwise. Unknown names are ignored.
+    pub fn report_success(&self, name: &str) {
+        if let Some(ctx) = self.find(name) {
+            self.record_outcome(&ctx, true);
+        }
+    }
+
+    /// Report a failed request to the named resource.
+    ///
+    /// Failures count towards the same failure threshold as failed probes, so
+    /// a resource that starts failing real requests is taken out of rotation
+    /// without waiting for the next probe interval. Unknown names are ignored.
+    pub fn report_failure(&self, name: &str) {
+        if let Some(ctx) = self.find(name) {
+            self.record_outcome(&ctx, false);
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<HealthCheckedContext<T>> {
+        let contexts = self.contexts.try_read().ok()?;
+        contexts.iter().find(|ctx| ctx.name == name).cloned()
+    }
+
     /// Get a healthy resource based on the selection strategy.
     ///
     /// Returns `None` if no healthy resources are available.
@@ -237,7 +240,29 @@ where
         F: Fn(HealthStatus) -> bool,
     {
         let contexts = self.contexts.read().await;
+        self.select(&contexts, filter).map(|ctx| ctx.context)
+    }
+
+    /// Get a resource matching the filter function without waiting.
+    ///
+    /// The resource list is only written while building, so the read lock is
+    /// always available; this lets `Service::call` select synchronously.
+    pub(crate) fn try_get_with_filter<F>(&self, filter: F) -> Option<HealthCheckedContext<T>>
+    where
+        F: Fn(HealthStatus) -> bool,
+    {
+        let contexts = self.contexts.try_read().ok()?;
+        self.select(&contexts, filter)
+    }
 
+    fn select<F>(
+        &self,
+        contexts: &[HealthCheckedContext<T>],
+        filter: F,
+    ) -> Option<HealthCheckedContext<T>>
+    where
+        F: Fn(HealthStatus) -> bool,
+    {
         // Filter to contexts matching the filter
         let available: Vec<_> = contexts
             .iter()
@@ -255,7 +280,7 @@ where
             .selection_strategy
             .select(&available, &self.round_robin_counter)?;
 
-        available.get(selected_idx).map(|ctx| ctx.context.clone())
+        available.into_iter().nth(selected_idx)
     }
 
     /// Get the health status of a specific resource by name.
@@ -290,6 +315,34 @@ where
             })
             .collect()
     }
+
+    /// Get an aggregated readiness report for the whole pool.
+    ///
+    /// With the `serde` feature the report serializes to JSON, and with the
+    /// `axum` feature it can be returned directly from a handler; see
+    /// [`readiness_router`](crate::readiness_router).
+    pub async fn report(&self) -> HealthReport {
+        HealthReport::new(self.get_health_details().await)
+    }
+}
+
+/// Receives the outcomes of routed requests, without exposing the checker type.
+pub(crate) trait OutcomeSink<T>: Send + Sync {
+    /// Feeds the outcome of a real request into a resource's health state.
+    fn record_outcome(&self, ctx: &HealthCheckedContext<T>, success: bool);
+}
+
+impl<T, C> OutcomeSink<T> for HealthCheckWrapper<T, C>
+where
+    T: Send + Sync,
+    C: Send + Sync,
+{
+    fn record_outcome(&self, ctx: &HealthCheckedContext<T>, success: bool) {
+        record_passive_outcome(ctx, &self.config, success);
+        if let Ok(contexts) = self.contexts.try_read() {
+            check_pool(&contexts, &self.pool_usable, &self.config);
+        }
+    }
 }
 
 impl<T, C> Drop for HealthCheckWrapper<T, C> {
@@ -306,6 +359,75 @@ impl<T, C> Drop for HealthCheckWrapper<T, C> {
     }
 }
 
+/// Applies a passive success or failure to a resource.
+fn record_passive_outcome<T>(
+    ctx: &HealthCheckedContext<T>,
+    config: &HealthCheckConfig,
+    success: bool,
+) {
+    transition(ctx, config, |ctx| {
+        if !success {
+            record_failure(ctx, config);
+            return;
+        }
+        ctx.record_success();
+        let recovering = matches!(
+            ctx.status(),
+            HealthStatus::Unhealthy | HealthStatus::Unknown
+        );
+        if recovering && ctx.consecutive_successes() >= config.success_threshold as u64 {
+            ctx.set_status(HealthStatus::Healthy);
+        }
+    });
+}
+
+/// Counts a failure and marks the resource unhealthy once the threshold is reached.
+fn record_failure<T>(ctx: &HealthCheckedContext<T>, config: &HealthCheckConfig) {
+    ctx.record_failure();
+    if ctx.consecutive_failures() >= config.failure_threshold as u64 {
+        ctx.set_status(HealthStatus::Unhealthy);
+    }
+}
+
+/// Applies `update` to a resource and notifies listeners if its status changed.
+fn transition<T>(
+    ctx: &HealthCheckedContext<T>,
+    config: &HealthCheckConfig,
+    update: impl FnOnce(&HealthCheckedContext<T>),
+) {
+    let old_status = ctx.status();
+
+    update(ctx);
+
+    let new_status = ctx.status();
+    if old_status != new_status {
+        config.record_status_change(&ctx.name, old_status, new_status);
+
+        // Emit health change callback
+        #[cfg(feature = "tracing")]
+        if let Some(ref callback) = config.on_health_change {
+            callback(&ctx.name, old_status, new_status);
+        }
+
+        // Notify triggers
+        #[cfg(feature = "triggers")]
+        crate::triggers::notify_triggers(&config.triggers, old_status, new_status);
+    }
+}
+
+/// Emits [`AllUnhealthy`](crate::HealthEvent::AllUnhealthy) when the pool
+/// loses its last usable resource.
+fn check_pool<T>(
+    contexts: &[HealthCheckedContext<T>],
+    pool_usable: &AtomicBool,
+    config: &HealthCheckConfig,
+) {
+    let usable = contexts.iter().any(|ctx| ctx.status().is_usable());
+    if pool_usable.swap(usable, Ordering::AcqRel) && !usable {
+        config.record_all_unhealthy(contexts.len());
+    }
+}
+
 /// Builder for `HealthCheckWrapper`.
 pub struct HealthCheckWrapperBuilder<T, C> {
     contexts: Vec<HealthCheckedContext<T>>,
@@ -333,6 +455,19 @@ where
         self
     }
 
+    /// Add a resource with a weight for
+    /// [`SelectionStrategy::WeightedRoundRobin`](crate::SelectionStrategy::WeightedRoundRobin).
+    pub fn with_weighted_context(
+        mut self,
+        context: T,
+        name: impl Into<String>,
+        weight: u32,
+    ) -> Self {
+        self.contexts
+            .push(HealthCheckedContext::new(context, name).with_weight(weight));
+        self
+    }
+
     /// Set the health checker.
     pub fn with_checker(mut self, checker: C) -> Self {
         self.checker = Some(checker);
@@ -375,6 +510,23 @@ where
         self
     }
 
+    /// Set the name used in events.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.config.name = name.into();
+        self
+    }
+
+    /// Add a listener for every [`HealthEvent`](crate::HealthEvent).
+    pub fn with_event_listener<F>(mut self, listener: F) -> Self
+    where
+        F: Fn(&crate::HealthEvent) + Send + Sync + 'static,
+    {
+        self.config
+            .event_listeners
+            .add(tower_resilience_core::FnListener::new(listener));
+        self
+    }
+
     /// Set the full configuration.
     pub fn with_config(mut self, config: HealthCheckConfig) -> Self {
         self.config = config;
@@ -416,9 +568,10 @@ where
         HealthCheckWrapper {
             contexts: Arc::new(RwLock::new(self.contexts)),
             checker: Arc::new(self.checker.expect("Health checker must be provided")),
-            config: self.config,
+            config: Arc::new(self.config),
             health_check_task: Arc::new(RwLock::new(None)),
             round_robin_counter: Arc::new(AtomicUsize::new(0)),
+            pool_usable: Arc::new(AtomicBool::new(true)),
         }
     }
 }
@@ -562,4 +715,132 @@ mod tests {
 
         wrapper.stop().await;
     }
+
+    #[tokio::test]
+    async fn test_passive_failures_mark_unhealthy() {
+        let wrapper = HealthCheckWrapper::builder()
+            .with_context(
+                MockResource {
+                    name: "flaky".to_string(),
+                    is_healthy: true,
+                },
+                "flaky",
+            )
+            .with_checker(MockChecker)
+            .with_interval(Duration::from_secs(60))
+            .with_initial_delay(Duration::from_millis(1))
+            .with_failure_threshold(2)
+            .build();
+
+        wrapper.start().await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(
+            wrapper.get_status("flaky").await,
+            Some(HealthStatus::Healthy)
+        );
+
+        // Real traffic fails long before the next probe is due
+        wrapper.report_failure("flaky");
+        assert_eq!(
+            wrapper.get_status("flaky").await,
+            Some(HealthStatus::Healthy)
+        );
+        wrapper.report_failure("flaky");
+        assert_eq!(
+            wrapper.get_status("flaky").await,
+            Some(HealthStatus::Unhealthy)
+        );
+        assert!(wrapper.get_healthy().await.is_none());
+
+        wrapper.report_success("flaky");
+        assert_eq!(
+            wrapper.get_status("flaky").await,
+            Some(HealthStatus::Healthy)
+        );
+
+        // Unknown names are ignored
+        wrapper.report_failure("missing");
+
+        wrapper.stop().await;
+    }
+
+    #[tokio::test]
+    async fn test_passive_success_keeps_degraded() {
+        let wrapper = HealthCheckWrapper::builder()
+            .with_context(
+                MockResource {
+                    name: "slow".to_string(),
+                    is_healthy: true,
+                },
+                "slow",
+            )
+            .with_checker(|_: &MockResource| async { HealthStatus::Degraded })
+            .with_interval(Duration::from_secs(60))
+            .with_initial_delay(Duration::from_millis(1))
+            .build();
+
+        wrapper.start().await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        wrapper.report_success("slow");
+        assert_eq!(
+            wrapper.get_status("slow").await,
+            Some(HealthStatus::Degraded)
+        );
+
+        wrapper.stop().await;
+    }
+
+    #[tokio::test]
+    async fn test_events_emitted() {
+        use crate::HealthEvent;
+        use std::sync::Mutex;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+
+        let wrapper = HealthCheckWrapper::builder()
+            .with_context(
+                MockResource {
+                    name: "db".to_string(),
+                    is_healthy: true,
+                },
+                "db",
+            )
+            .with_checker(MockChecker)
+            .with_name("pool")
+            .with_interval(Duration::from_secs(60))
+            .with_initial_delay(Duration::from_millis(1))
+            .with_failure_threshold(1)
+            .with_event_listener(move |event| {
+                let label = match event {
+                    HealthEvent::StatusChanged { resource, to, .. } => {
+                        format!("changed {} {:?}", resource, to)
+                    }
+                    HealthEvent::CheckSucceeded { resource, .. } => format!("ok {}", resource),
+                    HealthEvent::CheckFailed { resource, .. } => format!("failed {}", resource),
+                    HealthEvent::AllUnhealthy { resources, .. } => {
+                        format!("all unhealthy {}", resources)
+                    }
+                };
+                recorded.lock().unwrap().push(label);
+            })
+            .build();
+
+        wrapper.start().await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        wrapper.report_failure("db");
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                "ok db",
+                "changed db Healthy",
+                "changed db Unhealthy",
+                "all unhealthy 1",
+            ]
+        );
+
+        wrapper.stop().await;
+    }
 }
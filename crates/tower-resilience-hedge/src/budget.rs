@@ -0,0 +1,121 @@
+//! Hedge budget to cap the fraction of traffic that is hedged.
+//!
+//! Without a budget, a latency regression in the backend causes nearly every
+//! request to fire hedges, multiplying load exactly when the backend is
+//! least able to handle it. The budget bounds hedged requests to a fixed
+//! ratio of total traffic.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Fixed-point scale for fractional token accounting.
+const SCALE: u64 = 1000;
+
+/// Default maximum number of hedges that can be accumulated.
+const DEFAULT_MAX_BURST: usize = 10;
+
+/// Token bucket limiting hedged requests to a ratio of total requests.
+///
+/// Every request deposits `ratio` tokens and every hedge attempt withdraws
+/// one token. When the balance drops below one token, further hedges are
+/// suppressed until enough requests have been made to replenish it.
+///
+/// The budget is shared across all clones of a hedge service.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_hedge::{HedgeBudget, HedgeLayer};
+///
+/// // Hedge at most 5% of requests, allowing bursts of up to 20 hedges
+/// let layer = HedgeLayer::builder()
+///     .hedge_budget(HedgeBudget::new(0.05).max_burst(20))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct HedgeBudget {
+    /// Tokens deposited per request (scaled).
+    deposit: u64,
+    /// Maximum token balance (scaled).
+    max_tokens: u64,
+    /// Current token balance (scaled).
+    tokens: AtomicU64,
+}
+
+impl HedgeBudget {
+    /// Create a budget allowing at most `ratio` hedges per request.
+    ///
+    /// The ratio is clamped to `0.0..=1.0`. The balance starts empty and
+    /// can accumulate up to 10 hedges by default.
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            deposit: (ratio.clamp(0.0, 1.0) * SCALE as f64).round() as u64,
+            max_tokens: DEFAULT_MAX_BURST as u64 * SCALE,
+            tokens: AtomicU64::new(0),
+        }
+    }
+
+    /// Set the maximum number of hedges that can be accumulated.
+    pub fn max_burst(mut self, max_burst: usize) -> Self {
+        self.max_tokens = max_burst.max(1) as u64 * SCALE;
+        self
+    }
+
+    /// Deposit tokens for a new request.
+    pub(crate) fn deposit(&self) {
+        let _ = self
+            .tokens
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some((current + self.deposit).min(self.max_tokens))
+            });
+    }
+
+    /// Attempt to withdraw one hedge token.
+    ///
+    /// Returns `true` if the hedge is allowed.
+    pub(crate) fn try_withdraw(&self) -> bool {
+        self.tokens
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                current.checked_sub(SCALE)
+            })
+            .is_ok()
+    }
+
+    /// Returns the number of hedges currently available.
+    pub fn available(&self) -> usize {
+        (self.tokens.load(Ordering::Relaxed) / SCALE) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_empty() {
+        let budget = HedgeBudget::new(0.5);
+        assert_eq!(budget.available(), 0);
+        assert!(!budget.try_withdraw());
+    }
+
+    #[test]
+    fn deposits_ratio_per_request() {
+        let budget = HedgeBudget::new(0.1);
+        for _ in 0..9 {
+            budget.deposit();
+        }
+        assert!(!budget.try_withdraw());
+
+        budget.deposit();
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+    }
+
+    #[test]
+    fn balance_capped_at_max_burst() {
+        let budget = HedgeBudget::new(1.0).max_burst(2);
+        for _ in 0..10 {
+            budget.deposit();
+        }
+        assert_eq!(budget.available(), 2);
+    }
+}
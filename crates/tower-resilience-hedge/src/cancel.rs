@@ -0,0 +1,130 @@
+//! Cancellation hooks for losing hedge attempts.
+//!
+//! When one attempt wins, the remaining in-flight attempts are aborted.
+//! Aborting the spawned task drops the inner service's future, but work
+//! the inner client has already handed off (e.g. an HTTP request on a
+//! connection pool) may keep running. Cancel hooks let the inner client
+//! observe cancellation and abort its transport.
+
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+/// Hook invoked around the lifecycle of each hedge attempt.
+///
+/// Both methods have no-op default implementations.
+pub trait CancelHook<Req>: Send + Sync {
+    /// Called before an attempt is dispatched to the inner service.
+    ///
+    /// The token is cancelled if the attempt loses to another attempt or
+    /// the hedged call itself is dropped. Implementations can attach it to
+    /// the request (e.g. in HTTP request extensions) so the inner client can
+    /// abort in-flight work.
+    fn attach(&self, _req: &mut Req, _token: &CancellationToken) {}
+
+    /// Called for each in-flight attempt that is cancelled, with the request
+    /// as it was dispatched (after decoration and [`attach`](Self::attach)).
+    fn on_cancel(&self, _req: &Req) {}
+}
+
+/// No cancellation hook.
+///
+/// This is the default hook. It implements `CancelHook<Req>` for ALL request
+/// types, enabling type inference at the point of use.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoCancelHook;
+
+impl<Req> CancelHook<Req> for NoCancelHook {}
+
+/// Cancellation hook backed by a closure called with the cancelled request.
+pub struct FnCancelHook<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for FnCancelHook<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> FnCancelHook<F> {
+    /// Create a new cancellation hook from the given closure.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Req, F> CancelHook<Req> for FnCancelHook<F>
+where
+    F: Fn(&Req) + Send + Sync + 'static,
+{
+    fn on_cancel(&self, req: &Req) {
+        (self.f)(req)
+    }
+}
+
+/// Cancellation hook that attaches a [`CancellationToken`] to each attempt's
+/// request using a closure.
+pub struct PropagateCancellation<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for PropagateCancellation<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> PropagateCancellation<F> {
+    /// Create a new hook that attaches tokens using the given closure.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Req, F> CancelHook<Req> for PropagateCancellation<F>
+where
+    F: Fn(&mut Req, CancellationToken) + Send + Sync + 'static,
+{
+    fn attach(&self, req: &mut Req, token: &CancellationToken) {
+        (self.f)(req, token.clone())
+    }
+}
+
+/// Cancellation hook that runs two hooks in turn.
+///
+/// The builder chains each configured hook onto the ones before it, so
+/// [`on_cancel`](crate::HedgeConfigBuilder::on_cancel) and
+/// [`propagate_cancellation`](crate::HedgeConfigBuilder::propagate_cancellation)
+/// can be combined.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChainCancelHook<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> ChainCancelHook<A, B> {
+    /// Create a hook that runs `first` and then `second`.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<Req, A, B> CancelHook<Req> for ChainCancelHook<A, B>
+where
+    A: CancelHook<Req>,
+    B: CancelHook<Req>,
+{
+    fn attach(&self, req: &mut Req, token: &CancellationToken) {
+        self.first.attach(req, token);
+        self.second.attach(req, token);
+    }
+
+    fn on_cancel(&self, req: &Req) {
+        self.first.on_cancel(req);
+        self.second.on_cancel(req);
+    }
+}
@@ -1,9 +1,16 @@
 //! Configuration for the hedging middleware.
 
+use crate::budget::HedgeBudget;
+use crate::cancel::{ChainCancelHook, FnCancelHook, NoCancelHook, PropagateCancellation};
+use crate::decorate::{DecorateHedge, NoDecorator};
 use crate::events::HedgeEvent;
+use crate::latency::LatencyTracker;
 use crate::layer::HedgeLayer;
+use crate::saturation::SaturationSignal;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
 use tower_resilience_core::{EventListener, EventListeners};
 
 /// Delay strategy for hedged requests.
@@ -15,15 +22,31 @@ pub enum HedgeDelay {
     Immediate,
     /// Dynamic delay based on attempt number.
     Dynamic(Arc<dyn Fn(usize) -> Duration + Send + Sync>),
+    /// Delay derived from a quantile of recently observed latencies.
+    ///
+    /// No hedges are fired until the tracker has collected enough samples.
+    Quantile(Arc<LatencyTracker>),
 }
 
 impl HedgeDelay {
     /// Get the delay for the given attempt number (1-indexed).
+    ///
+    /// Returns `None` if no hedge should be fired.
     pub fn get_delay(&self, attempt: usize) -> Option<Duration> {
         match self {
             HedgeDelay::Fixed(d) => Some(*d),
             HedgeDelay::Immediate => Some(Duration::ZERO),
             HedgeDelay::Dynamic(f) => Some(f(attempt)),
+            HedgeDelay::Quantile(tracker) => tracker.current_delay(),
+        }
+    }
+
+    /// Record the latency of a successful attempt.
+    ///
+    /// Only quantile-based delays make use of observed latencies.
+    pub(crate) fn record(&self, latency: Duration) {
+        if let HedgeDelay::Quantile(tracker) = self {
+            tracker.record(latency);
         }
     }
 }
@@ -34,21 +57,79 @@ impl Default for HedgeDelay {
     }
 }
 
+/// Policy for selecting which attempt's result is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionPolicy {
+    /// Return the first successful response (default).
+    ///
+    /// Errors are only returned once every attempt has failed.
+    #[default]
+    FirstSuccess,
+    /// Prefer the primary's response to reduce result skew.
+    ///
+    /// If a hedge succeeds while the primary is still in flight, wait up to
+    /// `grace` for the primary before returning the hedge's response. If the
+    /// primary fails or the grace period elapses, the hedge's response is
+    /// returned.
+    PreferPrimary {
+        /// How long to wait for the primary after a hedge succeeds.
+        grace: Duration,
+    },
+    /// Return the first completed result, whether success or error.
+    ///
+    /// Use this for services where any deterministic result is acceptable,
+    /// e.g. a "not found" error is as good as a response.
+    FastestIncludingErrors,
+}
+
 /// Configuration for the hedging service.
 ///
-/// This configuration is type-agnostic - it doesn't depend on the request,
-/// response, or error types. Types are only constrained when the layer is
-/// applied to a service.
+/// This configuration is type-agnostic by default - it doesn't depend on the
+/// request, response, or error types. Types are only constrained when the
+/// layer is applied to a service.
+///
+/// The type parameter `H` is the cancellation hook type:
+/// - `HedgeConfig<NoCancelHook>` - no hook (works with any request type)
+/// - `HedgeConfig<ChainCancelHook<H, FnCancelHook<F>>>` - closure called for
+///   cancelled attempts, after the hooks in `H`
+/// - `HedgeConfig<ChainCancelHook<H, PropagateCancellation<F>>>` - token
+///   attached to each request, after the hooks in `H`
+///
+/// The type parameter `D` is the hedge request decorator, which defaults to
+/// [`NoDecorator`] and becomes [`DecorateHedge<F>`](DecorateHedge) once
+/// [`decorate_hedge`](HedgeConfigBuilder::decorate_hedge) is configured.
 #[derive(Clone)]
-pub struct HedgeConfig {
+pub struct HedgeConfig<H = NoCancelHook, D = NoDecorator> {
     /// Name for metrics/tracing.
     pub(crate) name: Option<String>,
     /// Maximum number of hedged attempts (including original).
     pub(crate) max_hedged_attempts: usize,
     /// Delay before firing each hedge.
     pub(crate) delay: HedgeDelay,
+    /// Budget limiting the ratio of hedged requests.
+    pub(crate) budget: Option<Arc<HedgeBudget>>,
+    /// Policy for selecting the returned result.
+    pub(crate) selection_policy: SelectionPolicy,
+    /// Global cap on outstanding hedge attempts, shared across calls.
+    pub(crate) max_outstanding: Option<Arc<Semaphore>>,
+    /// Only fire hedges when the inner service is immediately ready.
+    pub(crate) require_ready: bool,
+    /// Signal that disables hedging while the backend is saturated.
+    pub(crate) saturation: Option<Arc<SaturationSignal>>,
     /// Event listeners.
     pub(crate) listeners: EventListeners<HedgeEvent>,
+    /// Hook invoked for cancelled attempts.
+    pub(crate) cancel_hook: H,
+    /// Rewrites each hedge's request before it is dispatched.
+    pub(crate) decorator: D,
+}
+
+impl<H, D> HedgeConfig<H, D> {
+    /// Name used for metrics labels and events.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn metric_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| "hedge".to_string())
+    }
 }
 
 impl Default for HedgeConfig {
@@ -57,7 +138,14 @@ impl Default for HedgeConfig {
             name: None,
             max_hedged_attempts: 2,
             delay: HedgeDelay::default(),
+            budget: None,
+            selection_policy: SelectionPolicy::default(),
+            max_outstanding: None,
+            require_ready: false,
+            saturation: None,
             listeners: EventListeners::default(),
+            cancel_hook: NoCancelHook,
+            decorator: NoDecorator,
         }
     }
 }
@@ -65,6 +153,8 @@ impl Default for HedgeConfig {
 /// Builder for [`HedgeConfig`].
 ///
 /// No type parameters needed - types are inferred when the layer is applied to a service.
+/// The type parameters `H` and `D` only change when a cancellation hook or a
+/// hedge decorator is configured.
 ///
 /// # Example
 ///
@@ -78,8 +168,8 @@ impl Default for HedgeConfig {
 ///     .max_hedged_attempts(3)
 ///     .build();
 /// ```
-pub struct HedgeConfigBuilder {
-    config: HedgeConfig,
+pub struct HedgeConfigBuilder<H = NoCancelHook, D = NoDecorator> {
+    config: HedgeConfig<H, D>,
 }
 
 impl Default for HedgeConfigBuilder {
@@ -95,7 +185,9 @@ impl HedgeConfigBuilder {
             config: HedgeConfig::default(),
         }
     }
+}
 
+impl<H, D> HedgeConfigBuilder<H, D> {
     /// Set the name for this hedge instance (used in metrics/tracing).
     pub fn name(mut self, name: impl Into<String>) -> Self {
         self.config.name = Some(name.into());
@@ -191,6 +283,169 @@ impl HedgeConfigBuilder {
         self
     }
 
+    /// Derive the hedge delay from a quantile of recently observed latencies.
+    ///
+    /// Hedges fire once a request has been outstanding longer than the given
+    /// quantile (e.g. `0.95` for p95) of recent successful calls. Hedging is
+    /// disabled until 20 samples have been collected; use
+    /// [`delay_from_latency`](Self::delay_from_latency) to tune the window and
+    /// minimum sample count.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::HedgeLayer;
+    ///
+    /// // Hedge requests slower than the observed p95
+    /// let layer = HedgeLayer::builder()
+    ///     .delay_quantile(0.95)
+    ///     .build();
+    /// ```
+    pub fn delay_quantile(self, quantile: f64) -> Self {
+        self.delay_from_latency(LatencyTracker::new(quantile))
+    }
+
+    /// Derive the hedge delay from a custom [`LatencyTracker`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::{HedgeLayer, LatencyTracker};
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay_from_latency(LatencyTracker::new(0.95).min_samples(100))
+    ///     .build();
+    /// ```
+    pub fn delay_from_latency(mut self, tracker: LatencyTracker) -> Self {
+        self.config.delay = HedgeDelay::Quantile(Arc::new(tracker));
+        self
+    }
+
+    /// Cap hedged requests at the given ratio of total requests.
+    ///
+    /// For example, `0.05` allows at most one hedge per 20 requests on
+    /// average, so a latency regression cannot double the load on the
+    /// backend. Hedges blocked by the budget emit
+    /// [`HedgeEvent::HedgeSuppressed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::HedgeLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .max_hedge_ratio(0.05)
+    ///     .build();
+    /// ```
+    pub fn max_hedge_ratio(self, ratio: f64) -> Self {
+        self.hedge_budget(HedgeBudget::new(ratio))
+    }
+
+    /// Set a custom [`HedgeBudget`].
+    ///
+    /// The budget is shared across all services created from this layer.
+    pub fn hedge_budget(mut self, budget: HedgeBudget) -> Self {
+        self.config.budget = Some(Arc::new(budget));
+        self
+    }
+
+    /// Cap the number of hedge attempts in flight at once.
+    ///
+    /// The cap is shared across all calls through services created from this
+    /// layer. Primary requests are not counted. Hedges beyond the cap are not
+    /// fired and emit [`HedgeEvent::HedgeSuppressed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::HedgeLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .max_outstanding_hedges(10)
+    ///     .build();
+    /// ```
+    pub fn max_outstanding_hedges(mut self, max: usize) -> Self {
+        self.config.max_outstanding = Some(Arc::new(Semaphore::new(max)));
+        self
+    }
+
+    /// Only fire hedges when the inner service is immediately ready.
+    ///
+    /// By default, a hedge attempt waits for the inner service to become
+    /// ready, which can queue extra work on a saturated pool-based client.
+    /// When enabled, readiness is acquired before the hedge is fired and the
+    /// hedge is suppressed if the inner service is not ready.
+    ///
+    /// Default is `false`.
+    pub fn require_ready_hedges(mut self, enabled: bool) -> Self {
+        self.config.require_ready = enabled;
+        self
+    }
+
+    /// Disable hedging while the backend is saturated.
+    ///
+    /// The closure is polled before each hedge would fire. While it returns
+    /// `true`, hedges are not fired and emit [`HedgeEvent::HedgeSuppressed`]
+    /// with [`SuppressionReason::Saturated`](crate::SuppressionReason::Saturated),
+    /// so hedging does not amplify load during a partial outage.
+    /// [`HedgeEvent::HedgingDisabled`] and [`HedgeEvent::HedgingEnabled`]
+    /// are emitted when the reported state changes.
+    ///
+    /// Typical signals are the backend's recent error rate or the in-flight
+    /// count of an adaptive concurrency limiter in front of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::HedgeLayer;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// // Updated by the client as requests start and finish
+    /// let in_flight = Arc::new(AtomicUsize::new(0));
+    /// let gauge = Arc::clone(&in_flight);
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .disable_when_saturated(move || gauge.load(Ordering::Relaxed) > 100)
+    ///     .build();
+    /// ```
+    pub fn disable_when_saturated<F>(mut self, saturated: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.config.saturation = Some(Arc::new(SaturationSignal::new(Arc::new(saturated))));
+        self
+    }
+
+    /// Set the policy for selecting which attempt's result is returned.
+    ///
+    /// Default is [`SelectionPolicy::FirstSuccess`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::{HedgeLayer, SelectionPolicy};
+    /// use std::time::Duration;
+    ///
+    /// // Give the primary 10ms to catch up after a hedge wins
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .selection_policy(SelectionPolicy::PreferPrimary {
+    ///         grace: Duration::from_millis(10),
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn selection_policy(mut self, policy: SelectionPolicy) -> Self {
+        self.config.selection_policy = policy;
+        self
+    }
+
     /// Add an event listener for hedge events.
     ///
     /// # Example
@@ -218,8 +473,187 @@ impl HedgeConfigBuilder {
         self
     }
 
+    /// Set a closure called for each in-flight attempt that is cancelled.
+    ///
+    /// Losing attempts are aborted when another attempt wins, or when the
+    /// hedged call itself is dropped. Types are inferred from the closure.
+    /// Runs after any cancellation hooks configured earlier.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::HedgeLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .on_cancel(|req: &String| {
+    ///         println!("cancelled attempt for {}", req);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_cancel<Req, F>(
+        self,
+        f: F,
+    ) -> HedgeConfigBuilder<ChainCancelHook<H, FnCancelHook<F>>, D>
+    where
+        F: Fn(&Req) + Send + Sync + 'static,
+    {
+        self.cancel_hook(FnCancelHook::new(f))
+    }
+
+    /// Attach a [`CancellationToken`] to each attempt's request.
+    ///
+    /// The token is cancelled if the attempt loses or the hedged call is
+    /// dropped, letting the inner client abort its transport (e.g. abort
+    /// an in-flight HTTP request). Types are inferred from the closure.
+    /// Runs after any cancellation hooks configured earlier.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::{CancellationToken, HedgeLayer};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone)]
+    /// struct MyRequest {
+    ///     path: String,
+    ///     cancel: Option<CancellationToken>,
+    /// }
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .propagate_cancellation(|req: &mut MyRequest, token| {
+    ///         req.cancel = Some(token);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn propagate_cancellation<Req, F>(
+        self,
+        f: F,
+    ) -> HedgeConfigBuilder<ChainCancelHook<H, PropagateCancellation<F>>, D>
+    where
+        F: Fn(&mut Req, CancellationToken) + Send + Sync + 'static,
+    {
+        self.cancel_hook(PropagateCancellation::new(f))
+    }
+
+    /// Rewrite each hedge's copy of the request before it is dispatched.
+    ///
+    /// The closure receives the request and the hedge's attempt number (1 for
+    /// the first hedge), letting servers recognize redundant work, e.g. by
+    /// adding a `hedge-attempt` header or lowering the request's priority.
+    /// The primary request is dispatched unchanged. Types are inferred from
+    /// the closure. Cancellation hooks see the decorated request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_hedge::HedgeLayer;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone)]
+    /// struct MyRequest {
+    ///     path: String,
+    ///     hedge_attempt: Option<usize>,
+    /// }
+    ///
+    /// let layer = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .decorate_hedge(|mut req: MyRequest, attempt| {
+    ///         req.hedge_attempt = Some(attempt);
+    ///         req
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn decorate_hedge<Req, F>(self, f: F) -> HedgeConfigBuilder<H, DecorateHedge<F>>
+    where
+        F: Fn(Req, usize) -> Req + Send + Sync + 'static,
+    {
+        let HedgeConfig {
+            name,
+            max_hedged_attempts,
+            delay,
+            budget,
+            selection_policy,
+            max_outstanding,
+            require_ready,
+            saturation,
+            listeners,
+            cancel_hook,
+            decorator: _,
+        } = self.config;
+
+        HedgeConfigBuilder {
+            config: HedgeConfig {
+                name,
+                max_hedged_attempts,
+                delay,
+                budget,
+                selection_policy,
+                max_outstanding,
+                require_ready,
+                saturation,
+                listeners,
+                cancel_hook,
+                decorator: DecorateHedge::new(f),
+            },
+        }
+    }
+
+    /// Add a custom [`CancelHook`](crate::CancelHook).
+    ///
+    /// Use this to both attach tokens and observe cancellations with a
+    /// single hook. Runs after any cancellation hooks configured earlier.
+    pub fn cancel_hook<H2>(self, hook: H2) -> HedgeConfigBuilder<ChainCancelHook<H, H2>, D> {
+        self.map_cancel_hook(|current| ChainCancelHook::new(current, hook))
+    }
+
+    /// Replace the cancellation hook with one derived from the current hook.
+    fn map_cancel_hook<H2>(self, f: impl FnOnce(H) -> H2) -> HedgeConfigBuilder<H2, D> {
+        let HedgeConfig {
+            name,
+            max_hedged_attempts,
+            delay,
+            budget,
+            selection_policy,
+            max_outstanding,
+            require_ready,
+            saturation,
+            listeners,
+            cancel_hook,
+            decorator,
+        } = self.config;
+
+        HedgeConfigBuilder {
+            config: HedgeConfig {
+                name,
+                max_hedged_attempts,
+                delay,
+                budget,
+                selection_policy,
+                max_outstanding,
+                require_ready,
+                saturation,
+                listeners,
+                cancel_hook: f(cancel_hook),
+                decorator,
+            },
+        }
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.config
+            .listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Build the [`HedgeLayer`].
-    pub fn build(self) -> HedgeLayer {
+    pub fn build(self) -> HedgeLayer<H, D> {
         HedgeLayer::from_config(self.config)
     }
 }
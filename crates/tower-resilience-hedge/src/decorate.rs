@@ -0,0 +1,53 @@
+//! Rewriting of hedge requests before they are dispatched.
+
+use std::sync::Arc;
+
+/// Rewrites each hedge's copy of the request before it is dispatched.
+pub trait HedgeDecorator<Req>: Send + Sync {
+    /// Called with each hedge's copy of the request, where `attempt` is 1
+    /// for the first hedge. The primary request is dispatched unchanged.
+    fn decorate(&self, req: Req, attempt: usize) -> Req;
+}
+
+/// No decorator; hedges are dispatched with the original request.
+///
+/// This is the default decorator. It implements `HedgeDecorator<Req>` for ALL
+/// request types, enabling type inference at the point of use.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoDecorator;
+
+impl<Req> HedgeDecorator<Req> for NoDecorator {
+    fn decorate(&self, req: Req, _attempt: usize) -> Req {
+        req
+    }
+}
+
+/// Decorator backed by a closure called with each hedge's request and
+/// attempt number.
+pub struct DecorateHedge<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for DecorateHedge<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> DecorateHedge<F> {
+    /// Create a new decorator from the given closure.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Req, F> HedgeDecorator<Req> for DecorateHedge<F>
+where
+    F: Fn(Req, usize) -> Req + Send + Sync + 'static,
+{
+    fn decorate(&self, req: Req, attempt: usize) -> Req {
+        (self.f)(req, attempt)
+    }
+}
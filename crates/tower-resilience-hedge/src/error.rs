@@ -1,6 +1,7 @@
 //! Error types for the hedging middleware.
 
 use std::fmt;
+use tower_resilience_core::PatternError;
 
 /// Error type for the hedging service.
 #[derive(Debug, Clone)]
@@ -10,6 +11,9 @@ pub enum HedgeError<E> {
     /// Contains the error from the primary request.
     AllAttemptsFailed(E),
 
+    /// Every attempt panicked or was dropped before reporting a result.
+    AttemptPanicked,
+
     /// Error from the inner service.
     Inner(E),
 }
@@ -20,6 +24,9 @@ impl<E: fmt::Display> fmt::Display for HedgeError<E> {
             HedgeError::AllAttemptsFailed(e) => {
                 write!(f, "all hedged attempts failed: {}", e)
             }
+            HedgeError::AttemptPanicked => {
+                write!(f, "hedged attempts ended without a result")
+            }
             HedgeError::Inner(e) => write!(f, "{}", e),
         }
     }
@@ -29,35 +36,63 @@ impl<E: std::error::Error + 'static> std::error::Error for HedgeError<E> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             HedgeError::AllAttemptsFailed(e) => Some(e),
+            HedgeError::AttemptPanicked => None,
             HedgeError::Inner(e) => Some(e),
         }
     }
 }
 
+impl<E> PatternError for HedgeError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        false
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            HedgeError::AllAttemptsFailed(e) | HedgeError::Inner(e) => Some(e),
+            HedgeError::AttemptPanicked => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            HedgeError::AllAttemptsFailed(e) | HedgeError::Inner(e) => Ok(e),
+            HedgeError::AttemptPanicked => Err(self),
+        }
+    }
+}
+
 impl<E> HedgeError<E> {
     /// Returns `true` if all hedged attempts failed.
     pub fn is_all_attempts_failed(&self) -> bool {
         matches!(self, HedgeError::AllAttemptsFailed(_))
     }
 
+    /// Returns `true` if every attempt ended without a result.
+    pub fn is_attempt_panicked(&self) -> bool {
+        matches!(self, HedgeError::AttemptPanicked)
+    }
+
     /// Returns `true` if this is an inner service error.
     pub fn is_inner(&self) -> bool {
         matches!(self, HedgeError::Inner(_))
     }
 
-    /// Get a reference to the inner error.
-    pub fn inner(&self) -> &E {
+    /// Get a reference to the inner error, if any.
+    pub fn inner(&self) -> Option<&E> {
         match self {
-            HedgeError::AllAttemptsFailed(e) => e,
-            HedgeError::Inner(e) => e,
+            HedgeError::AllAttemptsFailed(e) | HedgeError::Inner(e) => Some(e),
+            HedgeError::AttemptPanicked => None,
         }
     }
 
-    /// Convert into the inner error.
-    pub fn into_inner(self) -> E {
+    /// Convert into the inner error, if any.
+    pub fn into_inner(self) -> Option<E> {
         match self {
-            HedgeError::AllAttemptsFailed(e) => e,
-            HedgeError::Inner(e) => e,
+            HedgeError::AllAttemptsFailed(e) | HedgeError::Inner(e) => Some(e),
+            HedgeError::AttemptPanicked => None,
         }
     }
 }
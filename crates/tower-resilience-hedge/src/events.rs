@@ -3,14 +3,47 @@
 use std::time::{Duration, Instant};
 use tower_resilience_core::ResilienceEvent;
 
+/// Why a hedge attempt was not fired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SuppressionReason {
+    /// The hedge budget was exhausted.
+    Budget,
+    /// The global cap on outstanding hedges was reached.
+    MaxOutstanding,
+    /// The inner service was not immediately ready.
+    NotReady,
+    /// The backend was reported as saturated.
+    Saturated,
+}
+
+impl SuppressionReason {
+    /// Returns the reason as a static string (for metrics labels).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuppressionReason::Budget => "budget",
+            SuppressionReason::MaxOutstanding => "max_outstanding",
+            SuppressionReason::NotReady => "not_ready",
+            SuppressionReason::Saturated => "saturated",
+        }
+    }
+}
+
 /// Events emitted during hedge execution.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum HedgeEvent {
     /// Primary request started.
     PrimaryStarted {
         /// Name of the hedge instance.
         name: Option<String>,
         /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
@@ -23,6 +56,51 @@ pub enum HedgeEvent {
         /// Delay that elapsed before this hedge was fired.
         delay: Duration,
         /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+
+    /// A hedge attempt was not fired.
+    HedgeSuppressed {
+        /// Name of the hedge instance.
+        name: Option<String>,
+        /// Which hedge attempt was suppressed (1-indexed).
+        attempt: usize,
+        /// Why the hedge was suppressed.
+        reason: SuppressionReason,
+        /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+
+    /// The backend was reported as saturated; hedges are suppressed until it
+    /// recovers.
+    HedgingDisabled {
+        /// Name of the hedge instance.
+        name: Option<String>,
+        /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+
+    /// The backend is no longer reported as saturated; hedges fire again.
+    HedgingEnabled {
+        /// Name of the hedge instance.
+        name: Option<String>,
+        /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
@@ -35,6 +113,10 @@ pub enum HedgeEvent {
         /// Number of hedge requests that were cancelled.
         hedges_cancelled: usize,
         /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
@@ -49,16 +131,29 @@ pub enum HedgeEvent {
         /// Whether the primary request was cancelled.
         primary_cancelled: bool,
         /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 
     /// All attempts (primary and hedges) failed.
+    ///
+    /// Under [`SelectionPolicy::FastestIncludingErrors`] this is emitted
+    /// when the first error to arrive is returned.
+    ///
+    /// [`SelectionPolicy::FastestIncludingErrors`]: crate::SelectionPolicy::FastestIncludingErrors
     AllFailed {
         /// Name of the hedge instance.
         name: Option<String>,
         /// Total number of attempts made.
         attempts: usize,
         /// When this event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
 }
@@ -68,6 +163,9 @@ impl ResilienceEvent for HedgeEvent {
         match self {
             HedgeEvent::PrimaryStarted { .. } => "primary_started",
             HedgeEvent::HedgeStarted { .. } => "hedge_started",
+            HedgeEvent::HedgeSuppressed { .. } => "hedge_suppressed",
+            HedgeEvent::HedgingDisabled { .. } => "hedging_disabled",
+            HedgeEvent::HedgingEnabled { .. } => "hedging_enabled",
             HedgeEvent::PrimarySucceeded { .. } => "primary_succeeded",
             HedgeEvent::HedgeSucceeded { .. } => "hedge_succeeded",
             HedgeEvent::AllFailed { .. } => "all_failed",
@@ -78,6 +176,9 @@ impl ResilienceEvent for HedgeEvent {
         match self {
             HedgeEvent::PrimaryStarted { timestamp, .. } => *timestamp,
             HedgeEvent::HedgeStarted { timestamp, .. } => *timestamp,
+            HedgeEvent::HedgeSuppressed { timestamp, .. } => *timestamp,
+            HedgeEvent::HedgingDisabled { timestamp, .. } => *timestamp,
+            HedgeEvent::HedgingEnabled { timestamp, .. } => *timestamp,
             HedgeEvent::PrimarySucceeded { timestamp, .. } => *timestamp,
             HedgeEvent::HedgeSucceeded { timestamp, .. } => *timestamp,
             HedgeEvent::AllFailed { timestamp, .. } => *timestamp,
@@ -88,6 +189,9 @@ impl ResilienceEvent for HedgeEvent {
         match self {
             HedgeEvent::PrimaryStarted { name, .. } => name.as_deref().unwrap_or("hedge"),
             HedgeEvent::HedgeStarted { name, .. } => name.as_deref().unwrap_or("hedge"),
+            HedgeEvent::HedgeSuppressed { name, .. } => name.as_deref().unwrap_or("hedge"),
+            HedgeEvent::HedgingDisabled { name, .. } => name.as_deref().unwrap_or("hedge"),
+            HedgeEvent::HedgingEnabled { name, .. } => name.as_deref().unwrap_or("hedge"),
             HedgeEvent::PrimarySucceeded { name, .. } => name.as_deref().unwrap_or("hedge"),
             HedgeEvent::HedgeSucceeded { name, .. } => name.as_deref().unwrap_or("hedge"),
             HedgeEvent::AllFailed { name, .. } => name.as_deref().unwrap_or("hedge"),
@@ -0,0 +1,164 @@
+//! Latency tracking for quantile-driven hedge delays.
+//!
+//! Implements the approach from "The Tail at Scale": rather than hedging
+//! after a fixed delay, hedge once a request has been outstanding longer
+//! than the observed p95 (or another configurable quantile) of recent calls.
+
+use std::time::Duration;
+use tower_resilience_core::LatencyWindow;
+
+/// Default number of recent samples kept for quantile estimation.
+const DEFAULT_WINDOW_SIZE: usize = 1000;
+
+/// Default number of samples required before hedging is enabled.
+const DEFAULT_MIN_SAMPLES: usize = 20;
+
+/// Floor on the produced delay. A zero delay would switch to parallel mode
+/// and fire every hedge at once.
+const MIN_DELAY_FLOOR: Duration = Duration::from_millis(1);
+
+/// Tracks a sliding window of recent call latencies and derives a hedge
+/// delay from a configurable quantile.
+///
+/// Until at least `min_samples` latencies have been recorded, no hedge
+/// delay is produced and requests are sent to the primary only. The
+/// produced delay never drops below `min_delay` (at least 1ms), so very
+/// fast backends do not trigger parallel mode.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_hedge::{HedgeLayer, LatencyTracker};
+///
+/// // Hedge at the observed p99 once 50 samples have been collected
+/// let layer = HedgeLayer::builder()
+///     .delay_from_latency(
+///         LatencyTracker::new(0.99)
+///             .min_samples(50)
+///             .window_size(500),
+///     )
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct LatencyTracker {
+    quantile: f64,
+    min_samples: usize,
+    min_delay: Duration,
+    samples: LatencyWindow,
+}
+
+impl LatencyTracker {
+    /// Create a tracker that produces delays at the given quantile.
+    ///
+    /// The quantile is clamped to `0.0..=1.0`. Defaults to a window of the
+    /// 1000 most recent samples, a minimum of 20 samples, and a minimum
+    /// delay of 1ms.
+    pub fn new(quantile: f64) -> Self {
+        Self {
+            quantile: quantile.clamp(0.0, 1.0),
+            min_samples: DEFAULT_MIN_SAMPLES,
+            min_delay: MIN_DELAY_FLOOR,
+            samples: LatencyWindow::new(DEFAULT_WINDOW_SIZE),
+        }
+    }
+
+    /// Set the minimum number of samples required before hedging is enabled.
+    pub fn min_samples(mut self, min_samples: usize) -> Self {
+        self.min_samples = min_samples.max(1);
+        self
+    }
+
+    /// Set the number of recent samples used for quantile estimation.
+    pub fn window_size(mut self, window_size: usize) -> Self {
+        self.samples = LatencyWindow::new(window_size);
+        self
+    }
+
+    /// Set the smallest delay this tracker will produce.
+    ///
+    /// Values below 1ms are raised to 1ms.
+    pub fn min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay.max(MIN_DELAY_FLOOR);
+        self
+    }
+
+    /// Returns the configured quantile.
+    pub fn quantile(&self) -> f64 {
+        self.quantile
+    }
+
+    /// Record an observed call latency.
+    pub fn record(&self, latency: Duration) {
+        self.samples.record(latency);
+    }
+
+    /// Returns the number of samples currently in the window.
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns the latency at the configured quantile, raised to the
+    /// minimum delay, or `None` if fewer than `min_samples` latencies have
+    /// been recorded.
+    pub fn current_delay(&self) -> Option<Duration> {
+        self.samples
+            .quantile(self.quantile, self.min_samples)
+            .map(|delay| delay.max(self.min_delay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_delay_until_min_samples() {
+        let tracker = LatencyTracker::new(0.95).min_samples(3);
+        tracker.record(Duration::from_millis(10));
+        tracker.record(Duration::from_millis(20));
+        assert_eq!(tracker.current_delay(), None);
+
+        tracker.record(Duration::from_millis(30));
+        assert!(tracker.current_delay().is_some());
+    }
+
+    #[test]
+    fn computes_quantile() {
+        let tracker = LatencyTracker::new(0.95).min_samples(1);
+        for ms in 1..=100 {
+            tracker.record(Duration::from_millis(ms));
+        }
+        assert_eq!(tracker.current_delay(), Some(Duration::from_millis(95)));
+
+        let median = LatencyTracker::new(0.5).min_samples(1);
+        for ms in 1..=100 {
+            median.record(Duration::from_millis(ms));
+        }
+        assert_eq!(median.current_delay(), Some(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn zero_quantile_is_clamped_to_min_delay() {
+        let tracker = LatencyTracker::new(0.95).min_samples(2);
+        tracker.record(Duration::ZERO);
+        tracker.record(Duration::ZERO);
+        assert_eq!(tracker.current_delay(), Some(Duration::from_millis(1)));
+
+        let tracker = LatencyTracker::new(0.95)
+            .min_samples(1)
+            .min_delay(Duration::from_millis(5));
+        tracker.record(Duration::ZERO);
+        assert_eq!(tracker.current_delay(), Some(Duration::from_millis(5)));
+    }
+
+    #[test]
+    fn window_evicts_oldest_samples() {
+        let tracker = LatencyTracker::new(1.0).min_samples(1).window_size(2);
+        tracker.record(Duration::from_millis(500));
+        tracker.record(Duration::from_millis(10));
+        tracker.record(Duration::from_millis(20));
+
+        assert_eq!(tracker.sample_count(), 2);
+        assert_eq!(tracker.current_delay(), Some(Duration::from_millis(20)));
+    }
+}
@@ -1,6 +1,9 @@
 //! Tower Layer implementation for hedging.
 
+use crate::cancel::NoCancelHook;
 use crate::config::{HedgeConfig, HedgeConfigBuilder};
+use crate::decorate::NoDecorator;
+use crate::replicas::HedgeAcross;
 use crate::Hedge;
 use std::time::Duration;
 use tower_layer::Layer;
@@ -8,6 +11,9 @@ use tower_layer::Layer;
 /// A Tower [`Layer`] that applies hedging to a service.
 ///
 /// No type parameters needed - types are inferred from the service.
+/// The type parameter `H` is the cancellation hook type, which defaults to
+/// [`NoCancelHook`], and `D` is the hedge request decorator, which defaults
+/// to [`NoDecorator`].
 ///
 /// See the [crate-level documentation](crate) for more details.
 ///
@@ -24,8 +30,8 @@ use tower_layer::Layer;
 ///     .build();
 /// ```
 #[derive(Clone)]
-pub struct HedgeLayer {
-    config: HedgeConfig,
+pub struct HedgeLayer<H = NoCancelHook, D = NoDecorator> {
+    config: HedgeConfig<H, D>,
 }
 
 impl HedgeLayer {
@@ -141,15 +147,64 @@ impl HedgeLayer {
     pub fn builder() -> HedgeConfigBuilder {
         HedgeConfigBuilder::new()
     }
+}
 
+impl<H, D> HedgeLayer<H, D> {
     /// Create a `HedgeLayer` from a configuration.
-    pub(crate) fn from_config(config: HedgeConfig) -> Self {
+    pub(crate) fn from_config(config: HedgeConfig<H, D>) -> Self {
         Self { config }
     }
 }
 
-impl<S> Layer<S> for HedgeLayer {
-    type Service = Hedge<S>;
+impl<H: Clone, D: Clone> HedgeLayer<H, D> {
+    /// Create a hedging service that spreads attempts across replicas.
+    ///
+    /// The primary attempt rotates across replicas in round-robin order and
+    /// each hedge is sent to the next replica rather than re-calling the
+    /// same one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `replicas` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower::service_fn;
+    /// use tower_resilience_hedge::HedgeLayer;
+    /// use std::time::Duration;
+    ///
+    /// let replica = |id: u32| service_fn(move |req: String| async move {
+    ///     Ok::<_, std::io::Error>(format!("{req} from replica {id}"))
+    /// });
+    ///
+    /// let service = HedgeLayer::builder()
+    ///     .delay(Duration::from_millis(50))
+    ///     .max_hedged_attempts(3)
+    ///     .build()
+    ///     .across(vec![replica(1), replica(2), replica(3)]);
+    /// ```
+    pub fn across<S>(&self, replicas: Vec<S>) -> HedgeAcross<S, H, D> {
+        HedgeAcross::round_robin(replicas, self.config.clone())
+    }
+
+    /// Create a hedging service that spreads attempts across weighted
+    /// replicas.
+    ///
+    /// The primary attempt is chosen by smooth weighted round-robin, so a
+    /// replica with weight 3 receives three times as many primaries as one
+    /// with weight 1. Hedges go to the remaining replicas, heaviest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `replicas` is empty or every weight is zero.
+    pub fn across_weighted<S>(&self, replicas: Vec<(S, u32)>) -> HedgeAcross<S, H, D> {
+        HedgeAcross::weighted(replicas, self.config.clone())
+    }
+}
+
+impl<S, H: Clone, D: Clone> Layer<S> for HedgeLayer<H, D> {
+    type Service = Hedge<S, H, D>;
 
     fn layer(&self, service: S) -> Self::Service {
         Hedge::new(service, self.config.clone())
@@ -54,6 +54,21 @@
 //!     .build();
 //! ```
 //!
+//! ## Quantile Mode
+//!
+//! Derive the delay from recently observed latencies, hedging only requests
+//! that are slower than, e.g., the p95 of recent calls. Hedging stays disabled
+//! until enough samples have been collected.
+//!
+//! ```rust,no_run
+//! use tower_resilience_hedge::{HedgeLayer, LatencyTracker};
+//!
+//! // Hedge at the observed p95 once 100 samples have been recorded
+//! let layer = HedgeLayer::builder()
+//!     .delay_from_latency(LatencyTracker::new(0.95).min_samples(100))
+//!     .build();
+//! ```
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -92,11 +107,146 @@
 //! # }
 //! ```
 //!
+//! # Hedge Budget
+//!
+//! A latency regression in the backend would otherwise cause nearly every
+//! request to be hedged. A hedge budget caps hedged requests at a ratio of
+//! total traffic:
+//!
+//! ```rust,no_run
+//! use tower_resilience_hedge::HedgeLayer;
+//! use std::time::Duration;
+//!
+//! // Hedge at most 5% of requests
+//! let layer = HedgeLayer::builder()
+//!     .delay(Duration::from_millis(50))
+//!     .max_hedge_ratio(0.05)
+//!     .build();
+//! ```
+//!
+//! # Backpressure
+//!
+//! Hedges add load to the inner service. For pool-based clients, two options
+//! keep hedging from overwhelming the pool:
+//!
+//! - [`max_outstanding_hedges`](HedgeConfigBuilder::max_outstanding_hedges) -
+//!   a global cap on in-flight hedge attempts, shared across calls
+//! - [`require_ready_hedges`](HedgeConfigBuilder::require_ready_hedges) -
+//!   only fire a hedge if the inner service is immediately ready
+//! - [`disable_when_saturated`](HedgeConfigBuilder::disable_when_saturated) -
+//!   stop hedging while a backend health signal reports saturation
+//!
+//! ```rust,no_run
+//! use tower_resilience_hedge::HedgeLayer;
+//! use std::time::Duration;
+//!
+//! let layer = HedgeLayer::builder()
+//!     .delay(Duration::from_millis(50))
+//!     .max_outstanding_hedges(16)
+//!     .require_ready_hedges(true)
+//!     .build();
+//! ```
+//!
+//! # Result Selection
+//!
+//! By default the first successful response is returned. A
+//! [`SelectionPolicy`] can instead prefer the primary's response for a short
+//! grace period (reducing result skew), or return the first completed result
+//! even if it is an error:
+//!
+//! ```rust,no_run
+//! use tower_resilience_hedge::{HedgeLayer, SelectionPolicy};
+//! use std::time::Duration;
+//!
+//! let layer = HedgeLayer::builder()
+//!     .delay(Duration::from_millis(50))
+//!     .selection_policy(SelectionPolicy::PreferPrimary {
+//!         grace: Duration::from_millis(5),
+//!     })
+//!     .build();
+//! ```
+//!
+//! # Replicas
+//!
+//! Instead of re-calling the same service, [`HedgeLayer::across`] sends each
+//! attempt to a different replica. The primary rotates across replicas in
+//! round-robin order; [`HedgeLayer::across_weighted`] distributes primaries
+//! by weight instead:
+//!
+//! ```rust,no_run
+//! use tower::service_fn;
+//! use tower_resilience_hedge::HedgeLayer;
+//! use std::time::Duration;
+//!
+//! # let replica = |_id: u32| service_fn(|req: String| async move { Ok::<_, std::io::Error>(req) });
+//! let service = HedgeLayer::builder()
+//!     .delay(Duration::from_millis(50))
+//!     .build()
+//!     .across_weighted(vec![(replica(1), 3), (replica(2), 1)]);
+//! ```
+//!
+//! # Marking Hedges
+//!
+//! [`decorate_hedge`](HedgeConfigBuilder::decorate_hedge) rewrites each
+//! hedge's request, so servers can deprioritize or deduplicate redundant work:
+//!
+//! ```rust,no_run
+//! use tower_resilience_hedge::HedgeLayer;
+//! use std::time::Duration;
+//!
+//! #[derive(Clone)]
+//! struct MyRequest {
+//!     low_priority: bool,
+//! }
+//!
+//! let layer = HedgeLayer::builder()
+//!     .delay(Duration::from_millis(50))
+//!     .decorate_hedge(|req: MyRequest, _attempt| MyRequest { low_priority: true, ..req })
+//!     .build();
+//! ```
+//!
 //! # Cancellation
 //!
-//! When one request succeeds, all other in-flight requests are cancelled
-//! by dropping their futures. This relies on the inner service supporting
-//! cooperative cancellation.
+//! When one request succeeds, all other in-flight attempts are aborted,
+//! dropping their futures. The same happens if the hedged call itself is
+//! dropped. Work the inner client has already handed off (e.g. a request on
+//! a pooled connection) may still run to completion, so two hooks are
+//! available to propagate cancellation further:
+//!
+//! - [`on_cancel`](HedgeConfigBuilder::on_cancel) - called with the request
+//!   of each cancelled attempt
+//! - [`propagate_cancellation`](HedgeConfigBuilder::propagate_cancellation) -
+//!   attaches a [`CancellationToken`] to each attempt's request (e.g. in HTTP
+//!   request extensions) that is cancelled when the attempt loses
+//!
+//! Both can be configured on the same layer; hooks run in the order added.
+//!
+//! ```rust,no_run
+//! use tower_resilience_hedge::{CancellationToken, HedgeLayer};
+//! use std::time::Duration;
+//!
+//! #[derive(Clone)]
+//! struct MyRequest {
+//!     cancel: Option<CancellationToken>,
+//! }
+//!
+//! let layer = HedgeLayer::builder()
+//!     .delay(Duration::from_millis(50))
+//!     .propagate_cancellation(|req: &mut MyRequest, token| {
+//!         req.cancel = Some(token);
+//!     })
+//!     .build();
+//! ```
+//!
+//! # Metrics
+//!
+//! With the `metrics` feature enabled, the following metrics are recorded,
+//! labeled by the hedge instance name (`hedge`):
+//!
+//! - `resilience_hedge_attempts_total{kind="primary"|"hedge"}` - attempts dispatched
+//! - `resilience_hedge_wins_total{winner="primary"|"hedge"}` - which attempt won
+//! - `resilience_hedge_suppressed_total{reason}` - hedges that were not fired
+//! - `resilience_hedge_latency_seconds{result="success"|"failure"}` - call duration
 //!
 //! # Type Requirements
 //!
@@ -113,21 +263,41 @@
 //! - Using a different resilience pattern like Retry which doesn't require
 //!   cloning requests
 
+mod budget;
+mod cancel;
 mod config;
+mod decorate;
 mod error;
 mod events;
+mod latency;
 mod layer;
+mod replicas;
+mod saturation;
 
-pub use config::{HedgeConfig, HedgeConfigBuilder, HedgeDelay};
+pub use budget::HedgeBudget;
+pub use cancel::{CancelHook, ChainCancelHook, FnCancelHook, NoCancelHook, PropagateCancellation};
+pub use config::{HedgeConfig, HedgeConfigBuilder, HedgeDelay, SelectionPolicy};
+pub use decorate::{DecorateHedge, HedgeDecorator, NoDecorator};
 pub use error::HedgeError;
-pub use events::HedgeEvent;
+pub use events::{HedgeEvent, SuppressionReason};
+pub use latency::LatencyTracker;
 pub use layer::HedgeLayer;
+pub use replicas::HedgeAcross;
+pub use tokio_util::sync::CancellationToken;
 
 use futures::future::BoxFuture;
+use futures::FutureExt;
+use saturation::Transition;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, OwnedSemaphorePermit};
+use tokio::task::AbortHandle;
 use tower::{Service, ServiceExt};
+use tower_resilience_core::Deadline;
+
+#[cfg(feature = "metrics")]
+use metrics::{counter, describe_counter, describe_histogram, histogram};
 
 /// Hedging service that wraps an inner service.
 ///
@@ -135,16 +305,21 @@ use tower::{Service, ServiceExt};
 /// It fires additional "hedge" requests after a configurable delay and returns
 /// whichever request completes first successfully.
 ///
-/// The type parameter is just the inner service type - request, response, and
-/// error types are derived from the service's associated types.
-pub struct Hedge<S> {
+/// The type parameter `S` is the inner service type - request, response, and
+/// error types are derived from the service's associated types. `H` is the
+/// cancellation hook type, which defaults to [`NoCancelHook`], and `D` is the
+/// hedge request decorator, which defaults to [`NoDecorator`].
+pub struct Hedge<S, H = NoCancelHook, D = NoDecorator> {
     inner: S,
-    config: Arc<HedgeConfig>,
+    config: Arc<HedgeConfig<H, D>>,
 }
 
-impl<S> Hedge<S> {
+impl<S, H, D> Hedge<S, H, D> {
     /// Create a new Hedge service with the given configuration.
-    pub fn new(inner: S, config: HedgeConfig) -> Self {
+    pub fn new(inner: S, config: HedgeConfig<H, D>) -> Self {
+        #[cfg(feature = "metrics")]
+        describe_metrics();
+
         Self {
             inner,
             config: Arc::new(config),
@@ -152,7 +327,28 @@ impl<S> Hedge<S> {
     }
 }
 
-impl<S: Clone> Clone for Hedge<S> {
+/// Register descriptions for the metrics emitted by hedged services.
+#[cfg(feature = "metrics")]
+fn describe_metrics() {
+    describe_counter!(
+        "resilience_hedge_attempts_total",
+        "Total number of attempts dispatched (primary and hedges)"
+    );
+    describe_counter!(
+        "resilience_hedge_wins_total",
+        "Total number of hedged calls won, by winning attempt type"
+    );
+    describe_counter!(
+        "resilience_hedge_suppressed_total",
+        "Total number of hedges suppressed by the hedge budget"
+    );
+    describe_histogram!(
+        "resilience_hedge_latency_seconds",
+        "Duration of hedged calls (successful or failed)"
+    );
+}
+
+impl<S: Clone, H, D> Clone for Hedge<S, H, D> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -161,13 +357,15 @@ impl<S: Clone> Clone for Hedge<S> {
     }
 }
 
-impl<S, Req> Service<Req> for Hedge<S>
+impl<S, H, D, Req> Service<Req> for Hedge<S, H, D>
 where
     S: Service<Req> + Clone + Send + 'static,
     S::Response: Send + Sync + 'static,
     S::Error: Clone + Send + Sync + 'static,
     S::Future: Send,
     Req: Clone + Send + Sync + 'static,
+    H: CancelHook<Req> + 'static,
+    D: HedgeDecorator<Req> + 'static,
 {
     type Response = S::Response;
     type Error = HedgeError<S::Error>;
@@ -183,15 +381,187 @@ where
         // Replace the clone we just made with the ready service
         let inner = std::mem::replace(&mut self.inner, inner);
 
-        Box::pin(async move { execute_with_hedging(inner, req, config).await })
+        // `inner` is the readied receiver moved out of `self.inner`. Clone
+        // for the hedge template *before* moving it into the primary spawn --
+        // each subsequent hedge spawn must drive `poll_ready` on its own
+        // clone before calling, since `Clone` does not propagate readiness
+        // for stateful services. See #293.
+        let hedge_template = inner.clone();
+
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span(
+            "hedge",
+            config.name.as_deref().unwrap_or("hedge"),
+        );
+
+        let future = async move {
+            execute_with_hedging(
+                inner,
+                true,
+                move |_attempt| hedge_template.clone(),
+                req,
+                config,
+            )
+            .await
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_result,
+        );
+
+        Box::pin(future)
+    }
+}
+
+/// Result of a single attempt, tagged with its attempt number.
+type AttemptResult<Res, E> = (usize, Result<Res, E>);
+
+/// Tracks in-flight attempts so losing attempts can be cancelled.
+///
+/// Any attempt that has not reported a result when this is dropped is
+/// cancelled: its token is cancelled, its task is aborted, and the cancel
+/// hook is invoked. This covers both a winning attempt and the hedged call
+/// itself being dropped by the caller.
+struct InFlight<Req, H, D>
+where
+    H: CancelHook<Req>,
+{
+    req: Req,
+    config: Arc<HedgeConfig<H, D>>,
+    /// Per attempt: its task, its token, and the request as sent.
+    attempts: Vec<Option<(AbortHandle, CancellationToken, Req)>>,
+}
+
+impl<Req, H, D> InFlight<Req, H, D>
+where
+    H: CancelHook<Req>,
+{
+    /// Returns `true` if the attempt has not yet reported a result.
+    fn is_pending(&self, attempt: usize) -> bool {
+        matches!(self.attempts.get(attempt), Some(Some(_)))
+    }
+
+    /// Returns the number of attempts that have not yet reported a result.
+    fn pending(&self) -> usize {
+        self.attempts.iter().filter(|a| a.is_some()).count()
+    }
+}
+
+impl<Req, H, D> InFlight<Req, H, D>
+where
+    Req: Clone + Send + 'static,
+    H: CancelHook<Req> + 'static,
+    D: HedgeDecorator<Req> + 'static,
+{
+    fn new(req: Req, config: Arc<HedgeConfig<H, D>>) -> Self {
+        let capacity = config.max_hedged_attempts;
+        Self {
+            req,
+            config,
+            attempts: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Spawn an attempt against `svc`.
+    ///
+    /// When `drive_ready` is set, `poll_ready` is driven on the service
+    /// before calling it. The permit, if any, is held until the attempt
+    /// completes or is aborted.
+    fn spawn<S>(
+        &mut self,
+        mut svc: S,
+        drive_ready: bool,
+        permit: Option<OwnedSemaphorePermit>,
+        tx: &mpsc::Sender<AttemptResult<S::Response, S::Error>>,
+    ) where
+        S: Service<Req> + Send + 'static,
+        S::Response: Send + 'static,
+        S::Error: Send + 'static,
+        S::Future: Send,
+    {
+        let attempt = self.attempts.len();
+
+        #[cfg(feature = "metrics")]
+        counter!(
+            "resilience_hedge_attempts_total",
+            "name" => self.config.metric_name(),
+            "kind" => if attempt == 0 { "primary" } else { "hedge" }
+        )
+        .increment(1);
+
+        let token = CancellationToken::new();
+        let mut req = self.req.clone();
+        if attempt > 0 {
+            req = self.config.decorator.decorate(req, attempt);
+        }
+        self.config.cancel_hook.attach(&mut req, &token);
+        let sent = req.clone();
+
+        let tx = tx.clone();
+        let config = Arc::clone(&self.config);
+        let task = async move {
+            let attempt_start = Instant::now();
+            let result = if drive_ready {
+                match svc.ready().await {
+                    Ok(svc) => svc.call(req).await,
+                    Err(e) => Err(e),
+                }
+            } else {
+                svc.call(req).await
+            };
+            if result.is_ok() {
+                config.delay.record(attempt_start.elapsed());
+            }
+            // Release the outstanding-hedge permit before reporting
+            drop(permit);
+            let _ = tx.send((attempt, result)).await;
+        };
+        // Spawned tasks do not inherit task-locals; carry the caller's
+        // deadline into the attempt so nested layers still see it.
+        let handle = match Deadline::current() {
+            Some(deadline) => tokio::spawn(deadline.scope(task)),
+            None => tokio::spawn(task),
+        };
+
+        self.attempts
+            .push(Some((handle.abort_handle(), token, sent)));
+    }
+
+    /// Mark an attempt as having reported its result.
+    fn complete(&mut self, attempt: usize) {
+        if let Some(slot) = self.attempts.get_mut(attempt) {
+            *slot = None;
+        }
+    }
+}
+
+impl<Req, H, D> Drop for InFlight<Req, H, D>
+where
+    H: CancelHook<Req>,
+{
+    fn drop(&mut self) {
+        for (handle, token, req) in self.attempts.drain(..).flatten() {
+            token.cancel();
+            handle.abort();
+            self.config.cancel_hook.on_cancel(&req);
+        }
     }
 }
 
 /// Execute the request with hedging strategy
-async fn execute_with_hedging<S, Req>(
-    service: S,
+///
+/// `primary` is the service for the primary attempt; `primary_ready` tells
+/// whether `poll_ready` has already been driven on it. `hedge_for` produces
+/// the (not yet readied) service for each hedge attempt.
+async fn execute_with_hedging<S, Req, H, D, F>(
+    primary: S,
+    primary_ready: bool,
+    hedge_for: F,
     req: Req,
-    config: Arc<HedgeConfig>,
+    config: Arc<HedgeConfig<H, D>>,
 ) -> Result<S::Response, HedgeError<S::Error>>
 where
     S: Service<Req> + Clone + Send + 'static,
@@ -199,9 +569,10 @@ where
     S::Error: Clone + Send + 'static,
     S::Future: Send,
     Req: Clone + Send + 'static,
+    H: CancelHook<Req> + 'static,
+    D: HedgeDecorator<Req> + 'static,
+    F: Fn(usize) -> S,
 {
-    use tokio::sync::mpsc;
-
     let max_attempts = config.max_hedged_attempts;
     let start = Instant::now();
 
@@ -211,161 +582,42 @@ where
         timestamp: Instant::now(),
     });
 
+    if let Some(budget) = &config.budget {
+        budget.deposit();
+    }
+
     // Channel to collect results from all attempts
-    let (tx, mut rx) = mpsc::channel::<(usize, Result<S::Response, S::Error>)>(max_attempts);
-
-    // `service` is the readied receiver moved out of `self.inner` by the
-    // calling `Service::call`. Clone for the hedge template *before* moving
-    // it into the primary spawn -- each subsequent hedge spawn must drive
-    // `poll_ready` on its own clone before calling, since `Clone` does not
-    // propagate readiness for stateful services. See #293.
-    let hedge_template = service.clone();
-
-    // Spawn primary request using the readied receiver directly.
-    let mut primary = service;
-    let req_clone = req.clone();
-    let tx_clone = tx.clone();
-    tokio::spawn(async move {
-        let result = primary.call(req_clone).await;
-        let _ = tx_clone.send((0, result)).await;
-    });
+    let (tx, mut rx) = mpsc::channel::<AttemptResult<S::Response, S::Error>>(max_attempts);
+    let mut in_flight = InFlight::new(req, Arc::clone(&config));
+
+    // Spawn the primary request, driving readiness first unless the caller
+    // already did.
+    in_flight.spawn(primary, !primary_ready, None, &tx);
 
-    // Track spawned hedge tasks
     let mut hedges_spawned: usize = 0;
+    let mut hedging_done = max_attempts <= 1;
     let mut primary_error: Option<S::Error> = None;
+    let mut hedge_error: Option<S::Error> = None;
+    // A successful hedge held back while waiting for the primary
+    // (`SelectionPolicy::PreferPrimary`).
+    let mut held: Option<(usize, S::Response)> = None;
 
     // Get delay for first hedge
-    let first_delay = config.delay.get_delay(1);
-
-    // If we have more attempts and there's a delay, set up hedge timing
-    if max_attempts > 1 {
-        match first_delay {
-            Some(delay) if delay > Duration::ZERO => {
-                // Latency mode: wait for delay or result
-                let mut delay_fut = std::pin::pin!(tokio::time::sleep(delay));
-
-                loop {
-                    tokio::select! {
-                        biased;
-
-                        // Check for results
-                        Some((attempt, result)) = rx.recv() => {
-                            match &result {
-                                Ok(_) => {
-                                    let duration = start.elapsed();
-                                    if attempt == 0 {
-                                        config.listeners.emit(&HedgeEvent::PrimarySucceeded {
-                                            name: config.name.clone(),
-                                            duration,
-                                            hedges_cancelled: hedges_spawned,
-                                            timestamp: Instant::now(),
-                                        });
-                                    } else {
-                                        config.listeners.emit(&HedgeEvent::HedgeSucceeded {
-                                            name: config.name.clone(),
-                                            attempt,
-                                            duration,
-                                            primary_cancelled: true,
-                                            timestamp: Instant::now(),
-                                        });
-                                    }
-                                    return result.map_err(HedgeError::Inner);
-                                }
-                                Err(e) => {
-                                    // Store error, continue waiting for other attempts
-                                    if attempt == 0 {
-                                        primary_error = Some(e.clone());
-                                    }
-                                    // Check if all attempts exhausted
-                                    if hedges_spawned + 1 >= max_attempts {
-                                        // All spawned, check if this was the last result
-                                        config.listeners.emit(&HedgeEvent::AllFailed {
-                                            name: config.name.clone(),
-                                            attempts: hedges_spawned + 1,
-                                            timestamp: Instant::now(),
-                                        });
-                                        return Err(HedgeError::AllAttemptsFailed(
-                                            primary_error.unwrap_or_else(|| e.clone())
-                                        ));
-                                    }
-                                }
-                            }
-                        }
-
-                        // Delay elapsed, spawn hedge
-                        _ = &mut delay_fut, if hedges_spawned + 1 < max_attempts => {
-                            hedges_spawned += 1;
-                            let attempt_num = hedges_spawned;
-
-                            config.listeners.emit(&HedgeEvent::HedgeStarted {
-                                name: config.name.clone(),
-                                attempt: attempt_num,
-                                delay,
-                                timestamp: Instant::now(),
-                            });
-
-                            let mut svc = hedge_template.clone();
-                            let r = req.clone();
-                            let tx_c = tx.clone();
-                            tokio::spawn(async move {
-                                // Drive poll_ready on the fresh clone before
-                                // calling; clones do not inherit readiness.
-                                let result = match svc.ready().await {
-                                    Ok(svc) => svc.call(r).await,
-                                    Err(e) => Err(e),
-                                };
-                                let _ = tx_c.send((attempt_num, result)).await;
-                            });
-
-                            // Set up next delay if more hedges available
-                            if hedges_spawned + 1 < max_attempts {
-                                if let Some(next_delay) = config.delay.get_delay(hedges_spawned + 1) {
-                                    delay_fut.set(tokio::time::sleep(next_delay));
-                                }
-                            }
-                        }
-
-                        else => {
-                            // No more hedges to spawn, just wait for results
-                            if let Some((attempt, result)) = rx.recv().await {
-                                match &result {
-                                    Ok(_) => {
-                                        let duration = start.elapsed();
-                                        if attempt == 0 {
-                                            config.listeners.emit(&HedgeEvent::PrimarySucceeded {
-                                                name: config.name.clone(),
-                                                duration,
-                                                hedges_cancelled: hedges_spawned,
-                                                timestamp: Instant::now(),
-                                            });
-                                        } else {
-                                            config.listeners.emit(&HedgeEvent::HedgeSucceeded {
-                                                name: config.name.clone(),
-                                                attempt,
-                                                duration,
-                                                primary_cancelled: attempt != 0,
-                                                timestamp: Instant::now(),
-                                            });
-                                        }
-                                        return result.map_err(HedgeError::Inner);
-                                    }
-                                    Err(e) => {
-                                        if attempt == 0 && primary_error.is_none() {
-                                            primary_error = Some(e.clone());
-                                        }
-                                    }
-                                }
-                            } else {
-                                // Channel closed, all senders dropped
-                                break;
-                            }
-                        }
-                    }
-                }
+    let mut delay = Duration::ZERO;
+    if !hedging_done {
+        match config.delay.get_delay(1) {
+            Some(d) if d > Duration::ZERO => delay = d,
+            None => {
+                // Hedging not enabled yet (e.g. too few latency samples);
+                // only the primary request is in flight.
+                hedging_done = true;
             }
-            _ => {
+            Some(_) => {
                 // Parallel mode: spawn all hedges immediately
                 for i in 1..max_attempts {
+                    let Some(slot) = acquire_hedge(&config, hedge_for(i), i) else {
+                        break;
+                    };
                     hedges_spawned += 1;
 
                     config.listeners.emit(&HedgeEvent::HedgeStarted {
@@ -375,72 +627,292 @@ where
                         timestamp: Instant::now(),
                     });
 
-                    let mut svc = hedge_template.clone();
-                    let r = req.clone();
-                    let tx_c = tx.clone();
-                    tokio::spawn(async move {
-                        // Drive poll_ready on the fresh clone before calling;
-                        // clones do not inherit readiness.
-                        let result = match svc.ready().await {
-                            Ok(svc) => svc.call(r).await,
-                            Err(e) => Err(e),
-                        };
-                        let _ = tx_c.send((i, result)).await;
-                    });
+                    in_flight.spawn(slot.svc, slot.drive_ready, slot.permit, &tx);
                 }
+                hedging_done = true;
             }
         }
     }
 
-    // Drop our sender so channel closes when all tasks complete
-    drop(tx);
+    let mut delay_fut = std::pin::pin!(tokio::time::sleep(delay));
+    let mut grace_fut = std::pin::pin!(tokio::time::sleep(Duration::ZERO));
+    let mut tx = Some(tx);
 
-    // Wait for first success or all failures
-    let mut attempts_received: usize = 0;
-    let total_attempts = hedges_spawned + 1;
+    loop {
+        // Once no more hedges will be fired, drop our sender so the channel
+        // closes if the remaining attempts end without reporting (e.g. panic).
+        if hedging_done {
+            tx = None;
+        }
 
-    while let Some((attempt, result)) = rx.recv().await {
-        attempts_received += 1;
+        tokio::select! {
+            biased;
 
-        match result {
-            Ok(res) => {
-                let duration = start.elapsed();
-                if attempt == 0 {
-                    config.listeners.emit(&HedgeEvent::PrimarySucceeded {
-                        name: config.name.clone(),
-                        duration,
-                        hedges_cancelled: hedges_spawned.saturating_sub(attempts_received - 1),
-                        timestamp: Instant::now(),
-                    });
-                } else {
-                    config.listeners.emit(&HedgeEvent::HedgeSucceeded {
-                        name: config.name.clone(),
-                        attempt,
-                        duration,
-                        primary_cancelled: true,
-                        timestamp: Instant::now(),
-                    });
+            // Check for results
+            result = rx.recv() => {
+                let Some((attempt, result)) = result else {
+                    // Every remaining attempt ended without a result
+                    if let Some((attempt, response)) = held.take() {
+                        emit_success(&config, &in_flight, attempt, start.elapsed());
+                        return Ok(response);
+                    }
+                    break;
+                };
+                in_flight.complete(attempt);
+                match result {
+                    Ok(response) => {
+                        if let SelectionPolicy::PreferPrimary { grace } = config.selection_policy {
+                            if attempt != 0 && in_flight.is_pending(0) {
+                                // Give the primary a chance to catch up; later
+                                // hedges never displace the first held one.
+                                if held.is_none() {
+                                    held = Some((attempt, response));
+                                    grace_fut.set(tokio::time::sleep(grace));
+                                }
+                                continue;
+                            }
+                        }
+                        emit_success(&config, &in_flight, attempt, start.elapsed());
+                        return Ok(response);
+                    }
+                    Err(e) => {
+                        if config.selection_policy == SelectionPolicy::FastestIncludingErrors {
+                            #[cfg(feature = "metrics")]
+                            histogram!("resilience_hedge_latency_seconds", "name" => config.metric_name(), "result" => "failure")
+                                .record(start.elapsed().as_secs_f64());
+
+                            config.listeners.emit(&HedgeEvent::AllFailed {
+                                name: config.name.clone(),
+                                attempts: hedges_spawned + 1,
+                                timestamp: Instant::now(),
+                            });
+
+                            return Err(HedgeError::Inner(e));
+                        }
+                        if attempt == 0 {
+                            primary_error = Some(e);
+                        } else if hedge_error.is_none() {
+                            hedge_error = Some(e);
+                        }
+                        // The primary failed while a hedge's response was held
+                        if let Some((attempt, response)) = held.take() {
+                            emit_success(&config, &in_flight, attempt, start.elapsed());
+                            return Ok(response);
+                        }
+                        // All attempts failed and no more will be fired
+                        if hedging_done && in_flight.pending() == 0 {
+                            break;
+                        }
+                    }
                 }
-                return Ok(res);
             }
-            Err(e) => {
-                if primary_error.is_none() {
-                    primary_error = Some(e);
+
+            // Grace period for the primary elapsed, return the held hedge
+            _ = &mut grace_fut, if held.is_some() => {
+                if let Some((attempt, response)) = held.take() {
+                    emit_success(&config, &in_flight, attempt, start.elapsed());
+                    return Ok(response);
+                }
+            }
+
+            // Delay elapsed, spawn hedge
+            _ = &mut delay_fut, if !hedging_done => {
+                let Some(slot) = acquire_hedge(&config, hedge_for(hedges_spawned + 1), hedges_spawned + 1) else {
+                    hedging_done = true;
+                    if in_flight.pending() == 0 {
+                        break;
+                    }
+                    continue;
+                };
+
+                hedges_spawned += 1;
+
+                config.listeners.emit(&HedgeEvent::HedgeStarted {
+                    name: config.name.clone(),
+                    attempt: hedges_spawned,
+                    delay,
+                    timestamp: Instant::now(),
+                });
+
+                if let Some(tx) = &tx {
+                    in_flight.spawn(slot.svc, slot.drive_ready, slot.permit, tx);
+                }
+
+                // Set up next delay if more hedges available
+                match config.delay.get_delay(hedges_spawned + 1) {
+                    Some(next_delay) if hedges_spawned + 1 < max_attempts => {
+                        delay = next_delay;
+                        delay_fut.set(tokio::time::sleep(next_delay));
+                    }
+                    _ => hedging_done = true,
                 }
             }
         }
     }
 
+    #[cfg(feature = "metrics")]
+    histogram!("resilience_hedge_latency_seconds", "name" => config.metric_name(), "result" => "failure")
+        .record(start.elapsed().as_secs_f64());
+
     // All attempts failed
     config.listeners.emit(&HedgeEvent::AllFailed {
         name: config.name.clone(),
-        attempts: total_attempts,
+        attempts: hedges_spawned + 1,
         timestamp: Instant::now(),
     });
 
-    Err(HedgeError::AllAttemptsFailed(
-        primary_error.expect("at least one error should exist"),
-    ))
+    // Attempts that panicked close the channel without reporting
+    match primary_error.or(hedge_error) {
+        Some(e) => Err(HedgeError::AllAttemptsFailed(e)),
+        None => Err(HedgeError::AttemptPanicked),
+    }
+}
+
+/// Emit the success event for the winning attempt.
+fn emit_success<Req, H, D>(
+    config: &HedgeConfig<H, D>,
+    in_flight: &InFlight<Req, H, D>,
+    attempt: usize,
+    duration: Duration,
+) where
+    H: CancelHook<Req>,
+{
+    #[cfg(feature = "metrics")]
+    {
+        let winner = if attempt == 0 { "primary" } else { "hedge" };
+        counter!("resilience_hedge_wins_total", "name" => config.metric_name(), "winner" => winner)
+            .increment(1);
+        histogram!("resilience_hedge_latency_seconds", "name" => config.metric_name(), "result" => "success")
+            .record(duration.as_secs_f64());
+    }
+
+    if attempt == 0 {
+        config.listeners.emit(&HedgeEvent::PrimarySucceeded {
+            name: config.name.clone(),
+            duration,
+            hedges_cancelled: in_flight.pending(),
+            timestamp: Instant::now(),
+        });
+    } else {
+        config.listeners.emit(&HedgeEvent::HedgeSucceeded {
+            name: config.name.clone(),
+            attempt,
+            duration,
+            primary_cancelled: in_flight.is_pending(0),
+            timestamp: Instant::now(),
+        });
+    }
+}
+
+/// A hedge attempt that passed all admission checks.
+struct HedgeSlot<S> {
+    /// Service clone to call.
+    svc: S,
+    /// Whether `poll_ready` still has to be driven before calling.
+    drive_ready: bool,
+    /// Permit held against the outstanding-hedge cap.
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+/// Decide whether hedge `attempt` may be fired.
+///
+/// Checks, in order, the saturation signal, the outstanding-hedge cap, inner
+/// readiness (when required), and the hedge budget. Emits
+/// [`HedgeEvent::HedgeSuppressed`] and returns `None` if any check fails.
+fn acquire_hedge<S, Req, H, D>(
+    config: &HedgeConfig<H, D>,
+    svc: S,
+    attempt: usize,
+) -> Option<HedgeSlot<S>>
+where
+    S: Service<Req>,
+{
+    if let Some(signal) = &config.saturation {
+        let (disabled, transition) = signal.check();
+        match transition {
+            Some(Transition::Disabled) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(hedge = ?config.name, "Backend saturated, hedging disabled");
+
+                config.listeners.emit(&HedgeEvent::HedgingDisabled {
+                    name: config.name.clone(),
+                    timestamp: Instant::now(),
+                });
+            }
+            Some(Transition::Enabled) => {
+                #[cfg(feature = "tracing")]
+                tracing::info!(hedge = ?config.name, "Backend recovered, hedging enabled");
+
+                config.listeners.emit(&HedgeEvent::HedgingEnabled {
+                    name: config.name.clone(),
+                    timestamp: Instant::now(),
+                });
+            }
+            None => {}
+        }
+        if disabled {
+            emit_suppressed(config, attempt, SuppressionReason::Saturated);
+            return None;
+        }
+    }
+
+    let permit = match &config.max_outstanding {
+        Some(semaphore) => match Arc::clone(semaphore).try_acquire_owned() {
+            Ok(permit) => Some(permit),
+            Err(_) => {
+                emit_suppressed(config, attempt, SuppressionReason::MaxOutstanding);
+                return None;
+            }
+        },
+        None => None,
+    };
+
+    let (svc, drive_ready) = if config.require_ready {
+        // Acquire readiness up front so the hedge is only fired if the
+        // inner service has capacity for it right now.
+        match svc.ready_oneshot().now_or_never() {
+            Some(Ok(svc)) => (svc, false),
+            _ => {
+                emit_suppressed(config, attempt, SuppressionReason::NotReady);
+                return None;
+            }
+        }
+    } else {
+        // Drive poll_ready on the fresh clone before calling; clones do not
+        // inherit readiness.
+        (svc, true)
+    };
+
+    if let Some(budget) = &config.budget {
+        if !budget.try_withdraw() {
+            emit_suppressed(config, attempt, SuppressionReason::Budget);
+            return None;
+        }
+    }
+
+    Some(HedgeSlot {
+        svc,
+        drive_ready,
+        permit,
+    })
+}
+
+/// Emit [`HedgeEvent::HedgeSuppressed`] for a hedge that was not fired.
+fn emit_suppressed<H, D>(config: &HedgeConfig<H, D>, attempt: usize, reason: SuppressionReason) {
+    #[cfg(feature = "metrics")]
+    counter!(
+        "resilience_hedge_suppressed_total",
+        "name" => config.metric_name(),
+        "reason" => reason.as_str()
+    )
+    .increment(1);
+
+    config.listeners.emit(&HedgeEvent::HedgeSuppressed {
+        name: config.name.clone(),
+        attempt,
+        reason,
+        timestamp: Instant::now(),
+    });
 }
 
 #[cfg(test)]
@@ -590,6 +1062,89 @@ mod tests {
         assert!(matches!(result, Err(HedgeError::AllAttemptsFailed(_))));
     }
 
+    #[tokio::test]
+    async fn test_panicking_attempt_does_not_hang() {
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let cc = Arc::clone(&call_count);
+
+        let service = tower::service_fn(move |_req: String| {
+            let count = cc.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if count == 0 {
+                    panic!("primary panicked");
+                }
+                Err::<String, _>(TestError)
+            }
+        });
+
+        let layer = HedgeLayer::builder()
+            .no_delay()
+            .max_hedged_attempts(2)
+            .build();
+
+        let mut service = layer.layer(service);
+        let future = service.ready().await.unwrap().call("test".to_string());
+
+        let result = tokio::time::timeout(Duration::from_secs(1), future)
+            .await
+            .expect("hedged call hung after an attempt panicked");
+        assert!(matches!(result, Err(HedgeError::AllAttemptsFailed(_))));
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_every_attempt_panicking_returns_error() {
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let cc = Arc::clone(&call_count);
+
+        let service = tower::service_fn(move |_req: String| {
+            cc.fetch_add(1, Ordering::SeqCst);
+            async move {
+                panic!("attempt panicked");
+                #[allow(unreachable_code)]
+                Err::<String, _>(TestError)
+            }
+        });
+
+        let layer = HedgeLayer::builder()
+            .no_delay()
+            .max_hedged_attempts(2)
+            .build();
+
+        let mut service = layer.layer(service);
+        let result = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await;
+        assert!(matches!(result, Err(HedgeError::AttemptPanicked)));
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_single_attempt_panicking_returns_error() {
+        let service = tower::service_fn(|_req: String| async move {
+            panic!("attempt panicked");
+            #[allow(unreachable_code)]
+            Err::<String, _>(TestError)
+        });
+
+        let layer = HedgeLayer::builder()
+            .no_delay()
+            .max_hedged_attempts(1)
+            .build();
+
+        let mut service = layer.layer(service);
+        let result = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await;
+        assert!(matches!(result, Err(HedgeError::AttemptPanicked)));
+    }
+
     #[test]
     fn test_preset_conservative() {
         let _layer = HedgeLayer::conservative();
@@ -0,0 +1,252 @@
+//! Hedging across multiple replica services.
+//!
+//! Re-sending a slow request to the same backend often lands on the same
+//! overloaded host or the same slow partition. Spreading attempts across
+//! replicas gives each hedge an independent chance of being fast.
+
+use crate::cancel::{CancelHook, NoCancelHook};
+use crate::config::HedgeConfig;
+use crate::decorate::{HedgeDecorator, NoDecorator};
+use crate::error::HedgeError;
+use crate::execute_with_hedging;
+use futures::future::BoxFuture;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tower::Service;
+
+/// How replicas are assigned to the attempts of a hedged call.
+#[derive(Debug)]
+enum ReplicaSelector {
+    /// Rotate the primary across replicas; hedges go to the next replicas
+    /// in order.
+    RoundRobin { next: AtomicUsize },
+    /// Smooth weighted round-robin for the primary; hedges go to the
+    /// remaining replicas, heaviest first.
+    Weighted {
+        weights: Vec<i64>,
+        total: i64,
+        current: Mutex<Vec<i64>>,
+    },
+}
+
+impl ReplicaSelector {
+    fn weighted(weights: Vec<u32>) -> Self {
+        let weights: Vec<i64> = weights.into_iter().map(i64::from).collect();
+        let total = weights.iter().sum();
+        let current = Mutex::new(vec![0; weights.len()]);
+        Self::Weighted {
+            weights,
+            total,
+            current,
+        }
+    }
+
+    /// Returns replica indices in the order attempts should use them.
+    fn order(&self, len: usize) -> Vec<usize> {
+        match self {
+            Self::RoundRobin { next } => {
+                let start = next.fetch_add(1, Ordering::Relaxed) % len;
+                (0..len).map(|i| (start + i) % len).collect()
+            }
+            Self::Weighted {
+                weights,
+                total,
+                current,
+            } => {
+                let primary = {
+                    let mut current = current.lock().unwrap_or_else(|e| e.into_inner());
+                    let mut best = 0;
+                    for (i, weight) in weights.iter().enumerate() {
+                        current[i] += weight;
+                        if current[i] > current[best] {
+                            best = i;
+                        }
+                    }
+                    current[best] -= total;
+                    best
+                };
+
+                let mut rest: Vec<usize> = (0..len).filter(|&i| i != primary).collect();
+                rest.sort_by_key(|&i| std::cmp::Reverse(weights[i]));
+                std::iter::once(primary).chain(rest).collect()
+            }
+        }
+    }
+}
+
+/// A hedging service that sends each attempt to a different replica.
+///
+/// Created with [`HedgeLayer::across`](crate::HedgeLayer::across) or
+/// [`HedgeLayer::across_weighted`](crate::HedgeLayer::across_weighted).
+/// The primary attempt goes to the selected replica and each hedge goes to
+/// the next replica in line. If `max_hedged_attempts` exceeds the number of
+/// replicas, attempts wrap around.
+///
+/// Readiness is driven per attempt on the chosen replica, so `poll_ready`
+/// on this service always returns ready.
+pub struct HedgeAcross<S, H = NoCancelHook, D = NoDecorator> {
+    replicas: Vec<S>,
+    selector: Arc<ReplicaSelector>,
+    config: Arc<HedgeConfig<H, D>>,
+}
+
+impl<S, H, D> HedgeAcross<S, H, D> {
+    /// Create a service that selects replicas in round-robin order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `replicas` is empty.
+    pub(crate) fn round_robin(replicas: Vec<S>, config: HedgeConfig<H, D>) -> Self {
+        Self::new(
+            replicas,
+            ReplicaSelector::RoundRobin {
+                next: AtomicUsize::new(0),
+            },
+            config,
+        )
+    }
+
+    /// Create a service that selects replicas by weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `replicas` is empty or all weights are zero.
+    pub(crate) fn weighted(replicas: Vec<(S, u32)>, config: HedgeConfig<H, D>) -> Self {
+        assert!(
+            replicas.iter().any(|(_, weight)| *weight > 0),
+            "HedgeAcross requires at least one replica with a non-zero weight"
+        );
+        let (replicas, weights): (Vec<S>, Vec<u32>) = replicas.into_iter().unzip();
+        Self::new(replicas, ReplicaSelector::weighted(weights), config)
+    }
+
+    fn new(replicas: Vec<S>, selector: ReplicaSelector, config: HedgeConfig<H, D>) -> Self {
+        assert!(
+            !replicas.is_empty(),
+            "HedgeAcross requires at least one replica"
+        );
+
+        #[cfg(feature = "metrics")]
+        crate::describe_metrics();
+
+        Self {
+            replicas,
+            selector: Arc::new(selector),
+            config: Arc::new(config),
+        }
+    }
+
+    /// Returns the number of replicas.
+    pub fn replica_count(&self) -> usize {
+        self.replicas.len()
+    }
+}
+
+impl<S: Clone, H, D> Clone for HedgeAcross<S, H, D> {
+    fn clone(&self) -> Self {
+        Self {
+            replicas: self.replicas.clone(),
+            selector: Arc::clone(&self.selector),
+            config: Arc::clone(&self.config),
+        }
+    }
+}
+
+impl<S, H, D, Req> Service<Req> for HedgeAcross<S, H, D>
+where
+    S: Service<Req> + Clone + Send + 'static,
+    S::Response: Send + Sync + 'static,
+    S::Error: Clone + Send + Sync + 'static,
+    S::Future: Send,
+    Req: Clone + Send + Sync + 'static,
+    H: CancelHook<Req> + 'static,
+    D: HedgeDecorator<Req> + 'static,
+{
+    type Response = S::Response;
+    type Error = HedgeError<S::Error>;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let config = Arc::clone(&self.config);
+        let targets: Vec<S> = self
+            .selector
+            .order(self.replicas.len())
+            .into_iter()
+            .map(|i| self.replicas[i].clone())
+            .collect();
+        let primary = targets[0].clone();
+
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span(
+            "hedge",
+            config.name.as_deref().unwrap_or("hedge"),
+        );
+
+        let future = async move {
+            execute_with_hedging(
+                primary,
+                false,
+                move |attempt| targets[attempt % targets.len()].clone(),
+                req,
+                config,
+            )
+            .await
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_result,
+        );
+
+        Box::pin(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin_rotates_primary() {
+        let selector = ReplicaSelector::RoundRobin {
+            next: AtomicUsize::new(0),
+        };
+        assert_eq!(selector.order(3), vec![0, 1, 2]);
+        assert_eq!(selector.order(3), vec![1, 2, 0]);
+        assert_eq!(selector.order(3), vec![2, 0, 1]);
+        assert_eq!(selector.order(3), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn weighted_distributes_primary_by_weight() {
+        let selector = ReplicaSelector::weighted(vec![5, 1, 1]);
+        let mut counts = [0; 3];
+        for _ in 0..70 {
+            counts[selector.order(3)[0]] += 1;
+        }
+        assert_eq!(counts, [50, 10, 10]);
+    }
+
+    #[test]
+    fn weighted_hedges_prefer_heavier_replicas() {
+        let selector = ReplicaSelector::weighted(vec![1, 5, 3]);
+        let order = selector.order(3);
+        assert_eq!(order[0], 1);
+        assert_eq!(&order[1..], &[2, 0]);
+    }
+
+    #[test]
+    fn zero_weight_never_primary() {
+        let selector = ReplicaSelector::weighted(vec![2, 0]);
+        for _ in 0..10 {
+            assert_eq!(selector.order(2)[0], 0);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Backend saturation signal that disables hedging.
+//!
+//! Hedging trades extra load for lower tail latency. When the backend is
+//! already saturated (high error rate, many requests in flight), that extra
+//! load deepens the outage, so hedging is switched off until the signal
+//! clears.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Closure reporting whether the backend is saturated.
+pub(crate) type SaturationFn = Arc<dyn Fn() -> bool + Send + Sync>;
+
+/// Change in hedging state observed by [`SaturationSignal::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Transition {
+    /// The backend became saturated; hedging is now disabled.
+    Disabled,
+    /// The backend recovered; hedging is enabled again.
+    Enabled,
+}
+
+/// Tracks a saturation closure and the last state it reported.
+///
+/// Shared across all clones of a hedge service.
+pub(crate) struct SaturationSignal {
+    saturated: SaturationFn,
+    disabled: AtomicBool,
+}
+
+impl SaturationSignal {
+    pub(crate) fn new(saturated: SaturationFn) -> Self {
+        Self {
+            saturated,
+            disabled: AtomicBool::new(false),
+        }
+    }
+
+    /// Polls the closure, returning whether hedging is disabled and the
+    /// transition, if the state changed since the last check.
+    pub(crate) fn check(&self) -> (bool, Option<Transition>) {
+        let disabled = (self.saturated)();
+        let was_disabled = self.disabled.swap(disabled, Ordering::AcqRel);
+        let transition = match (was_disabled, disabled) {
+            (false, true) => Some(Transition::Disabled),
+            (true, false) => Some(Transition::Enabled),
+            _ => None,
+        };
+        (disabled, transition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_transitions_once() {
+        let saturated = Arc::new(AtomicBool::new(false));
+        let s = Arc::clone(&saturated);
+        let signal = SaturationSignal::new(Arc::new(move || s.load(Ordering::SeqCst)));
+
+        assert_eq!(signal.check(), (false, None));
+
+        saturated.store(true, Ordering::SeqCst);
+        assert_eq!(signal.check(), (true, Some(Transition::Disabled)));
+        assert_eq!(signal.check(), (true, None));
+
+        saturated.store(false, Ordering::SeqCst);
+        assert_eq!(signal.check(), (false, Some(Transition::Enabled)));
+        assert_eq!(signal.check(), (false, None));
+    }
+}
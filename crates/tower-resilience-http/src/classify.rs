@@ -0,0 +1,141 @@
+//! Failure classification by HTTP status.
+
+use http::{Response, StatusCode};
+use tower_resilience_core::classifier::FailureClassifier;
+
+/// Classifies HTTP responses as failures by status code.
+///
+/// HTTP clients return `Ok` for every response the server sends, so a
+/// circuit breaker or outlier detector with the default classifier never sees
+/// a `500` as a failure. `StatusClassifier` counts transport errors as
+/// failures, plus responses whose status falls in the configured classes.
+///
+/// Individual codes can be moved either way with
+/// [`with_failure`](Self::with_failure) and
+/// [`with_success`](Self::with_success).
+///
+/// # Examples
+///
+/// ```rust
+/// use http::StatusCode;
+/// use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+/// use tower_resilience_http::StatusClassifier;
+///
+/// // 5xx and 429 trip the breaker; 501 means "not supported", not "down"
+/// let classifier = StatusClassifier::server_errors()
+///     .with_failure(StatusCode::TOO_MANY_REQUESTS)
+///     .with_success(StatusCode::NOT_IMPLEMENTED);
+///
+/// let layer = CircuitBreakerLayer::builder()
+///     .failure_classifier_type(classifier)
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusClassifier {
+    client_errors: bool,
+    server_errors: bool,
+    failures: Vec<StatusCode>,
+    successes: Vec<StatusCode>,
+}
+
+impl StatusClassifier {
+    /// Counts `5xx` responses as failures.
+    pub fn server_errors() -> Self {
+        Self {
+            client_errors: false,
+            server_errors: true,
+            failures: Vec::new(),
+            successes: Vec::new(),
+        }
+    }
+
+    /// Counts both `4xx` and `5xx` responses as failures.
+    pub fn client_and_server_errors() -> Self {
+        Self {
+            client_errors: true,
+            ..Self::server_errors()
+        }
+    }
+
+    /// Counts `status` as a failure regardless of its class.
+    pub fn with_failure(mut self, status: StatusCode) -> Self {
+        self.successes.retain(|s| *s != status);
+        self.failures.push(status);
+        self
+    }
+
+    /// Never counts `status` as a failure, even if its class would.
+    pub fn with_success(mut self, status: StatusCode) -> Self {
+        self.failures.retain(|s| *s != status);
+        self.successes.push(status);
+        self
+    }
+
+    /// Returns `true` if a response with this status counts as a failure.
+    pub fn is_failure(&self, status: StatusCode) -> bool {
+        if self.successes.contains(&status) {
+            return false;
+        }
+        if self.failures.contains(&status) {
+            return true;
+        }
+        (self.client_errors && status.is_client_error())
+            || (self.server_errors && status.is_server_error())
+    }
+}
+
+impl Default for StatusClassifier {
+    fn default() -> Self {
+        Self::server_errors()
+    }
+}
+
+impl<B, E> FailureClassifier<Response<B>, E> for StatusClassifier {
+    fn classify(&self, result: &Result<Response<B>, E>) -> bool {
+        match result {
+            Ok(response) => self.is_failure(response.status()),
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: StatusCode) -> Result<Response<()>, ()> {
+        let mut response = Response::new(());
+        *response.status_mut() = status;
+        Ok(response)
+    }
+
+    #[test]
+    fn server_errors_only() {
+        let classifier = StatusClassifier::server_errors();
+        assert!(!classifier.classify(&response(StatusCode::OK)));
+        assert!(!classifier.classify(&response(StatusCode::NOT_FOUND)));
+        assert!(classifier.classify(&response(StatusCode::BAD_GATEWAY)));
+        assert!(classifier.classify(&Err::<Response<()>, _>(())));
+    }
+
+    #[test]
+    fn client_and_server_errors() {
+        let classifier = StatusClassifier::client_and_server_errors();
+        assert!(classifier.classify(&response(StatusCode::NOT_FOUND)));
+        assert!(classifier.classify(&response(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(!classifier.classify(&response(StatusCode::NO_CONTENT)));
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let classifier = StatusClassifier::server_errors()
+            .with_failure(StatusCode::TOO_MANY_REQUESTS)
+            .with_success(StatusCode::NOT_IMPLEMENTED);
+        assert!(classifier.is_failure(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!classifier.is_failure(StatusCode::NOT_IMPLEMENTED));
+
+        // The last call for a status wins
+        let classifier = classifier.with_failure(StatusCode::NOT_IMPLEMENTED);
+        assert!(classifier.is_failure(StatusCode::NOT_IMPLEMENTED));
+    }
+}
@@ -0,0 +1,210 @@
+//! gRPC helpers for tonic clients.
+//!
+//! gRPC reports outcomes as [`Status`] codes rather than HTTP statuses, and
+//! carries its deadline in the `grpc-timeout` header. This module maps codes
+//! onto the failure and retry decisions the pattern crates expect, and writes
+//! the current [`Deadline`] into outgoing requests so the server stops
+//! working on a call the client has already given up on.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use tonic::Status;
+//! use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+//! use tower_resilience_http::grpc::{self, GrpcClassifier};
+//! use tower_resilience_retry::RetryLayer;
+//!
+//! // Retry UNAVAILABLE and DEADLINE_EXCEEDED, never INVALID_ARGUMENT
+//! let retry = RetryLayer::<String, String, Status>::builder()
+//!     .max_attempts(3)
+//!     .exponential_backoff(Duration::from_millis(100))
+//!     .retry_on(grpc::is_retryable)
+//!     .build();
+//!
+//! // Only server-side codes count against the breaker
+//! let breaker = CircuitBreakerLayer::builder()
+//!     .failure_classifier_type(GrpcClassifier::new())
+//!     .build();
+//! ```
+//!
+//! Under a time limiter, [`set_timeout`] forwards the remaining budget with
+//! each request:
+//!
+//! ```rust
+//! use tower_resilience_http::grpc;
+//! use tower_resilience_timelimiter::Deadline;
+//!
+//! # fn example(mut request: tonic::Request<String>) {
+//! if let Some(deadline) = Deadline::current() {
+//!     grpc::set_timeout(&mut request, deadline);
+//! }
+//! # }
+//! ```
+//!
+//! For services that take `http::Request`s, such as a tonic `Channel`,
+//! [`inject_grpc_timeout`] does the same as an `InjectDeadlineLayer` closure.
+
+use http::header::HeaderValue;
+use tonic::{Code, Status};
+use tower_resilience_core::classifier::FailureClassifier;
+use tower_resilience_core::Deadline;
+
+/// The header gRPC uses to carry a call's deadline.
+pub const GRPC_TIMEOUT_HEADER: &str = "grpc-timeout";
+
+/// Returns `true` for codes that indicate a transient condition worth
+/// retrying: `UNAVAILABLE`, `DEADLINE_EXCEEDED` and `RESOURCE_EXHAUSTED`.
+///
+/// Codes describing the request itself, such as `INVALID_ARGUMENT`,
+/// `NOT_FOUND` or `PERMISSION_DENIED`, fail the same way on every attempt.
+pub fn is_retryable_code(code: Code) -> bool {
+    matches!(
+        code,
+        Code::Unavailable | Code::DeadlineExceeded | Code::ResourceExhausted
+    )
+}
+
+/// Returns `true` if the status has a retryable code, for use with a retry
+/// layer's `retry_on`.
+pub fn is_retryable(status: &Status) -> bool {
+    is_retryable_code(status.code())
+}
+
+/// Classifies gRPC errors as failures by status code.
+///
+/// Only codes that say the server is unhealthy count as failures by default:
+/// `UNKNOWN`, `DEADLINE_EXCEEDED`, `RESOURCE_EXHAUSTED`, `INTERNAL`,
+/// `UNAVAILABLE` and `DATA_LOSS`. A burst of `INVALID_ARGUMENT` or
+/// `NOT_FOUND` responses is a caller problem and should not open a circuit.
+///
+/// Individual codes can be moved either way with
+/// [`with_failure`](Self::with_failure) and
+/// [`with_success`](Self::with_success).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrpcClassifier {
+    failures: Vec<Code>,
+    successes: Vec<Code>,
+}
+
+impl GrpcClassifier {
+    /// Creates a classifier with the default set of failure codes.
+    pub fn new() -> Self {
+        Self {
+            failures: Vec::new(),
+            successes: Vec::new(),
+        }
+    }
+
+    /// Counts `code` as a failure.
+    pub fn with_failure(mut self, code: Code) -> Self {
+        self.successes.retain(|c| *c != code);
+        self.failures.push(code);
+        self
+    }
+
+    /// Never counts `code` as a failure.
+    pub fn with_success(mut self, code: Code) -> Self {
+        self.failures.retain(|c| *c != code);
+        self.successes.push(code);
+        self
+    }
+
+    /// Returns `true` if an error with this code counts as a failure.
+    pub fn is_failure(&self, code: Code) -> bool {
+        if self.successes.contains(&code) {
+            return false;
+        }
+        if self.failures.contains(&code) {
+            return true;
+        }
+        matches!(
+            code,
+            Code::Unknown
+                | Code::DeadlineExceeded
+                | Code::ResourceExhausted
+                | Code::Internal
+                | Code::Unavailable
+                | Code::DataLoss
+        )
+    }
+}
+
+impl Default for GrpcClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Res> FailureClassifier<Res, Status> for GrpcClassifier {
+    fn classify(&self, result: &Result<Res, Status>) -> bool {
+        match result {
+            Ok(_) => false,
+            Err(status) => self.is_failure(status.code()),
+        }
+    }
+}
+
+/// Sets the `grpc-timeout` metadata of a tonic request from `deadline`.
+pub fn set_timeout<T>(request: &mut tonic::Request<T>, deadline: Deadline) {
+    request.set_timeout(deadline.remaining());
+}
+
+/// Sets the `grpc-timeout` header of an HTTP request from `deadline`.
+///
+/// The signature matches the closure taken by the time limiter's
+/// `InjectDeadlineLayer`, so it can be passed directly:
+///
+/// ```rust
+/// use tower_resilience_http::grpc::inject_grpc_timeout;
+/// use tower_resilience_timelimiter::InjectDeadlineLayer;
+///
+/// let layer = InjectDeadlineLayer::new(inject_grpc_timeout::<tonic::body::Body>);
+/// ```
+pub fn inject_grpc_timeout<B>(request: &mut http::Request<B>, deadline: Deadline) {
+    if let Ok(value) = HeaderValue::from_str(&deadline.grpc_timeout()) {
+        request.headers_mut().insert(GRPC_TIMEOUT_HEADER, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn retryable_codes() {
+        assert!(is_retryable(&Status::unavailable("down")));
+        assert!(is_retryable(&Status::deadline_exceeded("slow")));
+        assert!(!is_retryable(&Status::invalid_argument("bad")));
+        assert!(!is_retryable(&Status::permission_denied("no")));
+    }
+
+    #[test]
+    fn classifier_ignores_caller_errors() {
+        let classifier = GrpcClassifier::new();
+        assert!(!classifier.classify(&Ok::<_, Status>(())));
+        assert!(classifier.classify(&Err::<(), _>(Status::internal("boom"))));
+        assert!(!classifier.classify(&Err::<(), _>(Status::not_found("missing"))));
+
+        let classifier = classifier
+            .with_failure(Code::Aborted)
+            .with_success(Code::ResourceExhausted);
+        assert!(classifier.is_failure(Code::Aborted));
+        assert!(!classifier.is_failure(Code::ResourceExhausted));
+    }
+
+    #[test]
+    fn deadline_written_to_metadata() {
+        let deadline = Deadline::after(Duration::from_secs(2));
+
+        let mut request = tonic::Request::new(());
+        set_timeout(&mut request, deadline);
+        assert!(request.metadata().get(GRPC_TIMEOUT_HEADER).is_some());
+
+        let mut request = http::Request::new(());
+        inject_grpc_timeout(&mut request, deadline);
+        let value = request.headers()[GRPC_TIMEOUT_HEADER].to_str().unwrap();
+        assert!(value.ends_with('u') || value.ends_with('m'), "{value}");
+    }
+}
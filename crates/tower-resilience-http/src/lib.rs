@@ -0,0 +1,81 @@
+//! HTTP helpers for tower-resilience.
+//!
+//! The pattern crates are generic over request, response and error types, so
+//! every HTTP client ends up writing the same glue: "a 503 is a failure", "a
+//! 429 should be retried after the `Retry-After` delay", "cache by method and
+//! URI", "only retry idempotent methods". This crate provides those pieces for
+//! [`http::Request`] and [`http::Response`], ready to plug into the existing
+//! builders.
+//!
+//! # Failure Classification
+//!
+//! [`StatusClassifier`] counts responses as failures by status class, for the
+//! circuit breaker and any other pattern that accepts a `FailureClassifier`:
+//!
+//! ```rust
+//! use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+//! use tower_resilience_http::StatusClassifier;
+//!
+//! let layer = CircuitBreakerLayer::builder()
+//!     .failure_classifier_type(StatusClassifier::server_errors())
+//!     .build();
+//! ```
+//!
+//! # Retrying Throttled Requests
+//!
+//! [`is_retryable_response`] retries `429` and `503` responses, and
+//! [`retry_after`] waits as long as the server's `Retry-After` header asks.
+//! [`idempotent_attempts`] limits retries to idempotent methods:
+//!
+//! ```rust
+//! use http::{Request, Response};
+//! use std::time::Duration;
+//! use tower_resilience_http::{idempotent_attempts, is_retryable_response, retry_after};
+//! use tower_resilience_retry::RetryLayer;
+//!
+//! let layer = RetryLayer::<Request<String>, Response<String>, std::io::Error>::builder()
+//!     .max_attempts_fn(idempotent_attempts(3))
+//!     .exponential_backoff(Duration::from_millis(100))
+//!     .retry_on_response(is_retryable_response)
+//!     .retry_after_response(retry_after)
+//!     .build();
+//! ```
+//!
+//! # Caching
+//!
+//! [`request_key`] keys a cache by method and URI:
+//!
+//! ```rust
+//! use tower_resilience_cache::CacheLayer;
+//! use tower_resilience_http::request_key;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let layer = CacheLayer::builder()
+//!     .max_size(1000)
+//!     .key_extractor(request_key::<()>)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # gRPC
+//!
+//! With the `grpc` feature, the [`grpc`](crate::grpc) module classifies tonic
+//! `Status` codes for retries and circuit breakers, and propagates the time
+//! limiter's deadline as `grpc-timeout` metadata.
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+mod classify;
+mod request;
+mod retry;
+
+pub use classify::StatusClassifier;
+pub use request::{
+    idempotent_attempts, is_idempotent, is_idempotent_request, request_key, RequestKey,
+};
+pub use retry::{is_retryable_response, is_retryable_status, retry_after};
+
+/// Re-export of the `http` crate these helpers are written against.
+pub use http;
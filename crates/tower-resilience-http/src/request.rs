@@ -0,0 +1,127 @@
+//! Request helpers: cache keys and idempotency.
+
+use http::{Method, Request, Uri};
+
+/// A cache key made of a request's method and URI.
+///
+/// Use [`request_key`] as a cache layer's key extractor so that responses are
+/// shared between requests for the same resource. The body and headers are
+/// not part of the key, so only cache requests whose response depends on the
+/// method and URI alone (typically `GET` and `HEAD`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RequestKey {
+    method: Method,
+    uri: Uri,
+}
+
+impl RequestKey {
+    /// Returns the request method.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Returns the request URI.
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+}
+
+/// Extracts a [`RequestKey`] from the request's method and URI.
+///
+/// # Examples
+///
+/// ```rust
+/// use tower_resilience_cache::CacheLayer;
+/// use tower_resilience_http::request_key;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let layer = CacheLayer::builder()
+///     .max_size(1000)
+///     .key_extractor(request_key::<()>)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn request_key<B>(request: &Request<B>) -> RequestKey {
+    RequestKey {
+        method: request.method().clone(),
+        uri: request.uri().clone(),
+    }
+}
+
+/// Returns `true` for methods that RFC 9110 defines as idempotent: `GET`,
+/// `HEAD`, `OPTIONS`, `TRACE`, `PUT` and `DELETE`.
+///
+/// Repeating an idempotent request has the same effect as sending it once,
+/// so it is safe to retry or hedge.
+pub fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE | Method::PUT | Method::DELETE
+    )
+}
+
+/// Returns `true` if the request's method is idempotent.
+pub fn is_idempotent_request<B>(request: &Request<B>) -> bool {
+    is_idempotent(request.method())
+}
+
+/// Returns a `max_attempts_fn` for a retry layer that retries idempotent
+/// requests up to `max_attempts` times and sends everything else once.
+///
+/// # Examples
+///
+/// ```rust
+/// use http::{Request, Response};
+/// use tower_resilience_http::idempotent_attempts;
+/// use tower_resilience_retry::RetryLayer;
+///
+/// // POST and PATCH are never retried
+/// let layer = RetryLayer::<Request<String>, Response<String>, std::io::Error>::builder()
+///     .max_attempts_fn(idempotent_attempts(3))
+///     .build();
+/// ```
+pub fn idempotent_attempts<B>(max_attempts: usize) -> impl Fn(&Request<B>) -> usize + Clone {
+    move |request| {
+        if is_idempotent_request(request) {
+            max_attempts
+        } else {
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: Method, uri: &str) -> Request<()> {
+        Request::builder().method(method).uri(uri).body(()).unwrap()
+    }
+
+    #[test]
+    fn request_key_uses_method_and_uri() {
+        let get = request_key(&request(Method::GET, "/users?page=2"));
+        assert_eq!(get, request_key(&request(Method::GET, "/users?page=2")));
+        assert_ne!(get, request_key(&request(Method::HEAD, "/users?page=2")));
+        assert_ne!(get, request_key(&request(Method::GET, "/users?page=3")));
+        assert_eq!(get.method(), Method::GET);
+        assert_eq!(get.uri().path(), "/users");
+    }
+
+    #[test]
+    fn idempotent_methods() {
+        assert!(is_idempotent(&Method::GET));
+        assert!(is_idempotent(&Method::PUT));
+        assert!(is_idempotent(&Method::DELETE));
+        assert!(!is_idempotent(&Method::POST));
+        assert!(!is_idempotent(&Method::PATCH));
+    }
+
+    #[test]
+    fn idempotent_attempts_by_method() {
+        let attempts = idempotent_attempts(4);
+        assert_eq!(attempts(&request(Method::GET, "/")), 4);
+        assert_eq!(attempts(&request(Method::POST, "/")), 1);
+    }
+}
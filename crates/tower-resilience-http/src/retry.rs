@@ -0,0 +1,112 @@
+//! Retry predicates for throttled and unavailable responses.
+//!
+//! `429 Too Many Requests` and `503 Service Unavailable` are the two statuses
+//! a server uses to say "try again later", often with a `Retry-After` header
+//! saying how much later. Plug these functions into a retry layer's
+//! `retry_on_response` and `retry_after_response` so retries wait as long as
+//! the server asks instead of following the backoff:
+//!
+//! ```rust
+//! use http::{Request, Response};
+//! use std::time::Duration;
+//! use tower_resilience_http::{is_retryable_response, retry_after};
+//! use tower_resilience_retry::RetryLayer;
+//!
+//! let layer = RetryLayer::<Request<String>, Response<String>, std::io::Error>::builder()
+//!     .max_attempts(3)
+//!     .exponential_backoff(Duration::from_millis(100))
+//!     .retry_on_response(is_retryable_response)
+//!     .retry_after_response(retry_after)
+//!     .build();
+//! ```
+
+use http::header::RETRY_AFTER;
+use http::{HeaderValue, Response, StatusCode};
+use std::time::{Duration, SystemTime};
+
+/// Returns `true` for statuses that ask the client to retry later:
+/// `429 Too Many Requests` and `503 Service Unavailable`.
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    )
+}
+
+/// Returns `true` if the response has a retryable status, for use with
+/// `retry_on_response`.
+pub fn is_retryable_response<B>(response: &Response<B>) -> bool {
+    is_retryable_status(response.status())
+}
+
+/// Reads the delay requested by the response's `Retry-After` header, for use
+/// with `retry_after_response`.
+///
+/// Both forms of the header are supported: a number of seconds, or an HTTP
+/// date (a date in the past means "now"). Returns `None` when the header is
+/// missing or malformed, so the retry layer falls back to its backoff.
+pub fn retry_after<B>(response: &Response<B>) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?;
+    parse_retry_after(value, SystemTime::now())
+}
+
+fn parse_retry_after(value: &HeaderValue, now: SystemTime) -> Option<Duration> {
+    let value = value.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: StatusCode, retry_after: Option<&str>) -> Response<()> {
+        let mut builder = Response::builder().status(status);
+        if let Some(value) = retry_after {
+            builder = builder.header(RETRY_AFTER, value);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_retryable_response(&response(StatusCode::OK, None)));
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        let response = response(StatusCode::TOO_MANY_REQUESTS, Some("120"));
+        assert_eq!(retry_after(&response), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn retry_after_http_date() {
+        let now = httpdate::parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").unwrap();
+        let later = HeaderValue::from_static("Wed, 21 Oct 2015 07:28:30 GMT");
+        let earlier = HeaderValue::from_static("Wed, 21 Oct 2015 07:27:00 GMT");
+
+        assert_eq!(
+            parse_retry_after(&later, now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after(&earlier, now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_missing_or_malformed() {
+        assert_eq!(
+            retry_after(&response(StatusCode::SERVICE_UNAVAILABLE, None)),
+            None
+        );
+        assert_eq!(
+            retry_after(&response(StatusCode::SERVICE_UNAVAILABLE, Some("soon"))),
+            None
+        );
+    }
+}
@@ -11,6 +11,12 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tower_resilience_core::events::EventListeners;
 
+#[cfg(feature = "metrics")]
+use metrics::{counter, describe_counter, describe_gauge, gauge};
+
+#[cfg(feature = "metrics")]
+static METRICS_INIT: std::sync::Once = std::sync::Once::new();
+
 /// State for a single instance tracked by the detector.
 struct InstanceState {
     /// Whether this instance is currently ejected.
@@ -67,6 +73,26 @@ impl OutlierDetector {
     /// - `base_ejection_duration`: 30 seconds
     /// - No max ejection duration cap
     pub fn new() -> Self {
+        #[cfg(feature = "metrics")]
+        METRICS_INIT.call_once(|| {
+            describe_counter!(
+                "resilience_outlier_ejections_total",
+                "Total number of instance ejections"
+            );
+            describe_counter!(
+                "resilience_outlier_ejections_skipped_total",
+                "Ejections skipped because max_ejection_percent was reached"
+            );
+            describe_counter!(
+                "resilience_outlier_recoveries_total",
+                "Total number of ejected instances returned to service"
+            );
+            describe_gauge!(
+                "resilience_outlier_ejected_instances",
+                "Number of currently ejected instances"
+            );
+        });
+
         Self {
             inner: Arc::new(Mutex::new(DetectorInner {
                 instances: HashMap::new(),
@@ -154,6 +180,18 @@ impl OutlierDetector {
         self
     }
 
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(self, capacity: usize) -> Self {
+        self.inner
+            .lock()
+            .unwrap()
+            .event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Registers an instance with the detector using the consecutive errors strategy.
     ///
     /// `consecutive_error_threshold` is the number of consecutive errors
@@ -235,6 +273,15 @@ impl OutlierDetector {
                 current_ejection_percent: (currently_ejected as f64 / total as f64) * 100.0,
             };
             inner.event_listeners.emit(&event);
+
+            #[cfg(feature = "metrics")]
+            counter!(
+                "resilience_outlier_ejections_skipped_total",
+                "name" => inner.pattern_name.clone(),
+                "instance" => name.to_string()
+            )
+            .increment(1);
+
             return false;
         }
 
@@ -261,6 +308,18 @@ impl OutlierDetector {
         };
         inner.event_listeners.emit(&event);
 
+        #[cfg(feature = "metrics")]
+        {
+            counter!(
+                "resilience_outlier_ejections_total",
+                "name" => inner.pattern_name.clone(),
+                "instance" => name.to_string()
+            )
+            .increment(1);
+            gauge!("resilience_outlier_ejected_instances", "name" => inner.pattern_name.clone())
+                .set((currently_ejected + 1) as f64);
+        }
+
         true
     }
 
@@ -299,6 +358,19 @@ impl OutlierDetector {
             };
             inner.event_listeners.emit(&event);
 
+            #[cfg(feature = "metrics")]
+            {
+                counter!(
+                    "resilience_outlier_recoveries_total",
+                    "name" => inner.pattern_name.clone(),
+                    "instance" => name.to_string()
+                )
+                .increment(1);
+                let ejected = inner.instances.values().filter(|i| i.ejected).count();
+                gauge!("resilience_outlier_ejected_instances", "name" => inner.pattern_name.clone())
+                    .set(ejected as f64);
+            }
+
             return false;
         }
 
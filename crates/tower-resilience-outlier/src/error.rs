@@ -1,6 +1,6 @@
 //! Error types for the outlier detection middleware.
 
-use tower_resilience_core::ResilienceError;
+use tower_resilience_core::{PatternError, ResilienceError};
 
 /// Errors specific to the outlier detection pattern.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -68,6 +68,28 @@ impl<E: std::error::Error + 'static> std::error::Error for OutlierDetectionServi
     }
 }
 
+impl<E> PatternError for OutlierDetectionServiceError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        self.is_outlier_detection()
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            OutlierDetectionServiceError::OutlierDetection(_) => None,
+            OutlierDetectionServiceError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            OutlierDetectionServiceError::Inner(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 impl<E> From<OutlierDetectionError> for OutlierDetectionServiceError<E> {
     fn from(err: OutlierDetectionError) -> Self {
         OutlierDetectionServiceError::OutlierDetection(err)
@@ -5,12 +5,18 @@ use tower_resilience_core::events::ResilienceEvent;
 
 /// Events emitted by the outlier detection middleware.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum OutlierDetectionEvent {
     /// An instance has been ejected.
     Ejected {
         /// The pattern name.
         pattern_name: String,
         /// When the ejection occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// The name of the ejected instance.
         instance_name: String,
@@ -24,6 +30,10 @@ pub enum OutlierDetectionEvent {
         /// The pattern name.
         pattern_name: String,
         /// When recovery occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// The name of the recovered instance.
         instance_name: String,
@@ -35,6 +45,10 @@ pub enum OutlierDetectionEvent {
         /// The pattern name.
         pattern_name: String,
         /// When the rejection occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// The name of the ejected instance.
         instance_name: String,
@@ -44,6 +58,10 @@ pub enum OutlierDetectionEvent {
         /// The pattern name.
         pattern_name: String,
         /// When the skip occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// The name of the instance that would have been ejected.
         instance_name: String,
@@ -59,6 +59,16 @@
 //!     .error_on_ejection()
 //!     .build();
 //! ```
+//!
+//! # Metrics
+//!
+//! With the `metrics` feature enabled, labeled with the detector's `name` and
+//! the `instance` concerned:
+//!
+//! - `resilience_outlier_ejections_total{name, instance}` - Instance ejections
+//! - `resilience_outlier_ejections_skipped_total{name, instance}` - Ejections skipped by `max_ejection_percent`
+//! - `resilience_outlier_recoveries_total{name, instance}` - Instances returned to service
+//! - `resilience_outlier_ejected_instances{name}` - Currently ejected instances gauge
 
 /// Configuration types for outlier detection.
 pub mod config;
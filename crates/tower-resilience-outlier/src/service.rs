@@ -81,6 +81,38 @@ where
     }
 
     fn call(&mut self, request: Request) -> Self::Future {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tower_resilience_core::span::pattern_span(
+                "outlier_detection",
+                &self.config.instance_name,
+            );
+            let future = span.in_scope(|| self.call_untraced(request));
+            Box::pin(tower_resilience_core::span::instrument(
+                span,
+                future,
+                tower_resilience_core::span::Outcome::from_result,
+            ))
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.call_untraced(request)
+    }
+}
+
+impl<S, C> OutlierDetectionService<S, C> {
+    fn call_untraced<Request>(
+        &mut self,
+        request: Request,
+    ) -> BoxFuture<'static, Result<S::Response, OutlierDetectionServiceError<S::Error>>>
+    where
+        S: Service<Request> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        S::Response: Send + 'static,
+        S::Error: Send + 'static,
+        C: FailureClassifier<S::Response, S::Error> + Clone + Send + 'static,
+        Request: Send + 'static,
+    {
         let instance_name = self.config.instance_name.clone();
         let detector = self.config.detector.clone();
         let classifier = self.config.classifier.clone();
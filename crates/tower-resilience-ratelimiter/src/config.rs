@@ -1,6 +1,8 @@
 use crate::events::RateLimiterEvent;
+use std::sync::Arc;
 use std::time::Duration;
 use tower_resilience_core::events::{EventListeners, FnListener};
+use tower_resilience_core::{Clock, SharedClock, SystemClock};
 
 /// The type of window used for rate limiting.
 ///
@@ -8,6 +10,8 @@ use tower_resilience_core::events::{EventListeners, FnListener};
 /// over time. Each type has different trade-offs between precision, memory usage,
 /// and behavior at window boundaries.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WindowType {
     /// Fixed window rate limiting (default).
     ///
@@ -51,6 +55,30 @@ pub enum WindowType {
     /// - Precision: Approximate - slightly less accurate than SlidingLog
     /// - Best for: High-throughput APIs where memory efficiency matters
     SlidingCounter,
+
+    /// Generic cell rate algorithm (GCRA), a leaky bucket without the bucket.
+    ///
+    /// Spaces requests `period` apart while allowing up to `burst` requests
+    /// back to back. Instead of counting requests, it tracks the single
+    /// instant at which the next request would be perfectly on schedule.
+    ///
+    /// `limit_for_period` and `refresh_period` are ignored; the sustained
+    /// rate is one request per `period`.
+    ///
+    /// Trade-offs:
+    /// - Memory: O(1) - one timestamp
+    /// - Precision: Exact - smooth pacing with a strict burst bound
+    /// - Best for: Outbound API quotas that require steady request spacing
+    Gcra {
+        /// Minimum spacing between requests once the burst is used up.
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "tower_resilience_core::settings::duration")
+        )]
+        period: Duration,
+        /// Number of requests that may be sent back to back.
+        burst: usize,
+    },
 }
 
 /// Configuration for the rate limiter pattern.
@@ -60,6 +88,10 @@ pub struct RateLimiterConfig {
     pub(crate) timeout_duration: Duration,
     pub(crate) window_type: WindowType,
     pub(crate) backpressure: bool,
+    pub(crate) max_concurrent_calls: Option<usize>,
+    pub(crate) smooth_refill: bool,
+    pub(crate) observe_only: bool,
+    pub(crate) clock: SharedClock,
     pub(crate) event_listeners: EventListeners<RateLimiterEvent>,
     pub(crate) name: String,
 }
@@ -71,6 +103,10 @@ pub struct RateLimiterConfigBuilder {
     timeout_duration: Duration,
     window_type: WindowType,
     backpressure: bool,
+    max_concurrent_calls: Option<usize>,
+    smooth_refill: bool,
+    observe_only: bool,
+    clock: SharedClock,
     event_listeners: EventListeners<RateLimiterEvent>,
     name: String,
 }
@@ -97,6 +133,10 @@ impl RateLimiterConfigBuilder {
             timeout_duration: Duration::from_millis(100),
             window_type: WindowType::default(),
             backpressure: false,
+            max_concurrent_calls: None,
+            smooth_refill: false,
+            observe_only: false,
+            clock: Arc::new(SystemClock),
             event_listeners: EventListeners::new(),
             name: "<unnamed>".to_string(),
         }
@@ -169,6 +209,126 @@ impl RateLimiterConfigBuilder {
         self
     }
 
+    /// Also limits how many calls may be in flight at once.
+    ///
+    /// A call must hold both a concurrency slot and a rate permit to proceed.
+    /// The slot is taken first and held until the inner call completes, so a
+    /// call that cannot get a slot never consumes a rate permit. Both limits
+    /// share one set of events and one error: a call that cannot get a slot
+    /// within `timeout_duration` emits `PermitRejected` and fails with
+    /// `RateLimiterServiceError::RateLimited`. In backpressure mode,
+    /// `poll_ready()` waits for both.
+    ///
+    /// This replaces stacking a rate limiter over a bulkhead, where a call can
+    /// use up a rate permit and then be rejected by the bulkhead.
+    ///
+    /// If not called, concurrency is unlimited (the default).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_ratelimiter::RateLimiterLayer;
+    /// use std::time::Duration;
+    ///
+    /// // At most 100 requests per second, and at most 10 at a time
+    /// let limiter = RateLimiterLayer::builder()
+    ///     .limit_for_period(100)
+    ///     .refresh_period(Duration::from_secs(1))
+    ///     .max_concurrent_calls(10)
+    ///     .build();
+    /// ```
+    pub fn max_concurrent_calls(mut self, max: usize) -> Self {
+        self.max_concurrent_calls = Some(max);
+        self
+    }
+
+    /// Releases permits one at a time, spread evenly across the refresh
+    /// period, instead of all at once at each period boundary.
+    ///
+    /// With a limit of 100 per second, a permit becomes available every
+    /// 10ms. Saturated clients are then paced at the configured rate rather
+    /// than released in a synchronized burst at every refresh, which spares
+    /// downstream services the load spikes. Unused permits still accumulate
+    /// up to `limit_for_period`, so an idle limiter allows a full burst.
+    ///
+    /// Only affects [`WindowType::Fixed`]; the other window types already
+    /// free capacity gradually.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_ratelimiter::RateLimiterLayer;
+    /// use std::time::Duration;
+    ///
+    /// let limiter = RateLimiterLayer::builder()
+    ///     .limit_for_period(100)
+    ///     .refresh_period(Duration::from_secs(1))
+    ///     .smooth_refill()
+    ///     .build();
+    /// ```
+    pub fn smooth_refill(mut self) -> Self {
+        self.smooth_refill = true;
+        self
+    }
+
+    /// Runs the rate limiter as a dry run.
+    ///
+    /// Every call is still counted against the limit and produces the usual
+    /// `PermitAcquired` and `PermitRejected` events and metrics, but calls
+    /// that would be rejected are let through. Use this to measure what a
+    /// quota would do to production traffic before enforcing it.
+    ///
+    /// Observed calls never wait. A call that would have waited for a permit
+    /// runs right away and reserves the permit it would have taken once the
+    /// wait ends: it is reported as acquired (with the wait) if that permit
+    /// is still free, or rejected otherwise, so observed load uses up
+    /// permits as enforced load would. A call that finds no free
+    /// [concurrency slot](Self::max_concurrent_calls) is reported as
+    /// rejected. [`backpressure`](Self::backpressure) is ignored.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tower_resilience_ratelimiter::RateLimiterLayer;
+    /// use std::time::Duration;
+    ///
+    /// let limiter = RateLimiterLayer::builder()
+    ///     .limit_for_period(100)
+    ///     .refresh_period(Duration::from_secs(1))
+    ///     .observe_only(true)
+    ///     .on_permit_rejected(|_| println!("would have been rate limited"))
+    ///     .build();
+    /// ```
+    pub fn observe_only(mut self, observe_only: bool) -> Self {
+        self.observe_only = observe_only;
+        self
+    }
+
+    /// Sets the clock used to track permits and wait for them.
+    ///
+    /// Defaults to the system clock. Runtimes without a Tokio timer, such as
+    /// WebAssembly hosts, can supply their own. With a
+    /// [`MockClock`](tower_resilience_core::MockClock), waits for a permit
+    /// advance the mock clock instead of sleeping, so refresh periods can be
+    /// tested without real time passing.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tower_resilience_core::MockClock;
+    /// use tower_resilience_ratelimiter::RateLimiterLayer;
+    /// use std::time::Duration;
+    ///
+    /// let clock = MockClock::new();
+    /// let limiter = RateLimiterLayer::builder()
+    ///     .limit_for_period(10)
+    ///     .refresh_period(Duration::from_secs(60))
+    ///     .clock(clock.clone())
+    ///     .build();
+    /// ```
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
     /// Sets the window type for rate limiting.
     ///
     /// The window type determines how the rate limiter tracks requests over time:
@@ -182,6 +342,9 @@ impl RateLimiterConfigBuilder {
     /// - [`WindowType::SlidingCounter`]: Uses weighted averaging between buckets.
     ///   Approximate sliding window with O(1) memory.
     ///
+    /// - [`WindowType::Gcra`]: Paces requests a fixed period apart with a
+    ///   bounded burst. Exact with O(1) memory.
+    ///
     /// # Example
     /// ```rust,no_run
     /// use tower_resilience_ratelimiter::{RateLimiterLayer, WindowType};
@@ -208,6 +371,7 @@ impl RateLimiterConfigBuilder {
     /// `Fn(Duration)` - Called with the duration the request had to wait for the permit.
     /// - If the permit was immediately available, the duration will be close to zero.
     /// - If the request had to wait for the next refresh period, the duration will reflect that wait time.
+    /// - In backpressure mode, the duration is the time `poll_ready()` spent waiting.
     ///
     /// # Example
     /// ```rust,no_run
@@ -318,6 +482,15 @@ impl RateLimiterConfigBuilder {
         self
     }
 
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the rate limiter layer.
     pub fn build(self) -> crate::RateLimiterLayer {
         let config = self.into_config();
@@ -348,13 +521,15 @@ impl RateLimiterConfigBuilder {
             config.limit_for_period,
             config.refresh_period,
             config.timeout_duration,
-        );
+            Arc::clone(&config.clock),
+        )
+        .with_max_concurrent_calls(config.max_concurrent_calls)
+        .with_smooth_refill(config.smooth_refill);
 
-        let config = std::sync::Arc::new(config);
+        let config = Arc::new(config);
 
         let handle = crate::RateLimiterHandle {
             limiter: limiter.clone(),
-            config: std::sync::Arc::clone(&config),
         };
 
         let layer = crate::layer::RateLimiterLayer {
@@ -372,6 +547,10 @@ impl RateLimiterConfigBuilder {
             timeout_duration: self.timeout_duration,
             window_type: self.window_type,
             backpressure: self.backpressure,
+            max_concurrent_calls: self.max_concurrent_calls,
+            smooth_refill: self.smooth_refill,
+            observe_only: self.observe_only,
+            clock: self.clock,
             event_listeners: self.event_listeners,
             name: self.name,
         }
@@ -1,5 +1,5 @@
 use std::fmt;
-use tower_resilience_core::ResilienceError;
+use tower_resilience_core::{PatternError, ResilienceError};
 
 /// Errors that can occur when using the rate limiter.
 #[derive(Debug, Clone)]
@@ -86,6 +86,28 @@ impl<E: std::error::Error + 'static> std::error::Error for RateLimiterServiceErr
     }
 }
 
+impl<E> PatternError for RateLimiterServiceError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        self.is_rate_limited()
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            RateLimiterServiceError::RateLimited => None,
+            RateLimiterServiceError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            RateLimiterServiceError::Inner(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 impl<E> From<RateLimiterError> for RateLimiterServiceError<E> {
     fn from(_err: RateLimiterError) -> Self {
         RateLimiterServiceError::RateLimited
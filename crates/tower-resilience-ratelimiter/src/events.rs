@@ -1,24 +1,39 @@
-use std::time::{Duration, Instant};
-use tower_resilience_core::events::ResilienceEvent;
+use std::time::Duration;
+use tower_resilience_core::events::{ResilienceEvent, Severity};
+use tower_resilience_core::time::Instant;
 
 /// Events emitted by the rate limiter middleware.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum RateLimiterEvent {
     /// A permit was successfully acquired.
     PermitAcquired {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         wait_duration: Duration,
     },
     /// A request was rejected due to rate limit.
     PermitRejected {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         timeout_duration: Duration,
     },
     /// Permits were refreshed.
     PermitsRefreshed {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         available_permits: usize,
     },
@@ -48,6 +63,15 @@ impl ResilienceEvent for RateLimiterEvent {
             RateLimiterEvent::PermitsRefreshed { pattern_name, .. } => pattern_name,
         }
     }
+
+    fn severity(&self) -> Severity {
+        match self {
+            RateLimiterEvent::PermitRejected { .. } => Severity::Warn,
+            RateLimiterEvent::PermitAcquired { .. } | RateLimiterEvent::PermitsRefreshed { .. } => {
+                Severity::Info
+            }
+        }
+    }
 }
 
 #[cfg(test)]
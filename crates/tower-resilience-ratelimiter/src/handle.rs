@@ -1,8 +1,6 @@
-use crate::config::RateLimiterConfig;
 use crate::limiter::SharedRateLimiter;
-use std::sync::Arc;
 
-/// A read-only handle for observing rate limiter state.
+/// A handle for observing and adjusting rate limiter state.
 ///
 /// Obtained from [`crate::RateLimiterConfigBuilder::build_with_handle()`]. The handle
 /// is cheap to clone and safe to share across threads (`Clone + Send + Sync`).
@@ -28,7 +26,6 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct RateLimiterHandle {
     pub(crate) limiter: SharedRateLimiter,
-    pub(crate) config: Arc<RateLimiterConfig>,
 }
 
 impl RateLimiterHandle {
@@ -46,7 +43,7 @@ impl RateLimiterHandle {
     ///
     /// A value of 1.0 means all permits are consumed.
     pub fn utilization(&self) -> f64 {
-        let limit = self.config.limit_for_period;
+        let limit = self.limit_for_period();
         if limit == 0 {
             return 0.0;
         }
@@ -55,20 +52,27 @@ impl RateLimiterHandle {
         used as f64 / limit as f64
     }
 
-    /// Returns the configured limit per period.
+    /// Returns the current limit per period.
     pub fn limit_for_period(&self) -> usize {
-        self.config.limit_for_period
+        self.limiter.limit_for_period()
+    }
+
+    /// Changes the limit per period on the running rate limiter.
+    ///
+    /// Permits already used in the current period still count against the
+    /// new limit, so lowering the limit never grants extra calls.
+    pub fn set_limit_for_period(&self, limit: usize) {
+        self.limiter.set_limit_for_period(limit);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::RateLimiterLayer;
     use std::future::Future;
     use std::pin::Pin;
     use std::task::Poll;
-    use tower::{Layer, Service, ServiceExt};
+    use tower::{Layer, Service};
 
     #[derive(Clone)]
     struct OkService;
@@ -135,6 +139,21 @@ mod tests {
         assert_eq!(handle.available_permits(), 2);
     }
 
+    #[tokio::test]
+    async fn test_handle_sets_limit() {
+        let (layer, handle) = RateLimiterLayer::builder()
+            .limit_for_period(2)
+            .refresh_period(std::time::Duration::from_secs(60))
+            .build_with_handle();
+
+        let mut svc = layer.layer(OkService);
+        let _ = svc.call("a".to_string()).await;
+
+        handle.set_limit_for_period(5);
+        assert_eq!(handle.limit_for_period(), 5);
+        assert_eq!(handle.available_permits(), 4);
+    }
+
     #[tokio::test]
     async fn test_handle_clone() {
         let (_layer, handle) = RateLimiterLayer::builder()
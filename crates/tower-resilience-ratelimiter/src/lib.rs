@@ -6,14 +6,17 @@
 //! # Features
 //!
 //! - **Permit-based rate limiting**: Control requests per time period
-//! - **Multiple window types**: Fixed, sliding log, and sliding counter algorithms
+//! - **Multiple window types**: Fixed, sliding log, sliding counter, and GCRA algorithms
 //! - **Configurable timeout**: Wait up to a specified duration for permits
 //! - **Automatic refresh**: Permits automatically refresh after each period
+//! - **Smooth refill**: Optionally release permits evenly across the period
+//! - **Concurrency limit**: Optionally cap in-flight calls alongside the rate
+//! - **Observe-only mode**: Report what a limit would reject without enforcing it
 //! - **Event system**: Observability through rate limiter events
 //!
 //! # Window Types
 //!
-//! The rate limiter supports three different windowing strategies:
+//! The rate limiter supports four different windowing strategies:
 //!
 //! - **Fixed** (default): Resets permits at fixed intervals. Simple and efficient
 //!   but can allow bursts at window boundaries.
@@ -24,6 +27,9 @@
 //! - **SlidingCounter**: Uses weighted averaging between time buckets. Approximate
 //!   sliding window behavior with O(1) memory - ideal for high-throughput APIs.
 //!
+//! - **Gcra**: Paces requests a fixed period apart with a bounded burst, tracking
+//!   a single timestamp - ideal for outbound APIs that require steady spacing.
+//!
 //! # Examples
 //!
 //! ## Basic Rate Limiting (Fixed Window)
@@ -112,6 +118,35 @@
 //! # }
 //! ```
 //!
+//! ## GCRA Rate Limiting (Paced)
+//!
+//! Use GCRA when an upstream API requires requests to be evenly spaced. It
+//! allows a small burst, then lets one request through per `period`.
+//!
+//! ```
+//! use tower_resilience_ratelimiter::{RateLimiterLayer, WindowType};
+//! use tower::ServiceBuilder;
+//! use std::time::Duration;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // 10 requests per second, at most 5 back to back
+//! let rate_limiter = RateLimiterLayer::builder()
+//!     .window_type(WindowType::Gcra {
+//!         period: Duration::from_millis(100),
+//!         burst: 5,
+//!     })
+//!     .timeout_duration(Duration::from_millis(200))
+//!     .build();
+//!
+//! let service = ServiceBuilder::new()
+//!     .layer(rate_limiter)
+//!     .service(tower::service_fn(|req: String| async move {
+//!         Ok::<_, std::io::Error>(format!("Response: {}", req))
+//!     }));
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Fallback When Rate Limited
 //!
 //! Handle rate limiting errors with appropriate fallback strategies:
@@ -218,21 +253,25 @@ mod events;
 mod handle;
 mod layer;
 mod limiter;
+mod settings;
 
 pub use config::{RateLimiterConfig, RateLimiterConfigBuilder, WindowType};
 pub use error::{RateLimiterError, RateLimiterServiceError};
 pub use events::RateLimiterEvent;
 pub use handle::RateLimiterHandle;
 pub use layer::RateLimiterLayer;
+pub use settings::RateLimiterSettings;
 
 use crate::limiter::SharedRateLimiter;
 use futures::future::BoxFuture;
-use futures::Future;
-use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Instant;
+use std::time::Duration;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio_util::sync::PollSemaphore;
 use tower::Service;
+use tower_resilience_core::clock::Sleep;
+use tower_resilience_core::time::Instant;
 
 #[cfg(feature = "metrics")]
 use metrics::{counter, describe_counter, describe_histogram, histogram};
@@ -261,40 +300,33 @@ pub struct RateLimiter<S> {
     config: Arc<RateLimiterConfig>,
     limiter: SharedRateLimiter,
     /// Sleep future for backpressure mode wake-ups.
-    sleep: Option<Pin<Box<tokio::time::Sleep>>>,
+    sleep: Option<Sleep>,
     /// Whether a permit has been acquired in `poll_ready` (backpressure mode only).
     permit_acquired: bool,
+    /// Polls for a concurrency slot (backpressure mode with a concurrency limit).
+    concurrency: Option<PollSemaphore>,
+    /// Concurrency slot reserved in `poll_ready` (backpressure mode only).
+    slot: Option<OwnedSemaphorePermit>,
+    /// When `poll_ready` first had to wait for the current permit.
+    wait_start: Option<Instant>,
+    /// How long `poll_ready` waited for the acquired permit.
+    waited: Duration,
 }
 
 impl<S> RateLimiter<S> {
     /// Creates a new `RateLimiter` wrapping the given service.
     pub fn new(inner: S, config: Arc<RateLimiterConfig>) -> Self {
-        #[cfg(feature = "metrics")]
-        {
-            describe_counter!(
-                "ratelimiter_calls_total",
-                "Total number of rate limiter calls (permitted or rejected)"
-            );
-            describe_histogram!(
-                "ratelimiter_wait_duration_seconds",
-                "Time spent waiting for a permit"
-            );
-        }
-
         let limiter = SharedRateLimiter::new(
             config.window_type,
             config.limit_for_period,
             config.refresh_period,
             config.timeout_duration,
-        );
+            Arc::clone(&config.clock),
+        )
+        .with_max_concurrent_calls(config.max_concurrent_calls)
+        .with_smooth_refill(config.smooth_refill);
 
-        Self {
-            inner,
-            config,
-            limiter,
-            sleep: None,
-            permit_acquired: false,
-        }
+        Self::from_shared(inner, config, limiter)
     }
 
     /// Creates a new `RateLimiter` using pre-created shared limiter state.
@@ -306,21 +338,27 @@ impl<S> RateLimiter<S> {
         #[cfg(feature = "metrics")]
         {
             describe_counter!(
-                "ratelimiter_calls_total",
+                "resilience_ratelimiter_calls_total",
                 "Total number of rate limiter calls (permitted or rejected)"
             );
             describe_histogram!(
-                "ratelimiter_wait_duration_seconds",
+                "resilience_ratelimiter_wait_duration_seconds",
                 "Time spent waiting for a permit"
             );
         }
 
         Self {
             inner,
+            concurrency: limiter
+                .concurrency()
+                .map(|semaphore| PollSemaphore::new(Arc::clone(semaphore))),
+            slot: None,
             config,
             limiter,
             sleep: None,
             permit_acquired: false,
+            wait_start: None,
+            waited: Duration::ZERO,
         }
     }
 }
@@ -336,6 +374,10 @@ where
             limiter: self.limiter.clone(),
             sleep: None,
             permit_acquired: false,
+            concurrency: self.concurrency.clone(),
+            slot: None,
+            wait_start: None,
+            waited: Duration::ZERO,
         }
     }
 }
@@ -359,7 +401,7 @@ where
             Poll::Ready(Ok(())) => {}
         }
 
-        if !self.config.backpressure {
+        if !self.config.backpressure || self.config.observe_only {
             return Poll::Ready(Ok(()));
         }
 
@@ -368,6 +410,20 @@ where
             return Poll::Ready(Ok(()));
         }
 
+        // Reserve a concurrency slot before taking a rate permit
+        if self.slot.is_none() {
+            if let Some(concurrency) = self.concurrency.as_mut() {
+                match concurrency.poll_acquire(cx) {
+                    Poll::Pending => {
+                        let now = self.limiter.clock().now();
+                        self.wait_start.get_or_insert(now);
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(slot) => self.slot = slot,
+                }
+            }
+        }
+
         // If we have a pending sleep, poll it first
         if let Some(sleep) = self.sleep.as_mut() {
             match sleep.as_mut().poll(cx) {
@@ -382,23 +438,62 @@ where
         match self.limiter.try_acquire_now() {
             Ok(()) => {
                 self.permit_acquired = true;
+                let now = self.limiter.clock().now();
+                self.waited = self
+                    .wait_start
+                    .take()
+                    .map_or(Duration::ZERO, |start| now.duration_since(start));
                 Poll::Ready(Ok(()))
             }
             Err(wait_duration) => {
-                let sleep = tokio::time::sleep(wait_duration);
-                let mut pinned = Box::pin(sleep);
+                let clock = self.limiter.clock();
+                let now = clock.now();
+                let mut sleep = clock.sleep(wait_duration);
+                self.wait_start.get_or_insert(now);
                 // Register the waker so we get polled again when the sleep completes
-                let _ = pinned.as_mut().poll(cx);
-                self.sleep = Some(pinned);
+                if sleep.as_mut().poll(cx).is_ready() {
+                    cx.waker().wake_by_ref();
+                } else {
+                    self.sleep = Some(sleep);
+                }
                 Poll::Pending
             }
         }
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tower_resilience_core::span::pattern_span("rate_limiter", &self.config.name);
+            let future = span.in_scope(|| self.call_untraced(req));
+            Box::pin(tower_resilience_core::span::instrument(
+                span,
+                future,
+                tower_resilience_core::span::Outcome::from_result,
+            ))
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.call_untraced(req)
+    }
+}
+
+impl<S> RateLimiter<S> {
+    fn call_untraced<Req>(
+        &mut self,
+        req: Req,
+    ) -> BoxFuture<'static, Result<S::Response, RateLimiterServiceError<S::Error>>>
+    where
+        S: Service<Req> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        S::Error: Send + 'static,
+        Req: Send + 'static,
+    {
         if self.permit_acquired {
             // Backpressure mode: permit already acquired in poll_ready
             self.permit_acquired = false;
+            let slot = self.slot.take();
+            let wait_duration = std::mem::take(&mut self.waited);
             let config = Arc::clone(&self.config);
             let clone = self.inner.clone();
             let mut inner = std::mem::replace(&mut self.inner, clone);
@@ -406,28 +501,31 @@ where
             let event = RateLimiterEvent::PermitAcquired {
                 pattern_name: config.name.clone(),
                 timestamp: Instant::now(),
-                wait_duration: std::time::Duration::ZERO,
+                wait_duration,
             };
             config.event_listeners.emit(&event);
 
             #[cfg(feature = "metrics")]
             {
-                counter!("ratelimiter_calls_total", "ratelimiter" => config.name.clone(), "result" => "permitted").increment(1);
-                histogram!("ratelimiter_wait_duration_seconds", "ratelimiter" => config.name.clone())
-                    .record(0.0);
+                counter!("resilience_ratelimiter_calls_total", "name" => config.name.clone(), "result" => "permitted").increment(1);
+                histogram!("resilience_ratelimiter_wait_duration_seconds", "name" => config.name.clone())
+                    .record(wait_duration.as_secs_f64());
             }
 
             #[cfg(feature = "tracing")]
-            debug!(ratelimiter = %config.name, "Permit acquired via backpressure");
+            debug!(ratelimiter = %config.name, wait_ms = wait_duration.as_millis(), "Permit acquired via backpressure");
 
             return Box::pin(async move {
-                inner
-                    .call(req)
-                    .await
-                    .map_err(RateLimiterServiceError::Inner)
+                let result = inner.call(req).await;
+                drop(slot);
+                result.map_err(RateLimiterServiceError::Inner)
             });
         }
 
+        if self.config.observe_only {
+            return self.call_observed(req);
+        }
+
         // Rejection mode: acquire permit in call
         let limiter = self.limiter.clone();
         let config = Arc::clone(&self.config);
@@ -435,6 +533,20 @@ where
         let mut inner = std::mem::replace(&mut self.inner, clone);
 
         Box::pin(async move {
+            // Take a concurrency slot first so a call that cannot run never
+            // uses up a rate permit
+            let slot = match limiter.concurrency() {
+                Some(semaphore) => {
+                    let acquire = std::pin::pin!(Arc::clone(semaphore).acquire_owned());
+                    let timer = limiter.clock().sleep(config.timeout_duration);
+                    match futures::future::select(acquire, timer).await {
+                        futures::future::Either::Left((Ok(slot), _)) => Some(slot),
+                        _ => return Err(reject(&config)),
+                    }
+                }
+                None => None,
+            };
+
             match limiter.acquire().await {
                 Ok(wait_duration) => {
                     let event = RateLimiterEvent::PermitAcquired {
@@ -446,8 +558,8 @@ where
 
                     #[cfg(feature = "metrics")]
                     {
-                        counter!("ratelimiter_calls_total", "ratelimiter" => config.name.clone(), "result" => "permitted").increment(1);
-                        histogram!("ratelimiter_wait_duration_seconds", "ratelimiter" => config.name.clone())
+                        counter!("resilience_ratelimiter_calls_total", "name" => config.name.clone(), "result" => "permitted").increment(1);
+                        histogram!("resilience_ratelimiter_wait_duration_seconds", "name" => config.name.clone())
                             .record(wait_duration.as_secs_f64());
                     }
 
@@ -464,38 +576,112 @@ where
                         }
                     }
 
-                    inner
-                        .call(req)
-                        .await
-                        .map_err(RateLimiterServiceError::Inner)
+                    let result = inner.call(req).await;
+                    drop(slot);
+                    result.map_err(RateLimiterServiceError::Inner)
                 }
-                Err(()) => {
-                    let event = RateLimiterEvent::PermitRejected {
-                        pattern_name: config.name.clone(),
-                        timestamp: Instant::now(),
-                        timeout_duration: config.timeout_duration,
-                    };
-                    config.event_listeners.emit(&event);
-
-                    #[cfg(feature = "metrics")]
-                    {
-                        counter!("ratelimiter_calls_total", "ratelimiter" => config.name.clone(), "result" => "rejected").increment(1);
-                    }
-
-                    #[cfg(feature = "tracing")]
-                    warn!(
-                        ratelimiter = %config.name,
-                        timeout_ms = config.timeout_duration.as_millis(),
-                        "Rate limit exceeded - permit rejected"
-                    );
+                Err(()) => Err(reject(&config)),
+            }
+        })
+    }
 
-                    Err(RateLimiterServiceError::RateLimited)
+    /// Records the decision for `req` without enforcing it.
+    fn call_observed<Req>(
+        &mut self,
+        req: Req,
+    ) -> BoxFuture<'static, Result<S::Response, RateLimiterServiceError<S::Error>>>
+    where
+        S: Service<Req> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        S::Error: Send + 'static,
+        Req: Send + 'static,
+    {
+        // A call that would have to wait for a concurrency slot counts as rejected
+        let slot = match self.limiter.concurrency() {
+            Some(semaphore) => Arc::clone(semaphore)
+                .try_acquire_owned()
+                .map(Some)
+                .map_err(drop),
+            None => Ok(None),
+        };
+        let decision = slot.and_then(|slot| self.limiter.check().map(|wait| (slot, wait)));
+
+        let slot = match decision {
+            Ok((slot, Duration::ZERO)) => {
+                record_permit(&self.config, Duration::ZERO);
+                slot
+            }
+            Ok((slot, wait_duration)) => {
+                // The enforcing path would wait and then take the permit, or
+                // be rejected if it is gone by then. Reserve that permit now
+                // so observed load uses up permits like real load.
+                match self.limiter.reserve_after(wait_duration) {
+                    Ok(wait_duration) => record_permit(&self.config, wait_duration),
+                    Err(()) => record_rejection(&self.config),
                 }
+                slot
             }
+            Err(()) => {
+                record_rejection(&self.config);
+                None
+            }
+        };
+
+        let future = self.inner.call(req);
+        Box::pin(async move {
+            let result = future.await;
+            drop(slot);
+            result.map_err(RateLimiterServiceError::Inner)
         })
     }
 }
 
+/// Emits the event and metrics for a call that was granted a permit.
+fn record_permit(config: &RateLimiterConfig, wait_duration: Duration) {
+    let event = RateLimiterEvent::PermitAcquired {
+        pattern_name: config.name.clone(),
+        timestamp: Instant::now(),
+        wait_duration,
+    };
+    config.event_listeners.emit(&event);
+
+    #[cfg(feature = "metrics")]
+    {
+        counter!("resilience_ratelimiter_calls_total", "name" => config.name.clone(), "result" => "permitted").increment(1);
+        histogram!("resilience_ratelimiter_wait_duration_seconds", "name" => config.name.clone())
+            .record(wait_duration.as_secs_f64());
+    }
+}
+
+/// Records a rejected call and returns the error for it.
+fn reject<E>(config: &RateLimiterConfig) -> RateLimiterServiceError<E> {
+    record_rejection(config);
+    RateLimiterServiceError::RateLimited
+}
+
+/// Emits the event, metrics and log line for a rejected call.
+fn record_rejection(config: &RateLimiterConfig) {
+    let event = RateLimiterEvent::PermitRejected {
+        pattern_name: config.name.clone(),
+        timestamp: Instant::now(),
+        timeout_duration: config.timeout_duration,
+    };
+    config.event_listeners.emit(&event);
+
+    #[cfg(feature = "metrics")]
+    {
+        counter!("resilience_ratelimiter_calls_total", "name" => config.name.clone(), "result" => "rejected").increment(1);
+    }
+
+    #[cfg(feature = "tracing")]
+    warn!(
+        ratelimiter = %config.name,
+        timeout_ms = config.timeout_duration.as_millis(),
+        observe_only = config.observe_only,
+        "Rate limit exceeded - permit rejected"
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -579,6 +765,154 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_observe_only_reports_but_never_rejects() {
+        let acquired = Arc::new(AtomicUsize::new(0));
+        let rejected = Arc::new(AtomicUsize::new(0));
+        let a = Arc::clone(&acquired);
+        let r = Arc::clone(&rejected);
+
+        let service = service_fn(|req: u32| async move { Ok::<_, std::io::Error>(req) });
+
+        let layer = RateLimiterLayer::builder()
+            .limit_for_period(2)
+            .refresh_period(Duration::from_secs(10))
+            .timeout_duration(Duration::from_millis(10))
+            .observe_only(true)
+            .on_permit_acquired(move |_| {
+                a.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_permit_rejected(move |_| {
+                r.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+
+        let mut service = layer.layer(service);
+
+        for i in 0..5 {
+            let result = service.ready().await.unwrap().call(i).await;
+            assert_eq!(result.unwrap(), i);
+        }
+
+        assert_eq!(acquired.load(Ordering::SeqCst), 2);
+        assert_eq!(rejected.load(Ordering::SeqCst), 3);
+    }
+
+    /// Sends the same load through a limiter and returns the number of
+    /// (acquired, rejected) events it reported.
+    async fn permit_counts(observe_only: bool) -> (usize, usize) {
+        let acquired = Arc::new(AtomicUsize::new(0));
+        let rejected = Arc::new(AtomicUsize::new(0));
+        let a = Arc::clone(&acquired);
+        let r = Arc::clone(&rejected);
+
+        let service = service_fn(|req: u32| async move { Ok::<_, std::io::Error>(req) });
+
+        let layer = RateLimiterLayer::builder()
+            .limit_for_period(2)
+            .refresh_period(Duration::from_millis(200))
+            .timeout_duration(Duration::from_millis(150))
+            .observe_only(observe_only)
+            .on_permit_acquired(move |_| {
+                a.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_permit_rejected(move |_| {
+                r.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+        let service = layer.layer(service);
+
+        // Use up the period, then send a burst that has to wait for the
+        // next one, which only has room for two of them
+        for i in 0..2 {
+            let _ = service.clone().oneshot(i).await;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let burst = (0..5).map(|i| service.clone().oneshot(i));
+        let _ = futures::future::join_all(burst).await;
+
+        (
+            acquired.load(Ordering::SeqCst),
+            rejected.load(Ordering::SeqCst),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_observe_only_matches_enforced_counts() {
+        let (enforced, observed) = tokio::join!(permit_counts(false), permit_counts(true));
+        assert_eq!(enforced, (4, 3));
+        assert_eq!(observed, enforced);
+    }
+
+    #[test]
+    fn test_observe_only_records_waits_inline() {
+        let acquired = Arc::new(AtomicUsize::new(0));
+        let rejected = Arc::new(AtomicUsize::new(0));
+        let a = Arc::clone(&acquired);
+        let r = Arc::clone(&rejected);
+
+        let service = service_fn(|req: u32| async move { Ok::<_, std::io::Error>(req) });
+
+        let layer = RateLimiterLayer::builder()
+            .limit_for_period(10)
+            .refresh_period(Duration::from_secs(60))
+            .timeout_duration(Duration::from_secs(120))
+            .observe_only(true)
+            .on_permit_acquired(move |_| {
+                a.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_permit_rejected(move |_| {
+                r.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+        let mut service = layer.layer(service);
+
+        // No runtime here: would-wait calls must not need a task to report
+        futures::executor::block_on(async {
+            for i in 0..1000 {
+                assert_eq!(service.ready().await.unwrap().call(i).await.unwrap(), i);
+            }
+        });
+
+        // Ten permits now and ten reserved from each of the next two periods
+        assert_eq!(acquired.load(Ordering::SeqCst), 30);
+        assert_eq!(rejected.load(Ordering::SeqCst), 970);
+    }
+
+    #[tokio::test]
+    async fn test_observe_only_counts_busy_concurrency_slots() {
+        let rejected = Arc::new(AtomicUsize::new(0));
+        let r = Arc::clone(&rejected);
+
+        let service = service_fn(|delay: u64| async move {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            Ok::<_, std::io::Error>(delay)
+        });
+
+        let layer = RateLimiterLayer::builder()
+            .limit_for_period(100)
+            .max_concurrent_calls(1)
+            .observe_only(true)
+            .backpressure()
+            .on_permit_rejected(move |_| {
+                r.fetch_add(1, Ordering::SeqCst);
+            })
+            .build();
+
+        let service = layer.layer(service);
+
+        let mut slow = service.clone();
+        let handle = tokio::spawn(async move { slow.ready().await.unwrap().call(50).await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // The only slot is taken, so this call is reported but still runs
+        let mut fast = service.clone();
+        assert_eq!(fast.ready().await.unwrap().call(1).await.unwrap(), 1);
+        assert_eq!(rejected.load(Ordering::SeqCst), 1);
+
+        assert_eq!(handle.await.unwrap().unwrap(), 50);
+    }
+
     #[tokio::test]
     async fn test_permits_refresh_after_period() {
         let call_count = Arc::new(AtomicUsize::new(0));
@@ -854,4 +1188,38 @@ mod tests {
             assert!(result.is_ok());
         }
     }
+
+    #[tokio::test]
+    async fn test_backpressure_reports_wait_duration() {
+        let waits = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let w = Arc::clone(&waits);
+
+        let service =
+            service_fn(|_req: String| async move { Ok::<_, std::io::Error>("ok".to_string()) });
+
+        let layer = RateLimiterLayer::builder()
+            .window_type(WindowType::Gcra {
+                period: Duration::from_millis(30),
+                burst: 1,
+            })
+            .backpressure()
+            .on_permit_acquired(move |wait| w.lock().unwrap().push(wait))
+            .build();
+
+        let mut service = layer.layer(service);
+
+        for _ in 0..2 {
+            let result = service.ready().await.unwrap().call("x".to_string()).await;
+            assert!(result.is_ok());
+        }
+
+        // The first permit was free; the second waited in poll_ready
+        let waits = waits.lock().unwrap();
+        assert_eq!(waits[0], Duration::ZERO);
+        assert!(
+            waits[1] >= Duration::from_millis(20),
+            "waited {:?}",
+            waits[1]
+        );
+    }
 }
@@ -1,8 +1,10 @@
 use crate::config::WindowType;
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
-use tokio::time::sleep;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tower_resilience_core::time::Instant;
+use tower_resilience_core::SharedClock;
 
 /// Result of attempting to acquire a permit.
 /// Ok(wait_duration) means permit acquired (possibly after waiting).
@@ -11,34 +13,40 @@ type AcquireResult = Result<Duration, Duration>;
 
 /// Fixed window rate limiter state.
 ///
-/// Resets all permits at fixed interval boundaries.
+/// Resets all permits at fixed interval boundaries, or with smooth refill,
+/// releases them one at a time spread evenly across the period.
 #[derive(Debug)]
 struct FixedWindowState {
     limit_for_period: usize,
     refresh_period: Duration,
     timeout_duration: Duration,
     available_permits: usize,
+    /// Start of the current period, or with smooth refill, of the current
+    /// permit interval.
     period_start: Instant,
+    smooth_refill: bool,
 }
 
 impl FixedWindowState {
-    fn new(limit_for_period: usize, refresh_period: Duration, timeout_duration: Duration) -> Self {
+    fn new(
+        limit_for_period: usize,
+        refresh_period: Duration,
+        timeout_duration: Duration,
+        now: Instant,
+    ) -> Self {
         Self {
             limit_for_period,
             refresh_period,
             timeout_duration,
             available_permits: limit_for_period,
-            period_start: Instant::now(),
+            period_start: now,
+            smooth_refill: false,
         }
     }
 
-    fn try_acquire(&mut self) -> AcquireResult {
-        let now = Instant::now();
-
+    fn try_acquire(&mut self, now: Instant) -> AcquireResult {
         // Check if we need to refresh the period
-        if now.duration_since(self.period_start) >= self.refresh_period {
-            self.refresh(now);
-        }
+        self.replenish(now);
 
         // If permits available, grant immediately
         if self.available_permits > 0 {
@@ -47,9 +55,7 @@ impl FixedWindowState {
         }
 
         // No permits available - calculate wait time
-        let time_until_refresh = self
-            .refresh_period
-            .saturating_sub(now.duration_since(self.period_start));
+        let time_until_refresh = self.time_until_permit(now);
 
         // Check if wait time exceeds timeout
         if time_until_refresh > self.timeout_duration {
@@ -63,20 +69,65 @@ impl FixedWindowState {
     ///
     /// Returns `Duration::ZERO` if a permit was consumed, or the wait duration
     /// until a permit will be available.
-    fn try_acquire_no_timeout(&mut self) -> Duration {
-        let now = Instant::now();
-
-        if now.duration_since(self.period_start) >= self.refresh_period {
-            self.refresh(now);
-        }
+    fn try_acquire_no_timeout(&mut self, now: Instant) -> Duration {
+        self.replenish(now);
 
         if self.available_permits > 0 {
             self.available_permits -= 1;
             return Duration::ZERO;
         }
 
-        self.refresh_period
-            .saturating_sub(now.duration_since(self.period_start))
+        self.time_until_permit(now)
+    }
+
+    /// Restores the permits that have become available by `now`.
+    fn replenish(&mut self, now: Instant) {
+        if !self.smooth_refill {
+            if now.duration_since(self.period_start) >= self.refresh_period {
+                self.refresh(now);
+            }
+            return;
+        }
+
+        let interval = self.refill_interval().as_nanos();
+        if interval == 0 {
+            self.refresh(now);
+            return;
+        }
+
+        let intervals = now.duration_since(self.period_start).as_nanos() / interval;
+        if intervals == 0 {
+            return;
+        }
+
+        let refilled = usize::try_from(intervals).unwrap_or(usize::MAX);
+        self.available_permits = self
+            .available_permits
+            .saturating_add(refilled)
+            .min(self.limit_for_period);
+        if self.available_permits == self.limit_for_period {
+            // A full window does not bank time toward further permits
+            self.period_start = now;
+        } else {
+            let elapsed = u64::try_from(intervals * interval).unwrap_or(u64::MAX);
+            self.period_start += Duration::from_nanos(elapsed);
+        }
+    }
+
+    /// Time from `now` until the next permit becomes available.
+    fn time_until_permit(&self, now: Instant) -> Duration {
+        let next = if self.smooth_refill {
+            self.refill_interval()
+        } else {
+            self.refresh_period
+        };
+        (self.period_start + next).saturating_duration_since(now)
+    }
+
+    /// Time between permits when refilling smoothly.
+    fn refill_interval(&self) -> Duration {
+        let limit = u32::try_from(self.limit_for_period.max(1)).unwrap_or(u32::MAX);
+        self.refresh_period / limit
     }
 
     fn refresh(&mut self, now: Instant) {
@@ -84,7 +135,14 @@ impl FixedWindowState {
         self.period_start = now;
     }
 
-    fn available_permits(&self) -> usize {
+    /// Changes the limit, keeping the permits already used in this period.
+    fn set_limit(&mut self, limit_for_period: usize) {
+        let used = self.limit_for_period.saturating_sub(self.available_permits);
+        self.limit_for_period = limit_for_period;
+        self.available_permits = limit_for_period.saturating_sub(used);
+    }
+
+    fn available_permits(&self, _now: Instant) -> usize {
         self.available_permits
     }
 }
@@ -112,9 +170,7 @@ impl SlidingLogState {
         }
     }
 
-    fn try_acquire(&mut self) -> AcquireResult {
-        let now = Instant::now();
-
+    fn try_acquire(&mut self, now: Instant) -> AcquireResult {
         // Remove expired entries from the front
         while let Some(&timestamp) = self.request_log.front() {
             if now.duration_since(timestamp) >= self.window_duration {
@@ -126,7 +182,7 @@ impl SlidingLogState {
 
         // Check if we have capacity
         if self.request_log.len() < self.limit_for_period {
-            self.request_log.push_back(now);
+            self.record(now);
             return Ok(Duration::ZERO);
         }
 
@@ -149,9 +205,7 @@ impl SlidingLogState {
     }
 
     /// Attempts to acquire a permit without timeout enforcement.
-    fn try_acquire_no_timeout(&mut self) -> Duration {
-        let now = Instant::now();
-
+    fn try_acquire_no_timeout(&mut self, now: Instant) -> Duration {
         while let Some(&timestamp) = self.request_log.front() {
             if now.duration_since(timestamp) >= self.window_duration {
                 self.request_log.pop_front();
@@ -161,7 +215,7 @@ impl SlidingLogState {
         }
 
         if self.request_log.len() < self.limit_for_period {
-            self.request_log.push_back(now);
+            self.record(now);
             return Duration::ZERO;
         }
 
@@ -175,7 +229,16 @@ impl SlidingLogState {
         }
     }
 
-    fn available_permits(&self) -> usize {
+    /// Logs a request at `now`, keeping the log in time order even when a
+    /// permit was reserved ahead of time.
+    fn record(&mut self, now: Instant) {
+        let index = self
+            .request_log
+            .partition_point(|&timestamp| timestamp <= now);
+        self.request_log.insert(index, now);
+    }
+
+    fn available_permits(&self, _now: Instant) -> usize {
         self.limit_for_period.saturating_sub(self.request_log.len())
     }
 }
@@ -198,19 +261,23 @@ struct SlidingCounterState {
 }
 
 impl SlidingCounterState {
-    fn new(limit_for_period: usize, bucket_duration: Duration, timeout_duration: Duration) -> Self {
+    fn new(
+        limit_for_period: usize,
+        bucket_duration: Duration,
+        timeout_duration: Duration,
+        now: Instant,
+    ) -> Self {
         Self {
             limit_for_period,
             bucket_duration,
             timeout_duration,
             previous_count: 0,
             current_count: 0,
-            bucket_start: Instant::now(),
+            bucket_start: now,
         }
     }
 
-    fn try_acquire(&mut self) -> AcquireResult {
-        let now = Instant::now();
+    fn try_acquire(&mut self, now: Instant) -> AcquireResult {
         self.maybe_rotate_bucket(now);
 
         // Calculate weighted count
@@ -240,8 +307,7 @@ impl SlidingCounterState {
     }
 
     /// Attempts to acquire a permit without timeout enforcement.
-    fn try_acquire_no_timeout(&mut self) -> Duration {
-        let now = Instant::now();
+    fn try_acquire_no_timeout(&mut self, now: Instant) -> Duration {
         self.maybe_rotate_bucket(now);
 
         let elapsed = now.duration_since(self.bucket_start);
@@ -317,8 +383,7 @@ impl SlidingCounterState {
         }
     }
 
-    fn available_permits(&self) -> usize {
-        let now = Instant::now();
+    fn available_permits(&self, now: Instant) -> usize {
         let elapsed = now.duration_since(self.bucket_start);
         let elapsed_ratio =
             (elapsed.as_secs_f64() / self.bucket_duration.as_secs_f64()).clamp(0.0, 1.0);
@@ -331,12 +396,76 @@ impl SlidingCounterState {
     }
 }
 
+/// GCRA rate limiter state.
+///
+/// Tracks the theoretical arrival time (TAT) of the next request. A request
+/// is allowed when it arrives no earlier than `burst - 1` periods before its
+/// TAT, and each allowed request pushes the TAT back by one period.
+#[derive(Debug)]
+struct GcraState {
+    period: Duration,
+    burst: usize,
+    timeout_duration: Duration,
+    /// When the next request would be exactly on schedule.
+    tat: Instant,
+}
+
+impl GcraState {
+    fn new(period: Duration, burst: usize, timeout_duration: Duration, now: Instant) -> Self {
+        Self {
+            period,
+            burst,
+            timeout_duration,
+            tat: now,
+        }
+    }
+
+    fn try_acquire(&mut self, now: Instant) -> AcquireResult {
+        let wait = self.try_acquire_no_timeout(now);
+        if wait > self.timeout_duration {
+            Err(self.timeout_duration)
+        } else {
+            Ok(wait)
+        }
+    }
+
+    /// Attempts to acquire a permit without timeout enforcement.
+    fn try_acquire_no_timeout(&mut self, now: Instant) -> Duration {
+        let tat = self.tat.max(now);
+        let wait = tat
+            .saturating_duration_since(now)
+            .saturating_sub(self.tolerance());
+
+        if wait.is_zero() {
+            self.tat = tat + self.period;
+        }
+        wait
+    }
+
+    /// How far ahead of schedule a request may run.
+    fn tolerance(&self) -> Duration {
+        let extra = u32::try_from(self.burst.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.period.saturating_mul(extra)
+    }
+
+    fn available_permits(&self, now: Instant) -> usize {
+        if self.period.is_zero() {
+            return self.burst;
+        }
+        let ahead = self.tat.saturating_duration_since(now);
+        let used = ahead.as_nanos().div_ceil(self.period.as_nanos());
+        self.burst
+            .saturating_sub(usize::try_from(used).unwrap_or(usize::MAX))
+    }
+}
+
 /// Enum-based rate limiter state that dispatches to the appropriate implementation.
 #[derive(Debug)]
 enum RateLimiterStateInner {
     Fixed(FixedWindowState),
     SlidingLog(SlidingLogState),
     SlidingCounter(SlidingCounterState),
+    Gcra(GcraState),
 }
 
 impl RateLimiterStateInner {
@@ -345,12 +474,14 @@ impl RateLimiterStateInner {
         limit_for_period: usize,
         refresh_period: Duration,
         timeout_duration: Duration,
+        now: Instant,
     ) -> Self {
         match window_type {
             WindowType::Fixed => Self::Fixed(FixedWindowState::new(
                 limit_for_period,
                 refresh_period,
                 timeout_duration,
+                now,
             )),
             WindowType::SlidingLog => Self::SlidingLog(SlidingLogState::new(
                 limit_for_period,
@@ -361,31 +492,59 @@ impl RateLimiterStateInner {
                 limit_for_period,
                 refresh_period,
                 timeout_duration,
+                now,
             )),
+            WindowType::Gcra { period, burst } => {
+                Self::Gcra(GcraState::new(period, burst, timeout_duration, now))
+            }
+        }
+    }
+
+    fn try_acquire(&mut self, now: Instant) -> AcquireResult {
+        match self {
+            Self::Fixed(state) => state.try_acquire(now),
+            Self::SlidingLog(state) => state.try_acquire(now),
+            Self::SlidingCounter(state) => state.try_acquire(now),
+            Self::Gcra(state) => state.try_acquire(now),
+        }
+    }
+
+    fn try_acquire_no_timeout(&mut self, now: Instant) -> Duration {
+        match self {
+            Self::Fixed(state) => state.try_acquire_no_timeout(now),
+            Self::SlidingLog(state) => state.try_acquire_no_timeout(now),
+            Self::SlidingCounter(state) => state.try_acquire_no_timeout(now),
+            Self::Gcra(state) => state.try_acquire_no_timeout(now),
         }
     }
 
-    fn try_acquire(&mut self) -> AcquireResult {
+    fn available_permits(&self, now: Instant) -> usize {
         match self {
-            Self::Fixed(state) => state.try_acquire(),
-            Self::SlidingLog(state) => state.try_acquire(),
-            Self::SlidingCounter(state) => state.try_acquire(),
+            Self::Fixed(state) => state.available_permits(now),
+            Self::SlidingLog(state) => state.available_permits(now),
+            Self::SlidingCounter(state) => state.available_permits(now),
+            Self::Gcra(state) => state.available_permits(now),
         }
     }
 
-    fn try_acquire_no_timeout(&mut self) -> Duration {
+    fn limit_for_period(&self) -> usize {
         match self {
-            Self::Fixed(state) => state.try_acquire_no_timeout(),
-            Self::SlidingLog(state) => state.try_acquire_no_timeout(),
-            Self::SlidingCounter(state) => state.try_acquire_no_timeout(),
+            Self::Fixed(state) => state.limit_for_period,
+            Self::SlidingLog(state) => state.limit_for_period,
+            Self::SlidingCounter(state) => state.limit_for_period,
+            Self::Gcra(state) => state.burst,
         }
     }
 
-    fn available_permits(&self) -> usize {
+    fn set_limit(&mut self, limit_for_period: usize) {
         match self {
-            Self::Fixed(state) => state.available_permits(),
-            Self::SlidingLog(state) => state.available_permits(),
-            Self::SlidingCounter(state) => state.available_permits(),
+            Self::Fixed(state) => state.set_limit(limit_for_period),
+            // Both sliding windows count recorded requests against the limit
+            // on every check, so the new limit applies immediately
+            Self::SlidingLog(state) => state.limit_for_period = limit_for_period,
+            Self::SlidingCounter(state) => state.limit_for_period = limit_for_period,
+            // GCRA has no per-period limit; the handle's limit is the burst
+            Self::Gcra(state) => state.burst = limit_for_period,
         }
     }
 }
@@ -394,6 +553,10 @@ impl RateLimiterStateInner {
 #[derive(Debug, Clone)]
 pub(crate) struct SharedRateLimiter {
     state: Arc<Mutex<RateLimiterStateInner>>,
+    /// Slots for in-flight calls, when concurrency is also limited.
+    concurrency: Option<Arc<Semaphore>>,
+    /// Source of the current time and of waits for permits.
+    clock: SharedClock,
 }
 
 impl SharedRateLimiter {
@@ -402,6 +565,7 @@ impl SharedRateLimiter {
         limit_for_period: usize,
         refresh_period: Duration,
         timeout_duration: Duration,
+        clock: SharedClock,
     ) -> Self {
         Self {
             state: Arc::new(Mutex::new(RateLimiterStateInner::new(
@@ -409,38 +573,89 @@ impl SharedRateLimiter {
                 limit_for_period,
                 refresh_period,
                 timeout_duration,
+                clock.now(),
             ))),
+            concurrency: None,
+            clock,
         }
     }
 
+    /// Also limits in-flight calls to `max_concurrent_calls`, if set.
+    pub(crate) fn with_max_concurrent_calls(mut self, max_concurrent_calls: Option<usize>) -> Self {
+        self.concurrency = max_concurrent_calls.map(|max| Arc::new(Semaphore::new(max)));
+        self
+    }
+
+    /// Releases fixed window permits evenly across the refresh period
+    /// instead of all at once at its boundary.
+    pub(crate) fn with_smooth_refill(self, smooth_refill: bool) -> Self {
+        if let RateLimiterStateInner::Fixed(state) = &mut *self.state.lock().unwrap() {
+            state.smooth_refill = smooth_refill;
+        }
+        self
+    }
+
+    /// Returns the semaphore limiting in-flight calls, if any.
+    pub(crate) fn concurrency(&self) -> Option<&Arc<Semaphore>> {
+        self.concurrency.as_ref()
+    }
+
+    /// Returns the clock the limiter reads time from and waits on.
+    pub(crate) fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
     /// Attempts to acquire a permit.
     /// Returns Ok(duration_waited) if successful, Err if rate limited.
     pub(crate) async fn acquire(&self) -> Result<Duration, ()> {
-        let result = {
-            let mut state = self.state.lock().unwrap();
-            state.try_acquire()
-        };
+        match self.check() {
+            // Got permit immediately
+            Ok(Duration::ZERO) => Ok(Duration::ZERO),
+            // Need to wait
+            Ok(wait_duration) => self.acquire_after(wait_duration).await,
+            // Timeout would be exceeded
+            Err(()) => Err(()),
+        }
+    }
 
-        match result {
-            Ok(Duration::ZERO) => {
-                // Got permit immediately
-                Ok(Duration::ZERO)
-            }
-            Ok(wait_duration) => {
-                // Need to wait
-                sleep(wait_duration).await;
-
-                // Try again after waiting
-                let mut state = self.state.lock().unwrap();
-                match state.try_acquire() {
-                    Ok(additional_wait) => Ok(wait_duration + additional_wait),
-                    Err(_) => Err(()), // Timeout exceeded
-                }
-            }
-            Err(_) => {
-                // Timeout would be exceeded
-                Err(())
-            }
+    /// Decides whether a permit can be granted, without waiting for it.
+    ///
+    /// Returns `Ok(Duration::ZERO)` if a permit was consumed, `Ok(wait)` if
+    /// one may be available within the timeout, and `Err(())` if the call is
+    /// rate limited. A non-zero wait consumes nothing; finish the
+    /// acquisition with [`acquire_after`](Self::acquire_after).
+    pub(crate) fn check(&self) -> Result<Duration, ()> {
+        let now = self.clock.now();
+        self.state.lock().unwrap().try_acquire(now).map_err(|_| ())
+    }
+
+    /// Waits `wait_duration`, as returned by [`check`](Self::check), then
+    /// tries to take the permit.
+    pub(crate) async fn acquire_after(&self, wait_duration: Duration) -> Result<Duration, ()> {
+        self.clock.sleep(wait_duration).await;
+
+        // Try again after waiting
+        let now = self.clock.now();
+        let mut state = self.state.lock().unwrap();
+        match state.try_acquire(now) {
+            Ok(additional_wait) => Ok(wait_duration + additional_wait),
+            Err(_) => Err(()), // Timeout exceeded
+        }
+    }
+
+    /// Takes, without waiting, the permit that would be free once
+    /// `wait_duration`, as returned by [`check`](Self::check), has passed.
+    ///
+    /// The permit is taken as of the end of the wait, so calls checked in
+    /// the meantime already see it as used. Returns `Err(())` if it is
+    /// already reserved.
+    pub(crate) fn reserve_after(&self, wait_duration: Duration) -> Result<Duration, ()> {
+        let at = self.clock.now() + wait_duration;
+        let mut state = self.state.lock().unwrap();
+        if state.try_acquire_no_timeout(at).is_zero() {
+            Ok(wait_duration)
+        } else {
+            Err(())
         }
     }
 
@@ -449,8 +664,9 @@ impl SharedRateLimiter {
     /// Returns `Ok(())` if a permit was consumed, or `Err(wait_duration)` indicating
     /// how long to wait before retrying.
     pub(crate) fn try_acquire_now(&self) -> Result<(), Duration> {
+        let now = self.clock.now();
         let mut state = self.state.lock().unwrap();
-        let wait = state.try_acquire_no_timeout();
+        let wait = state.try_acquire_no_timeout(now);
         if wait == Duration::ZERO {
             Ok(())
         } else {
@@ -461,61 +677,149 @@ impl SharedRateLimiter {
     /// Returns the current number of available permits.
     #[allow(dead_code)]
     pub(crate) fn available_permits(&self) -> usize {
-        self.state.lock().unwrap().available_permits()
+        let now = self.clock.now();
+        self.state.lock().unwrap().available_permits(now)
+    }
+
+    /// Returns the current limit per period.
+    pub(crate) fn limit_for_period(&self) -> usize {
+        self.state.lock().unwrap().limit_for_period()
+    }
+
+    /// Changes the limit per period for all services sharing this limiter.
+    pub(crate) fn set_limit_for_period(&self, limit_for_period: usize) {
+        self.state.lock().unwrap().set_limit(limit_for_period);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tower_resilience_core::SystemClock;
 
     // ==================== Fixed Window Tests ====================
 
+    #[test]
+    fn test_fixed_set_limit_keeps_used_permits() {
+        let mut state = FixedWindowState::new(
+            10,
+            Duration::from_secs(60),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        for _ in 0..4 {
+            assert_eq!(state.try_acquire(Instant::now()), Ok(Duration::ZERO));
+        }
+
+        state.set_limit(20);
+        assert_eq!(state.available_permits(Instant::now()), 16);
+
+        state.set_limit(3);
+        assert_eq!(state.available_permits(Instant::now()), 0);
+    }
+
     #[test]
     fn test_fixed_initial_permits() {
-        let state = FixedWindowState::new(10, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.available_permits(), 10);
+        let state = FixedWindowState::new(
+            10,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        assert_eq!(state.available_permits(Instant::now()), 10);
     }
 
     #[test]
     fn test_fixed_acquire_permit() {
-        let mut state =
-            FixedWindowState::new(10, Duration::from_secs(1), Duration::from_millis(100));
+        let mut state = FixedWindowState::new(
+            10,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
 
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Duration::ZERO);
-        assert_eq!(state.available_permits(), 9);
+        assert_eq!(state.available_permits(Instant::now()), 9);
     }
 
     #[test]
     fn test_fixed_exhaust_permits() {
-        let mut state =
-            FixedWindowState::new(2, Duration::from_millis(100), Duration::from_secs(1));
+        let mut state = FixedWindowState::new(
+            2,
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Instant::now(),
+        );
 
-        assert!(state.try_acquire().is_ok());
-        assert!(state.try_acquire().is_ok());
-        assert_eq!(state.available_permits(), 0);
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert_eq!(state.available_permits(Instant::now()), 0);
 
         // Next acquire should indicate wait needed
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_fixed_refresh_restores_permits() {
-        let mut state = FixedWindowState::new(5, Duration::from_millis(10), Duration::from_secs(1));
+        let mut state = FixedWindowState::new(
+            5,
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+            Instant::now(),
+        );
 
         for _ in 0..5 {
-            state.try_acquire().unwrap();
+            state.try_acquire(Instant::now()).unwrap();
         }
-        assert_eq!(state.available_permits(), 0);
+        assert_eq!(state.available_permits(Instant::now()), 0);
 
         std::thread::sleep(Duration::from_millis(15));
 
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
-        assert!(state.available_permits() > 0);
+        assert!(state.available_permits(Instant::now()) > 0);
+    }
+
+    #[test]
+    fn test_fixed_smooth_refill_releases_permits_gradually() {
+        let mut state = FixedWindowState::new(
+            10,
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Instant::now(),
+        );
+        state.smooth_refill = true;
+
+        for _ in 0..10 {
+            state.try_acquire_no_timeout(Instant::now());
+        }
+
+        // The next permit is one tenth of the period away, not a full period
+        let wait = state.try_acquire_no_timeout(Instant::now());
+        assert!(wait > Duration::ZERO && wait <= Duration::from_millis(10));
+
+        std::thread::sleep(Duration::from_millis(25));
+        state.replenish(Instant::now());
+        assert!((2..10).contains(&state.available_permits(Instant::now())));
+    }
+
+    #[test]
+    fn test_fixed_smooth_refill_caps_at_limit() {
+        let mut state = FixedWindowState::new(
+            5,
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+            Instant::now(),
+        );
+        state.smooth_refill = true;
+        state.try_acquire(Instant::now()).unwrap();
+
+        std::thread::sleep(Duration::from_millis(30));
+        state.replenish(Instant::now());
+        assert_eq!(state.available_permits(Instant::now()), 5);
     }
 
     // ==================== Sliding Log Tests ====================
@@ -523,7 +827,7 @@ mod tests {
     #[test]
     fn test_sliding_log_initial_permits() {
         let state = SlidingLogState::new(10, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.available_permits(), 10);
+        assert_eq!(state.available_permits(Instant::now()), 10);
     }
 
     #[test]
@@ -531,22 +835,22 @@ mod tests {
         let mut state =
             SlidingLogState::new(10, Duration::from_secs(1), Duration::from_millis(100));
 
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Duration::ZERO);
-        assert_eq!(state.available_permits(), 9);
+        assert_eq!(state.available_permits(Instant::now()), 9);
     }
 
     #[test]
     fn test_sliding_log_exhaust_permits() {
         let mut state = SlidingLogState::new(2, Duration::from_millis(100), Duration::from_secs(1));
 
-        assert!(state.try_acquire().is_ok());
-        assert!(state.try_acquire().is_ok());
-        assert_eq!(state.available_permits(), 0);
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert_eq!(state.available_permits(Instant::now()), 0);
 
         // Next acquire should indicate wait needed
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
         assert!(result.unwrap() > Duration::ZERO);
     }
@@ -555,81 +859,114 @@ mod tests {
     fn test_sliding_log_expires_old_requests() {
         let mut state = SlidingLogState::new(2, Duration::from_millis(50), Duration::from_secs(1));
 
-        assert!(state.try_acquire().is_ok());
-        assert!(state.try_acquire().is_ok());
-        assert_eq!(state.available_permits(), 0);
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert_eq!(state.available_permits(Instant::now()), 0);
 
         // Wait for requests to expire
         std::thread::sleep(Duration::from_millis(60));
 
         // Should be able to acquire again
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Duration::ZERO);
     }
 
+    #[test]
+    fn test_sliding_log_keeps_reserved_entries_in_order() {
+        let mut state = SlidingLogState::new(2, Duration::from_millis(100), Duration::from_secs(1));
+        let now = Instant::now();
+
+        // A permit reserved ahead of time must not keep earlier ones from expiring
+        assert_eq!(
+            state.try_acquire(now + Duration::from_millis(80)),
+            Ok(Duration::ZERO)
+        );
+        assert_eq!(state.try_acquire(now), Ok(Duration::ZERO));
+
+        let later = now + Duration::from_millis(120);
+        assert_eq!(state.try_acquire(later), Ok(Duration::ZERO));
+        assert_eq!(state.available_permits(later), 0);
+    }
+
     #[test]
     fn test_sliding_log_no_boundary_burst() {
         let mut state =
             SlidingLogState::new(2, Duration::from_millis(100), Duration::from_millis(50));
 
         // Acquire 2 permits
-        assert!(state.try_acquire().is_ok());
-        assert!(state.try_acquire().is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
 
         // Wait 60ms (past fixed window boundary but within sliding window)
         std::thread::sleep(Duration::from_millis(60));
 
         // With sliding log, these requests are still in the window
         // so we should NOT be able to acquire more (unlike fixed window)
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         // Should either need to wait or timeout
         assert!(result.is_ok()); // Returns wait duration
-        assert!(result.unwrap() > Duration::ZERO || state.available_permits() < 2);
+        assert!(result.unwrap() > Duration::ZERO || state.available_permits(Instant::now()) < 2);
     }
 
     // ==================== Sliding Counter Tests ====================
 
     #[test]
     fn test_sliding_counter_initial_permits() {
-        let state =
-            SlidingCounterState::new(10, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.available_permits(), 10);
+        let state = SlidingCounterState::new(
+            10,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        assert_eq!(state.available_permits(Instant::now()), 10);
     }
 
     #[test]
     fn test_sliding_counter_acquire_permit() {
-        let mut state =
-            SlidingCounterState::new(10, Duration::from_secs(1), Duration::from_millis(100));
+        let mut state = SlidingCounterState::new(
+            10,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
 
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Duration::ZERO);
-        assert_eq!(state.available_permits(), 9);
+        assert_eq!(state.available_permits(Instant::now()), 9);
     }
 
     #[test]
     fn test_sliding_counter_exhaust_permits() {
-        let mut state =
-            SlidingCounterState::new(2, Duration::from_millis(100), Duration::from_secs(1));
+        let mut state = SlidingCounterState::new(
+            2,
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Instant::now(),
+        );
 
-        assert!(state.try_acquire().is_ok());
-        assert!(state.try_acquire().is_ok());
-        assert_eq!(state.available_permits(), 0);
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert_eq!(state.available_permits(Instant::now()), 0);
 
         // Next acquire should indicate wait needed
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
         assert!(result.unwrap() > Duration::ZERO);
     }
 
     #[test]
     fn test_sliding_counter_bucket_rotation() {
-        let mut state =
-            SlidingCounterState::new(2, Duration::from_millis(50), Duration::from_secs(1));
+        let mut state = SlidingCounterState::new(
+            2,
+            Duration::from_millis(50),
+            Duration::from_secs(1),
+            Instant::now(),
+        );
 
-        assert!(state.try_acquire().is_ok());
-        assert!(state.try_acquire().is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
 
         // Wait for bucket to rotate
         std::thread::sleep(Duration::from_millis(55));
@@ -642,10 +979,72 @@ mod tests {
         std::thread::sleep(Duration::from_millis(30));
 
         // Now weighted should be less than limit
-        let result = state.try_acquire();
+        let result = state.try_acquire(Instant::now());
         assert!(result.is_ok());
     }
 
+    // ==================== GCRA Tests ====================
+
+    #[test]
+    fn test_gcra_initial_permits() {
+        let state = GcraState::new(
+            Duration::from_millis(100),
+            3,
+            Duration::ZERO,
+            Instant::now(),
+        );
+        assert_eq!(state.available_permits(Instant::now()), 3);
+    }
+
+    #[test]
+    fn test_gcra_allows_burst_then_paces() {
+        let mut state = GcraState::new(
+            Duration::from_millis(100),
+            3,
+            Duration::ZERO,
+            Instant::now(),
+        );
+
+        for _ in 0..3 {
+            assert_eq!(state.try_acquire(Instant::now()), Ok(Duration::ZERO));
+        }
+        assert_eq!(state.available_permits(Instant::now()), 0);
+
+        // The next request must wait about one period
+        let wait = state.try_acquire_no_timeout(Instant::now());
+        assert!(wait > Duration::from_millis(90) && wait <= Duration::from_millis(100));
+        assert_eq!(state.try_acquire(Instant::now()), Err(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_gcra_frees_one_permit_per_period() {
+        let mut state =
+            GcraState::new(Duration::from_millis(20), 2, Duration::ZERO, Instant::now());
+
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_ok());
+        assert!(state.try_acquire(Instant::now()).is_err());
+
+        std::thread::sleep(Duration::from_millis(25));
+
+        assert_eq!(state.try_acquire(Instant::now()), Ok(Duration::ZERO));
+        assert!(state.try_acquire(Instant::now()).is_err());
+    }
+
+    #[test]
+    fn test_gcra_wait_within_timeout() {
+        let mut state = GcraState::new(
+            Duration::from_millis(10),
+            1,
+            Duration::from_millis(50),
+            Instant::now(),
+        );
+
+        assert_eq!(state.try_acquire(Instant::now()), Ok(Duration::ZERO));
+        let wait = state.try_acquire(Instant::now()).unwrap();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_millis(10));
+    }
+
     // ==================== SharedRateLimiter Tests ====================
 
     #[tokio::test]
@@ -655,6 +1054,7 @@ mod tests {
             2,
             Duration::from_secs(1),
             Duration::from_millis(100),
+            Arc::new(SystemClock),
         );
 
         assert!(limiter.acquire().await.is_ok());
@@ -671,6 +1071,7 @@ mod tests {
             2,
             Duration::from_secs(1),
             Duration::from_millis(100),
+            Arc::new(SystemClock),
         );
 
         assert!(limiter.acquire().await.is_ok());
@@ -687,6 +1088,7 @@ mod tests {
             2,
             Duration::from_secs(1),
             Duration::from_millis(100),
+            Arc::new(SystemClock),
         );
 
         assert!(limiter.acquire().await.is_ok());
@@ -700,50 +1102,66 @@ mod tests {
 
     #[test]
     fn test_fixed_try_acquire_no_timeout_returns_zero_when_available() {
-        let mut state =
-            FixedWindowState::new(2, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.try_acquire_no_timeout(), Duration::ZERO);
-        assert_eq!(state.available_permits(), 1);
+        let mut state = FixedWindowState::new(
+            2,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        assert_eq!(state.try_acquire_no_timeout(Instant::now()), Duration::ZERO);
+        assert_eq!(state.available_permits(Instant::now()), 1);
     }
 
     #[test]
     fn test_fixed_try_acquire_no_timeout_returns_wait_when_exhausted() {
-        let mut state =
-            FixedWindowState::new(1, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.try_acquire_no_timeout(), Duration::ZERO);
-        let wait = state.try_acquire_no_timeout();
+        let mut state = FixedWindowState::new(
+            1,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        assert_eq!(state.try_acquire_no_timeout(Instant::now()), Duration::ZERO);
+        let wait = state.try_acquire_no_timeout(Instant::now());
         assert!(wait > Duration::ZERO);
     }
 
     #[test]
     fn test_sliding_log_try_acquire_no_timeout_returns_zero_when_available() {
         let mut state = SlidingLogState::new(2, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.try_acquire_no_timeout(), Duration::ZERO);
-        assert_eq!(state.available_permits(), 1);
+        assert_eq!(state.try_acquire_no_timeout(Instant::now()), Duration::ZERO);
+        assert_eq!(state.available_permits(Instant::now()), 1);
     }
 
     #[test]
     fn test_sliding_log_try_acquire_no_timeout_returns_wait_when_exhausted() {
         let mut state = SlidingLogState::new(1, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.try_acquire_no_timeout(), Duration::ZERO);
-        let wait = state.try_acquire_no_timeout();
+        assert_eq!(state.try_acquire_no_timeout(Instant::now()), Duration::ZERO);
+        let wait = state.try_acquire_no_timeout(Instant::now());
         assert!(wait > Duration::ZERO);
     }
 
     #[test]
     fn test_sliding_counter_try_acquire_no_timeout_returns_zero_when_available() {
-        let mut state =
-            SlidingCounterState::new(2, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.try_acquire_no_timeout(), Duration::ZERO);
-        assert_eq!(state.available_permits(), 1);
+        let mut state = SlidingCounterState::new(
+            2,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        assert_eq!(state.try_acquire_no_timeout(Instant::now()), Duration::ZERO);
+        assert_eq!(state.available_permits(Instant::now()), 1);
     }
 
     #[test]
     fn test_sliding_counter_try_acquire_no_timeout_returns_wait_when_exhausted() {
-        let mut state =
-            SlidingCounterState::new(1, Duration::from_secs(1), Duration::from_millis(100));
-        assert_eq!(state.try_acquire_no_timeout(), Duration::ZERO);
-        let wait = state.try_acquire_no_timeout();
+        let mut state = SlidingCounterState::new(
+            1,
+            Duration::from_secs(1),
+            Duration::from_millis(100),
+            Instant::now(),
+        );
+        assert_eq!(state.try_acquire_no_timeout(Instant::now()), Duration::ZERO);
+        let wait = state.try_acquire_no_timeout(Instant::now());
         assert!(wait > Duration::ZERO);
     }
 
@@ -756,6 +1174,7 @@ mod tests {
             2,
             Duration::from_secs(1),
             Duration::from_millis(100),
+            Arc::new(SystemClock),
         );
         assert!(limiter.try_acquire_now().is_ok());
         assert_eq!(limiter.available_permits(), 1);
@@ -768,6 +1187,7 @@ mod tests {
             1,
             Duration::from_secs(1),
             Duration::from_millis(100),
+            Arc::new(SystemClock),
         );
         assert!(limiter.try_acquire_now().is_ok());
         let result = limiter.try_acquire_now();
@@ -0,0 +1,144 @@
+//! Rate limiter settings that can be loaded from configuration files.
+
+use crate::config::{RateLimiterConfigBuilder, WindowType};
+use crate::layer::RateLimiterLayer;
+use std::time::Duration;
+
+/// Plain-data rate limiter settings, deserializable with the `serde` feature.
+///
+/// Every field is optional; unset fields keep the builder defaults. Durations
+/// accept milliseconds or strings such as `"1s"` (see
+/// [`parse_duration`](tower_resilience_core::settings::parse_duration)).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tower_resilience_ratelimiter::{RateLimiterLayer, RateLimiterSettings};
+///
+/// let settings = RateLimiterSettings {
+///     limit_for_period: Some(100),
+///     refresh_period: Some(Duration::from_secs(1)),
+///     ..Default::default()
+/// };
+///
+/// let layer = RateLimiterLayer::from_config(settings);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct RateLimiterSettings {
+    /// Instance name used in events and metrics.
+    pub name: Option<String>,
+    /// Permits available per refresh period.
+    pub limit_for_period: Option<usize>,
+    /// Length of each refresh period.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub refresh_period: Option<Duration>,
+    /// Maximum time to wait for a permit before rejecting.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub timeout_duration: Option<Duration>,
+    /// Windowing algorithm: `fixed`, `sliding_log`, `sliding_counter` or
+    /// `{"gcra": {"period": "100ms", "burst": 5}}`.
+    pub window_type: Option<WindowType>,
+    /// Make `poll_ready` wait for a permit instead of rejecting calls.
+    pub backpressure: Option<bool>,
+    /// Maximum number of calls in flight at once.
+    pub max_concurrent_calls: Option<usize>,
+    /// Release fixed window permits evenly across the refresh period.
+    pub smooth_refill: Option<bool>,
+    /// Report rejections without enforcing them.
+    pub observe_only: Option<bool>,
+}
+
+impl RateLimiterConfigBuilder {
+    /// Applies every field that is set in `settings`, leaving the rest unchanged.
+    pub fn settings(mut self, settings: RateLimiterSettings) -> Self {
+        if let Some(name) = settings.name {
+            self = self.name(name);
+        }
+        if let Some(limit) = settings.limit_for_period {
+            self = self.limit_for_period(limit);
+        }
+        if let Some(duration) = settings.refresh_period {
+            self = self.refresh_period(duration);
+        }
+        if let Some(duration) = settings.timeout_duration {
+            self = self.timeout_duration(duration);
+        }
+        if let Some(window_type) = settings.window_type {
+            self = self.window_type(window_type);
+        }
+        if settings.backpressure == Some(true) {
+            self = self.backpressure();
+        }
+        if let Some(max) = settings.max_concurrent_calls {
+            self = self.max_concurrent_calls(max);
+        }
+        if settings.smooth_refill == Some(true) {
+            self = self.smooth_refill();
+        }
+        if let Some(observe_only) = settings.observe_only {
+            self = self.observe_only(observe_only);
+        }
+        self
+    }
+}
+
+impl RateLimiterLayer {
+    /// Creates a rate limiter layer from [`RateLimiterSettings`].
+    pub fn from_config(settings: RateLimiterSettings) -> Self {
+        Self::builder().settings(settings).build()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_settings() {
+        let settings: RateLimiterSettings = serde_json::from_str(
+            r#"{
+                "limit_for_period": 100,
+                "refresh_period": "1s",
+                "timeout_duration": 0,
+                "window_type": "sliding_counter",
+                "observe_only": true
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.limit_for_period, Some(100));
+        assert_eq!(settings.refresh_period, Some(Duration::from_secs(1)));
+        assert_eq!(settings.timeout_duration, Some(Duration::ZERO));
+        assert_eq!(settings.window_type, Some(WindowType::SlidingCounter));
+        assert_eq!(settings.observe_only, Some(true));
+
+        let _layer = RateLimiterLayer::from_config(settings);
+    }
+
+    #[test]
+    fn test_deserialize_gcra_window_type() {
+        let settings: RateLimiterSettings = serde_json::from_str(
+            r#"{
+                "window_type": { "gcra": { "period": "100ms", "burst": 5 } }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings.window_type,
+            Some(WindowType::Gcra {
+                period: Duration::from_millis(100),
+                burst: 5,
+            })
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Classification of connection-level errors.
+
+use tower_resilience_core::classifier::DefaultClassifier;
+
+/// Decides whether an error means the connection itself is broken.
+///
+/// Only errors classified as disconnects trigger reconnection; everything
+/// else is a request-level failure and is returned to the caller untouched as
+/// [`ReconnectError::ServiceError`](crate::ReconnectError::ServiceError).
+///
+/// [`DefaultClassifier`] treats every error as a disconnect, and any
+/// `Fn(&E) -> bool` closure is a classifier. Custom classifiers are
+/// installed with
+/// [`ReconnectLayer::disconnect_classifier`](crate::ReconnectLayer::disconnect_classifier).
+pub trait DisconnectClassifier<E>: Send + Sync {
+    /// Returns `true` if `error` indicates a broken connection.
+    fn is_disconnect(&self, error: &E) -> bool;
+}
+
+impl<E> DisconnectClassifier<E> for DefaultClassifier {
+    fn is_disconnect(&self, _error: &E) -> bool {
+        true
+    }
+}
+
+impl<F, E> DisconnectClassifier<E> for F
+where
+    F: Fn(&E) -> bool + Send + Sync,
+{
+    fn is_disconnect(&self, error: &E) -> bool {
+        self(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Error, ErrorKind};
+
+    #[test]
+    fn test_default_classifier_treats_all_errors_as_disconnects() {
+        let classifier = DefaultClassifier;
+        assert!(classifier.is_disconnect(&Error::from(ErrorKind::BrokenPipe)));
+        assert!(classifier.is_disconnect(&Error::other("rate limited")));
+    }
+
+    #[test]
+    fn test_closure_classifier() {
+        let classifier = |error: &Error| {
+            matches!(
+                error.kind(),
+                ErrorKind::BrokenPipe | ErrorKind::ConnectionReset
+            )
+        };
+        assert!(classifier.is_disconnect(&Error::from(ErrorKind::BrokenPipe)));
+        assert!(classifier.is_disconnect(&Error::from(ErrorKind::ConnectionReset)));
+        assert!(!classifier.is_disconnect(&Error::other("rate limited")));
+    }
+}
@@ -1,5 +1,12 @@
+use crate::events::ReconnectEvent;
 use crate::policy::ReconnectPolicy;
+use crate::state::ConnectionState;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tower_resilience_core::{EventListeners, FnListener};
+
+#[cfg(feature = "metrics")]
+use metrics::{counter, gauge};
 
 /// Determines whether an error should trigger reconnection.
 ///
@@ -9,6 +16,9 @@ pub type ReconnectPredicate = Arc<dyn Fn(&dyn std::error::Error) -> bool + Send
 
 /// Configuration for reconnection behavior.
 pub struct ReconnectConfig {
+    /// Name of this reconnect instance, used in events and metrics.
+    pub(crate) name: String,
+
     /// The reconnection policy determining backoff strategy.
     pub(crate) policy: ReconnectPolicy,
 
@@ -23,6 +33,9 @@ pub struct ReconnectConfig {
     /// None means all errors trigger reconnection (default).
     pub(crate) reconnect_predicate: Option<ReconnectPredicate>,
 
+    /// Listeners for connection lifecycle events.
+    pub(crate) event_listeners: EventListeners<ReconnectEvent>,
+
     /// Optional callback for reconnection events.
     #[cfg(feature = "tracing")]
     pub(crate) on_reconnect: Option<Arc<dyn Fn(u32) + Send + Sync>>,
@@ -37,10 +50,12 @@ pub struct ReconnectConfig {
 impl Clone for ReconnectConfig {
     fn clone(&self) -> Self {
         Self {
+            name: self.name.clone(),
             policy: self.policy.clone(),
             max_attempts: self.max_attempts,
             retry_on_reconnect: self.retry_on_reconnect,
             reconnect_predicate: self.reconnect_predicate.clone(),
+            event_listeners: self.event_listeners.clone(),
             #[cfg(feature = "tracing")]
             on_reconnect: self.on_reconnect.clone(),
             #[cfg(feature = "tracing")]
@@ -53,6 +68,7 @@ impl std::fmt::Debug for ReconnectConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug_struct = f.debug_struct("ReconnectConfig");
         debug_struct
+            .field("name", &self.name)
             .field("policy", &self.policy)
             .field("max_attempts", &self.max_attempts)
             .field("retry_on_reconnect", &self.retry_on_reconnect)
@@ -77,6 +93,11 @@ impl ReconnectConfig {
         ReconnectConfigBuilder::default()
     }
 
+    /// Returns the name of this reconnect instance.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     /// Returns the reconnection policy.
     pub fn policy(&self) -> &ReconnectPolicy {
         &self.policy
@@ -103,15 +124,80 @@ impl ReconnectConfig {
             true // Reconnect on all errors by default
         }
     }
+
+    /// Records that a connection-level error broke the connection.
+    pub(crate) fn record_disconnected(&self) {
+        self.record_state(ConnectionState::Disconnected);
+        self.event_listeners.emit(&ReconnectEvent::Disconnected {
+            pattern_name: self.name.clone(),
+            timestamp: Instant::now(),
+        });
+    }
+
+    /// Records that a reconnection attempt has been scheduled.
+    pub(crate) fn record_attempt(&self, attempt: u32, delay: Duration) {
+        #[cfg(feature = "metrics")]
+        counter!("resilience_reconnect_attempts_total", "name" => self.name.clone()).increment(1);
+
+        self.record_state(ConnectionState::Reconnecting);
+        self.event_listeners
+            .emit(&ReconnectEvent::ReconnectAttempt {
+                pattern_name: self.name.clone(),
+                timestamp: Instant::now(),
+                attempt,
+                delay,
+            });
+    }
+
+    /// Records a usable connection, emitting `Reconnected` if it took any attempts.
+    pub(crate) fn record_connected(&self, attempts: u32) {
+        self.record_state(ConnectionState::Connected);
+        if attempts > 0 {
+            self.event_listeners.emit(&ReconnectEvent::Reconnected {
+                pattern_name: self.name.clone(),
+                timestamp: Instant::now(),
+                attempts,
+            });
+        }
+    }
+
+    /// Records that reconnection was abandoned.
+    pub(crate) fn record_gave_up(&self, attempts: u32) {
+        self.record_state(ConnectionState::Disconnected);
+        self.event_listeners.emit(&ReconnectEvent::GaveUp {
+            pattern_name: self.name.clone(),
+            timestamp: Instant::now(),
+            attempts,
+        });
+    }
+
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    fn record_state(&self, state: ConnectionState) {
+        #[cfg(feature = "metrics")]
+        for (candidate, label) in [
+            (ConnectionState::Connected, "connected"),
+            (ConnectionState::Disconnected, "disconnected"),
+            (ConnectionState::Reconnecting, "reconnecting"),
+        ] {
+            gauge!(
+                "resilience_reconnect_connection_state",
+                "name" => self.name.clone(),
+                "state" => label
+            )
+            .set(if candidate == state { 1.0 } else { 0.0 });
+        }
+    }
 }
 
 impl Default for ReconnectConfig {
     fn default() -> Self {
         Self {
+            name: String::from("<unnamed>"),
             policy: ReconnectPolicy::default(),
             max_attempts: None,
             retry_on_reconnect: true,
             reconnect_predicate: None,
+            event_listeners: EventListeners::new(),
             #[cfg(feature = "tracing")]
             on_reconnect: None,
             #[cfg(feature = "tracing")]
@@ -122,10 +208,12 @@ impl Default for ReconnectConfig {
 
 /// Builder for constructing a `ReconnectConfig`.
 pub struct ReconnectConfigBuilder {
+    name: String,
     policy: ReconnectPolicy,
     max_attempts: Option<u32>,
     retry_on_reconnect: bool,
     reconnect_predicate: Option<ReconnectPredicate>,
+    event_listeners: EventListeners<ReconnectEvent>,
     #[cfg(feature = "tracing")]
     on_reconnect: Option<Arc<dyn Fn(u32) + Send + Sync>>,
     #[cfg(feature = "tracing")]
@@ -138,6 +226,7 @@ impl std::fmt::Debug for ReconnectConfigBuilder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug_struct = f.debug_struct("ReconnectConfigBuilder");
         debug_struct
+            .field("name", &self.name)
             .field("policy", &self.policy)
             .field("max_attempts", &self.max_attempts)
             .field("retry_on_reconnect", &self.retry_on_reconnect)
@@ -162,6 +251,15 @@ impl ReconnectConfigBuilder {
         Self::default()
     }
 
+    /// Sets the name of this reconnect instance for observability.
+    ///
+    /// The name is used as the `reconnect` label on metrics and as the
+    /// `pattern_name` of emitted events. Defaults to `"<unnamed>"`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
     /// Sets the reconnection policy.
     ///
     /// # Examples
@@ -310,6 +408,86 @@ impl ReconnectConfigBuilder {
         self
     }
 
+    /// Registers a callback for when a connection-level error breaks the connection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tower_resilience_reconnect::ReconnectConfig;
+    ///
+    /// let config = ReconnectConfig::builder()
+    ///     .on_disconnected(|| println!("connection lost"))
+    ///     .build();
+    /// ```
+    pub fn on_disconnected<F>(mut self, f: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if matches!(event, ReconnectEvent::Disconnected { .. }) {
+                f();
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback for each scheduled reconnection attempt.
+    ///
+    /// The callback receives the attempt number and the backoff delay before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tower_resilience_reconnect::ReconnectConfig;
+    ///
+    /// let config = ReconnectConfig::builder()
+    ///     .on_reconnect_attempt(|attempt, delay| {
+    ///         println!("reconnect attempt {} in {:?}", attempt, delay);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_reconnect_attempt<F>(mut self, f: F) -> Self
+    where
+        F: Fn(u32, Duration) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ReconnectEvent::ReconnectAttempt { attempt, delay, .. } = event {
+                f(*attempt, *delay);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback for when the connection is re-established.
+    ///
+    /// The callback receives the number of attempts it took.
+    pub fn on_reconnected<F>(mut self, f: F) -> Self
+    where
+        F: Fn(u32) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ReconnectEvent::Reconnected { attempts, .. } = event {
+                f(*attempts);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback for when reconnection is abandoned.
+    ///
+    /// The callback receives the number of attempts made.
+    pub fn on_gave_up<F>(mut self, f: F) -> Self
+    where
+        F: Fn(u32) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let ReconnectEvent::GaveUp { attempts, .. } = event {
+                f(*attempts);
+            }
+        }));
+        self
+    }
+
     /// Creates a predicate that only triggers reconnection) on common connection-level errors.
     ///
     /// This is a convenience helper that recognizes standard connection errors like:
@@ -342,13 +520,24 @@ impl ReconnectConfigBuilder {
         self
     }
 
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the `ReconnectConfig`.
     pub fn build(self) -> ReconnectConfig {
         ReconnectConfig {
+            name: self.name,
             policy: self.policy,
             max_attempts: self.max_attempts,
             retry_on_reconnect: self.retry_on_reconnect,
             reconnect_predicate: self.reconnect_predicate,
+            event_listeners: self.event_listeners,
             #[cfg(feature = "tracing")]
             on_reconnect: self.on_reconnect,
             #[cfg(feature = "tracing")]
@@ -360,10 +549,12 @@ impl ReconnectConfigBuilder {
 impl Default for ReconnectConfigBuilder {
     fn default() -> Self {
         Self {
+            name: String::from("<unnamed>"),
             policy: ReconnectPolicy::default(),
             max_attempts: None,
             retry_on_reconnect: true,
             reconnect_predicate: None,
+            event_listeners: EventListeners::new(),
             #[cfg(feature = "tracing")]
             on_reconnect: None,
             #[cfg(feature = "tracing")]
@@ -434,7 +625,7 @@ mod tests {
         let error = Error::new(ErrorKind::BrokenPipe, "test");
         assert!(config.should_reconnect(&error));
 
-        let error = Error::new(ErrorKind::Other, "test");
+        let error = Error::other("test");
         assert!(config.should_reconnect(&error));
     }
 
@@ -463,7 +654,7 @@ mod tests {
         )));
 
         // Other errors should NOT trigger reconnection
-        assert!(!config.should_reconnect(&Error::new(ErrorKind::Other, "other error")));
+        assert!(!config.should_reconnect(&Error::other("other error")));
         assert!(!config.should_reconnect(&Error::new(ErrorKind::TimedOut, "timed out")));
         assert!(!config.should_reconnect(&Error::new(
             ErrorKind::PermissionDenied,
@@ -0,0 +1,925 @@
+//! Connection factories for establishing fresh inner services.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use pin_project::pin_project;
+use tower::Service;
+use tower_resilience_core::classifier::DefaultClassifier;
+#[cfg(feature = "tracing")]
+use tower_resilience_core::span::{self, Outcome};
+
+use crate::{
+    classifier::DisconnectClassifier,
+    config::ReconnectConfig,
+    probe::{HealthProbe, NoProbe, ProbeError},
+    service::ReconnectError,
+    state::{ConnectionState, ReconnectState},
+};
+
+/// Establishes new connections for a [`ConnectorService`].
+///
+/// A connector is the factory the reconnect layer uses to dial a brand new
+/// inner service (re-open a TCP stream, re-create a gRPC channel, ...) when
+/// the current one is broken, instead of retrying on a clone of the dead one.
+///
+/// Any `FnMut() -> impl Future<Output = Result<S, E>>` closure is a connector.
+///
+/// # Examples
+///
+/// ```
+/// use tower_resilience_reconnect::Connector;
+///
+/// # async fn example() {
+/// let mut connector = || async {
+///     Ok::<_, std::io::Error>(tower::service_fn(|req: String| async move {
+///         Ok::<_, std::io::Error>(req)
+///     }))
+/// };
+///
+/// let service = connector.connect().await.unwrap();
+/// # let _ = service;
+/// # }
+/// ```
+pub trait Connector {
+    /// The service produced by a successful connection.
+    type Service;
+
+    /// The error returned when a connection cannot be established.
+    type Error;
+
+    /// The future returned by [`connect`](Connector::connect).
+    type Future: Future<Output = Result<Self::Service, Self::Error>>;
+
+    /// Attempts to establish a new connection.
+    fn connect(&mut self) -> Self::Future;
+}
+
+impl<F, Fut, S, E> Connector for F
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<S, E>>,
+{
+    type Service = S;
+    type Error = E;
+    type Future = Fut;
+
+    fn connect(&mut self) -> Self::Future {
+        self()
+    }
+}
+
+/// The connection shared by every clone of a [`ConnectorService`].
+///
+/// The generation increases with each new connection so that a request which
+/// observed a failure only discards the connection it actually used.
+struct Slot<S> {
+    generation: u64,
+    connection: Option<S>,
+    last_used: Instant,
+}
+
+/// A connection handed out to a request.
+struct Checkout<S> {
+    generation: u64,
+    connection: S,
+    /// How long the connection sat unused before this request.
+    idle: Duration,
+}
+
+impl<S: Clone> Slot<S> {
+    fn checkout(slot: &Mutex<Self>) -> Option<Checkout<S>> {
+        let mut slot = slot.lock().unwrap_or_else(|e| e.into_inner());
+        let connection = slot.connection.clone()?;
+        let now = Instant::now();
+        let idle = now.saturating_duration_since(slot.last_used);
+        slot.last_used = now;
+        Some(Checkout {
+            generation: slot.generation,
+            connection,
+            idle,
+        })
+    }
+
+    fn install(slot: &Mutex<Self>, connection: S) -> u64 {
+        let mut slot = slot.lock().unwrap_or_else(|e| e.into_inner());
+        slot.generation += 1;
+        slot.connection = Some(connection);
+        slot.last_used = Instant::now();
+        slot.generation
+    }
+
+    fn invalidate(slot: &Mutex<Self>, generation: u64) {
+        let mut slot = slot.lock().unwrap_or_else(|e| e.into_inner());
+        if slot.generation == generation {
+            slot.connection = None;
+        }
+    }
+}
+
+/// A Tower Service that manages its own connection through a [`Connector`].
+///
+/// The first request dials a connection; later requests share it across all
+/// clones. When a request fails with a reconnectable error the connection is
+/// dropped and, after the configured backoff, the connector is asked for a
+/// new one. Failed connection attempts count towards `max_attempts` and are
+/// retried with the same backoff. If a [`HealthProbe`] is configured, every
+/// new connection must pass it before it is shared or receives the request.
+///
+/// Connections left unused for longer than the [idle
+/// timeout](Self::idle_timeout) are validated with the
+/// [keepalive](Self::keepalive) probe, or re-dialed if none is set, before
+/// the next request is sent through them.
+///
+/// Created with [`ReconnectLayer::connector`](crate::ReconnectLayer::connector).
+pub struct ConnectorService<C: Connector, D = DefaultClassifier, P = NoProbe, K = NoProbe> {
+    connector: C,
+    config: Arc<ReconnectConfig>,
+    state: ReconnectState,
+    classifier: Arc<D>,
+    probe: Arc<P>,
+    idle_timeout: Option<Duration>,
+    keepalive: Option<Arc<K>>,
+    slot: Arc<Mutex<Slot<C::Service>>>,
+}
+
+impl<C: Connector + Clone, D, P, K> Clone for ConnectorService<C, D, P, K> {
+    fn clone(&self) -> Self {
+        Self {
+            connector: self.connector.clone(),
+            config: self.config.clone(),
+            state: self.state.clone(),
+            classifier: self.classifier.clone(),
+            probe: self.probe.clone(),
+            idle_timeout: self.idle_timeout,
+            keepalive: self.keepalive.clone(),
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<C: Connector, D, P> ConnectorService<C, D, P> {
+    /// Creates a new `ConnectorService` that dials connections with `connector`.
+    pub(crate) fn new(
+        connector: C,
+        config: Arc<ReconnectConfig>,
+        state: ReconnectState,
+        classifier: Arc<D>,
+        probe: Arc<P>,
+    ) -> Self {
+        Self {
+            connector,
+            config,
+            state,
+            classifier,
+            probe,
+            idle_timeout: None,
+            keepalive: None,
+            slot: Arc::new(Mutex::new(Slot {
+                generation: 0,
+                connection: None,
+                last_used: Instant::now(),
+            })),
+        }
+    }
+}
+
+impl<C: Connector, D, P, K> ConnectorService<C, D, P, K> {
+    /// Sets how long a connection may sit unused before it is checked again.
+    ///
+    /// The next request after the idle timeout first runs the
+    /// [keepalive](Self::keepalive) probe against the connection; without a
+    /// keepalive the connection is dropped and a fresh one dialed. This
+    /// catches sockets silently closed by the peer or a middlebox before a
+    /// real request is sent into them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer};
+    ///
+    /// let service = ReconnectLayer::new(ReconnectConfig::default())
+    ///     .connector(|| async {
+    ///         Ok::<_, std::io::Error>(tower::service_fn(|req: String| async move {
+    ///             Ok::<_, std::io::Error>(req)
+    ///         }))
+    ///     })
+    ///     .idle_timeout(Duration::from_secs(60));
+    /// ```
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the probe used to validate a connection that has been idle.
+    ///
+    /// If the keepalive succeeds the request proceeds on the existing
+    /// connection; if it fails the connection is discarded and a new one is
+    /// dialed straight away. Has no effect without an
+    /// [idle timeout](Self::idle_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tower::ServiceExt;
+    /// use tower::util::BoxCloneService;
+    /// use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer};
+    ///
+    /// let service = ReconnectLayer::new(ReconnectConfig::default())
+    ///     .connector(|| async {
+    ///         Ok::<_, std::io::Error>(BoxCloneService::new(tower::service_fn(
+    ///             |req: String| async move { Ok::<_, std::io::Error>(req) },
+    ///         )))
+    ///     })
+    ///     .idle_timeout(Duration::from_secs(30))
+    ///     .keepalive(|svc: BoxCloneService<String, String, std::io::Error>| async move {
+    ///         svc.oneshot("PING".to_string()).await
+    ///     });
+    /// ```
+    pub fn keepalive<F>(self, keepalive: F) -> ConnectorService<C, D, P, F> {
+        ConnectorService {
+            connector: self.connector,
+            config: self.config,
+            state: self.state,
+            classifier: self.classifier,
+            probe: self.probe,
+            idle_timeout: self.idle_timeout,
+            keepalive: Some(Arc::new(keepalive)),
+            slot: self.slot,
+        }
+    }
+
+    /// Returns a reference to the current reconnection state.
+    pub fn state(&self) -> &ReconnectState {
+        &self.state
+    }
+
+    /// Returns a reference to the reconnection configuration.
+    pub fn config(&self) -> &ReconnectConfig {
+        &self.config
+    }
+
+    /// Returns `true` if a connection is currently established.
+    pub fn is_connected(&self) -> bool {
+        self.slot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .connection
+            .is_some()
+    }
+}
+
+impl<C, D, P, K, Request> Service<Request> for ConnectorService<C, D, P, K>
+where
+    C: Connector + Clone,
+    C::Service: Service<Request> + Clone,
+    C::Error: std::error::Error + Send + Sync + 'static,
+    <C::Service as Service<Request>>::Error: std::error::Error + Send + Sync + 'static,
+    D: DisconnectClassifier<<C::Service as Service<Request>>::Error>,
+    P: HealthProbe<C::Service>,
+    K: HealthProbe<C::Service>,
+    Request: Clone,
+{
+    type Response = <C::Service as Service<Request>>::Response;
+    type Error = ReconnectError<<C::Service as Service<Request>>::Error>;
+    type Future = ConnectorFuture<C, Request, D, P, K>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The connection is readied inside the response future, since it may
+        // have to be established (or re-established) first.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let (generation, connection, phase) = match Slot::checkout(&self.slot) {
+            Some(checkout) if self.idle_timeout.is_some_and(|t| checkout.idle >= t) => {
+                match &self.keepalive {
+                    Some(keepalive) => {
+                        let validate = keepalive.probe(checkout.connection.clone());
+                        (
+                            checkout.generation,
+                            Some(checkout.connection),
+                            Phase::Validating(validate),
+                        )
+                    }
+                    None => {
+                        // Nothing to check it with; start over on a fresh connection
+                        Slot::invalidate(&self.slot, checkout.generation);
+                        (0, None, Phase::Connecting(self.connector.connect()))
+                    }
+                }
+            }
+            Some(checkout) => (
+                checkout.generation,
+                Some(checkout.connection),
+                Phase::Readying,
+            ),
+            None => (0, None, Phase::Connecting(self.connector.connect())),
+        };
+
+        ConnectorFuture {
+            connector: self.connector.clone(),
+            config: self.config.clone(),
+            state: self.state.clone(),
+            classifier: self.classifier.clone(),
+            probe: self.probe.clone(),
+            slot: self.slot.clone(),
+            request,
+            attempt: 0,
+            generation,
+            connection,
+            last_error: None,
+            #[cfg(feature = "tracing")]
+            span: span::pattern_span("reconnect", &self.config.name),
+            phase,
+        }
+    }
+}
+
+/// Future returned by [`ConnectorService`].
+#[pin_project]
+pub struct ConnectorFuture<C, Request, D = DefaultClassifier, P = NoProbe, K = NoProbe>
+where
+    C: Connector,
+    C::Service: Service<Request>,
+    P: HealthProbe<C::Service>,
+    K: HealthProbe<C::Service>,
+{
+    connector: C,
+    config: Arc<ReconnectConfig>,
+    state: ReconnectState,
+    classifier: Arc<D>,
+    probe: Arc<P>,
+    slot: Arc<Mutex<Slot<C::Service>>>,
+    request: Request,
+    attempt: u32,
+    generation: u64,
+    connection: Option<C::Service>,
+    last_error: Option<<C::Service as Service<Request>>::Error>,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[pin]
+    phase: Phase<C::Future, P::Future, K::Future, CallFuture<C, Request>>,
+}
+
+/// The future of a request sent over an established connection.
+type CallFuture<C, Request> = <<C as Connector>::Service as Service<Request>>::Future;
+
+#[pin_project(project = PhaseProj)]
+enum Phase<Connect, Probe, Keepalive, Call> {
+    Connecting(#[pin] Connect),
+    Probing(#[pin] Probe),
+    /// Checking an idle connection with the keepalive probe.
+    Validating(#[pin] Keepalive),
+    Readying,
+    Calling(#[pin] Call),
+    Sleeping(#[pin] tokio::time::Sleep),
+    Done,
+}
+
+/// Why the current attempt failed.
+enum Failure<E> {
+    /// The connection failed a readiness check or a call.
+    Call(E),
+    /// The connector could not establish a connection, or (`probe: true`)
+    /// the new connection failed its health probe.
+    Dial { error: ProbeError, probe: bool },
+}
+
+/// What to do after a failed call or connection attempt.
+pub(crate) enum Backoff {
+    Sleep(Duration),
+    Exhausted,
+    Disabled,
+}
+
+impl Backoff {
+    pub(crate) fn next(config: &ReconnectConfig, attempt: u32) -> Self {
+        if config.max_attempts.is_some_and(|max| attempt > max) {
+            return Backoff::Exhausted;
+        }
+        match config.policy.delay_for_attempt(attempt as usize) {
+            Some(delay) => Backoff::Sleep(delay),
+            None => Backoff::Disabled,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn notify_state_change(config: &ReconnectConfig, from: ConnectionState, to: ConnectionState) {
+    #[cfg(feature = "tracing")]
+    if let Some(ref callback) = config.on_state_change {
+        callback(from, to);
+    }
+}
+
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn notify_reconnect(config: &ReconnectConfig, attempt: u32) {
+    #[cfg(feature = "tracing")]
+    if let Some(ref callback) = config.on_reconnect {
+        callback(attempt);
+    }
+}
+
+impl<C, D, P, K, Request> ConnectorFuture<C, Request, D, P, K>
+where
+    C: Connector,
+    C::Service: Service<Request> + Clone,
+    C::Error: std::error::Error + Send + Sync + 'static,
+    <C::Service as Service<Request>>::Error: std::error::Error + Send + Sync + 'static,
+    D: DisconnectClassifier<<C::Service as Service<Request>>::Error>,
+    P: HealthProbe<C::Service>,
+    K: HealthProbe<C::Service>,
+    Request: Clone,
+{
+    /// Drives the request through connecting, calling and backing off.
+    fn poll_phase(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<<Self as Future>::Output> {
+        let mut this = self.project();
+
+        loop {
+            let failure = match this.phase.as_mut().project() {
+                PhaseProj::Connecting(connect) => match connect.poll(cx) {
+                    Poll::Ready(Ok(connection)) => {
+                        let probe = this.probe.probe(connection.clone());
+                        *this.connection = Some(connection);
+                        this.phase.set(Phase::Probing(probe));
+                        continue;
+                    }
+                    Poll::Ready(Err(error)) => Failure::Dial {
+                        error: Box::new(error),
+                        probe: false,
+                    },
+                    Poll::Pending => return Poll::Pending,
+                },
+                PhaseProj::Probing(probe) => match probe.poll(cx) {
+                    Poll::Ready(Ok(())) => {
+                        let connection = this
+                            .connection
+                            .clone()
+                            .expect("connection is set before probing");
+                        *this.generation = Slot::install(this.slot, connection);
+                        let from = this.state.state();
+                        this.state.mark_connected();
+                        this.config.record_connected(*this.attempt);
+                        notify_state_change(this.config, from, ConnectionState::Connected);
+
+                        if this.last_error.is_some() && !this.config.retry_on_reconnect {
+                            this.phase.set(Phase::Done);
+                            let error = this.last_error.take().unwrap();
+                            return Poll::Ready(Err(ReconnectError::ConnectionFailedNoRetry(
+                                error,
+                            )));
+                        }
+                        this.phase.set(Phase::Readying);
+                        continue;
+                    }
+                    Poll::Ready(Err(error)) => {
+                        *this.connection = None;
+                        Failure::Dial { error, probe: true }
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                PhaseProj::Validating(keepalive) => match keepalive.poll(cx) {
+                    Poll::Ready(Ok(())) => {
+                        this.phase.set(Phase::Readying);
+                        continue;
+                    }
+                    Poll::Ready(Err(_)) => {
+                        // The idle connection is dead; replace it right away
+                        // without counting it as a failed attempt
+                        Slot::invalidate(this.slot, *this.generation);
+                        *this.connection = None;
+                        this.state.mark_disconnected();
+                        this.config.record_disconnected();
+                        this.phase.set(Phase::Connecting(this.connector.connect()));
+                        continue;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                PhaseProj::Readying => {
+                    let connection = this
+                        .connection
+                        .as_mut()
+                        .expect("connection is set before readying");
+                    match connection.poll_ready(cx) {
+                        Poll::Ready(Ok(())) => {
+                            let call_future = connection.call(this.request.clone());
+                            this.phase.set(Phase::Calling(call_future));
+                            continue;
+                        }
+                        Poll::Ready(Err(error)) => Failure::Call(error),
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+                PhaseProj::Calling(call_future) => match call_future.poll(cx) {
+                    Poll::Ready(Ok(response)) => {
+                        this.phase.set(Phase::Done);
+                        if this.state.state() != ConnectionState::Connected {
+                            this.config.record_connected(*this.attempt);
+                        }
+                        this.state.mark_connected();
+                        return Poll::Ready(Ok(response));
+                    }
+                    Poll::Ready(Err(error)) => Failure::Call(error),
+                    Poll::Pending => return Poll::Pending,
+                },
+                PhaseProj::Sleeping(sleep) => match sleep.poll(cx) {
+                    Poll::Ready(()) => {
+                        // Another request may already have reconnected while
+                        // we were backing off; reuse its connection.
+                        if let Some(checkout) = Slot::checkout(this.slot) {
+                            *this.generation = checkout.generation;
+                            *this.connection = Some(checkout.connection);
+                            // Only a request whose call failed has anything to
+                            // not retry; one that backed off after a failed
+                            // dial goes on with the shared connection.
+                            if !this.config.retry_on_reconnect {
+                                if let Some(error) = this.last_error.take() {
+                                    this.phase.set(Phase::Done);
+                                    return Poll::Ready(Err(
+                                        ReconnectError::ConnectionFailedNoRetry(error),
+                                    ));
+                                }
+                            }
+                            this.phase.set(Phase::Readying);
+                        } else {
+                            this.phase.set(Phase::Connecting(this.connector.connect()));
+                        }
+                        continue;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                PhaseProj::Done => {
+                    panic!("ConnectorFuture polled after completion");
+                }
+            };
+
+            let error = match failure {
+                Failure::Call(error) => error,
+                Failure::Dial { error, probe } => {
+                    this.state.mark_disconnected();
+                    *this.attempt += 1;
+
+                    match Backoff::next(this.config, *this.attempt) {
+                        Backoff::Sleep(delay) => {
+                            this.state.mark_reconnecting();
+                            this.config.record_attempt(*this.attempt, delay);
+                            notify_reconnect(this.config, *this.attempt);
+                            this.phase.set(Phase::Sleeping(tokio::time::sleep(delay)));
+                            continue;
+                        }
+                        Backoff::Exhausted => {
+                            this.config.record_gave_up(*this.attempt);
+                            this.phase.set(Phase::Done);
+                            return Poll::Ready(Err(ReconnectError::MaxAttemptsExceeded {
+                                attempts: *this.attempt,
+                                error,
+                            }));
+                        }
+                        Backoff::Disabled => {
+                            this.config.record_gave_up(*this.attempt);
+                            this.phase.set(Phase::Done);
+                            return Poll::Ready(Err(if probe {
+                                ReconnectError::ProbeFailed(error)
+                            } else {
+                                ReconnectError::ConnectorFailed(error)
+                            }));
+                        }
+                    }
+                }
+            };
+
+            // The connection failed a readiness check or a call.
+            if !this.classifier.is_disconnect(&error) || !this.config.should_reconnect(&error) {
+                this.phase.set(Phase::Done);
+                return Poll::Ready(Err(ReconnectError::ServiceError(error)));
+            }
+
+            Slot::invalidate(this.slot, *this.generation);
+            *this.connection = None;
+            this.state.mark_disconnected();
+            if *this.attempt == 0 {
+                this.config.record_disconnected();
+            }
+            notify_state_change(
+                this.config,
+                ConnectionState::Connected,
+                ConnectionState::Disconnected,
+            );
+            *this.attempt += 1;
+
+            match Backoff::next(this.config, *this.attempt) {
+                Backoff::Sleep(delay) => {
+                    *this.last_error = Some(error);
+                    this.state.mark_reconnecting();
+                    this.config.record_attempt(*this.attempt, delay);
+                    notify_state_change(
+                        this.config,
+                        ConnectionState::Disconnected,
+                        ConnectionState::Reconnecting,
+                    );
+                    notify_reconnect(this.config, *this.attempt);
+                    this.phase.set(Phase::Sleeping(tokio::time::sleep(delay)));
+                }
+                Backoff::Exhausted => {
+                    this.config.record_gave_up(*this.attempt);
+                    this.phase.set(Phase::Done);
+                    return Poll::Ready(Err(ReconnectError::MaxAttemptsExceeded {
+                        attempts: *this.attempt,
+                        error: Box::new(error),
+                    }));
+                }
+                Backoff::Disabled => {
+                    this.config.record_gave_up(*this.attempt);
+                    this.phase.set(Phase::Done);
+                    return Poll::Ready(Err(ReconnectError::ConnectionFailed(error)));
+                }
+            }
+        }
+    }
+}
+
+impl<C, D, P, K, Request> Future for ConnectorFuture<C, Request, D, P, K>
+where
+    C: Connector,
+    C::Service: Service<Request> + Clone,
+    C::Error: std::error::Error + Send + Sync + 'static,
+    <C::Service as Service<Request>>::Error: std::error::Error + Send + Sync + 'static,
+    D: DisconnectClassifier<<C::Service as Service<Request>>::Error>,
+    P: HealthProbe<C::Service>,
+    K: HealthProbe<C::Service>,
+    Request: Clone,
+{
+    type Output = Result<
+        <C::Service as Service<Request>>::Response,
+        ReconnectError<<C::Service as Service<Request>>::Error>,
+    >;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            let span = self.span.clone();
+            let _enter = span.enter();
+            let poll = self.poll_phase(cx);
+            if let Poll::Ready(result) = &poll {
+                span::record_outcome(&span, Outcome::from_result(result));
+            }
+            poll
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.poll_phase(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReconnectLayer, ReconnectPolicy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::ServiceExt;
+
+    /// A connection that serves `healthy_calls` requests before breaking.
+    #[derive(Clone)]
+    struct Connection {
+        id: usize,
+        remaining: Arc<AtomicUsize>,
+    }
+
+    impl Service<String> for Connection {
+        type Response = String;
+        type Error = std::io::Error;
+        type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: String) -> Self::Future {
+            let alive = self
+                .remaining
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            let id = self.id;
+            Box::pin(async move {
+                if alive {
+                    Ok(format!("{}: {}", id, req))
+                } else {
+                    Err(std::io::Error::new(
+                        std::io::ErrorKind::BrokenPipe,
+                        "broken pipe",
+                    ))
+                }
+            })
+        }
+    }
+
+    fn connector(
+        dials: Arc<AtomicUsize>,
+        failed_dials: usize,
+        healthy_calls: usize,
+    ) -> impl Fn() -> Pin<Box<dyn Future<Output = Result<Connection, std::io::Error>> + Send>> + Clone
+    {
+        move || {
+            let id = dials.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                if id < failed_dials {
+                    Err(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionRefused,
+                        "connection refused",
+                    ))
+                } else {
+                    Ok(Connection {
+                        id,
+                        remaining: Arc::new(AtomicUsize::new(healthy_calls)),
+                    })
+                }
+            })
+        }
+    }
+
+    fn config(max_attempts: u32) -> ReconnectConfig {
+        ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(5)))
+            .max_attempts(max_attempts)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_connects_lazily_and_reuses_connection() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let mut service =
+            ReconnectLayer::new(config(3)).connector(connector(Arc::clone(&dials), 0, usize::MAX));
+
+        assert!(!service.is_connected());
+        assert_eq!(service.call("a".to_string()).await.unwrap(), "0: a");
+        assert_eq!(service.call("b".to_string()).await.unwrap(), "0: b");
+        assert!(service.is_connected());
+        assert_eq!(dials.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_redials_after_connection_breaks() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let mut service =
+            ReconnectLayer::new(config(3)).connector(connector(Arc::clone(&dials), 0, 1));
+
+        assert_eq!(service.call("a".to_string()).await.unwrap(), "0: a");
+        // The first connection is now broken; the request is replayed on a new one
+        assert_eq!(service.call("b".to_string()).await.unwrap(), "1: b");
+        assert_eq!(dials.load(Ordering::SeqCst), 2);
+        assert_eq!(service.state().state(), ConnectionState::Connected);
+    }
+
+    #[tokio::test]
+    async fn test_failed_dials_back_off_until_connected() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let mut service =
+            ReconnectLayer::new(config(5)).connector(connector(Arc::clone(&dials), 2, usize::MAX));
+
+        assert_eq!(service.call("a".to_string()).await.unwrap(), "2: a");
+        assert_eq!(dials.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_failed_dials_exhaust_max_attempts() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let mut service = ReconnectLayer::new(config(2)).connector(connector(
+            Arc::clone(&dials),
+            usize::MAX,
+            usize::MAX,
+        ));
+
+        match service.call("a".to_string()).await {
+            Err(ReconnectError::MaxAttemptsExceeded { attempts, .. }) => assert_eq!(attempts, 3),
+            other => panic!("Expected MaxAttemptsExceeded, got {:?}", other.err()),
+        }
+        assert_eq!(dials.load(Ordering::SeqCst), 3);
+        assert!(!service.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_dial_failure_without_backoff() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let config = ReconnectConfig::builder()
+            .policy(ReconnectPolicy::none())
+            .build();
+        let mut service = ReconnectLayer::new(config).connector(connector(
+            Arc::clone(&dials),
+            usize::MAX,
+            usize::MAX,
+        ));
+
+        let result = service.call("a".to_string()).await;
+        assert!(matches!(result, Err(ReconnectError::ConnectorFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_after_redial() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let config = ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(5)))
+            .retry_on_reconnect(false)
+            .build();
+        let mut service =
+            ReconnectLayer::new(config).connector(connector(Arc::clone(&dials), 0, 1));
+
+        service.call("a".to_string()).await.unwrap();
+        let result = service.call("b".to_string()).await;
+        assert!(matches!(
+            result,
+            Err(ReconnectError::ConnectionFailedNoRetry(_))
+        ));
+        // The new connection is in place for the next request
+        assert_eq!(dials.load(Ordering::SeqCst), 2);
+        assert_eq!(service.call("c".to_string()).await.unwrap(), "1: c");
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_reuses_connection_after_failed_dial() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let config = ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(50)))
+            .retry_on_reconnect(false)
+            .build();
+        let service =
+            ReconnectLayer::new(config).connector(connector(Arc::clone(&dials), 1, usize::MAX));
+
+        // The first request's dial fails and it backs off; the second dials
+        // successfully in the meantime and the first picks up its connection.
+        let first = service.clone().oneshot("a".to_string());
+        let second = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            service.clone().oneshot("b".to_string()).await
+        };
+        let (first, second) = tokio::join!(first, second);
+
+        assert_eq!(second.unwrap(), "1: b");
+        assert_eq!(first.unwrap(), "1: a");
+        assert_eq!(dials.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_idle_connection_redialed_without_keepalive() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let mut service = ReconnectLayer::new(config(3))
+            .connector(connector(Arc::clone(&dials), 0, usize::MAX))
+            .idle_timeout(Duration::from_millis(20));
+
+        assert_eq!(service.call("a".to_string()).await.unwrap(), "0: a");
+        assert_eq!(service.call("b".to_string()).await.unwrap(), "0: b");
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(service.call("c".to_string()).await.unwrap(), "1: c");
+        assert_eq!(dials.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_idle_connection_kept_when_keepalive_succeeds() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let keepalives = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&keepalives);
+        let mut service = ReconnectLayer::new(config(3))
+            .connector(connector(Arc::clone(&dials), 0, usize::MAX))
+            .idle_timeout(Duration::from_millis(20))
+            .keepalive(move |conn: Connection| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                conn.oneshot("PING".to_string())
+            });
+
+        assert_eq!(service.call("a".to_string()).await.unwrap(), "0: a");
+        // Not idle long enough for a keepalive
+        assert_eq!(service.call("b".to_string()).await.unwrap(), "0: b");
+        assert_eq!(keepalives.load(Ordering::SeqCst), 0);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(service.call("c".to_string()).await.unwrap(), "0: c");
+        assert_eq!(keepalives.load(Ordering::SeqCst), 1);
+        assert_eq!(dials.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idle_connection_replaced_when_keepalive_fails() {
+        let dials = Arc::new(AtomicUsize::new(0));
+        let mut service = ReconnectLayer::new(config(0))
+            .connector(connector(Arc::clone(&dials), 0, 1))
+            .idle_timeout(Duration::from_millis(20))
+            .keepalive(|conn: Connection| conn.oneshot("PING".to_string()));
+
+        assert_eq!(service.call("a".to_string()).await.unwrap(), "0: a");
+
+        // The keepalive finds the connection dead; the request goes to a fresh
+        // one without spending a reconnect attempt
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(service.call("b".to_string()).await.unwrap(), "1: b");
+        assert_eq!(dials.load(Ordering::SeqCst), 2);
+        assert_eq!(service.state().state(), ConnectionState::Connected);
+    }
+}
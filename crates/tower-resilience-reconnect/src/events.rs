@@ -0,0 +1,133 @@
+//! Event types for the reconnect pattern.
+
+use std::time::{Duration, Instant};
+use tower_resilience_core::ResilienceEvent;
+
+/// Events emitted over the lifecycle of a reconnecting connection.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum ReconnectEvent {
+    /// A connection-level error was observed and the connection is considered broken.
+    Disconnected {
+        /// The name of the reconnect instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+    },
+    /// A reconnection attempt was scheduled.
+    ReconnectAttempt {
+        /// The name of the reconnect instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The attempt number, starting at 1.
+        attempt: u32,
+        /// The backoff delay before the attempt.
+        delay: Duration,
+    },
+    /// The connection was re-established.
+    Reconnected {
+        /// The name of the reconnect instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The number of attempts it took.
+        attempts: u32,
+    },
+    /// Reconnection was abandoned and the error returned to the caller.
+    GaveUp {
+        /// The name of the reconnect instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The number of attempts made.
+        attempts: u32,
+    },
+}
+
+impl ResilienceEvent for ReconnectEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            ReconnectEvent::Disconnected { .. } => "reconnect_disconnected",
+            ReconnectEvent::ReconnectAttempt { .. } => "reconnect_attempt",
+            ReconnectEvent::Reconnected { .. } => "reconnect_reconnected",
+            ReconnectEvent::GaveUp { .. } => "reconnect_gave_up",
+        }
+    }
+
+    fn timestamp(&self) -> Instant {
+        match self {
+            ReconnectEvent::Disconnected { timestamp, .. }
+            | ReconnectEvent::ReconnectAttempt { timestamp, .. }
+            | ReconnectEvent::Reconnected { timestamp, .. }
+            | ReconnectEvent::GaveUp { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn pattern_name(&self) -> &str {
+        match self {
+            ReconnectEvent::Disconnected { pattern_name, .. }
+            | ReconnectEvent::ReconnectAttempt { pattern_name, .. }
+            | ReconnectEvent::Reconnected { pattern_name, .. }
+            | ReconnectEvent::GaveUp { pattern_name, .. } => pattern_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_types() {
+        let now = Instant::now();
+
+        let disconnected = ReconnectEvent::Disconnected {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+        };
+        assert_eq!(disconnected.event_type(), "reconnect_disconnected");
+        assert_eq!(disconnected.pattern_name(), "test");
+
+        let attempt = ReconnectEvent::ReconnectAttempt {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            attempt: 1,
+            delay: Duration::from_millis(100),
+        };
+        assert_eq!(attempt.event_type(), "reconnect_attempt");
+
+        let reconnected = ReconnectEvent::Reconnected {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            attempts: 2,
+        };
+        assert_eq!(reconnected.event_type(), "reconnect_reconnected");
+
+        let gave_up = ReconnectEvent::GaveUp {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            attempts: 5,
+        };
+        assert_eq!(gave_up.event_type(), "reconnect_gave_up");
+        assert_eq!(gave_up.pattern_name(), "test");
+        assert_eq!(gave_up.timestamp(), now);
+    }
+}
@@ -1,7 +1,17 @@
 use std::sync::Arc;
 use tower::layer::Layer;
+use tower_resilience_core::classifier::DefaultClassifier;
 
-use crate::{config::ReconnectConfig, service::ReconnectService, state::ReconnectState};
+#[cfg(feature = "metrics")]
+use metrics::{describe_counter, describe_gauge};
+
+use crate::{
+    config::ReconnectConfig,
+    connector::{Connector, ConnectorService},
+    probe::NoProbe,
+    service::ReconnectService,
+    state::ReconnectState,
+};
 
 /// A Tower Layer that adds automatic reconnection capabilities to a service.
 ///
@@ -15,10 +25,11 @@ use crate::{config::ReconnectConfig, service::ReconnectService, state::Reconnect
 ///
 /// let layer = ReconnectLayer::new(ReconnectConfig::default());
 /// ```
-#[derive(Clone, Debug)]
-pub struct ReconnectLayer {
+pub struct ReconnectLayer<D = DefaultClassifier, P = NoProbe> {
     config: Arc<ReconnectConfig>,
     state: ReconnectState,
+    classifier: Arc<D>,
+    probe: Arc<P>,
 }
 
 impl ReconnectLayer {
@@ -36,9 +47,23 @@ impl ReconnectLayer {
     /// let layer = ReconnectLayer::new(config);
     /// ```
     pub fn new(config: ReconnectConfig) -> Self {
+        #[cfg(feature = "metrics")]
+        {
+            describe_counter!(
+                "resilience_reconnect_attempts_total",
+                "Total number of reconnection attempts"
+            );
+            describe_gauge!(
+                "resilience_reconnect_connection_state",
+                "Current connection state (1 for the active state, 0 otherwise)"
+            );
+        }
+
         Self {
             config: Arc::new(config),
             state: ReconnectState::new(),
+            classifier: Arc::new(DefaultClassifier),
+            probe: Arc::new(NoProbe),
         }
     }
 
@@ -56,13 +81,139 @@ impl ReconnectLayer {
     pub fn with_defaults() -> Self {
         Self::new(ReconnectConfig::default())
     }
+}
 
+impl<D, P> ReconnectLayer<D, P> {
     /// Returns a reference to the reconnection state.
     ///
     /// This can be used to monitor the current connection state and statistics.
     pub fn state(&self) -> &ReconnectState {
         &self.state
     }
+
+    /// Creates a service that establishes its own connections with `connector`.
+    ///
+    /// Unlike [`layer`](Layer::layer), which retries calls on clones of the
+    /// wrapped service, the returned service dials a fresh connection whenever
+    /// the current one fails with a reconnectable error. The first connection
+    /// is made lazily on the first request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tower::{Service, ServiceExt};
+    /// use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer};
+    ///
+    /// # async fn example() {
+    /// let mut service = ReconnectLayer::new(ReconnectConfig::default()).connector(|| async {
+    ///     // e.g. TcpStream::connect(addr).await.map(Client::new)
+    ///     Ok::<_, std::io::Error>(tower::service_fn(|req: String| async move {
+    ///         Ok::<_, std::io::Error>(req)
+    ///     }))
+    /// });
+    ///
+    /// let response = service.ready().await.unwrap().call("ping".to_string()).await;
+    /// # }
+    /// ```
+    pub fn connector<C: Connector>(&self, connector: C) -> ConnectorService<C, D, P> {
+        ConnectorService::new(
+            connector,
+            self.config.clone(),
+            self.state.clone(),
+            self.classifier.clone(),
+            self.probe.clone(),
+        )
+    }
+
+    /// Sets the classifier deciding which errors mean the connection is broken.
+    ///
+    /// Only errors the classifier accepts trigger reconnection; request-level
+    /// errors pass straight through as `ReconnectError::ServiceError` without
+    /// touching the connection state. By default every error is treated as a
+    /// disconnect.
+    ///
+    /// Unlike [`ReconnectConfigBuilder::reconnect_predicate`], the classifier
+    /// receives the service's concrete error type, so it can match on error
+    /// kinds or enum variants instead of message strings. When both are set,
+    /// an error must satisfy both to trigger reconnection.
+    ///
+    /// [`ReconnectConfigBuilder::reconnect_predicate`]: crate::ReconnectConfigBuilder::reconnect_predicate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    /// use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer};
+    ///
+    /// let layer = ReconnectLayer::new(ReconnectConfig::default())
+    ///     .disconnect_classifier(|error: &Error| {
+    ///         matches!(
+    ///             error.kind(),
+    ///             ErrorKind::BrokenPipe
+    ///                 | ErrorKind::ConnectionReset
+    ///                 | ErrorKind::ConnectionAborted
+    ///                 | ErrorKind::NotConnected
+    ///         )
+    ///     });
+    /// ```
+    pub fn disconnect_classifier<F>(self, classifier: F) -> ReconnectLayer<F, P> {
+        ReconnectLayer {
+            config: self.config,
+            state: self.state,
+            classifier: Arc::new(classifier),
+            probe: self.probe,
+        }
+    }
+
+    /// Sets a health probe that must pass before traffic resumes after a reconnect.
+    ///
+    /// The probe receives a clone of the service once the backoff has elapsed
+    /// (or, with [`connector`](Self::connector), each freshly dialed
+    /// connection). Only if it resolves to `Ok` is the connection marked
+    /// connected and the request sent; an `Err` counts as another failed
+    /// attempt and backoff continues, so a half-alive connection never sees
+    /// real traffic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tower::{Service, ServiceExt};
+    /// use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer};
+    ///
+    /// let layer = ReconnectLayer::new(ReconnectConfig::default()).probe(
+    ///     |svc: tower::util::BoxCloneService<String, String, std::io::Error>| async move {
+    ///         svc.oneshot("PING".to_string()).await
+    ///     },
+    /// );
+    /// ```
+    pub fn probe<F>(self, probe: F) -> ReconnectLayer<D, F> {
+        ReconnectLayer {
+            config: self.config,
+            state: self.state,
+            classifier: self.classifier,
+            probe: Arc::new(probe),
+        }
+    }
+}
+
+impl<D, P> Clone for ReconnectLayer<D, P> {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            state: self.state.clone(),
+            classifier: self.classifier.clone(),
+            probe: self.probe.clone(),
+        }
+    }
+}
+
+impl<D, P> std::fmt::Debug for ReconnectLayer<D, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectLayer")
+            .field("config", &self.config)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for ReconnectLayer {
@@ -71,11 +222,17 @@ impl Default for ReconnectLayer {
     }
 }
 
-impl<S> Layer<S> for ReconnectLayer {
-    type Service = ReconnectService<S>;
+impl<S, D, P> Layer<S> for ReconnectLayer<D, P> {
+    type Service = ReconnectService<S, D, P>;
 
     fn layer(&self, inner: S) -> Self::Service {
-        ReconnectService::new(inner, self.config.clone(), self.state.clone())
+        ReconnectService::with_config(
+            inner,
+            self.config.clone(),
+            self.state.clone(),
+            self.classifier.clone(),
+            self.probe.clone(),
+        )
     }
 }
 
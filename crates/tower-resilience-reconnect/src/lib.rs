@@ -104,17 +104,147 @@
 //! // - Was the operation executed before the connection died?
 //! // - Can we safely retry without duplicating side effects?
 //! ```
+//!
+//! ## Classifying Disconnects
+//!
+//! By default every error is treated as a broken connection. A
+//! [`DisconnectClassifier`] on the layer restricts reconnection to
+//! connection-level errors, so request-level errors reach the caller as
+//! `ReconnectError::ServiceError` without touching the connection:
+//!
+//! ```rust
+//! use std::io::{Error, ErrorKind};
+//! use tower_resilience_reconnect::{ReconnectLayer, ReconnectConfig};
+//!
+//! let layer = ReconnectLayer::new(ReconnectConfig::default())
+//!     .disconnect_classifier(|error: &Error| {
+//!         matches!(
+//!             error.kind(),
+//!             ErrorKind::BrokenPipe | ErrorKind::ConnectionReset | ErrorKind::NotConnected
+//!         )
+//!     });
+//! ```
+//!
+//! ## Health Probes
+//!
+//! A reconnected service may accept connections before it can actually serve
+//! them. With [`ReconnectLayer::probe`], a probe request runs after each
+//! reconnect and traffic only resumes once it succeeds; a failing probe counts
+//! as another attempt and backoff continues.
+//!
+//! ```rust
+//! use tower::ServiceExt;
+//! use tower::util::BoxCloneService;
+//! use tower_resilience_reconnect::{ReconnectLayer, ReconnectConfig};
+//!
+//! let layer = ReconnectLayer::new(ReconnectConfig::default())
+//!     .probe(|svc: BoxCloneService<String, String, std::io::Error>| async move {
+//!         svc.oneshot("PING".to_string()).await
+//!     });
+//! ```
+//!
+//! ## Establishing New Connections
+//!
+//! Wrapping a service with [`ReconnectLayer`] retries calls on clones of that
+//! same service. When a broken connection has to be replaced, supply a
+//! [`Connector`] instead: any async closure returning `Result<S, E>`. The
+//! resulting [`ConnectorService`] dials lazily, shares the connection across
+//! clones, and re-dials with the configured backoff when it breaks.
+//!
+//! ```rust
+//! use tower_resilience_reconnect::{ReconnectLayer, ReconnectConfig, ReconnectPolicy};
+//! use std::time::Duration;
+//!
+//! let layer = ReconnectLayer::new(
+//!     ReconnectConfig::builder()
+//!         .policy(ReconnectPolicy::exponential(
+//!             Duration::from_millis(100),
+//!             Duration::from_secs(5),
+//!         ))
+//!         .max_attempts(10)
+//!         .build(),
+//! );
+//!
+//! let service = layer.connector(|| async {
+//!     // Re-dial the backend, e.g. open a new TCP stream or gRPC channel
+//!     Ok::<_, std::io::Error>(tower::service_fn(|req: String| async move {
+//!         Ok::<_, std::io::Error>(req)
+//!     }))
+//! });
+//! ```
+//!
+//! ## Idle Connections
+//!
+//! Peers and load balancers often close connections that sit unused, and the
+//! next request only finds out when it hits the dead socket. A
+//! [`ConnectorService`] with an `idle_timeout` checks the connection first
+//! whenever it has been idle that long: the `keepalive` probe runs against it,
+//! and if it fails (or no keepalive is set) a fresh connection is dialed
+//! before the request is sent.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use tower::ServiceExt;
+//! use tower::util::BoxCloneService;
+//! use tower_resilience_reconnect::{ReconnectLayer, ReconnectConfig};
+//!
+//! let service = ReconnectLayer::new(ReconnectConfig::default())
+//!     .connector(|| async {
+//!         Ok::<_, std::io::Error>(BoxCloneService::new(tower::service_fn(
+//!             |req: String| async move { Ok::<_, std::io::Error>(req) },
+//!         )))
+//!     })
+//!     .idle_timeout(Duration::from_secs(30))
+//!     .keepalive(|svc: BoxCloneService<String, String, std::io::Error>| async move {
+//!         svc.oneshot("PING".to_string()).await
+//!     });
+//! ```
+//!
+//! # Events
+//!
+//! [`ReconnectEvent`] reports the connection lifecycle through the core event
+//! system: `Disconnected` when a connection-level error breaks the connection,
+//! `ReconnectAttempt` for each scheduled attempt, `Reconnected` once traffic
+//! flows again, and `GaveUp` when attempts are exhausted. Register callbacks
+//! on the config builder:
+//!
+//! ```rust
+//! use tower_resilience_reconnect::ReconnectConfig;
+//!
+//! let config = ReconnectConfig::builder()
+//!     .name("redis")
+//!     .on_disconnected(|| println!("connection lost"))
+//!     .on_reconnect_attempt(|attempt, delay| println!("attempt {} in {:?}", attempt, delay))
+//!     .on_reconnected(|attempts| println!("reconnected after {} attempts", attempts))
+//!     .on_gave_up(|attempts| println!("gave up after {} attempts", attempts))
+//!     .build();
+//! ```
+//!
+//! # Metrics
+//!
+//! With the `metrics` feature enabled:
+//!
+//! - `resilience_reconnect_attempts_total{name}` - Reconnection attempts
+//! - `resilience_reconnect_connection_state{name, state}` - Current connection state gauge
 
+mod classifier;
 mod config;
+mod connector;
+mod events;
 mod layer;
 mod policy;
+mod probe;
 mod service;
 mod state;
 
+pub use classifier::DisconnectClassifier;
 pub use config::{ReconnectConfig, ReconnectConfigBuilder, ReconnectPredicate};
+pub use connector::{Connector, ConnectorFuture, ConnectorService};
+pub use events::ReconnectEvent;
 pub use layer::ReconnectLayer;
 pub use policy::ReconnectPolicy;
-pub use service::ReconnectService;
+pub use probe::{FnProbeFuture, HealthProbe, NoProbe, ProbeError};
+pub use service::{ReconnectError, ReconnectFuture, ReconnectService};
 pub use state::{ConnectionState, ReconnectState};
 
 // Re-export backoff strategies from retry crate for convenience
@@ -0,0 +1,104 @@
+//! Health probes run against a connection before it receives traffic.
+
+use std::{
+    future::{Future, Ready},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project::pin_project;
+
+/// A boxed error returned by a failed [`HealthProbe`].
+pub type ProbeError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Verifies that a reconnected service is actually usable.
+///
+/// After a reconnection the probe is handed a clone of the service; only if
+/// it succeeds is the connection marked as connected and real requests sent
+/// through it. A failed probe counts as a failed reconnection attempt and the
+/// backoff continues.
+///
+/// Any `Fn(S) -> impl Future<Output = Result<T, E>>` closure is a probe, where
+/// `E` converts into a [`ProbeError`]. Probes are installed with
+/// [`ReconnectLayer::probe`](crate::ReconnectLayer::probe).
+pub trait HealthProbe<S>: Send + Sync {
+    /// The future returned by [`probe`](HealthProbe::probe).
+    type Future: Future<Output = Result<(), ProbeError>>;
+
+    /// Probes the given service.
+    fn probe(&self, service: S) -> Self::Future;
+}
+
+/// The default probe, which accepts every connection without checking it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProbe;
+
+impl<S> HealthProbe<S> for NoProbe {
+    type Future = Ready<Result<(), ProbeError>>;
+
+    fn probe(&self, _service: S) -> Self::Future {
+        std::future::ready(Ok(()))
+    }
+}
+
+impl<F, Fut, S, T, E> HealthProbe<S> for F
+where
+    F: Fn(S) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<T, E>>,
+    E: Into<ProbeError>,
+{
+    type Future = FnProbeFuture<Fut>;
+
+    fn probe(&self, service: S) -> Self::Future {
+        FnProbeFuture {
+            inner: self(service),
+        }
+    }
+}
+
+/// Future returned by closure-based [`HealthProbe`]s.
+#[pin_project]
+pub struct FnProbeFuture<F> {
+    #[pin]
+    inner: F,
+}
+
+impl<F, T, E> Future for FnProbeFuture<F>
+where
+    F: Future<Output = Result<T, E>>,
+    E: Into<ProbeError>,
+{
+    type Output = Result<(), ProbeError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project()
+            .inner
+            .poll(cx)
+            .map(|result| result.map(|_| ()).map_err(Into::into))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_no_probe_accepts_everything() {
+        assert!(HealthProbe::<()>::probe(&NoProbe, ()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_closure_probe() {
+        let probe = |healthy: bool| async move {
+            if healthy {
+                Ok("pong")
+            } else {
+                Err(std::io::Error::other("no pong"))
+            }
+        };
+
+        assert!(probe.probe(true).await.is_ok());
+        let err = probe.probe(false).await.unwrap_err();
+        assert_eq!(err.to_string(), "no pong");
+    }
+}
@@ -8,7 +8,18 @@ use std::{
 use pin_project::pin_project;
 use tower::Service;
 
-use crate::{config::ReconnectConfig, state::ReconnectState};
+use tower_resilience_core::classifier::DefaultClassifier;
+#[cfg(feature = "tracing")]
+use tower_resilience_core::span::{self, Outcome};
+use tower_resilience_core::PatternError;
+
+use crate::{
+    classifier::DisconnectClassifier,
+    config::ReconnectConfig,
+    connector::Backoff,
+    probe::{HealthProbe, NoProbe},
+    state::{ConnectionState, ReconnectState},
+};
 
 /// A Tower Service that automatically reconnects on connection failures.
 ///
@@ -18,20 +29,57 @@ use crate::{config::ReconnectConfig, state::ReconnectState};
 /// # Type Parameters
 ///
 /// * `S` - The inner service
-#[derive(Clone)]
-pub struct ReconnectService<S> {
+/// * `D` - The [`DisconnectClassifier`] deciding which errors trigger reconnection
+/// * `P` - The [`HealthProbe`] run after backing off, before traffic resumes
+pub struct ReconnectService<S, D = DefaultClassifier, P = NoProbe> {
     inner: S,
     config: Arc<ReconnectConfig>,
     state: ReconnectState,
+    classifier: Arc<D>,
+    probe: Arc<P>,
+}
+
+impl<S: Clone, D, P> Clone for ReconnectService<S, D, P> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            config: self.config.clone(),
+            state: self.state.clone(),
+            classifier: self.classifier.clone(),
+            probe: self.probe.clone(),
+        }
+    }
 }
 
 impl<S> ReconnectService<S> {
     /// Creates a new `ReconnectService` wrapping the given service.
+    #[cfg(test)]
     pub(crate) fn new(inner: S, config: Arc<ReconnectConfig>, state: ReconnectState) -> Self {
+        Self::with_config(
+            inner,
+            config,
+            state,
+            Arc::new(DefaultClassifier),
+            Arc::new(NoProbe),
+        )
+    }
+}
+
+impl<S, D, P> ReconnectService<S, D, P> {
+    /// Creates a new `ReconnectService` with the given classifier and probe.
+    pub(crate) fn with_config(
+        inner: S,
+        config: Arc<ReconnectConfig>,
+        state: ReconnectState,
+        classifier: Arc<D>,
+        probe: Arc<P>,
+    ) -> Self {
         Self {
             inner,
             config,
             state,
+            classifier,
+            probe,
         }
     }
 
@@ -46,15 +94,17 @@ impl<S> ReconnectService<S> {
     }
 }
 
-impl<S, Request> Service<Request> for ReconnectService<S>
+impl<S, D, P, Request> Service<Request> for ReconnectService<S, D, P>
 where
     S: Service<Request> + Clone,
     S::Error: std::error::Error + Send + Sync + 'static,
+    D: DisconnectClassifier<S::Error>,
+    P: HealthProbe<S>,
     Request: Clone,
 {
     type Response = S::Response;
     type Error = ReconnectError<S::Error>;
-    type Future = ReconnectFuture<S, Request>;
+    type Future = ReconnectFuture<S, Request, D, P>;
 
     fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         self.inner
@@ -68,9 +118,13 @@ where
             inner: self.inner.clone(),
             config: self.config.clone(),
             state: self.state.clone(),
+            classifier: self.classifier.clone(),
+            probe: self.probe.clone(),
             request,
             attempt: 0,
             last_error: None,
+            #[cfg(feature = "tracing")]
+            span: span::pattern_span("reconnect", &self.config.name),
             phase: Phase::Calling(call_future),
         }
     }
@@ -78,24 +132,32 @@ where
 
 /// Future returned by `ReconnectService`.
 #[pin_project]
-pub struct ReconnectFuture<S, Request>
+pub struct ReconnectFuture<S, Request, D = DefaultClassifier, P = NoProbe>
 where
     S: Service<Request>,
+    P: HealthProbe<S>,
 {
     inner: S,
     config: Arc<ReconnectConfig>,
     state: ReconnectState,
+    classifier: Arc<D>,
+    probe: Arc<P>,
     request: Request,
     attempt: u32,
     last_error: Option<S::Error>,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
     #[pin]
-    phase: Phase<S::Future>,
+    phase: Phase<S::Future, P::Future>,
 }
 
 #[pin_project(project = PhaseProj)]
-enum Phase<F> {
+enum Phase<F, Probe> {
     Calling(#[pin] F),
     Sleeping(#[pin] tokio::time::Sleep),
+    /// Running the health probe against a clone of the inner service once
+    /// the backoff has elapsed.
+    Probing(#[pin] Probe),
     /// Driving `poll_ready` on the stored inner clone before issuing a retry
     /// `call`. The initial call uses the caller-readied receiver; every
     /// subsequent retry must re-ready the clone we hold here (tower::Service
@@ -104,15 +166,16 @@ enum Phase<F> {
     Failed,
 }
 
-impl<S, Request> Future for ReconnectFuture<S, Request>
+impl<S, D, P, Request> ReconnectFuture<S, Request, D, P>
 where
-    S: Service<Request>,
+    S: Service<Request> + Clone,
     S::Error: std::error::Error + Send + Sync + 'static,
+    D: DisconnectClassifier<S::Error>,
+    P: HealthProbe<S>,
     Request: Clone,
 {
-    type Output = Result<S::Response, ReconnectError<S::Error>>;
-
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    /// Drives the request through connecting, calling and backing off.
+    fn poll_phase(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<<Self as Future>::Output> {
         let mut this = self.project();
 
         loop {
@@ -120,6 +183,10 @@ where
                 PhaseProj::Calling(call_future) => {
                     match call_future.poll(cx) {
                         Poll::Ready(Ok(response)) => {
+                            if *this.attempt > 0 || this.state.state() != ConnectionState::Connected
+                            {
+                                this.config.record_connected(*this.attempt);
+                            }
                             this.state.mark_connected();
 
                             #[cfg(feature = "tracing")]
@@ -133,13 +200,18 @@ where
                         }
                         Poll::Ready(Err(error)) => {
                             // Check if this error should trigger reconnection
-                            if !this.config.should_reconnect(&error) {
+                            if !this.classifier.is_disconnect(&error)
+                                || !this.config.should_reconnect(&error)
+                            {
                                 // Not a reconnectable error, fail immediately
                                 this.phase.set(Phase::Failed);
                                 return Poll::Ready(Err(ReconnectError::ServiceError(error)));
                             }
 
                             this.state.mark_disconnected();
+                            if *this.attempt == 0 {
+                                this.config.record_disconnected();
+                            }
 
                             #[cfg(feature = "tracing")]
                             if let Some(ref callback) = this.config.on_state_change {
@@ -156,6 +228,7 @@ where
                             // Check if we've exceeded max attempts
                             if let Some(max) = this.config.max_attempts {
                                 if *this.attempt > max {
+                                    this.config.record_gave_up(*this.attempt);
                                     this.phase.set(Phase::Failed);
                                     return Poll::Ready(Err(ReconnectError::MaxAttemptsExceeded {
                                         attempts: *this.attempt,
@@ -169,6 +242,7 @@ where
                                 this.config.policy.delay_for_attempt(*this.attempt as usize)
                             {
                                 this.state.mark_reconnecting();
+                                this.config.record_attempt(*this.attempt, delay);
 
                                 #[cfg(feature = "tracing")]
                                 if let Some(ref callback) = this.config.on_state_change {
@@ -186,6 +260,7 @@ where
                                 this.phase.set(Phase::Sleeping(tokio::time::sleep(delay)));
                             } else {
                                 // No backoff - fail immediately
+                                this.config.record_gave_up(*this.attempt);
                                 this.phase.set(Phase::Failed);
                                 let error = this.last_error.take().unwrap();
                                 return Poll::Ready(Err(ReconnectError::ConnectionFailed(error)));
@@ -197,7 +272,18 @@ where
                 PhaseProj::Sleeping(sleep) => {
                     match sleep.poll(cx) {
                         Poll::Ready(()) => {
-                            // Sleep complete - check retry_on_reconnect flag
+                            // Sleep complete - make sure the service is usable
+                            // before sending traffic through it again
+                            let probe = this.probe.probe(this.inner.clone());
+                            this.phase.set(Phase::Probing(probe));
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+                PhaseProj::Probing(probe) => {
+                    match probe.poll(cx) {
+                        Poll::Ready(Ok(())) => {
+                            // Probe passed - check retry_on_reconnect flag
                             if this.config.retry_on_reconnect {
                                 // Drive poll_ready on the stored clone before
                                 // re-issuing the call -- the tower::Service
@@ -207,6 +293,7 @@ where
                                 // Don't retry - return error to caller
                                 // The backoff succeeded, so mark connected for next request
                                 this.state.mark_connected();
+                                this.config.record_connected(*this.attempt);
                                 this.phase.set(Phase::Failed);
                                 let error = this.last_error.take().unwrap();
                                 return Poll::Ready(Err(ReconnectError::ConnectionFailedNoRetry(
@@ -214,6 +301,37 @@ where
                                 )));
                             }
                         }
+                        Poll::Ready(Err(error)) => {
+                            // Still not healthy - count it as another attempt
+                            // and keep backing off
+                            *this.attempt += 1;
+
+                            match Backoff::next(this.config, *this.attempt) {
+                                Backoff::Sleep(delay) => {
+                                    this.config.record_attempt(*this.attempt, delay);
+
+                                    #[cfg(feature = "tracing")]
+                                    if let Some(ref callback) = this.config.on_reconnect {
+                                        callback(*this.attempt);
+                                    }
+
+                                    this.phase.set(Phase::Sleeping(tokio::time::sleep(delay)));
+                                }
+                                Backoff::Exhausted => {
+                                    this.config.record_gave_up(*this.attempt);
+                                    this.phase.set(Phase::Failed);
+                                    return Poll::Ready(Err(ReconnectError::MaxAttemptsExceeded {
+                                        attempts: *this.attempt,
+                                        error,
+                                    }));
+                                }
+                                Backoff::Disabled => {
+                                    this.config.record_gave_up(*this.attempt);
+                                    this.phase.set(Phase::Failed);
+                                    return Poll::Ready(Err(ReconnectError::ProbeFailed(error)));
+                                }
+                            }
+                        }
                         Poll::Pending => return Poll::Pending,
                     }
                 }
@@ -236,6 +354,33 @@ where
     }
 }
 
+impl<S, D, P, Request> Future for ReconnectFuture<S, Request, D, P>
+where
+    S: Service<Request> + Clone,
+    S::Error: std::error::Error + Send + Sync + 'static,
+    D: DisconnectClassifier<S::Error>,
+    P: HealthProbe<S>,
+    Request: Clone,
+{
+    type Output = Result<S::Response, ReconnectError<S::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            let span = self.span.clone();
+            let _enter = span.enter();
+            let poll = self.poll_phase(cx);
+            if let Poll::Ready(result) = &poll {
+                span::record_outcome(&span, Outcome::from_result(result));
+            }
+            poll
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.poll_phase(cx)
+    }
+}
+
 /// Errors that can occur during reconnection.
 #[derive(Debug)]
 pub enum ReconnectError<E> {
@@ -255,6 +400,12 @@ pub enum ReconnectError<E> {
 
     /// The service returned an error.
     ServiceError(E),
+
+    /// The connector could not establish a new connection.
+    ConnectorFailed(Box<dyn std::error::Error + Send + Sync>),
+
+    /// A reconnected service failed its health probe.
+    ProbeFailed(Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl<E> std::fmt::Display for ReconnectError<E>
@@ -273,6 +424,8 @@ where
             Self::ConnectionFailed(e) => write!(f, "connection failed: {}", e),
             Self::ConnectionFailedNoRetry(e) => write!(f, "connection failed (no retry): {}", e),
             Self::ServiceError(e) => write!(f, "service error: {}", e),
+            Self::ConnectorFailed(e) => write!(f, "connector failed: {}", e),
+            Self::ProbeFailed(e) => write!(f, "health probe failed: {}", e),
         }
     }
 }
@@ -287,6 +440,34 @@ where
             Self::ConnectionFailed(e) => Some(e),
             Self::ConnectionFailedNoRetry(e) => Some(e),
             Self::ServiceError(e) => Some(e),
+            Self::ConnectorFailed(e) => Some(e.as_ref()),
+            Self::ProbeFailed(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+impl<E> PatternError for ReconnectError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        false
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            Self::ConnectionFailed(e)
+            | Self::ConnectionFailedNoRetry(e)
+            | Self::ServiceError(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            Self::ConnectionFailed(e)
+            | Self::ConnectionFailedNoRetry(e)
+            | Self::ServiceError(e) => Ok(e),
+            other => Err(other),
         }
     }
 }
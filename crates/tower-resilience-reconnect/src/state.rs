@@ -6,6 +6,7 @@ use std::time::{Duration, Instant};
 
 /// Connection state information
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectionState {
     /// Connected and healthy
     Connected,
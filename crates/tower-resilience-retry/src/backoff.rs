@@ -1,3 +1,4 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Abstraction for computing retry intervals.
@@ -12,6 +13,14 @@ pub trait IntervalFunction: Send + Sync {
     fn next_interval(&self, attempt: usize) -> Duration;
 }
 
+/// Computes `initial * multiplier^attempt`, saturating at [`Duration::MAX`]
+/// instead of panicking once the interval no longer fits in a `Duration`.
+fn exponential_interval(initial: Duration, multiplier: f64, attempt: usize) -> Duration {
+    let exponent = attempt.min(i32::MAX as usize) as i32;
+    let secs = initial.as_secs_f64() * multiplier.powi(exponent);
+    Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+}
+
 /// Fixed interval backoff - returns the same duration for every retry.
 #[derive(Debug, Clone)]
 pub struct FixedInterval {
@@ -64,8 +73,7 @@ impl ExponentialBackoff {
 
 impl IntervalFunction for ExponentialBackoff {
     fn next_interval(&self, attempt: usize) -> Duration {
-        let multiplier = self.multiplier.powi(attempt as i32);
-        let interval = self.initial_interval.mul_f64(multiplier);
+        let interval = exponential_interval(self.initial_interval, self.multiplier, attempt);
 
         if let Some(max) = self.max_interval {
             interval.min(max)
@@ -125,8 +133,7 @@ impl ExponentialRandomBackoff {
 
 impl IntervalFunction for ExponentialRandomBackoff {
     fn next_interval(&self, attempt: usize) -> Duration {
-        let multiplier = self.multiplier.powi(attempt as i32);
-        let interval = self.initial_interval.mul_f64(multiplier);
+        let interval = exponential_interval(self.initial_interval, self.multiplier, attempt);
 
         let capped = if let Some(max) = self.max_interval {
             interval.min(max)
@@ -162,6 +169,27 @@ where
     }
 }
 
+/// Caps the intervals of another interval function.
+pub(crate) struct CappedInterval {
+    inner: Arc<dyn IntervalFunction>,
+    max_interval: Duration,
+}
+
+impl CappedInterval {
+    pub(crate) fn new(inner: Arc<dyn IntervalFunction>, max_interval: Duration) -> Self {
+        Self {
+            inner,
+            max_interval,
+        }
+    }
+}
+
+impl IntervalFunction for CappedInterval {
+    fn next_interval(&self, attempt: usize) -> Duration {
+        self.inner.next_interval(attempt).min(self.max_interval)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +230,24 @@ mod tests {
         assert_eq!(backoff.next_interval(4), Duration::from_millis(500)); // capped
     }
 
+    #[test]
+    fn exponential_backoff_saturates_instead_of_overflowing() {
+        let backoff = ExponentialBackoff::new(Duration::from_secs(1));
+        assert_eq!(backoff.next_interval(10_000), Duration::MAX);
+
+        let capped = backoff.max_interval(Duration::from_secs(30));
+        assert_eq!(capped.next_interval(10_000), Duration::from_secs(30));
+        assert_eq!(capped.next_interval(usize::MAX), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn capped_interval_limits_any_interval_function() {
+        let inner = FnInterval::new(|attempt| Duration::from_secs(attempt as u64));
+        let capped = CappedInterval::new(Arc::new(inner), Duration::from_secs(5));
+        assert_eq!(capped.next_interval(3), Duration::from_secs(3));
+        assert_eq!(capped.next_interval(7), Duration::from_secs(5));
+    }
+
     #[test]
     fn exponential_random_backoff_has_variance() {
         let backoff = ExponentialRandomBackoff::new(Duration::from_millis(100), 0.5);
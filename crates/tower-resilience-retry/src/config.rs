@@ -1,11 +1,12 @@
-use crate::backoff::{ExponentialBackoff, FixedInterval, IntervalFunction};
+use crate::backoff::{CappedInterval, ExponentialBackoff, FixedInterval, IntervalFunction};
 use crate::budget::RetryBudget;
 use crate::events::RetryEvent;
-use crate::policy::{ResponsePredicate, RetryPolicy, RetryPredicate};
+use crate::policy::{ResponseDelay, ResponsePredicate, RetryPolicy, RetryPredicate};
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Duration;
 use tower_resilience_core::events::{EventListeners, FnListener};
+use tower_resilience_core::{Clock, SharedClock, SystemClock};
 
 /// Source for determining the maximum number of retry attempts.
 ///
@@ -22,6 +23,9 @@ pub enum MaxAttemptsSource<Req> {
     Dynamic(Arc<dyn Fn(&Req) -> usize + Send + Sync>),
 }
 
+/// Function that prepares the request for a given zero-based attempt.
+pub(crate) type RequestDecorator<Req> = Arc<dyn Fn(Req, usize) -> Req + Send + Sync>;
+
 impl<Req> MaxAttemptsSource<Req> {
     /// Get the max attempts for a request.
     pub fn get_max_attempts(&self, req: &Req) -> usize {
@@ -45,17 +49,33 @@ pub struct RetryConfig<Req, Res, E> {
     pub(crate) event_listeners: EventListeners<RetryEvent>,
     pub(crate) name: String,
     pub(crate) budget: Option<Arc<dyn RetryBudget>>,
+    pub(crate) clock: SharedClock,
+    pub(crate) request_decorator: Option<RequestDecorator<Req>>,
+}
+
+impl<Req: Clone, Res, E> RetryConfig<Req, Res, E> {
+    /// Returns the request to send for the zero-based `attempt`.
+    pub(crate) fn request_for_attempt(&self, req: &Req, attempt: usize) -> Req {
+        match &self.request_decorator {
+            Some(decorate) => decorate(req.clone(), attempt),
+            None => req.clone(),
+        }
+    }
 }
 
 /// Builder for [`RetryConfig`].
 pub struct RetryConfigBuilder<Req, Res, E> {
     max_attempts_source: MaxAttemptsSource<Req>,
     interval_fn: Option<Arc<dyn IntervalFunction>>,
+    max_interval: Option<Duration>,
     retry_predicate: Option<RetryPredicate<E>>,
     response_predicate: Option<ResponsePredicate<Res>>,
+    response_delay: Option<ResponseDelay<Res>>,
     event_listeners: EventListeners<RetryEvent>,
     name: String,
     budget: Option<Arc<dyn RetryBudget>>,
+    clock: SharedClock,
+    request_decorator: Option<RequestDecorator<Req>>,
     _phantom: PhantomData<(Req, Res)>,
 }
 
@@ -77,11 +97,15 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         Self {
             max_attempts_source: MaxAttemptsSource::default(),
             interval_fn: None,
+            max_interval: None,
             retry_predicate: None,
             response_predicate: None,
+            response_delay: None,
             event_listeners: EventListeners::new(),
             name: "<unnamed>".to_string(),
             budget: None,
+            clock: Arc::new(SystemClock),
+            request_decorator: None,
             _phantom: PhantomData,
         }
     }
@@ -137,6 +161,46 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Prepares the request before each attempt.
+    ///
+    /// The function receives a clone of the original request and the
+    /// zero-based attempt number, and returns the request to send. Every
+    /// attempt starts from the original request, so changes made for one
+    /// attempt do not leak into the next.
+    ///
+    /// This is useful for tagging attempts, for example keeping a stable
+    /// idempotency key and adding the attempt number so the server can
+    /// deduplicate retries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_retry::RetryLayer;
+    ///
+    /// #[derive(Clone)]
+    /// struct Request {
+    ///     idempotency_key: String,
+    ///     attempt: usize,
+    /// }
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// let layer = RetryLayer::<Request, (), MyError>::builder()
+    ///     .max_attempts(3)
+    ///     .decorate_request(|mut req: Request, attempt| {
+    ///         req.attempt = attempt;
+    ///         req
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn decorate_request<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Req, usize) -> Req + Send + Sync + 'static,
+    {
+        self.request_decorator = Some(Arc::new(f));
+        self
+    }
+
     /// Sets a fixed backoff interval.
     pub fn fixed_backoff(mut self, duration: Duration) -> Self {
         self.interval_fn = Some(Arc::new(FixedInterval::new(duration)));
@@ -149,6 +213,33 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Sets exponential backoff that stops growing at `max_interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_retry::RetryLayer;
+    /// use std::time::Duration;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// // 100ms, 200ms, 400ms, ... never more than 5s
+    /// let layer = RetryLayer::<String, String, MyError>::builder()
+    ///     .max_attempts(20)
+    ///     .exponential_backoff_with_cap(Duration::from_millis(100), Duration::from_secs(5))
+    ///     .build();
+    /// ```
+    pub fn exponential_backoff_with_cap(
+        mut self,
+        initial_interval: Duration,
+        max_interval: Duration,
+    ) -> Self {
+        self.interval_fn = Some(Arc::new(
+            ExponentialBackoff::new(initial_interval).max_interval(max_interval),
+        ));
+        self
+    }
+
     /// Sets a custom interval function for backoff.
     pub fn backoff<I>(mut self, interval_fn: I) -> Self
     where
@@ -158,6 +249,32 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Caps every backoff interval at `max_interval`.
+    ///
+    /// Applies to whichever backoff is configured, including the default and
+    /// custom [`IntervalFunction`]s, regardless of the order the builder
+    /// methods are called in. Delays requested by
+    /// [`retry_after_response`](Self::retry_after_response) are not capped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_retry::{ExponentialRandomBackoff, RetryLayer};
+    /// use std::time::Duration;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// let layer = RetryLayer::<String, String, MyError>::builder()
+    ///     .max_attempts(20)
+    ///     .backoff(ExponentialRandomBackoff::new(Duration::from_millis(100), 0.5))
+    ///     .max_interval(Duration::from_secs(10))
+    ///     .build();
+    /// ```
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = Some(max_interval);
+        self
+    }
+
     /// Sets a predicate to determine which errors should be retried.
     pub fn retry_on<F>(mut self, predicate: F) -> Self
     where
@@ -210,6 +327,81 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Retries successful responses until they satisfy `condition`.
+    ///
+    /// The inverse of [`retry_on_response`](Self::retry_on_response), for
+    /// polling: each response that does not satisfy `condition` is retried
+    /// with the configured backoff, so a status endpoint can be polled until a
+    /// job is ready through the same layer that retries errors. If the
+    /// condition is still unmet after `max_attempts`, the last response is
+    /// returned. Replaces any predicate set with `retry_on_response`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_retry::RetryLayer;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, PartialEq)]
+    /// enum JobStatus {
+    ///     Pending,
+    ///     Ready,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct MyError;
+    ///
+    /// // Poll up to 10 times, backing off between polls
+    /// let layer = RetryLayer::<String, JobStatus, MyError>::builder()
+    ///     .max_attempts(10)
+    ///     .exponential_backoff(Duration::from_millis(500))
+    ///     .retry_until(|status: &JobStatus| *status == JobStatus::Ready)
+    ///     .build();
+    /// ```
+    pub fn retry_until<F>(self, condition: F) -> Self
+    where
+        F: Fn(&Res) -> bool + Send + Sync + 'static,
+    {
+        self.retry_on_response(move |response| !condition(response))
+    }
+
+    /// Sets a function reading the delay a retried response asks for.
+    ///
+    /// Applies to responses matched by
+    /// [`retry_on_response`](Self::retry_on_response). When the function
+    /// returns `Some`, that delay replaces the backoff interval for the next
+    /// attempt; otherwise the backoff is used as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_retry::RetryLayer;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone)]
+    /// struct Response {
+    ///     throttled: bool,
+    ///     retry_after_secs: Option<u64>,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct MyError;
+    ///
+    /// let layer = RetryLayer::<String, Response, MyError>::builder()
+    ///     .retry_on_response(|resp: &Response| resp.throttled)
+    ///     .retry_after_response(|resp: &Response| {
+    ///         resp.retry_after_secs.map(Duration::from_secs)
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn retry_after_response<F>(mut self, delay: F) -> Self
+    where
+        F: Fn(&Res) -> Option<Duration> + Send + Sync + 'static,
+    {
+        self.response_delay = Some(Arc::new(delay));
+        self
+    }
+
     /// Sets the name for this retry instance (used in events).
     pub fn name<S: Into<String>>(mut self, name: S) -> Self {
         self.name = name.into();
@@ -438,11 +630,94 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         self
     }
 
+    /// Sets the clock used to wait between attempts.
+    ///
+    /// Defaults to the system clock. With a [`MockClock`](tower_resilience_core::MockClock),
+    /// backoff delays advance the mock clock instead of sleeping, so tests with
+    /// long backoffs complete immediately.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the retry layer.
     pub fn build(self) -> crate::RetryLayer<Req, Res, E> {
+        crate::RetryLayer::new(self.into_config())
+    }
+
+    /// Builds a layer that hands retries to `scheduler` instead of sleeping
+    /// between attempts.
+    ///
+    /// Each call makes a single attempt and returns
+    /// [`Delivery::Scheduled`](crate::Delivery::Scheduled) when the request
+    /// was handed off for a later retry. See
+    /// [`ScheduledRetry`](crate::ScheduledRetry) for how scheduled jobs are
+    /// resumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tower_resilience_retry::{InMemoryRetryScheduler, RetryLayer};
+    /// use std::time::Duration;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// let (scheduler, due) = InMemoryRetryScheduler::new();
+    ///
+    /// let layer = RetryLayer::<String, (), MyError>::builder()
+    ///     .max_attempts(10)
+    ///     .exponential_backoff(Duration::from_secs(5))
+    ///     .build_scheduled(scheduler);
+    /// ```
+    pub fn build_scheduled<R>(self, scheduler: R) -> crate::ScheduledRetryLayer<Req, Res, E>
+    where
+        R: crate::RetryScheduler<Req> + 'static,
+    {
+        crate::ScheduledRetryLayer::new(self.into_config(), Arc::new(scheduler))
+    }
+
+    /// Builds a [`tower::retry::Policy`] for use with
+    /// [`tower::retry::Retry`] instead of a layer.
+    ///
+    /// See [`TowerRetryPolicy`](crate::TowerRetryPolicy) for what carries
+    /// over from the configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tower_resilience_retry::RetryLayer;
+    /// use std::time::Duration;
+    ///
+    /// # #[derive(Debug, Clone)]
+    /// # struct MyError;
+    /// let policy = RetryLayer::<String, String, MyError>::builder()
+    ///     .max_attempts(3)
+    ///     .exponential_backoff(Duration::from_millis(100))
+    ///     .build_policy();
+    ///
+    /// let layer = tower::retry::RetryLayer::new(policy);
+    /// ```
+    pub fn build_policy(self) -> crate::TowerRetryPolicy<Req, Res, E> {
+        crate::TowerRetryPolicy::new(self.into_config())
+    }
+
+    fn into_config(self) -> RetryConfig<Req, Res, E> {
         let interval_fn = self
             .interval_fn
             .unwrap_or_else(|| Arc::new(ExponentialBackoff::new(Duration::from_millis(100))));
+        let interval_fn: Arc<dyn IntervalFunction> = match self.max_interval {
+            Some(max_interval) => Arc::new(CappedInterval::new(interval_fn, max_interval)),
+            None => interval_fn,
+        };
 
         let mut policy = RetryPolicy::new(interval_fn);
         if let Some(predicate) = self.retry_predicate {
@@ -451,16 +726,17 @@ impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
         if let Some(predicate) = self.response_predicate {
             policy.response_predicate = Some(predicate);
         }
+        policy.response_delay = self.response_delay;
 
-        let config = RetryConfig {
+        RetryConfig {
             policy,
             max_attempts_source: self.max_attempts_source,
             event_listeners: self.event_listeners,
             name: self.name,
             budget: self.budget,
-        };
-
-        crate::RetryLayer::new(config)
+            clock: self.clock,
+            request_decorator: self.request_decorator,
+        }
     }
 }
 
@@ -1,12 +1,19 @@
-use std::time::{Duration, Instant};
-use tower_resilience_core::events::ResilienceEvent;
+use std::time::Duration;
+use tower_resilience_core::events::{ResilienceEvent, Severity};
+use tower_resilience_core::time::Instant;
 
 /// Events emitted by the retry middleware.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum RetryEvent {
     /// A retry attempt is about to be made.
     Retry {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         attempt: usize,
         delay: Duration,
@@ -14,23 +21,50 @@ pub enum RetryEvent {
     /// The operation succeeded (either on first try or after retries).
     Success {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         attempts: usize,
     },
     /// The operation failed after exhausting all retry attempts.
     Error {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         attempts: usize,
     },
     /// An error occurred but was not retried (filtered by retry predicate).
     IgnoredError {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
     },
+    /// A retry was handed to a retry scheduler instead of waited for in the call.
+    Scheduled {
+        pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        attempt: usize,
+        delay: Duration,
+    },
     /// A retry was skipped because the retry budget was exhausted.
     BudgetExhausted {
         pattern_name: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         attempt: usize,
     },
@@ -44,6 +78,7 @@ impl ResilienceEvent for RetryEvent {
             RetryEvent::Error { .. } => "error",
             RetryEvent::IgnoredError { .. } => "ignored_error",
             RetryEvent::BudgetExhausted { .. } => "budget_exhausted",
+            RetryEvent::Scheduled { .. } => "scheduled",
         }
     }
 
@@ -53,7 +88,8 @@ impl ResilienceEvent for RetryEvent {
             | RetryEvent::Success { timestamp, .. }
             | RetryEvent::Error { timestamp, .. }
             | RetryEvent::IgnoredError { timestamp, .. }
-            | RetryEvent::BudgetExhausted { timestamp, .. } => *timestamp,
+            | RetryEvent::BudgetExhausted { timestamp, .. }
+            | RetryEvent::Scheduled { timestamp, .. } => *timestamp,
         }
     }
 
@@ -63,7 +99,18 @@ impl ResilienceEvent for RetryEvent {
             | RetryEvent::Success { pattern_name, .. }
             | RetryEvent::Error { pattern_name, .. }
             | RetryEvent::IgnoredError { pattern_name, .. }
-            | RetryEvent::BudgetExhausted { pattern_name, .. } => pattern_name,
+            | RetryEvent::BudgetExhausted { pattern_name, .. }
+            | RetryEvent::Scheduled { pattern_name, .. } => pattern_name,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            RetryEvent::Error { .. } => Severity::Error,
+            RetryEvent::Retry { .. } | RetryEvent::BudgetExhausted { .. } => Severity::Warn,
+            RetryEvent::Success { .. }
+            | RetryEvent::IgnoredError { .. }
+            | RetryEvent::Scheduled { .. } => Severity::Info,
         }
     }
 }
@@ -10,10 +10,18 @@
 //!   - Exponential backoff with configurable multiplier
 //!   - Exponential random backoff with randomization factor
 //!   - Custom function-based backoff
+//!   - A `max_interval` cap that applies to any of the above
 //! - **Per-request configuration**: Extract max attempts from the request
 //! - **Retry predicates**: Control which errors should be retried
+//! - **Polling**: Retry successful responses until a condition holds with `retry_until`
 //! - **Event system**: Observability through retry events
 //! - **Flexible configuration**: Builder API with sensible defaults
+//! - **Deadline aware**: Stops retrying once the next backoff would pass the
+//!   [`Deadline`] set by an enclosing time limiter
+//! - **Scheduled retries**: Hand long backoffs to a [`RetryScheduler`] and
+//!   acknowledge the caller instead of sleeping in the call
+//! - **Tower interop**: Use the same configuration as a `tower::retry::Policy`
+//!   with [`TowerRetryPolicy`]
 //!
 //! # Examples
 //!
@@ -160,6 +168,9 @@ mod config;
 mod events;
 mod layer;
 mod policy;
+mod scheduler;
+mod settings;
+mod tower_policy;
 
 pub use backoff::{
     ExponentialBackoff, ExponentialRandomBackoff, FixedInterval, FnInterval, IntervalFunction,
@@ -168,14 +179,22 @@ pub use budget::{AimdBudget, RetryBudget, RetryBudgetBuilder, TokenBucketBudget}
 pub use config::{MaxAttemptsSource, RetryConfig, RetryConfigBuilder};
 pub use events::RetryEvent;
 pub use layer::RetryLayer;
-pub use policy::{ResponsePredicate, RetryPolicy, RetryPredicate};
+pub use policy::{ResponseDelay, ResponsePredicate, RetryPolicy, RetryPredicate};
+pub use scheduler::{
+    Delivery, DueRetries, InMemoryRetryScheduler, RetryJob, RetryScheduler, ScheduleError,
+    ScheduledRetry, ScheduledRetryLayer,
+};
+pub use settings::{BackoffSettings, RetrySettings};
+pub use tower_policy::TowerRetryPolicy;
 
 use futures::future::BoxFuture;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Instant;
+use std::time::Duration;
 use tower::Service;
+use tower_resilience_core::time::Instant;
+use tower_resilience_core::Deadline;
 
 #[cfg(feature = "metrics")]
 use metrics::{counter, describe_counter, describe_histogram, histogram};
@@ -203,14 +222,17 @@ impl<S, Req, Res, E> Retry<S, Req, Res, E> {
         #[cfg(feature = "metrics")]
         {
             describe_counter!(
-                "retry_calls_total",
+                "resilience_retry_calls_total",
                 "Total number of retry operations (success or exhausted)"
             );
             describe_counter!(
-                "retry_attempts_total",
+                "resilience_retry_attempts_total",
                 "Total number of retry attempts across all calls"
             );
-            describe_histogram!("retry_attempts", "Number of attempts per successful call");
+            describe_histogram!(
+                "resilience_retry_attempts",
+                "Number of attempts per successful call"
+            );
         }
 
         Self {
@@ -258,11 +280,16 @@ where
         // Extract max_attempts from request before moving it
         let max_attempts = config.max_attempts_source.get_max_attempts(&req);
 
-        Box::pin(async move {
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("retry", &config.name);
+
+        let future = async move {
             let mut attempt = 0;
 
             loop {
-                let result = service.call(req.clone()).await;
+                let result = service
+                    .call(config.request_for_attempt(&req, attempt))
+                    .await;
 
                 match result {
                     Ok(response) => {
@@ -272,7 +299,7 @@ where
                             if attempt + 1 >= max_attempts {
                                 #[cfg(feature = "metrics")]
                                 {
-                                    counter!("retry_calls_total", "retry" => config.name.clone(), "result" => "exhausted").increment(1);
+                                    counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "exhausted").increment(1);
                                 }
 
                                 #[cfg(feature = "tracing")]
@@ -287,33 +314,16 @@ where
                                 return Ok(response);
                             }
 
-                            // Check retry budget if configured
-                            if let Some(ref budget) = config.budget {
-                                if !budget.try_withdraw() {
-                                    #[cfg(feature = "metrics")]
-                                    {
-                                        counter!("retry_calls_total", "retry" => config.name.clone(), "result" => "budget_exhausted").increment(1);
-                                    }
-
-                                    #[cfg(feature = "tracing")]
-                                    warn!(retry = %config.name, attempt = attempt + 1, "Retry budget exhausted (response predicate)");
-
-                                    let event = RetryEvent::BudgetExhausted {
-                                        pattern_name: config.name.clone(),
-                                        timestamp: Instant::now(),
-                                        attempt: attempt + 1,
-                                    };
-                                    config.event_listeners.emit(&event);
-                                    return Ok(response);
-                                }
-                            }
-
                             // Calculate backoff and retry
-                            let delay = config.policy.next_backoff(attempt);
+                            let delay = config.policy.next_backoff_for_response(&response, attempt);
+
+                            if !admit_retry(&config, attempt, delay) {
+                                return Ok(response);
+                            }
 
                             #[cfg(feature = "metrics")]
                             {
-                                counter!("retry_attempts_total", "retry" => config.name.clone())
+                                counter!("resilience_retry_attempts_total", "name" => config.name.clone())
                                     .increment(1);
                             }
 
@@ -328,7 +338,7 @@ where
                             };
                             config.event_listeners.emit(&event);
 
-                            tokio::time::sleep(delay).await;
+                            config.clock.sleep(delay).await;
                             attempt += 1;
                             continue;
                         }
@@ -340,8 +350,8 @@ where
 
                         #[cfg(feature = "metrics")]
                         {
-                            counter!("retry_calls_total", "retry" => config.name.clone(), "result" => "success").increment(1);
-                            histogram!("retry_attempts", "retry" => config.name.clone())
+                            counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "success").increment(1);
+                            histogram!("resilience_retry_attempts", "name" => config.name.clone())
                                 .record((attempt + 1) as f64);
                         }
 
@@ -380,7 +390,7 @@ where
                         if attempt + 1 >= max_attempts {
                             #[cfg(feature = "metrics")]
                             {
-                                counter!("retry_calls_total", "retry" => config.name.clone(), "result" => "exhausted").increment(1);
+                                counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "exhausted").increment(1);
                             }
 
                             #[cfg(feature = "tracing")]
@@ -395,33 +405,16 @@ where
                             return Err(error);
                         }
 
-                        // Check retry budget if configured
-                        if let Some(ref budget) = config.budget {
-                            if !budget.try_withdraw() {
-                                #[cfg(feature = "metrics")]
-                                {
-                                    counter!("retry_calls_total", "retry" => config.name.clone(), "result" => "budget_exhausted").increment(1);
-                                }
-
-                                #[cfg(feature = "tracing")]
-                                warn!(retry = %config.name, attempt = attempt + 1, "Retry budget exhausted, failing immediately");
-
-                                let event = RetryEvent::BudgetExhausted {
-                                    pattern_name: config.name.clone(),
-                                    timestamp: Instant::now(),
-                                    attempt: attempt + 1,
-                                };
-                                config.event_listeners.emit(&event);
-                                return Err(error);
-                            }
-                        }
-
                         // Calculate backoff and retry
                         let delay = config.policy.next_backoff(attempt);
 
+                        if !admit_retry(&config, attempt, delay) {
+                            return Err(error);
+                        }
+
                         #[cfg(feature = "metrics")]
                         {
-                            counter!("retry_attempts_total", "retry" => config.name.clone())
+                            counter!("resilience_retry_attempts_total", "name" => config.name.clone())
                                 .increment(1);
                         }
 
@@ -436,15 +429,86 @@ where
                         };
                         config.event_listeners.emit(&event);
 
-                        tokio::time::sleep(delay).await;
+                        config.clock.sleep(delay).await;
                         attempt += 1;
                     }
                 }
             }
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_plain_result,
+        );
+
+        Box::pin(future)
     }
 }
 
+/// Returns `true` if sleeping for `delay` would pass the current deadline.
+fn exceeds_deadline(delay: Duration) -> bool {
+    Deadline::current().is_some_and(|deadline| delay >= deadline.remaining())
+}
+
+/// Records a call whose retries were cut short by the current deadline.
+fn emit_deadline_exhausted<Req, Res, E>(config: &RetryConfig<Req, Res, E>, attempt: usize) {
+    #[cfg(feature = "metrics")]
+    {
+        counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "deadline_exceeded").increment(1);
+    }
+
+    let event = RetryEvent::Error {
+        pattern_name: config.name.clone(),
+        timestamp: Instant::now(),
+        attempts: attempt + 1,
+    };
+    config.event_listeners.emit(&event);
+}
+
+/// Decides whether a retry after `delay` may be scheduled.
+///
+/// The retry is stopped if the backoff alone would exceed the deadline of an
+/// enclosing layer, or if the retry budget is exhausted. The deadline is
+/// checked first so a budget token is only spent on a retry that will run.
+/// Emits the event for whichever check stopped the retry.
+pub(crate) fn admit_retry<Req, Res, E>(
+    config: &RetryConfig<Req, Res, E>,
+    attempt: usize,
+    delay: Duration,
+) -> bool {
+    if exceeds_deadline(delay) {
+        #[cfg(feature = "tracing")]
+        warn!(retry = %config.name, attempt = attempt + 1, "Retry backoff exceeds deadline");
+
+        emit_deadline_exhausted(config, attempt);
+        return false;
+    }
+
+    if let Some(ref budget) = config.budget {
+        if !budget.try_withdraw() {
+            #[cfg(feature = "tracing")]
+            warn!(retry = %config.name, attempt = attempt + 1, "Retry budget exhausted");
+
+            #[cfg(feature = "metrics")]
+            {
+                counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "budget_exhausted").increment(1);
+            }
+
+            let event = RetryEvent::BudgetExhausted {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+                attempt: attempt + 1,
+            };
+            config.event_listeners.emit(&event);
+            return false;
+        }
+    }
+
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -823,5 +887,32 @@ mod tests {
         assert_eq!(call_count.load(Ordering::SeqCst), 2);
     }
 
+    #[tokio::test]
+    async fn deadline_stop_does_not_spend_budget() {
+        let budget = RetryBudgetBuilder::new()
+            .token_bucket()
+            .tokens_per_second(0.0)
+            .max_tokens(1)
+            .initial_tokens(1)
+            .build();
+
+        let service =
+            service_fn(|_req: ()| async { Err::<String, _>(TestError::new("always fails")) });
+
+        let layer = RetryLayer::<(), String, TestError>::builder()
+            .max_attempts(3)
+            .fixed_backoff(Duration::from_secs(1))
+            .budget(Arc::clone(&budget))
+            .build();
+        let service = layer.layer(service);
+
+        // The backoff outlasts the deadline, so the retry never happens
+        let result = Deadline::after(Duration::from_millis(50))
+            .scope(service.oneshot(()))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(budget.balance(), 1);
+    }
+
     // Note: Backoff behavior is tested in tests/retry/retry_backoff.rs
 }
@@ -12,6 +12,12 @@ pub type RetryPredicate<E> = Arc<dyn Fn(&E) -> bool + Send + Sync>;
 /// and re-sending the request.
 pub type ResponsePredicate<R> = Arc<dyn Fn(&R) -> bool + Send + Sync>;
 
+/// Reads a server-requested delay from a retried response.
+///
+/// When this returns `Some`, the delay is used instead of the backoff
+/// interval, e.g. to honor an HTTP `Retry-After` header.
+pub type ResponseDelay<R> = Arc<dyn Fn(&R) -> Option<Duration> + Send + Sync>;
+
 /// Policy for retry behavior.
 ///
 /// This policy combines the interval function (backoff strategy)
@@ -21,6 +27,7 @@ pub struct RetryPolicy<R, E> {
     pub(crate) interval_fn: Arc<dyn IntervalFunction>,
     pub(crate) retry_predicate: Option<RetryPredicate<E>>,
     pub(crate) response_predicate: Option<ResponsePredicate<R>>,
+    pub(crate) response_delay: Option<ResponseDelay<R>>,
 }
 
 impl<R, E> RetryPolicy<R, E> {
@@ -30,6 +37,7 @@ impl<R, E> RetryPolicy<R, E> {
             interval_fn,
             retry_predicate: None,
             response_predicate: None,
+            response_delay: None,
         }
     }
 
@@ -67,6 +75,15 @@ impl<R, E> RetryPolicy<R, E> {
     pub fn next_backoff(&self, attempt: usize) -> Duration {
         self.interval_fn.next_interval(attempt)
     }
+
+    /// Computes the delay before retrying a response matched by the response
+    /// predicate, preferring the delay the response asks for.
+    pub fn next_backoff_for_response(&self, response: &R, attempt: usize) -> Duration {
+        self.response_delay
+            .as_ref()
+            .and_then(|delay| delay(response))
+            .unwrap_or_else(|| self.next_backoff(attempt))
+    }
 }
 
 #[cfg(test)]
@@ -129,4 +146,24 @@ mod tests {
         assert_eq!(policy.next_backoff(0), Duration::from_secs(2));
         assert_eq!(policy.next_backoff(1), Duration::from_secs(2));
     }
+
+    #[test]
+    fn test_response_delay_overrides_backoff() {
+        let mut policy: RetryPolicy<TestResponse, TestError> =
+            RetryPolicy::new(Arc::new(FixedInterval::new(Duration::from_secs(2))));
+        policy.response_delay = Some(Arc::new(|r: &TestResponse| {
+            r.has_error.then(|| Duration::from_millis(250))
+        }));
+
+        let throttled = TestResponse { has_error: true };
+        let plain = TestResponse { has_error: false };
+        assert_eq!(
+            policy.next_backoff_for_response(&throttled, 0),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            policy.next_backoff_for_response(&plain, 0),
+            Duration::from_secs(2)
+        );
+    }
 }
@@ -0,0 +1,462 @@
+//! Scheduled retries that hand requests off instead of sleeping in the call.
+//!
+//! With long backoffs (seconds to minutes) keeping the caller's future open
+//! for the whole retry sequence is wasteful. A [`ScheduledRetry`] service
+//! makes the first attempt inline; when it fails with a retryable outcome the
+//! request is handed to a [`RetryScheduler`] and the caller immediately gets
+//! [`Delivery::Scheduled`]. When the delay has passed, the scheduler's worker
+//! passes the [`RetryJob`] back to [`ScheduledRetry::resume`], which makes the
+//! next attempt and schedules again if needed.
+//!
+//! [`InMemoryRetryScheduler`] is a delay queue for a single process; external
+//! queues (a database table, SQS, Redis) implement [`RetryScheduler`] and
+//! resume jobs from their own consumers.
+
+use crate::{RetryConfig, RetryEvent};
+use futures::future::BoxFuture;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_core::time::Instant;
+use tower_resilience_core::{Clock, SharedClock, SystemClock};
+
+#[cfg(feature = "metrics")]
+use metrics::{counter, describe_counter};
+
+#[cfg(feature = "tracing")]
+use tracing::{debug, warn};
+
+/// A request waiting to be retried.
+#[derive(Debug, Clone)]
+pub struct RetryJob<Req> {
+    /// The request to send again.
+    pub request: Req,
+    /// Zero-based index of the attempt to make when the job is due.
+    pub attempt: usize,
+    /// How long to wait before the attempt.
+    pub delay: Duration,
+}
+
+/// Error returned by a [`RetryScheduler`] that could not accept a job.
+#[derive(Debug, Clone)]
+pub struct ScheduleError {
+    message: String,
+}
+
+impl ScheduleError {
+    /// Creates a new error with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to schedule retry: {}", self.message)
+    }
+}
+
+impl std::error::Error for ScheduleError {}
+
+/// Accepts requests to be retried later.
+///
+/// Implementations store the job somewhere durable or in memory and, once
+/// `job.delay` has passed, hand it to [`ScheduledRetry::resume`].
+pub trait RetryScheduler<Req>: Send + Sync {
+    /// Accepts `job` for a later attempt.
+    fn schedule(&self, job: RetryJob<Req>) -> BoxFuture<'static, Result<(), ScheduleError>>;
+}
+
+/// An in-process delay queue for scheduled retries.
+///
+/// Jobs become available from the paired [`DueRetries`] once their delay has
+/// passed. Pending jobs are lost if the process exits; use an external
+/// [`RetryScheduler`] when retries must survive restarts.
+///
+/// # Example
+///
+/// ```
+/// use tower_resilience_retry::{InMemoryRetryScheduler, RetryLayer};
+/// use std::time::Duration;
+///
+/// # #[derive(Debug, Clone)]
+/// # struct MyError;
+/// # async fn example() {
+/// let (scheduler, due) = InMemoryRetryScheduler::new();
+///
+/// let layer = RetryLayer::<String, (), MyError>::builder()
+///     .max_attempts(5)
+///     .exponential_backoff(Duration::from_secs(30))
+///     .build_scheduled(scheduler);
+///
+/// let service = tower::Layer::layer(&layer, tower::service_fn(|_req: String| async {
+///     Ok::<_, MyError>(())
+/// }));
+///
+/// // Resume due retries in the background
+/// tokio::spawn(service.clone().process(due));
+/// # }
+/// ```
+pub struct InMemoryRetryScheduler<Req> {
+    sender: mpsc::UnboundedSender<RetryJob<Req>>,
+    clock: SharedClock,
+}
+
+impl<Req> InMemoryRetryScheduler<Req> {
+    /// Creates a scheduler and the receiver of its due jobs.
+    pub fn new() -> (Self, DueRetries<Req>) {
+        Self::with_clock(SystemClock)
+    }
+
+    /// Creates a scheduler that waits on `clock`.
+    pub fn with_clock(clock: impl Clock + 'static) -> (Self, DueRetries<Req>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        (
+            Self {
+                sender,
+                clock: Arc::new(clock),
+            },
+            DueRetries { receiver },
+        )
+    }
+}
+
+impl<Req> Clone for InMemoryRetryScheduler<Req> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            clock: Arc::clone(&self.clock),
+        }
+    }
+}
+
+impl<Req> fmt::Debug for InMemoryRetryScheduler<Req> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryRetryScheduler")
+            .field("clock", &self.clock)
+            .finish()
+    }
+}
+
+impl<Req: Send + 'static> RetryScheduler<Req> for InMemoryRetryScheduler<Req> {
+    fn schedule(&self, job: RetryJob<Req>) -> BoxFuture<'static, Result<(), ScheduleError>> {
+        if self.sender.is_closed() {
+            return Box::pin(async { Err(ScheduleError::new("due retry receiver was dropped")) });
+        }
+        let sender = self.sender.clone();
+        let sleep = self.clock.sleep(job.delay);
+        tokio::spawn(async move {
+            sleep.await;
+            let _ = sender.send(job);
+        });
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Jobs from an [`InMemoryRetryScheduler`] whose delay has passed.
+#[derive(Debug)]
+pub struct DueRetries<Req> {
+    receiver: mpsc::UnboundedReceiver<RetryJob<Req>>,
+}
+
+impl<Req> DueRetries<Req> {
+    /// Waits for the next due job.
+    ///
+    /// Returns `None` once every scheduler handle has been dropped and no jobs
+    /// remain.
+    pub async fn next(&mut self) -> Option<RetryJob<Req>> {
+        self.receiver.recv().await
+    }
+}
+
+/// The outcome of a call through a [`ScheduledRetry`] service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delivery<Res> {
+    /// An attempt completed and no further retry is needed.
+    Completed(Res),
+    /// The attempt failed and the request was handed to the scheduler.
+    Scheduled {
+        /// Zero-based index of the scheduled attempt.
+        attempt: usize,
+        /// Delay before the scheduled attempt.
+        delay: Duration,
+    },
+}
+
+impl<Res> Delivery<Res> {
+    /// Returns `true` if the request was scheduled for a later attempt.
+    pub fn is_scheduled(&self) -> bool {
+        matches!(self, Delivery::Scheduled { .. })
+    }
+
+    /// Returns the response if the call completed.
+    pub fn into_completed(self) -> Option<Res> {
+        match self {
+            Delivery::Completed(response) => Some(response),
+            Delivery::Scheduled { .. } => None,
+        }
+    }
+}
+
+/// A Tower [`Layer`] that retries through a [`RetryScheduler`].
+///
+/// Created by
+/// [`RetryConfigBuilder::build_scheduled`](crate::RetryConfigBuilder::build_scheduled).
+pub struct ScheduledRetryLayer<Req, Res, E> {
+    config: Arc<RetryConfig<Req, Res, E>>,
+    scheduler: Arc<dyn RetryScheduler<Req>>,
+}
+
+impl<Req, Res, E> ScheduledRetryLayer<Req, Res, E> {
+    pub(crate) fn new(
+        config: RetryConfig<Req, Res, E>,
+        scheduler: Arc<dyn RetryScheduler<Req>>,
+    ) -> Self {
+        Self {
+            config: Arc::new(config),
+            scheduler,
+        }
+    }
+}
+
+impl<Req, Res, E> Clone for ScheduledRetryLayer<Req, Res, E> {
+    fn clone(&self) -> Self {
+        Self {
+            config: Arc::clone(&self.config),
+            scheduler: Arc::clone(&self.scheduler),
+        }
+    }
+}
+
+impl<S, Req, Res, E> Layer<S> for ScheduledRetryLayer<Req, Res, E> {
+    type Service = ScheduledRetry<S, Req, Res, E>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        ScheduledRetry::new(
+            service,
+            Arc::clone(&self.config),
+            Arc::clone(&self.scheduler),
+        )
+    }
+}
+
+/// A Tower [`Service`] that hands failed requests to a [`RetryScheduler`]
+/// instead of sleeping between attempts.
+///
+/// Each call makes one attempt. Non-retryable errors and exhausted retries
+/// are returned as errors, exactly like [`Retry`](crate::Retry); a retryable
+/// outcome is scheduled and reported as [`Delivery::Scheduled`]. If the
+/// scheduler rejects the job, the outcome of the attempt is returned as is.
+pub struct ScheduledRetry<S, Req, Res, E> {
+    inner: S,
+    config: Arc<RetryConfig<Req, Res, E>>,
+    scheduler: Arc<dyn RetryScheduler<Req>>,
+    _phantom: PhantomData<Req>,
+}
+
+impl<S, Req, Res, E> ScheduledRetry<S, Req, Res, E> {
+    fn new(
+        inner: S,
+        config: Arc<RetryConfig<Req, Res, E>>,
+        scheduler: Arc<dyn RetryScheduler<Req>>,
+    ) -> Self {
+        #[cfg(feature = "metrics")]
+        describe_counter!(
+            "resilience_retry_scheduled_total",
+            "Total number of retries handed to a retry scheduler"
+        );
+
+        Self {
+            inner,
+            config,
+            scheduler,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Clone, Req, Res, E> Clone for ScheduledRetry<S, Req, Res, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            config: Arc::clone(&self.config),
+            scheduler: Arc::clone(&self.scheduler),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, Req, Res, E> ScheduledRetry<S, Req, Res, E>
+where
+    S: Service<Req, Response = Res, Error = E> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    Req: Clone + Send + 'static,
+    Res: Send + 'static,
+    E: Send + 'static,
+{
+    /// Makes the attempt described by a due `job`.
+    ///
+    /// Call this from the worker that receives due jobs from the scheduler.
+    pub async fn resume(&mut self, job: RetryJob<Req>) -> Result<Delivery<Res>, E> {
+        let service = self.inner.clone();
+        let service = std::mem::replace(&mut self.inner, service);
+        attempt(
+            service,
+            Arc::clone(&self.config),
+            Arc::clone(&self.scheduler),
+            job.request,
+            job.attempt,
+        )
+        .await
+    }
+
+    /// Resumes jobs from `due` as they become available, until every
+    /// scheduler handle has been dropped.
+    ///
+    /// Each job runs on its own task, so a slow attempt does not hold up the
+    /// others.
+    pub async fn process(self, mut due: DueRetries<Req>) {
+        while let Some(job) = due.next().await {
+            let mut service = self.clone();
+            tokio::spawn(async move {
+                let _ = service.resume(job).await;
+            });
+        }
+    }
+}
+
+impl<S, Req, Res, E> Service<Req> for ScheduledRetry<S, Req, Res, E>
+where
+    S: Service<Req, Response = Res, Error = E> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    Req: Clone + Send + 'static,
+    Res: Send + 'static,
+    E: Send + 'static,
+{
+    type Response = Delivery<Res>;
+    type Error = E;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let clone = self.inner.clone();
+        let service = std::mem::replace(&mut self.inner, clone);
+        Box::pin(attempt(
+            service,
+            Arc::clone(&self.config),
+            Arc::clone(&self.scheduler),
+            req,
+            0,
+        ))
+    }
+}
+
+/// Makes attempt number `attempt` and schedules the next one if needed.
+async fn attempt<S, Req, Res, E>(
+    mut service: S,
+    config: Arc<RetryConfig<Req, Res, E>>,
+    scheduler: Arc<dyn RetryScheduler<Req>>,
+    req: Req,
+    attempt: usize,
+) -> Result<Delivery<Res>, E>
+where
+    S: Service<Req, Response = Res, Error = E>,
+    Req: Clone,
+{
+    let max_attempts = config.max_attempts_source.get_max_attempts(&req);
+    let result = match service.ready().await {
+        Ok(service) => {
+            service
+                .call(config.request_for_attempt(&req, attempt))
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    let delay = match &result {
+        Ok(response) if config.policy.should_retry_response(response) => {
+            config.policy.next_backoff_for_response(response, attempt)
+        }
+        Ok(_) => {
+            if let Some(ref budget) = config.budget {
+                budget.deposit();
+            }
+
+            #[cfg(feature = "metrics")]
+            counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "success").increment(1);
+
+            config.event_listeners.emit(&RetryEvent::Success {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+                attempts: attempt + 1,
+            });
+            return result.map(Delivery::Completed);
+        }
+        Err(error) if !config.policy.should_retry(error) => {
+            config.event_listeners.emit(&RetryEvent::IgnoredError {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+            });
+            return result.map(Delivery::Completed);
+        }
+        Err(_) => config.policy.next_backoff(attempt),
+    };
+
+    if attempt + 1 >= max_attempts {
+        #[cfg(feature = "metrics")]
+        counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "exhausted").increment(1);
+
+        #[cfg(feature = "tracing")]
+        warn!(retry = %config.name, attempts = attempt + 1, "Scheduled retry attempts exhausted");
+
+        config.event_listeners.emit(&RetryEvent::Error {
+            pattern_name: config.name.clone(),
+            timestamp: Instant::now(),
+            attempts: attempt + 1,
+        });
+        return result.map(Delivery::Completed);
+    }
+
+    if !crate::admit_retry(&config, attempt, delay) {
+        return result.map(Delivery::Completed);
+    }
+
+    let job = RetryJob {
+        request: req,
+        attempt: attempt + 1,
+        delay,
+    };
+    if let Err(_error) = scheduler.schedule(job).await {
+        #[cfg(feature = "tracing")]
+        warn!(retry = %config.name, error = %_error, "Retry scheduler rejected job");
+
+        return result.map(Delivery::Completed);
+    }
+
+    #[cfg(feature = "metrics")]
+    counter!("resilience_retry_scheduled_total", "name" => config.name.clone()).increment(1);
+
+    #[cfg(feature = "tracing")]
+    debug!(retry = %config.name, attempt = attempt + 1, delay_ms = delay.as_millis(), "Retry scheduled");
+
+    config.event_listeners.emit(&RetryEvent::Scheduled {
+        pattern_name: config.name.clone(),
+        timestamp: Instant::now(),
+        attempt: attempt + 1,
+        delay,
+    });
+
+    Ok(Delivery::Scheduled {
+        attempt: attempt + 1,
+        delay,
+    })
+}
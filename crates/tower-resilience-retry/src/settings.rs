@@ -0,0 +1,216 @@
+//! Retry settings that can be loaded from configuration files.
+
+use crate::backoff::{ExponentialBackoff, ExponentialRandomBackoff};
+use crate::config::RetryConfigBuilder;
+use crate::layer::RetryLayer;
+use std::time::Duration;
+
+/// Plain-data retry settings, deserializable with the `serde` feature.
+///
+/// Every field is optional; unset fields keep the builder defaults. Which
+/// errors are retried is code rather than configuration, so combine these
+/// settings with [`RetryConfigBuilder::retry_on`] where needed.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tower_resilience_retry::{BackoffSettings, RetryLayer, RetrySettings};
+///
+/// # #[derive(Debug, Clone)]
+/// # struct MyError;
+/// let settings = RetrySettings {
+///     max_attempts: Some(5),
+///     backoff: Some(BackoffSettings::Fixed {
+///         interval: Duration::from_millis(200),
+///     }),
+///     ..Default::default()
+/// };
+///
+/// let layer = RetryLayer::<String, String, MyError>::from_config(settings);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct RetrySettings {
+    /// Instance name used in events and metrics.
+    pub name: Option<String>,
+    /// Maximum attempts, including the initial call.
+    pub max_attempts: Option<usize>,
+    /// Delay between attempts.
+    pub backoff: Option<BackoffSettings>,
+}
+
+/// Backoff strategy in [`RetrySettings`].
+///
+/// With the `serde` feature the strategy is selected by a `type` field:
+///
+/// ```yaml
+/// backoff:
+///   type: exponential
+///   initial_interval: 100ms
+///   multiplier: 2.0
+///   max_interval: 5s
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum BackoffSettings {
+    /// The same delay before every retry.
+    Fixed {
+        /// Delay between attempts.
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "tower_resilience_core::settings::duration")
+        )]
+        interval: Duration,
+    },
+    /// Delays that grow by `multiplier` after each attempt.
+    Exponential {
+        /// Delay before the first retry.
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "tower_resilience_core::settings::duration")
+        )]
+        initial_interval: Duration,
+        /// Growth factor; defaults to 2.0.
+        #[cfg_attr(feature = "serde", serde(default))]
+        multiplier: Option<f64>,
+        /// Upper bound on any single delay.
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default,
+                deserialize_with = "tower_resilience_core::settings::optional_duration"
+            )
+        )]
+        max_interval: Option<Duration>,
+    },
+    /// Exponential delays with random jitter.
+    ExponentialRandom {
+        /// Delay before the first retry.
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "tower_resilience_core::settings::duration")
+        )]
+        initial_interval: Duration,
+        /// Jitter as a fraction of each delay (0.0 to 1.0).
+        randomization_factor: f64,
+        /// Growth factor; defaults to 2.0.
+        #[cfg_attr(feature = "serde", serde(default))]
+        multiplier: Option<f64>,
+        /// Upper bound on any single delay.
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default,
+                deserialize_with = "tower_resilience_core::settings::optional_duration"
+            )
+        )]
+        max_interval: Option<Duration>,
+    },
+}
+
+impl<Req, Res, E> RetryConfigBuilder<Req, Res, E> {
+    /// Applies every field that is set in `settings`, leaving the rest unchanged.
+    pub fn settings(mut self, settings: RetrySettings) -> Self {
+        if let Some(name) = settings.name {
+            self = self.name(name);
+        }
+        if let Some(max_attempts) = settings.max_attempts {
+            self = self.max_attempts(max_attempts);
+        }
+        match settings.backoff {
+            None => {}
+            Some(BackoffSettings::Fixed { interval }) => {
+                self = self.fixed_backoff(interval);
+            }
+            Some(BackoffSettings::Exponential {
+                initial_interval,
+                multiplier,
+                max_interval,
+            }) => {
+                let mut backoff = ExponentialBackoff::new(initial_interval);
+                if let Some(multiplier) = multiplier {
+                    backoff = backoff.multiplier(multiplier);
+                }
+                if let Some(max_interval) = max_interval {
+                    backoff = backoff.max_interval(max_interval);
+                }
+                self = self.backoff(backoff);
+            }
+            Some(BackoffSettings::ExponentialRandom {
+                initial_interval,
+                randomization_factor,
+                multiplier,
+                max_interval,
+            }) => {
+                let mut backoff =
+                    ExponentialRandomBackoff::new(initial_interval, randomization_factor);
+                if let Some(multiplier) = multiplier {
+                    backoff = backoff.multiplier(multiplier);
+                }
+                if let Some(max_interval) = max_interval {
+                    backoff = backoff.max_interval(max_interval);
+                }
+                self = self.backoff(backoff);
+            }
+        }
+        self
+    }
+}
+
+impl<Req, Res, E> RetryLayer<Req, Res, E> {
+    /// Creates a retry layer from [`RetrySettings`], retrying every error.
+    pub fn from_config(settings: RetrySettings) -> Self {
+        Self::builder().settings(settings).build()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_settings() {
+        let settings: RetrySettings = serde_json::from_str(
+            r#"{
+                "name": "api",
+                "max_attempts": 4,
+                "backoff": {
+                    "type": "exponential",
+                    "initial_interval": "100ms",
+                    "max_interval": "5s"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.max_attempts, Some(4));
+        assert_eq!(
+            settings.backoff,
+            Some(BackoffSettings::Exponential {
+                initial_interval: Duration::from_millis(100),
+                multiplier: None,
+                max_interval: Some(Duration::from_secs(5)),
+            })
+        );
+
+        let _layer = RetryLayer::<(), (), std::io::Error>::from_config(settings);
+    }
+
+    #[test]
+    fn test_deserialize_fixed_backoff() {
+        let backoff: BackoffSettings =
+            serde_json::from_str(r#"{"type": "fixed", "interval": 250}"#).unwrap();
+        assert_eq!(
+            backoff,
+            BackoffSettings::Fixed {
+                interval: Duration::from_millis(250)
+            }
+        );
+    }
+}
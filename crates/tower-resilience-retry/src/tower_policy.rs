@@ -0,0 +1,289 @@
+//! Retry configuration as a [`tower::retry::Policy`].
+//!
+//! [`TowerRetryPolicy`] lets services already built on
+//! [`tower::retry::Retry`] use this crate's backoff strategies, budgets,
+//! predicates and events without switching to [`Retry`](crate::Retry).
+
+use crate::{RetryConfig, RetryEvent};
+use std::fmt;
+use std::sync::Arc;
+use tower_resilience_core::clock::Sleep;
+use tower_resilience_core::time::Instant;
+
+#[cfg(feature = "metrics")]
+use metrics::counter;
+
+#[cfg(feature = "tracing")]
+use tracing::{debug, warn};
+
+/// A [`tower::retry::Policy`] driven by a [`RetryConfig`].
+///
+/// Created by
+/// [`RetryConfigBuilder::build_policy`](crate::RetryConfigBuilder::build_policy).
+/// Max attempts, backoff, response delays, retry predicates, budgets,
+/// deadlines, events and metrics behave as they do in
+/// [`RetryLayer`](crate::RetryLayer). Backoff sleeps go through the
+/// configured clock.
+///
+/// `tower::retry::Retry` sends the first attempt as given, so
+/// [`decorate_request`](crate::RetryConfigBuilder::decorate_request) only
+/// applies to retries, and sees the request as it was last sent.
+///
+/// # Example
+///
+/// ```
+/// use tower::retry::RetryLayer as TowerRetryLayer;
+/// use tower::{Service, ServiceBuilder, ServiceExt};
+/// use tower_resilience_retry::RetryLayer;
+/// use std::time::Duration;
+///
+/// # #[derive(Debug, Clone)]
+/// # struct MyError;
+/// # async fn example() -> Result<(), MyError> {
+/// let policy = RetryLayer::<String, String, MyError>::builder()
+///     .max_attempts(3)
+///     .exponential_backoff(Duration::from_millis(100))
+///     .build_policy();
+///
+/// let mut service = ServiceBuilder::new()
+///     .layer(TowerRetryLayer::new(policy))
+///     .service(tower::service_fn(|req: String| async move {
+///         Ok::<_, MyError>(req)
+///     }));
+///
+/// let response = service.ready().await?.call("hello".to_string()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TowerRetryPolicy<Req, Res, E> {
+    config: Arc<RetryConfig<Req, Res, E>>,
+    attempt: usize,
+}
+
+impl<Req, Res, E> TowerRetryPolicy<Req, Res, E> {
+    pub(crate) fn new(config: RetryConfig<Req, Res, E>) -> Self {
+        Self {
+            config: Arc::new(config),
+            attempt: 0,
+        }
+    }
+}
+
+impl<Req, Res, E> Clone for TowerRetryPolicy<Req, Res, E> {
+    fn clone(&self) -> Self {
+        Self {
+            config: Arc::clone(&self.config),
+            attempt: self.attempt,
+        }
+    }
+}
+
+impl<Req, Res, E> fmt::Debug for TowerRetryPolicy<Req, Res, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TowerRetryPolicy")
+            .field("name", &self.config.name)
+            .field("attempt", &self.attempt)
+            .finish()
+    }
+}
+
+impl<Req, Res, E> tower::retry::Policy<Req, Res, E> for TowerRetryPolicy<Req, Res, E>
+where
+    Req: Clone,
+{
+    type Future = Sleep;
+
+    fn retry(&mut self, req: &mut Req, result: &mut Result<Res, E>) -> Option<Self::Future> {
+        let config = &self.config;
+        let attempt = self.attempt;
+
+        let delay = match result {
+            Ok(response) if config.policy.should_retry_response(response) => {
+                config.policy.next_backoff_for_response(response, attempt)
+            }
+            Ok(_) => {
+                if let Some(ref budget) = config.budget {
+                    budget.deposit();
+                }
+
+                #[cfg(feature = "metrics")]
+                counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "success").increment(1);
+
+                config.event_listeners.emit(&RetryEvent::Success {
+                    pattern_name: config.name.clone(),
+                    timestamp: Instant::now(),
+                    attempts: attempt + 1,
+                });
+                return None;
+            }
+            Err(error) if !config.policy.should_retry(error) => {
+                config.event_listeners.emit(&RetryEvent::IgnoredError {
+                    pattern_name: config.name.clone(),
+                    timestamp: Instant::now(),
+                });
+                return None;
+            }
+            Err(_) => config.policy.next_backoff(attempt),
+        };
+
+        if attempt + 1 >= config.max_attempts_source.get_max_attempts(req) {
+            #[cfg(feature = "metrics")]
+            counter!("resilience_retry_calls_total", "name" => config.name.clone(), "result" => "exhausted").increment(1);
+
+            #[cfg(feature = "tracing")]
+            warn!(retry = %config.name, attempts = attempt + 1, "Retry attempts exhausted");
+
+            config.event_listeners.emit(&RetryEvent::Error {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+                attempts: attempt + 1,
+            });
+            return None;
+        }
+
+        if !crate::admit_retry(config, attempt, delay) {
+            return None;
+        }
+
+        #[cfg(feature = "metrics")]
+        counter!("resilience_retry_attempts_total", "name" => config.name.clone()).increment(1);
+
+        #[cfg(feature = "tracing")]
+        debug!(retry = %config.name, attempt = attempt + 1, delay_ms = delay.as_millis(), "Retrying after delay");
+
+        config.event_listeners.emit(&RetryEvent::Retry {
+            pattern_name: config.name.clone(),
+            timestamp: Instant::now(),
+            attempt,
+            delay,
+        });
+
+        if config.request_decorator.is_some() {
+            *req = config.request_for_attempt(req, attempt + 1);
+        }
+        self.attempt += 1;
+        Some(config.clock.sleep(delay))
+    }
+
+    fn clone_request(&mut self, req: &Req) -> Option<Req> {
+        Some(req.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RetryLayer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tower::retry::RetryLayer as TowerRetryLayer;
+    use tower::{Layer, Service, ServiceExt};
+    use tower_resilience_core::MockClock;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestError {
+        retryable: bool,
+    }
+
+    fn failing_service(
+        failures: usize,
+        calls: Arc<AtomicUsize>,
+    ) -> impl Service<u32, Response = u32, Error = TestError, Future: Send> + Clone {
+        tower::service_fn(move |req: u32| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if call < failures {
+                    Err(TestError { retryable: true })
+                } else {
+                    Ok(req)
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let retries = Arc::new(AtomicUsize::new(0));
+        let retries_clone = Arc::clone(&retries);
+        let clock = MockClock::new();
+
+        let policy = RetryLayer::<u32, u32, TestError>::builder()
+            .max_attempts(3)
+            .fixed_backoff(Duration::from_secs(1))
+            .clock(clock.clone())
+            .on_retry(move |_, _| {
+                retries_clone.fetch_add(1, Ordering::SeqCst);
+            })
+            .build_policy();
+        let mut service =
+            TowerRetryLayer::new(policy).layer(failing_service(2, Arc::clone(&calls)));
+
+        let response = service.ready().await.unwrap().call(7).await;
+        assert_eq!(response, Ok(7));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(retries.load(Ordering::SeqCst), 2);
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_max_attempts() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let policy = RetryLayer::<u32, u32, TestError>::builder()
+            .max_attempts(2)
+            .fixed_backoff(Duration::from_millis(1))
+            .build_policy();
+        let mut service =
+            TowerRetryLayer::new(policy).layer(failing_service(5, Arc::clone(&calls)));
+
+        assert!(service.ready().await.unwrap().call(1).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Attempts are counted per request, not per policy
+        calls.store(0, Ordering::SeqCst);
+        assert!(service.ready().await.unwrap().call(2).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_stop_does_not_spend_budget() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let budget = crate::RetryBudgetBuilder::new()
+            .token_bucket()
+            .tokens_per_second(0.0)
+            .max_tokens(1)
+            .initial_tokens(1)
+            .build();
+        let policy = RetryLayer::<u32, u32, TestError>::builder()
+            .max_attempts(3)
+            .fixed_backoff(Duration::from_secs(1))
+            .budget(Arc::clone(&budget))
+            .build_policy();
+        let service = TowerRetryLayer::new(policy).layer(failing_service(5, Arc::clone(&calls)));
+
+        let result = tower_resilience_core::Deadline::after(Duration::from_millis(50))
+            .scope(service.oneshot(1))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(budget.balance(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_honors_retry_predicate() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = Arc::clone(&calls);
+        let policy = RetryLayer::<u32, u32, TestError>::builder()
+            .max_attempts(3)
+            .fixed_backoff(Duration::from_millis(1))
+            .retry_on(|err: &TestError| err.retryable)
+            .build_policy();
+        let mut service = TowerRetryLayer::new(policy).layer(tower::service_fn(move |_: u32| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            async { Err::<u32, _>(TestError { retryable: false }) }
+        }));
+
+        assert!(service.ready().await.unwrap().call(1).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,7 +1,7 @@
 //! Error types for the weighted router.
 
 use std::fmt;
-use tower_resilience_core::ResilienceError;
+use tower_resilience_core::{PatternError, ResilienceError};
 
 /// Errors that can occur in the weighted router.
 ///
@@ -44,6 +44,24 @@ impl<E: std::error::Error + 'static> std::error::Error for WeightedRouterError<E
     }
 }
 
+impl<E> PatternError for WeightedRouterError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        false
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            WeightedRouterError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        Ok(self.into_inner())
+    }
+}
+
 impl<E> From<WeightedRouterError<E>> for ResilienceError<E> {
     fn from(err: WeightedRouterError<E>) -> Self {
         match err {
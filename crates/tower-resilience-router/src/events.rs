@@ -5,12 +5,18 @@ use tower_resilience_core::events::ResilienceEvent;
 
 /// Events emitted by the weighted router.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum RouterEvent {
     /// A request was routed to a backend.
     RequestRouted {
         /// Name of the router instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// Index of the selected backend.
         backend_index: usize,
@@ -192,6 +192,13 @@ where
     }
 
     fn call(&mut self, request: Request) -> Self::Future {
+        // The router hands back the backend's own future, so its span covers
+        // backend selection and dispatch but records no outcome
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("router", &self.config.name);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
+
         let idx = self.selector.select();
         let (svc, weight) = &mut self.backends[idx];
 
@@ -201,7 +208,7 @@ where
                 ("router", self.config.name.clone()),
                 ("backend", idx.to_string()),
             ];
-            metrics::counter!("router_requests_routed_total", &labels).increment(1);
+            metrics::counter!("resilience_router_requests_routed_total", &labels).increment(1);
         }
 
         #[cfg(feature = "tracing")]
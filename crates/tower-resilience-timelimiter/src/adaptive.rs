@@ -0,0 +1,232 @@
+//! Adaptive timeouts derived from observed latency.
+//!
+//! A static timeout tuned for peak load is too loose off-peak, and one tuned
+//! off-peak causes spurious timeouts at peak. An adaptive timeout tracks a
+//! rolling latency quantile and scales it by a multiplier, so the timeout
+//! follows the service through diurnal load shifts.
+
+use crate::config::TimeoutFn;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_resilience_core::LatencyWindow;
+
+/// Default number of recent samples kept for quantile estimation.
+const DEFAULT_WINDOW_SIZE: usize = 1000;
+
+/// Default number of samples required before the timeout adapts.
+const DEFAULT_MIN_SAMPLES: usize = 20;
+
+/// Timeout source that sets the timeout to a rolling latency quantile scaled
+/// by a multiplier, clamped to configurable bounds.
+///
+/// Until `min_samples` latencies have been observed, the initial timeout is
+/// used. Timed-out calls are recorded as taking the full timeout, so a
+/// sustained slowdown raises the timeout (up to `max`) instead of being
+/// hidden by the calls that were cut short.
+///
+/// Clones share the same latency window.
+///
+/// # Example
+///
+/// ```rust
+/// use tower_resilience_timelimiter::{AdaptiveTimeout, TimeLimiterLayer};
+/// use std::time::Duration;
+///
+/// // Timeout at p99 x 1.5, between 100ms and 5s
+/// let layer = TimeLimiterLayer::builder()
+///     .adaptive_timeout(
+///         AdaptiveTimeout::new(0.99)
+///             .multiplier(1.5)
+///             .min(Duration::from_millis(100))
+///             .max(Duration::from_secs(5)),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdaptiveTimeout {
+    quantile: f64,
+    multiplier: f64,
+    min: Duration,
+    max: Duration,
+    initial: Duration,
+    min_samples: usize,
+    samples: Arc<LatencyWindow>,
+}
+
+impl AdaptiveTimeout {
+    /// Create an adaptive timeout at the given latency quantile.
+    ///
+    /// The quantile is clamped to `0.0..=1.0`. Defaults to a multiplier of
+    /// 1.0, bounds of 10ms to 30s, an initial timeout of 5s, a window of the
+    /// 1000 most recent samples, and a minimum of 20 samples.
+    pub fn new(quantile: f64) -> Self {
+        Self {
+            quantile: quantile.clamp(0.0, 1.0),
+            multiplier: 1.0,
+            min: Duration::from_millis(10),
+            max: Duration::from_secs(30),
+            initial: Duration::from_secs(5),
+            min_samples: DEFAULT_MIN_SAMPLES,
+            samples: Arc::new(LatencyWindow::new(DEFAULT_WINDOW_SIZE)),
+        }
+    }
+
+    /// Set the factor applied to the observed quantile.
+    ///
+    /// Negative values are treated as zero; NaN and infinite values are
+    /// ignored.
+    ///
+    /// Default: 1.0
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        if multiplier.is_finite() {
+            self.multiplier = multiplier.max(0.0);
+        }
+        self
+    }
+
+    /// Set the lower bound for the timeout.
+    ///
+    /// Default: 10ms
+    pub fn min(mut self, min: Duration) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the upper bound for the timeout.
+    ///
+    /// Default: 30 seconds
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the timeout used until enough samples have been observed.
+    ///
+    /// Default: 5 seconds
+    pub fn initial(mut self, initial: Duration) -> Self {
+        self.initial = initial;
+        self
+    }
+
+    /// Set the minimum number of samples required before the timeout adapts.
+    ///
+    /// Default: 20
+    pub fn min_samples(mut self, min_samples: usize) -> Self {
+        self.min_samples = min_samples.max(1);
+        self
+    }
+
+    /// Set the number of recent samples used for quantile estimation.
+    ///
+    /// Default: 1000
+    pub fn window_size(mut self, window_size: usize) -> Self {
+        self.samples = Arc::new(LatencyWindow::new(window_size));
+        self
+    }
+
+    /// Returns the timeout that would be applied to the next call.
+    pub fn current_timeout(&self) -> Duration {
+        let timeout = match self.samples.quantile(self.quantile, self.min_samples) {
+            // A product too large for a Duration is capped by the upper bound
+            Some(latency) => Duration::try_from_secs_f64(latency.as_secs_f64() * self.multiplier)
+                .unwrap_or(self.max),
+            None => self.initial,
+        };
+        timeout.clamp(self.min, self.max.max(self.min))
+    }
+
+    /// Returns the number of samples currently in the window.
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+impl<Req> TimeoutFn<Req> for AdaptiveTimeout {
+    fn get_timeout(&self, _req: &Req) -> Duration {
+        self.current_timeout()
+    }
+
+    fn clone_box(&self) -> Box<dyn TimeoutFn<Req>> {
+        Box::new(self.clone())
+    }
+
+    fn record(&self, latency: Duration) {
+        self.samples.record(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(timeout: &AdaptiveTimeout, latency: Duration) {
+        TimeoutFn::<()>::record(timeout, latency);
+    }
+
+    #[test]
+    fn uses_initial_until_min_samples() {
+        let timeout = AdaptiveTimeout::new(0.99)
+            .initial(Duration::from_secs(1))
+            .min_samples(3);
+        record(&timeout, Duration::from_millis(20));
+        record(&timeout, Duration::from_millis(20));
+        assert_eq!(timeout.current_timeout(), Duration::from_secs(1));
+
+        record(&timeout, Duration::from_millis(20));
+        assert_eq!(timeout.current_timeout(), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn scales_quantile_by_multiplier() {
+        let timeout = AdaptiveTimeout::new(0.5).multiplier(2.0).min_samples(1);
+        for ms in 1..=100 {
+            record(&timeout, Duration::from_millis(ms));
+        }
+        assert_eq!(timeout.current_timeout(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn ignores_non_finite_multiplier() {
+        let timeout = AdaptiveTimeout::new(0.5)
+            .multiplier(2.0)
+            .multiplier(f64::INFINITY)
+            .multiplier(f64::NAN)
+            .min_samples(1);
+        for ms in 1..=100 {
+            record(&timeout, Duration::from_millis(ms));
+        }
+        assert_eq!(timeout.current_timeout(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn caps_overflowing_multiplier_at_max() {
+        let timeout = AdaptiveTimeout::new(0.5)
+            .multiplier(f64::MAX)
+            .max(Duration::from_secs(2))
+            .min_samples(1);
+        record(&timeout, Duration::from_millis(10));
+        assert_eq!(timeout.current_timeout(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn clamps_to_bounds() {
+        let timeout = AdaptiveTimeout::new(0.99)
+            .min(Duration::from_millis(50))
+            .max(Duration::from_millis(200))
+            .min_samples(1);
+        record(&timeout, Duration::from_millis(1));
+        assert_eq!(timeout.current_timeout(), Duration::from_millis(50));
+
+        let timeout = timeout.window_size(1);
+        record(&timeout, Duration::from_secs(10));
+        assert_eq!(timeout.current_timeout(), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn clones_share_samples() {
+        let timeout = AdaptiveTimeout::new(0.5).min_samples(1);
+        let clone = timeout.clone();
+        record(&clone, Duration::from_millis(30));
+        assert_eq!(timeout.sample_count(), 1);
+    }
+}
@@ -0,0 +1,74 @@
+//! Cooperative cancellation propagation.
+//!
+//! Dropping the inner future on timeout stops the local state machine, but
+//! spawned tasks and remote calls started by the inner service keep running.
+//! A propagator attaches a [`CancellationToken`] to each request so the inner
+//! service can observe the timeout and abort that work itself.
+
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+/// Attaches a per-call [`CancellationToken`] to a request.
+///
+/// The time limiter creates a fresh token for every call and, when it cancels
+/// running futures, cancels the token if the call times out or is dropped.
+pub trait CancellationPropagator<Req>: Send + Sync {
+    /// Attach the token to the request before it is passed to the inner
+    /// service.
+    fn attach(&self, req: &mut Req, token: CancellationToken);
+
+    /// Returns whether tokens should be created at all.
+    ///
+    /// Defaults to `true`.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+}
+
+/// No cancellation propagation.
+///
+/// This is the default propagator. It implements `CancellationPropagator<Req>`
+/// for ALL request types, enabling type inference at the point of use.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoCancellationPropagation;
+
+impl<Req> CancellationPropagator<Req> for NoCancellationPropagation {
+    fn attach(&self, _req: &mut Req, _token: CancellationToken) {}
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Cancellation propagator backed by a closure.
+///
+/// The closure receives the request and the token for that call, and is
+/// expected to store the token somewhere the inner service can find it
+/// (e.g. HTTP request extensions).
+pub struct FnCancellationPropagator<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for FnCancellationPropagator<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> FnCancellationPropagator<F> {
+    /// Create a new propagator from the given closure.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Req, F> CancellationPropagator<Req> for FnCancellationPropagator<F>
+where
+    F: Fn(&mut Req, CancellationToken) + Send + Sync + 'static,
+{
+    fn attach(&self, req: &mut Req, token: CancellationToken) {
+        (self.f)(req, token)
+    }
+}
@@ -1,8 +1,12 @@
 //! Configuration for time limiter.
 
+use crate::adaptive::AdaptiveTimeout;
+use crate::cancel::{FnCancellationPropagator, NoCancellationPropagation};
 use crate::events::TimeLimiterEvent;
+use crate::fallback::{AsyncTimeoutFallback, FnTimeoutFallback, NoTimeoutFallback};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 use tower_resilience_core::{EventListeners, FnListener};
 
 /// Trait for determining timeout duration from a request.
@@ -15,6 +19,12 @@ pub trait TimeoutFn<Req>: Send + Sync {
 
     /// Clone this timeout function into a boxed trait object.
     fn clone_box(&self) -> Box<dyn TimeoutFn<Req>>;
+
+    /// Record the latency of a completed call.
+    ///
+    /// Called with the call duration on success and with the applied timeout
+    /// on timeout. The default implementation does nothing.
+    fn record(&self, _latency: Duration) {}
 }
 
 /// Fixed timeout that works with any request type.
@@ -83,18 +93,33 @@ where
 /// The type parameter `T` is the timeout source type:
 /// - `TimeLimiterConfig<FixedTimeout>` - uses fixed timeout (works with any request type)
 /// - `TimeLimiterConfig<DynamicTimeout<F>>` - uses dynamic timeout from request
-pub struct TimeLimiterConfig<T> {
+/// - `TimeLimiterConfig<AdaptiveTimeout>` - uses timeout derived from observed latency
+///
+/// The type parameter `C` is the cancellation propagator, which defaults to
+/// [`NoCancellationPropagation`]. `B` is the timeout fallback, which defaults
+/// to [`NoTimeoutFallback`].
+pub struct TimeLimiterConfig<T, C = NoCancellationPropagation, B = NoTimeoutFallback> {
     pub(crate) timeout_source: T,
     pub(crate) cancel_running_future: bool,
+    pub(crate) soft_timeout: Option<Duration>,
+    pub(crate) grace_period: Option<Duration>,
+    pub(crate) propagator: C,
+    pub(crate) parent_token: Option<CancellationToken>,
+    pub(crate) fallback: B,
     pub(crate) event_listeners: EventListeners<TimeLimiterEvent>,
     pub(crate) name: String,
 }
 
-impl<T: Clone> Clone for TimeLimiterConfig<T> {
+impl<T: Clone, C: Clone, B: Clone> Clone for TimeLimiterConfig<T, C, B> {
     fn clone(&self) -> Self {
         Self {
             timeout_source: self.timeout_source.clone(),
             cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator: self.propagator.clone(),
+            parent_token: self.parent_token.clone(),
+            fallback: self.fallback.clone(),
             event_listeners: self.event_listeners.clone(),
             name: self.name.clone(),
         }
@@ -137,9 +162,18 @@ impl<T: Clone> Clone for TimeLimiterConfig<T> {
 ///     })
 ///     .build();
 /// ```
-pub struct TimeLimiterConfigBuilder<T = FixedTimeout> {
+pub struct TimeLimiterConfigBuilder<
+    T = FixedTimeout,
+    C = NoCancellationPropagation,
+    B = NoTimeoutFallback,
+> {
     timeout_source: T,
     cancel_running_future: bool,
+    soft_timeout: Option<Duration>,
+    grace_period: Option<Duration>,
+    propagator: C,
+    parent_token: Option<CancellationToken>,
+    fallback: B,
     event_listeners: EventListeners<TimeLimiterEvent>,
     name: String,
 }
@@ -159,13 +193,18 @@ impl TimeLimiterConfigBuilder<FixedTimeout> {
         Self {
             timeout_source: FixedTimeout(Duration::from_secs(5)),
             cancel_running_future: true,
+            soft_timeout: None,
+            grace_period: None,
+            propagator: NoCancellationPropagation,
+            parent_token: None,
+            fallback: NoTimeoutFallback,
             event_listeners: EventListeners::new(),
             name: String::from("<unnamed>"),
         }
     }
 }
 
-impl<T> TimeLimiterConfigBuilder<T> {
+impl<T, C, B> TimeLimiterConfigBuilder<T, C, B> {
     /// Sets a fixed timeout duration for all requests.
     ///
     /// This is the simplest configuration where every request gets
@@ -184,10 +223,18 @@ impl<T> TimeLimiterConfigBuilder<T> {
     ///     .timeout_duration(Duration::from_secs(30))
     ///     .build();
     /// ```
-    pub fn timeout_duration(self, duration: Duration) -> TimeLimiterConfigBuilder<FixedTimeout> {
+    pub fn timeout_duration(
+        self,
+        duration: Duration,
+    ) -> TimeLimiterConfigBuilder<FixedTimeout, C, B> {
         TimeLimiterConfigBuilder {
             timeout_source: FixedTimeout(duration),
             cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator: self.propagator,
+            parent_token: self.parent_token,
+            fallback: self.fallback,
             event_listeners: self.event_listeners,
             name: self.name,
         }
@@ -226,18 +273,68 @@ impl<T> TimeLimiterConfigBuilder<T> {
     ///     })
     ///     .build();
     /// ```
-    pub fn timeout_fn<Req, F>(self, f: F) -> TimeLimiterConfigBuilder<DynamicTimeout<F>>
+    pub fn timeout_fn<Req, F>(self, f: F) -> TimeLimiterConfigBuilder<DynamicTimeout<F>, C, B>
     where
         F: Fn(&Req) -> Duration + Send + Sync + 'static,
     {
         TimeLimiterConfigBuilder {
             timeout_source: DynamicTimeout::new(f),
             cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator: self.propagator,
+            parent_token: self.parent_token,
+            fallback: self.fallback,
             event_listeners: self.event_listeners,
             name: self.name,
         }
     }
 
+    /// Sets an adaptive timeout derived from observed latency.
+    ///
+    /// The timeout tracks a rolling latency quantile scaled by a multiplier,
+    /// clamped to the configured bounds. See [`AdaptiveTimeout`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_timelimiter::{AdaptiveTimeout, TimeLimiterLayer};
+    /// use std::time::Duration;
+    ///
+    /// let layer = TimeLimiterLayer::builder()
+    ///     .adaptive_timeout(AdaptiveTimeout::new(0.99).multiplier(1.5))
+    ///     .build();
+    /// ```
+    pub fn adaptive_timeout(
+        self,
+        timeout: AdaptiveTimeout,
+    ) -> TimeLimiterConfigBuilder<AdaptiveTimeout, C, B> {
+        TimeLimiterConfigBuilder {
+            timeout_source: timeout,
+            cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator: self.propagator,
+            parent_token: self.parent_token,
+            fallback: self.fallback,
+            event_listeners: self.event_listeners,
+            name: self.name,
+        }
+    }
+
+    /// Sets a soft timeout that reports slow calls without cancelling them.
+    ///
+    /// When a call runs longer than the soft timeout, a
+    /// [`TimeLimiterEvent::SoftTimeout`] event is emitted and the call keeps
+    /// running until it completes or the hard timeout fires. Soft timeouts
+    /// at or above the hard timeout are ignored.
+    ///
+    /// Default: none
+    pub fn soft_timeout(mut self, soft_timeout: Duration) -> Self {
+        self.soft_timeout = Some(soft_timeout);
+        self
+    }
+
     /// Sets whether to cancel the running future when a timeout occurs.
     ///
     /// When true (the default), the future will be dropped on timeout, canceling
@@ -250,6 +347,155 @@ impl<T> TimeLimiterConfigBuilder<T> {
         self
     }
 
+    /// Sets a grace period for draining timed-out calls.
+    ///
+    /// When a call times out and
+    /// [`cancel_running_future`](Self::cancel_running_future) is enabled,
+    /// the future keeps running in a detached task for up to this long
+    /// instead of being dropped immediately. If it completes in that time, a
+    /// [`TimeLimiterEvent::TimedOutLate`] event records how late it was. The
+    /// caller still receives the timeout as soon as it fires.
+    ///
+    /// When cancellation propagation is configured, the call's token is
+    /// cancelled once the grace period expires rather than at the timeout.
+    ///
+    /// Default: none
+    pub fn grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = Some(grace_period);
+        self
+    }
+
+    /// Propagates a per-call [`CancellationToken`] to the inner service.
+    ///
+    /// The closure is called with each request and a fresh token before the
+    /// request is passed to the inner service. The token is cancelled when
+    /// the call times out or the caller drops it, so spawned work or remote
+    /// calls started by the inner service can stop cooperatively. With
+    /// [`cancel_running_future(false)`](Self::cancel_running_future) the call
+    /// is left running and the token is never cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_timelimiter::{CancellationToken, TimeLimiterLayer};
+    /// use std::time::Duration;
+    ///
+    /// struct MyRequest {
+    ///     cancel: Option<CancellationToken>,
+    /// }
+    ///
+    /// let layer = TimeLimiterLayer::builder()
+    ///     .timeout_duration(Duration::from_secs(5))
+    ///     .propagate_cancellation(|req: &mut MyRequest, token| {
+    ///         req.cancel = Some(token);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn propagate_cancellation<Req, F>(
+        self,
+        f: F,
+    ) -> TimeLimiterConfigBuilder<T, FnCancellationPropagator<F>, B>
+    where
+        F: Fn(&mut Req, CancellationToken) + Send + Sync + 'static,
+    {
+        self.cancellation_propagator(FnCancellationPropagator::new(f))
+    }
+
+    /// Sets a custom [`CancellationPropagator`](crate::CancellationPropagator).
+    pub fn cancellation_propagator<C2>(self, propagator: C2) -> TimeLimiterConfigBuilder<T, C2, B> {
+        TimeLimiterConfigBuilder {
+            timeout_source: self.timeout_source,
+            cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator,
+            parent_token: self.parent_token,
+            fallback: self.fallback,
+            event_listeners: self.event_listeners,
+            name: self.name,
+        }
+    }
+
+    /// Produces a fallback response when a call times out.
+    ///
+    /// Instead of returning [`TimeLimiterError::Timeout`](crate::TimeLimiterError::Timeout),
+    /// the time limiter calls the closure with a clone of the request and
+    /// returns its result. Timeout events and metrics are still recorded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_timelimiter::TimeLimiterLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = TimeLimiterLayer::builder()
+    ///     .timeout_duration(Duration::from_millis(500))
+    ///     .timeout_fallback(|req: String| format!("cached response for {req}"))
+    ///     .build();
+    /// ```
+    pub fn timeout_fallback<Req, Res, F>(
+        self,
+        f: F,
+    ) -> TimeLimiterConfigBuilder<T, C, FnTimeoutFallback<F>>
+    where
+        F: Fn(Req) -> Res + Send + Sync + 'static,
+    {
+        self.with_fallback(FnTimeoutFallback::new(f))
+    }
+
+    /// Produces a fallback response asynchronously when a call times out.
+    ///
+    /// Like [`timeout_fallback`](Self::timeout_fallback), but the closure
+    /// returns a future, e.g. to read a stale value from a cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tower_resilience_timelimiter::TimeLimiterLayer;
+    /// use std::time::Duration;
+    ///
+    /// let layer = TimeLimiterLayer::builder()
+    ///     .timeout_duration(Duration::from_millis(500))
+    ///     .timeout_fallback_async(|req: String| async move {
+    ///         format!("cached response for {req}")
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn timeout_fallback_async<Req, Res, F, Fut>(
+        self,
+        f: F,
+    ) -> TimeLimiterConfigBuilder<T, C, AsyncTimeoutFallback<F>>
+    where
+        F: Fn(Req) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Res> + Send + 'static,
+    {
+        self.with_fallback(AsyncTimeoutFallback::new(f))
+    }
+
+    fn with_fallback<B2>(self, fallback: B2) -> TimeLimiterConfigBuilder<T, C, B2> {
+        TimeLimiterConfigBuilder {
+            timeout_source: self.timeout_source,
+            cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator: self.propagator,
+            parent_token: self.parent_token,
+            fallback,
+            event_listeners: self.event_listeners,
+            name: self.name,
+        }
+    }
+
+    /// Sets a parent token for the per-call cancellation tokens.
+    ///
+    /// Each call's token is created as a child of this token, so cancelling
+    /// the parent (e.g. on shutdown) cancels every in-flight call's token.
+    /// Only used when cancellation propagation is configured.
+    pub fn parent_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.parent_token = Some(token);
+        self
+    }
+
     /// Sets the name of this time limiter instance for observability.
     ///
     /// Default: `"<unnamed>"`
@@ -297,11 +543,54 @@ impl<T> TimeLimiterConfigBuilder<T> {
         self
     }
 
+    /// Registers a callback when a call exceeds the soft timeout.
+    pub fn on_soft_timeout<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let TimeLimiterEvent::SoftTimeout { soft_timeout, .. } = event {
+                f(*soft_timeout);
+            }
+        }));
+        self
+    }
+
+    /// Registers a callback when a timed-out call completes during the grace
+    /// period.
+    ///
+    /// The callback receives how long after the timeout the call completed.
+    pub fn on_timed_out_late<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.event_listeners.add(FnListener::new(move |event| {
+            if let TimeLimiterEvent::TimedOutLate { late_by, .. } = event {
+                f(*late_by);
+            }
+        }));
+        self
+    }
+
+    /// Delivers events to listeners from a background task instead of inline
+    /// on the request path, buffering up to `capacity` events and dropping the
+    /// oldest when full.
+    pub fn async_events(mut self, capacity: usize) -> Self {
+        self.event_listeners
+            .set_event_bus(tower_resilience_core::EventBus::new(capacity));
+        self
+    }
+
     /// Builds the time limiter layer.
-    pub fn build(self) -> crate::TimeLimiterLayer<T> {
+    pub fn build(self) -> crate::TimeLimiterLayer<T, C, B> {
         let config = TimeLimiterConfig {
             timeout_source: self.timeout_source,
             cancel_running_future: self.cancel_running_future,
+            soft_timeout: self.soft_timeout,
+            grace_period: self.grace_period,
+            propagator: self.propagator,
+            parent_token: self.parent_token,
+            fallback: self.fallback,
             event_listeners: self.event_listeners,
             name: self.name,
         };
@@ -0,0 +1,84 @@
+//! Injection of the current deadline into outgoing requests.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::layer::Layer;
+use tower::Service;
+use tower_resilience_core::Deadline;
+
+/// A Tower layer that writes the current [`Deadline`] into each request.
+///
+/// Place it inside a [`TimeLimiterLayer`](crate::TimeLimiterLayer) so the
+/// downstream peer learns how long the caller is willing to wait, e.g. by
+/// setting a gRPC `grpc-timeout` header from [`Deadline::grpc_timeout`].
+/// Requests made outside any deadline scope are passed through unchanged.
+pub struct InjectDeadlineLayer<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for InjectDeadlineLayer<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> InjectDeadlineLayer<F> {
+    /// Create a new layer that injects deadlines using the given closure.
+    pub fn new<Req>(f: F) -> Self
+    where
+        F: Fn(&mut Req, Deadline),
+    {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<S, F> Layer<S> for InjectDeadlineLayer<F> {
+    type Service = InjectDeadline<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InjectDeadline {
+            inner,
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+/// A service that writes the current [`Deadline`] into each request.
+///
+/// Created by [`InjectDeadlineLayer`].
+pub struct InjectDeadline<S, F> {
+    inner: S,
+    f: Arc<F>,
+}
+
+impl<S: Clone, F> Clone for InjectDeadline<S, F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<S, F, Req> Service<Req> for InjectDeadline<S, F>
+where
+    S: Service<Req>,
+    F: Fn(&mut Req, Deadline),
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Req) -> Self::Future {
+        if let Some(deadline) = Deadline::current() {
+            (self.f)(&mut req, deadline);
+        }
+        self.inner.call(req)
+    }
+}
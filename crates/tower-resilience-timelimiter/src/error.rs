@@ -1,7 +1,7 @@
 //! Error types for time limiter.
 
 use std::fmt;
-use tower_resilience_core::ResilienceError;
+use tower_resilience_core::{PatternError, ResilienceError};
 
 /// Errors that can occur in the time limiter.
 #[derive(Debug)]
@@ -30,6 +30,32 @@ impl<E: std::error::Error + 'static> std::error::Error for TimeLimiterError<E> {
     }
 }
 
+impl<E> PatternError for TimeLimiterError<E> {
+    type Inner = E;
+
+    fn is_rejection(&self) -> bool {
+        false
+    }
+
+    fn is_timeout(&self) -> bool {
+        matches!(self, TimeLimiterError::Timeout)
+    }
+
+    fn source_inner(&self) -> Option<&E> {
+        match self {
+            TimeLimiterError::Timeout => None,
+            TimeLimiterError::Inner(e) => Some(e),
+        }
+    }
+
+    fn into_source_inner(self) -> Result<E, Self> {
+        match self {
+            TimeLimiterError::Inner(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
 impl<E> TimeLimiterError<E> {
     /// Returns true if this is a timeout error.
     pub fn is_timeout(&self) -> bool {
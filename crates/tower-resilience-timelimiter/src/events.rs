@@ -1,16 +1,22 @@
 //! Event types for time limiter.
 
 use std::time::{Duration, Instant};
-use tower_resilience_core::ResilienceEvent;
+use tower_resilience_core::{ResilienceEvent, Severity};
 
 /// Events emitted by the time limiter.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum TimeLimiterEvent {
     /// A call completed successfully within the timeout.
     Success {
         /// The name of the time limiter instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// How long the call took.
         duration: Duration,
@@ -20,19 +26,55 @@ pub enum TimeLimiterEvent {
         /// The name of the time limiter instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// How long before the error occurred.
         duration: Duration,
     },
+    /// A call exceeded the soft timeout but is still running.
+    SoftTimeout {
+        /// The name of the time limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The configured soft timeout duration.
+        soft_timeout: Duration,
+    },
     /// A call timed out.
     Timeout {
         /// The name of the time limiter instance.
         pattern_name: String,
         /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
         timestamp: Instant,
         /// The configured timeout duration.
         timeout_duration: Duration,
     },
+    /// A timed-out call completed during the grace period.
+    TimedOutLate {
+        /// The name of the time limiter instance.
+        pattern_name: String,
+        /// When the event occurred.
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "tower_resilience_core::timestamp::serialize")
+        )]
+        timestamp: Instant,
+        /// The timeout the call exceeded.
+        timeout_duration: Duration,
+        /// How long after the timeout the call completed.
+        late_by: Duration,
+    },
 }
 
 impl ResilienceEvent for TimeLimiterEvent {
@@ -40,7 +82,9 @@ impl ResilienceEvent for TimeLimiterEvent {
         match self {
             TimeLimiterEvent::Success { .. } => "success",
             TimeLimiterEvent::Error { .. } => "error",
+            TimeLimiterEvent::SoftTimeout { .. } => "soft_timeout",
             TimeLimiterEvent::Timeout { .. } => "timeout",
+            TimeLimiterEvent::TimedOutLate { .. } => "timed_out_late",
         }
     }
 
@@ -48,7 +92,9 @@ impl ResilienceEvent for TimeLimiterEvent {
         match self {
             TimeLimiterEvent::Success { timestamp, .. }
             | TimeLimiterEvent::Error { timestamp, .. }
-            | TimeLimiterEvent::Timeout { timestamp, .. } => *timestamp,
+            | TimeLimiterEvent::SoftTimeout { timestamp, .. }
+            | TimeLimiterEvent::Timeout { timestamp, .. }
+            | TimeLimiterEvent::TimedOutLate { timestamp, .. } => *timestamp,
         }
     }
 
@@ -56,7 +102,19 @@ impl ResilienceEvent for TimeLimiterEvent {
         match self {
             TimeLimiterEvent::Success { pattern_name, .. }
             | TimeLimiterEvent::Error { pattern_name, .. }
-            | TimeLimiterEvent::Timeout { pattern_name, .. } => pattern_name,
+            | TimeLimiterEvent::SoftTimeout { pattern_name, .. }
+            | TimeLimiterEvent::Timeout { pattern_name, .. }
+            | TimeLimiterEvent::TimedOutLate { pattern_name, .. } => pattern_name,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            TimeLimiterEvent::Timeout { .. } => Severity::Error,
+            TimeLimiterEvent::Error { .. } | TimeLimiterEvent::SoftTimeout { .. } => Severity::Warn,
+            TimeLimiterEvent::Success { .. } | TimeLimiterEvent::TimedOutLate { .. } => {
+                Severity::Info
+            }
         }
     }
 }
@@ -83,11 +141,26 @@ mod tests {
         };
         assert_eq!(error.event_type(), "error");
 
+        let soft_timeout = TimeLimiterEvent::SoftTimeout {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            soft_timeout: Duration::from_secs(1),
+        };
+        assert_eq!(soft_timeout.event_type(), "soft_timeout");
+
         let timeout = TimeLimiterEvent::Timeout {
             pattern_name: "test".to_string(),
             timestamp: now,
             timeout_duration: Duration::from_secs(5),
         };
         assert_eq!(timeout.event_type(), "timeout");
+
+        let late = TimeLimiterEvent::TimedOutLate {
+            pattern_name: "test".to_string(),
+            timestamp: now,
+            timeout_duration: Duration::from_secs(5),
+            late_by: Duration::from_millis(200),
+        };
+        assert_eq!(late.event_type(), "timed_out_late");
     }
 }
@@ -0,0 +1,119 @@
+//! Fallback responses produced directly by the time limiter on timeout.
+//!
+//! Without this, callers compose a separate fallback layer and pattern-match
+//! on [`TimeLimiterError::Timeout`](crate::TimeLimiterError::Timeout) to
+//! produce a degraded response.
+
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Produces a response when a call times out.
+///
+/// Because the inner service consumes the request, implementations capture
+/// whatever they need from it in [`prepare`](Self::prepare) before the call
+/// is dispatched.
+pub trait TimeoutFallback<Req, Res>: Send + Sync {
+    /// State captured from the request before the call.
+    type Pending: Send + 'static;
+
+    /// Capture state from the request before it is passed to the inner
+    /// service.
+    fn prepare(&self, req: &Req) -> Self::Pending;
+
+    /// Produce the fallback response, or `None` to return
+    /// [`TimeLimiterError::Timeout`](crate::TimeLimiterError::Timeout).
+    fn fallback(&self, pending: Self::Pending) -> Option<BoxFuture<'static, Res>>;
+}
+
+/// No timeout fallback.
+///
+/// This is the default. It implements `TimeoutFallback<Req, Res>` for ALL
+/// request and response types, enabling type inference at the point of use.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoTimeoutFallback;
+
+impl<Req, Res> TimeoutFallback<Req, Res> for NoTimeoutFallback {
+    type Pending = ();
+
+    fn prepare(&self, _req: &Req) {}
+
+    fn fallback(&self, _pending: ()) -> Option<BoxFuture<'static, Res>> {
+        None
+    }
+}
+
+/// Timeout fallback backed by a synchronous closure.
+pub struct FnTimeoutFallback<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for FnTimeoutFallback<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> FnTimeoutFallback<F> {
+    /// Create a new fallback from the given closure.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Req, Res, F> TimeoutFallback<Req, Res> for FnTimeoutFallback<F>
+where
+    Req: Clone + Send + 'static,
+    Res: Send + 'static,
+    F: Fn(Req) -> Res + Send + Sync + 'static,
+{
+    type Pending = Req;
+
+    fn prepare(&self, req: &Req) -> Req {
+        req.clone()
+    }
+
+    fn fallback(&self, req: Req) -> Option<BoxFuture<'static, Res>> {
+        let response = (self.f)(req);
+        Some(Box::pin(async move { response }))
+    }
+}
+
+/// Timeout fallback backed by an asynchronous closure.
+pub struct AsyncTimeoutFallback<F> {
+    f: Arc<F>,
+}
+
+impl<F> Clone for AsyncTimeoutFallback<F> {
+    fn clone(&self) -> Self {
+        Self {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<F> AsyncTimeoutFallback<F> {
+    /// Create a new fallback from the given async closure.
+    pub fn new(f: F) -> Self {
+        Self { f: Arc::new(f) }
+    }
+}
+
+impl<Req, Res, F, Fut> TimeoutFallback<Req, Res> for AsyncTimeoutFallback<F>
+where
+    Req: Clone + Send + 'static,
+    F: Fn(Req) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Res> + Send + 'static,
+{
+    type Pending = Req;
+
+    fn prepare(&self, req: &Req) -> Req {
+        req.clone()
+    }
+
+    fn fallback(&self, req: Req) -> Option<BoxFuture<'static, Res>> {
+        Some(Box::pin((self.f)(req)))
+    }
+}
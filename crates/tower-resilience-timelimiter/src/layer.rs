@@ -1,6 +1,8 @@
 //! Tower layer for time limiter.
 
+use crate::cancel::NoCancellationPropagation;
 use crate::config::{FixedTimeout, TimeLimiterConfig};
+use crate::fallback::NoTimeoutFallback;
 use crate::TimeLimiter;
 use std::sync::Arc;
 use std::time::Duration;
@@ -11,6 +13,11 @@ use tower::layer::Layer;
 /// The type parameter `T` is the timeout source type:
 /// - `TimeLimiterLayer<FixedTimeout>` - uses fixed timeout (works with any request type)
 /// - `TimeLimiterLayer<DynamicTimeout<F>>` - uses dynamic timeout from request
+/// - `TimeLimiterLayer<AdaptiveTimeout>` - uses timeout derived from observed latency
+///
+/// The type parameter `C` is the cancellation propagator, which defaults to
+/// [`NoCancellationPropagation`]. `B` is the timeout fallback, which defaults
+/// to [`NoTimeoutFallback`].
 ///
 /// # Usage
 ///
@@ -55,13 +62,14 @@ use tower::layer::Layer;
 ///     .service(service_fn(|req: MyRequest| async move { Ok::<_, ()>(format!("{:?}", req.timeout_ms)) }));
 /// ```
 #[derive(Clone)]
-pub struct TimeLimiterLayer<T = FixedTimeout> {
-    config: Arc<TimeLimiterConfig<T>>,
+pub struct TimeLimiterLayer<T = FixedTimeout, C = NoCancellationPropagation, B = NoTimeoutFallback>
+{
+    config: Arc<TimeLimiterConfig<T, C, B>>,
 }
 
-impl<T> TimeLimiterLayer<T> {
+impl<T, C, B> TimeLimiterLayer<T, C, B> {
     /// Creates a new time limiter layer from the given configuration.
-    pub(crate) fn new(config: impl Into<Arc<TimeLimiterConfig<T>>>) -> Self {
+    pub(crate) fn new(config: impl Into<Arc<TimeLimiterConfig<T, C, B>>>) -> Self {
         Self {
             config: config.into(),
         }
@@ -217,15 +225,24 @@ impl TimeLimiterLayer<FixedTimeout> {
     }
 }
 
-impl<T> From<TimeLimiterConfig<T>> for TimeLimiterLayer<T> {
-    fn from(config: TimeLimiterConfig<T>) -> Self {
+impl<T, C, B> From<TimeLimiterConfig<T, C, B>> for TimeLimiterLayer<T, C, B> {
+    fn from(config: TimeLimiterConfig<T, C, B>) -> Self {
         Self::new(config)
     }
 }
 
 // Implement Layer<S> for FixedTimeout - works with any service
-impl<S> Layer<S> for TimeLimiterLayer<FixedTimeout> {
-    type Service = TimeLimiter<S, FixedTimeout>;
+impl<S, C, B> Layer<S> for TimeLimiterLayer<FixedTimeout, C, B> {
+    type Service = TimeLimiter<S, FixedTimeout, C, B>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        TimeLimiter::new(service, Arc::clone(&self.config))
+    }
+}
+
+// Implement Layer<S> for AdaptiveTimeout - works with any service
+impl<S, C, B> Layer<S> for TimeLimiterLayer<crate::adaptive::AdaptiveTimeout, C, B> {
+    type Service = TimeLimiter<S, crate::adaptive::AdaptiveTimeout, C, B>;
 
     fn layer(&self, service: S) -> Self::Service {
         TimeLimiter::new(service, Arc::clone(&self.config))
@@ -233,11 +250,11 @@ impl<S> Layer<S> for TimeLimiterLayer<FixedTimeout> {
 }
 
 // Implement Layer<S> for DynamicTimeout - the closure determines compatible services
-impl<S, F> Layer<S> for TimeLimiterLayer<crate::config::DynamicTimeout<F>>
+impl<S, F, C, B> Layer<S> for TimeLimiterLayer<crate::config::DynamicTimeout<F>, C, B>
 where
     F: 'static,
 {
-    type Service = TimeLimiter<S, crate::config::DynamicTimeout<F>>;
+    type Service = TimeLimiter<S, crate::config::DynamicTimeout<F>, C, B>;
 
     fn layer(&self, service: S) -> Self::Service {
         TimeLimiter::new(service, Arc::clone(&self.config))
@@ -1,8 +1,11 @@
 //! Advanced timeout handling for Tower services.
 //!
 //! Provides timeout functionality with:
-//! - Configurable timeout duration (fixed or per-request)
+//! - Configurable timeout duration (fixed, per-request, or adaptive)
 //! - Optional future cancellation on timeout
+//! - Soft timeouts that report slow calls without failing them
+//! - Cooperative cancellation via per-call [`CancellationToken`]s
+//! - Deadline propagation to nested layers
 //! - Event system for observability (onSuccess, onError, onTimeout)
 //! - Metrics integration
 //!
@@ -86,6 +89,151 @@
 //! # }
 //! ```
 //!
+//! ## Soft Timeout
+//!
+//! A soft timeout emits an event for calls that run longer than expected but
+//! does not cancel them, so "almost timed out" requests can be observed
+//! before a stricter hard timeout is enforced:
+//!
+//! ```rust
+//! use tower_resilience_timelimiter::TimeLimiterLayer;
+//! use std::time::Duration;
+//!
+//! let layer = TimeLimiterLayer::builder()
+//!     .timeout_duration(Duration::from_secs(5))
+//!     .soft_timeout(Duration::from_secs(1))
+//!     .on_soft_timeout(|soft_timeout| {
+//!         eprintln!("Call exceeded {:?}", soft_timeout);
+//!     })
+//!     .build();
+//! ```
+//!
+//! ## Adaptive Timeout
+//!
+//! Track a rolling latency quantile instead of a static duration, so the
+//! timeout follows the service through load shifts:
+//!
+//! ```rust
+//! use tower_resilience_timelimiter::{AdaptiveTimeout, TimeLimiterLayer};
+//! use std::time::Duration;
+//!
+//! // Timeout at p99 x 1.5, never below 50ms or above 10s
+//! let layer = TimeLimiterLayer::builder()
+//!     .adaptive_timeout(
+//!         AdaptiveTimeout::new(0.99)
+//!             .multiplier(1.5)
+//!             .min(Duration::from_millis(50))
+//!             .max(Duration::from_secs(10)),
+//!     )
+//!     .build();
+//! ```
+//!
+//! ## Grace Period
+//!
+//! With cancellation enabled, a timed-out future is normally dropped at once.
+//! A grace period keeps it running in a detached task for a while longer and
+//! emits a `TimedOutLate` event if it completes, recording how late it was:
+//!
+//! ```rust
+//! use tower_resilience_timelimiter::TimeLimiterLayer;
+//! use std::time::Duration;
+//!
+//! let layer = TimeLimiterLayer::builder()
+//!     .timeout_duration(Duration::from_secs(1))
+//!     .grace_period(Duration::from_secs(5))
+//!     .on_timed_out_late(|late_by| {
+//!         eprintln!("Call would have taken {:?} longer", late_by);
+//!     })
+//!     .build();
+//! ```
+//!
+//! ## Timeout Fallback
+//!
+//! Return a degraded response directly from the layer on timeout, instead of
+//! composing a separate fallback layer and matching on the timeout error:
+//!
+//! ```rust
+//! use tower_resilience_timelimiter::TimeLimiterLayer;
+//! use tower::{Layer, service_fn};
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let layer = TimeLimiterLayer::builder()
+//!     .timeout_duration(Duration::from_millis(200))
+//!     .timeout_fallback(|req: String| format!("default for {req}"))
+//!     .build();
+//!
+//! let svc = service_fn(|req: String| async move { Ok::<String, ()>(req) });
+//! let mut service = layer.layer(svc);
+//! # }
+//! ```
+//!
+//! Use `timeout_fallback_async` when producing the fallback requires I/O.
+//!
+//! ## Cooperative Cancellation
+//!
+//! Dropping the future on timeout does not stop work the inner service has
+//! already spawned or sent to a remote peer. With
+//! [`propagate_cancellation`](TimeLimiterConfigBuilder::propagate_cancellation),
+//! each call gets a fresh [`CancellationToken`] that is cancelled when the
+//! call is abandoned: on timeout, or when the caller drops the call. Tokens
+//! are only cancelled when
+//! [`cancel_running_future`](TimeLimiterConfigBuilder::cancel_running_future)
+//! is enabled, since otherwise the call is meant to keep running:
+//!
+//! ```rust
+//! use tower_resilience_timelimiter::{CancellationToken, TimeLimiterLayer};
+//! use tower::{Layer, service_fn};
+//! use std::time::Duration;
+//!
+//! struct Job {
+//!     cancel: CancellationToken,
+//! }
+//!
+//! # async fn example() {
+//! let layer = TimeLimiterLayer::builder()
+//!     .timeout_duration(Duration::from_secs(5))
+//!     .propagate_cancellation(|job: &mut Job, token| job.cancel = token)
+//!     .build();
+//!
+//! let svc = service_fn(|job: Job| async move {
+//!     tokio::spawn(async move {
+//!         // Background work stops when the call times out
+//!         job.cancel.cancelled().await;
+//!     });
+//!     Ok::<_, ()>(())
+//! });
+//!
+//! let mut service = layer.layer(svc);
+//! # }
+//! ```
+//!
+//! ## Deadline Propagation
+//!
+//! Each call runs with a [`Deadline`] of now + timeout. Nested layers can read
+//! it with [`Deadline::current`] so their own budgets never exceed the outer
+//! one: nested time limiters shrink their timeout to the remaining time, and
+//! retries stop once the next backoff would pass the deadline.
+//!
+//! [`InjectDeadlineLayer`] writes the current deadline into outgoing requests,
+//! e.g. as a gRPC `grpc-timeout` header:
+//!
+//! ```rust
+//! use tower::{ServiceBuilder, service_fn};
+//! use tower_resilience_timelimiter::{InjectDeadlineLayer, TimeLimiterLayer};
+//! use std::collections::HashMap;
+//! use std::time::Duration;
+//!
+//! type Headers = HashMap<String, String>;
+//!
+//! let service = ServiceBuilder::new()
+//!     .layer(TimeLimiterLayer::builder().timeout_duration(Duration::from_secs(2)).build())
+//!     .layer(InjectDeadlineLayer::new(|req: &mut Headers, deadline| {
+//!         req.insert("grpc-timeout".to_string(), deadline.grpc_timeout());
+//!     }))
+//!     .service(service_fn(|req: Headers| async move { Ok::<_, ()>(req) }));
+//! ```
+//!
 //! ## Event Listeners
 //!
 //! ```rust
@@ -109,9 +257,10 @@
 //! ```
 
 use futures::future::BoxFuture;
+use std::future::Future;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::time::timeout;
 use tower::Service;
 
@@ -121,29 +270,47 @@ use metrics::{counter, describe_counter, describe_histogram, histogram};
 #[cfg(feature = "tracing")]
 use tracing::{debug, warn};
 
+pub use adaptive::AdaptiveTimeout;
+pub use cancel::{CancellationPropagator, FnCancellationPropagator, NoCancellationPropagation};
 pub use config::{
     DynamicTimeout, FixedTimeout, TimeLimiterConfig, TimeLimiterConfigBuilder, TimeoutFn,
 };
 pub use error::TimeLimiterError;
 pub use events::TimeLimiterEvent;
+pub use fallback::{AsyncTimeoutFallback, FnTimeoutFallback, NoTimeoutFallback, TimeoutFallback};
 pub use layer::TimeLimiterLayer;
+pub use settings::TimeLimiterSettings;
+pub use tokio_util::sync::CancellationToken;
+pub use tower_resilience_core::Deadline;
+
+pub use deadline::{InjectDeadline, InjectDeadlineLayer};
 
+mod adaptive;
+mod cancel;
 mod config;
+mod deadline;
 mod error;
 mod events;
+mod fallback;
 mod layer;
+mod settings;
 
 /// A Tower service that applies timeout limiting to an inner service.
 ///
 /// The type parameter `T` is the timeout source:
 /// - `FixedTimeout` - uses the same timeout for all requests
 /// - `DynamicTimeout<F>` - extracts timeout from each request using closure F
-pub struct TimeLimiter<S, T> {
+/// - `AdaptiveTimeout` - derives the timeout from observed latency
+///
+/// The type parameter `C` is the cancellation propagator, which defaults to
+/// [`NoCancellationPropagation`]. `B` is the timeout fallback, which defaults
+/// to [`NoTimeoutFallback`].
+pub struct TimeLimiter<S, T, C = NoCancellationPropagation, B = NoTimeoutFallback> {
     inner: S,
-    config: Arc<TimeLimiterConfig<T>>,
+    config: Arc<TimeLimiterConfig<T, C, B>>,
 }
 
-impl<S: Clone, T> Clone for TimeLimiter<S, T> {
+impl<S: Clone, T, C, B> Clone for TimeLimiter<S, T, C, B> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -152,17 +319,25 @@ impl<S: Clone, T> Clone for TimeLimiter<S, T> {
     }
 }
 
-impl<S, T> TimeLimiter<S, T> {
+impl<S, T, C, B> TimeLimiter<S, T, C, B> {
     /// Creates a new time limiter wrapping the given service.
-    pub(crate) fn new(inner: S, config: Arc<TimeLimiterConfig<T>>) -> Self {
+    pub(crate) fn new(inner: S, config: Arc<TimeLimiterConfig<T, C, B>>) -> Self {
         #[cfg(feature = "metrics")]
         {
             describe_counter!(
-                "timelimiter_calls_total",
+                "resilience_timelimiter_calls_total",
                 "Total number of time limiter calls (success, error, or timeout)"
             );
+            describe_counter!(
+                "resilience_timelimiter_soft_timeouts_total",
+                "Total number of calls that exceeded the soft timeout"
+            );
+            describe_counter!(
+                "resilience_timelimiter_late_completions_total",
+                "Total number of timed-out calls that completed during the grace period"
+            );
             describe_histogram!(
-                "timelimiter_call_duration_seconds",
+                "resilience_timelimiter_call_duration_seconds",
                 "Duration of calls (successful or failed)"
             );
         }
@@ -171,7 +346,7 @@ impl<S, T> TimeLimiter<S, T> {
     }
 }
 
-impl<S, T, Req> Service<Req> for TimeLimiter<S, T>
+impl<S, T, C, B, Req> Service<Req> for TimeLimiter<S, T, C, B>
 where
     S: Service<Req> + Clone + Send + 'static,
     S::Future: Send + 'static,
@@ -179,6 +354,8 @@ where
     S::Error: Send + 'static,
     Req: Send + 'static,
     T: TimeoutFn<Req> + 'static,
+    C: CancellationPropagator<Req> + 'static,
+    B: TimeoutFallback<Req, S::Response> + 'static,
 {
     type Response = S::Response;
     type Error = TimeLimiterError<S::Error>;
@@ -188,47 +365,142 @@ where
         self.inner.poll_ready(cx).map_err(TimeLimiterError::Inner)
     }
 
-    fn call(&mut self, req: Req) -> Self::Future {
+    fn call(&mut self, mut req: Req) -> Self::Future {
         let clone = self.inner.clone();
         let mut inner = std::mem::replace(&mut self.inner, clone);
         let config = Arc::clone(&self.config);
 
-        // Extract timeout from request before moving it
-        let timeout_duration = config.timeout_source.get_timeout(&req);
+        // Extract timeout from request before moving it, shrinking it to any
+        // deadline set by an enclosing layer
+        let timeout_duration = Deadline::remaining_or(config.timeout_source.get_timeout(&req));
+        let deadline = Deadline::after(timeout_duration);
         let cancel_on_timeout = config.cancel_running_future;
+        let grace_period = config.grace_period.filter(|_| cancel_on_timeout);
+        let pending_fallback = config.fallback.prepare(&req);
+
+        // Attach a per-call token so the inner service can stop work that
+        // outlives the dropped future (spawned tasks, remote calls).
+        let token = config.propagator.is_enabled().then(|| {
+            let token = match &config.parent_token {
+                Some(parent) => parent.child_token(),
+                None => CancellationToken::new(),
+            };
+            config.propagator.attach(&mut req, token.clone());
+            token
+        });
 
-        Box::pin(async move {
+        let drain_config = Arc::clone(&config);
+        let drain_token = token.clone();
+        // Cancels the token if the caller drops the call before it finishes
+        let guard = token
+            .filter(|_| cancel_on_timeout)
+            .map(CancellationToken::drop_guard);
+
+        #[cfg(feature = "tracing")]
+        let span = tower_resilience_core::span::pattern_span("time_limiter", &config.name);
+
+        let future = async move {
             let start = Instant::now();
 
             // Use Option to represent timeout (None = timed out, Some = got result)
-            let result: Option<Result<S::Response, S::Error>> = if cancel_on_timeout {
-                // Default behavior: timeout cancels the future by dropping it
-                timeout(timeout_duration, inner.call(req)).await.ok()
-            } else {
-                // Non-cancelling behavior: spawn the future and let it continue on timeout
-                let (tx, rx) = tokio::sync::oneshot::channel();
-
-                tokio::spawn(async move {
-                    let result = inner.call(req).await;
-                    // Ignore send error - receiver may have been dropped on timeout
-                    let _ = tx.send(result);
-                });
-
-                tokio::select! {
-                    result = rx => {
-                        // Task completed - unwrap the channel result
-                        result.ok()
+            let wait = async move {
+                if cancel_on_timeout {
+                    let call = deadline.scope(async move { inner.call(req).await });
+                    match grace_period {
+                        // Default behavior: timeout cancels the future by dropping it
+                        None => timeout(timeout_duration, call).await.ok(),
+                        Some(grace_period) => {
+                            let mut call = Box::pin(call);
+                            match timeout(timeout_duration, &mut call).await {
+                                Ok(result) => Some(result),
+                                Err(_) => {
+                                    // Keep driving the future in a detached task
+                                    // for the grace period before dropping it
+                                    tokio::spawn(drain_late(
+                                        call,
+                                        grace_period,
+                                        start,
+                                        timeout_duration,
+                                        drain_config,
+                                        drain_token,
+                                    ));
+                                    None
+                                }
+                            }
+                        }
                     }
-                    _ = tokio::time::sleep(timeout_duration) => {
-                        // Timeout fired, but the spawned task continues running
-                        None
+                } else {
+                    // Non-cancelling behavior: spawn the future and let it continue on timeout
+                    let (tx, rx) = tokio::sync::oneshot::channel();
+
+                    tokio::spawn(deadline.scope(async move {
+                        let result = inner.call(req).await;
+                        // Ignore send error - receiver may have been dropped on timeout
+                        let _ = tx.send(result);
+                    }));
+
+                    tokio::select! {
+                        result = rx => {
+                            // Task completed - unwrap the channel result
+                            result.ok()
+                        }
+                        _ = tokio::time::sleep(timeout_duration) => {
+                            // Timeout fired, but the spawned task continues running
+                            None
+                        }
                     }
                 }
             };
 
+            // A soft timeout only reports slow calls; the call keeps running
+            // until it completes or the hard timeout fires.
+            let result: Option<Result<S::Response, S::Error>> = match config
+                .soft_timeout
+                .filter(|soft| *soft < timeout_duration)
+            {
+                Some(soft_timeout) => {
+                    tokio::pin!(wait);
+                    tokio::select! {
+                        biased;
+                        result = &mut wait => result,
+                        _ = tokio::time::sleep(soft_timeout) => {
+                            config.event_listeners.emit(&TimeLimiterEvent::SoftTimeout {
+                                pattern_name: config.name.clone(),
+                                timestamp: Instant::now(),
+                                soft_timeout,
+                            });
+
+                            #[cfg(feature = "metrics")]
+                            {
+                                counter!("resilience_timelimiter_soft_timeouts_total", "name" => config.name.clone()).increment(1);
+                            }
+
+                            #[cfg(feature = "tracing")]
+                            debug!(
+                                timelimiter = %config.name,
+                                soft_timeout_ms = soft_timeout.as_millis(),
+                                "Call exceeded soft timeout"
+                            );
+
+                            wait.await
+                        }
+                    }
+                }
+                None => wait.await,
+            };
+
+            // Only a timeout that drops the call cancels it; a finished call
+            // or one handed to the grace-period drain keeps its token
+            if let Some(guard) = guard {
+                if result.is_some() || grace_period.is_some() {
+                    guard.disarm();
+                }
+            }
+
             match result {
                 Some(Ok(response)) => {
                     let duration = start.elapsed();
+                    config.timeout_source.record(duration);
                     config.event_listeners.emit(&TimeLimiterEvent::Success {
                         pattern_name: config.name.clone(),
                         timestamp: Instant::now(),
@@ -237,8 +509,8 @@ where
 
                     #[cfg(feature = "metrics")]
                     {
-                        counter!("timelimiter_calls_total", "timelimiter" => config.name.clone(), "result" => "success").increment(1);
-                        histogram!("timelimiter_call_duration_seconds", "timelimiter" => config.name.clone())
+                        counter!("resilience_timelimiter_calls_total", "name" => config.name.clone(), "result" => "success").increment(1);
+                        histogram!("resilience_timelimiter_call_duration_seconds", "name" => config.name.clone())
                             .record(duration.as_secs_f64());
                     }
 
@@ -261,8 +533,8 @@ where
 
                     #[cfg(feature = "metrics")]
                     {
-                        counter!("timelimiter_calls_total", "timelimiter" => config.name.clone(), "result" => "error").increment(1);
-                        histogram!("timelimiter_call_duration_seconds", "timelimiter" => config.name.clone())
+                        counter!("resilience_timelimiter_calls_total", "name" => config.name.clone(), "result" => "error").increment(1);
+                        histogram!("resilience_timelimiter_call_duration_seconds", "name" => config.name.clone())
                             .record(duration.as_secs_f64());
                     }
 
@@ -276,6 +548,8 @@ where
                     Err(TimeLimiterError::Inner(err))
                 }
                 None => {
+                    config.timeout_source.record(timeout_duration);
+
                     config.event_listeners.emit(&TimeLimiterEvent::Timeout {
                         pattern_name: config.name.clone(),
                         timestamp: Instant::now(),
@@ -284,7 +558,7 @@ where
 
                     #[cfg(feature = "metrics")]
                     {
-                        counter!("timelimiter_calls_total", "timelimiter" => config.name.clone(), "result" => "timeout").increment(1);
+                        counter!("resilience_timelimiter_calls_total", "name" => config.name.clone(), "result" => "timeout").increment(1);
                     }
 
                     #[cfg(feature = "tracing")]
@@ -294,10 +568,62 @@ where
                         "Call timed out"
                     );
 
-                    Err(TimeLimiterError::Timeout)
+                    match config.fallback.fallback(pending_fallback) {
+                        Some(fallback) => Ok(fallback.await),
+                        None => Err(TimeLimiterError::Timeout),
+                    }
                 }
             }
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        let future = tower_resilience_core::span::instrument(
+            span,
+            future,
+            tower_resilience_core::span::Outcome::from_result,
+        );
+
+        Box::pin(future)
+    }
+}
+
+/// Drives a timed-out call for up to `grace_period`, reporting how late it
+/// completed.
+async fn drain_late<F, T, C, B>(
+    call: F,
+    grace_period: Duration,
+    start: Instant,
+    timeout_duration: Duration,
+    config: Arc<TimeLimiterConfig<T, C, B>>,
+    token: Option<CancellationToken>,
+) where
+    F: Future,
+{
+    if timeout(grace_period, call).await.is_ok() {
+        let late_by = start.elapsed().saturating_sub(timeout_duration);
+        config
+            .event_listeners
+            .emit(&TimeLimiterEvent::TimedOutLate {
+                pattern_name: config.name.clone(),
+                timestamp: Instant::now(),
+                timeout_duration,
+                late_by,
+            });
+
+        #[cfg(feature = "metrics")]
+        {
+            counter!("resilience_timelimiter_late_completions_total", "name" => config.name.clone())
+                .increment(1);
+        }
+
+        #[cfg(feature = "tracing")]
+        debug!(
+            timelimiter = %config.name,
+            late_by_ms = late_by.as_millis(),
+            "Timed-out call completed during grace period"
+        );
+    } else if let Some(token) = token {
+        token.cancel();
     }
 }
 
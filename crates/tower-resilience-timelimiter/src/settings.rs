@@ -0,0 +1,101 @@
+//! Time limiter settings that can be loaded from configuration files.
+
+use crate::config::{FixedTimeout, TimeLimiterConfigBuilder};
+use crate::layer::TimeLimiterLayer;
+use std::time::Duration;
+
+/// Plain-data time limiter settings, deserializable with the `serde` feature.
+///
+/// Every field is optional; unset fields keep the builder defaults. Durations
+/// accept milliseconds or strings such as `"2s"` (see
+/// [`parse_duration`](tower_resilience_core::settings::parse_duration)).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tower_resilience_timelimiter::{TimeLimiterLayer, TimeLimiterSettings};
+///
+/// let settings = TimeLimiterSettings {
+///     timeout_duration: Some(Duration::from_secs(2)),
+///     ..Default::default()
+/// };
+///
+/// let layer = TimeLimiterLayer::from_config(settings);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct TimeLimiterSettings {
+    /// Instance name used in events and metrics.
+    pub name: Option<String>,
+    /// Fixed timeout applied to every call.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub timeout_duration: Option<Duration>,
+    /// Soft timeout that reports slow calls without cancelling them.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub soft_timeout: Option<Duration>,
+    /// Whether to drop the inner future when the timeout fires.
+    pub cancel_running_future: Option<bool>,
+    /// How long a timed-out call may keep running to drain.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "tower_resilience_core::settings::optional_duration")
+    )]
+    pub grace_period: Option<Duration>,
+}
+
+impl<C, B> TimeLimiterConfigBuilder<FixedTimeout, C, B> {
+    /// Applies every field that is set in `settings`, leaving the rest unchanged.
+    pub fn settings(mut self, settings: TimeLimiterSettings) -> Self {
+        if let Some(name) = settings.name {
+            self = self.name(name);
+        }
+        if let Some(duration) = settings.timeout_duration {
+            self = self.timeout_duration(duration);
+        }
+        if let Some(duration) = settings.soft_timeout {
+            self = self.soft_timeout(duration);
+        }
+        if let Some(cancel) = settings.cancel_running_future {
+            self = self.cancel_running_future(cancel);
+        }
+        if let Some(duration) = settings.grace_period {
+            self = self.grace_period(duration);
+        }
+        self
+    }
+}
+
+impl TimeLimiterLayer<FixedTimeout> {
+    /// Creates a time limiter layer from [`TimeLimiterSettings`].
+    pub fn from_config(settings: TimeLimiterSettings) -> Self {
+        Self::builder().settings(settings).build()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_settings() {
+        let settings: TimeLimiterSettings = serde_json::from_str(
+            r#"{"timeout_duration": "2s", "soft_timeout": 500, "cancel_running_future": false}"#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.timeout_duration, Some(Duration::from_secs(2)));
+        assert_eq!(settings.soft_timeout, Some(Duration::from_millis(500)));
+        assert_eq!(settings.cancel_running_future, Some(false));
+        assert_eq!(settings.grace_period, None);
+
+        let _layer = TimeLimiterLayer::from_config(settings);
+    }
+}
@@ -0,0 +1,482 @@
+//! HTTP admin endpoints for running resilience layers.
+//!
+//! Operators often need to look at a circuit breaker during an incident, trip
+//! it by hand, or raise a rate limit without a deploy. [`AdminRegistry`] holds
+//! named handles to running layers and serves them as an axum [`Router`]:
+//!
+//! | Method | Path | Action |
+//! |--------|------|--------|
+//! | `GET`  | `/resilience` | Names of every registered instance |
+//! | `GET`  | `/resilience/circuitbreakers` | State and metrics of every breaker |
+//! | `GET`  | `/resilience/circuitbreakers/{name}` | State and metrics of one breaker |
+//! | `POST` | `/resilience/circuitbreakers/{name}/force_open` | Open the circuit |
+//! | `POST` | `/resilience/circuitbreakers/{name}/reset` | Close the circuit and clear counts |
+//! | `GET`  | `/resilience/ratelimiters[/{name}]` | Limit, available permits, utilization |
+//! | `POST` | `/resilience/ratelimiters/{name}/set_limit` | Change the limit per period |
+//! | `GET`  | `/resilience/bulkheads[/{name}]` | Limit, active calls, utilization |
+//! | `POST` | `/resilience/bulkheads/{name}/set_limit` | Change the concurrency limit |
+//!
+//! `set_limit` takes a JSON body such as `{"limit": 200}`. Unknown names
+//! return `404 Not Found`; every successful request returns the current
+//! snapshot of the instance as JSON.
+//!
+//! Each pattern's routes are only present when its feature is enabled. The
+//! router has no authentication of its own; mount it on an internal port or
+//! behind your own auth middleware.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "circuitbreaker", feature = "ratelimiter"))]
+//! # {
+//! use tower_resilience::admin::AdminRegistry;
+//! use tower_resilience::circuitbreaker::CircuitBreakerLayer;
+//! use tower_resilience::ratelimiter::RateLimiterLayer;
+//!
+//! let (breaker, breaker_handle) = CircuitBreakerLayer::builder().build_with_handle();
+//! let (limiter, limiter_handle) = RateLimiterLayer::builder()
+//!     .limit_for_period(100)
+//!     .build_with_handle();
+//!
+//! let admin = AdminRegistry::new();
+//! admin.register_circuit_breaker("payments", breaker_handle);
+//! admin.register_rate_limiter("payments", limiter_handle);
+//!
+//! let app: axum::Router = axum::Router::new().merge(admin.router());
+//! # }
+//! ```
+
+use std::sync::{Arc, RwLock};
+
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde_json::Value;
+
+#[cfg(any(
+    feature = "circuitbreaker",
+    feature = "ratelimiter",
+    feature = "bulkhead"
+))]
+use {
+    axum::extract::Path,
+    axum::http::StatusCode,
+    axum::response::{IntoResponse, Response},
+    axum::routing::post,
+    serde_json::json,
+    std::collections::BTreeMap,
+};
+
+#[cfg(feature = "bulkhead")]
+use crate::bulkhead::BulkheadHandle;
+#[cfg(feature = "circuitbreaker")]
+use crate::circuitbreaker::{CircuitBreakerHandle, CircuitMetrics, CircuitState};
+#[cfg(feature = "ratelimiter")]
+use crate::ratelimiter::RateLimiterHandle;
+
+/// Named handles to running resilience layers, served over HTTP.
+///
+/// The registry is cheap to clone; clones share the same entries, so
+/// instances registered after [`router`](Self::router) is called are still
+/// visible through it. Registering a name twice replaces the earlier handle.
+#[derive(Clone, Default)]
+pub struct AdminRegistry {
+    inner: Arc<RwLock<Entries>>,
+}
+
+#[derive(Default)]
+struct Entries {
+    #[cfg(feature = "circuitbreaker")]
+    circuit_breakers: BTreeMap<String, Arc<dyn CircuitBreakerControl>>,
+    #[cfg(feature = "ratelimiter")]
+    rate_limiters: BTreeMap<String, RateLimiterHandle>,
+    #[cfg(feature = "bulkhead")]
+    bulkheads: BTreeMap<String, BulkheadHandle>,
+}
+
+impl AdminRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a circuit breaker under `name`.
+    #[cfg(feature = "circuitbreaker")]
+    pub fn register_circuit_breaker<C>(
+        &self,
+        name: impl Into<String>,
+        handle: CircuitBreakerHandle<C>,
+    ) where
+        C: Send + Sync + 'static,
+    {
+        self.inner
+            .write()
+            .unwrap()
+            .circuit_breakers
+            .insert(name.into(), Arc::new(handle));
+    }
+
+    /// Registers a rate limiter under `name`.
+    #[cfg(feature = "ratelimiter")]
+    pub fn register_rate_limiter(&self, name: impl Into<String>, handle: RateLimiterHandle) {
+        self.inner
+            .write()
+            .unwrap()
+            .rate_limiters
+            .insert(name.into(), handle);
+    }
+
+    /// Registers a bulkhead under `name`.
+    #[cfg(feature = "bulkhead")]
+    pub fn register_bulkhead(&self, name: impl Into<String>, handle: BulkheadHandle) {
+        self.inner
+            .write()
+            .unwrap()
+            .bulkheads
+            .insert(name.into(), handle);
+    }
+
+    /// Returns a router serving the admin endpoints for this registry.
+    pub fn router<S>(&self) -> Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let router = Router::new().route("/resilience", get(index));
+
+        #[cfg(feature = "circuitbreaker")]
+        let router = router
+            .route("/resilience/circuitbreakers", get(list_circuit_breakers))
+            .route(
+                "/resilience/circuitbreakers/{name}",
+                get(get_circuit_breaker),
+            )
+            .route(
+                "/resilience/circuitbreakers/{name}/force_open",
+                post(force_open_circuit_breaker),
+            )
+            .route(
+                "/resilience/circuitbreakers/{name}/reset",
+                post(reset_circuit_breaker),
+            );
+
+        #[cfg(feature = "ratelimiter")]
+        let router = router
+            .route("/resilience/ratelimiters", get(list_rate_limiters))
+            .route("/resilience/ratelimiters/{name}", get(get_rate_limiter))
+            .route(
+                "/resilience/ratelimiters/{name}/set_limit",
+                post(set_rate_limit),
+            );
+
+        #[cfg(feature = "bulkhead")]
+        let router = router
+            .route("/resilience/bulkheads", get(list_bulkheads))
+            .route("/resilience/bulkheads/{name}", get(get_bulkhead))
+            .route(
+                "/resilience/bulkheads/{name}/set_limit",
+                post(set_bulkhead_limit),
+            );
+
+        router.with_state(self.clone())
+    }
+}
+
+async fn index(State(registry): State<AdminRegistry>) -> Json<Value> {
+    let _entries = registry.inner.read().unwrap();
+    #[allow(unused_mut)]
+    let mut body = serde_json::Map::new();
+    #[cfg(feature = "circuitbreaker")]
+    body.insert(
+        "circuitbreakers".into(),
+        json!(_entries.circuit_breakers.keys().collect::<Vec<_>>()),
+    );
+    #[cfg(feature = "ratelimiter")]
+    body.insert(
+        "ratelimiters".into(),
+        json!(_entries.rate_limiters.keys().collect::<Vec<_>>()),
+    );
+    #[cfg(feature = "bulkhead")]
+    body.insert(
+        "bulkheads".into(),
+        json!(_entries.bulkheads.keys().collect::<Vec<_>>()),
+    );
+    Json(Value::Object(body))
+}
+
+/// Errors returned by the admin endpoints.
+#[cfg(any(
+    feature = "circuitbreaker",
+    feature = "ratelimiter",
+    feature = "bulkhead"
+))]
+enum AdminError {
+    /// No instance of this kind is registered under the name.
+    NotFound { kind: &'static str, name: String },
+    /// A `set_limit` body without a non-negative integer `limit`.
+    #[cfg(any(feature = "ratelimiter", feature = "bulkhead"))]
+    InvalidLimit,
+}
+
+#[cfg(any(
+    feature = "circuitbreaker",
+    feature = "ratelimiter",
+    feature = "bulkhead"
+))]
+impl IntoResponse for AdminError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            AdminError::NotFound { kind, name } => {
+                (StatusCode::NOT_FOUND, format!("no {kind} named '{name}'"))
+            }
+            #[cfg(any(feature = "ratelimiter", feature = "bulkhead"))]
+            AdminError::InvalidLimit => (
+                StatusCode::BAD_REQUEST,
+                "expected a body like {\"limit\": 100}".to_string(),
+            ),
+        };
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
+
+/// Reads the `limit` field of a `set_limit` request body.
+#[cfg(any(feature = "ratelimiter", feature = "bulkhead"))]
+fn parse_limit(body: &Value) -> Result<usize, AdminError> {
+    body.get("limit")
+        .and_then(Value::as_u64)
+        .map(|limit| limit as usize)
+        .ok_or(AdminError::InvalidLimit)
+}
+
+// Circuit breakers
+
+/// Object-safe view of a `CircuitBreakerHandle<C>`, so breakers with
+/// different classifiers can share one map.
+#[cfg(feature = "circuitbreaker")]
+trait CircuitBreakerControl: Send + Sync {
+    fn metrics(&self) -> BoxFuture<'_, CircuitMetrics>;
+    fn force_open(&self) -> BoxFuture<'_, ()>;
+    fn reset(&self) -> BoxFuture<'_, ()>;
+}
+
+#[cfg(feature = "circuitbreaker")]
+type BoxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
+
+#[cfg(feature = "circuitbreaker")]
+impl<C: Send + Sync> CircuitBreakerControl for CircuitBreakerHandle<C> {
+    fn metrics(&self) -> BoxFuture<'_, CircuitMetrics> {
+        Box::pin(CircuitBreakerHandle::metrics(self))
+    }
+
+    fn force_open(&self) -> BoxFuture<'_, ()> {
+        Box::pin(CircuitBreakerHandle::force_open(self))
+    }
+
+    fn reset(&self) -> BoxFuture<'_, ()> {
+        Box::pin(CircuitBreakerHandle::reset(self))
+    }
+}
+
+#[cfg(feature = "circuitbreaker")]
+fn circuit_state_name(state: CircuitState) -> &'static str {
+    match state {
+        CircuitState::Closed => "closed",
+        CircuitState::Open => "open",
+        CircuitState::HalfOpen => "half_open",
+    }
+}
+
+#[cfg(feature = "circuitbreaker")]
+async fn circuit_breaker_json(breaker: &dyn CircuitBreakerControl) -> Value {
+    let metrics = breaker.metrics().await;
+    json!({
+        "state": circuit_state_name(metrics.state),
+        "total_calls": metrics.total_calls,
+        "failure_count": metrics.failure_count,
+        "success_count": metrics.success_count,
+        "slow_call_count": metrics.slow_call_count,
+        "failure_rate": metrics.failure_rate,
+        "slow_call_rate": metrics.slow_call_rate,
+        "time_since_state_change_ms": metrics.time_since_state_change.as_millis() as u64,
+    })
+}
+
+#[cfg(feature = "circuitbreaker")]
+fn circuit_breaker(
+    registry: &AdminRegistry,
+    name: &str,
+) -> Result<Arc<dyn CircuitBreakerControl>, AdminError> {
+    registry
+        .inner
+        .read()
+        .unwrap()
+        .circuit_breakers
+        .get(name)
+        .cloned()
+        .ok_or_else(|| AdminError::NotFound {
+            kind: "circuit breaker",
+            name: name.to_string(),
+        })
+}
+
+#[cfg(feature = "circuitbreaker")]
+async fn list_circuit_breakers(State(registry): State<AdminRegistry>) -> Json<Value> {
+    // Clone the handles out so the lock isn't held across awaits
+    let breakers: Vec<_> = registry
+        .inner
+        .read()
+        .unwrap()
+        .circuit_breakers
+        .iter()
+        .map(|(name, breaker)| (name.clone(), Arc::clone(breaker)))
+        .collect();
+
+    let mut body = serde_json::Map::new();
+    for (name, breaker) in breakers {
+        body.insert(name, circuit_breaker_json(breaker.as_ref()).await);
+    }
+    Json(Value::Object(body))
+}
+
+#[cfg(feature = "circuitbreaker")]
+async fn get_circuit_breaker(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    let breaker = circuit_breaker(&registry, &name)?;
+    Ok(Json(circuit_breaker_json(breaker.as_ref()).await))
+}
+
+#[cfg(feature = "circuitbreaker")]
+async fn force_open_circuit_breaker(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    let breaker = circuit_breaker(&registry, &name)?;
+    breaker.force_open().await;
+    Ok(Json(circuit_breaker_json(breaker.as_ref()).await))
+}
+
+#[cfg(feature = "circuitbreaker")]
+async fn reset_circuit_breaker(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    let breaker = circuit_breaker(&registry, &name)?;
+    breaker.reset().await;
+    Ok(Json(circuit_breaker_json(breaker.as_ref()).await))
+}
+
+// Rate limiters
+
+#[cfg(feature = "ratelimiter")]
+fn rate_limiter_json(limiter: &RateLimiterHandle) -> Value {
+    json!({
+        "limit_for_period": limiter.limit_for_period(),
+        "available_permits": limiter.available_permits(),
+        "utilization": limiter.utilization(),
+    })
+}
+
+#[cfg(feature = "ratelimiter")]
+fn rate_limiter(registry: &AdminRegistry, name: &str) -> Result<RateLimiterHandle, AdminError> {
+    registry
+        .inner
+        .read()
+        .unwrap()
+        .rate_limiters
+        .get(name)
+        .cloned()
+        .ok_or_else(|| AdminError::NotFound {
+            kind: "rate limiter",
+            name: name.to_string(),
+        })
+}
+
+#[cfg(feature = "ratelimiter")]
+async fn list_rate_limiters(State(registry): State<AdminRegistry>) -> Json<Value> {
+    let entries = registry.inner.read().unwrap();
+    let body = entries
+        .rate_limiters
+        .iter()
+        .map(|(name, limiter)| (name.clone(), rate_limiter_json(limiter)))
+        .collect();
+    Json(Value::Object(body))
+}
+
+#[cfg(feature = "ratelimiter")]
+async fn get_rate_limiter(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    let limiter = rate_limiter(&registry, &name)?;
+    Ok(Json(rate_limiter_json(&limiter)))
+}
+
+#[cfg(feature = "ratelimiter")]
+async fn set_rate_limit(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, AdminError> {
+    let limiter = rate_limiter(&registry, &name)?;
+    limiter.set_limit_for_period(parse_limit(&body)?);
+    Ok(Json(rate_limiter_json(&limiter)))
+}
+
+// Bulkheads
+
+#[cfg(feature = "bulkhead")]
+fn bulkhead_json(bulkhead: &BulkheadHandle) -> Value {
+    json!({
+        "max_concurrent_calls": bulkhead.max_concurrent(),
+        "active_calls": bulkhead.active_calls(),
+        "available_permits": bulkhead.available_permits(),
+        "utilization": bulkhead.utilization(),
+    })
+}
+
+#[cfg(feature = "bulkhead")]
+fn bulkhead(registry: &AdminRegistry, name: &str) -> Result<BulkheadHandle, AdminError> {
+    registry
+        .inner
+        .read()
+        .unwrap()
+        .bulkheads
+        .get(name)
+        .cloned()
+        .ok_or_else(|| AdminError::NotFound {
+            kind: "bulkhead",
+            name: name.to_string(),
+        })
+}
+
+#[cfg(feature = "bulkhead")]
+async fn list_bulkheads(State(registry): State<AdminRegistry>) -> Json<Value> {
+    let entries = registry.inner.read().unwrap();
+    let body = entries
+        .bulkheads
+        .iter()
+        .map(|(name, bulkhead)| (name.clone(), bulkhead_json(bulkhead)))
+        .collect();
+    Json(Value::Object(body))
+}
+
+#[cfg(feature = "bulkhead")]
+async fn get_bulkhead(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    let bulkhead = bulkhead(&registry, &name)?;
+    Ok(Json(bulkhead_json(&bulkhead)))
+}
+
+#[cfg(feature = "bulkhead")]
+async fn set_bulkhead_limit(
+    State(registry): State<AdminRegistry>,
+    Path(name): Path<String>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, AdminError> {
+    let bulkhead = bulkhead(&registry, &name)?;
+    bulkhead.set_max_concurrent(parse_limit(&body)?);
+    Ok(Json(bulkhead_json(&bulkhead)))
+}
@@ -494,6 +494,12 @@ pub mod ordering {
     //! | 8 | Hedge | Fire parallel requests | Hedges not bounded by per-call timeout |
     //! | 9 | Service | The actual service | - |
     //!
+    //! [`ResilienceStack`](crate::ResilienceStack) applies the circuit breaker,
+    //! retry, bulkhead, time limiter and rate limiter in this order for you,
+    //! with the breaker outside retry as recommended below.
+    //! [`OrderedLayers`](crate::OrderedLayers) lets you pick the layers while
+    //! still rejecting, at compile time, orders that break these rules.
+    //!
     //! ## Client-Side (Outbound) - Correct Order
     //!
     //! In `ServiceBuilder`, add layers from innermost to outermost:
@@ -69,6 +69,7 @@
 //! | **Hedge** | [`conservative()`][h_conservative], [`standard()`][h_standard], [`aggressive()`][h_aggressive] |
 //! | **Rate Limiter** | [`per_second(n)`], [`per_minute(n)`], [`burst(rate, size)`] |
 //! | **Retry** | [`exponential_backoff()`], [`aggressive()`], [`conservative()`] |
+//! | **Resilience Stack** | [`http_client_defaults()`], [`database_defaults()`], [`message_consumer_defaults()`] |
 //! | **Time Limiter** | [`fast()`], [`standard()`][tl_standard], [`slow()`], [`streaming()`] |
 //!
 //! Presets return builders, so you can customize any setting:
@@ -101,6 +102,9 @@
 //! [`exponential_backoff()`]: retry::RetryLayer::exponential_backoff
 //! [`aggressive()`]: retry::RetryLayer::aggressive
 //! [`conservative()`]: retry::RetryLayer::conservative
+//! [`http_client_defaults()`]: ResilienceStack::http_client_defaults
+//! [`database_defaults()`]: ResilienceStack::database_defaults
+//! [`message_consumer_defaults()`]: ResilienceStack::message_consumer_defaults
 //! [`fast()`]: timelimiter::TimeLimiterLayer::fast
 //! [tl_standard]: timelimiter::TimeLimiterLayer::standard
 //! [`slow()`]: timelimiter::TimeLimiterLayer::slow
@@ -140,6 +144,105 @@
 //! [Router]: https://docs.rs/tower-resilience-router
 //! [Time Limiter]: https://docs.rs/tower-resilience-timelimiter
 //!
+//! The `http` feature adds [HTTP helpers]: status-based failure classifiers,
+//! `Retry-After` handling, cache keys and idempotency checks for
+//! `http::Request`/`Response` services. The `grpc` feature extends them to
+//! tonic clients.
+//!
+//! [HTTP helpers]: https://docs.rs/tower-resilience-http
+//!
+//! The `admin` feature adds an axum router, built by `admin::AdminRegistry`,
+//! that reports the state of registered circuit breakers, rate limiters and
+//! bulkheads and lets operators trip, reset or resize them at runtime.
+//!
+//! # Composing a Stack
+//!
+//! [`ResilienceStack`] builds one layer from the patterns you pick and applies
+//! them in the [recommended order](composition::ordering), whatever order the
+//! builder calls come in:
+//!
+//! ```rust,no_run
+//! # #[cfg(all(feature = "circuitbreaker", feature = "retry", feature = "timelimiter"))]
+//! # {
+//! use tower::ServiceBuilder;
+//! use tower_resilience::circuitbreaker::CircuitBreakerLayer;
+//! use tower_resilience::retry::RetryLayer;
+//! use tower_resilience::timelimiter::{TimeLimiterError, TimeLimiterLayer};
+//! use tower_resilience::ResilienceStack;
+//!
+//! # let my_service = tower::service_fn(|_req: ()| async { Ok::<_, std::io::Error>(()) });
+//! // Circuit breaker → retry → per-attempt timeout → service
+//! let stack = ResilienceStack::builder()
+//!     .with_time_limiter(TimeLimiterLayer::standard().build())
+//!     // Retry sees the time limiter's error wrapped around the service's
+//!     .with_retry(RetryLayer::<(), (), TimeLimiterError<std::io::Error>>::exponential_backoff().build())
+//!     .with_circuit_breaker(CircuitBreakerLayer::standard().build())
+//!     .build();
+//!
+//! let service = ServiceBuilder::new().layer(stack).service(my_service);
+//! # }
+//! ```
+//!
+//! The workload presets in the table above start from a complete stack, for
+//! example `ResilienceStack::http_client_defaults().build()`.
+//!
+//! # Configuration from Files
+//!
+//! Circuit breaker, retry, bulkhead, rate limiter and time limiter each have a
+//! plain-data settings struct (`CircuitBreakerSettings`, `RetrySettings`,
+//! `BulkheadSettings`, `RateLimiterSettings`, `TimeLimiterSettings`) and a
+//! `from_config` constructor on the layer. With the `serde` feature the
+//! settings deserialize from any serde format, so policies can live in YAML or
+//! TOML and be loaded at startup. Durations accept milliseconds or strings such
+//! as `"30s"`:
+//!
+//! ```rust,ignore
+//! use tower_resilience::circuitbreaker::{CircuitBreakerLayer, CircuitBreakerSettings};
+//! use tower_resilience::retry::{RetryLayer, RetrySettings};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Resilience {
+//!     payments: CircuitBreakerSettings,
+//!     payments_retry: RetrySettings,
+//! }
+//!
+//! // payments:
+//! //   failure_rate_threshold: 0.5
+//! //   wait_duration_in_open: 30s
+//! // payments_retry:
+//! //   max_attempts: 3
+//! //   backoff: { type: exponential, initial_interval: 100ms }
+//! let config: Resilience = serde_yaml::from_str(&std::fs::read_to_string("resilience.yaml")?)?;
+//!
+//! let breaker = CircuitBreakerLayer::from_config(config.payments);
+//! let retry = RetryLayer::<Request, Response, Error>::from_config(config.payments_retry);
+//! ```
+//!
+//! Settings that are code rather than data, such as failure classifiers and
+//! event listeners, can be added by applying the settings to a builder with
+//! `builder().settings(settings)` instead.
+//!
+//! ## Reloading at Runtime
+//!
+//! Layers built with `build_with_handle()` can pick up new settings without
+//! being rebuilt. A [`ConfigWatcher`](core::ConfigWatcher) holds the
+//! application's settings type and pushes each section to its registered
+//! handle whenever a new value arrives, either from
+//! [`apply`](core::ConfigWatcher::apply) or by polling a file with
+//! [`watch_file`](core::ConfigWatcher::watch_file). Circuit breaker thresholds
+//! and bulkhead limits are applied in place, and every reload emits a
+//! `ConfigReloaded` event:
+//!
+//! ```rust,ignore
+//! use tower_resilience::core::ConfigWatcher;
+//!
+//! let (breaker, breaker_handle) = CircuitBreakerLayer::builder().build_with_handle();
+//!
+//! let watcher = ConfigWatcher::new("resilience");
+//! watcher.register(breaker_handle, |config: &Resilience| Some(config.payments.clone()));
+//! watcher.watch_file("resilience.yaml", Duration::from_secs(5), serde_yaml::from_str);
+//! ```
+//!
 //! # Documentation Guides
 //!
 //! ## Getting Started
@@ -304,17 +407,65 @@
 //! ```
 //!
 //! For complete documentation, see [`core::ResilienceError`].
+//!
+//! ## Nested and Boxed Errors
+//!
+//! Every pattern error implements [`PatternError`], so nested types such as
+//! `CircuitBreakerError<TimeLimiterError<E>>` can be classified level by level
+//! with `is_rejection()`, `is_timeout()`, `retry_after()` and `source_inner()`.
+//! Stacks built on boxed errors can [`flatten`] each layer into a single
+//! [`Error`] and recover the classification with [`find_pattern_failure`]:
+//!
+//! ```rust
+//! # #[cfg(feature = "circuitbreaker")]
+//! # {
+//! use tower_resilience::circuitbreaker::CircuitBreakerError;
+//! use tower_resilience::{find_pattern_failure, flatten, Error, PatternError};
+//!
+//! let error: CircuitBreakerError<Error> = CircuitBreakerError::OpenCircuit;
+//! let error: Error = flatten(error);
+//!
+//! let failure = find_pattern_failure(&*error).unwrap();
+//! assert!(failure.is_rejection());
+//! # }
+//! ```
 
 // Documentation modules
+#[cfg(feature = "admin")]
+pub mod admin;
 pub mod composition;
 pub mod observability;
 pub mod patterns;
 pub mod tower_primer;
 pub mod use_cases;
 
+mod ordered;
+mod stack;
+pub use ordered::{OrderedLayers, Pattern, Placement};
+pub use stack::{ResilienceStack, ResilienceStackBuilder};
+
 // Re-export core (always available)
 pub use tower_resilience_core as core;
 
+/// Process-wide event subscription across every pattern.
+///
+/// [`subscribe`](events::subscribe) registers a single callback that receives
+/// events from every layer in the process, without wiring listeners into each
+/// builder:
+///
+/// ```rust
+/// use tower_resilience::events::{subscribe, ResilienceEvent};
+///
+/// subscribe(|event: &dyn ResilienceEvent| {
+///     println!("[{}] {}", event.pattern_name(), event.event_type());
+/// });
+/// ```
+pub mod events {
+    pub use tower_resilience_core::events::{
+        subscribe, GlobalSubscriber, ResilienceEvent, Subscription,
+    };
+}
+
 // Re-export patterns based on features (alphabetical)
 #[cfg(feature = "adaptive")]
 pub use tower_resilience_adaptive as adaptive;
@@ -346,6 +497,9 @@ pub use tower_resilience_hedge as hedge;
 #[cfg(feature = "healthcheck")]
 pub use tower_resilience_healthcheck as healthcheck;
 
+#[cfg(feature = "http")]
+pub use tower_resilience_http as http;
+
 #[cfg(feature = "outlier")]
 pub use tower_resilience_outlier as outlier;
 
@@ -364,6 +518,12 @@ pub use tower_resilience_router as router;
 #[cfg(feature = "timelimiter")]
 pub use tower_resilience_timelimiter as timelimiter;
 
+// Re-export error classification helpers
+pub use tower_resilience_core::{find_pattern_failure, flatten, PatternError, PatternFailure};
+
+/// A boxed error for `BoxError`-based stacks, produced by [`flatten`].
+pub type Error = tower_resilience_core::BoxError;
+
 // Re-export unified error layer types
 #[cfg(feature = "layer")]
 pub use tower_resilience_core::{
@@ -13,9 +13,32 @@ pub mod metrics {
     //! [dependencies]
     //! tower-resilience = { version = "0.3", features = ["circuitbreaker", "metrics"] }
     //! metrics = "0.24"
-    //! metrics-exporter-prometheus = "0.16"
+    //! metrics-exporter-prometheus = "0.17"
     //! ```
     //!
+    //! With the `prometheus` feature, [`install_prometheus`](super::install_prometheus)
+    //! installs the exporter with histogram buckets suited to these metrics:
+    //!
+    //! ```rust,ignore
+    //! use metrics_exporter_prometheus::PrometheusBuilder;
+    //!
+    //! let handle = tower_resilience::observability::install_prometheus(PrometheusBuilder::new())?;
+    //!
+    //! // Serve `handle.render()` from your /metrics endpoint
+    //! ```
+    //!
+    //! ## Naming Scheme
+    //!
+    //! Every metric is named `resilience_<pattern>_<measurement>` and carries a
+    //! `name` label holding the instance name, so one query shape works for
+    //! every pattern:
+    //!
+    //! ```promql
+    //! sum by (name) (rate(resilience_bulkhead_calls_rejected_total[5m]))
+    //! ```
+    //!
+    //! Counters end in `_total` and histograms of durations end in `_seconds`.
+    //!
     //! ## Instance Naming
     //!
     //! **Always name your instances** to distinguish metrics from multiple patterns:
@@ -33,77 +56,138 @@ pub mod metrics {
     //!
     //! Metrics will include the instance name as a label:
     //! ```text
-    //! circuitbreaker_calls_total{circuitbreaker="user-service",outcome="success"} 150
-    //! circuitbreaker_calls_total{circuitbreaker="payment-service",outcome="success"} 89
+    //! resilience_circuitbreaker_calls_total{name="user-service",outcome="success"} 150
+    //! resilience_circuitbreaker_calls_total{name="payment-service",outcome="success"} 89
     //! ```
     //!
     //! ## Available Metrics by Pattern
     //!
     //! ### Circuit Breaker
     //!
-    //! - `circuitbreaker_calls_total{circuitbreaker, outcome}` - Total calls (success/failure/rejected)
-    //! - `circuitbreaker_transitions_total{circuitbreaker, from, to}` - State transitions
-    //! - `circuitbreaker_state{circuitbreaker, state}` - Current state gauge
-    //! - `circuitbreaker_slow_calls_total{circuitbreaker}` - Slow call detections
-    //! - `circuitbreaker_call_duration_seconds{circuitbreaker}` - Call duration histogram
+    //! - `resilience_circuitbreaker_calls_total{name, outcome}` - Total calls (success/failure/rejected/ignored)
+    //! - `resilience_circuitbreaker_transitions_total{name, from, to}` - State transitions
+    //! - `resilience_circuitbreaker_state{name, state}` - Current state gauge
+    //! - `resilience_circuitbreaker_slow_calls_total{name}` - Slow call detections
+    //! - `resilience_circuitbreaker_failures_total{name, category}` - Failures by error category (with `error_category`)
+    //! - `resilience_circuitbreaker_call_duration_seconds{name}` - Call duration histogram
     //!
     //! ### Bulkhead
     //!
-    //! - `bulkhead_calls_permitted_total{bulkhead}` - Calls that acquired permits
-    //! - `bulkhead_calls_rejected_total{bulkhead}` - Calls rejected (no permits)
-    //! - `bulkhead_calls_finished_total{bulkhead}` - Successfully completed calls
-    //! - `bulkhead_calls_failed_total{bulkhead}` - Failed calls
-    //! - `bulkhead_concurrent_calls{bulkhead}` - Current concurrency gauge
-    //! - `bulkhead_wait_duration_seconds{bulkhead}` - Wait time histogram
-    //! - `bulkhead_call_duration_seconds{bulkhead}` - Call duration histogram
+    //! - `resilience_bulkhead_calls_permitted_total{name}` - Calls that acquired permits
+    //! - `resilience_bulkhead_calls_rejected_total{name}` - Calls rejected (no permits)
+    //! - `resilience_bulkhead_calls_finished_total{name}` - Successfully completed calls
+    //! - `resilience_bulkhead_calls_failed_total{name}` - Failed calls
+    //! - `resilience_bulkhead_concurrent_calls{name}` - Current concurrency gauge
+    //! - `resilience_bulkhead_wait_duration_seconds{name}` - Wait time histogram
+    //! - `resilience_bulkhead_call_duration_seconds{name}` - Call duration histogram
+    //! - `resilience_bulkhead_permit_held_too_long_total{name}` - Calls that held a permit past `max_permit_hold`
+    //! - `resilience_bulkhead_waiters_starved_total{name}` - Calls that waited for a permit past `starvation_threshold`
     //!
     //! ### Retry
     //!
-    //! - `retry_calls_total{retry, result}` - Total retry operations (success/exhausted)
-    //! - `retry_attempts_total{retry}` - Individual retry attempts
-    //! - `retry_attempts{retry}` - Attempts per call histogram
+    //! - `resilience_retry_calls_total{name, result}` - Total retry operations (success/exhausted)
+    //! - `resilience_retry_attempts_total{name}` - Individual retry attempts
+    //! - `resilience_retry_attempts{name}` - Attempts per call histogram
+    //! - `resilience_retry_scheduled_total{name}` - Retries handed to a retry scheduler
     //!
     //! ### Rate Limiter
     //!
-    //! - `ratelimiter_calls_total{ratelimiter, result}` - Calls (permitted/rejected)
-    //! - `ratelimiter_wait_duration_seconds{ratelimiter}` - Permit wait time histogram
+    //! - `resilience_ratelimiter_calls_total{name, result}` - Calls (permitted/rejected)
+    //! - `resilience_ratelimiter_wait_duration_seconds{name}` - Permit wait time histogram
     //!
     //! ### Time Limiter
     //!
-    //! - `timelimiter_calls_total{timelimiter, result}` - Calls (success/error/timeout)
-    //! - `timelimiter_call_duration_seconds{timelimiter}` - Call duration histogram
+    //! - `resilience_timelimiter_calls_total{name, result}` - Calls (success/error/timeout)
+    //! - `resilience_timelimiter_call_duration_seconds{name}` - Call duration histogram
+    //! - `resilience_timelimiter_soft_timeouts_total{name}` - Calls that exceeded the soft timeout
+    //! - `resilience_timelimiter_late_completions_total{name}` - Timed-out calls that completed during the grace period
+    //!
+    //! ### Hedge
+    //!
+    //! - `resilience_hedge_attempts_total{name, kind}` - Attempts dispatched (primary/hedge)
+    //! - `resilience_hedge_wins_total{name, winner}` - Winning attempt (primary/hedge)
+    //! - `resilience_hedge_suppressed_total{name, reason}` - Hedges not fired (budget/max_outstanding/not_ready)
+    //! - `resilience_hedge_latency_seconds{name, result}` - Call duration histogram (success/failure)
+    //!
+    //! ### Chaos
+    //!
+    //! - `resilience_chaos_errors_injected_total{name}` - Injected errors
+    //! - `resilience_chaos_latency_injected_seconds{name}` - Injected latency histogram
+    //! - `resilience_chaos_passthrough_total{name}` - Requests passed through without injected faults
+    //! - `resilience_chaos_responses_corrupted_total{name}` - Corrupted responses
+    //! - `resilience_chaos_blackholed_total{name}` - Blackholed requests
+    //! - `resilience_chaos_pressure_injected_total{name}` - Requests with CPU/memory pressure applied (`chaos-pressure` feature)
+    //!
+    //! ### Coalesce
+    //!
+    //! - `resilience_coalesce_requests_total{name, role}` - Requests by role (leader/waiter/shared/rejected/bypassed)
+    //! - `resilience_coalesce_followers_total{name}` - Deduplicated requests
+    //! - `resilience_coalesce_dedup_ratio{name}` - Fraction of requests deduplicated gauge
+    //!
+    //! ### Adaptive
+    //!
+    //! - `resilience_adaptive_limit{name}` - Current concurrency limit gauge
+    //! - `resilience_adaptive_calls_total{name, result}` - Calls (permitted/rejected)
+    //!
+    //! ### Reconnect
+    //!
+    //! - `resilience_reconnect_attempts_total{name}` - Reconnection attempts
+    //! - `resilience_reconnect_connection_state{name, state}` - Current connection state gauge (connected/disconnected/reconnecting)
     //!
     //! ### Cache
     //!
-    //! - `cache_requests_total{cache, result}` - Cache requests (hit/miss)
-    //! - `cache_evictions_total{cache}` - Cache evictions
-    //! - `cache_size{cache}` - Current cache size gauge
+    //! - `resilience_cache_requests_total{name, result}` - Cache requests (hit/miss)
+    //! - `resilience_cache_evictions_total{name}` - Cache evictions
+    //! - `resilience_cache_refreshes_total{name}` - Background refreshes of entries near expiry
+    //! - `resilience_cache_size{name}` - Current cache size gauge
+    //!
+    //! ### Fallback
+    //!
+    //! - `resilience_fallback_calls_total{name, result, strategy}` - Fallback operations
+    //! - `resilience_fallback_applied_total{name, strategy}` - Responses served by the fallback
+    //! - `resilience_fallback_active_ratio{name}` - Fraction of recent calls served by the fallback gauge
+    //!
+    //! ### Router
+    //!
+    //! - `resilience_router_requests_routed_total{name, backend}` - Requests per backend
+    //!
+    //! ### Outlier Detection
+    //!
+    //! - `resilience_outlier_ejections_total{name, instance}` - Instance ejections
+    //! - `resilience_outlier_ejections_skipped_total{name, instance}` - Ejections skipped by `max_ejection_percent`
+    //! - `resilience_outlier_recoveries_total{name, instance}` - Instances returned to service
+    //! - `resilience_outlier_ejected_instances{name}` - Currently ejected instances gauge
+    //!
+    //! ### Events
+    //!
+    //! - `resilience_event_listener_panics_total{pattern, event_type}` - Listener panics caught during dispatch
+    //! - `resilience_event_bus_dropped_total{pattern, event_type}` - Events dropped by a full async event bus
     //!
     //! ## Example Prometheus Queries
     //!
     //! ```promql
     //! # Circuit breaker failure rate
-    //! rate(circuitbreaker_calls_total{outcome="failure"}[5m])
+    //! rate(resilience_circuitbreaker_calls_total{outcome="failure"}[5m])
     //!   /
-    //! rate(circuitbreaker_calls_total[5m]) * 100
+    //! rate(resilience_circuitbreaker_calls_total[5m]) * 100
     //!
     //! # Bulkhead rejection percentage
-    //! rate(bulkhead_calls_rejected_total[5m])
+    //! rate(resilience_bulkhead_calls_rejected_total[5m])
     //!   /
-    //! (rate(bulkhead_calls_permitted_total[5m]) + rate(bulkhead_calls_rejected_total[5m]))
+    //! (rate(resilience_bulkhead_calls_permitted_total[5m]) + rate(resilience_bulkhead_calls_rejected_total[5m]))
     //!   * 100
     //!
     //! # Average retry attempts per call
-    //! rate(retry_attempts_total[5m]) / rate(retry_calls_total[5m])
+    //! rate(resilience_retry_attempts_total[5m]) / rate(resilience_retry_calls_total[5m])
     //!
     //! # Cache hit rate
-    //! rate(cache_requests_total{result="hit"}[5m])
+    //! rate(resilience_cache_requests_total{result="hit"}[5m])
     //!   /
-    //! rate(cache_requests_total[5m]) * 100
+    //! rate(resilience_cache_requests_total[5m]) * 100
     //!
     //! # P95 call latency
     //! histogram_quantile(0.95,
-    //!   rate(circuitbreaker_call_duration_seconds_bucket[5m])
+    //!   rate(resilience_circuitbreaker_call_duration_seconds_bucket[5m])
     //! )
     //! ```
     //!
@@ -112,22 +196,22 @@ pub mod metrics {
     //! ```yaml
     //! # Circuit breaker opened
     //! - alert: CircuitBreakerOpen
-    //!   expr: circuitbreaker_state{state="Open"} == 1
+    //!   expr: resilience_circuitbreaker_state{state="Open"} == 1
     //!   for: 1m
     //!
     //! # High failure rate
     //! - alert: HighFailureRate
     //!   expr: |
-    //!     rate(circuitbreaker_calls_total{outcome="failure"}[5m])
-    //!     / rate(circuitbreaker_calls_total[5m]) > 0.1
+    //!     rate(resilience_circuitbreaker_calls_total{outcome="failure"}[5m])
+    //!     / rate(resilience_circuitbreaker_calls_total[5m]) > 0.1
     //!   for: 5m
     //!
     //! # Bulkhead saturation
     //! - alert: BulkheadSaturated
     //!   expr: |
-    //!     rate(bulkhead_calls_rejected_total[5m])
-    //!     / (rate(bulkhead_calls_permitted_total[5m])
-    //!        + rate(bulkhead_calls_rejected_total[5m])) > 0.5
+    //!     rate(resilience_bulkhead_calls_rejected_total[5m])
+    //!     / (rate(resilience_bulkhead_calls_permitted_total[5m])
+    //!        + rate(resilience_bulkhead_calls_rejected_total[5m])) > 0.5
     //!   for: 5m
     //! ```
 }
@@ -151,6 +235,29 @@ pub mod tracing_guide {
     //! INFO  retry: Request succeeded after retries attempts=3 retry="api-client"
     //! DEBUG bulkhead: Permit acquired after waiting wait_ms=12 bulkhead="db-pool"
     //! ```
+    //!
+    //! ## Request Spans
+    //!
+    //! Every layer also wraps each request in a `resilience` span with the same
+    //! fields, defined in [`span`](tower_resilience_core::span):
+    //!
+    //! | Field | Value |
+    //! |-------|-------|
+    //! | `resilience.pattern` | The pattern, e.g. `circuit_breaker`, `retry`, `bulkhead` |
+    //! | `resilience.name` | The instance name set with `.name(...)` |
+    //! | `resilience.outcome` | `success`, `error`, `rejected` or `timeout` |
+    //!
+    //! A composed stack produces one nested span per layer, so filtering on
+    //! `resilience.outcome = "rejected"` shows which layer turned a request away:
+    //!
+    //! ```text
+    //! resilience{resilience.pattern="retry" resilience.name="api" resilience.outcome="error"}
+    //!   resilience{resilience.pattern="circuit_breaker" resilience.name="api" resilience.outcome="rejected"}
+    //! ```
+    //!
+    //! The router and coalesce layers return futures they don't own, so their
+    //! spans cover routing and joining but only record an outcome when it is
+    //! known up front.
 }
 
 pub mod events {
@@ -170,4 +277,122 @@ pub mod events {
     //! ```
     //!
     //! See individual pattern documentation for available event listeners.
+    //!
+    //! ## Global Subscription
+    //!
+    //! Observability integrations usually want every event rather than a few
+    //! callbacks per layer. [`events::subscribe`](crate::events::subscribe)
+    //! registers a process-wide subscriber that sees events from all layers as
+    //! `&dyn ResilienceEvent`, identified by pattern name and event type:
+    //!
+    //! ```rust
+    //! use tower_resilience::events::{subscribe, ResilienceEvent};
+    //!
+    //! let subscription = subscribe(|event: &dyn ResilienceEvent| {
+    //!     if event.event_type() == "state_transition" {
+    //!         println!("{} changed state", event.pattern_name());
+    //!     }
+    //! });
+    //!
+    //! // Stop receiving events
+    //! subscription.unsubscribe();
+    //! ```
+    //!
+    //! Subscribers run after the layer's own listeners and follow the layer's
+    //! dispatch mode, so with `async_events` they run on the background task too.
+    //!
+    //! ## Serializing Events
+    //!
+    //! With the `serde` feature every event enum implements `serde::Serialize`,
+    //! so events can be shipped to JSON logs, message queues or live dashboards.
+    //! Events are tagged with a snake_case `type` field named after the variant,
+    //! and timestamps are written as milliseconds since the Unix epoch
+    //! (see [`timestamp`](tower_resilience_core::timestamp)):
+    //!
+    //! ```rust,ignore
+    //! use tower_resilience::circuitbreaker::CircuitBreakerEvent;
+    //!
+    //! fn log_event(event: &CircuitBreakerEvent) {
+    //!     println!("{}", serde_json::to_string(event).unwrap());
+    //! }
+    //! // {"type":"state_transition","pattern_name":"payments","timestamp":1760000000000,
+    //! //  "from_state":"Closed","to_state":"Open"}
+    //! ```
+    //!
+    //! Metrics snapshots such as `CircuitMetrics` serialize the same way.
+    //!
+    //! ## OpenTelemetry
+    //!
+    //! With the `otel` feature, [`otel::install`](tower_resilience_core::otel::install)
+    //! forwards every event to OpenTelemetry instead of (or alongside) the
+    //! `metrics` crate. Each event is added to the active span as a
+    //! `resilience.<event_type>` span event, and a `resilience.events` counter is
+    //! incremented, both with `resilience.pattern` and `resilience.event_type`
+    //! attributes:
+    //!
+    //! ```rust,ignore
+    //! use tower_resilience::core::otel;
+    //!
+    //! // After configuring the global tracer and meter providers
+    //! let _subscription = otel::install();
+    //! ```
+    //!
+    //! ## Asynchronous Dispatch
+    //!
+    //! Listeners run inline by default, so a slow listener (one that writes to
+    //! a socket or takes a lock, say) adds its latency to every call that emits
+    //! an event. `async_events(capacity)` on a layer's builder moves delivery to
+    //! a background task fed by a bounded [`EventBus`](tower_resilience_core::EventBus):
+    //!
+    //! ```rust,ignore
+    //! let circuit_breaker = CircuitBreakerLayer::builder()
+    //!     .on_state_transition(|from, to| send_to_alerting(from, to))
+    //!     .async_events(1024)
+    //!     .build();
+    //! ```
+    //!
+    //! When listeners fall behind and the buffer fills, the oldest queued
+    //! events are dropped rather than slowing requests down. Drops are counted
+    //! by `resilience_event_bus_dropped_total{pattern, event_type}` when the
+    //! `metrics` feature is enabled.
+}
+
+/// Default histogram buckets for `resilience_*_seconds` metrics, from 1ms to
+/// 30s.
+#[cfg(feature = "prometheus")]
+const SECONDS_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+/// Histogram buckets for `resilience_retry_attempts`.
+#[cfg(feature = "prometheus")]
+const ATTEMPT_BUCKETS: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 10.0];
+
+/// Installs a Prometheus recorder for the metrics emitted by every pattern.
+///
+/// Sets histogram buckets for the `resilience_*_seconds` durations and the
+/// retry attempt histogram on `builder`, then installs the recorder globally. Serve
+/// [`PrometheusHandle::render`](metrics_exporter_prometheus::PrometheusHandle::render)
+/// from your metrics endpoint.
+///
+/// Requires the `prometheus` feature, which also enables `metrics`.
+///
+/// # Errors
+///
+/// Returns an error if a global recorder is already installed or the builder
+/// is invalid.
+#[cfg(feature = "prometheus")]
+pub fn install_prometheus(
+    builder: metrics_exporter_prometheus::PrometheusBuilder,
+) -> Result<metrics_exporter_prometheus::PrometheusHandle, metrics_exporter_prometheus::BuildError>
+{
+    use metrics_exporter_prometheus::Matcher;
+
+    builder
+        .set_buckets_for_metric(
+            Matcher::Full("resilience_retry_attempts".to_string()),
+            ATTEMPT_BUCKETS,
+        )?
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), SECONDS_BUCKETS)?
+        .install_recorder()
 }
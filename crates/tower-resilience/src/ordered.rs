@@ -0,0 +1,251 @@
+//! Layer composition checked against the ordering guide at compile time.
+
+use tower_layer::{Identity, Layer};
+
+/// A pattern with a position in the recommended layer order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    /// Degraded responses when everything inside fails.
+    Fallback,
+    /// Responses served without reaching the layers inside.
+    Cache,
+    /// Fails fast while the service is down.
+    CircuitBreaker,
+    /// Repeats failed attempts.
+    Retry,
+    /// Limits concurrent calls.
+    Bulkhead,
+    /// Bounds the time of the operation or of each attempt.
+    TimeLimiter,
+    /// Limits the rate of calls to the service.
+    RateLimiter,
+    /// Races parallel attempts.
+    Hedge,
+}
+
+impl Pattern {
+    const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+
+    /// Patterns that must not be outside this one.
+    const fn must_not_be_outside(self) -> u32 {
+        const CIRCUIT_BREAKER: u32 = Pattern::CircuitBreaker.bit();
+        const RETRY: u32 = Pattern::Retry.bit();
+        const BULKHEAD: u32 = Pattern::Bulkhead.bit();
+        const RATE_LIMITER: u32 = Pattern::RateLimiter.bit();
+        const HEDGE: u32 = Pattern::Hedge.bit();
+
+        match self {
+            Pattern::Fallback => !Pattern::Fallback.bit(),
+            Pattern::Cache => CIRCUIT_BREAKER | RETRY | BULKHEAD | RATE_LIMITER | HEDGE,
+            Pattern::CircuitBreaker => RETRY | BULKHEAD | RATE_LIMITER | HEDGE,
+            Pattern::Retry => BULKHEAD | RATE_LIMITER | HEDGE,
+            Pattern::Bulkhead | Pattern::RateLimiter => HEDGE,
+            Pattern::TimeLimiter | Pattern::Hedge => 0,
+        }
+    }
+}
+
+/// Where a layer belongs in the recommended order.
+///
+/// Implemented for the layers of every enabled pattern, and for [`Identity`].
+/// Implement it for your own layers to use them with [`OrderedLayers`];
+/// `None` places no constraints on the layer.
+pub trait Placement {
+    /// The pattern this layer implements, if it has a recommended position.
+    const PATTERN: Option<Pattern>;
+}
+
+impl Placement for Identity {
+    const PATTERN: Option<Pattern> = None;
+}
+
+/// Panics, at compile time when called from a constant, if a layer
+/// implementing `pattern` may not be inside the patterns in `outside`.
+const fn check_placement(outside: u32, pattern: Option<Pattern>) {
+    let Some(pattern) = pattern else {
+        return;
+    };
+    if outside & pattern.must_not_be_outside() == 0 {
+        return;
+    }
+    match pattern {
+        Pattern::Fallback => panic!("fallback must be the outermost layer"),
+        Pattern::Cache => {
+            panic!("cache must be outside circuit breaker, retry, bulkhead, rate limiter and hedge")
+        }
+        Pattern::CircuitBreaker => {
+            panic!("circuit breaker must be outside retry, bulkhead, rate limiter and hedge")
+        }
+        Pattern::Retry => panic!("retry must be outside bulkhead, rate limiter and hedge"),
+        Pattern::Bulkhead => panic!("bulkhead must be outside hedge"),
+        Pattern::RateLimiter => panic!("rate limiter must be outside hedge"),
+        Pattern::TimeLimiter | Pattern::Hedge => {}
+    }
+}
+
+/// Layers composed from outermost to innermost, with their order checked at
+/// compile time.
+///
+/// [`ResilienceStack`](crate::ResilienceStack) fixes the order of five
+/// patterns. `OrderedLayers` instead lets you choose the layers and their
+/// order, from outermost to innermost, and refuses to build when a layer is
+/// placed inside one that the [ordering guide](crate::composition::ordering)
+/// says must be inside it:
+///
+/// | Pattern | Must not be inside |
+/// |---------|--------------------|
+/// | Fallback | any other pattern |
+/// | Cache | circuit breaker, retry, bulkhead, rate limiter, hedge |
+/// | Circuit breaker | retry, bulkhead, rate limiter, hedge |
+/// | Retry | bulkhead, rate limiter, hedge |
+/// | Bulkhead | hedge |
+/// | Rate limiter | hedge |
+///
+/// Time limiters bound either the whole operation (outside retry) or a single
+/// attempt (inside it), so they can go anywhere. The same holds for layers
+/// whose [`Placement`] has no [`Pattern`].
+///
+/// The breaker goes outside retry, as in `ResilienceStack`, so it counts
+/// failed requests rather than failed attempts. Retrying around a breaker is
+/// only sound with a retry budget, which the type system cannot see; compose
+/// that deliberately with `ServiceBuilder`.
+///
+/// Violations are reported when the layers are built (`cargo build`, not
+/// `cargo check`), with a message naming the misplaced pattern:
+///
+/// ```rust,compile_fail
+/// use tower_resilience::circuitbreaker::CircuitBreakerLayer;
+/// use tower_resilience::retry::RetryLayer;
+/// use tower_resilience::OrderedLayers;
+///
+/// // Error: circuit breaker must not be inside retry
+/// let layers = OrderedLayers::new()
+///     .then(RetryLayer::<String, String, std::io::Error>::builder().build())
+///     .then(CircuitBreakerLayer::builder().build());
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "circuitbreaker", feature = "retry", feature = "timelimiter"))]
+/// # async fn example() {
+/// use std::time::Duration;
+/// use tower::{Layer, Service, ServiceExt};
+/// use tower_resilience::circuitbreaker::CircuitBreakerLayer;
+/// use tower_resilience::retry::RetryLayer;
+/// use tower_resilience::timelimiter::TimeLimiterLayer;
+/// use tower_resilience::OrderedLayers;
+///
+/// let layers = OrderedLayers::new()
+///     .then(TimeLimiterLayer::builder().timeout_duration(Duration::from_secs(30)).build())
+///     .then(CircuitBreakerLayer::builder().build())
+///     .then(RetryLayer::builder().max_attempts(3).build())
+///     .then(TimeLimiterLayer::builder().timeout_duration(Duration::from_secs(5)).build());
+///
+/// let service = tower::service_fn(|req: String| async move {
+///     Ok::<_, std::io::Error>(req.len())
+/// });
+/// let mut service = layers.layer(service);
+///
+/// let len = service.ready().await.unwrap().call("hello".to_string()).await.unwrap();
+/// assert_eq!(len, 5);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderedLayers<O = Identity, I = Identity> {
+    outer: O,
+    inner: I,
+}
+
+impl OrderedLayers {
+    /// Creates an empty composition.
+    pub fn new() -> Self {
+        Self {
+            outer: Identity::new(),
+            inner: Identity::new(),
+        }
+    }
+}
+
+impl Default for OrderedLayers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Patterns present in a composition, as a bit set.
+#[doc(hidden)]
+pub trait Composition {
+    const PATTERNS: u32;
+}
+
+impl Composition for Identity {
+    const PATTERNS: u32 = 0;
+}
+
+impl<O: Composition, I: Placement> Composition for OrderedLayers<O, I> {
+    const PATTERNS: u32 = O::PATTERNS
+        | match I::PATTERN {
+            Some(pattern) => pattern.bit(),
+            None => 0,
+        };
+}
+
+impl<O: Composition, I: Placement> OrderedLayers<O, I> {
+    /// Adds `layer` inside the layers added so far.
+    ///
+    /// Fails to build if `layer` must not be inside one of them.
+    pub fn then<N: Placement>(self, layer: N) -> OrderedLayers<Self, N> {
+        const { check_placement(<Self as Composition>::PATTERNS, N::PATTERN) };
+        OrderedLayers {
+            outer: self,
+            inner: layer,
+        }
+    }
+}
+
+impl<S, O, I> Layer<S> for OrderedLayers<O, I>
+where
+    I: Layer<S>,
+    O: Layer<I::Service>,
+{
+    type Service = O::Service;
+
+    fn layer(&self, service: S) -> Self::Service {
+        self.outer.layer(self.inner.layer(service))
+    }
+}
+
+macro_rules! placement {
+    ($feature:literal, $pattern:ident, $ty:ty $(, $param:ident)*) => {
+        #[cfg(feature = $feature)]
+        impl<$($param),*> Placement for $ty {
+            const PATTERN: Option<Pattern> = Some(Pattern::$pattern);
+        }
+    };
+}
+
+placement!("fallback", Fallback, tower_resilience_fallback::FallbackLayer<Req, Res, E>, Req, Res, E);
+placement!("cache", Cache, tower_resilience_cache::CacheLayer<Req, K>, Req, K);
+placement!("cache", Cache, tower_resilience_cache::SharedCacheLayer<Req, K, Resp>, Req, K, Resp);
+placement!(
+    "circuitbreaker",
+    CircuitBreaker,
+    tower_resilience_circuitbreaker::CircuitBreakerLayer<C>,
+    C
+);
+placement!("retry", Retry, tower_resilience_retry::RetryLayer<Req, Res, E>, Req, Res, E);
+placement!(
+    "bulkhead",
+    Bulkhead,
+    tower_resilience_bulkhead::BulkheadLayer
+);
+placement!("timelimiter", TimeLimiter, tower_resilience_timelimiter::TimeLimiterLayer<T, C, B>, T, C, B);
+placement!(
+    "ratelimiter",
+    RateLimiter,
+    tower_resilience_ratelimiter::RateLimiterLayer
+);
+placement!("hedge", Hedge, tower_resilience_hedge::HedgeLayer<H>, H);
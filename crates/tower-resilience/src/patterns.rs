@@ -596,6 +596,7 @@ pub mod coalesce {
 
 /// Outlier Detection pattern guide
 
+#[allow(clippy::empty_line_after_doc_comments, clippy::mixed_attributes_style)]
 pub mod executor {
     //! # Executor
     //!
@@ -929,8 +930,17 @@ pub mod healthcheck {
     //! ### PreferHealthy
     //! Prefers fully healthy resources, falls back to degraded if needed.
     //!
+    //! ### WeightedRoundRobin
+    //! Distributes load in proportion to per-resource weights.
+    //!
+    //! ### LeastOutstanding
+    //! Sends each request to the resource with the fewest requests in flight.
+    //!
+    //! ### LatencyAware
+    //! Prefers the resource with the lowest moving-average latency.
+    //!
     //! ### Custom
-    //! Implement custom logic (latency-based, geographic proximity, weighted, etc.).
+    //! Implement custom logic (geographic proximity, affinity, etc.).
     //!
     //! ## Health Status States
     //!
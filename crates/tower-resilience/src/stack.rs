@@ -0,0 +1,396 @@
+//! A single layer that composes patterns in the recommended order.
+//!
+//! Stacking layers by hand means remembering which goes outside which (see
+//! the [ordering guide](crate::composition::ordering)) and threading the
+//! nested error types through each one. [`ResilienceStack`] takes the
+//! patterns you want, in any order, and always applies them as:
+//!
+//! ```text
+//! Request → Circuit Breaker → Retry → Bulkhead → Time Limiter → Rate Limiter → Service
+//! ```
+//!
+//! - The circuit breaker sees the result of the whole retry sequence, so it
+//!   counts "did the request fail" rather than "did one attempt fail".
+//! - Each retry attempt takes its own bulkhead permit and gets its own
+//!   timeout, so one slow attempt cannot use up the retry budget.
+//! - The rate limiter is closest to the service, so every attempt counts
+//!   against the downstream limit.
+//!
+//! Patterns that are not configured are left out entirely; they cost nothing
+//! at runtime and don't appear in the error type.
+//!
+//! # Error Types
+//!
+//! The stacked service's error is the nested error of the layers you chose,
+//! for example `CircuitBreakerError<BulkheadServiceError<TimeLimiterError<E>>>`.
+//! The retry layer sees the error of everything inside it, so its `E`
+//! parameter is that inner error (usually left for the compiler to infer).
+//! Use [`find_pattern_failure`](crate::find_pattern_failure) or
+//! [`flatten`](crate::flatten) rather than matching on the nesting.
+
+use tower_layer::{Identity, Layer};
+
+#[cfg(all(feature = "circuitbreaker", feature = "retry", feature = "timelimiter"))]
+use std::time::Duration;
+#[cfg(feature = "bulkhead")]
+use tower_resilience_bulkhead::BulkheadLayer;
+#[cfg(feature = "circuitbreaker")]
+use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+#[cfg(feature = "ratelimiter")]
+use tower_resilience_ratelimiter::RateLimiterLayer;
+#[cfg(feature = "retry")]
+use tower_resilience_retry::RetryLayer;
+#[cfg(feature = "timelimiter")]
+use tower_resilience_timelimiter::TimeLimiterLayer;
+
+/// A [`Layer`] applying the configured patterns in the recommended order.
+///
+/// Build one with [`ResilienceStack::builder`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "circuitbreaker", feature = "retry", feature = "bulkhead", feature = "timelimiter"))]
+/// # async fn example() {
+/// use tower::{Layer, Service, ServiceExt};
+/// use tower_resilience::bulkhead::BulkheadLayer;
+/// use tower_resilience::circuitbreaker::CircuitBreakerLayer;
+/// use tower_resilience::retry::RetryLayer;
+/// use tower_resilience::timelimiter::TimeLimiterLayer;
+/// use tower_resilience::ResilienceStack;
+///
+/// let stack = ResilienceStack::builder()
+///     .with_retry(RetryLayer::builder().max_attempts(3).build())
+///     .with_circuit_breaker(CircuitBreakerLayer::standard().name("api").build())
+///     .with_time_limiter(TimeLimiterLayer::fast().build())
+///     .with_bulkhead(BulkheadLayer::medium().build())
+///     .build();
+///
+/// let service = tower::service_fn(|req: String| async move {
+///     Ok::<_, std::io::Error>(req.len())
+/// });
+/// let mut service = stack.layer(service);
+///
+/// let len = service.ready().await.unwrap().call("hello".to_string()).await.unwrap();
+/// assert_eq!(len, 5);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ResilienceStack<
+    CB = Identity,
+    RT = Identity,
+    BH = Identity,
+    TL = Identity,
+    RL = Identity,
+> {
+    circuit_breaker: CB,
+    retry: RT,
+    bulkhead: BH,
+    time_limiter: TL,
+    rate_limiter: RL,
+}
+
+impl ResilienceStack {
+    /// Creates a builder with no patterns configured.
+    pub fn builder() -> ResilienceStackBuilder {
+        ResilienceStackBuilder::new()
+    }
+}
+
+/// Stack presets for common workloads.
+///
+/// Each preset returns a builder with thresholds taken from the
+/// [composition guide](crate::composition::stacks), so any pattern can still
+/// be replaced or added before calling `build()`. Retry retries every error
+/// by default; narrow it with a `with_retry` call when some errors are
+/// permanent.
+#[cfg(all(feature = "circuitbreaker", feature = "retry", feature = "timelimiter"))]
+impl ResilienceStack {
+    /// Preset: calls to external HTTP APIs.
+    ///
+    /// Configuration:
+    /// - Circuit breaker: 50% failure rate, 30s open
+    /// - Retry: 3 attempts, 100ms exponential backoff
+    /// - Time limiter: 10s per attempt
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "circuitbreaker", feature = "retry", feature = "timelimiter"))]
+    /// # {
+    /// use tower_resilience::ResilienceStack;
+    /// use tower_resilience::timelimiter::TimeLimiterError;
+    ///
+    /// let stack = ResilienceStack::http_client_defaults::<String, String, TimeLimiterError<std::io::Error>>()
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn http_client_defaults<Req, Res, E>() -> ResilienceStackBuilder<
+        CircuitBreakerLayer,
+        RetryLayer<Req, Res, E>,
+        Identity,
+        TimeLimiterLayer,
+        Identity,
+    > {
+        ResilienceStack::builder()
+            .with_circuit_breaker(
+                CircuitBreakerLayer::builder()
+                    .name("http_client")
+                    .failure_rate_threshold(0.5)
+                    .wait_duration_in_open(Duration::from_secs(30))
+                    .build(),
+            )
+            .with_retry(
+                RetryLayer::builder()
+                    .name("http_client")
+                    .max_attempts(3)
+                    .exponential_backoff(Duration::from_millis(100))
+                    .build(),
+            )
+            .with_time_limiter(
+                TimeLimiterLayer::builder()
+                    .name("http_client")
+                    .timeout_duration(Duration::from_secs(10))
+                    .build(),
+            )
+    }
+
+    /// Preset: database clients.
+    ///
+    /// Configuration:
+    /// - Circuit breaker: 50% failure rate over at least 10 calls
+    /// - Retry: 2 attempts, 50ms fixed backoff
+    /// - Bulkhead: 20 concurrent queries, matching a typical pool size
+    /// - Time limiter: 5s per query
+    ///
+    /// Size the bulkhead to your connection pool with `with_bulkhead`.
+    #[cfg(feature = "bulkhead")]
+    pub fn database_defaults<Req, Res, E>() -> ResilienceStackBuilder<
+        CircuitBreakerLayer,
+        RetryLayer<Req, Res, E>,
+        BulkheadLayer,
+        TimeLimiterLayer,
+        Identity,
+    > {
+        ResilienceStack::builder()
+            .with_circuit_breaker(
+                CircuitBreakerLayer::builder()
+                    .name("database")
+                    .failure_rate_threshold(0.5)
+                    .minimum_number_of_calls(10)
+                    .build(),
+            )
+            .with_retry(
+                RetryLayer::builder()
+                    .name("database")
+                    .max_attempts(2)
+                    .fixed_backoff(Duration::from_millis(50))
+                    .build(),
+            )
+            .with_bulkhead(
+                BulkheadLayer::builder()
+                    .name("database")
+                    .max_concurrent_calls(20)
+                    .build(),
+            )
+            .with_time_limiter(
+                TimeLimiterLayer::builder()
+                    .name("database")
+                    .timeout_duration(Duration::from_secs(5))
+                    .build(),
+            )
+    }
+
+    /// Preset: message queue consumers.
+    ///
+    /// Configuration:
+    /// - Circuit breaker: 50% failure rate, 60s open
+    /// - Retry: 5 attempts, exponential backoff from 1s capped at 60s
+    /// - Time limiter: 30s per message
+    pub fn message_consumer_defaults<Req, Res, E>() -> ResilienceStackBuilder<
+        CircuitBreakerLayer,
+        RetryLayer<Req, Res, E>,
+        Identity,
+        TimeLimiterLayer,
+        Identity,
+    > {
+        ResilienceStack::builder()
+            .with_circuit_breaker(
+                CircuitBreakerLayer::builder()
+                    .name("message_consumer")
+                    .failure_rate_threshold(0.5)
+                    .wait_duration_in_open(Duration::from_secs(60))
+                    .build(),
+            )
+            .with_retry(
+                RetryLayer::builder()
+                    .name("message_consumer")
+                    .max_attempts(5)
+                    .backoff(
+                        tower_resilience_retry::ExponentialBackoff::new(Duration::from_secs(1))
+                            .max_interval(Duration::from_secs(60)),
+                    )
+                    .build(),
+            )
+            .with_time_limiter(
+                TimeLimiterLayer::builder()
+                    .name("message_consumer")
+                    .timeout_duration(Duration::from_secs(30))
+                    .build(),
+            )
+    }
+}
+
+impl<S, CB, RT, BH, TL, RL> Layer<S> for ResilienceStack<CB, RT, BH, TL, RL>
+where
+    RL: Layer<S>,
+    TL: Layer<RL::Service>,
+    BH: Layer<TL::Service>,
+    RT: Layer<BH::Service>,
+    CB: Layer<RT::Service>,
+{
+    type Service = CB::Service;
+
+    fn layer(&self, service: S) -> Self::Service {
+        let service = self.rate_limiter.layer(service);
+        let service = self.time_limiter.layer(service);
+        let service = self.bulkhead.layer(service);
+        let service = self.retry.layer(service);
+        self.circuit_breaker.layer(service)
+    }
+}
+
+/// Builder for [`ResilienceStack`].
+///
+/// Each `with_*` method sets one pattern; calling it again replaces the
+/// previous layer for that pattern. The order of the calls does not matter.
+#[derive(Clone)]
+pub struct ResilienceStackBuilder<
+    CB = Identity,
+    RT = Identity,
+    BH = Identity,
+    TL = Identity,
+    RL = Identity,
+> {
+    stack: ResilienceStack<CB, RT, BH, TL, RL>,
+}
+
+impl ResilienceStackBuilder {
+    /// Creates a builder with no patterns configured.
+    pub fn new() -> Self {
+        Self {
+            stack: ResilienceStack {
+                circuit_breaker: Identity::new(),
+                retry: Identity::new(),
+                bulkhead: Identity::new(),
+                time_limiter: Identity::new(),
+                rate_limiter: Identity::new(),
+            },
+        }
+    }
+}
+
+impl Default for ResilienceStackBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<CB, RT, BH, TL, RL> ResilienceStackBuilder<CB, RT, BH, TL, RL> {
+    /// Sets the circuit breaker, the outermost layer of the stack.
+    #[cfg(feature = "circuitbreaker")]
+    pub fn with_circuit_breaker<C>(
+        self,
+        circuit_breaker: CircuitBreakerLayer<C>,
+    ) -> ResilienceStackBuilder<CircuitBreakerLayer<C>, RT, BH, TL, RL> {
+        let stack = self.stack;
+        ResilienceStackBuilder {
+            stack: ResilienceStack {
+                circuit_breaker,
+                retry: stack.retry,
+                bulkhead: stack.bulkhead,
+                time_limiter: stack.time_limiter,
+                rate_limiter: stack.rate_limiter,
+            },
+        }
+    }
+
+    /// Sets the retry layer, inside the circuit breaker.
+    ///
+    /// `E` is the error of the layers inside retry, not of the service.
+    #[cfg(feature = "retry")]
+    pub fn with_retry<Req, Res, E>(
+        self,
+        retry: RetryLayer<Req, Res, E>,
+    ) -> ResilienceStackBuilder<CB, RetryLayer<Req, Res, E>, BH, TL, RL> {
+        let stack = self.stack;
+        ResilienceStackBuilder {
+            stack: ResilienceStack {
+                circuit_breaker: stack.circuit_breaker,
+                retry,
+                bulkhead: stack.bulkhead,
+                time_limiter: stack.time_limiter,
+                rate_limiter: stack.rate_limiter,
+            },
+        }
+    }
+
+    /// Sets the bulkhead, inside retry so each attempt takes its own permit.
+    #[cfg(feature = "bulkhead")]
+    pub fn with_bulkhead(
+        self,
+        bulkhead: BulkheadLayer,
+    ) -> ResilienceStackBuilder<CB, RT, BulkheadLayer, TL, RL> {
+        let stack = self.stack;
+        ResilienceStackBuilder {
+            stack: ResilienceStack {
+                circuit_breaker: stack.circuit_breaker,
+                retry: stack.retry,
+                bulkhead,
+                time_limiter: stack.time_limiter,
+                rate_limiter: stack.rate_limiter,
+            },
+        }
+    }
+
+    /// Sets the per-attempt time limiter, inside the bulkhead.
+    #[cfg(feature = "timelimiter")]
+    pub fn with_time_limiter<T, C, B>(
+        self,
+        time_limiter: TimeLimiterLayer<T, C, B>,
+    ) -> ResilienceStackBuilder<CB, RT, BH, TimeLimiterLayer<T, C, B>, RL> {
+        let stack = self.stack;
+        ResilienceStackBuilder {
+            stack: ResilienceStack {
+                circuit_breaker: stack.circuit_breaker,
+                retry: stack.retry,
+                bulkhead: stack.bulkhead,
+                time_limiter,
+                rate_limiter: stack.rate_limiter,
+            },
+        }
+    }
+
+    /// Sets the rate limiter, the innermost layer of the stack.
+    #[cfg(feature = "ratelimiter")]
+    pub fn with_rate_limiter(
+        self,
+        rate_limiter: RateLimiterLayer,
+    ) -> ResilienceStackBuilder<CB, RT, BH, TL, RateLimiterLayer> {
+        let stack = self.stack;
+        ResilienceStackBuilder {
+            stack: ResilienceStack {
+                circuit_breaker: stack.circuit_breaker,
+                retry: stack.retry,
+                bulkhead: stack.bulkhead,
+                time_limiter: stack.time_limiter,
+                rate_limiter,
+            },
+        }
+    }
+
+    /// Builds the stack.
+    pub fn build(self) -> ResilienceStack<CB, RT, BH, TL, RL> {
+        self.stack
+    }
+}
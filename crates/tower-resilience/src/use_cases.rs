@@ -44,6 +44,9 @@ pub mod message_queue {
     //! ├─ Rate limit for broker protection
     //! └─ Bulkhead for connection pool
     //! ```
+    //!
+    //! [`ResilienceStack::message_consumer_defaults`](crate::ResilienceStack::message_consumer_defaults)
+    //! sets up the consumer's retry, circuit breaker and timeout layers.
 }
 
 pub mod microservices {
@@ -63,6 +66,9 @@ pub mod microservices {
     //! ├─ Circuit breaker per route
     //! └─ Cache for popular responses
     //! ```
+    //!
+    //! [`ResilienceStack::http_client_defaults`](crate::ResilienceStack::http_client_defaults)
+    //! sets up the circuit breaker, retry and timeout layers for outbound calls.
 }
 
 pub mod background_jobs {
@@ -4,6 +4,7 @@
 //! 1. Circuit breaker with health check integration (PR #121)
 //! 2. Chaos engineering with configurable failure injection
 //! 3. Kubernetes-ready health endpoints
+//! 4. Admin endpoints for inspecting and controlling the circuit breaker
 //!
 //! The circuit breaker automatically opens when chaos failures exceed the threshold,
 //! demonstrating real resilience patterns in action.
@@ -16,19 +17,17 @@ use axum::{
     routing::{get, post},
     Router,
 };
-use rand::Rng;
 use serde::Deserialize;
 use std::{
     collections::HashMap,
     net::SocketAddr,
-    sync::{
-        atomic::{AtomicU32, Ordering},
-        Arc, RwLock,
-    },
+    sync::{Arc, RwLock},
     time::Duration,
 };
 use tokio::net::TcpListener;
 use tower::{Layer, Service, ServiceExt};
+use tower_resilience::admin::AdminRegistry;
+use tower_resilience_chaos::{Chaos, ChaosControl, ChaosLayer, CustomErrorFn};
 use tower_resilience_circuitbreaker::{CircuitBreaker, CircuitBreakerLayer, DefaultClassifier};
 
 /// Database request
@@ -60,7 +59,6 @@ impl std::error::Error for DbError {}
 #[derive(Clone)]
 struct DatabaseService {
     db: Arc<RwLock<HashMap<String, Bytes>>>,
-    chaos_failure_rate: Arc<AtomicU32>,
 }
 
 impl tower::Service<DbRequest> for DatabaseService {
@@ -79,19 +77,8 @@ impl tower::Service<DbRequest> for DatabaseService {
 
     fn call(&mut self, req: DbRequest) -> Self::Future {
         let db = Arc::clone(&self.db);
-        let chaos_rate = Arc::clone(&self.chaos_failure_rate);
 
         Box::pin(async move {
-            // Chaos injection: random failures based on configured rate
-            let rate_bits = chaos_rate.load(Ordering::Relaxed);
-            let failure_rate = f64::from_bits(rate_bits as u64);
-
-            if rand::rng().random::<f64>() < failure_rate {
-                tracing::warn!("Chaos: Injected database failure for key '{}'", req.key);
-                return Err(DbError("Simulated database failure (chaos)".to_string()));
-            }
-
-            // Normal database operation
             let db = db.read().unwrap();
             match db.get(&req.key) {
                 Some(value) => Ok(DbResponse::Found(value.clone())),
@@ -101,28 +88,42 @@ impl tower::Service<DbRequest> for DatabaseService {
     }
 }
 
-type DbService = CircuitBreaker<DatabaseService, DefaultClassifier>;
+/// Chaos error generator for database requests
+fn chaos_error(req: &DbRequest) -> DbError {
+    tracing::warn!("Chaos: Injected database failure for key '{}'", req.key);
+    DbError("Simulated database failure (chaos)".to_string())
+}
+
+type ChaosErrorFn = fn(&DbRequest) -> DbError;
+type DbService =
+    CircuitBreaker<Chaos<DatabaseService, CustomErrorFn<ChaosErrorFn>>, DefaultClassifier>;
 
 #[derive(Clone)]
 struct AppState {
     db: Arc<RwLock<HashMap<String, Bytes>>>,
     db_service: Arc<tokio::sync::Mutex<DbService>>,
-    chaos_failure_rate: Arc<AtomicU32>,
+    chaos: ChaosControl,
+    admin: AdminRegistry,
 }
 
 impl AppState {
     fn new() -> Self {
         let db: Arc<RwLock<HashMap<String, Bytes>>> = Arc::new(RwLock::new(HashMap::new()));
-        let chaos_failure_rate = Arc::new(AtomicU32::new(0));
 
         // Create the database service
         let base_service = DatabaseService {
             db: Arc::clone(&db),
-            chaos_failure_rate: Arc::clone(&chaos_failure_rate),
         };
 
+        // Wrap with chaos injection, starting with no failures. The control
+        // handle lets the admin endpoint change the rate at runtime.
+        let (chaos_layer, chaos) = ChaosLayer::builder()
+            .name("kv-store-chaos")
+            .error_fn(chaos_error as ChaosErrorFn)
+            .build_with_control();
+
         // Wrap with circuit breaker
-        let circuit_breaker_layer = CircuitBreakerLayer::builder()
+        let (circuit_breaker_layer, circuit_breaker_handle) = CircuitBreakerLayer::builder()
             .name("kv-store-db")
             .failure_rate_threshold(0.5)
             .sliding_window_size(10)
@@ -134,14 +135,19 @@ impl AppState {
             .on_call_rejected(|| {
                 tracing::warn!("Circuit breaker rejected call (circuit OPEN)");
             })
-            .build();
+            .build_with_handle();
+
+        // Expose the breaker under /resilience/circuitbreakers/kv-store-db
+        let admin = AdminRegistry::new();
+        admin.register_circuit_breaker("kv-store-db", circuit_breaker_handle);
 
-        let db_service = circuit_breaker_layer.layer(base_service);
+        let db_service = circuit_breaker_layer.layer(chaos_layer.layer(base_service));
 
         Self {
             db,
             db_service: Arc::new(tokio::sync::Mutex::new(db_service)),
-            chaos_failure_rate,
+            chaos,
+            admin,
         }
     }
 }
@@ -164,6 +170,15 @@ async fn main() {
         "  - Set failure rate: POST http://{}/admin/chaos?rate=0.8",
         addr
     );
+    tracing::info!("Circuit breaker admin:");
+    tracing::info!(
+        "  - State and metrics: GET http://{}/resilience/circuitbreakers",
+        addr
+    );
+    tracing::info!(
+        "  - Trip or reset: POST http://{}/resilience/circuitbreakers/kv-store-db/force_open (or /reset)",
+        addr
+    );
     tracing::info!("");
     tracing::info!("Try it:");
     tracing::info!("  curl -X POST http://{}/mykey -d 'hello world'", addr);
@@ -178,6 +193,7 @@ async fn main() {
 
 fn app() -> Router {
     let state = AppState::new();
+    let admin = state.admin.router();
 
     Router::new()
         .route("/:key", get(get_key).post(set_key))
@@ -186,6 +202,7 @@ fn app() -> Router {
         .route("/metrics", get(get_metrics))
         .route("/admin/chaos", post(set_chaos_rate))
         .with_state(state)
+        .merge(admin)
 }
 
 /// Get a value from the store (goes through circuit breaker with chaos)
@@ -272,8 +289,7 @@ async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
         (metrics, health, circuit_state, http_status)
     }; // MutexGuard dropped here
 
-    let rate_bits = state.chaos_failure_rate.load(Ordering::Relaxed);
-    let chaos_rate = f64::from_bits(rate_bits as u64);
+    let chaos_rate = state.chaos.error_rate().unwrap_or(0.0);
     let keys_count = state.db.read().unwrap().len();
 
     Json(serde_json::json!({
@@ -314,9 +330,7 @@ async fn set_chaos_rate(
     Query(params): Query<ChaosParams>,
 ) -> impl IntoResponse {
     let rate = params.rate.clamp(0.0, 1.0);
-    state
-        .chaos_failure_rate
-        .store(rate.to_bits() as u32, Ordering::Relaxed);
+    state.chaos.set_error_rate(rate);
 
     tracing::info!("Chaos failure rate set to {:.1}%", rate * 100.0);
 
@@ -77,6 +77,9 @@ async fn main() {
             HedgeEvent::HedgeStarted { attempt, delay, .. } => {
                 println!("[Event] Hedge #{} started after {:?}", attempt, delay);
             }
+            HedgeEvent::HedgeSuppressed { attempt, .. } => {
+                println!("[Event] Hedge #{} suppressed by budget", attempt);
+            }
             HedgeEvent::PrimarySucceeded {
                 duration,
                 hedges_cancelled,
@@ -95,6 +98,12 @@ async fn main() {
             HedgeEvent::AllFailed { attempts, .. } => {
                 println!("[Event] All {} attempts failed", attempts);
             }
+            HedgeEvent::HedgingDisabled { .. } => {
+                println!("[Event] Backend saturated, hedging disabled");
+            }
+            HedgeEvent::HedgingEnabled { .. } => {
+                println!("[Event] Backend recovered, hedging enabled");
+            }
         }))
         .build();
 
@@ -67,45 +67,49 @@ async fn main() {
     println!("\n📈 Metrics that would be available in Prometheus:");
     println!("   Circuit Breaker:");
     println!(
-        "     - circuitbreaker_calls_total{{circuitbreaker=\"user-service\",outcome=\"success|failure|rejected\"}}"
+        "     - resilience_circuitbreaker_calls_total{{name=\"user-service\",outcome=\"success|failure|rejected\"}}"
     );
     println!(
-        "     - circuitbreaker_transitions_total{{circuitbreaker=\"user-service\",from=\"Closed\",to=\"Open\"}}"
+        "     - resilience_circuitbreaker_transitions_total{{name=\"user-service\",from=\"Closed\",to=\"Open\"}}"
     );
     println!(
-        "     - circuitbreaker_state{{circuitbreaker=\"user-service\",state=\"Open|Closed|HalfOpen\"}}"
+        "     - resilience_circuitbreaker_state{{name=\"user-service\",state=\"Open|Closed|HalfOpen\"}}"
     );
     println!("   Retry:");
-    println!("     - retry_calls_total{{retry=\"api-retry\",result=\"success|exhausted\"}}");
-    println!("     - retry_attempts_total{{retry=\"api-retry\"}}");
+    println!(
+        "     - resilience_retry_calls_total{{name=\"api-retry\",result=\"success|exhausted\"}}"
+    );
+    println!("     - resilience_retry_attempts_total{{name=\"api-retry\"}}");
     println!("   Bulkhead:");
-    println!("     - bulkhead_calls_permitted_total{{bulkhead=\"payment-bulkhead\"}}");
-    println!("     - bulkhead_concurrent_calls{{bulkhead=\"payment-bulkhead\"}}");
+    println!("     - resilience_bulkhead_calls_permitted_total{{name=\"payment-bulkhead\"}}");
+    println!("     - resilience_bulkhead_concurrent_calls{{name=\"payment-bulkhead\"}}");
     println!("   Rate Limiter:");
     println!(
-        "     - ratelimiter_calls_total{{ratelimiter=\"payment-ratelimit\",result=\"permitted|rejected\"}}"
+        "     - resilience_ratelimiter_calls_total{{name=\"payment-ratelimit\",result=\"permitted|rejected\"}}"
     );
     println!("   Time Limiter:");
     println!(
-        "     - timelimiter_calls_total{{timelimiter=\"api-timeout\",result=\"success|timeout\"}}"
+        "     - resilience_timelimiter_calls_total{{name=\"api-timeout\",result=\"success|timeout\"}}"
     );
     println!("   Cache:");
-    println!("     - cache_requests_total{{cache=\"data-cache\",result=\"hit|miss\"}}");
-    println!("     - cache_size{{cache=\"data-cache\"}}");
+    println!("     - resilience_cache_requests_total{{name=\"data-cache\",result=\"hit|miss\"}}");
+    println!("     - resilience_cache_size{{name=\"data-cache\"}}");
     println!("\n📊 Example Prometheus Queries:");
     println!("   Failure Rate:");
     println!(
-        "     rate(circuitbreaker_calls_total{{outcome=\"failure\"}}[5m]) / rate(circuitbreaker_calls_total[5m])"
+        "     rate(resilience_circuitbreaker_calls_total{{outcome=\"failure\"}}[5m]) / rate(resilience_circuitbreaker_calls_total[5m])"
     );
     println!("   Retry Attempts per Call:");
-    println!("     rate(retry_attempts_total[5m]) / rate(retry_calls_total[5m])");
+    println!(
+        "     rate(resilience_retry_attempts_total[5m]) / rate(resilience_retry_calls_total[5m])"
+    );
     println!("   Cache Hit Rate:");
     println!(
-        "     rate(cache_requests_total{{result=\"hit\"}}[5m]) / rate(cache_requests_total[5m])"
+        "     rate(resilience_cache_requests_total{{result=\"hit\"}}[5m]) / rate(resilience_cache_requests_total[5m])"
     );
     println!("   P95 Latency:");
     println!(
-        "     histogram_quantile(0.95, rate(circuitbreaker_call_duration_seconds_bucket[5m]))"
+        "     histogram_quantile(0.95, rate(resilience_circuitbreaker_call_duration_seconds_bucket[5m]))"
     );
 }
 
@@ -5,7 +5,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tower::{Service, ServiceBuilder, ServiceExt};
 use tower_resilience_adaptive::{
-    AdaptiveLimiterLayer, Aimd, Algorithm, ConcurrencyAlgorithm, Vegas,
+    AdaptiveLimiterLayer, Aimd, Algorithm, ConcurrencyAlgorithm, Gradient2, Vegas,
 };
 
 #[tokio::test]
@@ -212,6 +212,43 @@ async fn test_algorithm_enum_vegas() {
     assert_eq!(response, 42);
 }
 
+#[tokio::test]
+async fn test_gradient2_basic_operation() {
+    let service = tower::service_fn(|req: i32| async move {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        Ok::<_, &str>(req * 2)
+    });
+
+    let mut service = ServiceBuilder::new()
+        .layer(AdaptiveLimiterLayer::new(
+            Gradient2::builder()
+                .initial_limit(20)
+                .max_limit(100)
+                .build(),
+        ))
+        .service(service);
+
+    for i in 0..20 {
+        let response = service.ready().await.unwrap().call(i).await.unwrap();
+        assert_eq!(response, i * 2);
+    }
+}
+
+#[tokio::test]
+async fn test_algorithm_enum_gradient2() {
+    let service = tower::service_fn(|req: i32| async move { Ok::<_, &str>(req) });
+
+    let algorithm = Algorithm::Gradient2(Gradient2::builder().initial_limit(10).build());
+    assert_eq!(algorithm.limit(), 10);
+
+    let mut service = ServiceBuilder::new()
+        .layer(AdaptiveLimiterLayer::new(algorithm))
+        .service(service);
+
+    let response = service.ready().await.unwrap().call(42).await.unwrap();
+    assert_eq!(response, 42);
+}
+
 #[tokio::test]
 async fn test_aimd_slow_response_decreases_limit() {
     let service = tower::service_fn(|_req: ()| async {
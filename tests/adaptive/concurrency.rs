@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tower::{Service, ServiceBuilder, ServiceExt};
-use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd, Vegas};
+use tower_resilience_adaptive::{AdaptiveError, AdaptiveLimiterLayer, Aimd, LimitPolicy, Vegas};
 
 #[tokio::test]
 async fn test_concurrent_requests_within_limit() {
@@ -279,3 +279,181 @@ async fn test_limit_adapts_under_varying_load() {
 
     assert_eq!(call_count.load(Ordering::SeqCst), 25);
 }
+
+#[tokio::test]
+async fn test_reject_policy_fails_fast_at_limit() {
+    let service = tower::service_fn(|_req: ()| async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok::<_, &str>(())
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            AdaptiveLimiterLayer::new(
+                Aimd::builder()
+                    .initial_limit(2)
+                    .min_limit(1)
+                    .latency_threshold(Duration::from_secs(1))
+                    .build(),
+            )
+            .on_limit(LimitPolicy::Reject),
+        )
+        .service(service);
+
+    let mut handles = vec![];
+    for _ in 0..5 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(()).await
+        }));
+    }
+
+    let mut ok = 0;
+    let mut rejected = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(()) => ok += 1,
+            Err(AdaptiveError::LimitExceeded) => rejected += 1,
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+
+    assert_eq!(ok, 2);
+    assert_eq!(rejected, 3);
+}
+
+#[tokio::test]
+async fn test_queue_policy_waits_for_slot() {
+    let service = tower::service_fn(|_req: ()| async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok::<_, &str>(())
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            AdaptiveLimiterLayer::new(
+                Aimd::builder()
+                    .initial_limit(1)
+                    .min_limit(1)
+                    .max_limit(1)
+                    .latency_threshold(Duration::from_secs(1))
+                    .build(),
+            )
+            .on_limit(LimitPolicy::Queue {
+                max_wait: Duration::from_secs(1),
+                max_queue: 10,
+            }),
+        )
+        .service(service);
+
+    let mut handles = vec![];
+    for _ in 0..3 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(()).await
+        }));
+    }
+
+    // Queued requests run one at a time as slots free up
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+}
+
+#[tokio::test]
+async fn test_queue_policy_times_out_and_caps_queue() {
+    let timeouts = Arc::new(AtomicUsize::new(0));
+    let rejections = Arc::new(AtomicUsize::new(0));
+    let t = Arc::clone(&timeouts);
+    let r = Arc::clone(&rejections);
+
+    let service = tower::service_fn(|_req: ()| async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        Ok::<_, &str>(())
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            AdaptiveLimiterLayer::new(
+                Aimd::builder()
+                    .initial_limit(1)
+                    .min_limit(1)
+                    .max_limit(1)
+                    .latency_threshold(Duration::from_secs(1))
+                    .build(),
+            )
+            .on_limit(LimitPolicy::Queue {
+                max_wait: Duration::from_millis(20),
+                max_queue: 1,
+            })
+            .on_queue_timeout(move |_waited| {
+                t.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_call_rejected(move || {
+                r.fetch_add(1, Ordering::SeqCst);
+            }),
+        )
+        .service(service);
+
+    // One runs, one queues and times out, one finds the queue full
+    let mut running = service.clone();
+    let mut queued = service.clone();
+    let mut overflow = service.clone();
+
+    let running = running.ready().await.unwrap().call(());
+    let queued = queued.ready().await.unwrap().call(());
+    let overflow = overflow.ready().await.unwrap().call(());
+
+    let (running, queued, overflow) = tokio::join!(running, queued, overflow);
+
+    assert!(running.is_ok());
+    assert!(matches!(queued, Err(AdaptiveError::LimitExceeded)));
+    assert!(matches!(overflow, Err(AdaptiveError::LimitExceeded)));
+    assert_eq!(timeouts.load(Ordering::SeqCst), 1);
+    assert_eq!(rejections.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_queued_request_calls_inner_only_with_slot() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+
+    // Counts calls as they are made, before the returned future is polled
+    let service = tower::service_fn(move |_req: ()| {
+        c.fetch_add(1, Ordering::SeqCst);
+        async {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok::<_, &str>(())
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            AdaptiveLimiterLayer::new(
+                Aimd::builder()
+                    .initial_limit(1)
+                    .min_limit(1)
+                    .max_limit(1)
+                    .latency_threshold(Duration::from_secs(1))
+                    .build(),
+            )
+            .on_limit(LimitPolicy::Queue {
+                max_wait: Duration::from_millis(20),
+                max_queue: 1,
+            }),
+        )
+        .service(service);
+
+    let mut running = service.clone();
+    let mut queued = service.clone();
+
+    let running = running.ready().await.unwrap().call(());
+    let queued = queued.ready().await.unwrap().call(());
+
+    let (running, queued) = tokio::join!(running, queued);
+
+    assert!(running.is_ok());
+    assert!(matches!(queued, Err(AdaptiveError::LimitExceeded)));
+    // The queued request never got a slot, so the inner service never saw it
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -167,3 +167,132 @@ async fn test_layer_builder() {
     let response = service.ready().await.unwrap().call(42).await.unwrap();
     assert_eq!(response, 42);
 }
+
+#[tokio::test]
+async fn test_limit_change_and_call_events() {
+    let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let permitted = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&changes);
+    let p = Arc::clone(&permitted);
+
+    let service =
+        tower::service_fn(|fail: bool| async move { if fail { Err("boom") } else { Ok(()) } });
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            AdaptiveLimiterLayer::new(
+                Aimd::builder()
+                    .initial_limit(10)
+                    .min_limit(1)
+                    .decrease_factor(0.5)
+                    .latency_threshold(Duration::from_secs(1))
+                    .build(),
+            )
+            .name("events")
+            .on_limit_changed(move |from, to| c.lock().unwrap().push((from, to)))
+            .on_call_permitted(move || {
+                p.fetch_add(1, Ordering::SeqCst);
+            }),
+        )
+        .service(service);
+
+    let _ = service.ready().await.unwrap().call(true).await;
+    let _ = service.ready().await.unwrap().call(false).await;
+
+    assert_eq!(permitted.load(Ordering::SeqCst), 2);
+
+    // The failure halves the limit, the success nudges it back up
+    let changes = changes.lock().unwrap();
+    assert_eq!(changes.first(), Some(&(10, 5)));
+    assert_eq!(changes.get(1), Some(&(5, 6)));
+}
+
+#[tokio::test]
+async fn test_congestion_classifier_ignores_non_overload_errors() {
+    let service =
+        tower::service_fn(
+            |status: u16| async move { if status == 200 { Ok(()) } else { Err(status) } },
+        );
+
+    let algorithm = Aimd::builder()
+        .initial_limit(10)
+        .increase_by(1)
+        .decrease_factor(0.5)
+        .latency_threshold(Duration::from_secs(1))
+        .build();
+    let layer = AdaptiveLimiterLayer::new(algorithm)
+        .congestion_classifier(|result: &Result<(), u16>| matches!(result, Err(503)));
+    let mut service = layer.layer(service);
+
+    // A 404 is not overload and counts as an ordinary sample
+    let _ = service.ready().await.unwrap().call(404).await;
+    assert_eq!(service.limit(), 11);
+
+    // A 503 is overload and halves the limit
+    let _ = service.ready().await.unwrap().call(503).await;
+    assert_eq!(service.limit(), 5);
+}
+
+#[tokio::test]
+async fn test_control_pins_and_freezes_limit() {
+    let service = tower::service_fn(|_req: ()| async { Err::<(), _>("overloaded") });
+
+    let layer = AdaptiveLimiterLayer::new(
+        Aimd::builder()
+            .initial_limit(10)
+            .decrease_factor(0.5)
+            .build(),
+    );
+    let control = layer.control();
+    let mut service = layer.layer(service);
+    assert_eq!(control.current_limit(), 10);
+
+    // A pinned limit takes effect and failures no longer shrink it
+    control.set_limit(4);
+    let _ = service.ready().await.unwrap().call(()).await;
+    assert_eq!(service.limit(), 4);
+    assert_eq!(control.current_limit(), 4);
+
+    // Unfreezing hands control back to the untouched algorithm
+    control.unfreeze();
+    let _ = service.ready().await.unwrap().call(()).await;
+    assert_eq!(service.limit(), 5);
+    assert_eq!(control.current_limit(), 5);
+}
+
+#[tokio::test]
+async fn test_load_reports_demand_relative_to_limit() {
+    use tower::load::Load;
+
+    let service = tower::service_fn(|_req: ()| async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok::<_, &str>(())
+    });
+
+    let service = AdaptiveLimiterLayer::new(
+        Aimd::builder()
+            .initial_limit(4)
+            .latency_threshold(Duration::from_secs(1))
+            .build(),
+    )
+    .layer(service);
+
+    assert_eq!(service.load(), 0.0);
+
+    let mut handles = Vec::new();
+    for _ in 0..2 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(()).await
+        }));
+    }
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    // Two of four slots are in use
+    assert_eq!(service.load(), 0.5);
+
+    for handle in handles {
+        handle.await.unwrap().unwrap();
+    }
+    assert_eq!(service.load(), 0.0);
+}
@@ -3,7 +3,7 @@
 //! This test suite provides coverage for the adaptive concurrency limiter:
 //!
 //! - **integration**: Basic integration tests verifying core functionality
-//! - **algorithms**: Tests for AIMD and Vegas algorithms
+//! - **algorithms**: Tests for AIMD, Vegas and Gradient2 algorithms
 //! - **concurrency**: Tests for concurrent request handling
 
 mod algorithms;
@@ -0,0 +1,4 @@
+//! Admin router integration tests.
+
+#[path = "admin/mod.rs"]
+mod admin;
@@ -0,0 +1,6 @@
+//! Integration tests for the admin endpoints.
+//!
+//! Test organization:
+//! - router.rs: Reading state and applying controls over HTTP
+
+mod router;
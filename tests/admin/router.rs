@@ -0,0 +1,137 @@
+//! The admin router reads and controls registered instances.
+
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode};
+use serde_json::Value;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience::admin::AdminRegistry;
+use tower_resilience::bulkhead::BulkheadLayer;
+use tower_resilience::circuitbreaker::{CircuitBreakerLayer, CircuitState};
+use tower_resilience::ratelimiter::RateLimiterLayer;
+
+async fn send(
+    router: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<&str>,
+) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(body.map_or_else(Body::empty, |b| Body::from(b.to_string())))
+        .unwrap();
+    let response = router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn circuit_breaker_state_and_controls() {
+    let (layer, handle) = CircuitBreakerLayer::builder().build_with_handle();
+    let mut service = layer.layer(tower::service_fn(|_req: ()| async {
+        Ok::<_, std::io::Error>(())
+    }));
+
+    let admin = AdminRegistry::new();
+    admin.register_circuit_breaker("payments", handle.clone());
+    let router = admin.router();
+
+    let (status, body) = send(&router, "GET", "/resilience/circuitbreakers", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["payments"]["state"], "closed");
+
+    let (status, body) = send(
+        &router,
+        "POST",
+        "/resilience/circuitbreakers/payments/force_open",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["state"], "open");
+    assert_eq!(handle.state(), CircuitState::Open);
+    assert!(service.ready().await.unwrap().call(()).await.is_err());
+
+    let (_, body) = send(
+        &router,
+        "POST",
+        "/resilience/circuitbreakers/payments/reset",
+        None,
+    )
+    .await;
+    assert_eq!(body["state"], "closed");
+    assert!(service.ready().await.unwrap().call(()).await.is_ok());
+}
+
+#[tokio::test]
+async fn limits_can_be_changed() {
+    let (_, limiter) = RateLimiterLayer::builder()
+        .limit_for_period(10)
+        .build_with_handle();
+    let (_, bulkhead) = BulkheadLayer::builder()
+        .max_concurrent_calls(5)
+        .build_with_handle();
+
+    let admin = AdminRegistry::new();
+    admin.register_rate_limiter("api", limiter.clone());
+    admin.register_bulkhead("db", bulkhead.clone());
+    let router = admin.router();
+
+    let (status, body) = send(
+        &router,
+        "POST",
+        "/resilience/ratelimiters/api/set_limit",
+        Some(r#"{"limit": 50}"#),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["limit_for_period"], 50);
+    assert_eq!(limiter.limit_for_period(), 50);
+
+    let (status, body) = send(
+        &router,
+        "POST",
+        "/resilience/bulkheads/db/set_limit",
+        Some(r#"{"limit": 8}"#),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["max_concurrent_calls"], 8);
+    assert_eq!(bulkhead.max_concurrent(), 8);
+
+    let (status, _) = send(
+        &router,
+        "POST",
+        "/resilience/bulkheads/db/set_limit",
+        Some(r#"{"limit": "lots"}"#),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&router, "GET", "/resilience", None).await;
+    assert_eq!(body["ratelimiters"][0], "api");
+    assert_eq!(body["bulkheads"][0], "db");
+}
+
+#[tokio::test]
+async fn unknown_names_are_not_found() {
+    let router = AdminRegistry::new().router();
+
+    let (status, body) = send(&router, "GET", "/resilience/circuitbreakers/missing", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body["error"].as_str().unwrap().contains("missing"));
+
+    let (status, _) = send(
+        &router,
+        "POST",
+        "/resilience/ratelimiters/missing/set_limit",
+        Some(r#"{"limit": 1}"#),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -5,7 +5,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tokio::time::sleep;
 use tower::{Service, ServiceBuilder, ServiceExt};
-use tower_resilience_bulkhead::{BulkheadError, BulkheadLayer, BulkheadServiceError};
+use tower_resilience_bulkhead::{BulkheadError, BulkheadLayer, BulkheadServiceError, Priority};
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -88,6 +88,82 @@ async fn test_bulkhead_rejects_when_full_with_timeout() {
     handle2.await.unwrap().unwrap();
 }
 
+#[tokio::test]
+async fn test_bulkhead_reserves_capacity_for_priority() {
+    let service = ServiceBuilder::new()
+        .layer(
+            BulkheadLayer::builder()
+                .max_concurrent_calls(2)
+                .max_wait_duration(Duration::from_millis(20))
+                .reserve(Priority::Critical, 0.5)
+                .priority_fn(|req: &Priority| *req)
+                .build(),
+        )
+        .service_fn(|_req: Priority| async {
+            sleep(Duration::from_millis(100)).await;
+            Ok::<_, TestError>(())
+        });
+
+    // A low-priority request takes the only unreserved permit
+    let mut svc1 = service.clone();
+    let handle1 = tokio::spawn(async move { svc1.ready().await?.call(Priority::Low).await });
+    sleep(Duration::from_millis(10)).await;
+
+    // Another one may not take the reserved permit
+    let mut svc2 = service.clone();
+    let result = svc2.ready().await.unwrap().call(Priority::Normal).await;
+    assert!(matches!(
+        result,
+        Err(BulkheadServiceError::Bulkhead(BulkheadError::Timeout))
+    ));
+
+    // A critical request can
+    let mut svc3 = service.clone();
+    svc3.ready()
+        .await
+        .unwrap()
+        .call(Priority::Critical)
+        .await
+        .unwrap();
+
+    handle1.await.unwrap().unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_bulkhead_admits_low_priority_waiter_on_release() {
+    let service = ServiceBuilder::new()
+        .layer(
+            BulkheadLayer::builder()
+                .max_concurrent_calls(2)
+                .max_wait_duration(Duration::from_secs(2))
+                .reserve(Priority::Critical, 0.5)
+                .priority_fn(|req: &Priority| *req)
+                .build(),
+        )
+        .service_fn(|_req: Priority| async {
+            sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>(())
+        });
+
+    // A low-priority request takes the only unreserved permit
+    let mut svc1 = service.clone();
+    let handle1 = tokio::spawn(async move { svc1.ready().await?.call(Priority::Low).await });
+    sleep(Duration::from_millis(10)).await;
+
+    // A second one queues, and is admitted as soon as the first finishes
+    let start = std::time::Instant::now();
+    let mut svc2 = service.clone();
+    svc2.ready()
+        .await
+        .unwrap()
+        .call(Priority::Low)
+        .await
+        .unwrap();
+    assert!(start.elapsed() < Duration::from_millis(500));
+
+    handle1.await.unwrap().unwrap();
+}
+
 #[tokio::test]
 async fn test_bulkhead_event_listeners() {
     let permitted_count = Arc::new(AtomicUsize::new(0));
@@ -197,3 +273,35 @@ async fn test_bulkhead_without_timeout_waits() {
 
     handle1.await.unwrap().unwrap();
 }
+
+#[tokio::test]
+async fn test_load_reports_capacity_in_use() {
+    use tower::load::Load;
+
+    let layer = BulkheadLayer::builder().max_concurrent_calls(4).build();
+    let service = ServiceBuilder::new()
+        .layer(layer)
+        .service_fn(|_req: ()| async move {
+            sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>(())
+        });
+
+    assert_eq!(service.load(), 0.0);
+
+    let mut handles = Vec::new();
+    for _ in 0..2 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(()).await
+        }));
+    }
+    sleep(Duration::from_millis(10)).await;
+
+    // Two of four permits are held by in-flight calls
+    assert_eq!(service.load(), 0.5);
+
+    for handle in handles {
+        handle.await.unwrap().unwrap();
+    }
+    assert_eq!(service.load(), 0.0);
+}
@@ -467,3 +467,105 @@ async fn test_starvation_prevention() {
         assert!(result.is_ok(), "Request should not be starved");
     }
 }
+
+#[tokio::test]
+async fn test_permit_held_too_long_reported() {
+    let overruns = Arc::new(AtomicUsize::new(0));
+    let o = overruns.clone();
+
+    let layer = BulkheadLayer::builder()
+        .max_concurrent_calls(1)
+        .max_permit_hold(Duration::from_millis(20))
+        .on_permit_held_too_long(move |held| {
+            assert!(held >= Duration::from_millis(20));
+            o.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let mut service = ServiceBuilder::new()
+        .layer(layer)
+        .service_fn(|delay: u64| async move {
+            sleep(Duration::from_millis(delay)).await;
+            Ok::<_, TestError>(delay)
+        });
+
+    // A fast call stays under the threshold
+    let result = service.ready().await.unwrap().call(1).await;
+    assert_eq!(result.unwrap(), 1);
+    assert_eq!(overruns.load(Ordering::SeqCst), 0);
+
+    // A slow call is reported once but still completes
+    let result = service.ready().await.unwrap().call(60).await;
+    assert_eq!(result.unwrap(), 60);
+    assert_eq!(overruns.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_release_held_permits_frees_slot() {
+    let layer = BulkheadLayer::builder()
+        .max_concurrent_calls(1)
+        .max_wait_duration(Duration::from_millis(100))
+        .max_permit_hold(Duration::from_millis(20))
+        .release_held_permits()
+        .build();
+
+    let service = ServiceBuilder::new()
+        .layer(layer)
+        .service_fn(|delay: u64| async move {
+            sleep(Duration::from_millis(delay)).await;
+            Ok::<_, TestError>(delay)
+        });
+
+    // Occupy the only slot with a call that overruns the hold limit
+    let mut slow = service.clone();
+    let handle = tokio::spawn(async move { slow.ready().await.unwrap().call(300).await });
+    sleep(Duration::from_millis(5)).await;
+
+    // Once the slow call's permit is released, another call gets through
+    // well before the slow call finishes
+    let mut fast = service.clone();
+    let result = fast.ready().await.unwrap().call(1).await;
+    assert_eq!(result.unwrap(), 1);
+    assert!(!handle.is_finished());
+
+    assert_eq!(handle.await.unwrap().unwrap(), 300);
+}
+
+#[tokio::test]
+async fn test_waiter_starved_reported_before_timeout() {
+    let starved = Arc::new(AtomicUsize::new(0));
+    let s = starved.clone();
+
+    let layer = BulkheadLayer::builder()
+        .max_concurrent_calls(1)
+        .max_wait_duration(Duration::from_millis(200))
+        .starvation_threshold(Duration::from_millis(20))
+        .on_waiter_starved(move |waited| {
+            assert!(waited >= Duration::from_millis(20));
+            s.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let service = ServiceBuilder::new()
+        .layer(layer)
+        .service_fn(|delay: u64| async move {
+            sleep(Duration::from_millis(delay)).await;
+            Ok::<_, TestError>(delay)
+        });
+
+    // A call that gets a permit straight away is not reported
+    let mut first = service.clone();
+    assert_eq!(first.ready().await.unwrap().call(1).await.unwrap(), 1);
+    assert_eq!(starved.load(Ordering::SeqCst), 0);
+
+    // A call queued behind a slow one is reported once, then still permitted
+    let mut slow = service.clone();
+    let handle = tokio::spawn(async move { slow.ready().await.unwrap().call(60).await });
+    sleep(Duration::from_millis(5)).await;
+
+    let mut waiter = service.clone();
+    assert_eq!(waiter.ready().await.unwrap().call(1).await.unwrap(), 1);
+    assert_eq!(starved.load(Ordering::SeqCst), 1);
+
+    assert_eq!(handle.await.unwrap().unwrap(), 60);
+}
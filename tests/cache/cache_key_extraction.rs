@@ -7,13 +7,14 @@
 //! - Hash-based key extraction
 //! - Simple type key extraction
 //! - Key extraction consistency
+//! - Composite keys with `vary_by`
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use tower::{Layer, Service, ServiceExt};
-use tower_resilience_cache::CacheLayer;
+use tower_resilience_cache::{CacheLayer, VaryKey};
 
 #[derive(Clone, Debug)]
 struct ComplexRequest {
@@ -407,3 +408,64 @@ async fn key_extraction_consistency() {
     service.ready().await.unwrap().call(req4).await.unwrap();
     assert_eq!(call_count.load(Ordering::SeqCst), 3); // No new call
 }
+
+#[tokio::test]
+async fn vary_by_composes_key_parts() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: ComplexRequest| {
+        let cc = Arc::clone(&cc);
+        async move {
+            cc.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, std::io::Error>(req.action)
+        }
+    });
+
+    let layer = CacheLayer::<ComplexRequest, VaryKey>::builder()
+        .max_size(10)
+        .vary_by(|req| req.user_id)
+        .vary_by(|req| req.action.clone())
+        .build()
+        .unwrap();
+    let mut service = layer.layer(service);
+
+    let request = |user_id, action: &str| ComplexRequest {
+        user_id,
+        resource_id: 0,
+        action: action.to_string(),
+    };
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(1, "read"))
+        .await
+        .unwrap();
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(1, "read"))
+        .await
+        .unwrap();
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+    // Any differing part is a different entry
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(2, "read"))
+        .await
+        .unwrap();
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(1, "write"))
+        .await
+        .unwrap();
+    assert_eq!(call_count.load(Ordering::SeqCst), 3);
+}
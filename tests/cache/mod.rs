@@ -6,9 +6,15 @@
 //! - cache_key_extraction.rs: Key extraction scenarios
 //! - cache_layer.rs: Layer composition and Tower integration
 //! - eviction_policies.rs: Eviction policy behavior comparison
+//! - panic_safety.rs: Recovery from panics in user code
+//! - refresh_ahead.rs: Background refresh of entries near expiry
+//! - warm_start.rs: Exporting and importing cache contents
 
 mod cache_concurrency;
 mod cache_edge_cases;
 mod cache_key_extraction;
 mod cache_layer;
 mod eviction_policies;
+mod panic_safety;
+mod refresh_ahead;
+mod warm_start;
@@ -0,0 +1,101 @@
+//! Panic safety tests for the cache.
+//!
+//! A panic in user code called by the cache must only fail the request that
+//! triggered it, never the requests that follow.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_cache::CacheLayer;
+
+#[tokio::test]
+async fn panicking_key_extractor_does_not_brick_layer() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let layer = CacheLayer::builder()
+        .max_size(10)
+        .key_extractor(|req: &String| {
+            assert_ne!(req, "bad", "cannot extract a key");
+            req.clone()
+        })
+        .build()
+        .unwrap();
+
+    let service = layer.layer(tower::service_fn(move |req: String| {
+        cc.fetch_add(1, Ordering::SeqCst);
+        async move { Ok::<_, std::io::Error>(req.to_uppercase()) }
+    }));
+
+    let mut warm = service.clone();
+    let response = warm.ready().await.unwrap().call("a".to_string()).await;
+    assert_eq!(response.unwrap(), "A");
+
+    let mut bad = service.clone();
+    let panicked =
+        tokio::spawn(async move { bad.ready().await.unwrap().call("bad".to_string()).await }).await;
+    assert!(panicked.unwrap_err().is_panic());
+
+    // The cached entry survives and new requests still work
+    let mut after = service.clone();
+    let response = after.ready().await.unwrap().call("a".to_string()).await;
+    assert_eq!(response.unwrap(), "A");
+    let response = after.ready().await.unwrap().call("b".to_string()).await;
+    assert_eq!(response.unwrap(), "B");
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+/// A response whose `Clone` panics while `PANIC_ON_CLONE` is set.
+#[derive(Debug, PartialEq)]
+struct Fragile(u32);
+
+static PANIC_ON_CLONE: AtomicBool = AtomicBool::new(false);
+
+impl Clone for Fragile {
+    fn clone(&self) -> Self {
+        assert!(!PANIC_ON_CLONE.load(Ordering::SeqCst), "clone failed");
+        Fragile(self.0)
+    }
+}
+
+#[tokio::test]
+async fn panic_while_store_locked_empties_cache() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let layer = CacheLayer::builder()
+        .max_size(10)
+        .key_extractor(|req: &u32| *req)
+        .build()
+        .unwrap();
+
+    let service = layer.layer(tower::service_fn(move |req: u32| {
+        cc.fetch_add(1, Ordering::SeqCst);
+        async move { Ok::<_, std::io::Error>(Fragile(req)) }
+    }));
+
+    let mut warm = service.clone();
+    assert_eq!(
+        warm.ready().await.unwrap().call(1).await.unwrap(),
+        Fragile(1)
+    );
+
+    // A cache hit clones the entry while holding the store lock
+    PANIC_ON_CLONE.store(true, Ordering::SeqCst);
+    let mut hit = service.clone();
+    let panicked = tokio::spawn(async move { hit.ready().await.unwrap().call(1).await }).await;
+    assert!(panicked.unwrap_err().is_panic());
+    PANIC_ON_CLONE.store(false, Ordering::SeqCst);
+
+    // The poisoned store is rebuilt empty and keeps caching
+    let mut after = service.clone();
+    assert_eq!(
+        after.ready().await.unwrap().call(1).await.unwrap(),
+        Fragile(1)
+    );
+    assert_eq!(
+        after.ready().await.unwrap().call(1).await.unwrap(),
+        Fragile(1)
+    );
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,148 @@
+//! Refresh-ahead tests for the cache.
+//!
+//! Tests background refresh of entries close to expiry, including:
+//! - Hits early in the TTL do not refresh
+//! - A hit late in the TTL serves the cached value and refreshes it
+//! - Only one refresh per entry is in flight
+//! - A failed refresh is retried on the next hit
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_cache::CacheLayer;
+use tower_resilience_core::MockClock;
+
+fn counting_service(
+    calls: Arc<AtomicUsize>,
+) -> impl Service<String, Response = String, Error = std::io::Error, Future: Send> + Clone {
+    tower::service_fn(move |req: String| {
+        let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+        async move {
+            if req == "fail" && n > 1 {
+                return Err(std::io::Error::other("refresh failed"));
+            }
+            Ok(format!("{}-v{}", req, n))
+        }
+    })
+}
+
+/// Lets spawned refresh tasks run to completion.
+async fn settle() {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[tokio::test]
+async fn refresh_ahead_replaces_entry_before_expiry() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let refreshes = Arc::new(AtomicUsize::new(0));
+    let r = Arc::clone(&refreshes);
+    let clock = MockClock::new();
+
+    let layer = CacheLayer::builder()
+        .ttl(Duration::from_secs(100))
+        .refresh_ahead(0.2)
+        .clock(clock.clone())
+        .key_extractor(|req: &String| req.clone())
+        .on_refresh(move || {
+            r.fetch_add(1, Ordering::SeqCst);
+        })
+        .build()
+        .unwrap();
+    let mut service = layer.layer(counting_service(Arc::clone(&calls)));
+
+    let first = service.ready().await.unwrap().call("key".to_string()).await;
+    assert_eq!(first.unwrap(), "key-v1");
+
+    // Early in the TTL: plain hit
+    clock.advance(Duration::from_secs(50));
+    let hit = service.ready().await.unwrap().call("key".to_string()).await;
+    assert_eq!(hit.unwrap(), "key-v1");
+    settle().await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Late in the TTL: the cached value is served and refreshed
+    clock.advance(Duration::from_secs(35));
+    let hit = service.ready().await.unwrap().call("key".to_string()).await;
+    assert_eq!(hit.unwrap(), "key-v1");
+    settle().await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+    // The refreshed entry outlives the original TTL
+    clock.advance(Duration::from_secs(30));
+    let hit = service.ready().await.unwrap().call("key".to_string()).await;
+    assert_eq!(hit.unwrap(), "key-v2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn refresh_ahead_refreshes_once_while_in_flight() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let clock = MockClock::new();
+
+    let layer = CacheLayer::builder()
+        .ttl(Duration::from_secs(100))
+        .refresh_ahead(0.5)
+        .clock(clock.clone())
+        .key_extractor(|req: &String| req.clone())
+        .build()
+        .unwrap();
+    let mut service = layer.layer(counting_service(Arc::clone(&calls)));
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("key".to_string())
+        .await
+        .unwrap();
+    clock.advance(Duration::from_secs(60));
+
+    // Several hits before the refresh task runs trigger a single refresh
+    for _ in 0..5 {
+        let hit = service.ready().await.unwrap().call("key".to_string()).await;
+        assert_eq!(hit.unwrap(), "key-v1");
+    }
+    settle().await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn refresh_ahead_retries_after_failure() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let clock = MockClock::new();
+
+    let layer = CacheLayer::builder()
+        .ttl(Duration::from_secs(100))
+        .refresh_ahead(0.5)
+        .clock(clock.clone())
+        .key_extractor(|req: &String| req.clone())
+        .build()
+        .unwrap();
+    let mut service = layer.layer(counting_service(Arc::clone(&calls)));
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("fail".to_string())
+        .await
+        .unwrap();
+    clock.advance(Duration::from_secs(60));
+
+    // The failed refresh keeps the cached value and frees the next hit to retry
+    for expected_calls in [2, 3] {
+        let hit = service
+            .ready()
+            .await
+            .unwrap()
+            .call("fail".to_string())
+            .await;
+        assert_eq!(hit.unwrap(), "fail-v1");
+        settle().await;
+        assert_eq!(calls.load(Ordering::SeqCst), expected_calls);
+    }
+}
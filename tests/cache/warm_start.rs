@@ -0,0 +1,101 @@
+//! Warm start tests for the cache.
+//!
+//! Tests snapshotting cache contents and reloading them, including:
+//! - Imported entries are served without calling the inner service
+//! - A snapshot taken from a service restores into a shared layer
+//! - Imported entries respect max_size
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_cache::{CacheLayer, SharedCacheLayer};
+
+fn counting_service(
+    calls: Arc<AtomicUsize>,
+) -> impl Service<String, Response = String, Error = std::io::Error, Future: Send> + Clone {
+    tower::service_fn(move |req: String| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        async move { Ok(format!("response-{}", req)) }
+    })
+}
+
+#[tokio::test]
+async fn snapshot_restores_into_new_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let layer = CacheLayer::builder()
+        .max_size(10)
+        .key_extractor(|req: &String| req.clone())
+        .build()
+        .unwrap();
+
+    let mut before = layer.layer(counting_service(Arc::clone(&calls)));
+    for key in ["a", "b"] {
+        before
+            .ready()
+            .await
+            .unwrap()
+            .call(key.to_string())
+            .await
+            .unwrap();
+    }
+    let snapshot = before.export();
+    assert_eq!(snapshot.len(), 2);
+
+    // Simulate a restart: a fresh cache loaded from the snapshot
+    let mut after = layer.layer(counting_service(Arc::clone(&calls)));
+    after.import(snapshot);
+    for key in ["a", "b"] {
+        let response = after
+            .ready()
+            .await
+            .unwrap()
+            .call(key.to_string())
+            .await
+            .unwrap();
+        assert_eq!(response, format!("response-{}", key));
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn shared_layer_import_is_visible_to_all_services() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let layer: SharedCacheLayer<String, String, String> = SharedCacheLayer::builder()
+        .key_extractor(|req: &String| req.clone())
+        .build()
+        .unwrap();
+    layer.import(vec![("warm".to_string(), "preloaded".to_string())]);
+
+    let mut service1 = layer.layer(counting_service(Arc::clone(&calls)));
+    let mut service2 = layer.layer(counting_service(Arc::clone(&calls)));
+    for service in [&mut service1, &mut service2] {
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call("warm".to_string())
+            .await
+            .unwrap();
+        assert_eq!(response, "preloaded");
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        layer.export(),
+        vec![("warm".to_string(), "preloaded".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn import_respects_max_size() {
+    let layer: SharedCacheLayer<String, String, String> = SharedCacheLayer::builder()
+        .max_size(2)
+        .key_extractor(|req: &String| req.clone())
+        .build()
+        .unwrap();
+
+    layer.import((1..=3).map(|i| (format!("k{}", i), format!("v{}", i))));
+
+    // The first entry is evicted, the rest keep their order
+    let keys: Vec<_> = layer.export().into_iter().map(|(k, _)| k).collect();
+    assert_eq!(keys, vec!["k2", "k3"]);
+}
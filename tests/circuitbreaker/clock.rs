@@ -0,0 +1,101 @@
+use std::time::Duration;
+use tower::{Layer, Service};
+use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitState};
+use tower_resilience_core::MockClock;
+
+/// Open and half-open transitions follow the configured clock, not wall time
+#[tokio::test]
+async fn mock_clock_drives_open_to_half_open() {
+    let clock = MockClock::new();
+    let layer = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_size(4)
+        .minimum_number_of_calls(4)
+        .wait_duration_in_open(Duration::from_secs(60))
+        .permitted_calls_in_half_open(1)
+        .clock(clock.clone())
+        .name("mock-clock")
+        .build();
+
+    let failing =
+        tower::service_fn(|fail: bool| async move { if fail { Err("error") } else { Ok(()) } });
+    let mut cb = layer.layer(failing);
+
+    for _ in 0..4 {
+        let _ = cb.call(true).await;
+    }
+    assert_eq!(cb.state().await, CircuitState::Open);
+
+    // Almost a minute later the circuit is still open
+    clock.advance(Duration::from_secs(59));
+    assert!(cb.call(false).await.unwrap_err().is_circuit_open());
+    assert_eq!(cb.state().await, CircuitState::Open);
+
+    // Once the wait has elapsed the next call is a half-open probe
+    clock.advance(Duration::from_secs(1));
+    assert!(cb.call(false).await.is_ok());
+    assert_eq!(cb.state().await, CircuitState::Closed);
+}
+
+/// Slow-call detection measures durations on the configured clock
+#[tokio::test]
+async fn mock_clock_measures_slow_calls() {
+    let clock = MockClock::new();
+    let layer = CircuitBreakerLayer::builder()
+        .sliding_window_size(2)
+        .minimum_number_of_calls(2)
+        .slow_call_duration_threshold(Duration::from_secs(10))
+        .slow_call_rate_threshold(1.0)
+        .clock(clock.clone())
+        .name("mock-clock-slow")
+        .build();
+
+    let slow = {
+        let clock = clock.clone();
+        tower::service_fn(move |_: ()| {
+            clock.advance(Duration::from_secs(10));
+            async { Ok::<_, &str>(()) }
+        })
+    };
+    let mut cb = layer.layer(slow);
+
+    for _ in 0..2 {
+        cb.call(()).await.unwrap();
+    }
+    assert_eq!(cb.state().await, CircuitState::Open);
+}
+
+/// A breaker built open waits out the configured clock before probing
+#[tokio::test]
+async fn build_with_state_starts_open() {
+    let clock = MockClock::new();
+    let (layer, handle) = CircuitBreakerLayer::builder()
+        .wait_duration_in_open(Duration::from_secs(30))
+        .permitted_calls_in_half_open(1)
+        .clock(clock.clone())
+        .name("preset-open")
+        .build_with_state(CircuitState::Open);
+
+    let mut cb = layer.layer(tower::service_fn(|_: ()| async { Ok::<_, &str>(()) }));
+    assert_eq!(handle.state(), CircuitState::Open);
+    assert!(cb.call(()).await.unwrap_err().is_circuit_open());
+
+    clock.advance(Duration::from_secs(30));
+    assert!(cb.call(()).await.is_ok());
+    assert_eq!(handle.state(), CircuitState::Closed);
+}
+
+/// A breaker built half-open reopens on a single failed probe
+#[tokio::test]
+async fn build_with_state_starts_half_open() {
+    let (layer, handle) = CircuitBreakerLayer::builder()
+        .permitted_calls_in_half_open(1)
+        .name("preset-half-open")
+        .build_with_state(CircuitState::HalfOpen);
+
+    let mut cb = layer.layer(tower::service_fn(|_: ()| async { Err::<(), _>("error") }));
+    assert_eq!(handle.state(), CircuitState::HalfOpen);
+
+    assert!(cb.call(()).await.is_err());
+    assert_eq!(handle.state(), CircuitState::Open);
+}
@@ -5,8 +5,11 @@ use std::sync::{
 use std::time::Duration;
 use tokio::time::sleep;
 use tower::{Layer, Service};
+use tower_resilience_circuitbreaker::CircuitBreakerEvent;
 use tower_resilience_circuitbreaker::CircuitBreakerLayer;
 use tower_resilience_circuitbreaker::CircuitState;
+use tower_resilience_circuitbreaker::SlidingWindowType;
+use tower_resilience_core::{FnListener, ResilienceEvent, Severity};
 
 /// Test multiple event listeners on same event type
 #[tokio::test]
@@ -301,6 +304,40 @@ async fn all_event_types_emitted() {
     );
 }
 
+/// Test that a severity-filtered listener skips per-call events
+#[tokio::test]
+async fn severity_filter_keeps_transitions() {
+    let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let s = Arc::clone(&seen);
+
+    let service = tower::service_fn(|_req: ()| async { Err::<(), _>("error") });
+
+    let layer = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_size(2)
+        .minimum_number_of_calls(2)
+        .on_event(
+            FnListener::new(move |event: &CircuitBreakerEvent| {
+                s.lock()
+                    .unwrap()
+                    .push((event.event_type(), event.severity()));
+            })
+            .min_severity(Severity::Error),
+        )
+        .build();
+
+    let mut cb = layer.layer(service);
+    for _ in 0..3 {
+        let _ = cb.call(()).await;
+    }
+
+    // Permitted calls, failures and the rejection are all filtered out
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![("state_transition", Severity::Error)]
+    );
+}
+
 /// Test window size of 1
 #[tokio::test]
 async fn sliding_window_size_one() {
@@ -363,3 +400,123 @@ async fn half_open_one_permitted_call() {
     // Should transition to closed immediately
     assert_eq!(cb.state().await, CircuitState::Closed);
 }
+
+/// Ignored outcomes affect neither the failure rate nor the call count
+#[tokio::test]
+async fn ignored_outcomes_are_not_recorded() {
+    let ignored = Arc::new(AtomicUsize::new(0));
+    let ignored_clone = Arc::clone(&ignored);
+
+    let layer = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_size(2)
+        .ignore_classifier(
+            |result: &Result<(), &'static str>| matches!(result, Err(e) if *e == "invalid"),
+        )
+        .on_call_ignored(move || {
+            ignored_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let mut service = layer.layer(tower::service_fn(|req: &'static str| async move {
+        match req {
+            "ok" => Ok(()),
+            other => Err(other),
+        }
+    }));
+
+    for _ in 0..5 {
+        assert!(service.call("invalid").await.is_err());
+    }
+    assert_eq!(ignored.load(Ordering::SeqCst), 5);
+    assert_eq!(service.metrics().await.total_calls, 0);
+    assert_eq!(service.state().await, CircuitState::Closed);
+
+    // Real failures are still recorded
+    let _ = service.call("down").await;
+    let _ = service.call("down").await;
+    assert_eq!(service.state().await, CircuitState::Open);
+}
+
+#[tokio::test]
+async fn error_category_counts_failures_per_category() {
+    let layer = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_type(SlidingWindowType::TimeBased)
+        .sliding_window_duration(Duration::from_secs(60))
+        .minimum_number_of_calls(4)
+        .ignore_classifier(
+            |result: &Result<(), &'static str>| matches!(result, Err(e) if *e == "invalid"),
+        )
+        .error_category(|err: &&'static str| match *err {
+            "timed out" => "timeout",
+            _ => "connection",
+        })
+        .build();
+
+    let mut service = layer.layer(tower::service_fn(|req: &'static str| async move {
+        match req {
+            "ok" => Ok(()),
+            other => Err(other),
+        }
+    }));
+
+    for req in ["ok", "invalid", "timed out", "refused", "timed out"] {
+        let _ = service.call(req).await;
+    }
+
+    // Opening clears the window but keeps the categories that tripped it
+    assert_eq!(service.state().await, CircuitState::Open);
+    let metrics = service.metrics().await;
+    assert!(metrics.failures_by_category.is_empty());
+    assert_eq!(
+        metrics.failures_by_category_at_open.get("timeout"),
+        Some(&2)
+    );
+    assert_eq!(
+        metrics.failures_by_category_at_open.get("connection"),
+        Some(&1)
+    );
+    assert_eq!(metrics.failures_by_category_at_open.len(), 2);
+}
+
+/// Shadow mode opens the circuit as usual but never rejects calls
+#[tokio::test]
+async fn shadow_mode_transitions_without_rejecting() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let rejections = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+    let r = Arc::clone(&rejections);
+
+    let layer = CircuitBreakerLayer::builder()
+        .consecutive_failures(2)
+        .wait_duration_in_open(Duration::from_millis(50))
+        .shadow_mode(true)
+        .on_call_rejected(move || {
+            r.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let mut service = layer.layer(tower::service_fn(move |fail: bool| {
+        c.fetch_add(1, Ordering::SeqCst);
+        async move { if fail { Err("boom") } else { Ok(()) } }
+    }));
+
+    for _ in 0..2 {
+        assert!(service.call(true).await.is_err());
+    }
+    assert_eq!(service.state().await, CircuitState::Open);
+
+    // Calls the open circuit would reject still reach the service, and their
+    // outcomes do not move the circuit
+    assert!(service.call(false).await.is_ok());
+    assert!(service.call(false).await.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    assert_eq!(rejections.load(Ordering::SeqCst), 2);
+    assert_eq!(service.state().await, CircuitState::Open);
+
+    // Recovery still goes through a half-open probe
+    sleep(Duration::from_millis(60)).await;
+    assert!(service.call(false).await.is_ok());
+    assert_eq!(service.state().await, CircuitState::Closed);
+}
@@ -0,0 +1,38 @@
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitState};
+
+/// Failures through one member open the circuit for every member
+#[tokio::test]
+async fn failures_on_one_route_open_all_routes() {
+    let group = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_size(4)
+        .name("upstream")
+        .build_group();
+
+    let mut get = group
+        .layer("upstream-get")
+        .layer(tower::service_fn(|_req: ()| async {
+            Err::<(), _>("error")
+        }));
+    let mut post = group
+        .layer("upstream-post")
+        .layer(tower::service_fn(|_req: ()| async { Ok::<_, &str>(()) }));
+
+    // Two successes and two failures across routes fill the shared window
+    post.ready().await.unwrap().call(()).await.unwrap();
+    post.ready().await.unwrap().call(()).await.unwrap();
+    assert!(get.ready().await.unwrap().call(()).await.is_err());
+    assert!(get.ready().await.unwrap().call(()).await.is_err());
+
+    assert_eq!(group.handle().state(), CircuitState::Open);
+    assert!(
+        post.ready()
+            .await
+            .unwrap()
+            .call(())
+            .await
+            .unwrap_err()
+            .is_circuit_open()
+    );
+}
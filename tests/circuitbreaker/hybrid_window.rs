@@ -0,0 +1,65 @@
+use std::time::Duration;
+use tower::{Layer, Service};
+use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitState, SlidingWindowType};
+use tower_resilience_core::MockClock;
+
+fn hybrid_layer(clock: &MockClock) -> CircuitBreakerLayer {
+    CircuitBreakerLayer::builder()
+        .sliding_window_type(SlidingWindowType::Hybrid)
+        .sliding_window_size(4)
+        .sliding_window_duration(Duration::from_secs(10))
+        .minimum_number_of_calls(4)
+        .failure_rate_threshold(0.5)
+        .clock(clock.clone())
+        .name("hybrid")
+        .build()
+}
+
+fn service() -> impl Service<bool, Response = (), Error = &'static str, Future: Send> + Clone {
+    tower::service_fn(|fail: bool| async move { if fail { Err("error") } else { Ok(()) } })
+}
+
+/// A burst of failures is not evaluated until the window duration has passed
+#[tokio::test]
+async fn hybrid_window_waits_for_duration() {
+    let clock = MockClock::new();
+    let mut cb = hybrid_layer(&clock).layer(service());
+
+    for _ in 0..4 {
+        let _ = cb.call(true).await;
+    }
+    assert_eq!(cb.state().await, CircuitState::Closed);
+
+    // Once the circuit has observed a full window, the next failure trips it
+    clock.advance(Duration::from_secs(5));
+    let _ = cb.call(true).await;
+    assert_eq!(cb.state().await, CircuitState::Closed);
+    clock.advance(Duration::from_secs(5));
+    let _ = cb.call(true).await;
+    assert_eq!(cb.state().await, CircuitState::Open);
+}
+
+/// Samples expire by age as well as by count
+#[tokio::test]
+async fn hybrid_window_expires_by_age_and_count() {
+    let clock = MockClock::new();
+    let mut cb = hybrid_layer(&clock).layer(service());
+    clock.advance(Duration::from_secs(10));
+
+    // A failure pushed out of the last 4 calls no longer counts
+    let _ = cb.call(true).await;
+    for _ in 0..4 {
+        cb.call(false).await.unwrap();
+    }
+    let metrics = cb.metrics().await;
+    assert_eq!(metrics.total_calls, 4);
+    assert_eq!(metrics.failure_count, 0);
+    assert_eq!(cb.state().await, CircuitState::Closed);
+
+    // Old samples age out, so fewer than the minimum calls remain
+    clock.advance(Duration::from_secs(11));
+    let _ = cb.call(true).await;
+    let metrics = cb.metrics().await;
+    assert_eq!(metrics.total_calls, 1);
+    assert_eq!(cb.state().await, CircuitState::Closed);
+}
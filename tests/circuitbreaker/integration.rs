@@ -274,7 +274,7 @@ async fn metrics_are_emitted() {
 
     let snapshot_vec = RECORDER.snapshotter().snapshot().into_vec();
     let calls = snapshot_vec.iter().find_map(|(key, _, _, value)| {
-        if key.key().name() == "circuitbreaker_calls_total" {
+        if key.key().name() == "resilience_circuitbreaker_calls_total" {
             Some(value)
         } else {
             None
@@ -283,33 +283,33 @@ async fn metrics_are_emitted() {
 
     assert!(
         matches!(calls, Some(DebugValue::Counter(_))),
-        "expected circuitbreaker_calls_total metric",
+        "expected resilience_circuitbreaker_calls_total metric",
     );
 
     assert!(matches!(
         snapshot_vec
             .iter()
-            .find(|(key, ..)| key.key().name() == "circuitbreaker_calls_total"),
+            .find(|(key, ..)| key.key().name() == "resilience_circuitbreaker_calls_total"),
         Some((_, _, _, DebugValue::Counter(_)))
     ));
 
     assert!(matches!(
         snapshot_vec
             .iter()
-            .find(|(key, ..)| key.key().name() == "circuitbreaker_transitions_total"),
+            .find(|(key, ..)| key.key().name() == "resilience_circuitbreaker_transitions_total"),
         Some((_, _, _, DebugValue::Counter(_)))
     ));
 
     assert!(matches!(
         snapshot_vec
             .iter()
-            .find(|(key, ..)| key.key().name() == "circuitbreaker_state"),
+            .find(|(key, ..)| key.key().name() == "resilience_circuitbreaker_state"),
         Some((_, _, _, DebugValue::Gauge(_)))
     ));
 
     snapshot_vec.iter().any(|record| {
         let (key, _, _, value) = record;
-        if key.key().name() == "circuitbreaker_calls_total" {
+        if key.key().name() == "resilience_circuitbreaker_calls_total" {
             if let DebugValue::Counter(_) = value {
                 key.key()
                     .labels()
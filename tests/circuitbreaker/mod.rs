@@ -2,21 +2,27 @@
 //!
 //! Test organization:
 //! - integration.rs: Basic integration tests
+//! - clock.rs: Deterministic timing with a mock clock and preset circuit state
 //! - concurrency.rs: P0 - Concurrent access patterns
 //! - config_validation.rs: P0 - Configuration edge cases
 //! - thresholds.rs: P0 - Threshold precision testing
 //! - time_based.rs: P0 - Time-based window behavior
+//! - hybrid_window.rs: Count- and time-bounded window behavior
 //! - combinations.rs: P1 - Feature combinations
 //! - half_open.rs: P1 - Half-open state complexity
 //! - reset.rs: P1 - Reset functionality
+//! - group.rs: Breakers sharing one circuit
 //! - edge_cases.rs: P2 - Event listeners, failure classifiers
 
+mod clock;
 mod combinations;
 mod concurrency;
 mod config_validation;
 mod edge_cases;
 mod failure_model;
+mod group;
 mod half_open;
+mod hybrid_window;
 mod integration;
 mod reset;
 mod thresholds;
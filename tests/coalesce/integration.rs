@@ -3,8 +3,9 @@
 use super::TestError;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use tower::{Service, ServiceBuilder, ServiceExt};
-use tower_resilience_coalesce::CoalesceLayer;
+use tower_resilience_coalesce::{CoalesceError, CoalesceLayer, LeaderFailurePolicy};
 
 #[tokio::test]
 async fn test_single_request_passes_through() {
@@ -176,3 +177,473 @@ async fn test_service_clone() {
     assert_eq!(r2, "response: b");
     assert_eq!(call_count.load(Ordering::SeqCst), 2);
 }
+
+#[tokio::test]
+async fn test_share_window_reuses_recent_result() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            let n = count.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, TestError>(format!("response-{}: {}", n, req))
+        }
+    });
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .share_window(Duration::from_millis(100))
+                .build(),
+        )
+        .service(service);
+
+    let r1 = service
+        .ready()
+        .await
+        .unwrap()
+        .call("key".to_string())
+        .await
+        .unwrap();
+
+    // Arrives after the leader completed but within the window
+    let r2 = service
+        .ready()
+        .await
+        .unwrap()
+        .call("key".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(r1, "response-0: key");
+    assert_eq!(r2, "response-0: key");
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+    // After the window expires, the request executes again
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let r3 = service
+        .ready()
+        .await
+        .unwrap()
+        .call("key".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(r3, "response-1: key");
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_share_window_does_not_share_errors() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |_req: String| {
+        let count = cc.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            Err::<String, _>(TestError::new("failure"))
+        }
+    });
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .share_window(Duration::from_secs(60))
+                .build(),
+        )
+        .service(service);
+
+    for _ in 0..2 {
+        let result = service.ready().await.unwrap().call("key".to_string()).await;
+        assert!(result.is_err());
+    }
+
+    // Each failing request executed
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_max_waiters_per_key_rejects_excess() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok::<_, TestError>(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .max_waiters_per_key(2)
+                .build(),
+        )
+        .service(service);
+
+    // Leader plus two waiters fit; two more are rejected
+    let mut handles = vec![];
+    for _ in 0..5 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready()
+                .await
+                .unwrap()
+                .call("same-key".to_string())
+                .await
+        }));
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    let mut ok = 0;
+    let mut rejected = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(_) => ok += 1,
+            Err(CoalesceError::TooManyWaiters) => rejected += 1,
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+
+    assert_eq!(ok, 3);
+    assert_eq!(rejected, 2);
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_share_errors_disabled_promotes_waiter() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    // First call fails, later calls succeed
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            let n = count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if n == 0 {
+                Err(TestError::new("transient"))
+            } else {
+                Ok(format!("response: {}", req))
+            }
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .share_errors(false)
+                .build(),
+        )
+        .service(service);
+
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready()
+                .await
+                .unwrap()
+                .call("same-key".to_string())
+                .await
+        }));
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    let results: Vec<_> = futures::future::join_all(handles)
+        .await
+        .into_iter()
+        .map(|r| r.unwrap())
+        .collect();
+
+    // Only the leader sees its error; the promoted waiter's retry serves the rest
+    assert!(results[0].is_err());
+    for result in &results[1..] {
+        assert_eq!(result.as_ref().unwrap(), "response: same-key");
+    }
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_leader_failure_policy_retry_on_cancel() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .leader_failure_policy(LeaderFailurePolicy::Retry)
+                .build(),
+        )
+        .service(service);
+
+    // Leader gives up before the inner call completes
+    let mut leader = service.clone();
+    let leader = tokio::spawn(async move {
+        let fut = leader.ready().await.unwrap().call("key".to_string());
+        tokio::time::timeout(Duration::from_millis(20), fut).await
+    });
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let mut waiter = service.clone();
+    let result = waiter.ready().await.unwrap().call("key".to_string()).await;
+
+    assert!(leader.await.unwrap().is_err());
+    assert_eq!(result.unwrap(), "response: key");
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_leader_cancel_propagates_by_default() {
+    let service = tower::service_fn(|req: String| async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok::<_, TestError>(format!("response: {}", req))
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(CoalesceLayer::new(|req: &String| req.clone()))
+        .service(service);
+
+    let mut leader = service.clone();
+    let leader = tokio::spawn(async move {
+        let fut = leader.ready().await.unwrap().call("key".to_string());
+        tokio::time::timeout(Duration::from_millis(20), fut).await
+    });
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let mut waiter = service.clone();
+    let result = waiter.ready().await.unwrap().call("key".to_string()).await;
+
+    assert!(leader.await.unwrap().is_err());
+    assert!(matches!(result, Err(CoalesceError::LeaderCancelled)));
+}
+
+#[tokio::test]
+async fn test_events_emitted() {
+    let started = Arc::new(AtomicUsize::new(0));
+    let joined = Arc::new(AtomicUsize::new(0));
+    let shared = Arc::new(AtomicUsize::new(0));
+    let failed = Arc::new(AtomicUsize::new(0));
+
+    let (s, j, sh, f) = (
+        Arc::clone(&started),
+        Arc::clone(&joined),
+        Arc::clone(&shared),
+        Arc::clone(&failed),
+    );
+
+    let service = tower::service_fn(|req: String| async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        if req == "bad" {
+            Err(TestError::new("failure"))
+        } else {
+            Ok(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .name("events")
+                .on_leader_started(move || {
+                    s.fetch_add(1, Ordering::SeqCst);
+                })
+                .on_follower_joined(move || {
+                    j.fetch_add(1, Ordering::SeqCst);
+                })
+                .on_result_shared(move |followers| {
+                    sh.fetch_add(followers, Ordering::SeqCst);
+                })
+                .on_leader_failed(move |followers| {
+                    f.fetch_add(followers, Ordering::SeqCst);
+                })
+                .build(),
+        )
+        .service(service);
+
+    let mut handles = vec![];
+    for key in ["good", "good", "good", "bad", "bad"] {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(key.to_string()).await
+        }));
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    for handle in handles {
+        let _ = handle.await.unwrap();
+    }
+
+    assert_eq!(started.load(Ordering::SeqCst), 2);
+    assert_eq!(joined.load(Ordering::SeqCst), 3);
+    assert_eq!(shared.load(Ordering::SeqCst), 2);
+    assert_eq!(failed.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_detached_leader_survives_caller_cancel() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let completed = Arc::new(AtomicUsize::new(0));
+    let (cc, done) = (Arc::clone(&call_count), Arc::clone(&completed));
+
+    let service = tower::service_fn(move |req: String| {
+        let (count, done) = (cc.clone(), done.clone());
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            done.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, TestError>(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .detach_leader(true)
+                .build(),
+        )
+        .service(service);
+
+    // Leader gives up before the inner call completes
+    let mut leader = service.clone();
+    let leader = tokio::spawn(async move {
+        let fut = leader.ready().await.unwrap().call("key".to_string());
+        tokio::time::timeout(Duration::from_millis(20), fut).await
+    });
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let mut waiter = service.clone();
+    let result = waiter.ready().await.unwrap().call("key".to_string()).await;
+
+    // The request ran to completion once and the waiter got its result
+    assert!(leader.await.unwrap().is_err());
+    assert_eq!(result.unwrap(), "response: key");
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    assert_eq!(completed.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_coalesce_if_bypasses_non_matching_requests() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .coalesce_if(|req: &String| req.starts_with("get"))
+                .build(),
+        )
+        .service(service);
+
+    let mut handles = vec![];
+    for key in ["get-a", "get-a", "get-a", "put-a", "put-a", "put-a"] {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(key.to_string()).await
+        }));
+    }
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    // Reads coalesce into one call; each write executes
+    assert_eq!(call_count.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_try_key_extractor_bypasses_on_error() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(CoalesceLayer::try_builder(|req: &String| req.parse::<u64>()).build())
+        .service(service);
+
+    let mut handles = vec![];
+    for key in ["42", "42", "42", "abc", "abc", "abc"] {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(key.to_string()).await
+        }));
+    }
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    // Parsable keys coalesce into one call; the rest execute individually
+    assert_eq!(call_count.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_normalize_key_coalesces_equivalent_requests() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |req: String| {
+        let count = cc.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>(format!("response: {}", req))
+        }
+    });
+
+    let service = ServiceBuilder::new()
+        .layer(
+            CoalesceLayer::builder(|req: &String| req.clone())
+                .normalize_key(|key: String| {
+                    key.split('?').next().unwrap_or_default().to_lowercase()
+                })
+                .build(),
+        )
+        .service(service);
+
+    let mut handles = vec![];
+    for key in [
+        "/users/1",
+        "/Users/1",
+        "/users/1?trace=a",
+        "/users/1?trace=b",
+    ] {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(key.to_string()).await
+        }));
+    }
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+}
@@ -20,5 +20,7 @@ mod latency_critical;
 mod message_queues;
 mod microservices;
 mod order_verification;
+mod ordered_layers;
+mod resilience_stack;
 mod server_side;
 mod test_utils;
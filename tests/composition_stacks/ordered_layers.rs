@@ -0,0 +1,64 @@
+//! `OrderedLayers` composes layers in the order they are added.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience::OrderedLayers;
+use tower_resilience::circuitbreaker::{CircuitBreakerError, CircuitBreakerLayer};
+use tower_resilience::retry::RetryLayer;
+
+use super::external_api::{ApiError, ApiRequest, ApiResponse};
+
+/// The first layer added is the outermost, so the breaker added before
+/// retry records one failure per request rather than one per attempt.
+#[tokio::test]
+async fn first_layer_is_outermost() {
+    let call_count = Arc::new(AtomicU32::new(0));
+    let call_count_clone = call_count.clone();
+
+    let failing_service = tower::service_fn(move |_req: ApiRequest| {
+        let count = call_count_clone.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            Err::<ApiResponse, _>(ApiError("always fails".into()))
+        }
+    });
+
+    let layers = OrderedLayers::new()
+        .then(
+            CircuitBreakerLayer::builder()
+                .failure_rate_threshold(1.0)
+                .sliding_window_size(2)
+                .minimum_number_of_calls(2)
+                .build(),
+        )
+        .then(tower::layer::util::Identity::new())
+        .then(
+            RetryLayer::builder()
+                .max_attempts(3)
+                .fixed_backoff(Duration::from_millis(1))
+                .build(),
+        );
+    let mut service = layers.layer(failing_service);
+
+    for endpoint in ["a", "b"] {
+        let _ = service
+            .ready()
+            .await
+            .unwrap()
+            .call(ApiRequest::new(endpoint))
+            .await;
+    }
+    assert_eq!(call_count.load(Ordering::SeqCst), 6);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::new("c"))
+        .await;
+    assert!(matches!(result, Err(CircuitBreakerError::OpenCircuit)));
+    assert_eq!(call_count.load(Ordering::SeqCst), 6);
+}
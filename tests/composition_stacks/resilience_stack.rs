@@ -0,0 +1,166 @@
+//! `ResilienceStack` applies patterns in the documented order.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience::ResilienceStack;
+use tower_resilience::bulkhead::BulkheadLayer;
+use tower_resilience::circuitbreaker::{CircuitBreakerError, CircuitBreakerLayer, CircuitState};
+use tower_resilience::retry::RetryLayer;
+use tower_resilience::timelimiter::TimeLimiterLayer;
+
+use super::external_api::{ApiError, ApiRequest, ApiResponse};
+
+/// Retry runs inside the circuit breaker, so the breaker records one failure
+/// per request rather than one per attempt, whatever order the builder
+/// methods are called in.
+#[tokio::test]
+async fn circuit_breaker_wraps_retry() {
+    let call_count = Arc::new(AtomicU32::new(0));
+    let call_count_clone = call_count.clone();
+
+    let failing_service = tower::service_fn(move |_req: ApiRequest| {
+        let count = call_count_clone.clone();
+        async move {
+            count.fetch_add(1, Ordering::SeqCst);
+            Err::<ApiResponse, _>(ApiError("always fails".into()))
+        }
+    });
+
+    let stack = ResilienceStack::builder()
+        .with_retry(
+            RetryLayer::builder()
+                .max_attempts(3)
+                .fixed_backoff(Duration::from_millis(1))
+                .build(),
+        )
+        .with_circuit_breaker(
+            CircuitBreakerLayer::builder()
+                .failure_rate_threshold(1.0)
+                .sliding_window_size(2)
+                .minimum_number_of_calls(2)
+                .build(),
+        )
+        .build();
+    let mut service = stack.layer(failing_service);
+
+    // One request, three attempts, one recorded failure
+    let _ = service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::new("a"))
+        .await;
+    assert_eq!(call_count.load(Ordering::SeqCst), 3);
+    assert_eq!(service.state_sync(), CircuitState::Closed);
+
+    let _ = service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::new("b"))
+        .await;
+    assert_eq!(call_count.load(Ordering::SeqCst), 6);
+    assert_eq!(service.state_sync(), CircuitState::Open);
+
+    // Rejected by the breaker before any attempt is made
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::new("c"))
+        .await;
+    assert!(matches!(result, Err(CircuitBreakerError::OpenCircuit)));
+    assert_eq!(call_count.load(Ordering::SeqCst), 6);
+}
+
+/// The time limiter sits inside retry, so a slow attempt is cut short and
+/// retried instead of consuming the whole request.
+#[tokio::test]
+async fn timeout_applies_per_attempt() {
+    let call_count = Arc::new(AtomicU32::new(0));
+    let call_count_clone = call_count.clone();
+
+    let service = tower::service_fn(move |req: ApiRequest| {
+        let attempt = call_count_clone.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if attempt == 0 {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            Ok::<_, ApiError>(ApiResponse::new(&req.endpoint))
+        }
+    });
+
+    let stack = ResilienceStack::builder()
+        .with_time_limiter(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_millis(50))
+                .build(),
+        )
+        .with_bulkhead(BulkheadLayer::builder().max_concurrent_calls(1).build())
+        .with_retry(
+            RetryLayer::builder()
+                .max_attempts(2)
+                .fixed_backoff(Duration::from_millis(1))
+                .build(),
+        )
+        .build();
+    let mut service = stack.layer(service);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::new("/users"))
+        .await;
+    assert!(response.is_ok());
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+/// Presets produce working stacks and can be customized before building.
+#[tokio::test]
+async fn presets_build_working_stacks() {
+    let service = tower::service_fn(|req: ApiRequest| async move {
+        Ok::<_, ApiError>(ApiResponse::new(&req.endpoint))
+    });
+
+    let mut database = ResilienceStack::database_defaults()
+        .with_bulkhead(BulkheadLayer::builder().max_concurrent_calls(4).build())
+        .build()
+        .layer(service);
+    let response = database
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::new("/rows"))
+        .await
+        .unwrap();
+    assert_eq!(response.body, "/rows");
+
+    let mut http = ResilienceStack::http_client_defaults()
+        .build()
+        .layer(service);
+    assert!(
+        http.ready()
+            .await
+            .unwrap()
+            .call(ApiRequest::new("/users"))
+            .await
+            .is_ok()
+    );
+
+    let mut consumer = ResilienceStack::message_consumer_defaults()
+        .build()
+        .layer(service);
+    assert!(
+        consumer
+            .ready()
+            .await
+            .unwrap()
+            .call(ApiRequest::new("/events"))
+            .await
+            .is_ok()
+    );
+}
@@ -0,0 +1,81 @@
+//! Runtime reload of settings through control handles.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service};
+use tower_resilience_bulkhead::{BulkheadLayer, BulkheadSettings};
+use tower_resilience_circuitbreaker::{CircuitBreakerLayer, CircuitBreakerSettings, CircuitState};
+use tower_resilience_core::ConfigWatcher;
+
+#[derive(Debug, Clone, Default)]
+struct Settings {
+    breaker: Option<CircuitBreakerSettings>,
+    bulkhead: Option<BulkheadSettings>,
+}
+
+#[tokio::test]
+async fn watcher_updates_registered_handles() {
+    let reloads = Arc::new(AtomicUsize::new(0));
+    let reloads_clone = Arc::clone(&reloads);
+
+    let (breaker, breaker_handle) = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(1.0)
+        .sliding_window_size(2)
+        .minimum_number_of_calls(2)
+        .build_with_handle();
+    let (_bulkhead, bulkhead_handle) = BulkheadLayer::builder()
+        .max_concurrent_calls(4)
+        .build_with_handle();
+
+    let watcher = ConfigWatcher::new("app").on_reloaded(move |applied| {
+        reloads_clone.fetch_add(applied, Ordering::SeqCst);
+    });
+    watcher.register(breaker_handle.clone(), |s: &Settings| s.breaker.clone());
+    watcher.register(bulkhead_handle.clone(), |s: &Settings| s.bulkhead.clone());
+
+    watcher.apply(Settings {
+        breaker: Some(CircuitBreakerSettings {
+            failure_rate_threshold: Some(0.5),
+            ..Default::default()
+        }),
+        bulkhead: Some(BulkheadSettings {
+            max_concurrent_calls: Some(8),
+            ..Default::default()
+        }),
+    });
+    assert_eq!(reloads.load(Ordering::SeqCst), 2);
+    assert_eq!(bulkhead_handle.max_concurrent(), 8);
+
+    // One failure in two calls now meets the lowered threshold
+    let mut flaky = {
+        let calls = Arc::new(AtomicUsize::new(0));
+        breaker.layer(tower::service_fn(move |_: ()| {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if n.is_multiple_of(2) {
+                    Ok(())
+                } else {
+                    Err("boom")
+                }
+            }
+        }))
+    };
+    let _ = flaky.call(()).await;
+    let _ = flaky.call(()).await;
+    assert_eq!(breaker_handle.state(), CircuitState::Open);
+}
+
+#[tokio::test]
+async fn missing_sections_leave_handles_unchanged() {
+    let (_bulkhead, handle) = BulkheadLayer::builder()
+        .max_concurrent_calls(4)
+        .build_with_handle();
+
+    let watcher = ConfigWatcher::new("app");
+    watcher.register(handle.clone(), |s: &Settings| s.bulkhead.clone());
+    watcher.apply(Settings::default());
+
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    assert_eq!(handle.max_concurrent(), 4);
+}
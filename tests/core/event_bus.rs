@@ -0,0 +1,48 @@
+//! Tests for asynchronous event dispatch through layer builders.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Service, ServiceBuilder, ServiceExt};
+use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn blocked_listener_does_not_stall_requests() {
+    let release = Arc::new(AtomicBool::new(false));
+    let delivered = Arc::new(AtomicUsize::new(0));
+
+    let layer = {
+        let release = Arc::clone(&release);
+        let delivered = Arc::clone(&delivered);
+        CircuitBreakerLayer::builder()
+            .name("async-events")
+            .on_success(move |_| {
+                while !release.load(Ordering::SeqCst) {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+                delivered.fetch_add(1, Ordering::SeqCst);
+            })
+            .async_events(16)
+            .build()
+    };
+
+    let mut service = ServiceBuilder::new()
+        .layer(layer)
+        .service_fn(|req: u32| async move { Ok::<_, std::io::Error>(req) });
+
+    // Every call completes while the listener is still blocked
+    for i in 0..3 {
+        let response = service.ready().await.unwrap().call(i).await.unwrap();
+        assert_eq!(response, i);
+    }
+    assert_eq!(delivered.load(Ordering::SeqCst), 0);
+
+    release.store(true, Ordering::SeqCst);
+    for _ in 0..500 {
+        if delivered.load(Ordering::SeqCst) == 3 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(2)).await;
+    }
+    assert_eq!(delivered.load(Ordering::SeqCst), 3);
+}
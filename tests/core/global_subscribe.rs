@@ -0,0 +1,48 @@
+//! Tests for process-wide event subscription.
+
+use std::sync::{Arc, Mutex};
+use tower::{Service, ServiceBuilder, ServiceExt};
+use tower_resilience_bulkhead::BulkheadLayer;
+use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+use tower_resilience_core::events::{ResilienceEvent, subscribe};
+
+#[tokio::test]
+async fn subscriber_sees_events_from_every_layer() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let subscription = {
+        let seen = Arc::clone(&seen);
+        subscribe(move |event: &dyn ResilienceEvent| {
+            // Other tests in this binary emit events concurrently
+            if event.pattern_name().starts_with("global-sub-") {
+                seen.lock()
+                    .unwrap()
+                    .push((event.pattern_name().to_string(), event.event_type()));
+            }
+        })
+    };
+
+    let mut service = ServiceBuilder::new()
+        .layer(CircuitBreakerLayer::builder().name("global-sub-cb").build())
+        .layer(
+            BulkheadLayer::builder()
+                .name("global-sub-bulkhead")
+                .max_concurrent_calls(4)
+                .build(),
+        )
+        .service_fn(|req: u32| async move { Ok::<_, std::io::Error>(req) });
+
+    service.ready().await.unwrap().call(1).await.unwrap();
+
+    let events = seen.lock().unwrap().clone();
+    assert!(events.contains(&("global-sub-cb".to_string(), "success_recorded")));
+    assert!(
+        events
+            .iter()
+            .any(|(pattern, _)| pattern == "global-sub-bulkhead")
+    );
+
+    subscription.unsubscribe();
+    let before = seen.lock().unwrap().len();
+    service.ready().await.unwrap().call(2).await.unwrap();
+    assert_eq!(seen.lock().unwrap().len(), before);
+}
@@ -1,7 +1,11 @@
 //! Comprehensive tests for tower-resilience-core.
 
 mod concurrency;
+mod config_reload;
+mod event_bus;
 mod events;
 mod fn_listener;
+mod global_subscribe;
 mod lifecycle;
 mod panics;
+mod spans;
@@ -6,6 +6,8 @@
 //! - Multiple panicking listeners
 //! - Emit returns normally after panic
 //! - Panics with complex event types
+//! - Panics reported to global subscribers as ListenerPanicked
+//! - Listeners that opt out of panic catching
 //!
 //! IMPORTANT: The EventListeners::emit() method uses std::panic::catch_unwind
 //! to ensure that a panic in one listener doesn't prevent other listeners
@@ -15,7 +17,9 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
-use tower_resilience_core::events::{EventListeners, FnListener, ResilienceEvent};
+use tower_resilience_core::events::{
+    EventListeners, FnListener, ListenerPanicked, ResilienceEvent, Severity, subscribe,
+};
 
 #[derive(Debug, Clone)]
 struct TestEvent {
@@ -305,3 +309,50 @@ fn panic_behavior_documented_in_test() {
     listeners.emit(&event);
     assert_eq!(good_listener_called.load(Ordering::SeqCst), 2);
 }
+
+#[test]
+fn panic_is_reported_to_global_subscribers() {
+    let reports = Arc::new(AtomicUsize::new(0));
+    let r = Arc::clone(&reports);
+
+    // Other tests panic concurrently, so only count this test's reports
+    let subscription = subscribe(move |event: &dyn ResilienceEvent| {
+        if event.event_type() == ListenerPanicked::EVENT_TYPE
+            && event.pattern_name() == "reported-panic"
+        {
+            assert_eq!(event.severity(), Severity::Error);
+            r.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    let mut listeners = EventListeners::new();
+    listeners.add(FnListener::new(|_: &TestEvent| {}));
+    listeners.add(FnListener::new(|_: &TestEvent| panic!("broken listener")));
+
+    let event = TestEvent {
+        name: "reported-panic".to_string(),
+        timestamp: Instant::now(),
+        value: 0,
+    };
+    listeners.emit(&event);
+    subscription.unsubscribe();
+
+    assert_eq!(reports.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn listener_can_opt_out_of_panic_catching() {
+    let mut listeners = EventListeners::new();
+    listeners.add(FnListener::new(|_: &TestEvent| panic!("surface me")).catch_panics(false));
+
+    let event = TestEvent {
+        name: "uncaught-panic".to_string(),
+        timestamp: Instant::now(),
+        value: 0,
+    };
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        listeners.emit(&event);
+    }));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,65 @@
+//! Standard `resilience` spans emitted by pattern layers.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tower::{Layer, Service, ServiceExt, service_fn};
+use tower_resilience_circuitbreaker::CircuitBreakerLayer;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Captured {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn circuit_breaker_span_records_outcome() {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    let _guard = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .finish()
+        .set_default();
+
+    let layer = CircuitBreakerLayer::builder().name("payments").build();
+    let mut service = layer.layer(service_fn(|req: u32| async move {
+        Ok::<_, std::io::Error>(req)
+    }));
+
+    service.ready().await.unwrap().call(1).await.unwrap();
+    let output = captured.text();
+    assert!(
+        output.contains("resilience.pattern=\"circuit_breaker\""),
+        "{output}"
+    );
+    assert!(output.contains("resilience.name=\"payments\""), "{output}");
+    assert!(
+        output.contains("resilience.outcome=\"success\""),
+        "{output}"
+    );
+
+    service.force_open().await;
+    let _ = service.ready().await.unwrap().call(2).await;
+    let output = captured.text();
+    assert!(
+        output.contains("resilience.outcome=\"rejected\""),
+        "{output}"
+    );
+}
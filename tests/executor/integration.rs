@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tower::{Service, ServiceBuilder, ServiceExt};
-use tower_resilience_executor::{ExecutorError, ExecutorLayer};
+use tower_resilience_executor::{ExecutorError, ExecutorLayer, TaskFuture};
 
 #[tokio::test]
 async fn basic_request_processing() {
@@ -171,3 +171,216 @@ async fn builder_pattern_works() {
     let resp = svc.ready().await.unwrap().call(41).await.unwrap();
     assert_eq!(resp, 42);
 }
+
+#[tokio::test]
+async fn max_in_flight_rejects_when_queue_full() {
+    let svc = tower::service_fn(|_req: ()| async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok::<_, std::io::Error>(())
+    });
+
+    let svc = ServiceBuilder::new()
+        .layer(
+            ExecutorLayer::<tokio::runtime::Handle>::builder()
+                .current()
+                .max_in_flight(1)
+                .max_queue(1)
+                .build(),
+        )
+        .service(svc);
+
+    // One runs, one waits in the queue, one is rejected
+    let mut a = svc.clone();
+    let mut b = svc.clone();
+    let mut c = svc.clone();
+    let a = a.ready().await.unwrap().call(());
+    let b = b.ready().await.unwrap().call(());
+    let c = c.ready().await.unwrap().call(());
+
+    let (a, b, c) = tokio::join!(a, b, c);
+    assert!(a.is_ok());
+    assert!(b.is_ok());
+    assert!(matches!(c, Err(ExecutorError::Saturated)));
+}
+
+#[tokio::test]
+async fn max_in_flight_bounds_parallelism() {
+    let current = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let (cur, pk) = (Arc::clone(&current), Arc::clone(&peak));
+
+    let svc = tower::service_fn(move |_req: ()| {
+        let current = Arc::clone(&cur);
+        let peak = Arc::clone(&pk);
+        async move {
+            let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            current.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, std::io::Error>(())
+        }
+    });
+
+    let svc = ServiceBuilder::new()
+        .layer(
+            ExecutorLayer::<tokio::runtime::Handle>::builder()
+                .current()
+                .max_in_flight(2)
+                .max_queue(10)
+                .build(),
+        )
+        .service(svc);
+
+    let mut handles = Vec::new();
+    for _ in 0..8 {
+        let mut svc = svc.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(()).await
+        }));
+    }
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn panics_become_errors() {
+    let panics = Arc::new(AtomicUsize::new(0));
+    let p = Arc::clone(&panics);
+
+    let svc = tower::service_fn(|fail: bool| async move {
+        if fail {
+            panic!("inner service exploded");
+        }
+        Ok::<_, std::io::Error>(())
+    });
+
+    let mut svc = ServiceBuilder::new()
+        .layer(
+            ExecutorLayer::<tokio::runtime::Handle>::builder()
+                .current()
+                .on_task_panicked(move |message| {
+                    assert_eq!(message, "inner service exploded");
+                    p.fetch_add(1, Ordering::SeqCst);
+                })
+                .build(),
+        )
+        .service(svc);
+
+    let result = svc.ready().await.unwrap().call(true).await;
+    assert!(matches!(
+        result,
+        Err(ExecutorError::Panicked(ref message)) if message == "inner service exploded"
+    ));
+    assert_eq!(panics.load(Ordering::SeqCst), 1);
+
+    // The service keeps working after a panic
+    assert!(svc.ready().await.unwrap().call(false).await.is_ok());
+}
+
+#[tokio::test]
+async fn task_timeout_aborts_runaway_tasks() {
+    let timeouts = Arc::new(AtomicUsize::new(0));
+    let finished = Arc::new(AtomicUsize::new(0));
+    let t = Arc::clone(&timeouts);
+    let f = Arc::clone(&finished);
+
+    let svc = tower::service_fn(move |_req: ()| {
+        let finished = Arc::clone(&f);
+        async move {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            finished.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, std::io::Error>(())
+        }
+    });
+
+    let mut svc = ServiceBuilder::new()
+        .layer(
+            ExecutorLayer::<tokio::runtime::Handle>::builder()
+                .current()
+                .task_timeout(Duration::from_millis(20))
+                .on_task_timeout(move |_| {
+                    t.fetch_add(1, Ordering::SeqCst);
+                })
+                .build(),
+        )
+        .service(svc);
+
+    let result = svc.ready().await.unwrap().call(()).await;
+    assert!(matches!(result, Err(ExecutorError::Timeout)));
+    assert_eq!(timeouts.load(Ordering::SeqCst), 1);
+    assert_eq!(finished.load(Ordering::SeqCst), 0);
+}
+
+tokio::task_local! {
+    static REQUEST_ID: u64;
+}
+
+#[tokio::test]
+async fn context_propagator_carries_task_locals() {
+    let svc = tower::service_fn(|_req: ()| async move {
+        Ok::<_, std::io::Error>(REQUEST_ID.try_with(|id| *id).ok())
+    });
+
+    let mut svc = ServiceBuilder::new()
+        .layer(
+            ExecutorLayer::<tokio::runtime::Handle>::builder()
+                .current()
+                .context_propagator(|| {
+                    let id = REQUEST_ID.try_with(|id| *id).ok();
+                    move |task: TaskFuture| -> TaskFuture {
+                        match id {
+                            Some(id) => Box::pin(REQUEST_ID.scope(id, task)),
+                            None => task,
+                        }
+                    }
+                })
+                .build(),
+        )
+        .service(svc);
+
+    let svc = &mut svc;
+    let seen = REQUEST_ID
+        .scope(7, async move { svc.ready().await.unwrap().call(()).await })
+        .await
+        .unwrap();
+    assert_eq!(seen, Some(7));
+}
+
+#[tokio::test]
+async fn shutdown_waits_for_tasks_then_aborts_stragglers() {
+    let drained = Arc::new(AtomicUsize::new(usize::MAX));
+    let d = Arc::clone(&drained);
+
+    let svc = tower::service_fn(|delay_ms: u64| async move {
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        Ok::<_, std::io::Error>(delay_ms)
+    });
+
+    let layer = ExecutorLayer::<tokio::runtime::Handle>::builder()
+        .current()
+        .on_drain_completed(move |aborted| d.store(aborted, Ordering::SeqCst))
+        .build();
+    let svc = tower::Layer::layer(&layer, svc);
+
+    let mut fast = svc.clone();
+    let mut slow = svc.clone();
+    let fast = fast.ready().await.unwrap().call(10);
+    let slow = slow.ready().await.unwrap().call(10_000);
+    assert_eq!(svc.in_flight(), 2);
+
+    let aborted = svc.shutdown(Duration::from_millis(100)).await;
+    assert_eq!(aborted, 1);
+    assert_eq!(drained.load(Ordering::SeqCst), 1);
+    assert_eq!(svc.in_flight(), 0);
+
+    assert_eq!(fast.await.unwrap(), 10);
+    assert!(matches!(slow.await, Err(ExecutorError::TaskCancelled)));
+
+    // New requests are rejected once shutdown has begun
+    let mut late = svc.clone();
+    let result = late.ready().await.unwrap().call(1).await;
+    assert!(matches!(result, Err(ExecutorError::ShuttingDown)));
+}
@@ -208,3 +208,52 @@ async fn test_concurrent_calls() {
         assert_eq!(result, "fallback");
     }
 }
+
+#[tokio::test]
+async fn test_fallback_rate_exceeded_callback() {
+    let ratios = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let r = Arc::clone(&ratios);
+
+    // Requests starting with "fail" fail and are served by the fallback
+    let service = service_fn(|req: String| async move {
+        if req.starts_with("fail") {
+            Err(TestError::new("down"))
+        } else {
+            Ok(req)
+        }
+    });
+
+    let layer: FallbackLayer<String, String, TestError> = FallbackLayer::builder()
+        .value("degraded".to_string())
+        .fallback_rate_window(4)
+        .on_fallback_rate_exceeded(0.5, move |ratio| r.lock().unwrap().push(ratio))
+        .build();
+    let mut service = layer.layer(service);
+
+    let mut call = async |req: &str| {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call(req.to_string())
+            .await
+            .unwrap()
+    };
+
+    // 2 of 4 degraded: at the threshold, not over it
+    for req in ["ok", "fail", "ok", "fail"] {
+        call(req).await;
+    }
+    assert!(ratios.lock().unwrap().is_empty());
+
+    // 3 of 4 degraded: reported once, not again while still over
+    call("fail").await;
+    call("fail").await;
+    assert_eq!(*ratios.lock().unwrap(), vec![0.75]);
+
+    // Recovering and degrading again reports a new crossing
+    for req in ["ok", "ok", "ok", "fail", "fail", "fail"] {
+        call(req).await;
+    }
+    assert_eq!(*ratios.lock().unwrap(), vec![0.75, 0.75]);
+}
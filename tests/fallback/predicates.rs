@@ -210,3 +210,95 @@ async fn test_predicate_with_from_error_strategy() {
     let r2 = service.ready().await.unwrap().call("404".to_string()).await;
     assert!(matches!(r2, Err(FallbackError::Inner(e)) if e.code == 404));
 }
+
+#[tokio::test]
+async fn test_on_circuit_open_only_handles_open_circuit() {
+    use tower_resilience_circuitbreaker::{CircuitBreakerError, CircuitBreakerLayer};
+
+    let service =
+        service_fn(
+            |_req: String| async move { Err::<String, _>(TestError::new("downstream error")) },
+        );
+    let breaker = CircuitBreakerLayer::builder().build().layer(service);
+    let handle = breaker.clone();
+
+    let layer = FallbackLayer::<String, String, CircuitBreakerError<TestError>>::on_circuit_open(
+        "circuit open fallback".to_string(),
+    );
+    let mut service = layer.layer(breaker);
+
+    // Errors from the inner service pass through
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+    match result {
+        Err(FallbackError::Inner(CircuitBreakerError::Inner(e))) => {
+            assert_eq!(e.message, "downstream error")
+        }
+        _ => panic!("expected inner error to pass through"),
+    }
+
+    // Open-circuit rejections are replaced
+    handle.force_open().await;
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "circuit open fallback");
+}
+
+#[tokio::test]
+async fn test_on_circuit_open_handles_open_circuit_below_other_layers() {
+    use std::time::Duration;
+    use tower_resilience_circuitbreaker::{CircuitBreakerError, CircuitBreakerLayer};
+    use tower_resilience_timelimiter::{TimeLimiterError, TimeLimiterLayer};
+
+    let service =
+        service_fn(
+            |_req: String| async move { Err::<String, _>(TestError::new("downstream error")) },
+        );
+    let breaker = CircuitBreakerLayer::builder().build().layer(service);
+    let handle = breaker.clone();
+    let limited = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_secs(5))
+        .build()
+        .layer(breaker);
+
+    let layer = FallbackLayer::<
+        String,
+        String,
+        TimeLimiterError<CircuitBreakerError<TestError>>,
+    >::on_circuit_open("circuit open fallback".to_string());
+    let mut service = layer.layer(limited);
+
+    // Errors from the inner service pass through
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+    assert!(matches!(
+        result,
+        Err(FallbackError::Inner(TimeLimiterError::Inner(
+            CircuitBreakerError::Inner(_)
+        )))
+    ));
+
+    // An open circuit below the time limiter is still replaced
+    handle.force_open().await;
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "circuit open fallback");
+}
@@ -0,0 +1,112 @@
+//! Integration tests for health check events.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tower_resilience_core::ResilienceEvent;
+use tower_resilience_healthcheck::{
+    HealthCheckConfig, HealthCheckWrapper, HealthEvent, HealthStatus,
+};
+
+#[tokio::test]
+async fn all_unhealthy_fires_once_when_pool_goes_down() {
+    let up = Arc::new(AtomicBool::new(true));
+    let alerts = Arc::new(AtomicUsize::new(0));
+    let changes = Arc::new(Mutex::new(Vec::new()));
+
+    let a = Arc::clone(&alerts);
+    let c = Arc::clone(&changes);
+    let config = HealthCheckConfig::builder()
+        .name("backends")
+        .interval(Duration::from_millis(20))
+        .initial_delay(Duration::from_millis(1))
+        .failure_threshold(1)
+        .on_all_unhealthy(move || {
+            a.fetch_add(1, Ordering::SeqCst);
+        })
+        .on_status_changed(move |resource, from, to| {
+            c.lock().unwrap().push((resource.to_string(), from, to));
+        })
+        .build();
+
+    let u = Arc::clone(&up);
+    let wrapper = HealthCheckWrapper::builder()
+        .with_context("a".to_string(), "a")
+        .with_context("b".to_string(), "b")
+        .with_checker(move |_: &String| {
+            let healthy = u.load(Ordering::SeqCst);
+            async move {
+                if healthy {
+                    HealthStatus::Healthy
+                } else {
+                    HealthStatus::Unhealthy
+                }
+            }
+        })
+        .with_config(config)
+        .build();
+
+    wrapper.start().await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(alerts.load(Ordering::SeqCst), 0);
+
+    up.store(false, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // Several failing rounds, but only one alert
+    assert_eq!(alerts.load(Ordering::SeqCst), 1);
+
+    let changes = changes.lock().unwrap().clone();
+    for name in ["a", "b"] {
+        assert!(changes.contains(&(
+            name.to_string(),
+            HealthStatus::Unknown,
+            HealthStatus::Healthy
+        )));
+        assert!(changes.contains(&(
+            name.to_string(),
+            HealthStatus::Healthy,
+            HealthStatus::Unhealthy
+        )));
+    }
+
+    wrapper.stop().await;
+}
+
+#[tokio::test]
+async fn check_events_carry_pattern_name_and_timeout() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let e = Arc::clone(&events);
+
+    let wrapper = HealthCheckWrapper::builder()
+        .with_context("slow".to_string(), "slow")
+        .with_checker(|_: &String| async {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            HealthStatus::Healthy
+        })
+        .with_name("probe-pool")
+        .with_timeout(Duration::from_millis(10))
+        .with_initial_delay(Duration::from_millis(1))
+        .with_interval(Duration::from_secs(60))
+        .with_event_listener(move |event| e.lock().unwrap().push(event.clone()))
+        .build();
+
+    wrapper.start().await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let events = events.lock().unwrap().clone();
+    let failed = events
+        .iter()
+        .find(|event| event.event_type() == "health_check_failed")
+        .expect("timed out check should emit CheckFailed");
+    assert_eq!(failed.pattern_name(), "probe-pool");
+    assert!(matches!(
+        failed,
+        HealthEvent::CheckFailed {
+            timed_out: true,
+            ..
+        }
+    ));
+
+    wrapper.stop().await;
+}
@@ -1 +1,3 @@
+mod events;
 mod integration;
+mod service;
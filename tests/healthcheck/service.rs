@@ -0,0 +1,230 @@
+//! Integration tests for routing requests through a health-checked pool.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceBuilder, ServiceExt};
+use tower_resilience_healthcheck::{
+    HealthCheckLayer, HealthCheckWrapper, HealthChecker, HealthStatus, SelectionStrategy,
+};
+use tower_resilience_timelimiter::TimeLimiterLayer;
+
+/// A backend whose health can be toggled from the test.
+#[derive(Clone)]
+struct Backend {
+    name: &'static str,
+    up: Arc<AtomicBool>,
+}
+
+impl Backend {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            up: Arc::new(AtomicBool::new(true)),
+        }
+    }
+}
+
+impl Service<String> for Backend {
+    type Response = String;
+    type Error = std::io::Error;
+    type Future = std::future::Ready<Result<String, std::io::Error>>;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: String) -> Self::Future {
+        std::future::ready(Ok(format!("{}: {}", self.name, req)))
+    }
+}
+
+struct UpChecker;
+
+impl HealthChecker<Backend> for UpChecker {
+    async fn check(&self, backend: &Backend) -> HealthStatus {
+        if backend.up.load(Ordering::SeqCst) {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+}
+
+#[tokio::test]
+async fn routes_around_unhealthy_backend_in_service_builder_stack() {
+    let primary = Backend::new("primary");
+    let secondary = Backend::new("secondary");
+
+    let pool = Arc::new(
+        HealthCheckWrapper::builder()
+            .with_context(primary.clone(), "primary")
+            .with_context(secondary.clone(), "secondary")
+            .with_checker(UpChecker)
+            .with_interval(Duration::from_millis(20))
+            .with_initial_delay(Duration::from_millis(1))
+            .with_failure_threshold(1)
+            .build(),
+    );
+    pool.start().await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_secs(1))
+                .build(),
+        )
+        .layer(HealthCheckLayer::new())
+        .service(Arc::clone(&pool));
+
+    let response = service.ready().await.unwrap().call("a".into()).await;
+    assert_eq!(response.unwrap(), "primary: a");
+
+    primary.up.store(false, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    let response = service.ready().await.unwrap().call("b".into()).await;
+    assert_eq!(response.unwrap(), "secondary: b");
+
+    pool.stop().await;
+}
+
+#[tokio::test]
+async fn round_robin_across_healthy_backends() {
+    let pool = HealthCheckWrapper::builder()
+        .with_context(Backend::new("a"), "a")
+        .with_context(Backend::new("b"), "b")
+        .with_checker(UpChecker)
+        .with_interval(Duration::from_millis(20))
+        .with_initial_delay(Duration::from_millis(1))
+        .with_selection_strategy(SelectionStrategy::RoundRobin)
+        .build();
+    pool.start().await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    let mut service = HealthCheckLayer::new().layer(pool);
+
+    let mut responses = Vec::new();
+    for _ in 0..4 {
+        responses.push(
+            service
+                .ready()
+                .await
+                .unwrap()
+                .call("x".into())
+                .await
+                .unwrap(),
+        );
+    }
+    assert_eq!(responses, ["a: x", "b: x", "a: x", "b: x"]);
+}
+
+#[tokio::test]
+async fn rejects_requests_before_any_backend_is_checked() {
+    let pool = HealthCheckWrapper::builder()
+        .with_context(Backend::new("a"), "a")
+        .with_checker(UpChecker)
+        .build();
+
+    let service = HealthCheckLayer::new().layer(pool);
+    let err = service.oneshot("x".into()).await.unwrap_err();
+    assert!(err.is_no_healthy_resource());
+}
+
+#[tokio::test]
+async fn passive_failures_eject_backend_between_probes() {
+    let primary = Backend::new("primary");
+    let secondary = Backend::new("secondary");
+
+    let pool = Arc::new(
+        HealthCheckWrapper::builder()
+            .with_context(primary, "primary")
+            .with_context(secondary, "secondary")
+            .with_checker(UpChecker)
+            .with_interval(Duration::from_secs(60))
+            .with_initial_delay(Duration::from_millis(1))
+            .with_failure_threshold(1)
+            .build(),
+    );
+    pool.start().await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    let mut service = HealthCheckLayer::new().layer(Arc::clone(&pool));
+    let response = service.ready().await.unwrap().call("a".into()).await;
+    assert_eq!(response.unwrap(), "primary: a");
+
+    // The caller saw primary misbehave; the probe still thinks it is fine
+    pool.report_failure("primary");
+
+    let response = service.ready().await.unwrap().call("b".into()).await;
+    assert_eq!(response.unwrap(), "secondary: b");
+
+    pool.report_success("primary");
+    let response = service.ready().await.unwrap().call("c".into()).await;
+    assert_eq!(response.unwrap(), "primary: c");
+}
+
+#[tokio::test]
+async fn weighted_round_robin_follows_weights() {
+    let pool = HealthCheckWrapper::builder()
+        .with_weighted_context(Backend::new("large"), "large", 2)
+        .with_weighted_context(Backend::new("small"), "small", 1)
+        .with_checker(UpChecker)
+        .with_interval(Duration::from_millis(20))
+        .with_initial_delay(Duration::from_millis(1))
+        .with_selection_strategy(SelectionStrategy::WeightedRoundRobin)
+        .build();
+    pool.start().await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    let mut service = HealthCheckLayer::new().layer(pool);
+
+    let mut responses = Vec::new();
+    for _ in 0..6 {
+        responses.push(
+            service
+                .ready()
+                .await
+                .unwrap()
+                .call("x".into())
+                .await
+                .unwrap(),
+        );
+    }
+    assert_eq!(
+        responses,
+        [
+            "large: x", "large: x", "small: x", "large: x", "large: x", "small: x"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn least_outstanding_avoids_busy_backends() {
+    let pool = HealthCheckWrapper::builder()
+        .with_context(Backend::new("a"), "a")
+        .with_context(Backend::new("b"), "b")
+        .with_checker(UpChecker)
+        .with_interval(Duration::from_millis(20))
+        .with_initial_delay(Duration::from_millis(1))
+        .with_selection_strategy(SelectionStrategy::LeastOutstanding)
+        .build();
+    pool.start().await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    let mut service = HealthCheckLayer::new().layer(pool);
+
+    // Requests count as in flight from dispatch until their future completes
+    let first = service.call("1".into());
+    let second = service.call("2".into());
+    assert_eq!(first.await.unwrap(), "a: 1");
+    assert_eq!(second.await.unwrap(), "b: 2");
+
+    // Both finished, so the pool is idle again
+    let third = service.call("3".into());
+    assert_eq!(third.await.unwrap(), "a: 3");
+}
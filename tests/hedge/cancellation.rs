@@ -0,0 +1,254 @@
+//! Tests for cancellation of losing hedge attempts.
+
+use super::TestError;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt, service_fn};
+use tower_resilience_hedge::{CancellationToken, HedgeLayer};
+
+#[tokio::test]
+async fn test_losing_attempt_is_aborted() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let slow_finished = Arc::new(AtomicBool::new(false));
+    let c = Arc::clone(&calls);
+    let sf = Arc::clone(&slow_finished);
+
+    let service = service_fn(move |_req: String| {
+        let c = Arc::clone(&c);
+        let sf = Arc::clone(&sf);
+        async move {
+            if c.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                sf.store(true, Ordering::SeqCst);
+            }
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .build();
+    let mut service = layer.layer(service);
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(
+        !slow_finished.load(Ordering::SeqCst),
+        "losing primary should have been aborted"
+    );
+}
+
+#[tokio::test]
+async fn test_on_cancel_called_for_losing_attempt() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+    let cc = Arc::clone(&cancelled);
+
+    let service = service_fn(move |_req: String| {
+        let c = Arc::clone(&c);
+        async move {
+            if c.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .on_cancel(move |req: &String| {
+            assert_eq!(req, "test");
+            cc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(cancelled.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_on_cancel_not_called_without_hedge() {
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&cancelled);
+
+    let service =
+        service_fn(|_req: String| async move { Ok::<_, TestError>("success".to_string()) });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(100))
+        .max_hedged_attempts(2)
+        .on_cancel(move |_req: &String| {
+            cc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(cancelled.load(Ordering::SeqCst), 0);
+}
+
+#[derive(Clone)]
+struct TokenRequest {
+    cancel: Option<CancellationToken>,
+}
+
+#[tokio::test]
+async fn test_propagated_token_cancelled_for_loser() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let observed_cancel = Arc::new(AtomicBool::new(false));
+    let c = Arc::clone(&calls);
+    let oc = Arc::clone(&observed_cancel);
+
+    // Tokens are observed outside the attempt task, since the task itself
+    // is aborted along with the token being cancelled.
+    let tokens = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let t = Arc::clone(&tokens);
+
+    let service = service_fn(move |req: TokenRequest| {
+        let c = Arc::clone(&c);
+        let t = Arc::clone(&t);
+        async move {
+            let token = req.cancel.expect("token should be attached");
+            t.lock().unwrap().push(token.clone());
+            if c.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .propagate_cancellation(|req: &mut TokenRequest, token| {
+            req.cancel = Some(token);
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    let primary_token = {
+        let call = service
+            .ready()
+            .await
+            .unwrap()
+            .call(TokenRequest { cancel: None });
+        let result = call.await;
+        assert!(result.is_ok());
+        tokens.lock().unwrap()[0].clone()
+    };
+
+    let watcher = tokio::spawn(async move {
+        primary_token.cancelled().await;
+        oc.store(true, Ordering::SeqCst);
+    });
+    tokio::time::timeout(Duration::from_millis(100), watcher)
+        .await
+        .expect("primary token should be cancelled")
+        .unwrap();
+
+    assert!(observed_cancel.load(Ordering::SeqCst));
+    let tokens = tokens.lock().unwrap();
+    assert_eq!(tokens.len(), 2);
+    assert!(!tokens[1].is_cancelled(), "winning token is not cancelled");
+}
+
+#[tokio::test]
+async fn test_dropping_call_cancels_attempts() {
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&cancelled);
+
+    let service = service_fn(|_req: String| async move {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Ok::<_, TestError>("success".to_string())
+    });
+
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(3)
+        .on_cancel(move |_req: &String| {
+            cc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    let call = service.ready().await.unwrap().call("test".to_string());
+    let result = tokio::time::timeout(Duration::from_millis(50), call).await;
+    assert!(result.is_err());
+
+    assert_eq!(cancelled.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_on_cancel_and_propagate_cancellation_combine() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+    let cancelled = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let cl = Arc::clone(&cancelled);
+
+    // The primary is slow, so the hedge wins and the primary is cancelled
+    let service = service_fn(move |req: TokenRequest| {
+        assert!(req.cancel.is_some(), "token should be attached");
+        let slow = c.fetch_add(1, Ordering::SeqCst) == 0;
+        async move {
+            if slow {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .on_cancel(move |req: &TokenRequest| {
+            cl.lock().unwrap().push(req.cancel.clone());
+        })
+        .propagate_cancellation(|req: &mut TokenRequest, token| {
+            req.cancel = Some(token);
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest { cancel: None })
+        .await;
+    assert!(result.is_ok());
+
+    let cancelled = cancelled.lock().unwrap();
+    assert_eq!(cancelled.len(), 1);
+    let token = cancelled[0]
+        .as_ref()
+        .expect("cancelled request has a token");
+    assert!(token.is_cancelled());
+}
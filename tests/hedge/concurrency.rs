@@ -1,11 +1,12 @@
 //! Concurrency tests for hedge pattern.
 
 use super::TestError;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tower::{Layer, Service, ServiceExt, service_fn};
-use tower_resilience_hedge::HedgeLayer;
+use tower_resilience_core::FnListener;
+use tower_resilience_hedge::{HedgeEvent, HedgeLayer, SuppressionReason};
 
 #[tokio::test]
 async fn test_concurrent_calls_independent() {
@@ -252,7 +253,7 @@ async fn test_mixed_success_failure_concurrent() {
 #[tokio::test]
 async fn test_cancellation_on_first_success() {
     // This test verifies that when one request succeeds, the others are
-    // effectively cancelled (their results are ignored).
+    // cancelled (their tasks are aborted).
     let completed_count = Arc::new(AtomicUsize::new(0));
     let started_count = Arc::new(AtomicUsize::new(0));
 
@@ -306,9 +307,167 @@ async fn test_cancellation_on_first_success() {
     // Both should have started
     assert_eq!(started_count.load(Ordering::SeqCst), 2);
 
-    // Give time for primary to complete
+    // Give time for primary to complete if it had not been cancelled
     tokio::time::sleep(Duration::from_millis(1100)).await;
 
-    // Both should eventually complete (tasks run to completion)
-    assert_eq!(completed_count.load(Ordering::SeqCst), 2);
+    // Only the hedge completes; the losing primary was aborted
+    assert_eq!(completed_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_max_outstanding_hedges_shared_across_calls() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let suppressed = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+    let sc = Arc::clone(&suppressed);
+
+    let service = service_fn(move |_req: String| {
+        let cc = Arc::clone(&cc);
+        async move {
+            cc.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(3)
+        .max_outstanding_hedges(2)
+        .on_event(FnListener::new(move |e: &HedgeEvent| {
+            if let HedgeEvent::HedgeSuppressed {
+                reason: SuppressionReason::MaxOutstanding,
+                ..
+            } = e
+            {
+                sc.fetch_add(1, Ordering::SeqCst);
+            }
+        }))
+        .build();
+    let service = layer.layer(service);
+
+    // Two concurrent calls would fire four hedges, but only two may be
+    // outstanding at once. The first call takes both slots; the second is
+    // suppressed on its first hedge and stops hedging.
+    let mut handles = Vec::new();
+    for _ in 0..2 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call("test".to_string()).await
+        }));
+    }
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    assert_eq!(call_count.load(Ordering::SeqCst), 4);
+    assert_eq!(suppressed.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_require_ready_suppresses_hedge_when_saturated() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let suppressed = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+    let sc = Arc::clone(&suppressed);
+
+    let inner = service_fn(move |_req: String| {
+        let cc = Arc::clone(&cc);
+        async move {
+            cc.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+    // A pool with room for a single request at a time
+    let inner = tower::limit::ConcurrencyLimit::new(inner, 1);
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .require_ready_hedges(true)
+        .on_event(FnListener::new(move |e: &HedgeEvent| {
+            if let HedgeEvent::HedgeSuppressed {
+                reason: SuppressionReason::NotReady,
+                ..
+            } = e
+            {
+                sc.fetch_add(1, Ordering::SeqCst);
+            }
+        }))
+        .build();
+    let mut service = layer.layer(inner);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+    assert!(result.is_ok());
+
+    // The primary holds the only slot, so the hedge was never fired
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    assert_eq!(suppressed.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_saturation_signal_disables_hedging() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let saturated = Arc::new(AtomicBool::new(true));
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let cc = Arc::clone(&call_count);
+    let s = Arc::clone(&saturated);
+    let ev = Arc::clone(&events);
+
+    let inner = service_fn(move |_req: String| {
+        let cc = Arc::clone(&cc);
+        async move {
+            cc.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(60)).await;
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(10))
+        .max_hedged_attempts(2)
+        .disable_when_saturated(move || s.load(Ordering::SeqCst))
+        .on_event(FnListener::new(move |e: &HedgeEvent| match e {
+            HedgeEvent::HedgingDisabled { .. } => ev.lock().unwrap().push("disabled"),
+            HedgeEvent::HedgingEnabled { .. } => ev.lock().unwrap().push("enabled"),
+            HedgeEvent::HedgeSuppressed {
+                reason: SuppressionReason::Saturated,
+                ..
+            } => ev.lock().unwrap().push("suppressed"),
+            _ => {}
+        }))
+        .build();
+    let mut service = layer.layer(inner);
+
+    // Saturated: only the primary is sent
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+    // Recovered: the hedge fires again
+    saturated.store(false, Ordering::SeqCst);
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(call_count.load(Ordering::SeqCst), 3);
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["disabled", "suppressed", "enabled"]
+    );
 }
@@ -0,0 +1,142 @@
+//! Tests for decorating hedge requests.
+
+use super::TestError;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt, service_fn};
+use tower_resilience_hedge::{CancellationToken, HedgeLayer};
+
+#[tokio::test]
+async fn test_hedges_are_decorated_with_attempt_number() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let s = Arc::clone(&seen);
+
+    // Every attempt fails so all of them run to completion
+    let service = service_fn(move |req: String| {
+        s.lock().unwrap().push(req);
+        async move { Err::<String, _>(TestError::new("fail")) }
+    });
+
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(3)
+        .decorate_hedge(|req: String, attempt| format!("{}#hedge-{}", req, attempt))
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = service.ready().await.unwrap().call("req".to_string()).await;
+    assert!(result.is_err());
+
+    let mut seen = seen.lock().unwrap().clone();
+    seen.sort();
+    assert_eq!(seen, vec!["req", "req#hedge-1", "req#hedge-2"]);
+}
+
+#[tokio::test]
+async fn test_on_cancel_receives_decorated_request() {
+    let cancelled = Arc::new(Mutex::new(Vec::new()));
+    let c = Arc::clone(&cancelled);
+
+    // The hedge is slow, so the primary wins and the hedge is cancelled
+    let service = service_fn(|req: String| async move {
+        let ms = if req.contains("#hedge") { 500 } else { 60 };
+        tokio::time::sleep(Duration::from_millis(ms)).await;
+        Ok::<_, TestError>(req)
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .on_cancel(move |req: &String| c.lock().unwrap().push(req.clone()))
+        .decorate_hedge(|req: String, attempt| format!("{}#hedge-{}", req, attempt))
+        .build();
+    let mut service = layer.layer(service);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("req".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "req");
+    assert_eq!(*cancelled.lock().unwrap(), vec!["req#hedge-1"]);
+}
+
+#[derive(Clone)]
+struct TaggedRequest {
+    hedge: Option<usize>,
+    cancel: Option<CancellationToken>,
+}
+
+#[tokio::test]
+async fn test_decorate_keeps_cancellation_hook() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let s = Arc::clone(&seen);
+
+    let service = service_fn(move |req: TaggedRequest| {
+        s.lock().unwrap().push((req.hedge, req.cancel.is_some()));
+        async move {
+            if req.hedge.is_none() {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+            Ok::<_, TestError>("ok".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .propagate_cancellation(|req: &mut TaggedRequest, token| {
+            req.cancel = Some(token);
+        })
+        .decorate_hedge(|req: TaggedRequest, attempt| TaggedRequest {
+            hedge: Some(attempt),
+            ..req
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TaggedRequest {
+            hedge: None,
+            cancel: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(response, "ok");
+
+    assert_eq!(*seen.lock().unwrap(), vec![(None, true), (Some(1), true)]);
+}
+
+#[tokio::test]
+async fn test_decorator_survives_later_cancel_hook() {
+    let cancelled = Arc::new(Mutex::new(Vec::new()));
+    let c = Arc::clone(&cancelled);
+
+    // The hedge is slow, so the primary wins and the hedge is cancelled
+    let service = service_fn(|req: String| async move {
+        let ms = if req.contains("#hedge") { 500 } else { 60 };
+        tokio::time::sleep(Duration::from_millis(ms)).await;
+        Ok::<_, TestError>(req)
+    });
+
+    // The decorator is configured before the cancellation hook
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .decorate_hedge(|req: String, attempt| format!("{}#hedge-{}", req, attempt))
+        .on_cancel(move |req: &String| c.lock().unwrap().push(req.clone()))
+        .build();
+    let mut service = layer.layer(service);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("req".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "req");
+    assert_eq!(*cancelled.lock().unwrap(), vec!["req#hedge-1"]);
+}
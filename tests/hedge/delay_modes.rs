@@ -5,7 +5,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tower::{Layer, Service, ServiceExt, service_fn};
-use tower_resilience_hedge::HedgeLayer;
+use tower_resilience_hedge::{HedgeLayer, LatencyTracker};
 
 #[tokio::test]
 async fn test_parallel_mode_fires_all_immediately() {
@@ -294,3 +294,91 @@ async fn test_multiple_hedges_with_increasing_delays() {
         total_time
     );
 }
+
+#[tokio::test]
+async fn test_quantile_mode_no_hedge_before_min_samples() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = service_fn(move |_req: String| {
+        let cc = Arc::clone(&cc);
+        async move {
+            cc.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, TestError>("success".to_string())
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay_from_latency(LatencyTracker::new(0.5).min_samples(3))
+        .max_hedged_attempts(2)
+        .build();
+    let mut service = layer.layer(service);
+
+    for _ in 0..2 {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+    }
+
+    // Not enough samples yet, so no hedges were fired
+    assert_eq!(call_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_quantile_mode_hedges_after_warmup() {
+    let slow_calls = Arc::new(AtomicUsize::new(0));
+    let sc = Arc::clone(&slow_calls);
+
+    let service = service_fn(move |req: String| {
+        let sc = Arc::clone(&sc);
+        async move {
+            if req == "slow" && sc.fetch_add(1, Ordering::SeqCst) == 0 {
+                // Only the first "slow" attempt is slow; the hedge is fast
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            } else {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            Ok::<_, TestError>(req)
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay_from_latency(LatencyTracker::new(0.95).min_samples(5))
+        .max_hedged_attempts(2)
+        .build();
+    let mut service = layer.layer(service);
+
+    // Warm up the latency tracker with fast calls
+    for _ in 0..5 {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call("warm".to_string())
+            .await
+            .unwrap();
+    }
+
+    let start = std::time::Instant::now();
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("slow".to_string())
+        .await
+        .unwrap();
+    let elapsed = start.elapsed();
+
+    assert_eq!(response, "slow");
+    assert_eq!(slow_calls.load(Ordering::SeqCst), 2);
+    assert!(
+        elapsed < Duration::from_millis(300),
+        "hedge should have won: {:?}",
+        elapsed
+    );
+}
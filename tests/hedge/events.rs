@@ -251,6 +251,9 @@ async fn test_event_name_preserved() {
             HedgeEvent::PrimaryStarted { name, .. } => name,
             HedgeEvent::PrimarySucceeded { name, .. } => name,
             HedgeEvent::HedgeStarted { name, .. } => name,
+            HedgeEvent::HedgeSuppressed { name, .. } => name,
+            HedgeEvent::HedgingDisabled { name, .. } => name,
+            HedgeEvent::HedgingEnabled { name, .. } => name,
             HedgeEvent::HedgeSucceeded { name, .. } => name,
             HedgeEvent::AllFailed { name, .. } => name,
         };
@@ -297,3 +300,52 @@ async fn test_multiple_listeners() {
     assert_eq!(events1.len(), events2.len());
     assert!(events1.len() >= 2);
 }
+
+#[tokio::test]
+async fn test_hedge_suppressed_by_budget() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let ev = Arc::clone(&events);
+    let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+
+    let service = service_fn(move |_req: String| {
+        c.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        async move { Ok::<_, TestError>("success".to_string()) }
+    });
+
+    // Each request earns half a hedge, so every other request may hedge
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(2)
+        .max_hedge_ratio(0.5)
+        .on_event(FnListener::new(move |e: &HedgeEvent| {
+            ev.lock().unwrap().push(e.clone());
+        }))
+        .build();
+    let mut service = layer.layer(service);
+
+    for _ in 0..4 {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+    }
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    let events = events.lock().unwrap();
+    let suppressed = events
+        .iter()
+        .filter(|e| matches!(e, HedgeEvent::HedgeSuppressed { attempt: 1, .. }))
+        .count();
+    let started = events
+        .iter()
+        .filter(|e| matches!(e, HedgeEvent::HedgeStarted { .. }))
+        .count();
+    assert_eq!(suppressed, 2);
+    assert_eq!(started, 2);
+    assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 6);
+}
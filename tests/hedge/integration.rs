@@ -282,3 +282,30 @@ async fn test_error_preserved_in_all_failed() {
         _ => panic!("expected AllAttemptsFailed error"),
     }
 }
+
+#[tokio::test]
+async fn test_primary_failure_with_exhausted_budget() {
+    let service = service_fn(|_req: String| async move {
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        Err::<String, _>(TestError::new("primary failed"))
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(10))
+        .max_hedged_attempts(3)
+        .max_hedge_ratio(0.0)
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        service.ready().await.unwrap().call("test".to_string()),
+    )
+    .await
+    .expect("call should not hang when hedges are suppressed");
+
+    match result {
+        Err(HedgeError::AllAttemptsFailed(e)) => assert_eq!(e.message, "primary failed"),
+        other => panic!("expected AllAttemptsFailed, got {:?}", other),
+    }
+}
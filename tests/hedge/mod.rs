@@ -6,11 +6,19 @@
 //! - **delay_modes**: Tests for latency mode vs parallel mode
 //! - **events**: Tests for event emission and listeners
 //! - **concurrency**: Tests for concurrent request handling
+//! - **cancellation**: Tests for cancelling losing attempts
+//! - **selection**: Tests for result selection policies
+//! - **replicas**: Tests for hedging across replica services
+//! - **decorate**: Tests for decorating hedge requests
 
+mod cancellation;
 mod concurrency;
+mod decorate;
 mod delay_modes;
 mod events;
 mod integration;
+mod replicas;
+mod selection;
 
 use std::fmt;
 
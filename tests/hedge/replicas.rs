@@ -0,0 +1,148 @@
+//! Tests for hedging across multiple replica services.
+
+use super::TestError;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Service, ServiceExt, service_fn};
+use tower_resilience_hedge::HedgeLayer;
+
+/// A replica that responds with its id after `latency_ms`, counting calls.
+fn replica(
+    id: usize,
+    latency_ms: u64,
+    calls: Arc<AtomicUsize>,
+) -> impl Service<
+    String,
+    Response = String,
+    Error = TestError,
+    Future = impl Future<Output = Result<String, TestError>> + Send,
+> + Clone
++ Send
++ 'static {
+    service_fn(move |_req: String| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            tokio::time::sleep(Duration::from_millis(latency_ms)).await;
+            Ok(format!("replica-{id}"))
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_hedge_goes_to_next_replica() {
+    let calls: Vec<_> = (0..3).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+    let mut service = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .build()
+        .across(vec![
+            replica(0, 200, Arc::clone(&calls[0])),
+            replica(1, 10, Arc::clone(&calls[1])),
+            replica(2, 10, Arc::clone(&calls[2])),
+        ]);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(response, "replica-1");
+    assert_eq!(calls[0].load(Ordering::SeqCst), 1);
+    assert_eq!(calls[1].load(Ordering::SeqCst), 1);
+    assert_eq!(calls[2].load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_primary_rotates_round_robin() {
+    let calls: Vec<_> = (0..3).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+    let mut service = HedgeLayer::builder()
+        .delay(Duration::from_millis(100))
+        .max_hedged_attempts(2)
+        .build()
+        .across(vec![
+            replica(0, 5, Arc::clone(&calls[0])),
+            replica(1, 5, Arc::clone(&calls[1])),
+            replica(2, 5, Arc::clone(&calls[2])),
+        ]);
+
+    let mut responses = Vec::new();
+    for _ in 0..3 {
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+        responses.push(response);
+    }
+
+    assert_eq!(responses, vec!["replica-0", "replica-1", "replica-2"]);
+    for count in &calls {
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[tokio::test]
+async fn test_parallel_mode_fans_out_to_all_replicas() {
+    let calls: Vec<_> = (0..3).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+    let mut service = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(3)
+        .build()
+        .across(vec![
+            replica(0, 50, Arc::clone(&calls[0])),
+            replica(1, 50, Arc::clone(&calls[1])),
+            replica(2, 5, Arc::clone(&calls[2])),
+        ]);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(response, "replica-2");
+    for count in &calls {
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[tokio::test]
+async fn test_weighted_primary_distribution() {
+    let calls: Vec<_> = (0..2).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+    let mut service = HedgeLayer::builder()
+        .delay(Duration::from_millis(100))
+        .max_hedged_attempts(2)
+        .build()
+        .across_weighted(vec![
+            (replica(0, 1, Arc::clone(&calls[0])), 3),
+            (replica(1, 1, Arc::clone(&calls[1])), 1),
+        ]);
+
+    for _ in 0..8 {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call("test".to_string())
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(calls[0].load(Ordering::SeqCst), 6);
+    assert_eq!(calls[1].load(Ordering::SeqCst), 2);
+}
+
+#[test]
+#[should_panic(expected = "at least one replica")]
+fn test_empty_replicas_panics() {
+    let replicas: Vec<tower::util::BoxCloneService<String, String, TestError>> = Vec::new();
+    let _ = HedgeLayer::builder().build().across(replicas);
+}
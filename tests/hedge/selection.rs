@@ -0,0 +1,277 @@
+//! Tests for result selection policies.
+
+use super::TestError;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt, service_fn};
+use tower_resilience_core::FnListener;
+use tower_resilience_hedge::{HedgeError, HedgeEvent, HedgeLayer, SelectionPolicy};
+
+/// A service whose first call is the primary and returns after
+/// `primary_ms`; later calls are hedges that return after `hedge_ms`.
+fn primary_and_hedge(
+    primary_ms: u64,
+    hedge_ms: u64,
+) -> impl Service<
+    String,
+    Response = String,
+    Error = TestError,
+    Future = impl Future<Output = Result<String, TestError>> + Send,
+> + Clone
++ Send
++ 'static {
+    let calls = Arc::new(AtomicUsize::new(0));
+    service_fn(move |_req: String| {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if n == 0 {
+                tokio::time::sleep(Duration::from_millis(primary_ms)).await;
+                Ok("primary".to_string())
+            } else {
+                tokio::time::sleep(Duration::from_millis(hedge_ms)).await;
+                Ok("hedge".to_string())
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_first_success_returns_hedge() {
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .build();
+    let mut service = layer.layer(primary_and_hedge(100, 10));
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "hedge");
+}
+
+#[tokio::test]
+async fn test_prefer_primary_within_grace() {
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .selection_policy(SelectionPolicy::PreferPrimary {
+            grace: Duration::from_millis(200),
+        })
+        .build();
+    let mut service = layer.layer(primary_and_hedge(60, 10));
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "primary");
+}
+
+#[tokio::test]
+async fn test_prefer_primary_grace_elapses() {
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .selection_policy(SelectionPolicy::PreferPrimary {
+            grace: Duration::from_millis(20),
+        })
+        .build();
+    let mut service = layer.layer(primary_and_hedge(1000, 10));
+
+    let start = std::time::Instant::now();
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "hedge");
+    assert!(
+        start.elapsed() < Duration::from_millis(500),
+        "elapsed: {:?}",
+        start.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn test_prefer_primary_primary_fails_during_grace() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = service_fn(move |_req: String| {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if n == 0 {
+                tokio::time::sleep(Duration::from_millis(60)).await;
+                Err(TestError::new("primary failed"))
+            } else {
+                Ok("hedge".to_string())
+            }
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .selection_policy(SelectionPolicy::PreferPrimary {
+            grace: Duration::from_millis(500),
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    let start = std::time::Instant::now();
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "hedge");
+    assert!(start.elapsed() < Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_prefer_primary_keeps_first_held_hedge() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = service_fn(move |_req: String| {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            let ms = match n {
+                0 => 1000,
+                1 => 10,
+                _ => 30,
+            };
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+            Ok::<_, TestError>(format!("attempt-{}", n))
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(3)
+        .selection_policy(SelectionPolicy::PreferPrimary {
+            grace: Duration::from_millis(100),
+        })
+        .build();
+    let mut service = layer.layer(service);
+
+    let start = std::time::Instant::now();
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    // The second hedge must not cut the grace period short or replace the
+    // first held response.
+    assert_eq!(response, "attempt-1");
+    assert!(
+        start.elapsed() >= Duration::from_millis(100),
+        "elapsed: {:?}",
+        start.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn test_fastest_including_errors_returns_error() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = service_fn(move |_req: String| {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if n == 0 {
+                Err(TestError::new("not found"))
+            } else {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok("hedge".to_string())
+            }
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(2)
+        .selection_policy(SelectionPolicy::FastestIncludingErrors)
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+    match result {
+        Err(HedgeError::Inner(e)) => assert_eq!(e.message, "not found"),
+        other => panic!("expected inner error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_fastest_including_errors_emits_all_failed() {
+    let all_failed = Arc::new(AtomicUsize::new(0));
+    let af = Arc::clone(&all_failed);
+
+    let service =
+        service_fn(|_req: String| async move { Err::<String, _>(TestError::new("gone")) });
+
+    let layer = HedgeLayer::builder()
+        .delay(Duration::from_millis(100))
+        .max_hedged_attempts(2)
+        .selection_policy(SelectionPolicy::FastestIncludingErrors)
+        .on_event(FnListener::new(move |event: &HedgeEvent| {
+            if matches!(event, HedgeEvent::AllFailed { .. }) {
+                af.fetch_add(1, Ordering::SeqCst);
+            }
+        }))
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+    assert!(matches!(result, Err(HedgeError::Inner(_))));
+    assert_eq!(all_failed.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_first_success_waits_for_in_flight_hedges() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = service_fn(move |_req: String| {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if n == 0 {
+                Err(TestError::new("fast failure"))
+            } else {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok("hedge".to_string())
+            }
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .no_delay()
+        .max_hedged_attempts(2)
+        .build();
+    let mut service = layer.layer(service);
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response, "hedge");
+}
@@ -6,12 +6,17 @@
 
 #[cfg(feature = "metrics")]
 mod metrics_regression {
+    mod adaptive;
     mod bulkhead;
     mod cache;
     mod chaos;
     mod circuitbreaker;
+    mod coalesce;
     mod core;
+    mod hedge;
+    mod outlier;
     mod ratelimiter;
+    mod reconnect;
     mod retry;
     mod timelimiter;
 
@@ -0,0 +1,47 @@
+//! Adaptive limiter metrics regression tests
+
+use super::helpers::*;
+use serial_test::serial;
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_adaptive::{AdaptiveLimiterLayer, Aimd, LimitPolicy};
+
+#[tokio::test]
+#[serial]
+async fn adaptive_metrics_exist() {
+    init_recorder();
+
+    let layer = AdaptiveLimiterLayer::new(Aimd::builder().initial_limit(1).build())
+        .name("test_adaptive")
+        .on_limit(LimitPolicy::Reject);
+
+    let service = tower::service_fn(|req: u64| async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok::<_, &'static str>(req)
+    });
+
+    let service = layer.layer(service);
+
+    // Two concurrent calls against a limit of one - one permitted, one rejected
+    let mut first = service.clone();
+    let mut second = service.clone();
+    let (a, b) = tokio::join!(
+        async { first.ready().await.unwrap().call(1).await },
+        async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            second.ready().await.unwrap().call(2).await
+        }
+    );
+    assert!(a.is_ok());
+    assert!(b.is_err());
+
+    // Verify counter metrics
+    assert_counter_exists("resilience_adaptive_calls_total");
+    assert_metric_has_label("resilience_adaptive_calls_total", "name", "test_adaptive");
+    assert_metric_has_label("resilience_adaptive_calls_total", "result", "permitted");
+    assert_metric_has_label("resilience_adaptive_calls_total", "result", "rejected");
+
+    // Verify gauge metric
+    assert_gauge_exists("resilience_adaptive_limit");
+    assert_metric_has_label("resilience_adaptive_limit", "name", "test_adaptive");
+}
@@ -2,6 +2,6 @@
 //!
 //! Note: Bulkhead metrics tests are complex due to trait bounds on Service cloning.
 //! See tests/bulkhead/integration.rs for comprehensive bulkhead testing.
-//! The metrics emitted are: bulkhead_calls_permitted_total, bulkhead_calls_rejected_total,
-//! bulkhead_calls_finished_total, bulkhead_calls_failed_total, bulkhead_concurrent_calls,
-//! bulkhead_wait_duration_seconds, bulkhead_call_duration_seconds
+//! The metrics emitted are: resilience_bulkhead_calls_permitted_total, resilience_bulkhead_calls_rejected_total,
+//! resilience_bulkhead_calls_finished_total, resilience_bulkhead_calls_failed_total, resilience_bulkhead_concurrent_calls,
+//! resilience_bulkhead_wait_duration_seconds, resilience_bulkhead_call_duration_seconds
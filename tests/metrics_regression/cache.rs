@@ -36,14 +36,14 @@ async fn cache_metrics_exist() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify counter metrics
-    assert_counter_exists("cache_requests_total");
-    assert_metric_has_label("cache_requests_total", "cache", "test_cache");
-    assert_metric_has_label("cache_requests_total", "result", "hit");
-    assert_metric_has_label("cache_requests_total", "result", "miss");
+    assert_counter_exists("resilience_cache_requests_total");
+    assert_metric_has_label("resilience_cache_requests_total", "name", "test_cache");
+    assert_metric_has_label("resilience_cache_requests_total", "result", "hit");
+    assert_metric_has_label("resilience_cache_requests_total", "result", "miss");
 
     // Verify gauge metric
-    assert_gauge_exists("cache_size");
-    assert_metric_has_label("cache_size", "cache", "test_cache");
+    assert_gauge_exists("resilience_cache_size");
+    assert_metric_has_label("resilience_cache_size", "name", "test_cache");
 }
 
 #[tokio::test]
@@ -68,6 +68,6 @@ async fn cache_eviction_metrics() {
     }
 
     // Verify eviction counter exists
-    assert_counter_exists("cache_evictions_total");
-    assert_metric_has_label("cache_evictions_total", "cache", "eviction_cache");
+    assert_counter_exists("resilience_cache_evictions_total");
+    assert_metric_has_label("resilience_cache_evictions_total", "name", "eviction_cache");
 }
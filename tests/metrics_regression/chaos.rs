@@ -26,8 +26,12 @@ async fn chaos_error_injection_metrics() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify error injection counter
-    assert_counter_exists("chaos.errors_injected");
-    assert_metric_has_label("chaos.errors_injected", "layer", "error_chaos");
+    assert_counter_exists("resilience_chaos_errors_injected_total");
+    assert_metric_has_label(
+        "resilience_chaos_errors_injected_total",
+        "name",
+        "error_chaos",
+    );
 }
 
 #[tokio::test]
@@ -51,11 +55,12 @@ async fn chaos_latency_injection_metrics() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify latency injection metrics
-    assert_counter_exists("chaos.latency_injections");
-    assert_metric_has_label("chaos.latency_injections", "layer", "latency_chaos");
-
-    assert_histogram_exists("chaos.injected_latency_ms");
-    assert_metric_has_label("chaos.injected_latency_ms", "layer", "latency_chaos");
+    assert_histogram_exists("resilience_chaos_latency_injected_seconds");
+    assert_metric_has_label(
+        "resilience_chaos_latency_injected_seconds",
+        "name",
+        "latency_chaos",
+    );
 }
 
 #[tokio::test]
@@ -77,6 +82,64 @@ async fn chaos_passthrough_metrics() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify passthrough counter
-    assert_counter_exists("chaos.passed_through");
-    assert_metric_has_label("chaos.passed_through", "layer", "passthrough_chaos");
+    assert_counter_exists("resilience_chaos_passthrough_total");
+    assert_metric_has_label(
+        "resilience_chaos_passthrough_total",
+        "name",
+        "passthrough_chaos",
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn chaos_response_corruption_metrics() {
+    init_recorder();
+
+    let layer = ChaosLayer::builder()
+        .name("corruption_chaos")
+        .corruption_rate(1.0)
+        .corrupt_response_fn(|_resp: &'static str| "corrupted")
+        .build();
+
+    let service = tower::service_fn(|_: u64| async { Ok::<_, &'static str>("success") });
+
+    let mut service = layer.layer(service);
+
+    // Make a call whose response will be corrupted
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    // Verify corruption counter
+    assert_counter_exists("resilience_chaos_responses_corrupted_total");
+    assert_metric_has_label(
+        "resilience_chaos_responses_corrupted_total",
+        "name",
+        "corruption_chaos",
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn chaos_blackhole_metrics() {
+    init_recorder();
+
+    let layer = ChaosLayer::builder()
+        .name("blackhole_chaos")
+        .blackhole_rate(1.0)
+        .blackhole_cap(Duration::from_millis(1))
+        .build();
+
+    let service = tower::service_fn(|_: u64| async { Ok::<_, &'static str>("success") });
+
+    let mut service = layer.layer(service);
+
+    // Make a call that will be blackholed until the cap
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    // Verify blackhole counter
+    assert_counter_exists("resilience_chaos_blackholed_total");
+    assert_metric_has_label(
+        "resilience_chaos_blackholed_total",
+        "name",
+        "blackhole_chaos",
+    );
 }
@@ -43,28 +43,36 @@ async fn circuitbreaker_metrics_exist() {
     }
 
     // Verify counter metrics
-    assert_counter_exists("circuitbreaker_calls_total");
-    assert_metric_has_label("circuitbreaker_calls_total", "circuitbreaker", "test_cb");
-    assert_metric_has_label("circuitbreaker_calls_total", "outcome", "success");
-    assert_metric_has_label("circuitbreaker_calls_total", "outcome", "failure");
+    assert_counter_exists("resilience_circuitbreaker_calls_total");
+    assert_metric_has_label("resilience_circuitbreaker_calls_total", "name", "test_cb");
+    assert_metric_has_label(
+        "resilience_circuitbreaker_calls_total",
+        "outcome",
+        "success",
+    );
+    assert_metric_has_label(
+        "resilience_circuitbreaker_calls_total",
+        "outcome",
+        "failure",
+    );
 
     // Verify transition counter
-    assert_counter_exists("circuitbreaker_transitions_total");
+    assert_counter_exists("resilience_circuitbreaker_transitions_total");
     assert_metric_has_label(
-        "circuitbreaker_transitions_total",
-        "circuitbreaker",
+        "resilience_circuitbreaker_transitions_total",
+        "name",
         "test_cb",
     );
 
     // Verify state gauge
-    assert_gauge_exists("circuitbreaker_state");
-    assert_metric_has_label("circuitbreaker_state", "circuitbreaker", "test_cb");
+    assert_gauge_exists("resilience_circuitbreaker_state");
+    assert_metric_has_label("resilience_circuitbreaker_state", "name", "test_cb");
 
     // Verify duration histogram
-    assert_histogram_exists("circuitbreaker_call_duration_seconds");
+    assert_histogram_exists("resilience_circuitbreaker_call_duration_seconds");
     assert_metric_has_label(
-        "circuitbreaker_call_duration_seconds",
-        "circuitbreaker",
+        "resilience_circuitbreaker_call_duration_seconds",
+        "name",
         "test_cb",
     );
 }
@@ -98,10 +106,10 @@ async fn circuitbreaker_slow_call_metrics() {
     }
 
     // Verify slow call counter exists
-    assert_counter_exists("circuitbreaker_slow_calls_total");
+    assert_counter_exists("resilience_circuitbreaker_slow_calls_total");
     assert_metric_has_label(
-        "circuitbreaker_slow_calls_total",
-        "circuitbreaker",
+        "resilience_circuitbreaker_slow_calls_total",
+        "name",
         "slow_cb",
     );
 }
@@ -147,6 +155,38 @@ async fn circuitbreaker_state_transition_labels() {
     let _ = service.ready().await.unwrap().call(100).await;
 
     // Verify transition labels exist
-    assert_metric_has_label("circuitbreaker_transitions_total", "from", "Closed");
-    assert_metric_has_label("circuitbreaker_transitions_total", "to", "Open");
+    assert_metric_has_label(
+        "resilience_circuitbreaker_transitions_total",
+        "from",
+        "Closed",
+    );
+    assert_metric_has_label("resilience_circuitbreaker_transitions_total", "to", "Open");
+}
+
+#[tokio::test]
+#[serial]
+async fn circuitbreaker_failure_category_metrics() {
+    init_recorder();
+
+    let layer = CircuitBreakerLayer::builder()
+        .name("category_cb")
+        .error_category(|err: &&'static str| *err)
+        .build();
+
+    let service = tower::service_fn(|_: u64| async { Err::<(), _>("timeout") });
+    let mut service = layer.layer(service);
+
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    assert_counter_exists("resilience_circuitbreaker_failures_total");
+    assert_metric_has_label(
+        "resilience_circuitbreaker_failures_total",
+        "name",
+        "category_cb",
+    );
+    assert_metric_has_label(
+        "resilience_circuitbreaker_failures_total",
+        "category",
+        "timeout",
+    );
 }
@@ -0,0 +1,57 @@
+//! Coalesce metrics regression tests
+
+use super::helpers::*;
+use serial_test::serial;
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_coalesce::CoalesceLayer;
+
+#[tokio::test]
+#[serial]
+async fn coalesce_metrics_exist() {
+    init_recorder();
+
+    let layer = CoalesceLayer::builder(|req: &u64| *req)
+        .name("test_coalesce")
+        .build();
+
+    let service = tower::service_fn(|req: u64| async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok::<_, &'static str>(req)
+    });
+
+    let service = layer.layer(service);
+
+    // Concurrent calls with the same key - one leader, one waiter
+    let mut leader = service.clone();
+    let mut waiter = service.clone();
+    let (a, b) = tokio::join!(
+        async { leader.ready().await.unwrap().call(1).await },
+        async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            waiter.ready().await.unwrap().call(1).await
+        }
+    );
+    assert!(a.is_ok() && b.is_ok());
+
+    // Verify counter metrics
+    assert_counter_exists("resilience_coalesce_requests_total");
+    assert_metric_has_label(
+        "resilience_coalesce_requests_total",
+        "name",
+        "test_coalesce",
+    );
+    assert_metric_has_label("resilience_coalesce_requests_total", "role", "leader");
+    assert_metric_has_label("resilience_coalesce_requests_total", "role", "waiter");
+
+    assert_counter_exists("resilience_coalesce_followers_total");
+    assert_metric_has_label(
+        "resilience_coalesce_followers_total",
+        "name",
+        "test_coalesce",
+    );
+
+    // Verify gauge metric
+    assert_gauge_exists("resilience_coalesce_dedup_ratio");
+    assert_metric_has_label("resilience_coalesce_dedup_ratio", "name", "test_coalesce");
+}
@@ -0,0 +1,90 @@
+//! Hedge metrics regression tests
+
+use super::helpers::*;
+use serial_test::serial;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_hedge::HedgeLayer;
+
+#[tokio::test]
+#[serial]
+async fn hedge_metrics_exist() {
+    init_recorder();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = tower::service_fn(move |_: u64| {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if n == 0 {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+            Ok::<_, &'static str>("success")
+        }
+    });
+
+    let layer = HedgeLayer::builder()
+        .name("test_hedge")
+        .delay(Duration::from_millis(20))
+        .max_hedged_attempts(2)
+        .build();
+    let mut service = layer.layer(service);
+
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    assert_counter_exists("resilience_hedge_attempts_total");
+    assert_metric_has_label("resilience_hedge_attempts_total", "name", "test_hedge");
+    assert_metric_has_label("resilience_hedge_attempts_total", "kind", "primary");
+    assert_metric_has_label("resilience_hedge_attempts_total", "kind", "hedge");
+
+    assert_counter_exists("resilience_hedge_wins_total");
+    assert_metric_has_label("resilience_hedge_wins_total", "winner", "hedge");
+
+    assert_histogram_exists("resilience_hedge_latency_seconds");
+    assert_metric_has_label("resilience_hedge_latency_seconds", "name", "test_hedge");
+    assert_metric_has_label("resilience_hedge_latency_seconds", "result", "success");
+}
+
+#[tokio::test]
+#[serial]
+async fn hedge_suppressed_metrics() {
+    init_recorder();
+
+    let service = tower::service_fn(|_: u64| async { Ok::<_, &'static str>("success") });
+
+    let layer = HedgeLayer::builder()
+        .name("suppressed_hedge")
+        .no_delay()
+        .max_hedge_ratio(0.0)
+        .build();
+    let mut service = layer.layer(service);
+
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    assert_counter_exists("resilience_hedge_suppressed_total");
+    assert_metric_has_label(
+        "resilience_hedge_suppressed_total",
+        "name",
+        "suppressed_hedge",
+    );
+    assert_metric_has_label("resilience_hedge_wins_total", "winner", "primary");
+}
+
+#[tokio::test]
+#[serial]
+async fn hedge_failure_metrics() {
+    init_recorder();
+
+    let service = tower::service_fn(|_: u64| async { Err::<&'static str, _>("error") });
+
+    let layer = HedgeLayer::builder()
+        .name("failed_hedge")
+        .no_delay()
+        .build();
+    let mut service = layer.layer(service);
+
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    assert_metric_has_label("resilience_hedge_latency_seconds", "result", "failure");
+}
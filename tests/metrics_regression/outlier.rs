@@ -0,0 +1,29 @@
+//! Outlier detection metrics regression tests
+
+use super::helpers::*;
+use serial_test::serial;
+use tower_resilience_outlier::OutlierDetector;
+
+#[tokio::test]
+#[serial]
+async fn outlier_metrics_exist() {
+    init_recorder();
+
+    let detector = OutlierDetector::new()
+        .name("test_outlier")
+        .max_ejection_percent(100);
+    detector.register("backend-1", 2);
+
+    detector.record_failure("backend-1");
+    detector.record_failure("backend-1");
+    assert!(detector.is_ejected("backend-1"));
+
+    assert_counter_exists("resilience_outlier_ejections_total");
+    assert_gauge_exists("resilience_outlier_ejected_instances");
+    assert_metric_has_label("resilience_outlier_ejections_total", "name", "test_outlier");
+    assert_metric_has_label(
+        "resilience_outlier_ejections_total",
+        "instance",
+        "backend-1",
+    );
+}
@@ -28,15 +28,19 @@ async fn ratelimiter_metrics_exist() {
     }
 
     // Verify counter metrics
-    assert_counter_exists("ratelimiter_calls_total");
-    assert_metric_has_label("ratelimiter_calls_total", "ratelimiter", "test_ratelimiter");
-    assert_metric_has_label("ratelimiter_calls_total", "result", "permitted");
+    assert_counter_exists("resilience_ratelimiter_calls_total");
+    assert_metric_has_label(
+        "resilience_ratelimiter_calls_total",
+        "name",
+        "test_ratelimiter",
+    );
+    assert_metric_has_label("resilience_ratelimiter_calls_total", "result", "permitted");
 
     // Verify histogram metric
-    assert_histogram_exists("ratelimiter_wait_duration_seconds");
+    assert_histogram_exists("resilience_ratelimiter_wait_duration_seconds");
     assert_metric_has_label(
-        "ratelimiter_wait_duration_seconds",
-        "ratelimiter",
+        "resilience_ratelimiter_wait_duration_seconds",
+        "name",
         "test_ratelimiter",
     );
 }
@@ -62,5 +66,5 @@ async fn ratelimiter_rejection_metrics() {
     }
 
     // Verify rejection label exists
-    assert_metric_has_label("ratelimiter_calls_total", "result", "rejected");
+    assert_metric_has_label("resilience_ratelimiter_calls_total", "result", "rejected");
 }
@@ -0,0 +1,72 @@
+//! Reconnect metrics regression tests
+
+use super::helpers::*;
+use serial_test::serial;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer, ReconnectPolicy};
+
+#[tokio::test]
+#[serial]
+async fn reconnect_metrics_exist() {
+    init_recorder();
+
+    let layer = ReconnectLayer::new(
+        ReconnectConfig::builder()
+            .name("test_reconnect")
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(1)))
+            .max_attempts(3)
+            .build(),
+    );
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = tower::service_fn(move |req: u64| {
+        let call = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if call == 0 {
+                Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+            } else {
+                Ok(req)
+            }
+        }
+    });
+
+    let mut service = layer.layer(service);
+
+    // First call fails once, reconnects and is retried
+    let result = service.ready().await.unwrap().call(1).await;
+    assert!(result.is_ok());
+
+    // Verify counter metrics
+    assert_counter_exists("resilience_reconnect_attempts_total");
+    assert_metric_has_label(
+        "resilience_reconnect_attempts_total",
+        "name",
+        "test_reconnect",
+    );
+
+    // Verify gauge metrics
+    assert_gauge_exists("resilience_reconnect_connection_state");
+    assert_metric_has_label(
+        "resilience_reconnect_connection_state",
+        "name",
+        "test_reconnect",
+    );
+    assert_metric_has_label(
+        "resilience_reconnect_connection_state",
+        "state",
+        "connected",
+    );
+    assert_metric_has_label(
+        "resilience_reconnect_connection_state",
+        "state",
+        "disconnected",
+    );
+    assert_metric_has_label(
+        "resilience_reconnect_connection_state",
+        "state",
+        "reconnecting",
+    );
+}
@@ -37,16 +37,16 @@ async fn retry_metrics_exist() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify counter metrics
-    assert_counter_exists("retry_calls_total");
-    assert_metric_has_label("retry_calls_total", "retry", "test_retry");
-    assert_metric_has_label("retry_calls_total", "result", "success");
+    assert_counter_exists("resilience_retry_calls_total");
+    assert_metric_has_label("resilience_retry_calls_total", "name", "test_retry");
+    assert_metric_has_label("resilience_retry_calls_total", "result", "success");
 
-    assert_counter_exists("retry_attempts_total");
-    assert_metric_has_label("retry_attempts_total", "retry", "test_retry");
+    assert_counter_exists("resilience_retry_attempts_total");
+    assert_metric_has_label("resilience_retry_attempts_total", "name", "test_retry");
 
     // Verify histogram metric
-    assert_histogram_exists("retry_attempts");
-    assert_metric_has_label("retry_attempts", "retry", "test_retry");
+    assert_histogram_exists("resilience_retry_attempts");
+    assert_metric_has_label("resilience_retry_attempts", "name", "test_retry");
 }
 
 #[tokio::test]
@@ -68,5 +68,5 @@ async fn retry_exhausted_metrics() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify exhausted result label
-    assert_metric_has_label("retry_calls_total", "result", "exhausted");
+    assert_metric_has_label("resilience_retry_calls_total", "result", "exhausted");
 }
@@ -27,15 +27,19 @@ async fn timelimiter_metrics_exist() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify counter metrics
-    assert_counter_exists("timelimiter_calls_total");
-    assert_metric_has_label("timelimiter_calls_total", "timelimiter", "test_timelimiter");
-    assert_metric_has_label("timelimiter_calls_total", "result", "success");
+    assert_counter_exists("resilience_timelimiter_calls_total");
+    assert_metric_has_label(
+        "resilience_timelimiter_calls_total",
+        "name",
+        "test_timelimiter",
+    );
+    assert_metric_has_label("resilience_timelimiter_calls_total", "result", "success");
 
     // Verify histogram metric
-    assert_histogram_exists("timelimiter_call_duration_seconds");
+    assert_histogram_exists("resilience_timelimiter_call_duration_seconds");
     assert_metric_has_label(
-        "timelimiter_call_duration_seconds",
-        "timelimiter",
+        "resilience_timelimiter_call_duration_seconds",
+        "name",
         "test_timelimiter",
     );
 }
@@ -61,7 +65,7 @@ async fn timelimiter_timeout_metrics() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify timeout result label
-    assert_metric_has_label("timelimiter_calls_total", "result", "timeout");
+    assert_metric_has_label("resilience_timelimiter_calls_total", "result", "timeout");
 }
 
 #[tokio::test]
@@ -82,5 +86,34 @@ async fn timelimiter_error_metrics() {
     let _ = service.ready().await.unwrap().call(1).await;
 
     // Verify error result label
-    assert_metric_has_label("timelimiter_calls_total", "result", "error");
+    assert_metric_has_label("resilience_timelimiter_calls_total", "result", "error");
+}
+
+#[tokio::test]
+#[serial]
+async fn timelimiter_soft_timeout_metrics() {
+    init_recorder();
+
+    let layer = TimeLimiterLayer::builder()
+        .name("soft_timelimiter")
+        .timeout_duration(Duration::from_millis(200))
+        .soft_timeout(Duration::from_millis(10))
+        .build();
+
+    let service = tower::service_fn(|_: u64| async {
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        Ok::<_, &'static str>("success")
+    });
+
+    let mut service = layer.layer(service);
+
+    // Make a call that exceeds the soft timeout but completes
+    let _ = service.ready().await.unwrap().call(1).await;
+
+    assert_counter_exists("resilience_timelimiter_soft_timeouts_total");
+    assert_metric_has_label(
+        "resilience_timelimiter_soft_timeouts_total",
+        "name",
+        "soft_timelimiter",
+    );
 }
@@ -0,0 +1,29 @@
+//! Integration test for the Prometheus exporter helper.
+//!
+//! Kept in its own binary because it installs the global metrics recorder.
+
+use metrics_exporter_prometheus::PrometheusBuilder;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience::circuitbreaker::CircuitBreakerLayer;
+use tower_resilience::observability::install_prometheus;
+
+#[tokio::test]
+async fn exports_metrics_under_uniform_names() {
+    let handle = install_prometheus(PrometheusBuilder::new()).unwrap();
+
+    let layer = CircuitBreakerLayer::builder().name("payments").build();
+    let mut service = layer.layer(tower::service_fn(|_req: ()| async {
+        Ok::<_, std::io::Error>(())
+    }));
+    service.ready().await.unwrap().call(()).await.unwrap();
+
+    let output = handle.render();
+    assert!(
+        output.contains("resilience_circuitbreaker_calls_total{name=\"payments\""),
+        "{output}"
+    );
+    assert!(
+        output.contains("resilience_circuitbreaker_call_duration_seconds_bucket"),
+        "{output}"
+    );
+}
@@ -1,5 +1,8 @@
 mod ratelimiter {
+    mod clock;
+    mod concurrency;
     mod fixed_window;
+    mod gcra;
     mod sliding_counter;
     mod sliding_log;
     mod window_comparison;
@@ -0,0 +1,98 @@
+//! Rate limiter waits on the configured clock
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_core::MockClock;
+use tower_resilience_ratelimiter::RateLimiterLayer;
+
+/// A call waiting for the next period advances the mock clock, not wall time
+#[tokio::test]
+async fn mock_clock_drives_permit_waits() {
+    let clock = MockClock::new();
+    let layer = RateLimiterLayer::builder()
+        .limit_for_period(1)
+        .refresh_period(Duration::from_secs(60))
+        .timeout_duration(Duration::from_secs(120))
+        .clock(clock.clone())
+        .name("mock-clock")
+        .build();
+
+    let svc = tower::service_fn(|_req: ()| async { Ok::<_, std::io::Error>(()) });
+    let mut service = layer.layer(svc);
+
+    let start = std::time::Instant::now();
+    service.ready().await.unwrap().call(()).await.unwrap();
+    service.ready().await.unwrap().call(()).await.unwrap();
+
+    assert_eq!(clock.elapsed(), Duration::from_secs(60));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+/// Backpressure waits for the next period on the configured clock
+#[tokio::test]
+async fn mock_clock_drives_backpressure() {
+    let clock = MockClock::new();
+    let layer = RateLimiterLayer::builder()
+        .limit_for_period(1)
+        .refresh_period(Duration::from_secs(60))
+        .backpressure()
+        .clock(clock.clone())
+        .name("mock-clock-backpressure")
+        .build();
+
+    let svc = tower::service_fn(|_req: ()| async { Ok::<_, std::io::Error>(()) });
+    let mut service = layer.layer(svc);
+
+    let start = std::time::Instant::now();
+    service.ready().await.unwrap().call(()).await.unwrap();
+    service.ready().await.unwrap().call(()).await.unwrap();
+
+    assert_eq!(clock.elapsed(), Duration::from_secs(60));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+/// Time spent waiting for a concurrency slot is measured on the configured clock
+#[tokio::test]
+async fn mock_clock_measures_concurrency_waits() {
+    let clock = MockClock::new();
+    let waits = Arc::new(Mutex::new(Vec::new()));
+    let w = Arc::clone(&waits);
+    let layer = RateLimiterLayer::builder()
+        .limit_for_period(10)
+        .refresh_period(Duration::from_secs(60))
+        .max_concurrent_calls(1)
+        .backpressure()
+        .clock(clock.clone())
+        .on_permit_acquired(move |wait| w.lock().unwrap().push(wait))
+        .name("mock-clock-concurrency")
+        .build();
+
+    let svc = tower::service_fn(|release: Option<oneshot::Receiver<()>>| async move {
+        if let Some(release) = release {
+            let _ = release.await;
+        }
+        Ok::<_, std::io::Error>(())
+    });
+    let service = layer.layer(svc);
+
+    let (release, held) = oneshot::channel();
+    let mut first = service.clone();
+    let first_call = first.ready().await.unwrap().call(Some(held));
+    let first_call = tokio::spawn(first_call);
+
+    let mut second = service.clone();
+    let second_call = tokio::spawn(async move { second.ready().await.unwrap().call(None).await });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    clock.advance(Duration::from_secs(30));
+    release.send(()).unwrap();
+    first_call.await.unwrap().unwrap();
+    second_call.await.unwrap().unwrap();
+
+    assert_eq!(
+        *waits.lock().unwrap(),
+        vec![Duration::ZERO, Duration::from_secs(30)]
+    );
+}
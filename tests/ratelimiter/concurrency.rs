@@ -0,0 +1,123 @@
+//! Combined rate and concurrency limit integration tests
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::time::sleep;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_ratelimiter::{RateLimiterLayer, RateLimiterServiceError};
+
+#[tokio::test]
+async fn concurrency_limit_rejects_excess_in_flight_calls() {
+    let rejected = Arc::new(AtomicUsize::new(0));
+    let r = Arc::clone(&rejected);
+
+    let svc = tower::service_fn(|_req: u32| async {
+        sleep(Duration::from_millis(100)).await;
+        Ok::<_, std::io::Error>(())
+    });
+
+    let layer = RateLimiterLayer::builder()
+        .limit_for_period(100)
+        .refresh_period(Duration::from_secs(1))
+        .timeout_duration(Duration::from_millis(10))
+        .max_concurrent_calls(2)
+        .on_permit_rejected(move |_| {
+            r.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let service = layer.layer(svc);
+
+    let mut handles = Vec::new();
+    for i in 0..3 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(i).await
+        }));
+        sleep(Duration::from_millis(5)).await;
+    }
+
+    let mut results = Vec::new();
+    for handle in handles {
+        results.push(handle.await.unwrap());
+    }
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(matches!(
+        results[2],
+        Err(RateLimiterServiceError::RateLimited)
+    ));
+    assert_eq!(rejected.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn concurrency_rejection_does_not_use_rate_permit() {
+    let svc = tower::service_fn(|delay: u64| async move {
+        sleep(Duration::from_millis(delay)).await;
+        Ok::<_, std::io::Error>(())
+    });
+
+    let (layer, handle) = RateLimiterLayer::builder()
+        .limit_for_period(10)
+        .refresh_period(Duration::from_secs(60))
+        .timeout_duration(Duration::ZERO)
+        .max_concurrent_calls(1)
+        .build_with_handle();
+
+    let service = layer.layer(svc);
+
+    let mut slow = service.clone();
+    let slow_call = tokio::spawn(async move { slow.ready().await.unwrap().call(50).await });
+    sleep(Duration::from_millis(5)).await;
+
+    let mut fast = service.clone();
+    let result = fast.ready().await.unwrap().call(0).await;
+    assert!(result.is_err());
+
+    slow_call.await.unwrap().unwrap();
+
+    // Only the call that ran consumed a rate permit
+    assert_eq!(handle.available_permits(), 9);
+}
+
+#[tokio::test]
+async fn backpressure_waits_for_concurrency_slot() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let (f, p) = (Arc::clone(&in_flight), Arc::clone(&peak));
+
+    let svc = tower::service_fn(move |_req: u32| {
+        let (f, p) = (Arc::clone(&f), Arc::clone(&p));
+        async move {
+            let now = f.fetch_add(1, Ordering::SeqCst) + 1;
+            p.fetch_max(now, Ordering::SeqCst);
+            sleep(Duration::from_millis(20)).await;
+            f.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, std::io::Error>(())
+        }
+    });
+
+    let layer = RateLimiterLayer::builder()
+        .limit_for_period(100)
+        .refresh_period(Duration::from_secs(1))
+        .max_concurrent_calls(2)
+        .backpressure()
+        .build();
+
+    let service = layer.layer(svc);
+
+    let mut handles = Vec::new();
+    for i in 0..6 {
+        let mut svc = service.clone();
+        handles.push(tokio::spawn(async move {
+            svc.ready().await.unwrap().call(i).await
+        }));
+    }
+
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+}
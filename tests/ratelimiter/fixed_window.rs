@@ -133,6 +133,35 @@ async fn fixed_window_allows_burst_at_boundary() {
     assert_eq!(call_count.load(Ordering::SeqCst), 10);
 }
 
+#[tokio::test]
+async fn fixed_window_smooth_refill_paces_after_exhaustion() {
+    let svc = tower::service_fn(|_req: u32| async { Ok::<_, std::io::Error>(()) });
+
+    let layer = RateLimiterLayer::builder()
+        .limit_for_period(10)
+        .refresh_period(Duration::from_millis(500))
+        .timeout_duration(Duration::from_millis(5))
+        .window_type(WindowType::Fixed)
+        .smooth_refill()
+        .build();
+
+    let mut service = layer.layer(svc);
+
+    for i in 0..10 {
+        let result = service.ready().await.unwrap().call(i).await;
+        assert!(result.is_ok());
+    }
+
+    // One permit is released every 50ms rather than all ten after 500ms
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    let result = service.ready().await.unwrap().call(10).await;
+    assert!(result.is_ok(), "a permit should be released mid-period");
+
+    let result = service.ready().await.unwrap().call(11).await;
+    assert!(result.is_err(), "only one permit should have been released");
+}
+
 #[tokio::test]
 async fn fixed_window_event_listeners() {
     let acquired = Arc::new(AtomicUsize::new(0));
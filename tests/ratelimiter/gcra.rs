@@ -0,0 +1,80 @@
+//! GCRA rate limiter integration tests
+
+use std::time::{Duration, Instant};
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_ratelimiter::{RateLimiterLayer, WindowType};
+
+#[tokio::test]
+async fn gcra_allows_burst_then_rejects() {
+    let svc = tower::service_fn(|_req: u32| async { Ok::<_, std::io::Error>(()) });
+
+    let layer = RateLimiterLayer::builder()
+        .window_type(WindowType::Gcra {
+            period: Duration::from_secs(1),
+            burst: 3,
+        })
+        .timeout_duration(Duration::from_millis(10))
+        .build();
+
+    let mut service = layer.layer(svc);
+
+    for i in 0..3 {
+        let result = service.ready().await.unwrap().call(i).await;
+        assert!(result.is_ok(), "Request {} should succeed", i);
+    }
+
+    let result = service.ready().await.unwrap().call(3).await;
+    assert!(
+        result.is_err(),
+        "Request beyond the burst should be rejected"
+    );
+}
+
+#[tokio::test]
+async fn gcra_spaces_requests_after_burst() {
+    let svc = tower::service_fn(|_req: u32| async { Ok::<_, std::io::Error>(()) });
+
+    let layer = RateLimiterLayer::builder()
+        .window_type(WindowType::Gcra {
+            period: Duration::from_millis(20),
+            burst: 1,
+        })
+        .timeout_duration(Duration::from_millis(100))
+        .build();
+
+    let mut service = layer.layer(svc);
+
+    let start = Instant::now();
+    for i in 0..4 {
+        let result = service.ready().await.unwrap().call(i).await;
+        assert!(result.is_ok(), "Request {} should succeed", i);
+    }
+
+    // The first request goes straight through; each later one waits a period
+    assert!(
+        start.elapsed() >= Duration::from_millis(55),
+        "requests should be paced, took {:?}",
+        start.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn gcra_handle_reports_burst_capacity() {
+    let svc = tower::service_fn(|_req: u32| async { Ok::<_, std::io::Error>(()) });
+
+    let (layer, handle) = RateLimiterLayer::builder()
+        .window_type(WindowType::Gcra {
+            period: Duration::from_secs(1),
+            burst: 4,
+        })
+        .timeout_duration(Duration::ZERO)
+        .build_with_handle();
+
+    let mut service = layer.layer(svc);
+    assert_eq!(handle.available_permits(), 4);
+
+    service.ready().await.unwrap().call(0).await.unwrap();
+    service.ready().await.unwrap().call(1).await.unwrap();
+    assert_eq!(handle.available_permits(), 2);
+    assert_eq!(handle.limit_for_period(), 4);
+}
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Service, ServiceExt};
+use tower_resilience_reconnect::{
+    ReconnectConfig, ReconnectError, ReconnectLayer, ReconnectPolicy,
+};
+
+fn layer() -> ReconnectLayer {
+    ReconnectLayer::new(
+        ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(10)))
+            .max_attempts(3)
+            .build(),
+    )
+}
+
+#[tokio::test]
+async fn connector_shares_connection_across_clones() {
+    let dials = Arc::new(AtomicUsize::new(0));
+    let d = Arc::clone(&dials);
+
+    let service = layer().connector(move || {
+        let id = d.fetch_add(1, Ordering::SeqCst);
+        async move {
+            Ok::<_, std::io::Error>(tower::service_fn(move |req: u32| async move {
+                Ok::<_, std::io::Error>((id, req))
+            }))
+        }
+    });
+
+    // Establish the connection, then fan out over clones
+    service.clone().oneshot(0).await.unwrap();
+
+    let mut handles = vec![];
+    for i in 1..=10 {
+        let svc = service.clone();
+        handles.push(tokio::spawn(async move { svc.oneshot(i).await }));
+    }
+    for handle in handles {
+        let (id, _) = handle.await.unwrap().unwrap();
+        assert_eq!(id, 0);
+    }
+
+    assert_eq!(dials.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn connector_replaces_broken_connection() {
+    let dials = Arc::new(AtomicUsize::new(0));
+    let d = Arc::clone(&dials);
+
+    // Every connection breaks after a single request
+    let mut service = layer().connector(move || {
+        let id = d.fetch_add(1, Ordering::SeqCst);
+        let used = Arc::new(AtomicUsize::new(0));
+        async move {
+            Ok::<_, std::io::Error>(tower::service_fn(move |req: u32| {
+                let first = used.fetch_add(1, Ordering::SeqCst) == 0;
+                async move {
+                    if first {
+                        Ok((id, req))
+                    } else {
+                        Err(std::io::Error::new(
+                            std::io::ErrorKind::ConnectionReset,
+                            "connection reset",
+                        ))
+                    }
+                }
+            }))
+        }
+    });
+
+    for expected in 0..3 {
+        let (id, _) = service.ready().await.unwrap().call(expected).await.unwrap();
+        assert_eq!(id, expected as usize);
+    }
+    assert_eq!(dials.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn connector_reports_dial_failures() {
+    let mut service = layer().connector(|| async {
+        Err::<tower::util::BoxCloneService<(), (), std::io::Error>, _>(std::io::Error::new(
+            std::io::ErrorKind::ConnectionRefused,
+            "connection refused",
+        ))
+    });
+
+    let err = service.ready().await.unwrap().call(()).await.unwrap_err();
+    assert!(matches!(
+        err,
+        ReconnectError::MaxAttemptsExceeded { attempts: 4, .. }
+    ));
+    assert!(err.to_string().contains("connection refused"));
+}
+
+#[tokio::test]
+async fn connector_keeps_connection_on_request_errors() {
+    use std::io::{Error, ErrorKind};
+
+    let dials = Arc::new(AtomicUsize::new(0));
+    let d = Arc::clone(&dials);
+
+    let mut service = layer()
+        .disconnect_classifier(|error: &Error| error.kind() == ErrorKind::BrokenPipe)
+        .connector(move || {
+            d.fetch_add(1, Ordering::SeqCst);
+            async {
+                Ok::<_, Error>(tower::service_fn(|req: u32| async move {
+                    if req == 0 {
+                        Err(Error::new(ErrorKind::InvalidData, "bad request"))
+                    } else {
+                        Ok(req)
+                    }
+                }))
+            }
+        });
+
+    let err = service.ready().await.unwrap().call(0).await.unwrap_err();
+    assert!(matches!(err, ReconnectError::ServiceError(_)));
+    assert!(service.is_connected());
+
+    assert_eq!(service.ready().await.unwrap().call(1).await.unwrap(), 1);
+    assert_eq!(dials.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn connector_discards_connections_that_fail_probe() {
+    let dials = Arc::new(AtomicUsize::new(0));
+    let d = Arc::clone(&dials);
+
+    let mut service = layer()
+        .probe(
+            |svc: tower::util::BoxCloneService<u32, usize, std::io::Error>| async move {
+                // Connection 0 is half-alive and fails its health check
+                match svc.oneshot(0).await? {
+                    0 => Err(std::io::Error::other("unhealthy")),
+                    _ => Ok(()),
+                }
+            },
+        )
+        .connector(move || {
+            let id = d.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok::<_, std::io::Error>(tower::util::BoxCloneService::new(tower::service_fn(
+                    move |_req: u32| async move { Ok::<_, std::io::Error>(id) },
+                )))
+            }
+        });
+
+    assert_eq!(service.ready().await.unwrap().call(7).await.unwrap(), 1);
+    assert_eq!(dials.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn connector_validates_idle_connection_with_keepalive() {
+    let dials = Arc::new(AtomicUsize::new(0));
+    let alive = Arc::new(std::sync::atomic::AtomicBool::new(true));
+    let d = Arc::clone(&dials);
+    let a = Arc::clone(&alive);
+
+    let mut service = layer()
+        .connector(move || {
+            let id = d.fetch_add(1, Ordering::SeqCst);
+            let alive = Arc::clone(&a);
+            alive.store(true, Ordering::SeqCst);
+            async move {
+                Ok::<_, std::io::Error>(tower::util::BoxCloneService::new(tower::service_fn(
+                    move |_req: u32| {
+                        let alive = alive.load(Ordering::SeqCst);
+                        async move {
+                            if alive {
+                                Ok(id)
+                            } else {
+                                Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+                            }
+                        }
+                    },
+                )))
+            }
+        })
+        .idle_timeout(Duration::from_millis(20))
+        .keepalive(|svc: tower::util::BoxCloneService<u32, usize, std::io::Error>| svc.oneshot(0));
+
+    assert_eq!(service.ready().await.unwrap().call(1).await.unwrap(), 0);
+
+    // The peer drops the socket while the connection sits idle
+    alive.store(false, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    assert_eq!(service.ready().await.unwrap().call(2).await.unwrap(), 1);
+    assert_eq!(dials.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tower::{Layer, Service};
+use tower_resilience_reconnect::{ReconnectConfig, ReconnectLayer, ReconnectPolicy};
+
+/// Service that fails with a connection error on the given call indices.
+fn flaky(
+    failing_calls: &'static [usize],
+) -> impl Service<(), Response = (), Error = std::io::Error, Future = impl Send> + Clone {
+    let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    tower::service_fn(move |_req: ()| {
+        let call = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        async move {
+            if failing_calls.contains(&call) {
+                Err(std::io::Error::from(std::io::ErrorKind::ConnectionReset))
+            } else {
+                Ok(())
+            }
+        }
+    })
+}
+
+fn recording_config(log: &Arc<Mutex<Vec<String>>>, max_attempts: u32) -> ReconnectConfig {
+    let (a, b, c, d) = (log.clone(), log.clone(), log.clone(), log.clone());
+    ReconnectConfig::builder()
+        .name("events")
+        .policy(ReconnectPolicy::fixed(Duration::from_millis(1)))
+        .max_attempts(max_attempts)
+        .on_disconnected(move || a.lock().unwrap().push("disconnected".into()))
+        .on_reconnect_attempt(move |attempt, delay| {
+            assert_eq!(delay, Duration::from_millis(1));
+            b.lock().unwrap().push(format!("attempt {}", attempt));
+        })
+        .on_reconnected(move |attempts| c.lock().unwrap().push(format!("reconnected {}", attempts)))
+        .on_gave_up(move |attempts| d.lock().unwrap().push(format!("gave up {}", attempts)))
+        .build()
+}
+
+#[tokio::test]
+async fn emits_lifecycle_events_on_recovery() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut service = ReconnectLayer::new(recording_config(&log, 5)).layer(flaky(&[1, 2]));
+
+    service.call(()).await.unwrap();
+    service.call(()).await.unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["disconnected", "attempt 1", "attempt 2", "reconnected 2"]
+    );
+}
+
+#[tokio::test]
+async fn emits_gave_up_when_attempts_exhausted() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut service = ReconnectLayer::new(recording_config(&log, 1)).layer(flaky(&[0, 1, 2, 3]));
+
+    assert!(service.call(()).await.is_err());
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["disconnected", "attempt 1", "gave up 2"]
+    );
+}
+
+#[tokio::test]
+async fn connector_emits_lifecycle_events() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let dials = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let mut service = ReconnectLayer::new(recording_config(&log, 5)).connector(move || {
+        let dial = dials.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        async move {
+            if dial == 1 {
+                return Err(std::io::Error::from(std::io::ErrorKind::ConnectionRefused));
+            }
+            // The first connection breaks after one call
+            Ok(flaky(if dial == 0 { &[1] } else { &[] }))
+        }
+    });
+
+    service.call(()).await.unwrap();
+    service.call(()).await.unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["disconnected", "attempt 1", "attempt 2", "reconnected 2"]
+    );
+}
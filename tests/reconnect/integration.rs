@@ -270,3 +270,133 @@ async fn reconnect_predicate_allows_matching_errors() {
         "Should take 3 attempts (2 failures + 1 success)"
     );
 }
+
+#[tokio::test]
+async fn disconnect_classifier_passes_request_errors_through() {
+    use std::io::{Error, ErrorKind};
+    use tower_resilience_reconnect::{ConnectionState, ReconnectError};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+    let inner = tower::service_fn(move |_req: String| {
+        c.fetch_add(1, Ordering::SeqCst);
+        async { Err::<String, _>(Error::new(ErrorKind::InvalidInput, "rate limited")) }
+    });
+
+    let layer = ReconnectLayer::new(
+        ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(1)))
+            .max_attempts(5)
+            .build(),
+    )
+    .disconnect_classifier(|error: &Error| {
+        matches!(
+            error.kind(),
+            ErrorKind::BrokenPipe | ErrorKind::ConnectionReset
+        )
+    });
+    let state = layer.state().clone();
+    let mut service = layer.layer(inner);
+
+    let result = service.call("test".to_string()).await;
+
+    assert!(matches!(result, Err(ReconnectError::ServiceError(_))));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(state.state(), ConnectionState::Disconnected);
+    assert_eq!(state.attempts(), 0);
+}
+
+#[tokio::test]
+async fn disconnect_classifier_reconnects_on_connection_errors() {
+    use std::io::{Error, ErrorKind};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&calls);
+    let inner = tower::service_fn(move |req: String| {
+        let call = c.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if call < 2 {
+                Err(Error::from(ErrorKind::ConnectionReset))
+            } else {
+                Ok(req)
+            }
+        }
+    });
+
+    let layer = ReconnectLayer::new(
+        ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(1)))
+            .max_attempts(5)
+            .build(),
+    )
+    .disconnect_classifier(|error: &Error| error.kind() == ErrorKind::ConnectionReset);
+    let mut service = layer.layer(inner);
+
+    let result = service.call("test".to_string()).await;
+
+    assert_eq!(result.unwrap(), "test");
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn probe_gates_traffic_after_reconnect() {
+    let inner = FailingService::new(1);
+    let calls = inner.fail_count.clone();
+
+    let probes = Arc::new(AtomicUsize::new(0));
+    let p = Arc::clone(&probes);
+
+    let layer = ReconnectLayer::new(
+        ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(1)))
+            .max_attempts(5)
+            .build(),
+    )
+    .probe(move |_svc: FailingService| {
+        // Unhealthy for the first two probes
+        let healthy = p.fetch_add(1, Ordering::SeqCst) >= 2;
+        async move {
+            if healthy {
+                Ok(())
+            } else {
+                Err(std::io::Error::other("not ready"))
+            }
+        }
+    });
+    let mut service = layer.layer(inner);
+
+    let result = service.call("test".to_string()).await;
+
+    assert_eq!(result.unwrap(), "Response: test");
+    assert_eq!(probes.load(Ordering::SeqCst), 3);
+    // The request was only replayed once the probe passed
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn failing_probe_exhausts_max_attempts() {
+    use tower_resilience_reconnect::ReconnectError;
+
+    let inner = FailingService::new(1);
+    let calls = inner.fail_count.clone();
+
+    let layer = ReconnectLayer::new(
+        ReconnectConfig::builder()
+            .policy(ReconnectPolicy::fixed(Duration::from_millis(1)))
+            .max_attempts(3)
+            .build(),
+    )
+    .probe(|_svc: FailingService| async { Err::<(), _>(std::io::Error::other("not ready")) });
+    let mut service = layer.layer(inner);
+
+    let result = service.call("test".to_string()).await;
+
+    match result {
+        Err(ReconnectError::MaxAttemptsExceeded { attempts, error }) => {
+            assert_eq!(attempts, 4);
+            assert_eq!(error.to_string(), "not ready");
+        }
+        other => panic!("Expected MaxAttemptsExceeded, got {:?}", other),
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
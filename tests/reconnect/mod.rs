@@ -2,9 +2,13 @@
 //!
 //! Test organization:
 //! - integration.rs: Basic reconnection and policy tests
+//! - connector.rs: Connection factory tests
+//! - events.rs: Lifecycle event tests
 //! - config.rs: Configuration and builder tests
 //! - state.rs: Connection state tracking tests
 
 mod config;
+mod connector;
+mod events;
 mod integration;
 mod state;
@@ -6,9 +6,13 @@
 //! - retry_behavior.rs: Core retry logic tests
 //! - retry_events.rs: Event system tests
 //! - retry_config.rs: Configuration and builder tests
+//! - retry_scheduled.rs: Retries handed to a scheduler
+//! - retry_tower_policy.rs: Configuration used as a `tower::retry::Policy`
 
 mod retry_backoff;
 mod retry_behavior;
 mod retry_config;
 mod retry_events;
 mod retry_predicates;
+mod retry_scheduled;
+mod retry_tower_policy;
@@ -5,7 +5,7 @@
 //! - Exponential growth with various multipliers
 //! - Exponential random variance bounds
 //! - Custom function intervals
-//! - Max backoff duration enforcement
+//! - Max backoff duration enforcement, including caps on any interval function
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -590,3 +590,107 @@ async fn zero_backoff_retries_immediately() {
     );
     assert_eq!(call_count.load(Ordering::SeqCst), 3);
 }
+
+#[tokio::test]
+async fn mock_clock_backoff_advances_without_sleeping() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |_req: String| {
+        let count = cc.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if count < 2 {
+                Err(TestError)
+            } else {
+                Ok::<_, TestError>("success".to_string())
+            }
+        }
+    });
+
+    let clock = tower_resilience_core::MockClock::new();
+    let layer = RetryLayer::builder()
+        .max_attempts(3)
+        .backoff(FixedInterval::new(Duration::from_secs(3600)))
+        .clock(clock.clone())
+        .build();
+    let mut service = layer.layer(service);
+
+    let start = Instant::now();
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(call_count.load(Ordering::SeqCst), 3);
+    // Two hour-long backoffs elapsed on the mock clock, not in real time
+    assert_eq!(clock.elapsed(), Duration::from_secs(7200));
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn max_interval_caps_any_backoff() {
+    let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let d = Arc::clone(&delays);
+
+    let service = tower::service_fn(|_req: String| async { Err::<String, _>(TestError) });
+
+    let clock = tower_resilience_core::MockClock::new();
+    let layer = RetryLayer::builder()
+        .max_attempts(5)
+        .max_interval(Duration::from_secs(60))
+        .backoff(FnInterval::new(|attempt| {
+            Duration::from_secs(30 * (attempt as u64 + 1))
+        }))
+        .clock(clock.clone())
+        .on_retry(move |_, delay| d.lock().unwrap().push(delay))
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        *delays.lock().unwrap(),
+        vec![
+            Duration::from_secs(30),
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn exponential_backoff_with_cap_stops_growing() {
+    let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let d = Arc::clone(&delays);
+
+    let service = tower::service_fn(|_req: String| async { Err::<String, _>(TestError) });
+
+    let clock = tower_resilience_core::MockClock::new();
+    let layer = RetryLayer::builder()
+        .max_attempts(6)
+        .exponential_backoff_with_cap(Duration::from_secs(1), Duration::from_secs(5))
+        .clock(clock.clone())
+        .on_retry(move |_, delay| d.lock().unwrap().push(delay))
+        .build();
+    let mut service = layer.layer(service);
+
+    let _ = service
+        .ready()
+        .await
+        .unwrap()
+        .call("test".to_string())
+        .await;
+
+    let secs: Vec<u64> = delays.lock().unwrap().iter().map(|d| d.as_secs()).collect();
+    assert_eq!(secs, vec![1, 2, 4, 5, 5]);
+}
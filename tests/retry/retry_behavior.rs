@@ -6,6 +6,7 @@
 //! - Exhaust all attempts
 //! - Stop retrying on non-retryable error
 //! - Request cloning works correctly
+//! - Requests decorated per attempt
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -584,3 +585,43 @@ async fn retries_error_without_clone_bound() {
     assert_eq!(err.source.kind(), std::io::ErrorKind::ConnectionReset);
     assert_eq!(call_count.load(Ordering::SeqCst), 3);
 }
+
+#[tokio::test]
+async fn decorate_request_tags_each_attempt() {
+    let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let s = Arc::clone(&seen);
+
+    let service = tower::service_fn(move |req: String| {
+        let s = Arc::clone(&s);
+        async move {
+            let mut seen = s.lock().unwrap();
+            seen.push(req.clone());
+            if seen.len() < 3 {
+                Err(TestError::new("transient"))
+            } else {
+                Ok(req)
+            }
+        }
+    });
+
+    let layer = RetryLayer::builder()
+        .max_attempts(5)
+        .fixed_backoff(std::time::Duration::from_millis(10))
+        .decorate_request(|req: String, attempt| format!("{}#{}", req, attempt))
+        .build();
+    let mut service = layer.layer(service);
+
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call("key-42".to_string())
+        .await;
+
+    // Each attempt starts from the original request, not the previous one
+    assert_eq!(result.unwrap(), "key-42#2");
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["key-42#0", "key-42#1", "key-42#2"]
+    );
+}
@@ -6,6 +6,7 @@
 //! - Custom predicate logic
 //! - Predicate with stateful logic
 //! - Combining predicates
+//! - Polling with retry_until
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
@@ -428,3 +429,62 @@ async fn different_predicates_different_services() {
     // Service 2 doesn't retry
     assert_eq!(call_count2.load(Ordering::SeqCst), 1);
 }
+
+#[tokio::test]
+async fn retry_until_polls_until_condition_holds() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    // Reports "pending" twice, then "ready"
+    let service = tower::service_fn(move |_req: String| {
+        let attempt = cc.fetch_add(1, Ordering::SeqCst);
+        async move { Ok::<_, TestError>(if attempt < 2 { "pending" } else { "ready" }.to_string()) }
+    });
+
+    let layer = RetryLayer::<String, String, TestError>::builder()
+        .max_attempts(5)
+        .fixed_backoff(std::time::Duration::from_millis(1))
+        .retry_until(|status: &String| status == "ready")
+        .build();
+
+    let mut service = layer.layer(service);
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("job-1".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(response, "ready");
+    assert_eq!(call_count.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn retry_until_returns_last_response_when_exhausted() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let service = tower::service_fn(move |_req: String| {
+        cc.fetch_add(1, Ordering::SeqCst);
+        async { Ok::<_, TestError>("pending".to_string()) }
+    });
+
+    let layer = RetryLayer::<String, String, TestError>::builder()
+        .max_attempts(3)
+        .fixed_backoff(std::time::Duration::from_millis(1))
+        .retry_until(|status: &String| status == "ready")
+        .build();
+
+    let mut service = layer.layer(service);
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call("job-1".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(response, "pending");
+    assert_eq!(call_count.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_core::{Deadline, MockClock};
+use tower_resilience_retry::{
+    Delivery, InMemoryRetryScheduler, RetryBudgetBuilder, RetryJob, RetryLayer, RetryScheduler,
+    ScheduleError,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct TestError;
+
+/// Fails the first `failures` calls, then succeeds
+fn flaky(
+    failures: usize,
+    calls: Arc<AtomicUsize>,
+) -> impl Service<u32, Response = u32, Error = TestError, Future: Send> + Clone + Send + 'static {
+    tower::service_fn(move |req: u32| {
+        let call = calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if call < failures {
+                Err(TestError)
+            } else {
+                Ok(req)
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn caller_is_acknowledged_and_retry_runs_later() {
+    let clock = MockClock::new();
+    let (scheduler, mut due) = InMemoryRetryScheduler::with_clock(clock.clone());
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let layer = RetryLayer::<u32, u32, TestError>::builder()
+        .max_attempts(3)
+        .fixed_backoff(Duration::from_secs(60))
+        .build_scheduled(scheduler);
+    let mut service = layer.layer(flaky(2, Arc::clone(&calls)));
+
+    // The first attempt fails and is handed off
+    let delivery = service.ready().await.unwrap().call(7).await.unwrap();
+    assert_eq!(
+        delivery,
+        Delivery::Scheduled {
+            attempt: 1,
+            delay: Duration::from_secs(60)
+        }
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // The second attempt fails again and is rescheduled
+    let job = due.next().await.unwrap();
+    assert_eq!(job.request, 7);
+    assert!(service.resume(job).await.unwrap().is_scheduled());
+
+    // The third attempt succeeds
+    let job = due.next().await.unwrap();
+    assert_eq!(job.attempt, 2);
+    assert_eq!(service.resume(job).await.unwrap(), Delivery::Completed(7));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(clock.elapsed(), Duration::from_secs(120));
+}
+
+#[tokio::test]
+async fn exhausted_retries_return_the_error() {
+    let (scheduler, mut due) = InMemoryRetryScheduler::with_clock(MockClock::new());
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let layer = RetryLayer::<u32, u32, TestError>::builder()
+        .max_attempts(2)
+        .fixed_backoff(Duration::from_secs(1))
+        .build_scheduled(scheduler);
+    let mut service = layer.layer(flaky(usize::MAX, Arc::clone(&calls)));
+
+    assert!(
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call(1)
+            .await
+            .unwrap()
+            .is_scheduled()
+    );
+    let job = due.next().await.unwrap();
+    assert_eq!(service.resume(job).await, Err(TestError));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn rejected_jobs_return_the_original_outcome() {
+    struct Full;
+
+    impl RetryScheduler<u32> for Full {
+        fn schedule(
+            &self,
+            _job: RetryJob<u32>,
+        ) -> futures::future::BoxFuture<'static, Result<(), ScheduleError>> {
+            Box::pin(async { Err(ScheduleError::new("queue full")) })
+        }
+    }
+
+    let layer = RetryLayer::<u32, u32, TestError>::builder()
+        .max_attempts(5)
+        .build_scheduled(Full);
+    let mut service = layer.layer(flaky(usize::MAX, Arc::new(AtomicUsize::new(0))));
+
+    let result = service.ready().await.unwrap().call(1).await;
+    assert_eq!(result, Err(TestError));
+}
+
+#[tokio::test]
+async fn process_resumes_due_jobs() {
+    let (scheduler, due) = InMemoryRetryScheduler::with_clock(MockClock::new());
+    let calls = Arc::new(AtomicUsize::new(0));
+    let succeeded = Arc::new(AtomicUsize::new(0));
+    let s = Arc::clone(&succeeded);
+
+    let layer = RetryLayer::<u32, u32, TestError>::builder()
+        .max_attempts(3)
+        .fixed_backoff(Duration::from_secs(5))
+        .on_success(move |_| {
+            s.fetch_add(1, Ordering::SeqCst);
+        })
+        .build_scheduled(scheduler);
+    let mut service = layer.layer(flaky(1, Arc::clone(&calls)));
+    tokio::spawn(service.clone().process(due));
+
+    assert!(
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call(1)
+            .await
+            .unwrap()
+            .is_scheduled()
+    );
+
+    for _ in 0..100 {
+        if succeeded.load(Ordering::SeqCst) == 1 {
+            break;
+        }
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(succeeded.load(Ordering::SeqCst), 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn deadline_stops_scheduling_without_spending_budget() {
+    let (scheduler, _due) = InMemoryRetryScheduler::with_clock(MockClock::new());
+    let calls = Arc::new(AtomicUsize::new(0));
+    let budget = RetryBudgetBuilder::new()
+        .token_bucket()
+        .tokens_per_second(0.0)
+        .max_tokens(1)
+        .initial_tokens(1)
+        .build();
+
+    let layer = RetryLayer::<u32, u32, TestError>::builder()
+        .max_attempts(3)
+        .fixed_backoff(Duration::from_secs(60))
+        .budget(Arc::clone(&budget))
+        .build_scheduled(scheduler);
+    let service = layer.layer(flaky(usize::MAX, Arc::clone(&calls)));
+
+    // The backoff alone would outlast the caller's deadline
+    let result = Deadline::after(Duration::from_secs(1))
+        .scope(service.oneshot(1))
+        .await;
+    assert_eq!(result, Err(TestError));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(budget.balance(), 1);
+}
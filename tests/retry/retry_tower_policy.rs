@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::retry::RetryLayer as TowerRetryLayer;
+use tower::{Layer, Service, ServiceExt};
+use tower_resilience_core::MockClock;
+use tower_resilience_retry::{RetryBudgetBuilder, RetryLayer};
+
+#[derive(Debug, Clone, PartialEq)]
+struct TestError;
+
+#[tokio::test]
+async fn tower_retry_polls_responses_with_configured_backoff() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    let clock = MockClock::new();
+
+    let policy = RetryLayer::<(), usize, TestError>::builder()
+        .max_attempts(5)
+        .fixed_backoff(Duration::from_secs(2))
+        .retry_until(|ready: &usize| *ready >= 2)
+        .clock(clock.clone())
+        .build_policy();
+    let mut service = TowerRetryLayer::new(policy).layer(tower::service_fn(move |_: ()| {
+        let call = calls_clone.fetch_add(1, Ordering::SeqCst);
+        async move { Ok::<_, TestError>(call) }
+    }));
+
+    assert_eq!(service.ready().await.unwrap().call(()).await, Ok(2));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(clock.elapsed(), Duration::from_secs(4));
+}
+
+#[tokio::test]
+async fn tower_retry_stops_when_budget_is_exhausted() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    let exhausted = Arc::new(AtomicUsize::new(0));
+    let exhausted_clone = Arc::clone(&exhausted);
+
+    let budget = RetryBudgetBuilder::new()
+        .token_bucket()
+        .tokens_per_second(0.0)
+        .initial_tokens(1)
+        .max_tokens(1)
+        .build();
+    let policy = RetryLayer::<(), (), TestError>::builder()
+        .max_attempts(5)
+        .fixed_backoff(Duration::from_millis(1))
+        .budget(budget)
+        .on_budget_exhausted(move |_| {
+            exhausted_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .build_policy();
+    let mut service = TowerRetryLayer::new(policy).layer(tower::service_fn(move |_: ()| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        async { Err::<(), _>(TestError) }
+    }));
+
+    // One attempt plus the single retry the budget allows
+    assert!(service.ready().await.unwrap().call(()).await.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(exhausted.load(Ordering::SeqCst), 1);
+}
@@ -8,7 +8,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio::time::sleep;
 use tower::{Layer, Service, ServiceExt, service_fn};
-use tower_resilience_timelimiter::TimeLimiterLayer;
+use tower_resilience_timelimiter::{CancellationToken, TimeLimiterLayer};
 
 /// A guard that sets a flag when dropped, allowing us to detect future cancellation.
 struct DropGuard {
@@ -246,3 +246,273 @@ async fn no_resource_leaks() {
     // This is a basic check - in production you'd use more sophisticated leak detection
     assert!(allocations.load(Ordering::SeqCst));
 }
+
+/// Request carrying a slot for the propagated cancellation token.
+#[derive(Default)]
+struct TokenRequest {
+    token: Option<CancellationToken>,
+}
+
+#[tokio::test]
+async fn propagated_token_cancelled_on_timeout_for_spawned_work() {
+    let stopped = Arc::new(AtomicBool::new(false));
+    let stopped_clone = Arc::clone(&stopped);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(30))
+        .propagate_cancellation(|req: &mut TokenRequest, token| req.token = Some(token))
+        .build();
+
+    let svc = service_fn(move |req: TokenRequest| {
+        let stopped = Arc::clone(&stopped_clone);
+        async move {
+            let token = req.token.expect("token should be attached");
+            // Spawned work outlives the dropped future unless it observes the token
+            tokio::spawn(async move {
+                token.cancelled().await;
+                stopped.store(true, Ordering::SeqCst);
+            });
+            sleep(Duration::from_millis(200)).await;
+            Ok::<_, TestError>("should timeout")
+        }
+    });
+
+    let mut service = layer.layer(svc);
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest::default())
+        .await;
+    assert!(result.unwrap_err().is_timeout());
+
+    sleep(Duration::from_millis(10)).await;
+    assert!(stopped.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn propagated_token_not_cancelled_without_cancel_running_future() {
+    let observed = Arc::new(AtomicBool::new(false));
+    let observed_clone = Arc::clone(&observed);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(30))
+        .cancel_running_future(false)
+        .propagate_cancellation(|req: &mut TokenRequest, token| req.token = Some(token))
+        .build();
+
+    let svc = service_fn(move |req: TokenRequest| {
+        let observed = Arc::clone(&observed_clone);
+        async move {
+            let token = req.token.unwrap();
+            tokio::select! {
+                _ = token.cancelled() => observed.store(true, Ordering::SeqCst),
+                _ = sleep(Duration::from_millis(500)) => {}
+            }
+            Ok::<_, TestError>("done")
+        }
+    });
+
+    let mut service = layer.layer(svc);
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest::default())
+        .await;
+    assert!(result.unwrap_err().is_timeout());
+
+    // The call is meant to keep running, so its token is left alone
+    sleep(Duration::from_millis(10)).await;
+    assert!(!observed.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn propagated_token_cancelled_when_caller_drops_call() {
+    let observed = Arc::new(AtomicBool::new(false));
+    let observed_clone = Arc::clone(&observed);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_secs(5))
+        .propagate_cancellation(|req: &mut TokenRequest, token| req.token = Some(token))
+        .build();
+
+    let svc = service_fn(move |req: TokenRequest| {
+        let observed = Arc::clone(&observed_clone);
+        async move {
+            let token = req.token.unwrap();
+            tokio::spawn(async move {
+                token.cancelled().await;
+                observed.store(true, Ordering::SeqCst);
+            });
+            sleep(Duration::from_secs(1)).await;
+            Ok::<_, TestError>("done")
+        }
+    });
+
+    let mut service = layer.layer(svc);
+    let call = service.ready().await.unwrap().call(TokenRequest::default());
+
+    // The caller gives up long before the timeout
+    let _ = tokio::time::timeout(Duration::from_millis(20), call).await;
+
+    sleep(Duration::from_millis(10)).await;
+    assert!(observed.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn propagated_token_not_cancelled_on_success() {
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(100))
+        .propagate_cancellation(|req: &mut TokenRequest, token| req.token = Some(token))
+        .build();
+
+    let svc = service_fn(|req: TokenRequest| async move { Ok::<_, TestError>(req.token.unwrap()) });
+
+    let mut service = layer.layer(svc);
+    let token = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest::default())
+        .await
+        .unwrap();
+    assert!(!token.is_cancelled());
+}
+
+#[tokio::test]
+async fn parent_token_cancels_per_call_tokens() {
+    let parent = CancellationToken::new();
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(100))
+        .parent_cancellation_token(parent.clone())
+        .propagate_cancellation(|req: &mut TokenRequest, token| req.token = Some(token))
+        .build();
+
+    let svc = service_fn(|req: TokenRequest| async move { Ok::<_, TestError>(req.token.unwrap()) });
+
+    let mut service = layer.layer(svc);
+    let first = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest::default())
+        .await
+        .unwrap();
+    let second = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest::default())
+        .await
+        .unwrap();
+
+    parent.cancel();
+    assert!(first.is_cancelled());
+    assert!(second.is_cancelled());
+}
+
+#[tokio::test]
+async fn grace_period_reports_late_completion() {
+    let late = Arc::new(std::sync::Mutex::new(None));
+    let late_clone = Arc::clone(&late);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(30))
+        .grace_period(Duration::from_millis(200))
+        .on_timed_out_late(move |late_by| {
+            *late_clone.lock().unwrap() = Some(late_by);
+        })
+        .build();
+
+    let svc = service_fn(|_req: ()| async {
+        sleep(Duration::from_millis(80)).await;
+        Ok::<_, TestError>("late")
+    });
+
+    let mut service = layer.layer(svc);
+    let start = std::time::Instant::now();
+    let result = service.ready().await.unwrap().call(()).await;
+
+    // The caller sees the timeout immediately
+    assert!(result.unwrap_err().is_timeout());
+    assert!(start.elapsed() < Duration::from_millis(70));
+
+    sleep(Duration::from_millis(100)).await;
+    let late_by = late.lock().unwrap().expect("late completion reported");
+    assert!(late_by >= Duration::from_millis(40));
+    assert!(late_by < Duration::from_millis(150));
+}
+
+#[tokio::test]
+async fn grace_period_drops_future_after_expiry() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let dropped_clone = Arc::clone(&dropped);
+    let late_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let lc = Arc::clone(&late_count);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(20))
+        .grace_period(Duration::from_millis(30))
+        .on_timed_out_late(move |_| {
+            lc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let svc = service_fn(move |_req: ()| {
+        let flag = Arc::clone(&dropped_clone);
+        async move {
+            let _guard = DropGuard { flag };
+            sleep(Duration::from_millis(500)).await;
+            Ok::<_, TestError>("never")
+        }
+    });
+
+    let mut service = layer.layer(svc);
+    let result = service.ready().await.unwrap().call(()).await;
+    assert!(result.unwrap_err().is_timeout());
+
+    // Still running during the grace period
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    sleep(Duration::from_millis(60)).await;
+    assert!(dropped.load(Ordering::SeqCst));
+    assert_eq!(late_count.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn grace_period_delays_token_cancellation() {
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(20))
+        .grace_period(Duration::from_millis(50))
+        .propagate_cancellation(|req: &mut TokenRequest, token| req.token = Some(token))
+        .build();
+
+    let observed = Arc::new(std::sync::Mutex::new(None));
+    let observed_clone = Arc::clone(&observed);
+
+    let svc = service_fn(move |req: TokenRequest| {
+        let observed = Arc::clone(&observed_clone);
+        async move {
+            *observed.lock().unwrap() = req.token.clone();
+            sleep(Duration::from_millis(500)).await;
+            Ok::<_, TestError>("never")
+        }
+    });
+
+    let mut service = layer.layer(svc);
+    let result = service
+        .ready()
+        .await
+        .unwrap()
+        .call(TokenRequest::default())
+        .await;
+    assert!(result.unwrap_err().is_timeout());
+
+    let token = observed.lock().unwrap().clone().unwrap();
+    assert!(!token.is_cancelled());
+
+    sleep(Duration::from_millis(80)).await;
+    assert!(token.is_cancelled());
+}
@@ -0,0 +1,198 @@
+//! Deadline propagation tests.
+//!
+//! Tests that the deadline set by a time limiter is visible to nested layers
+//! and that those layers shrink their budgets to fit within it.
+
+use super::TestError;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+use tower::{Layer, Service, ServiceBuilder, ServiceExt, service_fn};
+use tower_resilience_hedge::HedgeLayer;
+use tower_resilience_retry::RetryLayer;
+use tower_resilience_timelimiter::{Deadline, InjectDeadlineLayer, TimeLimiterLayer};
+
+#[tokio::test]
+async fn inner_service_sees_deadline() {
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(200))
+        .build();
+
+    let svc = service_fn(|_req: ()| async {
+        Ok::<_, TestError>(Deadline::current().map(|deadline| deadline.remaining()))
+    });
+
+    let mut service = layer.layer(svc);
+    let remaining = service.ready().await.unwrap().call(()).await.unwrap();
+
+    let remaining = remaining.expect("deadline should be set");
+    assert!(remaining <= Duration::from_millis(200));
+    assert!(remaining > Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn no_deadline_outside_time_limiter() {
+    assert_eq!(Deadline::current(), None);
+}
+
+#[tokio::test]
+async fn nested_time_limiter_shrinks_to_outer_deadline() {
+    let svc = service_fn(|_req: ()| async {
+        sleep(Duration::from_millis(500)).await;
+        Ok::<_, TestError>(())
+    });
+
+    let inner_timeouts = Arc::new(AtomicUsize::new(0));
+    let it = Arc::clone(&inner_timeouts);
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_millis(50))
+                .build(),
+        )
+        .map_err(|e| format!("{e:?}"))
+        .layer(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_secs(10))
+                .on_timeout(move || {
+                    it.fetch_add(1, Ordering::SeqCst);
+                })
+                .cancel_running_future(false)
+                .build(),
+        )
+        .service(svc);
+
+    let start = Instant::now();
+    let result = service.ready().await.unwrap().call(()).await;
+
+    assert!(result.is_err());
+    assert!(start.elapsed() < Duration::from_millis(300));
+
+    // The inner limiter would not fire for 10s on its own; with the outer
+    // deadline it is capped at ~50ms and times out alongside the outer one.
+    sleep(Duration::from_millis(20)).await;
+    assert_eq!(inner_timeouts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn retry_stops_when_backoff_exceeds_deadline() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let cc = Arc::clone(&call_count);
+
+    let svc = service_fn(move |_req: ()| {
+        cc.fetch_add(1, Ordering::SeqCst);
+        async { Err::<(), _>(TestError("fail".to_string())) }
+    });
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_millis(100))
+                .build(),
+        )
+        .layer(
+            RetryLayer::<(), (), TestError>::builder()
+                .max_attempts(5)
+                .fixed_backoff(Duration::from_millis(200))
+                .build(),
+        )
+        .service(svc);
+
+    let start = Instant::now();
+    let result = service.ready().await.unwrap().call(()).await;
+
+    // The retry layer gives up immediately rather than sleeping past the
+    // deadline, so the inner error is returned instead of a timeout.
+    let err = result.unwrap_err();
+    assert!(!err.is_timeout());
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    assert!(start.elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn hedge_attempts_see_deadline() {
+    let seen = Arc::new(AtomicUsize::new(0));
+    let s = Arc::clone(&seen);
+
+    let svc = service_fn(move |_req: ()| {
+        let s = Arc::clone(&s);
+        async move {
+            if Deadline::current().is_some() {
+                s.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok::<_, TestError>(())
+        }
+    });
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_millis(200))
+                .build(),
+        )
+        .layer(
+            HedgeLayer::builder()
+                .no_delay()
+                .max_hedged_attempts(2)
+                .build(),
+        )
+        .service(svc);
+
+    service.ready().await.unwrap().call(()).await.unwrap();
+    sleep(Duration::from_millis(10)).await;
+
+    assert!(seen.load(Ordering::SeqCst) >= 1);
+}
+
+#[tokio::test]
+async fn inject_deadline_layer_sets_header() {
+    type Headers = HashMap<String, String>;
+
+    let mut service = ServiceBuilder::new()
+        .layer(
+            TimeLimiterLayer::builder()
+                .timeout_duration(Duration::from_secs(2))
+                .build(),
+        )
+        .layer(InjectDeadlineLayer::new(|req: &mut Headers, deadline| {
+            req.insert("grpc-timeout".to_string(), deadline.grpc_timeout());
+        }))
+        .service(service_fn(
+            |req: Headers| async move { Ok::<_, TestError>(req) },
+        ));
+
+    let headers = service
+        .ready()
+        .await
+        .unwrap()
+        .call(Headers::new())
+        .await
+        .unwrap();
+
+    let value = headers.get("grpc-timeout").expect("header should be set");
+    assert!(value.ends_with('u'), "{value}");
+}
+
+#[tokio::test]
+async fn inject_deadline_layer_passes_through_without_deadline() {
+    type Headers = HashMap<String, String>;
+
+    let mut service = InjectDeadlineLayer::new(|req: &mut Headers, deadline: Deadline| {
+        req.insert("grpc-timeout".to_string(), deadline.grpc_timeout());
+    })
+    .layer(service_fn(
+        |req: Headers| async move { Ok::<_, TestError>(req) },
+    ));
+
+    let headers = service
+        .ready()
+        .await
+        .unwrap()
+        .call(Headers::new())
+        .await
+        .unwrap();
+    assert!(headers.is_empty());
+}
@@ -9,7 +9,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tokio::time::sleep;
 use tower::{Layer, Service, ServiceExt, service_fn};
-use tower_resilience_timelimiter::TimeLimiterLayer;
+use tower_resilience_timelimiter::{AdaptiveTimeout, TimeLimiterLayer};
 
 #[tokio::test]
 async fn success_within_timeout() {
@@ -228,3 +228,166 @@ async fn instant_error() {
     assert!(result.is_err());
     assert!(!result.unwrap_err().is_timeout());
 }
+
+#[tokio::test]
+async fn adaptive_timeout_tracks_observed_latency() {
+    let adaptive = AdaptiveTimeout::new(0.9)
+        .multiplier(2.0)
+        .min(Duration::from_millis(1))
+        .initial(Duration::from_secs(5))
+        .min_samples(5);
+
+    let layer = TimeLimiterLayer::builder()
+        .adaptive_timeout(adaptive.clone())
+        .build();
+
+    let svc = service_fn(|sleep_ms: u64| async move {
+        sleep(Duration::from_millis(sleep_ms)).await;
+        Ok::<_, TestError>(())
+    });
+    let mut service = layer.layer(svc);
+
+    // A slow call succeeds under the initial timeout
+    let result = service.ready().await.unwrap().call(100).await;
+    assert!(result.is_ok());
+
+    // Fast calls pull the timeout down towards 2x the observed latency
+    for _ in 0..10 {
+        service.ready().await.unwrap().call(5).await.unwrap();
+    }
+    assert!(adaptive.current_timeout() < Duration::from_millis(100));
+
+    // The same slow call now times out
+    let result = service.ready().await.unwrap().call(100).await;
+    assert!(result.unwrap_err().is_timeout());
+}
+
+#[tokio::test]
+async fn soft_timeout_reports_without_cancelling() {
+    let soft_count = Arc::new(AtomicUsize::new(0));
+    let timeout_count = Arc::new(AtomicUsize::new(0));
+    let sc = Arc::clone(&soft_count);
+    let tc = Arc::clone(&timeout_count);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(200))
+        .soft_timeout(Duration::from_millis(20))
+        .on_soft_timeout(move |soft_timeout| {
+            assert_eq!(soft_timeout, Duration::from_millis(20));
+            sc.fetch_add(1, Ordering::SeqCst);
+        })
+        .on_timeout(move || {
+            tc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let svc = service_fn(|sleep_ms: u64| async move {
+        sleep(Duration::from_millis(sleep_ms)).await;
+        Ok::<_, TestError>("done")
+    });
+    let mut service = layer.layer(svc);
+
+    // Fast call: no soft timeout
+    let result = service.ready().await.unwrap().call(5).await;
+    assert!(result.is_ok());
+    assert_eq!(soft_count.load(Ordering::SeqCst), 0);
+
+    // Slow call: soft timeout fires, but the call still succeeds
+    let result = service.ready().await.unwrap().call(60).await;
+    assert_eq!(result.unwrap(), "done");
+    assert_eq!(soft_count.load(Ordering::SeqCst), 1);
+
+    // Very slow call: soft timeout then hard timeout
+    let result = service.ready().await.unwrap().call(500).await;
+    assert!(result.unwrap_err().is_timeout());
+    assert_eq!(soft_count.load(Ordering::SeqCst), 2);
+    assert_eq!(timeout_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn soft_timeout_above_hard_timeout_is_ignored() {
+    let soft_count = Arc::new(AtomicUsize::new(0));
+    let sc = Arc::clone(&soft_count);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(20))
+        .soft_timeout(Duration::from_millis(50))
+        .on_soft_timeout(move |_| {
+            sc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let svc = service_fn(|_req: ()| async {
+        sleep(Duration::from_millis(100)).await;
+        Ok::<_, TestError>("done")
+    });
+    let mut service = layer.layer(svc);
+
+    let result = service.ready().await.unwrap().call(()).await;
+    assert!(result.unwrap_err().is_timeout());
+    assert_eq!(soft_count.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn timeout_fallback_returns_degraded_response() {
+    let timeout_count = Arc::new(AtomicUsize::new(0));
+    let tc = Arc::clone(&timeout_count);
+
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(20))
+        .timeout_fallback(|req: u64| format!("fallback for {req}"))
+        .on_timeout(move || {
+            tc.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    let svc = service_fn(|sleep_ms: u64| async move {
+        sleep(Duration::from_millis(sleep_ms)).await;
+        Ok::<_, TestError>(format!("slept {sleep_ms}"))
+    });
+    let mut service = layer.layer(svc);
+
+    let result = service.ready().await.unwrap().call(1).await;
+    assert_eq!(result.unwrap(), "slept 1");
+
+    let result = service.ready().await.unwrap().call(100).await;
+    assert_eq!(result.unwrap(), "fallback for 100");
+    assert_eq!(timeout_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn timeout_fallback_does_not_mask_inner_errors() {
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(50))
+        .timeout_fallback(|_req: ()| "fallback")
+        .build();
+
+    let svc = service_fn(|_req: ()| async { Err::<&str, _>(TestError("boom".to_string())) });
+    let mut service = layer.layer(svc);
+
+    let result = service.ready().await.unwrap().call(()).await;
+    assert_eq!(
+        result.unwrap_err().into_inner(),
+        Some(TestError("boom".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn async_timeout_fallback() {
+    let layer = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(20))
+        .timeout_fallback_async(|req: String| async move {
+            sleep(Duration::from_millis(5)).await;
+            format!("cached {req}")
+        })
+        .build();
+
+    let svc = service_fn(|req: String| async move {
+        sleep(Duration::from_millis(100)).await;
+        Ok::<_, TestError>(req)
+    });
+    let mut service = layer.layer(svc);
+
+    let result = service.ready().await.unwrap().call("key".to_string()).await;
+    assert_eq!(result.unwrap(), "cached key");
+}
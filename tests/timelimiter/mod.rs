@@ -4,13 +4,15 @@
 //!
 //! - **integration**: Basic integration tests verifying core functionality
 //! - **timeout_precision**: Tests for timeout accuracy and edge cases
-//! - **cancellation**: Tests for future cancellation behavior
+//! - **cancellation**: Tests for future cancellation and token propagation
 //! - **concurrency**: Tests for concurrent timeout handling
 //! - **config**: Tests for configuration validation
+//! - **deadline**: Tests for deadline propagation to nested layers
 
 mod cancellation;
 mod concurrency;
 mod config;
+mod deadline;
 mod integration;
 mod timeout_precision;
 
@@ -1,4 +1,5 @@
-//! Integration tests for ResilienceErrorLayer and unified error composition.
+//! Integration tests for ResilienceErrorLayer, unified error composition and
+//! pattern error classification.
 
 use std::fmt;
 use std::sync::Arc;
@@ -6,10 +7,12 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tower::{Service, ServiceBuilder, ServiceExt};
 use tower_resilience_bulkhead::BulkheadLayer;
-use tower_resilience_circuitbreaker::CircuitBreakerLayer;
-use tower_resilience_core::{ResilienceError, ResilienceErrorLayer};
+use tower_resilience_circuitbreaker::{CircuitBreakerError, CircuitBreakerLayer};
+use tower_resilience_core::{
+    BoxError, PatternError, ResilienceError, ResilienceErrorLayer, find_pattern_failure, flatten,
+};
 use tower_resilience_ratelimiter::RateLimiterLayer;
-use tower_resilience_timelimiter::TimeLimiterLayer;
+use tower_resilience_timelimiter::{TimeLimiterError, TimeLimiterLayer};
 
 #[derive(Debug, Clone)]
 struct AppError(String);
@@ -230,3 +233,84 @@ async fn test_error_display_distinguishes_failure_modes() {
     };
     assert!(ejected_err.to_string().contains("backend-1"));
 }
+
+#[tokio::test]
+async fn test_nested_errors_classified_per_level() {
+    let svc = tower::service_fn(|req: String| async move {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Ok::<_, AppError>(req)
+    });
+
+    let cb = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_size(100)
+        .build();
+    let tl = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(10))
+        .build();
+
+    let mut svc = ServiceBuilder::new().layer(cb).layer(tl).service(svc);
+
+    let err: CircuitBreakerError<TimeLimiterError<AppError>> = svc
+        .ready()
+        .await
+        .unwrap()
+        .call("slow".into())
+        .await
+        .unwrap_err();
+
+    assert!(!err.is_rejection());
+    let inner = err.source_inner().unwrap();
+    assert!(PatternError::is_timeout(inner));
+    assert!(inner.source_inner().is_none());
+}
+
+#[tokio::test]
+async fn test_flattened_box_error_stack() {
+    let svc = tower::service_fn(|req: String| async move {
+        if req == "bad" {
+            Err(AppError("bad request".into()))
+        } else {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(req)
+        }
+    });
+
+    let cb = CircuitBreakerLayer::builder()
+        .failure_rate_threshold(0.5)
+        .sliding_window_size(100)
+        .build();
+    let tl = TimeLimiterLayer::builder()
+        .timeout_duration(Duration::from_millis(10))
+        .build();
+
+    let mut svc = ServiceBuilder::new()
+        .map_err(flatten::<CircuitBreakerError<BoxError>>)
+        .layer(cb)
+        .map_err(flatten::<TimeLimiterError<AppError>>)
+        .layer(tl)
+        .service(svc);
+
+    let err: BoxError = svc
+        .ready()
+        .await
+        .unwrap()
+        .call("slow".into())
+        .await
+        .unwrap_err();
+    let failure = find_pattern_failure(&*err).unwrap();
+    assert!(failure.is_timeout());
+    assert!(!failure.is_rejection());
+    assert_eq!(err.to_string(), "request timed out");
+
+    // Application errors come out unwrapped
+    let err: BoxError = svc
+        .ready()
+        .await
+        .unwrap()
+        .call("bad".into())
+        .await
+        .unwrap_err();
+    assert!(find_pattern_failure(&*err).is_none());
+    assert_eq!(err.downcast_ref::<AppError>().unwrap().0, "bad request");
+}